@@ -0,0 +1,10 @@
+//! Generates the gRPC server and client code from `proto/`.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    if std::env::var_os("PROTOC").is_none() {
+        std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    }
+    println!("cargo:rerun-if-changed=proto");
+    tonic_prost_build::configure().compile_protos(&["proto/ocpp.proto"], &["proto"])?;
+    Ok(())
+}
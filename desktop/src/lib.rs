@@ -14,6 +14,7 @@ use tauri::{
     AppHandle, Manager, Runtime, State,
 };
 
+use texnouz_ocpp::config::reload::is_live;
 use texnouz_ocpp::config::{default_config_path, AppConfig};
 
 // ── Server State ───────────────────────────────────────────────
@@ -26,6 +27,8 @@ struct ServerStateInner {
     process: Option<Child>,
     config_path: PathBuf,
     config: AppConfig,
+    /// Configuration the running server was started with
+    started_with: Option<AppConfig>,
 }
 
 impl ServerState {
@@ -37,6 +40,7 @@ impl ServerState {
                 process: None,
                 config_path,
                 config,
+                started_with: None,
             }),
         }
     }
@@ -86,9 +90,26 @@ impl ServerState {
             config_path.display()
         );
         s.process = Some(child);
+        s.started_with = Some(s.config.clone());
         Ok(())
     }
 
+    /// Whether the running server picks up `config` from the file by
+    /// itself, i.e. it watches the file and only live settings changed.
+    fn applies_live(&self, config: &AppConfig) -> bool {
+        if !self.is_running() {
+            return false;
+        }
+        let s = self.inner.lock().unwrap();
+        s.started_with.as_ref().is_some_and(|started_with| {
+            started_with.server.config_reload_interval_secs > 0
+                && started_with
+                    .changed_settings(config)
+                    .iter()
+                    .all(|setting| is_live(setting))
+        })
+    }
+
     pub fn stop_server(&self) -> Result<(), String> {
         let mut s = self.inner.lock().unwrap();
         if let Some(ref mut child) = s.process {
@@ -197,6 +218,7 @@ fn server_restart(state: State<'_, ServerState>) -> Result<(), String> {
 
 #[tauri::command]
 fn save_and_restart(state: State<'_, ServerState>, config: AppConfig) -> Result<(), String> {
+    let applies_live = state.applies_live(&config);
     // Save config to disk
     {
         let mut s = state.inner.lock().map_err(|e| e.to_string())?;
@@ -205,6 +227,11 @@ fn save_and_restart(state: State<'_, ServerState>, config: AppConfig) -> Result<
         eprintln!("[desktop] Config saved to {}", path.display());
         s.config = config;
     }
+    if applies_live {
+        // The server reloads the file itself
+        eprintln!("[desktop] Settings apply without a restart");
+        return Ok(());
+    }
     // Restart server with new config
     state.restart_server()
 }
@@ -0,0 +1,315 @@
+//! Paged exports
+//!
+//! Exports are read a page at a time so that large tables (id tags,
+//! transactions) can be streamed to the client. Column names match the
+//! import columns, so an export edited in a spreadsheet can be imported
+//! again; columns an import does not know are ignored.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use super::service::BulkService;
+use super::table::Cell;
+use super::BulkEntity;
+use crate::domain::{ChargePoint, DomainResult, IdTag, Tariff, Transaction, TransactionBilling};
+
+/// Rows read per page.
+pub const EXPORT_PAGE_SIZE: u64 = 500;
+
+const ID_TAG_COLUMNS: &[&str] = &[
+    "id_tag",
+    "parent_id_tag",
+    "status",
+    "user_id",
+    "name",
+    "expiry_date",
+    "max_active_transactions",
+    "is_active",
+    "created_at",
+    "last_used_at",
+];
+
+const CHARGE_POINT_COLUMNS: &[&str] = &[
+    "id",
+    "vendor",
+    "model",
+    "serial_number",
+    "firmware_version",
+    "ocpp_version",
+    "connectors",
+    "has_password",
+    "status",
+    "registered_at",
+    "last_heartbeat",
+];
+
+const TARIFF_COLUMNS: &[&str] = &[
+    "id",
+    "name",
+    "description",
+    "tariff_type",
+    "price_per_kwh",
+    "price_per_minute",
+    "session_fee",
+    "currency",
+    "min_fee",
+    "max_fee",
+    "is_active",
+    "is_default",
+    "valid_from",
+    "valid_until",
+];
+
+const TRANSACTION_COLUMNS: &[&str] = &[
+    "id",
+    "charge_point_id",
+    "connector_id",
+    "id_tag",
+    "status",
+    "started_at",
+    "stopped_at",
+    "duration_seconds",
+    "meter_start",
+    "meter_stop",
+    "energy_wh",
+    "stop_reason",
+    "tariff_id",
+    "energy_cost",
+    "time_cost",
+    "session_fee",
+    "total_cost",
+    "currency",
+    "billing_status",
+];
+
+/// Restricts a transaction export to those started in `[from, to)`.
+#[derive(Debug, Clone, Default)]
+pub struct ExportFilter {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// One page of an export.
+#[derive(Debug, Clone)]
+pub struct ExportPage {
+    pub rows: Vec<Vec<Cell>>,
+    /// Cursor for the next page; `None` after the last.
+    pub next: Option<String>,
+}
+
+impl BulkEntity {
+    /// Export columns, in order.
+    pub fn columns(&self) -> &'static [&'static str] {
+        match self {
+            Self::IdTags => ID_TAG_COLUMNS,
+            Self::ChargePoints => CHARGE_POINT_COLUMNS,
+            Self::Tariffs => TARIFF_COLUMNS,
+            Self::Transactions => TRANSACTION_COLUMNS,
+        }
+    }
+}
+
+impl BulkService {
+    /// Read the page after cursor `after` (`None` for the first page).
+    pub async fn export_page(
+        &self,
+        entity: BulkEntity,
+        filter: &ExportFilter,
+        after: Option<&str>,
+    ) -> DomainResult<ExportPage> {
+        match entity {
+            BulkEntity::IdTags => {
+                let tags = self
+                    .repos
+                    .id_tags()
+                    .find_page(after.unwrap_or_default(), EXPORT_PAGE_SIZE)
+                    .await?;
+                let next = next_cursor(&tags, |t| t.id_tag.clone());
+                Ok(ExportPage {
+                    rows: tags.into_iter().map(id_tag_row).collect(),
+                    next,
+                })
+            }
+            // Small enough to export in one page
+            BulkEntity::ChargePoints => {
+                let mut charge_points = self.repos.charge_points().find_all().await?;
+                charge_points.sort_by(|a, b| a.id.cmp(&b.id));
+                Ok(ExportPage {
+                    rows: charge_points.into_iter().map(charge_point_row).collect(),
+                    next: None,
+                })
+            }
+            BulkEntity::Tariffs => {
+                let mut tariffs = self.repos.tariffs().find_all().await?;
+                tariffs.sort_by_key(|t| t.id);
+                Ok(ExportPage {
+                    rows: tariffs.into_iter().map(tariff_row).collect(),
+                    next: None,
+                })
+            }
+            BulkEntity::Transactions => {
+                let after_id = after.and_then(|a| a.parse().ok()).unwrap_or(0);
+                let transactions = self
+                    .repos
+                    .transactions()
+                    .find_page(after_id, filter.from, filter.to, EXPORT_PAGE_SIZE)
+                    .await?;
+                let ids: Vec<i32> = transactions.iter().map(|t| t.id).collect();
+                let mut billings: HashMap<i32, TransactionBilling> = self
+                    .repos
+                    .billing()
+                    .find_billings(&ids)
+                    .await?
+                    .into_iter()
+                    .map(|b| (b.transaction_id, b))
+                    .collect();
+                let next = next_cursor(&transactions, |t| t.id.to_string());
+                Ok(ExportPage {
+                    rows: transactions
+                        .into_iter()
+                        .map(|t| {
+                            let billing = billings.remove(&t.id);
+                            transaction_row(t, billing)
+                        })
+                        .collect(),
+                    next,
+                })
+            }
+        }
+    }
+}
+
+/// The last item's key when the page is full, i.e. more may follow.
+fn next_cursor<T>(page: &[T], key: impl Fn(&T) -> String) -> Option<String> {
+    if page.len() as u64 == EXPORT_PAGE_SIZE {
+        page.last().map(key)
+    } else {
+        None
+    }
+}
+
+fn id_tag_row(t: IdTag) -> Vec<Cell> {
+    vec![
+        t.id_tag.into(),
+        t.parent_id_tag.into(),
+        t.status.to_string().into(),
+        t.user_id.into(),
+        t.name.into(),
+        t.expiry_date.into(),
+        t.max_active_transactions.into(),
+        Cell::bool(t.is_active),
+        t.created_at.into(),
+        t.last_used_at.into(),
+    ]
+}
+
+fn charge_point_row(cp: ChargePoint) -> Vec<Cell> {
+    // Connector 0 stands for the station itself
+    let connectors = cp.connectors.iter().filter(|c| c.id > 0).count() as i64;
+    vec![
+        cp.id.into(),
+        cp.vendor.into(),
+        cp.model.into(),
+        cp.serial_number.into(),
+        cp.firmware_version.into(),
+        cp.ocpp_version.map(|v| v.to_string()).into(),
+        connectors.into(),
+        Cell::bool(cp.password_hash.is_some()),
+        cp.status.to_string().into(),
+        cp.registered_at.into(),
+        cp.last_heartbeat.into(),
+    ]
+}
+
+fn tariff_row(t: Tariff) -> Vec<Cell> {
+    vec![
+        t.id.into(),
+        t.name.into(),
+        t.description.into(),
+        t.tariff_type.to_string().into(),
+        t.price_per_kwh.into(),
+        t.price_per_minute.into(),
+        t.session_fee.into(),
+        t.currency.into(),
+        t.min_fee.into(),
+        t.max_fee.into(),
+        Cell::bool(t.is_active),
+        Cell::bool(t.is_default),
+        t.valid_from.into(),
+        t.valid_until.into(),
+    ]
+}
+
+fn transaction_row(t: Transaction, billing: Option<TransactionBilling>) -> Vec<Cell> {
+    let duration = t.stopped_at.map(|stop| (stop - t.started_at).num_seconds());
+    let energy = t.energy_consumed();
+    let status = format!("{:?}", t.status);
+    let mut row: Vec<Cell> = vec![
+        t.id.into(),
+        t.charge_point_id.into(),
+        t.connector_id.into(),
+        t.id_tag.into(),
+        status.into(),
+        t.started_at.into(),
+        t.stopped_at.into(),
+        duration.into(),
+        t.meter_start.into(),
+        t.meter_stop.into(),
+        energy.into(),
+        t.stop_reason.into(),
+    ];
+    let billing: [Cell; 7] = match billing {
+        Some(b) => [
+            b.tariff_id.into(),
+            b.energy_cost.into(),
+            b.time_cost.into(),
+            b.session_fee.into(),
+            b.total_cost.into(),
+            b.currency.into(),
+            b.status.to_string().into(),
+        ],
+        None => std::array::from_fn(|_| Cell::Empty),
+    };
+    row.extend(billing);
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_match_their_columns() {
+        let now = Utc::now();
+        let mut tx = Transaction::new(7, "CP-1", 1, "TAG-1", 1_000);
+        tx.stop(2_500, Some("Local".to_string()));
+        let billing = TransactionBilling {
+            transaction_id: 7,
+            tariff_id: Some(1),
+            energy_wh: 1_500,
+            duration_seconds: 60,
+            energy_cost: 300,
+            time_cost: 0,
+            session_fee: 0,
+            total_cost: 300,
+            currency: "UZS".to_string(),
+            status: Default::default(),
+        };
+
+        let billed = transaction_row(tx.clone(), Some(billing));
+        assert_eq!(billed.len(), TRANSACTION_COLUMNS.len());
+        assert_eq!(billed[10], Cell::Number(1_500));
+        assert_eq!(billed[16], Cell::Number(300));
+        assert_eq!(transaction_row(tx, None).len(), TRANSACTION_COLUMNS.len());
+
+        assert_eq!(id_tag_row(IdTag::new("TAG-1")).len(), ID_TAG_COLUMNS.len());
+        let cp = ChargePoint::new("CP-1");
+        assert_eq!(charge_point_row(cp).len(), CHARGE_POINT_COLUMNS.len());
+        let row = id_tag_row(IdTag {
+            expiry_date: Some(now),
+            ..IdTag::new("TAG-2")
+        });
+        assert_eq!(row[5], Cell::time(now));
+    }
+}
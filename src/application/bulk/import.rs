@@ -0,0 +1,732 @@
+//! Spreadsheet import with per-row validation
+//!
+//! The whole file is validated before anything is written: on a dry run,
+//! or when any row is invalid, the report lists every row error and what
+//! would have been created or updated, and nothing changes. Existing
+//! entities are matched by their key and updated; a column left out of the
+//! file keeps the stored value, a blank cell clears an optional one.
+//!
+//! All rows of a file are written in one database transaction, so a
+//! database error leaves nothing written either. Id tags are saved parents
+//! first.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use tracing::info;
+
+use super::service::BulkService;
+use super::table::{Record, Table};
+use super::BulkEntity;
+use crate::domain::{
+    ChargePoint, DomainError, DomainResult, IdTag, IdTagStatus, Tariff, TariffType,
+};
+use crate::infrastructure::crypto::password::hash_password;
+
+/// Most data rows accepted in one file.
+pub const MAX_IMPORT_ROWS: usize = 50_000;
+
+/// Most connectors a charge point row may declare.
+const MAX_CONNECTORS: u32 = 32;
+
+/// Why a row was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowError {
+    /// Line (CSV) or row (XLSX) number in the file.
+    pub line: usize,
+    /// Offending column, when the error concerns a single value.
+    pub column: Option<String>,
+    pub message: String,
+}
+
+/// Outcome of an import.
+#[derive(Debug, Clone)]
+pub struct ImportReport {
+    pub entity: BulkEntity,
+    pub dry_run: bool,
+    /// Whether the rows were written.
+    pub applied: bool,
+    /// Data rows in the file.
+    pub rows: usize,
+    /// Rows that created a new entity, or would have.
+    pub created: usize,
+    /// Rows that updated an existing entity, or would have.
+    pub updated: usize,
+    pub errors: Vec<RowError>,
+}
+
+impl ImportReport {
+    fn new(entity: BulkEntity, table: &Table, dry_run: bool) -> Self {
+        Self {
+            entity,
+            dry_run,
+            applied: false,
+            rows: table.rows.len(),
+            created: 0,
+            updated: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Count the planned changes; returns them for writing unless this is
+    /// a dry run or some row is invalid.
+    fn validated<T>(&mut self, changes: Vec<Change<T>>) -> Option<Vec<Change<T>>> {
+        self.updated = changes.iter().filter(|c| c.exists).count();
+        self.created = changes.len() - self.updated;
+        if self.dry_run || !self.errors.is_empty() {
+            return None;
+        }
+        self.applied = true;
+        Some(changes)
+    }
+}
+
+/// A validated row, ready to be written.
+struct Change<T> {
+    line: usize,
+    item: T,
+    /// Whether it updates a stored entity rather than creating one.
+    exists: bool,
+}
+
+/// Reads the values of one record, collecting its errors.
+struct Fields<'a> {
+    record: Record<'a>,
+    errors: Vec<RowError>,
+}
+
+impl<'a> Fields<'a> {
+    fn new(record: Record<'a>) -> Self {
+        Self {
+            record,
+            errors: Vec::new(),
+        }
+    }
+
+    fn fail(&mut self, column: &str, message: impl Into<String>) {
+        self.errors.push(RowError {
+            line: self.record.line(),
+            column: Some(column.to_string()),
+            message: message.into(),
+        });
+    }
+
+    /// A value that must be present, of at most `max` characters.
+    fn required(&mut self, column: &str, max: usize) -> Option<String> {
+        match self.text(column, max) {
+            Some(Some(value)) => Some(value),
+            _ => {
+                self.fail(column, "required");
+                None
+            }
+        }
+    }
+
+    /// Text of at most `max` characters: `None` when the column is missing,
+    /// `Some(None)` when the cell is blank.
+    fn text(&mut self, column: &str, max: usize) -> Option<Option<String>> {
+        self.parse(column, |value| {
+            if value.chars().count() > max {
+                Err(format!("longer than {} characters", max))
+            } else {
+                Ok(value.to_string())
+            }
+        })
+    }
+
+    /// A parsed value: `None` when the column is missing or the value is
+    /// invalid, `Some(None)` when the cell is blank.
+    fn parse<T>(
+        &mut self,
+        column: &str,
+        parse: impl FnOnce(&str) -> Result<T, String>,
+    ) -> Option<Option<T>> {
+        if !self.record.has(column) {
+            return None;
+        }
+        let Some(value) = self.record.get(column) else {
+            return Some(None);
+        };
+        match parse(value) {
+            Ok(parsed) => Some(Some(parsed)),
+            Err(message) => {
+                self.fail(column, message);
+                None
+            }
+        }
+    }
+
+    /// Move this row's errors into the report; returns whether it was valid.
+    fn finish(self, report: &mut ImportReport) -> bool {
+        let valid = self.errors.is_empty();
+        report.errors.extend(self.errors);
+        valid
+    }
+}
+
+impl BulkService {
+    /// Import a file for `entity`.
+    ///
+    /// Fails when the file as a whole cannot be imported, or when writing
+    /// it fails, in which case nothing was written; row errors are listed
+    /// in the report.
+    pub async fn import(
+        &self,
+        entity: BulkEntity,
+        table: &Table,
+        dry_run: bool,
+    ) -> DomainResult<ImportReport> {
+        if table.rows.len() > MAX_IMPORT_ROWS {
+            return Err(DomainError::Validation(format!(
+                "at most {} rows can be imported at once",
+                MAX_IMPORT_ROWS
+            )));
+        }
+
+        let report = match entity {
+            BulkEntity::IdTags => self.import_id_tags(table, dry_run).await?,
+            BulkEntity::ChargePoints => self.import_charge_points(table, dry_run).await?,
+            BulkEntity::Tariffs => self.import_tariffs(table, dry_run).await?,
+            BulkEntity::Transactions => {
+                return Err(DomainError::Validation(format!(
+                    "{} cannot be imported",
+                    entity
+                )))
+            }
+        };
+
+        info!(
+            entity = %entity,
+            rows = report.rows,
+            created = report.created,
+            updated = report.updated,
+            errors = report.errors.len(),
+            applied = report.applied,
+            "Bulk import finished"
+        );
+        Ok(report)
+    }
+
+    async fn import_id_tags(&self, table: &Table, dry_run: bool) -> DomainResult<ImportReport> {
+        require_columns(table, &["id_tag"])?;
+        let mut report = ImportReport::new(BulkEntity::IdTags, table, dry_run);
+        let in_file: HashSet<&str> = table.records().filter_map(|r| r.get("id_tag")).collect();
+        let mut seen = HashSet::new();
+        let mut known_users = HashMap::new();
+        let mut changes = Vec::new();
+
+        for record in table.records() {
+            let mut f = Fields::new(record);
+            let Some(value) = f.required("id_tag", 20) else {
+                f.finish(&mut report);
+                continue;
+            };
+            if !seen.insert(value.clone()) {
+                f.fail("id_tag", "appears more than once in the file");
+            }
+
+            let existing = self.repos.id_tags().find(&value).await?;
+            let exists = existing.is_some();
+            let mut tag = existing.unwrap_or_else(|| IdTag::new(value.clone()));
+
+            if let Some(parent) = f.text("parent_id_tag", 20) {
+                if let Some(parent) = &parent {
+                    if *parent == value {
+                        f.fail("parent_id_tag", "a tag cannot be its own parent");
+                    } else if !in_file.contains(parent.as_str())
+                        && self.repos.id_tags().find(parent).await?.is_none()
+                    {
+                        f.fail("parent_id_tag", format!("unknown id tag '{}'", parent));
+                    }
+                }
+                tag.parent_id_tag = parent;
+            }
+            if let Some(status) = f.parse("status", parse_id_tag_status) {
+                tag.status = status.unwrap_or_default();
+            }
+            if let Some(user_id) = f.text("user_id", 255) {
+                if let Some(id) = &user_id {
+                    let known = match known_users.get(id) {
+                        Some(known) => *known,
+                        None => {
+                            let known = self.users.get_user_by_id(id).await?.is_some();
+                            known_users.insert(id.clone(), known);
+                            known
+                        }
+                    };
+                    if !known {
+                        f.fail("user_id", format!("unknown user '{}'", id));
+                    }
+                }
+                tag.user_id = user_id;
+            }
+            if let Some(name) = f.text("name", 255) {
+                tag.name = name;
+            }
+            if let Some(expiry_date) = f.parse("expiry_date", parse_time) {
+                tag.expiry_date = expiry_date;
+            }
+            if let Some(max) = f.parse("max_active_transactions", parse_count) {
+                tag.max_active_transactions = max;
+            }
+            if let Some(is_active) = f.parse("is_active", parse_bool) {
+                tag.is_active = is_active.unwrap_or(true);
+            }
+
+            if f.finish(&mut report) {
+                changes.push(Change {
+                    line: record.line(),
+                    item: tag,
+                    exists,
+                });
+            }
+        }
+
+        let changes = match parents_first(changes) {
+            Ok(changes) => changes,
+            Err(lines) => {
+                report.errors.extend(lines.into_iter().map(|line| RowError {
+                    line,
+                    column: Some("parent_id_tag".to_string()),
+                    message: "parent tags in the file form a loop".to_string(),
+                }));
+                report.errors.sort_by_key(|e| e.line);
+                Vec::new()
+            }
+        };
+        let Some(changes) = report.validated(changes) else {
+            return Ok(report);
+        };
+        self.repos
+            .id_tags()
+            .save_all(changes.into_iter().map(|c| c.item).collect())
+            .await?;
+        Ok(report)
+    }
+
+    async fn import_charge_points(
+        &self,
+        table: &Table,
+        dry_run: bool,
+    ) -> DomainResult<ImportReport> {
+        require_columns(table, &["id"])?;
+        let mut report = ImportReport::new(BulkEntity::ChargePoints, table, dry_run);
+        let mut seen = HashSet::new();
+        let mut changes = Vec::new();
+
+        for record in table.records() {
+            let mut f = Fields::new(record);
+            let Some(id) = f.required("id", 48) else {
+                f.finish(&mut report);
+                continue;
+            };
+            if id.contains(|c: char| c == '/' || c.is_whitespace()) {
+                f.fail("id", "must not contain '/' or whitespace");
+            }
+            if !seen.insert(id.clone()) {
+                f.fail("id", "appears more than once in the file");
+            }
+
+            let existing = self.repos.charge_points().find_by_id(&id).await?;
+            let exists = existing.is_some();
+            let mut cp = existing.unwrap_or_else(|| ChargePoint::new(id));
+
+            if let Some(vendor) = f.text("vendor", 20) {
+                cp.vendor = vendor;
+            }
+            if let Some(model) = f.text("model", 20) {
+                cp.model = model;
+            }
+            if let Some(serial_number) = f.text("serial_number", 25) {
+                cp.serial_number = serial_number;
+            }
+            if let Some(Some(connectors)) = f.parse("connectors", parse_count) {
+                if connectors as u32 > MAX_CONNECTORS {
+                    f.fail("connectors", format!("at most {}", MAX_CONNECTORS));
+                } else {
+                    // Only adds connectors; those the station reported are kept
+                    cp.ensure_connectors(connectors as u32);
+                }
+            }
+            // A blank password keeps the current one
+            let mut password = None;
+            if let Some(Some(value)) = f.text("password", 128) {
+                if value.chars().count() < 8 {
+                    f.fail("password", "must be at least 8 characters");
+                }
+                password = Some(value);
+            }
+
+            if f.finish(&mut report) {
+                changes.push(Change {
+                    line: record.line(),
+                    item: (cp, password),
+                    exists,
+                });
+            }
+        }
+
+        let Some(changes) = report.validated(changes) else {
+            return Ok(report);
+        };
+        let mut charge_points = Vec::with_capacity(changes.len());
+        for change in changes {
+            let (mut cp, password) = change.item;
+            if let Some(password) = password {
+                cp.password_hash = Some(hash_in_background(password).await?);
+            }
+            charge_points.push(cp);
+        }
+        self.repos.charge_points().save_all(charge_points).await?;
+        Ok(report)
+    }
+
+    async fn import_tariffs(&self, table: &Table, dry_run: bool) -> DomainResult<ImportReport> {
+        require_columns(table, &["name"])?;
+        let mut report = ImportReport::new(BulkEntity::Tariffs, table, dry_run);
+        let stored = self.repos.tariffs().find_all().await?;
+        let by_name: HashMap<&str, &Tariff> = stored.iter().map(|t| (t.name.as_str(), t)).collect();
+        let mut seen = HashSet::new();
+        let mut default_line = None;
+        let mut changes = Vec::new();
+
+        for record in table.records() {
+            let mut f = Fields::new(record);
+            let id = f.parse("id", parse_count).flatten();
+            let Some(name) = f.required("name", 100) else {
+                f.finish(&mut report);
+                continue;
+            };
+            if !seen.insert(name.clone()) {
+                f.fail("name", "appears more than once in the file");
+            }
+
+            // Matched by id when given, otherwise by name
+            let existing = match id {
+                Some(id) => {
+                    let found = stored.iter().find(|t| t.id == id);
+                    if found.is_none() {
+                        f.fail("id", format!("unknown tariff {}", id));
+                    }
+                    found
+                }
+                None => by_name.get(name.as_str()).copied(),
+            };
+            let exists = existing.is_some();
+            let mut tariff = existing.cloned().unwrap_or_else(|| new_tariff(&name));
+            tariff.name = name;
+
+            if let Some(description) = f.text("description", 500) {
+                tariff.description = description;
+            }
+            if let Some(Some(tariff_type)) = f.parse("tariff_type", parse_tariff_type) {
+                tariff.tariff_type = tariff_type;
+            }
+            for (column, amount) in [
+                ("price_per_kwh", &mut tariff.price_per_kwh),
+                ("price_per_minute", &mut tariff.price_per_minute),
+                ("session_fee", &mut tariff.session_fee),
+                ("min_fee", &mut tariff.min_fee),
+                ("max_fee", &mut tariff.max_fee),
+            ] {
+                if let Some(value) = f.parse(column, parse_count) {
+                    *amount = value.unwrap_or(0);
+                }
+            }
+            match f.parse("currency", parse_currency) {
+                Some(Some(currency)) => tariff.currency = currency,
+                _ if !exists && tariff.currency.is_empty() => {
+                    f.fail("currency", "required for a new tariff")
+                }
+                _ => {}
+            }
+            if let Some(is_active) = f.parse("is_active", parse_bool) {
+                tariff.is_active = is_active.unwrap_or(true);
+            }
+            if let Some(is_default) = f.parse("is_default", parse_bool) {
+                tariff.is_default = is_default.unwrap_or(false);
+            }
+            if tariff.is_default && f.record.has("is_default") {
+                if let Some(line) = default_line {
+                    f.fail(
+                        "is_default",
+                        format!("line {} already sets the default tariff", line),
+                    );
+                }
+                default_line = Some(record.line());
+            }
+            if let Some(valid_from) = f.parse("valid_from", parse_time) {
+                tariff.valid_from = valid_from;
+            }
+            if let Some(valid_until) = f.parse("valid_until", parse_time) {
+                tariff.valid_until = valid_until;
+            }
+            if let (Some(from), Some(until)) = (tariff.valid_from, tariff.valid_until) {
+                if until <= from {
+                    f.fail("valid_until", "must be after valid_from");
+                }
+            }
+
+            if f.finish(&mut report) {
+                changes.push(Change {
+                    line: record.line(),
+                    item: tariff,
+                    exists,
+                });
+            }
+        }
+
+        let Some(changes) = report.validated(changes) else {
+            return Ok(report);
+        };
+        self.repos
+            .tariffs()
+            .save_all(changes.into_iter().map(|c| c.item).collect())
+            .await?;
+        Ok(report)
+    }
+}
+
+/// Hash a charge point password; bcrypt is deliberately slow, so this runs
+/// off the async workers.
+async fn hash_in_background(password: String) -> DomainResult<String> {
+    tokio::task::spawn_blocking(move || hash_password(&password))
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|hashed| hashed.map_err(|e| e.to_string()))
+        .map_err(|e| DomainError::Validation(format!("Failed to hash password: {}", e)))
+}
+
+fn require_columns(table: &Table, columns: &[&str]) -> DomainResult<()> {
+    let missing: Vec<&str> = columns
+        .iter()
+        .copied()
+        .filter(|c| !table.has_column(c))
+        .collect();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(DomainError::Validation(format!(
+            "missing column(s): {}",
+            missing.join(", ")
+        )))
+    }
+}
+
+/// Order tags so that a parent from the file is saved before its children.
+/// Fails with the lines of tags whose parents within the file form a loop.
+fn parents_first(changes: Vec<Change<IdTag>>) -> Result<Vec<Change<IdTag>>, Vec<usize>> {
+    let mut depths: Vec<Option<usize>> = vec![None; changes.len()];
+    let mut looped = Vec::new();
+    {
+        let index: HashMap<&str, usize> = changes
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.item.id_tag.as_str(), i))
+            .collect();
+        let parent = |i: usize| {
+            changes[i]
+                .item
+                .parent_id_tag
+                .as_deref()
+                .and_then(|p| index.get(p).copied())
+        };
+
+        for start in 0..changes.len() {
+            // Walk up to a tag of known depth or a parent outside the file
+            let mut path: Vec<usize> = Vec::new();
+            let mut on_path = HashSet::new();
+            let mut at = Some(start);
+            let mut above = 0;
+            while let Some(i) = at {
+                if let Some(depth) = depths[i] {
+                    above = depth.saturating_add(1);
+                    break;
+                }
+                if !on_path.insert(i) {
+                    let from = path.iter().position(|&p| p == i).unwrap_or(0);
+                    looped.extend(path[from..].iter().map(|&p| changes[p].line));
+                    above = usize::MAX;
+                    break;
+                }
+                path.push(i);
+                at = parent(i);
+            }
+            for (n, i) in path.into_iter().rev().enumerate() {
+                depths[i] = Some(above.saturating_add(n));
+            }
+        }
+    }
+
+    if !looped.is_empty() {
+        looped.sort_unstable();
+        return Err(looped);
+    }
+    let mut ordered: Vec<_> = depths.into_iter().zip(changes).collect();
+    ordered.sort_by_key(|(depth, _)| *depth);
+    Ok(ordered.into_iter().map(|(_, change)| change).collect())
+}
+
+fn new_tariff(name: &str) -> Tariff {
+    let now = Utc::now();
+    Tariff {
+        id: 0,
+        name: name.to_string(),
+        description: None,
+        tariff_type: TariffType::PerKwh,
+        price_per_kwh: 0,
+        price_per_minute: 0,
+        session_fee: 0,
+        currency: String::new(),
+        min_fee: 0,
+        max_fee: 0,
+        is_active: true,
+        is_default: false,
+        valid_from: None,
+        valid_until: None,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+// ── Value parsers ───────────────────────────────────────────────
+
+fn parse_bool(value: &str) -> Result<bool, String> {
+    match value.to_lowercase().as_str() {
+        "true" | "yes" | "y" | "1" => Ok(true),
+        "false" | "no" | "n" | "0" => Ok(false),
+        _ => Err(format!("'{}' is not true or false", value)),
+    }
+}
+
+/// A non-negative whole number.
+fn parse_count(value: &str) -> Result<i32, String> {
+    value
+        .parse::<i32>()
+        .ok()
+        .filter(|n| *n >= 0)
+        .ok_or_else(|| format!("'{}' is not a non-negative whole number", value))
+}
+
+/// RFC 3339, `YYYY-MM-DD HH:MM:SS` or `YYYY-MM-DD`; times without an offset are UTC.
+fn parse_time(value: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    if let Ok(at) = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
+        return Ok(at.and_utc());
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc());
+    }
+    Err(format!("'{}' is not a date or timestamp", value))
+}
+
+fn parse_id_tag_status(value: &str) -> Result<IdTagStatus, String> {
+    let status = IdTagStatus::from(value);
+    // Anything unrecognised maps to Invalid
+    if status == IdTagStatus::Invalid && !value.eq_ignore_ascii_case("invalid") {
+        return Err(format!("unknown status '{}'", value));
+    }
+    Ok(status)
+}
+
+fn parse_tariff_type(value: &str) -> Result<TariffType, String> {
+    [
+        TariffType::PerKwh,
+        TariffType::PerMinute,
+        TariffType::PerSession,
+        TariffType::Combined,
+    ]
+    .into_iter()
+    .find(|t| t.to_string().eq_ignore_ascii_case(value))
+    .ok_or_else(|| format!("unknown tariff type '{}'", value))
+}
+
+fn parse_currency(value: &str) -> Result<String, String> {
+    if value.len() == 3 && value.chars().all(|c| c.is_ascii_alphabetic()) {
+        Ok(value.to_ascii_uppercase())
+    } else {
+        Err(format!("'{}' is not an ISO 4217 currency code", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_spreadsheet_values() {
+        assert_eq!(parse_bool("Yes"), Ok(true));
+        assert_eq!(parse_bool("0"), Ok(false));
+        assert!(parse_bool("maybe").is_err());
+
+        assert_eq!(parse_count("12"), Ok(12));
+        assert!(parse_count("-1").is_err());
+        assert!(parse_count("1.5").is_err());
+
+        let midnight = "2025-03-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(parse_time("2025-03-01"), Ok(midnight));
+        assert_eq!(parse_time("2025-03-01 00:00:00"), Ok(midnight));
+        assert_eq!(parse_time("2025-03-01T05:00:00+05:00"), Ok(midnight));
+        assert!(parse_time("01/03/2025").is_err());
+
+        assert_eq!(parse_id_tag_status("blocked"), Ok(IdTagStatus::Blocked));
+        assert_eq!(parse_id_tag_status("Invalid"), Ok(IdTagStatus::Invalid));
+        assert!(parse_id_tag_status("lost").is_err());
+        assert_eq!(parse_tariff_type("perkwh"), Ok(TariffType::PerKwh));
+        assert_eq!(parse_currency("uzs"), Ok("UZS".to_string()));
+        assert!(parse_currency("SUM1").is_err());
+    }
+
+    #[test]
+    fn fields_tell_missing_columns_from_blank_cells() {
+        let mut table = Table::new(["id_tag", "name", "is_active"]);
+        table.push(2, vec!["TAG-1".into(), String::new(), "nope".into()]);
+        let record = table.records().next().unwrap();
+
+        let mut f = Fields::new(record);
+        assert_eq!(f.text("name", 10), Some(None));
+        assert_eq!(f.text("parent_id_tag", 10), None);
+        assert_eq!(f.text("id_tag", 3), None, "too long");
+        assert_eq!(f.parse("is_active", parse_bool), None);
+
+        let columns: Vec<_> = f.errors.iter().map(|e| e.column.as_deref()).collect();
+        assert_eq!(columns, [Some("id_tag"), Some("is_active")]);
+        assert!(f.errors.iter().all(|e| e.line == 2));
+    }
+
+    fn tag_change(line: usize, id_tag: &str, parent: Option<&str>) -> Change<IdTag> {
+        let mut item = IdTag::new(id_tag.to_string());
+        item.parent_id_tag = parent.map(str::to_string);
+        Change {
+            line,
+            item,
+            exists: false,
+        }
+    }
+
+    #[test]
+    fn parents_are_saved_before_children() {
+        let changes = vec![
+            tag_change(2, "CHILD", Some("MIDDLE")),
+            tag_change(3, "MIDDLE", Some("ROOT")),
+            tag_change(4, "ROOT", Some("STORED")),
+            tag_change(5, "OTHER", None),
+        ];
+        let order: Vec<_> = parents_first(changes)
+            .ok()
+            .unwrap()
+            .into_iter()
+            .map(|c| c.line)
+            .collect();
+        assert_eq!(order, [4, 5, 3, 2]);
+
+        let changes = vec![
+            tag_change(2, "A", Some("B")),
+            tag_change(3, "B", Some("A")),
+            tag_change(4, "C", Some("A")),
+        ];
+        assert_eq!(parents_first(changes).err(), Some(vec![2, 3]));
+    }
+}
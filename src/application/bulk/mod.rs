@@ -0,0 +1,59 @@
+//! Bulk module — spreadsheet import and export
+//!
+//! Imports id tags, charge points (with their WebSocket passwords) and
+//! tariffs from CSV / XLSX files, and exports the same entities plus
+//! transactions with their billing. The file formats themselves are
+//! handled in `infrastructure::tabular`; this module works on [`Table`]s.
+
+pub mod export;
+pub mod import;
+pub mod service;
+pub mod table;
+
+pub use export::{ExportFilter, ExportPage, EXPORT_PAGE_SIZE};
+pub use import::{ImportReport, RowError, MAX_IMPORT_ROWS};
+pub use service::BulkService;
+pub use table::{Cell, Record, Row, Table};
+
+/// Entity kinds handled by the bulk endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BulkEntity {
+    IdTags,
+    ChargePoints,
+    Tariffs,
+    Transactions,
+}
+
+impl BulkEntity {
+    pub const ALL: [BulkEntity; 4] = [
+        BulkEntity::IdTags,
+        BulkEntity::ChargePoints,
+        BulkEntity::Tariffs,
+        BulkEntity::Transactions,
+    ];
+
+    /// Name used in URLs, file names and sheet names.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::IdTags => "id-tags",
+            Self::ChargePoints => "charge-points",
+            Self::Tariffs => "tariffs",
+            Self::Transactions => "transactions",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|e| e.as_str() == s)
+    }
+
+    /// Transactions are recorded by the stations and can only be exported.
+    pub fn is_importable(&self) -> bool {
+        !matches!(self, Self::Transactions)
+    }
+}
+
+impl std::fmt::Display for BulkEntity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,20 @@
+//! Bulk service — entry point for spreadsheet imports and exports
+
+use std::sync::Arc;
+
+use crate::domain::{RepositoryProvider, UserRepositoryInterface};
+
+/// Imports and exports entities in bulk; see `import` and `export`.
+pub struct BulkService {
+    pub(super) repos: Arc<dyn RepositoryProvider>,
+    pub(super) users: Arc<dyn UserRepositoryInterface>,
+}
+
+impl BulkService {
+    pub fn new(
+        repos: Arc<dyn RepositoryProvider>,
+        users: Arc<dyn UserRepositoryInterface>,
+    ) -> Self {
+        Self { repos, users }
+    }
+}
@@ -0,0 +1,167 @@
+//! Format-neutral tables read from and written to CSV / XLSX files
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+/// An imported file: its header and data rows, values as trimmed text.
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    /// Column names, lower-cased.
+    pub headers: Vec<String>,
+    pub rows: Vec<Row>,
+}
+
+/// A data row of an imported file.
+#[derive(Debug, Clone)]
+pub struct Row {
+    /// 1-based line (CSV) or row (XLSX) number in the file, header included.
+    pub line: usize,
+    pub values: Vec<String>,
+}
+
+impl Table {
+    pub fn new<S: AsRef<str>>(headers: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            headers: headers
+                .into_iter()
+                .map(|h| h.as_ref().trim().to_lowercase())
+                .collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Append a row; rows with nothing but blanks are skipped.
+    pub fn push(&mut self, line: usize, values: Vec<String>) {
+        let values: Vec<String> = values.into_iter().map(|v| v.trim().to_string()).collect();
+        if values.iter().any(|v| !v.is_empty()) {
+            self.rows.push(Row { line, values });
+        }
+    }
+
+    pub fn has_column(&self, column: &str) -> bool {
+        self.headers.iter().any(|h| h == column)
+    }
+
+    pub fn records(&self) -> impl Iterator<Item = Record<'_>> {
+        self.rows.iter().map(move |row| Record { table: self, row })
+    }
+}
+
+/// A data row with access to its values by column name.
+#[derive(Debug, Clone, Copy)]
+pub struct Record<'a> {
+    table: &'a Table,
+    row: &'a Row,
+}
+
+impl<'a> Record<'a> {
+    pub fn line(&self) -> usize {
+        self.row.line
+    }
+
+    /// Whether the file has the column at all, blank or not.
+    pub fn has(&self, column: &str) -> bool {
+        self.table.has_column(column)
+    }
+
+    /// The value in `column`; `None` when the column is missing or the cell blank.
+    pub fn get(&self, column: &str) -> Option<&'a str> {
+        let index = self.table.headers.iter().position(|h| h == column)?;
+        self.row
+            .values
+            .get(index)
+            .map(String::as_str)
+            .filter(|v| !v.is_empty())
+    }
+}
+
+/// A value written to an export.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Empty,
+    Text(String),
+    Number(i64),
+}
+
+impl Cell {
+    pub fn time(at: DateTime<Utc>) -> Self {
+        Self::Text(at.to_rfc3339_opts(SecondsFormat::Secs, true))
+    }
+
+    pub fn bool(value: bool) -> Self {
+        Self::Text(value.to_string())
+    }
+
+    /// The value as it appears in a CSV file.
+    pub fn to_text(&self) -> String {
+        match self {
+            Self::Empty => String::new(),
+            Self::Text(s) => s.clone(),
+            Self::Number(n) => n.to_string(),
+        }
+    }
+}
+
+impl From<String> for Cell {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_string())
+    }
+}
+
+impl From<i64> for Cell {
+    fn from(n: i64) -> Self {
+        Self::Number(n)
+    }
+}
+
+impl From<i32> for Cell {
+    fn from(n: i32) -> Self {
+        Self::Number(n.into())
+    }
+}
+
+impl From<u32> for Cell {
+    fn from(n: u32) -> Self {
+        Self::Number(n.into())
+    }
+}
+
+impl From<DateTime<Utc>> for Cell {
+    fn from(at: DateTime<Utc>) -> Self {
+        Self::time(at)
+    }
+}
+
+impl<T: Into<Cell>> From<Option<T>> for Cell {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Self::Empty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_read_values_by_column_name() {
+        let mut table = Table::new([" ID_Tag ", "Name"]);
+        table.push(2, vec!["TAG-1".into(), " Alice ".into()]);
+        table.push(3, vec![" ".into(), String::new()]);
+        table.push(4, vec!["TAG-2".into()]);
+
+        assert_eq!(table.headers, ["id_tag", "name"]);
+        let records: Vec<_> = table.records().collect();
+        assert_eq!(records.len(), 2, "blank row skipped");
+        assert_eq!(records[0].get("name"), Some("Alice"));
+        assert_eq!(records[1].line(), 4);
+        assert_eq!(records[1].get("name"), None, "short row");
+        assert!(records[1].has("name"));
+        assert_eq!(records[1].get("status"), None);
+        assert!(!records[1].has("status"));
+    }
+}
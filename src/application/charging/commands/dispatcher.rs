@@ -20,12 +20,20 @@ pub use super::{
 };
 pub use v201::clear_charging_profile::ClearChargingProfileCriteria;
 pub use v201::clear_variable_monitoring::ClearVariableMonitoringResult;
+pub use v201::customer_information::{
+    CustomerCertificate, CustomerIdentifier, CustomerInformationResult,
+};
 pub use v201::get_charging_profiles::{GetChargingProfilesCriteria, GetChargingProfilesResult};
+pub use v201::get_display_messages::{GetDisplayMessagesCriteria, GetDisplayMessagesResult};
 pub use v201::get_log::GetLogResult;
+pub use v201::get_monitoring_report::GetMonitoringReportResult;
+pub use v201::get_report::{ComponentVariableSelector, GetReportResult};
 pub use v201::get_base_report::GetBaseReportResult;
 pub use v201::get_transaction_status::GetTransactionStatusResult;
 pub use v201::get_variables::GetVariablesResult;
+pub use v201::set_display_message::{DisplayMessageInfo, SetDisplayMessageResult};
 pub use v201::set_monitoring_base::SetMonitoringBaseResult;
+pub use v201::set_monitoring_level::SetMonitoringLevelResult;
 pub use v201::set_variable_monitoring::{MonitorDescriptor, SetVariableMonitoringResult};
 pub use v201::set_variables::SetVariablesResult;
 
@@ -898,6 +906,40 @@ impl CommandDispatcher {
         result
     }
 
+    // ─── GetReport (v2.0.1 only) ──────────────────────────────────────
+
+    /// Request a report filtered by component criteria and/or
+    /// component-variable pairs from a v2.0.1 charge point.
+    pub async fn get_report(
+        &self,
+        charge_point_id: &str,
+        request_id: i32,
+        component_criteria: &[String],
+        component_variables: &[ComponentVariableSelector],
+    ) -> Result<GetReportResult, CommandError> {
+        let version = self.resolve_version(charge_point_id)?;
+        let start = std::time::Instant::now();
+        info!(%version, "Dispatching GetReport");
+
+        let result = match version {
+            OcppVersion::V16 => Err(CommandError::UnsupportedVersion(
+                "GetReport is not supported for OCPP 1.6".to_string(),
+            )),
+            OcppVersion::V201 | OcppVersion::V21 => {
+                v201::get_report::get_report(
+                    &self.command_sender,
+                    charge_point_id,
+                    request_id,
+                    component_criteria,
+                    component_variables,
+                )
+                .await
+            }
+        };
+        record_command_latency("get_report", start);
+        result
+    }
+
     // ─── SetVariableMonitoring (v2.0.1 only) ──────────────────────────
 
     /// Configure variable monitors on a v2.0.1 charge point.
@@ -956,6 +998,68 @@ impl CommandDispatcher {
         result
     }
 
+    // ─── SetMonitoringLevel (v2.0.1 only) ─────────────────────────────
+
+    /// Set the severity level up to which a v2.0.1 charge point reports events.
+    pub async fn set_monitoring_level(
+        &self,
+        charge_point_id: &str,
+        severity: u8,
+    ) -> Result<SetMonitoringLevelResult, CommandError> {
+        let version = self.resolve_version(charge_point_id)?;
+        let start = std::time::Instant::now();
+        info!(%version, "Dispatching SetMonitoringLevel");
+
+        let result = match version {
+            OcppVersion::V16 => Err(CommandError::UnsupportedVersion(
+                "SetMonitoringLevel is not supported for OCPP 1.6".to_string(),
+            )),
+            OcppVersion::V201 | OcppVersion::V21 => {
+                v201::set_monitoring_level::set_monitoring_level(
+                    &self.command_sender,
+                    charge_point_id,
+                    severity,
+                )
+                .await
+            }
+        };
+        record_command_latency("set_monitoring_level", start);
+        result
+    }
+
+    // ─── GetMonitoringReport (v2.0.1 only) ────────────────────────────
+
+    /// Request a report of the monitors configured on a v2.0.1 charge point.
+    pub async fn get_monitoring_report(
+        &self,
+        charge_point_id: &str,
+        request_id: i32,
+        monitoring_criteria: &[String],
+        component_variables: &[ComponentVariableSelector],
+    ) -> Result<GetMonitoringReportResult, CommandError> {
+        let version = self.resolve_version(charge_point_id)?;
+        let start = std::time::Instant::now();
+        info!(%version, "Dispatching GetMonitoringReport");
+
+        let result = match version {
+            OcppVersion::V16 => Err(CommandError::UnsupportedVersion(
+                "GetMonitoringReport is not supported for OCPP 1.6".to_string(),
+            )),
+            OcppVersion::V201 | OcppVersion::V21 => {
+                v201::get_monitoring_report::get_monitoring_report(
+                    &self.command_sender,
+                    charge_point_id,
+                    request_id,
+                    monitoring_criteria,
+                    component_variables,
+                )
+                .await
+            }
+        };
+        record_command_latency("get_monitoring_report", start);
+        result
+    }
+
     // ─── ClearVariableMonitoring (v2.0.1 only) ────────────────────────
 
     /// Clear variable monitors on a v2.0.1 charge point.
@@ -1049,6 +1153,135 @@ impl CommandDispatcher {
         record_command_latency("get_transaction_status", start);
         result
     }
+
+    // ─── Display Messages (v2.0.1 only) ───────────────────────────────
+
+    /// SetDisplayMessage — install a message on the station display.
+    ///
+    /// v1.6 does not support this action; returns `UnsupportedVersion`.
+    pub async fn set_display_message(
+        &self,
+        charge_point_id: &str,
+        message: DisplayMessageInfo,
+    ) -> Result<SetDisplayMessageResult, CommandError> {
+        let version = self.resolve_version(charge_point_id)?;
+        let start = std::time::Instant::now();
+        info!(%version, "Dispatching SetDisplayMessage");
+
+        let result = match version {
+            OcppVersion::V16 => Err(CommandError::UnsupportedVersion(
+                "SetDisplayMessage is not supported for OCPP 1.6".to_string(),
+            )),
+            OcppVersion::V201 | OcppVersion::V21 => {
+                v201::set_display_message::set_display_message(
+                    &self.command_sender,
+                    charge_point_id,
+                    message,
+                )
+                .await
+            }
+        };
+        record_command_latency("set_display_message", start);
+        result
+    }
+
+    /// GetDisplayMessages — ask the station to report its messages via
+    /// NotifyDisplayMessages.
+    ///
+    /// v1.6 does not support this action; returns `UnsupportedVersion`.
+    pub async fn get_display_messages(
+        &self,
+        charge_point_id: &str,
+        request_id: i32,
+        criteria: GetDisplayMessagesCriteria,
+    ) -> Result<GetDisplayMessagesResult, CommandError> {
+        let version = self.resolve_version(charge_point_id)?;
+        let start = std::time::Instant::now();
+        info!(%version, "Dispatching GetDisplayMessages");
+
+        let result = match version {
+            OcppVersion::V16 => Err(CommandError::UnsupportedVersion(
+                "GetDisplayMessages is not supported for OCPP 1.6".to_string(),
+            )),
+            OcppVersion::V201 | OcppVersion::V21 => {
+                v201::get_display_messages::get_display_messages(
+                    &self.command_sender,
+                    charge_point_id,
+                    request_id,
+                    criteria,
+                )
+                .await
+            }
+        };
+        record_command_latency("get_display_messages", start);
+        result
+    }
+
+    /// ClearDisplayMessage — remove a message from the station display.
+    ///
+    /// v1.6 does not support this action; returns `UnsupportedVersion`.
+    pub async fn clear_display_message(
+        &self,
+        charge_point_id: &str,
+        message_id: i32,
+    ) -> Result<String, CommandError> {
+        let version = self.resolve_version(charge_point_id)?;
+        let start = std::time::Instant::now();
+        info!(%version, "Dispatching ClearDisplayMessage");
+
+        let result = match version {
+            OcppVersion::V16 => Err(CommandError::UnsupportedVersion(
+                "ClearDisplayMessage is not supported for OCPP 1.6".to_string(),
+            )),
+            OcppVersion::V201 | OcppVersion::V21 => {
+                v201::clear_display_message::clear_display_message(
+                    &self.command_sender,
+                    charge_point_id,
+                    message_id,
+                )
+                .await
+            }
+        };
+        record_command_latency("clear_display_message", start);
+        result
+    }
+
+    /// CustomerInformation — ask the station to report and/or clear the
+    /// data it holds about a customer. Reports arrive asynchronously via
+    /// NotifyCustomerInformation.
+    ///
+    /// v1.6 does not support this action; returns `UnsupportedVersion`.
+    pub async fn customer_information(
+        &self,
+        charge_point_id: &str,
+        request_id: i32,
+        report: bool,
+        clear: bool,
+        customer: &CustomerIdentifier,
+    ) -> Result<CustomerInformationResult, CommandError> {
+        let version = self.resolve_version(charge_point_id)?;
+        let start = std::time::Instant::now();
+        info!(%version, "Dispatching CustomerInformation");
+
+        let result = match version {
+            OcppVersion::V16 => Err(CommandError::UnsupportedVersion(
+                "CustomerInformation is not supported for OCPP 1.6".to_string(),
+            )),
+            OcppVersion::V201 | OcppVersion::V21 => {
+                v201::customer_information::customer_information(
+                    &self.command_sender,
+                    charge_point_id,
+                    request_id,
+                    report,
+                    clear,
+                    customer,
+                )
+                .await
+            }
+        };
+        record_command_latency("customer_information", start);
+        result
+    }
 }
 
 pub type SharedCommandDispatcher = Arc<CommandDispatcher>;
@@ -1302,6 +1535,23 @@ impl OcppOutboundPort for CommandDispatcher {
             .await
     }
 
+    async fn get_report(
+        &self,
+        charge_point_id: &str,
+        request_id: i32,
+        component_criteria: &[String],
+        component_variables: &[ComponentVariableSelector],
+    ) -> Result<GetReportResult, CommandError> {
+        CommandDispatcher::get_report(
+            self,
+            charge_point_id,
+            request_id,
+            component_criteria,
+            component_variables,
+        )
+        .await
+    }
+
     async fn set_variable_monitoring(
         &self,
         charge_point_id: &str,
@@ -1320,6 +1570,31 @@ impl OcppOutboundPort for CommandDispatcher {
             .await
     }
 
+    async fn set_monitoring_level(
+        &self,
+        charge_point_id: &str,
+        severity: u8,
+    ) -> Result<SetMonitoringLevelResult, CommandError> {
+        CommandDispatcher::set_monitoring_level(self, charge_point_id, severity).await
+    }
+
+    async fn get_monitoring_report(
+        &self,
+        charge_point_id: &str,
+        request_id: i32,
+        monitoring_criteria: &[String],
+        component_variables: &[ComponentVariableSelector],
+    ) -> Result<GetMonitoringReportResult, CommandError> {
+        CommandDispatcher::get_monitoring_report(
+            self,
+            charge_point_id,
+            request_id,
+            monitoring_criteria,
+            component_variables,
+        )
+        .await
+    }
+
     async fn clear_variable_monitoring(
         &self,
         charge_point_id: &str,
@@ -23,16 +23,19 @@ pub mod v16;
 pub mod v201;
 
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::time::Duration;
 
 use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tokio::sync::oneshot;
 use tokio::time::timeout;
 use tracing::{info, warn};
 
+use super::schema::SharedSchemaValidator;
 use super::session::SharedSessionRegistry;
+use crate::application::cluster::SharedClusterNode;
 use crate::shared::ocpp_frame::OcppFrame;
 
 // ── Common types used by both v16 and v201 implementations ─────────
@@ -126,7 +129,7 @@ struct PendingRequest {
     response_sender: oneshot::Sender<Result<Value, CommandError>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CommandError {
     NotConnected(String),
     SendFailed(String),
@@ -135,6 +138,8 @@ pub enum CommandError {
     CallError { code: String, description: String },
     /// The command is not supported by the charge point's OCPP version.
     UnsupportedVersion(String),
+    /// The outgoing payload failed schema validation (strict mode).
+    InvalidPayload(String),
 }
 
 impl std::fmt::Display for CommandError {
@@ -148,6 +153,7 @@ impl std::fmt::Display for CommandError {
                 write!(f, "CallError {}: {}", code, description)
             }
             Self::UnsupportedVersion(msg) => write!(f, "Unsupported version: {}", msg),
+            Self::InvalidPayload(msg) => write!(f, "Invalid payload: {}", msg),
         }
     }
 }
@@ -155,18 +161,35 @@ impl std::fmt::Display for CommandError {
 impl std::error::Error for CommandError {}
 
 /// Command sender for sending OCPP commands to charge points
+///
+/// In cluster mode, commands for charge points connected to another node
+/// are relayed to that node.
 pub struct CommandSender {
     session_registry: SharedSessionRegistry,
+    schema_validator: SharedSchemaValidator,
     pending_requests: DashMap<(String, String), PendingRequest>,
     message_counter: AtomicU64,
+    cluster: OnceLock<SharedClusterNode>,
 }
 
 impl CommandSender {
-    pub fn new(session_registry: SharedSessionRegistry) -> Self {
+    pub fn new(
+        session_registry: SharedSessionRegistry,
+        schema_validator: SharedSchemaValidator,
+    ) -> Self {
         Self {
             session_registry,
+            schema_validator,
             pending_requests: DashMap::new(),
             message_counter: AtomicU64::new(1),
+            cluster: OnceLock::new(),
+        }
+    }
+
+    /// Relay commands for charge points held by other nodes through `cluster`.
+    pub fn attach_cluster(&self, cluster: SharedClusterNode) {
+        if self.cluster.set(cluster).is_err() {
+            warn!("Command sender already attached to a cluster — ignoring");
         }
     }
 
@@ -186,6 +209,40 @@ impl CommandSender {
         charge_point_id: &str,
         action: &str,
         payload: Value,
+    ) -> Result<Value, CommandError> {
+        if let Some(version) = self.session_registry.get_version(charge_point_id) {
+            if let Err(violation) = self
+                .schema_validator
+                .check_outbound(version, action, &payload)
+            {
+                warn!(
+                    charge_point_id,
+                    action,
+                    error_code = violation.error_code,
+                    "Refusing to send command: {}", violation.description
+                );
+                return Err(CommandError::InvalidPayload(violation.to_string()));
+            }
+        }
+
+        if let Some(cluster) = self.cluster.get() {
+            if let Some(owner) = self.session_registry.remote_owner(charge_point_id) {
+                return cluster
+                    .relay_command(&owner, charge_point_id, action, payload)
+                    .await;
+            }
+        }
+
+        self.send_local(charge_point_id, action, payload).await
+    }
+
+    /// Send a command over this node's own WebSocket to the charge point,
+    /// without schema validation or cluster relay.
+    pub async fn send_local(
+        &self,
+        charge_point_id: &str,
+        action: &str,
+        payload: Value,
     ) -> Result<Value, CommandError> {
         let message_id = self.generate_message_id();
 
@@ -238,6 +295,14 @@ impl CommandSender {
         }
     }
 
+    /// Action of the command still awaiting a response under `message_id`.
+    pub fn pending_action(&self, charge_point_id: &str, message_id: &str) -> Option<String> {
+        let key = (charge_point_id.to_string(), message_id.to_string());
+        self.pending_requests
+            .get(&key)
+            .map(|pending| pending.action_name.clone())
+    }
+
     pub fn handle_response(&self, charge_point_id: &str, message_id: &str, payload: Value) {
         let key = (charge_point_id.to_string(), message_id.to_string());
         if let Some((_, pending)) = self.pending_requests.remove(&key) {
@@ -285,6 +350,9 @@ impl CommandSender {
 
 pub type SharedCommandSender = Arc<CommandSender>;
 
-pub fn create_command_sender(session_registry: SharedSessionRegistry) -> SharedCommandSender {
-    Arc::new(CommandSender::new(session_registry))
+pub fn create_command_sender(
+    session_registry: SharedSessionRegistry,
+    schema_validator: SharedSchemaValidator,
+) -> SharedCommandSender {
+    Arc::new(CommandSender::new(session_registry, schema_validator))
 }
@@ -0,0 +1,32 @@
+//! v2.0.1 ClearDisplayMessage command
+
+use rust_ocpp::v2_0_1::messages::clear_display_message::{
+    ClearDisplayMessageRequest, ClearDisplayMessageResponse,
+};
+use tracing::info;
+
+use crate::application::charging::commands::{CommandError, SharedCommandSender};
+
+/// Remove a display message from a v2.0.1 charging station.
+///
+/// Returns `Accepted` or `Unknown` (no message with that id).
+pub async fn clear_display_message(
+    command_sender: &SharedCommandSender,
+    charge_point_id: &str,
+    message_id: i32,
+) -> Result<String, CommandError> {
+    info!(charge_point_id, message_id, "v2.0.1 ClearDisplayMessage");
+
+    let request = ClearDisplayMessageRequest { id: message_id };
+    let payload = serde_json::to_value(&request)
+        .map_err(|e| CommandError::SendFailed(format!("Serialization failed: {}", e)))?;
+
+    let result = command_sender
+        .send_command(charge_point_id, "ClearDisplayMessage", payload)
+        .await?;
+
+    let response: ClearDisplayMessageResponse = serde_json::from_value(result)
+        .map_err(|e| CommandError::InvalidResponse(format!("Failed to parse response: {}", e)))?;
+
+    Ok(format!("{:?}", response.status))
+}
@@ -0,0 +1,107 @@
+//! v2.0.1 CustomerInformation command
+
+use rust_ocpp::v2_0_1::datatypes::certificate_hash_data_type::CertificateHashDataType;
+use rust_ocpp::v2_0_1::datatypes::id_token_type::IdTokenType;
+use rust_ocpp::v2_0_1::enumerations::hash_algorithm_enum_type::HashAlgorithmEnumType;
+use rust_ocpp::v2_0_1::enumerations::id_token_enum_type::IdTokenEnumType;
+use rust_ocpp::v2_0_1::messages::customer_information::{
+    CustomerInformationRequest, CustomerInformationResponse,
+};
+use tracing::info;
+
+use crate::application::charging::commands::{CommandError, SharedCommandSender};
+
+/// Certificate hash identifying a customer (ISO 15118 contract certificate).
+#[derive(Debug, Clone)]
+pub struct CustomerCertificate {
+    /// SHA256, SHA384 or SHA512.
+    pub hash_algorithm: String,
+    pub issuer_name_hash: String,
+    pub issuer_key_hash: String,
+    pub serial_number: String,
+}
+
+/// Identifies the customer a CustomerInformation request refers to.
+///
+/// At least one identifier should be set.
+#[derive(Debug, Clone, Default)]
+pub struct CustomerIdentifier {
+    /// Vendor-specific customer identifier.
+    pub customer_identifier: Option<String>,
+    /// Id token (RFID uid / central token) of the customer.
+    pub id_token: Option<String>,
+    /// Contract certificate of the customer.
+    pub certificate: Option<CustomerCertificate>,
+}
+
+impl CustomerIdentifier {
+    pub fn is_empty(&self) -> bool {
+        self.customer_identifier.is_none() && self.id_token.is_none() && self.certificate.is_none()
+    }
+}
+
+/// Result of a CustomerInformation command.
+#[derive(Debug, Clone)]
+pub struct CustomerInformationResult {
+    pub status: String,
+}
+
+pub fn parse_hash_algorithm(s: &str) -> Option<HashAlgorithmEnumType> {
+    match s.to_uppercase().as_str() {
+        "SHA256" => Some(HashAlgorithmEnumType::SHA256),
+        "SHA384" => Some(HashAlgorithmEnumType::SHA384),
+        "SHA512" => Some(HashAlgorithmEnumType::SHA512),
+        _ => None,
+    }
+}
+
+pub async fn customer_information(
+    command_sender: &SharedCommandSender,
+    charge_point_id: &str,
+    request_id: i32,
+    report: bool,
+    clear: bool,
+    customer: &CustomerIdentifier,
+) -> Result<CustomerInformationResult, CommandError> {
+    info!(
+        charge_point_id,
+        request_id, report, clear, "v2.0.1 CustomerInformation"
+    );
+
+    let customer_certificate = customer
+        .certificate
+        .as_ref()
+        .map(|cert| CertificateHashDataType {
+            hash_algorithm: parse_hash_algorithm(&cert.hash_algorithm).unwrap_or_default(),
+            issuer_name_hash: cert.issuer_name_hash.clone(),
+            issuer_key_hash: cert.issuer_key_hash.clone(),
+            serial_number: cert.serial_number.clone(),
+        });
+
+    let request = CustomerInformationRequest {
+        request_id,
+        report,
+        clear,
+        customer_identifier: customer.customer_identifier.clone(),
+        id_token: customer.id_token.as_ref().map(|token| IdTokenType {
+            id_token: token.clone(),
+            kind: IdTokenEnumType::Central,
+            additional_info: None,
+        }),
+        customer_certificate,
+    };
+
+    let payload = serde_json::to_value(&request)
+        .map_err(|e| CommandError::SendFailed(format!("Serialization failed: {}", e)))?;
+
+    let result = command_sender
+        .send_command(charge_point_id, "CustomerInformation", payload)
+        .await?;
+
+    let response: CustomerInformationResponse = serde_json::from_value(result)
+        .map_err(|e| CommandError::InvalidResponse(format!("Failed to parse response: {}", e)))?;
+
+    Ok(CustomerInformationResult {
+        status: format!("{:?}", response.status),
+    })
+}
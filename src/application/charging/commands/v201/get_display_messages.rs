@@ -0,0 +1,59 @@
+//! v2.0.1 GetDisplayMessages command
+
+use rust_ocpp::v2_0_1::messages::get_display_message::{
+    GetDisplayMessagesRequest, GetDisplayMessagesResponse,
+};
+use tracing::info;
+
+use super::set_display_message::{parse_priority, parse_state};
+use crate::application::charging::commands::{CommandError, SharedCommandSender};
+
+/// Criteria for selecting which display messages the station reports.
+#[derive(Debug, Clone, Default)]
+pub struct GetDisplayMessagesCriteria {
+    pub ids: Option<Vec<i32>>,
+    pub priority: Option<String>,
+    pub state: Option<String>,
+}
+
+/// Result of a GetDisplayMessages command.
+#[derive(Debug, Clone)]
+pub struct GetDisplayMessagesResult {
+    /// Accepted (NotifyDisplayMessages follows) or Unknown (no messages).
+    pub status: String,
+}
+
+pub async fn get_display_messages(
+    command_sender: &SharedCommandSender,
+    charge_point_id: &str,
+    request_id: i32,
+    criteria: GetDisplayMessagesCriteria,
+) -> Result<GetDisplayMessagesResult, CommandError> {
+    info!(
+        charge_point_id,
+        request_id,
+        ?criteria,
+        "v2.0.1 GetDisplayMessages"
+    );
+
+    let request = GetDisplayMessagesRequest {
+        id: criteria.ids,
+        request_id,
+        priority: criteria.priority.as_deref().and_then(parse_priority),
+        state: criteria.state.as_deref().and_then(parse_state),
+    };
+
+    let payload = serde_json::to_value(&request)
+        .map_err(|e| CommandError::SendFailed(format!("Serialization failed: {}", e)))?;
+
+    let result = command_sender
+        .send_command(charge_point_id, "GetDisplayMessages", payload)
+        .await?;
+
+    let response: GetDisplayMessagesResponse = serde_json::from_value(result)
+        .map_err(|e| CommandError::InvalidResponse(format!("Failed to parse response: {}", e)))?;
+
+    Ok(GetDisplayMessagesResult {
+        status: format!("{:?}", response.status),
+    })
+}
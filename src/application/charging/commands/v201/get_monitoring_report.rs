@@ -0,0 +1,75 @@
+//! v2.0.1 GetMonitoringReport command
+//!
+//! Asks the station to report its configured monitors via
+//! NotifyMonitoringReport, optionally filtered by monitoring criteria
+//! and/or component-variable pairs.
+
+use rust_ocpp::v2_0_1::datatypes::component_variable_type::ComponentVariableType;
+use rust_ocpp::v2_0_1::enumerations::monitoring_criterion_enum_type::MonitoringCriterionEnumType;
+use rust_ocpp::v2_0_1::messages::get_monitoring_report::{
+    GetMonitoringReportRequest, GetMonitoringReportResponse,
+};
+use tracing::info;
+
+use super::get_report::ComponentVariableSelector;
+use crate::application::charging::commands::{CommandError, SharedCommandSender};
+
+/// Result of a GetMonitoringReport command.
+#[derive(Debug, Clone)]
+pub struct GetMonitoringReportResult {
+    pub status: String,
+}
+
+pub fn parse_monitoring_criterion(s: &str) -> Option<MonitoringCriterionEnumType> {
+    match s {
+        "ThresholdMonitoring" => Some(MonitoringCriterionEnumType::ThresholdMonitoring),
+        "DeltaMonitoring" => Some(MonitoringCriterionEnumType::DeltaMonitoring),
+        "PeriodicMonitoring" => Some(MonitoringCriterionEnumType::PeriodicMonitoring),
+        _ => None,
+    }
+}
+
+pub async fn get_monitoring_report(
+    command_sender: &SharedCommandSender,
+    charge_point_id: &str,
+    request_id: i32,
+    monitoring_criteria: &[String],
+    component_variables: &[ComponentVariableSelector],
+) -> Result<GetMonitoringReportResult, CommandError> {
+    info!(
+        charge_point_id,
+        request_id,
+        criteria = monitoring_criteria.len(),
+        component_variables = component_variables.len(),
+        "v2.0.1 GetMonitoringReport"
+    );
+
+    let criteria: Vec<MonitoringCriterionEnumType> = monitoring_criteria
+        .iter()
+        .filter_map(|c| parse_monitoring_criterion(c))
+        .collect();
+    let component_variable: Vec<ComponentVariableType> = component_variables
+        .iter()
+        .map(ComponentVariableSelector::to_ocpp)
+        .collect();
+
+    let request = GetMonitoringReportRequest {
+        request_id,
+        monitoring_criteria: (!criteria.is_empty()).then_some(criteria),
+        component_variable: (!component_variable.is_empty()).then_some(component_variable),
+    };
+
+    let payload = serde_json::to_value(&request)
+        .map_err(|e| CommandError::SendFailed(format!("Serialization failed: {}", e)))?;
+
+    let result = command_sender
+        .send_command(charge_point_id, "GetMonitoringReport", payload)
+        .await?;
+
+    let response: GetMonitoringReportResponse = serde_json::from_value(result)
+        .map_err(|e| CommandError::InvalidResponse(format!("Failed to parse response: {}", e)))?;
+
+    Ok(GetMonitoringReportResult {
+        status: format!("{:?}", response.status),
+    })
+}
@@ -0,0 +1,137 @@
+//! v2.0.1 GetReport command
+//!
+//! Targeted alternative to GetBaseReport: the station reports only the
+//! components matching `componentCriteria` and/or the listed
+//! component-variable pairs, via NotifyReport.
+
+use rust_ocpp::v2_0_1::datatypes::component_type::ComponentType;
+use rust_ocpp::v2_0_1::datatypes::component_variable_type::ComponentVariableType;
+use rust_ocpp::v2_0_1::datatypes::evse_type::EVSEType;
+use rust_ocpp::v2_0_1::datatypes::variable_type::VariableType;
+use rust_ocpp::v2_0_1::enumerations::component_criterion_enum_type::ComponentCriterionEnumType;
+use rust_ocpp::v2_0_1::messages::get_report::{GetReportRequest, GetReportResponse};
+use tracing::info;
+
+use crate::application::charging::commands::{CommandError, SharedCommandSender};
+
+/// Component (and optionally variable) to include in a report.
+///
+/// Omitting `variable` selects every variable of the component.
+#[derive(Debug, Clone, Default)]
+pub struct ComponentVariableSelector {
+    pub component: String,
+    pub component_instance: Option<String>,
+    pub evse_id: Option<i32>,
+    pub connector_id: Option<i32>,
+    pub variable: Option<String>,
+    pub variable_instance: Option<String>,
+}
+
+impl ComponentVariableSelector {
+    pub(crate) fn to_ocpp(&self) -> ComponentVariableType {
+        ComponentVariableType {
+            component: ComponentType {
+                name: self.component.clone(),
+                instance: self.component_instance.clone(),
+                evse: self.evse_id.map(|id| EVSEType {
+                    id,
+                    connector_id: self.connector_id,
+                }),
+            },
+            variable: self.variable.as_ref().map(|name| VariableType {
+                name: name.clone(),
+                instance: self.variable_instance.clone(),
+            }),
+        }
+    }
+}
+
+/// Result of a GetReport command.
+#[derive(Debug, Clone)]
+pub struct GetReportResult {
+    pub status: String,
+}
+
+pub fn parse_component_criterion(s: &str) -> Option<ComponentCriterionEnumType> {
+    match s {
+        "Active" => Some(ComponentCriterionEnumType::Active),
+        "Available" => Some(ComponentCriterionEnumType::Available),
+        "Enabled" => Some(ComponentCriterionEnumType::Enabled),
+        "Problem" => Some(ComponentCriterionEnumType::Problem),
+        _ => None,
+    }
+}
+
+pub async fn get_report(
+    command_sender: &SharedCommandSender,
+    charge_point_id: &str,
+    request_id: i32,
+    component_criteria: &[String],
+    component_variables: &[ComponentVariableSelector],
+) -> Result<GetReportResult, CommandError> {
+    info!(
+        charge_point_id,
+        request_id,
+        criteria = component_criteria.len(),
+        component_variables = component_variables.len(),
+        "v2.0.1 GetReport"
+    );
+
+    let criteria: Vec<ComponentCriterionEnumType> = component_criteria
+        .iter()
+        .filter_map(|c| parse_component_criterion(c))
+        .collect();
+    let component_variable: Vec<ComponentVariableType> = component_variables
+        .iter()
+        .map(ComponentVariableSelector::to_ocpp)
+        .collect();
+
+    let request = GetReportRequest {
+        request_id,
+        component_criteria: (!criteria.is_empty()).then_some(criteria),
+        component_variable: (!component_variable.is_empty()).then_some(component_variable),
+    };
+
+    let payload = serde_json::to_value(&request)
+        .map_err(|e| CommandError::SendFailed(format!("Serialization failed: {}", e)))?;
+
+    let result = command_sender
+        .send_command(charge_point_id, "GetReport", payload)
+        .await?;
+
+    let response: GetReportResponse = serde_json::from_value(result)
+        .map_err(|e| CommandError::InvalidResponse(format!("Failed to parse response: {}", e)))?;
+
+    Ok(GetReportResult {
+        status: format!("{:?}", response.status),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selector_without_variable_selects_whole_component() {
+        let selector = ComponentVariableSelector {
+            component: "EVSE".to_string(),
+            evse_id: Some(1),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(selector.to_ocpp()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "component": { "name": "EVSE", "evse": { "id": 1 } } })
+        );
+    }
+
+    #[test]
+    fn unknown_component_criterion_is_rejected() {
+        assert_eq!(
+            parse_component_criterion("Problem"),
+            Some(ComponentCriterionEnumType::Problem)
+        );
+        assert_eq!(parse_component_criterion("problem"), None);
+    }
+}
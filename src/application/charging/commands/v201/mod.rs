@@ -7,13 +7,18 @@ pub mod cancel_reservation;
 pub mod change_availability;
 pub mod clear_cache;
 pub mod clear_charging_profile;
+pub mod clear_display_message;
 pub mod clear_variable_monitoring;
+pub mod customer_information;
 pub mod data_transfer;
 pub mod get_base_report;
 pub mod get_charging_profiles;
 pub mod get_composite_schedule;
+pub mod get_display_messages;
 pub mod get_local_list_version;
 pub mod get_log;
+pub mod get_monitoring_report;
+pub mod get_report;
 pub mod get_transaction_status;
 pub mod get_variables;
 pub mod remote_start;
@@ -22,7 +27,9 @@ pub mod reserve_now;
 pub mod reset;
 pub mod send_local_list;
 pub mod set_charging_profile;
+pub mod set_display_message;
 pub mod set_monitoring_base;
+pub mod set_monitoring_level;
 pub mod set_variable_monitoring;
 pub mod set_variables;
 pub mod trigger_message;
@@ -0,0 +1,112 @@
+//! v2.0.1 SetDisplayMessage command
+
+use chrono::{DateTime, Utc};
+use rust_ocpp::v2_0_1::datatypes::message_content_type::MessageContentType;
+use rust_ocpp::v2_0_1::datatypes::message_info_type::MessageInfoType;
+use rust_ocpp::v2_0_1::enumerations::message_format_enum_type::MessageFormatEnumType;
+use rust_ocpp::v2_0_1::enumerations::message_priority_enum_type::MessagePriorityEnumType;
+use rust_ocpp::v2_0_1::enumerations::message_state_enum_type::MessageStateEnumType;
+use rust_ocpp::v2_0_1::messages::set_display_message::{
+    SetDisplayMessageRequest, SetDisplayMessageResponse,
+};
+use tracing::info;
+
+use crate::application::charging::commands::{CommandError, SharedCommandSender};
+
+/// Message to install on the station display (version-agnostic strings).
+#[derive(Debug, Clone)]
+pub struct DisplayMessageInfo {
+    pub id: i32,
+    /// AlwaysFront, InFront, NormalCycle.
+    pub priority: String,
+    /// Charging, Faulted, Idle, Unavailable.
+    pub state: Option<String>,
+    pub start_date_time: Option<DateTime<Utc>>,
+    pub end_date_time: Option<DateTime<Utc>>,
+    pub transaction_id: Option<String>,
+    /// ASCII, HTML, URI, UTF8.
+    pub format: String,
+    pub language: Option<String>,
+    pub content: String,
+}
+
+/// Result of a SetDisplayMessage command.
+#[derive(Debug, Clone)]
+pub struct SetDisplayMessageResult {
+    /// Accepted, NotSupportedMessageFormat, Rejected, NotSupportedPriority,
+    /// NotSupportedState, UnknownTransaction.
+    pub status: String,
+}
+
+pub fn parse_priority(s: &str) -> Option<MessagePriorityEnumType> {
+    match s {
+        "AlwaysFront" => Some(MessagePriorityEnumType::AlwaysFront),
+        "InFront" => Some(MessagePriorityEnumType::InFront),
+        "NormalCycle" => Some(MessagePriorityEnumType::NormalCycle),
+        _ => None,
+    }
+}
+
+pub fn parse_state(s: &str) -> Option<MessageStateEnumType> {
+    match s {
+        "Charging" => Some(MessageStateEnumType::Charging),
+        "Faulted" => Some(MessageStateEnumType::Faulted),
+        "Idle" => Some(MessageStateEnumType::Idle),
+        "Unavailable" => Some(MessageStateEnumType::Unavailable),
+        _ => None,
+    }
+}
+
+pub fn parse_format(s: &str) -> Option<MessageFormatEnumType> {
+    match s {
+        "ASCII" => Some(MessageFormatEnumType::ASCII),
+        "HTML" => Some(MessageFormatEnumType::HTML),
+        "URI" => Some(MessageFormatEnumType::URI),
+        "UTF8" => Some(MessageFormatEnumType::UTF8),
+        _ => None,
+    }
+}
+
+pub async fn set_display_message(
+    command_sender: &SharedCommandSender,
+    charge_point_id: &str,
+    message: DisplayMessageInfo,
+) -> Result<SetDisplayMessageResult, CommandError> {
+    info!(
+        charge_point_id,
+        message_id = message.id,
+        priority = message.priority.as_str(),
+        "v2.0.1 SetDisplayMessage"
+    );
+
+    let request = SetDisplayMessageRequest {
+        message: MessageInfoType {
+            id: message.id,
+            priority: parse_priority(&message.priority).unwrap_or_default(),
+            state: message.state.as_deref().and_then(parse_state),
+            start_date_time: message.start_date_time,
+            end_date_time: message.end_date_time,
+            transaction_id: message.transaction_id,
+            message: MessageContentType {
+                format: parse_format(&message.format).unwrap_or_default(),
+                language: message.language,
+                content: message.content,
+            },
+            display: None,
+        },
+    };
+
+    let payload = serde_json::to_value(&request)
+        .map_err(|e| CommandError::SendFailed(format!("Serialization failed: {}", e)))?;
+
+    let result = command_sender
+        .send_command(charge_point_id, "SetDisplayMessage", payload)
+        .await?;
+
+    let response: SetDisplayMessageResponse = serde_json::from_value(result)
+        .map_err(|e| CommandError::InvalidResponse(format!("Failed to parse response: {}", e)))?;
+
+    Ok(SetDisplayMessageResult {
+        status: format!("{:?}", response.status),
+    })
+}
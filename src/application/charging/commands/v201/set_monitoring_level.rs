@@ -0,0 +1,41 @@
+//! v2.0.1 SetMonitoringLevel command
+//!
+//! Restricts the events the station reports via NotifyEvent to monitors
+//! with a severity at or below the given level (0 = Danger … 9 = Debug).
+
+use rust_ocpp::v2_0_1::messages::set_monitoring_level::{
+    SetMonitoringLevelRequest, SetMonitoringLevelResponse,
+};
+use tracing::info;
+
+use crate::application::charging::commands::{CommandError, SharedCommandSender};
+
+/// Result of a SetMonitoringLevel command.
+#[derive(Debug, Clone)]
+pub struct SetMonitoringLevelResult {
+    pub status: String,
+}
+
+pub async fn set_monitoring_level(
+    command_sender: &SharedCommandSender,
+    charge_point_id: &str,
+    severity: u8,
+) -> Result<SetMonitoringLevelResult, CommandError> {
+    info!(charge_point_id, severity, "v2.0.1 SetMonitoringLevel");
+
+    let request = SetMonitoringLevelRequest { severity };
+
+    let payload = serde_json::to_value(&request)
+        .map_err(|e| CommandError::SendFailed(format!("Serialization failed: {}", e)))?;
+
+    let result = command_sender
+        .send_command(charge_point_id, "SetMonitoringLevel", payload)
+        .await?;
+
+    let response: SetMonitoringLevelResponse = serde_json::from_value(result)
+        .map_err(|e| CommandError::InvalidResponse(format!("Failed to parse response: {}", e)))?;
+
+    Ok(SetMonitoringLevelResult {
+        status: format!("{:?}", response.status),
+    })
+}
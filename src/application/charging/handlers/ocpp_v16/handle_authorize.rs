@@ -4,17 +4,22 @@ use chrono::Utc;
 use rust_ocpp::v1_6::messages::authorize::{AuthorizeRequest, AuthorizeResponse};
 use rust_ocpp::v1_6::types::{AuthorizationStatus, IdTagInfo};
 use serde_json::Value;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::events::{AuthorizationEvent, Event};
 use crate::application::OcppHandlerV16;
+use crate::domain::OcppVersion;
 
-pub async fn handle_authorize(handler: &OcppHandlerV16, payload: &Value) -> Value {
+pub async fn handle_authorize(
+    handler: &OcppHandlerV16,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     let req: AuthorizeRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
             error!(charge_point_id = handler.charge_point_id.as_str(), error = %e, "Failed to parse Authorize");
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V16, &e));
         }
     };
 
@@ -39,6 +44,22 @@ pub async fn handle_authorize(handler: &OcppHandlerV16, payload: &Value) -> Valu
         Some("Invalid") | Some(_) | None => AuthorizationStatus::Invalid,
     };
 
+    if let Err(e) = handler
+        .service
+        .record_auth_event(
+            &handler.charge_point_id,
+            &req.id_tag,
+            &format!("{:?}", status),
+        )
+        .await
+    {
+        warn!(
+            charge_point_id = handler.charge_point_id.as_str(),
+            error = %e,
+            "Failed to record authorization event"
+        );
+    }
+
     handler
         .event_bus
         .publish(Event::AuthorizationResult(AuthorizationEvent {
@@ -56,5 +77,5 @@ pub async fn handle_authorize(handler: &OcppHandlerV16, payload: &Value) -> Valu
         },
     };
 
-    serde_json::to_value(&response).unwrap_or_default()
+    Ok(serde_json::to_value(&response).unwrap_or_default())
 }
@@ -7,6 +7,7 @@ use rust_ocpp::v1_6::messages::boot_notification::{
 use rust_ocpp::v1_6::types::RegistrationStatus;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::events::{BootNotificationEvent, Event};
 use crate::application::OcppHandlerV16;
 use crate::domain::OcppVersion;
@@ -14,7 +15,7 @@ use crate::domain::OcppVersion;
 pub async fn handle_boot_notification(
     handler: &OcppHandlerV16,
     payload: &serde_json::Value,
-) -> serde_json::Value {
+) -> Result<serde_json::Value, PayloadViolation> {
     let payload: BootNotificationRequest = match serde_json::from_value(payload.clone()) {
         Ok(p) => p,
         Err(e) => {
@@ -23,7 +24,7 @@ pub async fn handle_boot_notification(
                 error = %e,
                 "Failed to deserialize BootNotificationRequest"
             );
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V16, &e));
         }
     };
 
@@ -67,11 +68,19 @@ pub async fn handle_boot_notification(
             timestamp: Utc::now(),
         }));
 
+    let interval = handler
+        .service
+        .heartbeat_interval_for(&handler.charge_point_id);
+    let _ = handler
+        .service
+        .record_heartbeat_interval(&handler.charge_point_id, interval)
+        .await;
+
     let response = BootNotificationResponse {
         current_time: Utc::now(),
-        interval: 300,
+        interval: interval.max(0) as u32,
         status: RegistrationStatus::Accepted,
     };
 
-    serde_json::to_value(&response).unwrap_or_default()
+    Ok(serde_json::to_value(&response).unwrap_or_default())
 }
@@ -1,18 +1,28 @@
 //! DataTransfer handler
+//!
+//! Routed to the vendor extension registered for `vendorId` / `messageId`.
 
 use rust_ocpp::v1_6::messages::data_transfer::{DataTransferRequest, DataTransferResponse};
 use rust_ocpp::v1_6::types::DataTransferStatus;
 use serde_json::Value;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::charging::services::data_transfer::{
+    DataTransferStatus as ReplyStatus, IncomingDataTransfer,
+};
 use crate::application::OcppHandlerV16;
+use crate::domain::OcppVersion;
 
-pub async fn handle_data_transfer(handler: &OcppHandlerV16, payload: &Value) -> Value {
+pub async fn handle_data_transfer(
+    handler: &OcppHandlerV16,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     let req: DataTransferRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
             error!(charge_point_id = handler.charge_point_id.as_str(), error = %e, "Failed to parse DataTransfer");
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V16, &e));
         }
     };
 
@@ -23,10 +33,26 @@ pub async fn handle_data_transfer(handler: &OcppHandlerV16, payload: &Value) ->
         "DataTransfer"
     );
 
+    let reply = handler
+        .data_transfers
+        .handle_incoming(IncomingDataTransfer {
+            charge_point_id: handler.charge_point_id.clone(),
+            ocpp_version: OcppVersion::V16,
+            vendor_id: req.vendor_string,
+            message_id: req.message_id,
+            data: req.data,
+        })
+        .await;
+
     let response = DataTransferResponse {
-        status: DataTransferStatus::Accepted,
-        data: None,
+        status: match reply.status {
+            ReplyStatus::Accepted => DataTransferStatus::Accepted,
+            ReplyStatus::Rejected => DataTransferStatus::Rejected,
+            ReplyStatus::UnknownMessageId => DataTransferStatus::UnknownMessageId,
+            ReplyStatus::UnknownVendorId => DataTransferStatus::UnknownVendorId,
+        },
+        data: reply.data,
     };
 
-    serde_json::to_value(&response).unwrap_or_default()
+    Ok(serde_json::to_value(&response).unwrap_or_default())
 }
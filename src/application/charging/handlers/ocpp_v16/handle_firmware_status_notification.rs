@@ -6,17 +6,19 @@ use rust_ocpp::v1_6::messages::firmware_status_notification::{
 use serde_json::Value;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::OcppHandlerV16;
+use crate::domain::OcppVersion;
 
 pub async fn handle_firmware_status_notification(
     handler: &OcppHandlerV16,
     payload: &Value,
-) -> Value {
+) -> Result<Value, PayloadViolation> {
     let req: FirmwareStatusNotificationRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
             error!(charge_point_id = handler.charge_point_id.as_str(), error = %e, "Failed to parse FirmwareStatusNotification");
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V16, &e));
         }
     };
 
@@ -26,5 +28,5 @@ pub async fn handle_firmware_status_notification(
         "FirmwareStatusNotification"
     );
 
-    serde_json::to_value(&FirmwareStatusNotificationResponse {}).unwrap_or_default()
+    Ok(serde_json::to_value(&FirmwareStatusNotificationResponse {}).unwrap_or_default())
 }
@@ -5,10 +5,14 @@ use rust_ocpp::v1_6::messages::heart_beat::HeartbeatResponse;
 use serde_json::Value;
 use tracing::info;
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::events::{Event, HeartbeatEvent};
 use crate::application::OcppHandlerV16;
 
-pub async fn handle_heartbeat(handler: &OcppHandlerV16, _payload: &Value) -> Value {
+pub async fn handle_heartbeat(
+    handler: &OcppHandlerV16,
+    _payload: &Value,
+) -> Result<Value, PayloadViolation> {
     info!(
         charge_point_id = handler.charge_point_id.as_str(),
         "Heartbeat"
@@ -27,5 +31,5 @@ pub async fn handle_heartbeat(handler: &OcppHandlerV16, _payload: &Value) -> Val
         current_time: Utc::now(),
     };
 
-    serde_json::to_value(&response).unwrap_or_default()
+    Ok(serde_json::to_value(&response).unwrap_or_default())
 }
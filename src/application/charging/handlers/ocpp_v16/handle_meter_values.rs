@@ -5,15 +5,20 @@ use rust_ocpp::v1_6::types::{Measurand, UnitOfMeasure};
 use serde_json::Value;
 use tracing::{error, info, warn};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::events::{Event, MeterValuesEvent};
 use crate::application::OcppHandlerV16;
+use crate::domain::OcppVersion;
 
-pub async fn handle_meter_values(handler: &OcppHandlerV16, payload: &Value) -> Value {
+pub async fn handle_meter_values(
+    handler: &OcppHandlerV16,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     let req: MeterValuesRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
             error!(charge_point_id = handler.charge_point_id.as_str(), error = %e, "Failed to parse MeterValues");
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V16, &e));
         }
     };
 
@@ -25,7 +30,24 @@ pub async fn handle_meter_values(handler: &OcppHandlerV16, payload: &Value) -> V
         "MeterValues"
     );
 
-    let transaction_id = req.transaction_id;
+    let transaction_id = match req.transaction_id {
+        Some(id) => Some(id),
+        // Stations with the quirk omit it even mid-transaction
+        None if handler
+            .quirk_profile()
+            .await
+            .is_some_and(|p| p.infer_meter_values_transaction_id) =>
+        {
+            handler
+                .service
+                .get_active_transaction_for_connector(&handler.charge_point_id, req.connector_id)
+                .await
+                .ok()
+                .flatten()
+                .map(|tx| tx.id)
+        }
+        None => None,
+    };
 
     let mut energy_wh: Option<f64> = None;
     let mut power_w: Option<f64> = None;
@@ -171,5 +193,5 @@ pub async fn handle_meter_values(handler: &OcppHandlerV16, payload: &Value) -> V
                 .unwrap_or_else(chrono::Utc::now),
         }));
 
-    serde_json::to_value(&MeterValuesResponse {}).unwrap_or_default()
+    Ok(serde_json::to_value(&MeterValuesResponse {}).unwrap_or_default())
 }
@@ -9,7 +9,9 @@ use serde::Deserialize;
 use serde_json::Value;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::OcppHandlerV16;
+use crate::domain::OcppVersion;
 
 /// Local struct for SecurityEventNotification (vendor extension in OCPP 1.6)
 #[derive(Debug, Deserialize)]
@@ -25,12 +27,12 @@ struct SecurityEventNotificationRequest {
 pub async fn handle_security_event_notification(
     handler: &OcppHandlerV16,
     payload: &Value,
-) -> Value {
+) -> Result<Value, PayloadViolation> {
     let req: SecurityEventNotificationRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
             error!(charge_point_id = handler.charge_point_id.as_str(), error = %e, "Failed to parse SecurityEventNotification");
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V16, &e));
         }
     };
 
@@ -41,5 +43,5 @@ pub async fn handle_security_event_notification(
         "SecurityEventNotification"
     );
 
-    serde_json::json!({})
+    Ok(serde_json::json!({}))
 }
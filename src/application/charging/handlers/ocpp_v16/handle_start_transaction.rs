@@ -5,17 +5,25 @@ use rust_ocpp::v1_6::messages::start_transaction::{
 };
 use rust_ocpp::v1_6::types::{AuthorizationStatus, IdTagInfo};
 use serde_json::Value;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-use crate::application::events::{Event, TransactionStartedEvent};
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::charging::services::ReservationCheck;
+use crate::application::events::{
+    Event, ReservationStatusChangedEvent, TransactionStartedEvent,
+};
 use crate::application::OcppHandlerV16;
+use crate::domain::OcppVersion;
 
-pub async fn handle_start_transaction(handler: &OcppHandlerV16, payload: &Value) -> Value {
+pub async fn handle_start_transaction(
+    handler: &OcppHandlerV16,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     let req: StartTransactionRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
             error!(charge_point_id = handler.charge_point_id.as_str(), error = %e, "Failed to parse StartTransaction");
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V16, &e));
         }
     };
 
@@ -33,7 +41,33 @@ pub async fn handle_start_transaction(handler: &OcppHandlerV16, payload: &Value)
         .await
         .unwrap_or(false);
 
-    if !is_valid {
+    let reservation = match handler
+        .service
+        .check_reservation(
+            &handler.charge_point_id,
+            req.connector_id,
+            &req.id_tag,
+            req.reservation_id,
+        )
+        .await
+    {
+        Ok(check) => check,
+        Err(e) => {
+            error!(charge_point_id = handler.charge_point_id.as_str(), error = %e, "Failed to check reservations");
+            ReservationCheck::Free
+        }
+    };
+
+    if let ReservationCheck::Blocked(r) = &reservation {
+        warn!(
+            charge_point_id = handler.charge_point_id.as_str(),
+            connector_id = req.connector_id,
+            reservation_id = r.id,
+            "StartTransaction rejected: connector reserved for another tag"
+        );
+    }
+
+    if !is_valid || matches!(reservation, ReservationCheck::Blocked(_)) {
         let response = StartTransactionResponse {
             transaction_id: 0,
             id_tag_info: IdTagInfo {
@@ -42,7 +76,7 @@ pub async fn handle_start_transaction(handler: &OcppHandlerV16, payload: &Value)
                 parent_id_tag: None,
             },
         };
-        return serde_json::to_value(&response).unwrap_or_default();
+        return Ok(serde_json::to_value(&response).unwrap_or_default());
     }
 
     match handler
@@ -52,6 +86,7 @@ pub async fn handle_start_transaction(handler: &OcppHandlerV16, payload: &Value)
             req.connector_id,
             &req.id_tag,
             req.meter_start,
+            None,
         )
         .await
     {
@@ -67,6 +102,19 @@ pub async fn handle_start_transaction(handler: &OcppHandlerV16, payload: &Value)
                     timestamp: req.timestamp,
                 }));
 
+            if let ReservationCheck::Held(r) = reservation {
+                match handler.service.use_reservation(r).await {
+                    Ok(used) => handler.event_bus.publish(Event::ReservationStatusChanged(
+                        ReservationStatusChangedEvent::from_reservation(&used),
+                    )),
+                    Err(e) => error!(
+                        charge_point_id = handler.charge_point_id.as_str(),
+                        error = %e,
+                        "Failed to mark reservation used"
+                    ),
+                }
+            }
+
             let response = StartTransactionResponse {
                 transaction_id: transaction.id,
                 id_tag_info: IdTagInfo {
@@ -75,7 +123,7 @@ pub async fn handle_start_transaction(handler: &OcppHandlerV16, payload: &Value)
                     parent_id_tag: None,
                 },
             };
-            serde_json::to_value(&response).unwrap_or_default()
+            Ok(serde_json::to_value(&response).unwrap_or_default())
         }
         Err(e) => {
             error!(
@@ -91,7 +139,7 @@ pub async fn handle_start_transaction(handler: &OcppHandlerV16, payload: &Value)
                     parent_id_tag: None,
                 },
             };
-            serde_json::to_value(&response).unwrap_or_default()
+            Ok(serde_json::to_value(&response).unwrap_or_default())
         }
     }
 }
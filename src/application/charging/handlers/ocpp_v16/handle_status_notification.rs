@@ -8,16 +8,21 @@ use rust_ocpp::v1_6::types::ChargePointStatus;
 use serde_json::Value;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::events::{ConnectorStatusChangedEvent, Event};
 use crate::application::OcppHandlerV16;
 use crate::domain::ConnectorStatus;
+use crate::domain::OcppVersion;
 
-pub async fn handle_status_notification(handler: &OcppHandlerV16, payload: &Value) -> Value {
+pub async fn handle_status_notification(
+    handler: &OcppHandlerV16,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     let req: StatusNotificationRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
             error!(charge_point_id = handler.charge_point_id.as_str(), error = %e, "Failed to parse StatusNotification");
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V16, &e));
         }
     };
 
@@ -54,8 +59,10 @@ pub async fn handle_status_notification(handler: &OcppHandlerV16, payload: &Valu
             status: format!("{:?}", req.status),
             error_code: Some(format!("{:?}", req.error_code)),
             info: req.info.clone(),
+            vendor_id: req.vendor_id.clone(),
+            vendor_error_code: req.vendor_error_code.clone(),
             timestamp: req.timestamp.unwrap_or_else(Utc::now),
         }));
 
-    serde_json::to_value(&StatusNotificationResponse {}).unwrap_or_default()
+    Ok(serde_json::to_value(&StatusNotificationResponse {}).unwrap_or_default())
 }
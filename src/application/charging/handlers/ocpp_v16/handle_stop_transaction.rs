@@ -7,15 +7,20 @@ use rust_ocpp::v1_6::types::{AuthorizationStatus, IdTagInfo};
 use serde_json::Value;
 use tracing::{error, info, warn};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::events::{Event, TransactionBilledEvent, TransactionStoppedEvent};
 use crate::application::OcppHandlerV16;
+use crate::domain::OcppVersion;
 
-pub async fn handle_stop_transaction(handler: &OcppHandlerV16, payload: &Value) -> Value {
+pub async fn handle_stop_transaction(
+    handler: &OcppHandlerV16,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     let req: StopTransactionRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
             error!(charge_point_id = handler.charge_point_id.as_str(), error = %e, "Failed to parse StopTransaction");
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V16, &e));
         }
     };
 
@@ -191,5 +196,5 @@ pub async fn handle_stop_transaction(handler: &OcppHandlerV16, payload: &Value)
         }),
     };
 
-    serde_json::to_value(&response).unwrap_or_default()
+    Ok(serde_json::to_value(&response).unwrap_or_default())
 }
@@ -7,6 +7,7 @@
 use serde_json::Value;
 use tracing::{error, warn};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::OcppHandlerV16;
 
 mod handle_authorize;
@@ -39,8 +40,13 @@ pub use handle_stop_transaction::handle_stop_transaction;
 /// `payload` is the raw JSON payload. Each handler deserializes it
 /// into the appropriate `rust_ocpp::v1_6` request type.
 ///
-/// Returns a `serde_json::Value` representing the response payload.
-pub async fn v16_action_matcher(handler: &OcppHandlerV16, action: &str, payload: &Value) -> Value {
+/// Returns the response payload, or the CallError to answer with when the
+/// payload does not deserialize or the action is not accepted from a charge point.
+pub async fn v16_action_matcher(
+    handler: &OcppHandlerV16,
+    action: &str,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     match action {
         "Authorize" => handle_authorize(handler, payload).await,
         "BootNotification" => handle_boot_notification(handler, payload).await,
@@ -64,14 +70,15 @@ pub async fn v16_action_matcher(handler: &OcppHandlerV16, action: &str, payload:
                     action = unknown,
                     "Received CS→CP action from charge point (protocol error)"
                 );
+                Err(PayloadViolation::not_supported(unknown))
             } else {
                 error!(
                     charge_point_id = handler.charge_point_id.as_str(),
                     action = unknown,
                     "Unknown OCPP 1.6 action"
                 );
+                Err(PayloadViolation::not_implemented(unknown))
             }
-            serde_json::json!({})
         }
     }
 }
@@ -1,16 +1,21 @@
 //! OCPP 1.6 message handler
 //!
-//! Parses raw OCPP-J frames, dispatches to action handlers,
+//! Parses raw OCPP-J frames, applies the station's quirk profile, validates
+//! Call payloads against the bundled schemas, dispatches to action handlers,
 //! and serializes responses using `rust_ocpp::v1_6` types.
 
 use std::sync::Arc;
 
 use serde_json::Value;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 use crate::application::charging::handlers::ocpp_v16::v16_action_matcher;
+use crate::application::charging::quirks::{QuirkProfile, SharedQuirkRegistry};
+use crate::application::charging::schema::SharedSchemaValidator;
+use crate::application::charging::services::data_transfer::SharedDataTransferService;
 use crate::application::events::SharedEventBus;
 use crate::application::{BillingService, ChargePointService, CommandSender};
+use crate::domain::OcppVersion;
 use crate::shared::ocpp_frame::OcppFrame;
 
 /// Handler for OCPP 1.6 messages
@@ -20,15 +25,22 @@ pub struct OcppHandlerV16 {
     pub billing_service: Arc<BillingService>,
     pub command_sender: Arc<CommandSender>,
     pub event_bus: SharedEventBus,
+    pub schema_validator: SharedSchemaValidator,
+    pub quirks: SharedQuirkRegistry,
+    pub data_transfers: SharedDataTransferService,
 }
 
 impl OcppHandlerV16 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         charge_point_id: impl Into<String>,
         service: Arc<ChargePointService>,
         billing_service: Arc<BillingService>,
         command_sender: Arc<CommandSender>,
         event_bus: SharedEventBus,
+        schema_validator: SharedSchemaValidator,
+        quirks: SharedQuirkRegistry,
+        data_transfers: SharedDataTransferService,
     ) -> Self {
         Self {
             charge_point_id: charge_point_id.into(),
@@ -36,9 +48,34 @@ impl OcppHandlerV16 {
             billing_service,
             command_sender,
             event_bus,
+            schema_validator,
+            quirks,
+            data_transfers,
         }
     }
 
+    /// The quirk profile for this charge point, resolved from its stored
+    /// vendor/model the first time when it has not booted since startup.
+    pub async fn quirk_profile(&self) -> Option<Arc<QuirkProfile>> {
+        if self.quirks.is_empty() {
+            return None;
+        }
+        if let Some(profile) = self.quirks.assigned(&self.charge_point_id) {
+            return profile;
+        }
+        let charge_point = self
+            .service
+            .get_charge_point(&self.charge_point_id)
+            .await
+            .ok()
+            .flatten();
+        self.quirks.assign(
+            &self.charge_point_id,
+            charge_point.as_ref().and_then(|cp| cp.vendor.as_deref()),
+            charge_point.as_ref().and_then(|cp| cp.model.as_deref()),
+        )
+    }
+
     pub async fn handle(&self, text: &str) -> Option<String> {
         info!(
             charge_point_id = self.charge_point_id.as_str(),
@@ -186,28 +223,78 @@ impl OcppHandlerV16 {
         OcppFrame::parse(&sanitized).ok()
     }
 
-    async fn handle_call(&self, unique_id: &str, action: &str, payload: Value) -> Option<String> {
+    async fn handle_call(
+        &self,
+        unique_id: &str,
+        action: &str,
+        mut payload: Value,
+    ) -> Option<String> {
         info!(
             charge_point_id = self.charge_point_id.as_str(),
             action, "Received Call"
         );
 
-        let response_payload = v16_action_matcher(self, action, &payload).await;
+        if action == "BootNotification" && !self.quirks.is_empty() {
+            self.quirks.assign(
+                &self.charge_point_id,
+                payload.get("chargePointVendor").and_then(Value::as_str),
+                payload.get("chargePointModel").and_then(Value::as_str),
+            );
+        }
+        if let Some(profile) = self.quirk_profile().await {
+            let fixes = profile.apply_call(action, &mut payload);
+            if fixes > 0 {
+                debug!(
+                    charge_point_id = self.charge_point_id.as_str(),
+                    action,
+                    profile = profile.name.as_str(),
+                    fixes,
+                    "Applied quirk profile"
+                );
+            }
+        }
+
+        let result = match self
+            .schema_validator
+            .check_inbound(OcppVersion::V16, action, &payload)
+        {
+            Ok(()) => v16_action_matcher(self, action, &payload).await,
+            Err(violation) => Err(violation),
+        };
 
-        let response = OcppFrame::CallResult {
-            unique_id: unique_id.to_string(),
-            payload: response_payload,
+        let response = match result {
+            Ok(response_payload) => OcppFrame::CallResult {
+                unique_id: unique_id.to_string(),
+                payload: response_payload,
+            },
+            Err(violation) => {
+                warn!(
+                    charge_point_id = self.charge_point_id.as_str(),
+                    action,
+                    error_code = violation.error_code,
+                    "Rejected Call: {}", violation.description
+                );
+                violation.to_frame(unique_id)
+            }
         };
 
         Some(response.serialize())
     }
 
-    async fn handle_call_result(&self, unique_id: &str, payload: Value) {
+    async fn handle_call_result(&self, unique_id: &str, mut payload: Value) {
         info!(
             charge_point_id = self.charge_point_id.as_str(),
             message_id = unique_id,
             "Received CallResult"
         );
+        if let Some(profile) = self.quirk_profile().await {
+            if let Some(action) = self
+                .command_sender
+                .pending_action(&self.charge_point_id, unique_id)
+            {
+                profile.apply_call_result(&action, &mut payload);
+            }
+        }
         self.command_sender
             .handle_response(&self.charge_point_id, unique_id, payload);
     }
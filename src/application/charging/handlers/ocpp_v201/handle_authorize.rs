@@ -1,16 +1,30 @@
 //! V201 Authorize handler
+//!
+//! Plug & Charge requests carry `iso15118CertificateHashData` for the EV's
+//! contract chain; it is validated before the eMAID is looked up as an id tag.
 
 use chrono::Utc;
 use rust_ocpp::v2_0_1::datatypes::id_token_info_type::IdTokenInfoType;
 use rust_ocpp::v2_0_1::enumerations::authorization_status_enum_type::AuthorizationStatusEnumType;
+use rust_ocpp::v2_0_1::enumerations::authorize_certificate_status_enum_type::AuthorizeCertificateStatusEnumType;
+use rust_ocpp::v2_0_1::enumerations::id_token_enum_type::IdTokenEnumType;
 use rust_ocpp::v2_0_1::messages::authorize::{AuthorizeRequest, AuthorizeResponse};
 use serde_json::Value;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
+use super::handle_get_certificate_status::ocsp_request_data;
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::charging::services::plug_and_charge::{
+    normalize_emaid, ContractCertificateStatus,
+};
 use crate::application::events::{AuthorizationEvent, Event};
 use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
 
-pub async fn handle_authorize(handler: &OcppHandlerV201, payload: &Value) -> Value {
+pub async fn handle_authorize(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     let req: AuthorizeRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
@@ -19,7 +33,7 @@ pub async fn handle_authorize(handler: &OcppHandlerV201, payload: &Value) -> Val
                 error = %e,
                 "V201: Failed to parse Authorize"
             );
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
         }
     };
 
@@ -31,9 +45,34 @@ pub async fn handle_authorize(handler: &OcppHandlerV201, payload: &Value) -> Val
         "V201 Authorize"
     );
 
-    let auth_status = handler.service.get_auth_status(id_tag).await.ok().flatten();
+    let certificate_status = match (&req.iso_15118_certificate_hash_data, &req.certificate) {
+        (Some(hash_data), _) => {
+            let chain: Vec<_> = hash_data.iter().map(ocsp_request_data).collect();
+            Some(handler.plug_and_charge.verify_contract(&chain).await)
+        }
+        (None, Some(_)) => {
+            warn!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                "V201 Authorize: PEM contract chains are not validated, \
+                 station must send iso15118CertificateHashData"
+            );
+            Some(ContractCertificateStatus::CertChainError)
+        }
+        (None, None) => None,
+    };
+
+    let mut auth_status = handler.service.get_auth_status(id_tag).await.ok().flatten();
+    if auth_status.is_none() && req.id_token.kind == IdTokenEnumType::EMAID {
+        let emaid = normalize_emaid(id_tag);
+        if emaid != *id_tag {
+            auth_status = handler.service.get_auth_status(&emaid).await.ok().flatten();
+        }
+    }
 
+    let contract_rejected =
+        certificate_status.is_some_and(|s| s != ContractCertificateStatus::Accepted);
     let status = match auth_status.as_deref() {
+        _ if contract_rejected => AuthorizationStatusEnumType::Invalid,
         Some("Accepted") => AuthorizationStatusEnumType::Accepted,
         Some("Blocked") => AuthorizationStatusEnumType::Blocked,
         Some("Expired") => AuthorizationStatusEnumType::Expired,
@@ -41,6 +80,18 @@ pub async fn handle_authorize(handler: &OcppHandlerV201, payload: &Value) -> Val
         Some("Invalid") | Some(_) | None => AuthorizationStatusEnumType::Invalid,
     };
 
+    if let Err(e) = handler
+        .service
+        .record_auth_event(&handler.charge_point_id, id_tag, &format!("{:?}", status))
+        .await
+    {
+        warn!(
+            charge_point_id = handler.charge_point_id.as_str(),
+            error = %e,
+            "Failed to record authorization event"
+        );
+    }
+
     handler
         .event_bus
         .publish(Event::AuthorizationResult(AuthorizationEvent {
@@ -51,7 +102,18 @@ pub async fn handle_authorize(handler: &OcppHandlerV201, payload: &Value) -> Val
         }));
 
     let response = AuthorizeResponse {
-        certificate_status: None,
+        certificate_status: certificate_status.map(|s| match s {
+            ContractCertificateStatus::Accepted => AuthorizeCertificateStatusEnumType::Accepted,
+            ContractCertificateStatus::CertChainError => {
+                AuthorizeCertificateStatusEnumType::CertChainError
+            }
+            ContractCertificateStatus::CertificateRevoked => {
+                AuthorizeCertificateStatusEnumType::CertificateRevoked
+            }
+            ContractCertificateStatus::NoCertificateAvailable => {
+                AuthorizeCertificateStatusEnumType::NoCertificateAvailable
+            }
+        }),
         id_token_info: IdTokenInfoType {
             status,
             cache_expiry_date_time: None,
@@ -64,5 +126,5 @@ pub async fn handle_authorize(handler: &OcppHandlerV201, payload: &Value) -> Val
         },
     };
 
-    serde_json::to_value(&response).unwrap_or_default()
+    Ok(serde_json::to_value(&response).unwrap_or_default())
 }
@@ -7,6 +7,7 @@ use rust_ocpp::v2_0_1::messages::boot_notification::{
 };
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::events::{BootNotificationEvent, Event};
 use crate::application::OcppHandlerV201;
 use crate::domain::OcppVersion;
@@ -14,7 +15,7 @@ use crate::domain::OcppVersion;
 pub async fn handle_boot_notification(
     handler: &OcppHandlerV201,
     payload: &serde_json::Value,
-) -> serde_json::Value {
+) -> Result<serde_json::Value, PayloadViolation> {
     // Some charging stations omit the mandatory `reason` field.
     // Inject a default ("PowerUp") before deserializing so we don't reject the message.
     let mut patched = payload.clone();
@@ -30,7 +31,7 @@ pub async fn handle_boot_notification(
                 error = %e,
                 "V201: Failed to deserialize BootNotificationRequest"
             );
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
         }
     };
 
@@ -83,12 +84,25 @@ pub async fn handle_boot_notification(
             timestamp: Utc::now(),
         }));
 
+    // The response carries at most u16::MAX seconds
+    let interval = u16::try_from(
+        handler
+            .service
+            .heartbeat_interval_for(&handler.charge_point_id)
+            .max(0),
+    )
+    .unwrap_or(u16::MAX);
+    let _ = handler
+        .service
+        .record_heartbeat_interval(&handler.charge_point_id, interval.into())
+        .await;
+
     let response = BootNotificationResponse {
         current_time: Utc::now(),
-        interval: 300,
+        interval,
         status: RegistrationStatusEnumType::Accepted,
         status_info: None,
     };
 
-    serde_json::to_value(&response).unwrap_or_default()
+    Ok(serde_json::to_value(&response).unwrap_or_default())
 }
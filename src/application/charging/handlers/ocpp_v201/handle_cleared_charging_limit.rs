@@ -0,0 +1,69 @@
+//! V201 ClearedChargingLimit handler
+//!
+//! The EMS or system operator lifted a limit it had imposed on the station.
+
+use chrono::Utc;
+use rust_ocpp::v2_0_1::messages::cleared_charging_limit::{
+    ClearedChargingLimitRequest, ClearedChargingLimitResponse,
+};
+use serde_json::Value;
+use tracing::{error, info};
+
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::events::{Event, ExternalChargingLimitEvent};
+use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
+
+pub async fn handle_cleared_charging_limit(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
+    let req: ClearedChargingLimitRequest = match serde_json::from_value(payload.clone()) {
+        Ok(r) => r,
+        Err(e) => {
+            error!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                error = %e,
+                "V201: Failed to parse ClearedChargingLimit"
+            );
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
+        }
+    };
+
+    let source = format!("{:?}", req.charging_limit_source);
+    let cleared = handler
+        .service
+        .clear_external_limits(&handler.charge_point_id, &source, req.evse_id)
+        .await
+        .unwrap_or_else(|e| {
+            error!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                error = %e,
+                "V201: Failed to clear external charging limits"
+            );
+            0
+        });
+
+    info!(
+        charge_point_id = handler.charge_point_id.as_str(),
+        evse_id = ?req.evse_id,
+        source = source.as_str(),
+        cleared,
+        "V201 ClearedChargingLimit"
+    );
+
+    handler
+        .event_bus
+        .publish(Event::ExternalChargingLimitCleared(
+            ExternalChargingLimitEvent {
+                charge_point_id: handler.charge_point_id.clone(),
+                evse_id: req.evse_id,
+                source,
+                is_grid_critical: None,
+                schedule_json: "[]".to_string(),
+                timestamp: Utc::now(),
+            },
+        ));
+
+    Ok(serde_json::to_value(ClearedChargingLimitResponse {}).unwrap_or_default())
+}
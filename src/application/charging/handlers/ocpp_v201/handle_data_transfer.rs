@@ -1,15 +1,24 @@
 //! V201 DataTransfer handler
 //!
 //! In OCPP 2.0.1, the field is `vendor_id` (not `vendor_string` like V1.6).
+//! Routed to the vendor extension registered for `vendorId` / `messageId`.
 
 use rust_ocpp::v2_0_1::enumerations::data_transfer_status_enum_type::DataTransferStatusEnumType;
 use rust_ocpp::v2_0_1::messages::datatransfer::{DataTransferRequest, DataTransferResponse};
 use serde_json::Value;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::charging::services::data_transfer::{
+    DataTransferStatus, IncomingDataTransfer,
+};
 use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
 
-pub async fn handle_data_transfer(handler: &OcppHandlerV201, payload: &Value) -> Value {
+pub async fn handle_data_transfer(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     let req: DataTransferRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
@@ -18,7 +27,7 @@ pub async fn handle_data_transfer(handler: &OcppHandlerV201, payload: &Value) ->
                 error = %e,
                 "V201: Failed to parse DataTransfer"
             );
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
         }
     };
 
@@ -29,11 +38,27 @@ pub async fn handle_data_transfer(handler: &OcppHandlerV201, payload: &Value) ->
         "V201 DataTransfer"
     );
 
+    let reply = handler
+        .data_transfers
+        .handle_incoming(IncomingDataTransfer {
+            charge_point_id: handler.charge_point_id.clone(),
+            ocpp_version: OcppVersion::V201,
+            vendor_id: req.vendor_id,
+            message_id: req.message_id,
+            data: req.data,
+        })
+        .await;
+
     let response = DataTransferResponse {
-        status: DataTransferStatusEnumType::Accepted,
-        data: None,
+        status: match reply.status {
+            DataTransferStatus::Accepted => DataTransferStatusEnumType::Accepted,
+            DataTransferStatus::Rejected => DataTransferStatusEnumType::Rejected,
+            DataTransferStatus::UnknownMessageId => DataTransferStatusEnumType::UnknownMessageId,
+            DataTransferStatus::UnknownVendorId => DataTransferStatusEnumType::UnknownVendorId,
+        },
+        data: reply.data,
         status_info: None,
     };
 
-    serde_json::to_value(&response).unwrap_or_default()
+    Ok(serde_json::to_value(&response).unwrap_or_default())
 }
@@ -6,12 +6,14 @@ use rust_ocpp::v2_0_1::messages::firmware_status_notification::{
 use serde_json::Value;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
 
 pub async fn handle_firmware_status_notification(
     handler: &OcppHandlerV201,
     payload: &Value,
-) -> Value {
+) -> Result<Value, PayloadViolation> {
     let req: FirmwareStatusNotificationRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
@@ -20,7 +22,7 @@ pub async fn handle_firmware_status_notification(
                 error = %e,
                 "V201: Failed to parse FirmwareStatusNotification"
             );
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
         }
     };
 
@@ -31,5 +33,5 @@ pub async fn handle_firmware_status_notification(
         "V201 FirmwareStatusNotification"
     );
 
-    serde_json::to_value(&FirmwareStatusNotificationResponse {}).unwrap_or_default()
+    Ok(serde_json::to_value(&FirmwareStatusNotificationResponse {}).unwrap_or_default())
 }
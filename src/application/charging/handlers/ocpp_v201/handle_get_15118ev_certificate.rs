@@ -0,0 +1,79 @@
+//! V201 Get15118EVCertificate handler
+//!
+//! Relays the EV's ISO 15118 certificate installation/update request to the
+//! contract certificate pool.
+
+use rust_ocpp::v2_0_1::datatypes::status_info_type::StatusInfoType;
+use rust_ocpp::v2_0_1::enumerations::certificate_action_enum_type::CertificateActionEnumType;
+use rust_ocpp::v2_0_1::enumerations::iso15118ev_certificate_status_enum_type::Iso15118EVCertificateStatusEnumType;
+use rust_ocpp::v2_0_1::messages::get_15118ev_certificate::{
+    Get15118EVCertificateRequest, Get15118EVCertificateResponse,
+};
+use serde_json::Value;
+use tracing::{error, info, warn};
+
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::charging::services::plug_and_charge::{
+    EvCertificateAction, EvCertificateRequest,
+};
+use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
+
+pub async fn handle_get_15118ev_certificate(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
+    let req: Get15118EVCertificateRequest = match serde_json::from_value(payload.clone()) {
+        Ok(r) => r,
+        Err(e) => {
+            error!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                error = %e,
+                "V201: Failed to parse Get15118EVCertificate"
+            );
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
+        }
+    };
+
+    info!(
+        charge_point_id = handler.charge_point_id.as_str(),
+        schema_version = req.iso_15118_schema_version.as_str(),
+        action = ?req.action,
+        "V201 Get15118EVCertificate"
+    );
+
+    let request = EvCertificateRequest {
+        charge_point_id: handler.charge_point_id.clone(),
+        iso15118_schema_version: req.iso_15118_schema_version,
+        action: match req.action {
+            CertificateActionEnumType::Install => EvCertificateAction::Install,
+            CertificateActionEnumType::Update => EvCertificateAction::Update,
+        },
+        exi_request: req.exi_request,
+    };
+
+    let response = match handler.plug_and_charge.ev_certificate(&request).await {
+        Ok(exi_response) => Get15118EVCertificateResponse {
+            status: Iso15118EVCertificateStatusEnumType::Accepted,
+            exi_response,
+            status_info: None,
+        },
+        Err(reason) => {
+            warn!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                reason = reason.as_str(),
+                "V201 Get15118EVCertificate failed"
+            );
+            Get15118EVCertificateResponse {
+                status: Iso15118EVCertificateStatusEnumType::Failed,
+                exi_response: String::new(),
+                status_info: Some(StatusInfoType {
+                    reason_code: "NoCertificateAvailable".to_string(),
+                    additional_info: Some(reason),
+                }),
+            }
+        }
+    };
+
+    Ok(serde_json::to_value(&response).unwrap_or_default())
+}
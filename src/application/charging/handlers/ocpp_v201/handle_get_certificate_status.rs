@@ -0,0 +1,79 @@
+//! V201 GetCertificateStatus handler
+//!
+//! The station asks for the OCSP status of a certificate it cannot check
+//! itself (typically a contract sub-CA during Plug & Charge).
+
+use rust_ocpp::v2_0_1::datatypes::ocsp_request_data_type::OCSPRequestDataType;
+use rust_ocpp::v2_0_1::datatypes::status_info_type::StatusInfoType;
+use rust_ocpp::v2_0_1::enumerations::get_certificate_status_enum_type::GetCertificateStatusEnumType;
+use rust_ocpp::v2_0_1::messages::get_certificate_status::{
+    GetCertificateStatusRequest, GetCertificateStatusResponse,
+};
+use serde_json::Value;
+use tracing::{error, info, warn};
+
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::charging::services::plug_and_charge::OcspRequestData;
+use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
+
+pub async fn handle_get_certificate_status(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
+    let req: GetCertificateStatusRequest = match serde_json::from_value(payload.clone()) {
+        Ok(r) => r,
+        Err(e) => {
+            error!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                error = %e,
+                "V201: Failed to parse GetCertificateStatus"
+            );
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
+        }
+    };
+
+    let data = ocsp_request_data(&req.ocsp_request_data);
+    info!(
+        charge_point_id = handler.charge_point_id.as_str(),
+        serial_number = data.serial_number.as_str(),
+        responder_url = data.responder_url.as_str(),
+        "V201 GetCertificateStatus"
+    );
+
+    let response = match handler.plug_and_charge.certificate_status(&data).await {
+        Ok(result) => GetCertificateStatusResponse {
+            status: GetCertificateStatusEnumType::Accepted,
+            ocsp_result: Some(result.response),
+            status_info: None,
+        },
+        Err(reason) => {
+            warn!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                reason = reason.as_str(),
+                "V201 GetCertificateStatus failed"
+            );
+            GetCertificateStatusResponse {
+                status: GetCertificateStatusEnumType::Failed,
+                ocsp_result: None,
+                status_info: Some(StatusInfoType {
+                    reason_code: "OcspUnavailable".to_string(),
+                    additional_info: Some(reason),
+                }),
+            }
+        }
+    };
+
+    Ok(serde_json::to_value(&response).unwrap_or_default())
+}
+
+/// Convert the wire type into the service's representation.
+pub(super) fn ocsp_request_data(data: &OCSPRequestDataType) -> OcspRequestData {
+    OcspRequestData {
+        hash_algorithm: format!("{:?}", data.hash_algorithm),
+        issuer_name_hash: data.issuer_name_hash.clone(),
+        issuer_key_hash: data.issuer_key_hash.clone(),
+        serial_number: data.serial_number.clone(),
+        responder_url: data.responder_url.clone(),
+    }
+}
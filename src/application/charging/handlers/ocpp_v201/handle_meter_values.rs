@@ -8,10 +8,15 @@ use rust_ocpp::v2_0_1::messages::meter_values::{MeterValuesRequest, MeterValuesR
 use serde_json::Value;
 use tracing::{error, info, warn};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::events::{Event, MeterValuesEvent};
 use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
 
-pub async fn handle_meter_values(handler: &OcppHandlerV201, payload: &Value) -> Value {
+pub async fn handle_meter_values(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     let req: MeterValuesRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
@@ -20,7 +25,7 @@ pub async fn handle_meter_values(handler: &OcppHandlerV201, payload: &Value) ->
                 error = %e,
                 "V201: Failed to parse MeterValues"
             );
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
         }
     };
 
@@ -150,5 +155,5 @@ pub async fn handle_meter_values(handler: &OcppHandlerV201, payload: &Value) ->
                 .unwrap_or_else(chrono::Utc::now),
         }));
 
-    serde_json::to_value(&MeterValuesResponse {}).unwrap_or_default()
+    Ok(serde_json::to_value(&MeterValuesResponse {}).unwrap_or_default())
 }
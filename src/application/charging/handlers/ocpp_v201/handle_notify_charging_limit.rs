@@ -0,0 +1,87 @@
+//! V201 NotifyChargingLimit handler
+//!
+//! An EMS or system operator imposed a limit directly on the station. The
+//! limit is stored as a `ChargingStationExternalConstraints` profile tagged
+//! with its source and announced on the event bus.
+
+use chrono::Utc;
+use rust_ocpp::v2_0_1::messages::notify_charging_limit::{
+    NotifyChargingLimitRequest, NotifyChargingLimitResponse,
+};
+use serde_json::Value;
+use tracing::{error, info};
+
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::events::{Event, ExternalChargingLimitEvent};
+use crate::application::OcppHandlerV201;
+use crate::domain::{ChargingProfile, OcppVersion};
+
+pub async fn handle_notify_charging_limit(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
+    let req: NotifyChargingLimitRequest = match serde_json::from_value(payload.clone()) {
+        Ok(r) => r,
+        Err(e) => {
+            error!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                error = %e,
+                "V201: Failed to parse NotifyChargingLimit"
+            );
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
+        }
+    };
+
+    let source = format!("{:?}", req.charging_limit.charging_limit_source);
+    let schedules = req.charging_schedule.unwrap_or_default();
+    let schedule_json = serde_json::to_string(&schedules).unwrap_or_else(|_| "[]".into());
+
+    info!(
+        charge_point_id = handler.charge_point_id.as_str(),
+        evse_id = ?req.evse_id,
+        source = source.as_str(),
+        is_grid_critical = ?req.charging_limit.is_grid_critical,
+        schedules = schedules.len(),
+        "V201 NotifyChargingLimit"
+    );
+
+    let now = Utc::now();
+    let limit = ChargingProfile {
+        id: 0,
+        charge_point_id: handler.charge_point_id.clone(),
+        evse_id: req.evse_id.unwrap_or(0),
+        profile_id: schedules.first().map(|s| s.id).unwrap_or(0),
+        stack_level: 0,
+        purpose: "ChargingStationExternalConstraints".to_string(),
+        kind: "Absolute".to_string(),
+        recurrency_kind: None,
+        valid_from: schedules.first().and_then(|s| s.start_schedule),
+        valid_to: None,
+        schedule_json: schedule_json.clone(),
+        is_active: true,
+        limit_source: Some(source.clone()),
+        created_at: now,
+        updated_at: now,
+    };
+
+    if let Err(e) = handler.service.record_external_limit(limit).await {
+        error!(
+            charge_point_id = handler.charge_point_id.as_str(),
+            error = %e,
+            "V201: Failed to store external charging limit"
+        );
+    }
+
+    handler.event_bus.publish(Event::ExternalChargingLimitSet(
+        ExternalChargingLimitEvent {
+            charge_point_id: handler.charge_point_id.clone(),
+            evse_id: req.evse_id,
+            source,
+            is_grid_critical: req.charging_limit.is_grid_critical,
+            schedule_json,
+            timestamp: now,
+        },
+    ));
+
+    Ok(serde_json::to_value(NotifyChargingLimitResponse {}).unwrap_or_default())
+}
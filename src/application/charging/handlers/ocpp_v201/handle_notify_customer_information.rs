@@ -0,0 +1,72 @@
+//! V201 NotifyCustomerInformation handler
+//!
+//! Receives the customer data a station holds, in response to a
+//! CustomerInformation command with `report = true`. Parts are aggregated
+//! in the CustomerInformationStore. The payload itself is personal data and
+//! is never logged.
+
+use rust_ocpp::v2_0_1::messages::notify_customer_information::{
+    NotifyCustomerInformationRequest, NotifyCustomerInformationResponse,
+};
+use serde_json::Value;
+use tracing::{error, info};
+
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::charging::services::customer_information::CustomerDataPart;
+use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
+
+pub async fn handle_notify_customer_information(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
+    let req: NotifyCustomerInformationRequest = match serde_json::from_value(payload.clone()) {
+        Ok(r) => r,
+        Err(e) => {
+            error!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                error = %e,
+                "V201: Failed to parse NotifyCustomerInformation"
+            );
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
+        }
+    };
+
+    let tbc = req.tbc.unwrap_or(false);
+
+    info!(
+        charge_point_id = handler.charge_point_id.as_str(),
+        request_id = req.request_id,
+        seq_no = req.seq_no,
+        data_len = req.data.len(),
+        tbc,
+        "V201 NotifyCustomerInformation"
+    );
+
+    handler.customer_info_store.append_part(
+        &handler.charge_point_id,
+        req.request_id,
+        CustomerDataPart {
+            seq_no: req.seq_no,
+            generated_at: req.generated_at,
+            data: req.data,
+        },
+        tbc,
+    );
+
+    if !tbc {
+        if let Some(report) = handler
+            .customer_info_store
+            .get_report(&handler.charge_point_id, req.request_id)
+        {
+            info!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                request_id = req.request_id,
+                total_parts = report.parts_received,
+                "V201 NotifyCustomerInformation complete — customer data assembled"
+            );
+        }
+    }
+
+    Ok(serde_json::to_value(NotifyCustomerInformationResponse {}).unwrap_or_default())
+}
@@ -0,0 +1,124 @@
+//! V201 NotifyDisplayMessages handler
+//!
+//! Receives the station's display messages in response to a
+//! GetDisplayMessages command (possibly split over several parts) and
+//! reconciles them with the stored message catalogue.
+
+use chrono::Utc;
+use rust_ocpp::v2_0_1::datatypes::message_info_type::MessageInfoType;
+use rust_ocpp::v2_0_1::messages::notify_display_messages::{
+    NotifyDisplayMessagesRequest, NotifyDisplayMessagesResponse,
+};
+use serde_json::Value;
+use tracing::{error, info};
+
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
+use crate::domain::{DisplayMessage, DisplayMessageStatus};
+
+/// Convert a reported MessageInfo into a catalogue entry.
+fn to_display_message(charge_point_id: &str, info: MessageInfoType) -> DisplayMessage {
+    let now = Utc::now();
+    DisplayMessage {
+        id: 0,
+        charge_point_id: charge_point_id.to_string(),
+        message_id: info.id,
+        priority: format!("{:?}", info.priority),
+        state: info.state.map(|s| format!("{:?}", s)),
+        start_date_time: info.start_date_time,
+        end_date_time: info.end_date_time,
+        transaction_id: info.transaction_id,
+        format: format!("{:?}", info.message.format),
+        language: info.message.language,
+        content: info.message.content,
+        status: DisplayMessageStatus::Accepted,
+        status_reason: None,
+        last_reported_at: Some(now),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+pub async fn handle_notify_display_messages(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
+    let req: NotifyDisplayMessagesRequest = match serde_json::from_value(payload.clone()) {
+        Ok(r) => r,
+        Err(e) => {
+            error!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                error = %e,
+                "V201: Failed to parse NotifyDisplayMessages"
+            );
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
+        }
+    };
+
+    let tbc = req.tbc.unwrap_or(false);
+    let messages: Vec<DisplayMessage> = req
+        .message_info
+        .unwrap_or_default()
+        .into_iter()
+        .map(|info| to_display_message(&handler.charge_point_id, info))
+        .collect();
+
+    info!(
+        charge_point_id = handler.charge_point_id.as_str(),
+        request_id = req.request_id,
+        tbc,
+        messages = messages.len(),
+        "V201 NotifyDisplayMessages received ({} message(s){})",
+        messages.len(),
+        if tbc { ", more coming" } else { "" }
+    );
+
+    if let Err(e) = handler
+        .service
+        .record_display_messages(&handler.charge_point_id, req.request_id, messages, tbc)
+        .await
+    {
+        error!(
+            charge_point_id = handler.charge_point_id.as_str(),
+            error = %e,
+            "V201: Failed to store reported display messages"
+        );
+    }
+
+    Ok(serde_json::to_value(NotifyDisplayMessagesResponse {}).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_ocpp::v2_0_1::datatypes::message_content_type::MessageContentType;
+    use rust_ocpp::v2_0_1::enumerations::message_format_enum_type::MessageFormatEnumType;
+    use rust_ocpp::v2_0_1::enumerations::message_priority_enum_type::MessagePriorityEnumType;
+    use rust_ocpp::v2_0_1::enumerations::message_state_enum_type::MessageStateEnumType;
+
+    use super::*;
+
+    #[test]
+    fn reported_message_maps_to_catalogue_entry() {
+        let info = MessageInfoType {
+            id: 3,
+            priority: MessagePriorityEnumType::InFront,
+            state: Some(MessageStateEnumType::Charging),
+            transaction_id: Some("tx-1".to_string()),
+            message: MessageContentType {
+                format: MessageFormatEnumType::UTF8,
+                language: Some("uz".to_string()),
+                content: "Xush kelibsiz".to_string(),
+            },
+            ..Default::default()
+        };
+
+        let msg = to_display_message("CP001", info);
+        assert_eq!(msg.message_id, 3);
+        assert_eq!(msg.priority, "InFront");
+        assert_eq!(msg.state.as_deref(), Some("Charging"));
+        assert_eq!(msg.format, "UTF8");
+        assert_eq!(msg.status, DisplayMessageStatus::Accepted);
+        assert!(msg.last_reported_at.is_some());
+    }
+}
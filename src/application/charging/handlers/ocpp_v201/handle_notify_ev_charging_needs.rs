@@ -0,0 +1,112 @@
+//! V201 NotifyEVChargingNeeds handler
+//!
+//! An ISO 15118 EV reports how much energy it needs, when it leaves and
+//! what it can take. The needs are stored against the running transaction
+//! and answered with a TxProfile computed in the background.
+
+use chrono::Utc;
+use rust_ocpp::v2_0_1::enumerations::notify_ev_charging_needs_status_enum_type::NotifyEVChargingNeedsStatusEnumType;
+use rust_ocpp::v2_0_1::messages::notify_ev_charging_needs::{
+    NotifyEVChargingNeedsRequest, NotifyEVChargingNeedsResponse,
+};
+use serde_json::Value;
+use tracing::{error, info};
+
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::OcppHandlerV201;
+use crate::domain::{EvChargingNeeds, OcppVersion};
+
+pub async fn handle_notify_ev_charging_needs(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
+    let req: NotifyEVChargingNeedsRequest = match serde_json::from_value(payload.clone()) {
+        Ok(r) => r,
+        Err(e) => {
+            error!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                error = %e,
+                "V201: Failed to parse NotifyEVChargingNeeds"
+            );
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
+        }
+    };
+
+    let needs = &req.charging_needs;
+    let energy_transfer_mode = serde_json::to_value(&needs.requested_energy_transfer)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    let ac = needs.ac_charging_parameters.as_ref();
+    let dc = needs.dc_charging_parameters.as_ref();
+
+    info!(
+        charge_point_id = handler.charge_point_id.as_str(),
+        evse_id = req.evse_id,
+        energy_transfer_mode = energy_transfer_mode.as_str(),
+        departure_time = ?needs.departure_time,
+        "V201 NotifyEVChargingNeeds"
+    );
+
+    let transaction_id = handler
+        .service
+        .get_active_transaction_for_connector(&handler.charge_point_id, req.evse_id as u32)
+        .await
+        .ok()
+        .flatten()
+        .map(|tx| tx.id);
+
+    let now = Utc::now();
+    let record = EvChargingNeeds {
+        id: 0,
+        charge_point_id: handler.charge_point_id.clone(),
+        evse_id: req.evse_id,
+        transaction_id,
+        energy_transfer_mode,
+        departure_time: needs.departure_time,
+        energy_amount_wh: ac
+            .map(|p| p.energy_amount)
+            .or_else(|| dc.and_then(|p| p.energy_amount)),
+        ev_max_current: ac
+            .map(|p| p.ev_max_current)
+            .or_else(|| dc.map(|p| p.ev_max_current))
+            .unwrap_or_default(),
+        ev_max_voltage: ac
+            .map(|p| p.ev_max_voltage)
+            .or_else(|| dc.map(|p| p.ev_max_voltage))
+            .unwrap_or_default(),
+        ev_max_power: dc.and_then(|p| p.ev_max_power),
+        state_of_charge: dc.and_then(|p| p.state_of_charge).map(i32::from),
+        max_schedule_tuples: req.max_schedule_tuples,
+        profile_id: None,
+        profile_status: None,
+        schedule_json: None,
+        ev_schedule_json: None,
+        created_at: now,
+        updated_at: now,
+    };
+
+    let status = match handler.smart_charging.record_needs(record).await {
+        Ok(saved) if handler.smart_charging.is_enabled() => {
+            let smart_charging = handler.smart_charging.clone();
+            tokio::spawn(async move { smart_charging.apply(saved).await });
+            NotifyEVChargingNeedsStatusEnumType::Accepted
+        }
+        Ok(_) => NotifyEVChargingNeedsStatusEnumType::Rejected,
+        Err(e) => {
+            error!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                error = %e,
+                "V201: Failed to store EV charging needs"
+            );
+            NotifyEVChargingNeedsStatusEnumType::Rejected
+        }
+    };
+
+    let response = NotifyEVChargingNeedsResponse {
+        status,
+        status_info: None,
+    };
+
+    Ok(serde_json::to_value(&response).unwrap_or_default())
+}
@@ -0,0 +1,73 @@
+//! V201 NotifyEVChargingSchedule handler
+//!
+//! After receiving a TxProfile the EV plans its own schedule within it and
+//! the station forwards that plan. It is stored with the charging needs it
+//! answers.
+
+use rust_ocpp::v2_0_1::enumerations::generic_status_enum_type::GenericStatusEnumType;
+use rust_ocpp::v2_0_1::messages::notify_ev_charging_schedule::{
+    NotifyEVChargingScheduleRequest, NotifyEVChargingScheduleResponse,
+};
+use serde_json::Value;
+use tracing::{error, info, warn};
+
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
+
+pub async fn handle_notify_ev_charging_schedule(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
+    let req: NotifyEVChargingScheduleRequest = match serde_json::from_value(payload.clone()) {
+        Ok(r) => r,
+        Err(e) => {
+            error!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                error = %e,
+                "V201: Failed to parse NotifyEVChargingSchedule"
+            );
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
+        }
+    };
+
+    info!(
+        charge_point_id = handler.charge_point_id.as_str(),
+        evse_id = req.evse_id,
+        time_base = %req.time_base,
+        periods = req.charging_schedule.charging_schedule_period.len(),
+        "V201 NotifyEVChargingSchedule"
+    );
+
+    let schedule = serde_json::to_value(&req.charging_schedule).unwrap_or_default();
+    let status = match handler
+        .smart_charging
+        .record_ev_schedule(&handler.charge_point_id, req.evse_id, &schedule)
+        .await
+    {
+        Ok(true) => GenericStatusEnumType::Accepted,
+        Ok(false) => {
+            warn!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                evse_id = req.evse_id,
+                "V201: EV charging schedule without charging needs"
+            );
+            GenericStatusEnumType::Rejected
+        }
+        Err(e) => {
+            error!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                error = %e,
+                "V201: Failed to store EV charging schedule"
+            );
+            GenericStatusEnumType::Rejected
+        }
+    };
+
+    let response = NotifyEVChargingScheduleResponse {
+        status,
+        status_info: None,
+    };
+
+    Ok(serde_json::to_value(&response).unwrap_or_default())
+}
@@ -8,10 +8,15 @@ use rust_ocpp::v2_0_1::messages::notify_event::{NotifyEventRequest, NotifyEventR
 use serde_json::Value;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::events::{DeviceAlertEvent, Event};
 use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
 
-pub async fn handle_notify_event(handler: &OcppHandlerV201, payload: &Value) -> Value {
+pub async fn handle_notify_event(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     let req: NotifyEventRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
@@ -20,7 +25,7 @@ pub async fn handle_notify_event(handler: &OcppHandlerV201, payload: &Value) ->
                 error = %e,
                 "V201: Failed to parse NotifyEvent"
             );
-            return serde_json::to_value(NotifyEventResponse {}).unwrap_or_default();
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
         }
     };
 
@@ -88,7 +93,7 @@ pub async fn handle_notify_event(handler: &OcppHandlerV201, payload: &Value) ->
             .publish(Event::DeviceAlert(alert));
     }
 
-    serde_json::to_value(NotifyEventResponse {}).unwrap_or_default()
+    Ok(serde_json::to_value(NotifyEventResponse {}).unwrap_or_default())
 }
 
 #[cfg(test)]
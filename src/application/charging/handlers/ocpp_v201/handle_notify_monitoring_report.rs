@@ -1,21 +1,62 @@
 //! V201 NotifyMonitoringReport handler
 //!
 //! Receives monitoring configuration reports from charge points in response
-//! to GetMonitoringReport or similar CSMS-initiated requests.
-//! Currently logs the data; a dedicated monitoring store could be added later.
+//! to GetMonitoringReport (possibly split over several parts) and stores the
+//! reported monitors, so the CSMS knows which monitors are actually active.
 
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_ocpp::v2_0_1::datatypes::monitoring_data_type::MonitoringDataType;
 use rust_ocpp::v2_0_1::messages::notify_monitoring_report::{
     NotifyMonitoringReportRequest, NotifyMonitoringReportResponse,
 };
 use serde_json::Value;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
+use crate::domain::VariableMonitor;
+
+/// Flatten reported MonitoringData into one entry per monitor.
+fn to_variable_monitors(
+    charge_point_id: &str,
+    request_id: i32,
+    generated_at: DateTime<Utc>,
+    data: MonitoringDataType,
+) -> Vec<VariableMonitor> {
+    let now = Utc::now();
+    let evse_id = data.component.evse.as_ref().map(|e| e.id);
+    let connector_id = data.component.evse.as_ref().and_then(|e| e.connector_id);
+
+    data.variable_monitoring
+        .into_iter()
+        .map(|vm| VariableMonitor {
+            id: 0,
+            charge_point_id: charge_point_id.to_string(),
+            monitor_id: vm.id,
+            component: data.component.name.clone(),
+            component_instance: data.component.instance.clone(),
+            evse_id,
+            connector_id,
+            variable: data.variable.name.clone(),
+            variable_instance: data.variable.instance.clone(),
+            monitor_type: format!("{:?}", vm.kind),
+            value: vm.value.to_f64().unwrap_or_default(),
+            severity: vm.severity as i32,
+            transaction: vm.transaction,
+            request_id,
+            last_reported_at: generated_at,
+            created_at: now,
+            updated_at: now,
+        })
+        .collect()
+}
 
 pub async fn handle_notify_monitoring_report(
     handler: &OcppHandlerV201,
     payload: &Value,
-) -> Value {
+) -> Result<Value, PayloadViolation> {
     let req: NotifyMonitoringReportRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
@@ -24,64 +65,55 @@ pub async fn handle_notify_monitoring_report(
                 error = %e,
                 "V201: Failed to parse NotifyMonitoringReport"
             );
-            return serde_json::to_value(NotifyMonitoringReportResponse {}).unwrap_or_default();
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
         }
     };
 
     let tbc = req.tbc.unwrap_or(false);
-    let monitor_count = req.monitor.as_ref().map(|v| v.len()).unwrap_or(0);
+    let monitors: Vec<VariableMonitor> = req
+        .monitor
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|md| {
+            to_variable_monitors(&handler.charge_point_id, req.request_id, req.generated_at, md)
+        })
+        .collect();
 
     info!(
         charge_point_id = handler.charge_point_id.as_str(),
         request_id = req.request_id,
         seq_no = req.seq_no,
         tbc,
-        monitors = monitor_count,
+        monitors = monitors.len(),
         "V201 NotifyMonitoringReport received (part {}{})",
         req.seq_no,
         if tbc { ", more coming" } else { ", final" }
     );
 
-    if let Some(monitors) = &req.monitor {
-        for md in monitors {
-            let component = &md.component.name;
-            let variable = &md.variable.name;
-            let monitor_count_inner = md.variable_monitoring.len();
-
-            info!(
-                charge_point_id = handler.charge_point_id.as_str(),
-                component,
-                variable,
-                monitors = monitor_count_inner,
-                "Monitoring data: {} / {} — {} monitor(s)",
-                component,
-                variable,
-                monitor_count_inner
-            );
-
-            for vm in &md.variable_monitoring {
-                info!(
-                    charge_point_id = handler.charge_point_id.as_str(),
-                    monitor_id = vm.id,
-                    transaction = vm.transaction,
-                    value = %vm.value,
-                    monitor_type = ?vm.kind,
-                    severity = vm.severity,
-                    "  Monitor: id={}, type={:?}, value={}, severity={}",
-                    vm.id,
-                    vm.kind,
-                    vm.value,
-                    vm.severity
-                );
-            }
-        }
+    if let Err(e) = handler
+        .service
+        .record_monitoring_report(&handler.charge_point_id, req.request_id, monitors, tbc)
+        .await
+    {
+        error!(
+            charge_point_id = handler.charge_point_id.as_str(),
+            error = %e,
+            "V201: Failed to store reported variable monitors"
+        );
     }
 
-    serde_json::to_value(NotifyMonitoringReportResponse {}).unwrap_or_default()
+    Ok(serde_json::to_value(NotifyMonitoringReportResponse {}).unwrap_or_default())
 }
 
 #[cfg(test)]
 mod tests {
+    use rust_decimal::Decimal;
+    use rust_ocpp::v2_0_1::datatypes::component_type::ComponentType;
+    use rust_ocpp::v2_0_1::datatypes::evse_type::EVSEType;
+    use rust_ocpp::v2_0_1::datatypes::variable_monitoring_type::VariableMonitoringType;
+    use rust_ocpp::v2_0_1::datatypes::variable_type::VariableType;
+    use rust_ocpp::v2_0_1::enumerations::monitor_enum_type::MonitorEnumType;
+
     use super::*;
 
     #[test]
@@ -90,4 +122,55 @@ mod tests {
         let json = serde_json::to_value(&resp).unwrap();
         assert_eq!(json, serde_json::json!({}));
     }
+
+    #[test]
+    fn monitoring_data_is_flattened_per_monitor() {
+        let data = MonitoringDataType {
+            component: ComponentType {
+                name: "EVSE".to_string(),
+                instance: None,
+                evse: Some(EVSEType {
+                    id: 1,
+                    connector_id: Some(2),
+                }),
+            },
+            variable: VariableType {
+                name: "Temperature".to_string(),
+                instance: None,
+            },
+            variable_monitoring: vec![
+                VariableMonitoringType {
+                    id: 10,
+                    transaction: false,
+                    value: Decimal::new(605, 1),
+                    kind: MonitorEnumType::UpperThreshold,
+                    severity: 2,
+                },
+                VariableMonitoringType {
+                    id: 11,
+                    transaction: true,
+                    value: Decimal::new(300, 0),
+                    kind: MonitorEnumType::Periodic,
+                    severity: 8,
+                },
+            ],
+        };
+
+        let generated_at = Utc::now();
+        let monitors = to_variable_monitors("CP001", 42, generated_at, data);
+
+        assert_eq!(monitors.len(), 2);
+        assert_eq!(monitors[0].monitor_id, 10);
+        assert_eq!(monitors[0].component, "EVSE");
+        assert_eq!(monitors[0].evse_id, Some(1));
+        assert_eq!(monitors[0].connector_id, Some(2));
+        assert_eq!(monitors[0].variable, "Temperature");
+        assert_eq!(monitors[0].monitor_type, "UpperThreshold");
+        assert_eq!(monitors[0].value, 60.5);
+        assert_eq!(monitors[0].severity, 2);
+        assert_eq!(monitors[0].request_id, 42);
+        assert_eq!(monitors[0].last_reported_at, generated_at);
+        assert_eq!(monitors[1].monitor_type, "Periodic");
+        assert!(monitors[1].transaction);
+    }
 }
@@ -7,15 +7,17 @@ use rust_ocpp::v2_0_1::messages::notify_report::{NotifyReportRequest, NotifyRepo
 use serde_json::Value;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::charging::services::device_report::{
     ReportVariable, VariableAttributeEntry,
 };
 use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
 
 pub async fn handle_notify_report(
     handler: &OcppHandlerV201,
     payload: &Value,
-) -> Value {
+) -> Result<Value, PayloadViolation> {
     let req: NotifyReportRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
@@ -24,7 +26,7 @@ pub async fn handle_notify_report(
                 error = %e,
                 "V201: Failed to parse NotifyReport"
             );
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
         }
     };
 
@@ -106,5 +108,5 @@ pub async fn handle_notify_report(
         }
     }
 
-    serde_json::to_value(&NotifyReportResponse {}).unwrap_or_default()
+    Ok(serde_json::to_value(&NotifyReportResponse {}).unwrap_or_default())
 }
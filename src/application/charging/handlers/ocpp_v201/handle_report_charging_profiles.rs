@@ -9,12 +9,14 @@ use rust_ocpp::v2_0_1::messages::report_charging_profiles::{
 use serde_json::Value;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
 
 pub async fn handle_report_charging_profiles(
     handler: &OcppHandlerV201,
     payload: &Value,
-) -> Value {
+) -> Result<Value, PayloadViolation> {
     let req: ReportChargingProfilesRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
@@ -23,7 +25,7 @@ pub async fn handle_report_charging_profiles(
                 error = %e,
                 "V201: Failed to parse ReportChargingProfiles"
             );
-            return serde_json::to_value(ReportChargingProfilesResponse {}).unwrap_or_default();
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
         }
     };
 
@@ -58,7 +60,7 @@ pub async fn handle_report_charging_profiles(
         );
     }
 
-    serde_json::to_value(ReportChargingProfilesResponse {}).unwrap_or_default()
+    Ok(serde_json::to_value(ReportChargingProfilesResponse {}).unwrap_or_default())
 }
 
 #[cfg(test)]
@@ -0,0 +1,66 @@
+//! V201 ReservationStatusUpdate handler
+//!
+//! The station reports that a reservation ended without being used: it
+//! expired on the station, or was removed (e.g. the EVSE became faulted).
+
+use rust_ocpp::v2_0_1::enumerations::reservation_update_status_enum_type::ReservationUpdateStatusEnumType;
+use rust_ocpp::v2_0_1::messages::reservation_status_update::{
+    ReservationStatusUpdateRequest, ReservationStatusUpdateResponse,
+};
+use serde_json::Value;
+use tracing::{error, info, warn};
+
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::events::{Event, ReservationStatusChangedEvent};
+use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
+
+pub async fn handle_reservation_status_update(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
+    let req: ReservationStatusUpdateRequest = match serde_json::from_value(payload.clone()) {
+        Ok(r) => r,
+        Err(e) => {
+            error!(
+                charge_point_id = handler.charge_point_id.as_str(),
+                error = %e,
+                "V201: Failed to parse ReservationStatusUpdate"
+            );
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
+        }
+    };
+
+    info!(
+        charge_point_id = handler.charge_point_id.as_str(),
+        reservation_id = req.reservation_id,
+        status = ?req.reservation_update_status,
+        "V201 ReservationStatusUpdate"
+    );
+
+    let expired = matches!(
+        req.reservation_update_status,
+        ReservationUpdateStatusEnumType::Expired
+    );
+    match handler
+        .service
+        .end_reservation(&handler.charge_point_id, req.reservation_id, expired)
+        .await
+    {
+        Ok(Some(reservation)) => handler.event_bus.publish(Event::ReservationStatusChanged(
+            ReservationStatusChangedEvent::from_reservation(&reservation),
+        )),
+        Ok(None) => warn!(
+            charge_point_id = handler.charge_point_id.as_str(),
+            reservation_id = req.reservation_id,
+            "V201: ReservationStatusUpdate for unknown or inactive reservation"
+        ),
+        Err(e) => error!(
+            charge_point_id = handler.charge_point_id.as_str(),
+            error = %e,
+            "V201: Failed to update reservation"
+        ),
+    }
+
+    Ok(serde_json::to_value(ReservationStatusUpdateResponse {}).unwrap_or_default())
+}
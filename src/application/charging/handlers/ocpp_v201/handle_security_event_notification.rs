@@ -8,12 +8,14 @@ use rust_ocpp::v2_0_1::messages::security_event_notification::{
 use serde_json::Value;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
 
 pub async fn handle_security_event_notification(
     handler: &OcppHandlerV201,
     payload: &Value,
-) -> Value {
+) -> Result<Value, PayloadViolation> {
     let req: SecurityEventNotificationRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
@@ -22,7 +24,7 @@ pub async fn handle_security_event_notification(
                 error = %e,
                 "V201: Failed to parse SecurityEventNotification"
             );
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
         }
     };
 
@@ -34,5 +36,5 @@ pub async fn handle_security_event_notification(
         "V201 SecurityEventNotification"
     );
 
-    serde_json::to_value(&SecurityEventNotificationResponse {}).unwrap_or_default()
+    Ok(serde_json::to_value(&SecurityEventNotificationResponse {}).unwrap_or_default())
 }
@@ -10,11 +10,16 @@ use rust_ocpp::v2_0_1::messages::status_notification::{
 use serde_json::Value;
 use tracing::{error, info};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::events::{ConnectorStatusChangedEvent, Event};
 use crate::application::OcppHandlerV201;
 use crate::domain::ConnectorStatus;
+use crate::domain::OcppVersion;
 
-pub async fn handle_status_notification(handler: &OcppHandlerV201, payload: &Value) -> Value {
+pub async fn handle_status_notification(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     let req: StatusNotificationRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
@@ -23,7 +28,7 @@ pub async fn handle_status_notification(handler: &OcppHandlerV201, payload: &Val
                 error = %e,
                 "V201: Failed to parse StatusNotification"
             );
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
         }
     };
 
@@ -66,8 +71,10 @@ pub async fn handle_status_notification(handler: &OcppHandlerV201, payload: &Val
             status: format!("{:?}", req.connector_status),
             error_code: None,
             info: None,
+            vendor_id: None,
+            vendor_error_code: None,
             timestamp: req.timestamp,
         }));
 
-    serde_json::to_value(&StatusNotificationResponse {}).unwrap_or_default()
+    Ok(serde_json::to_value(&StatusNotificationResponse {}).unwrap_or_default())
 }
@@ -18,13 +18,19 @@ use rust_ocpp::v2_0_1::messages::transaction_event::{
 use serde_json::Value;
 use tracing::{error, info, warn};
 
+use crate::application::charging::schema::PayloadViolation;
+use crate::application::charging::services::ReservationCheck;
 use crate::application::events::{
-    Event, MeterValuesEvent, TransactionBilledEvent, TransactionStartedEvent,
-    TransactionStoppedEvent,
+    Event, MeterValuesEvent, ReservationStatusChangedEvent, TransactionBilledEvent,
+    TransactionStartedEvent, TransactionStoppedEvent,
 };
 use crate::application::OcppHandlerV201;
+use crate::domain::OcppVersion;
 
-pub async fn handle_transaction_event(handler: &OcppHandlerV201, payload: &Value) -> Value {
+pub async fn handle_transaction_event(
+    handler: &OcppHandlerV201,
+    payload: &Value,
+) -> Result<Value, PayloadViolation> {
     let req: TransactionEventRequest = match serde_json::from_value(payload.clone()) {
         Ok(r) => r,
         Err(e) => {
@@ -33,7 +39,7 @@ pub async fn handle_transaction_event(handler: &OcppHandlerV201, payload: &Value
                 error = %e,
                 "V201: Failed to parse TransactionEvent"
             );
-            return serde_json::json!({});
+            return Err(PayloadViolation::from_serde(OcppVersion::V201, &e));
         }
     };
 
@@ -54,18 +60,29 @@ pub async fn handle_transaction_event(handler: &OcppHandlerV201, payload: &Value
         "V201 TransactionEvent"
     );
 
+    // TxProfiles sent for EV charging needs address the station's transaction id
+    if req.event_type == TransactionEventEnumType::Ended {
+        handler
+            .smart_charging
+            .forget_transaction(&handler.charge_point_id, evse_id);
+    } else {
+        handler
+            .smart_charging
+            .track_transaction(&handler.charge_point_id, evse_id, tx_id_str);
+    }
+
     // Extract meter values if present
     let (energy_wh, power_w, soc) = extract_meter_values(&req);
 
     match req.event_type {
         TransactionEventEnumType::Started => {
-            handle_started(handler, &req, evse_id as u32, &id_tag, energy_wh).await
+            Ok(handle_started(handler, &req, evse_id as u32, &id_tag, energy_wh).await)
         }
         TransactionEventEnumType::Updated => {
-            handle_updated(handler, &req, evse_id as u32, energy_wh, power_w, soc).await
+            Ok(handle_updated(handler, &req, evse_id as u32, energy_wh, power_w, soc).await)
         }
         TransactionEventEnumType::Ended => {
-            handle_ended(handler, &req, evse_id as u32, &id_tag, energy_wh).await
+            Ok(handle_ended(handler, &req, evse_id as u32, &id_tag, energy_wh).await)
         }
     }
 }
@@ -91,6 +108,36 @@ async fn handle_started(
         return build_response(Some(AuthorizationStatusEnumType::Invalid));
     }
 
+    let reservation = if id_tag.is_empty() {
+        ReservationCheck::Free
+    } else {
+        match handler
+            .service
+            .check_reservation(&handler.charge_point_id, evse_id, id_tag, req.reservation_id)
+            .await
+        {
+            Ok(check) => check,
+            Err(e) => {
+                error!(
+                    charge_point_id = handler.charge_point_id.as_str(),
+                    error = %e,
+                    "V201: Failed to check reservations"
+                );
+                ReservationCheck::Free
+            }
+        }
+    };
+
+    if let ReservationCheck::Blocked(r) = &reservation {
+        warn!(
+            charge_point_id = handler.charge_point_id.as_str(),
+            evse_id,
+            reservation_id = r.id,
+            "V201: Transaction rejected: EVSE reserved for another token"
+        );
+        return build_response(Some(AuthorizationStatusEnumType::Invalid));
+    }
+
     match handler
         .service
         .start_transaction(
@@ -98,6 +145,7 @@ async fn handle_started(
             evse_id,
             if id_tag.is_empty() { "unknown" } else { id_tag },
             meter_start,
+            Some(&req.transaction_info.transaction_id),
         )
         .await
     {
@@ -113,6 +161,19 @@ async fn handle_started(
                     timestamp: req.timestamp,
                 }));
 
+            if let ReservationCheck::Held(r) = reservation {
+                match handler.service.use_reservation(r).await {
+                    Ok(used) => handler.event_bus.publish(Event::ReservationStatusChanged(
+                        ReservationStatusChangedEvent::from_reservation(&used),
+                    )),
+                    Err(e) => error!(
+                        charge_point_id = handler.charge_point_id.as_str(),
+                        error = %e,
+                        "V201: Failed to mark reservation used"
+                    ),
+                }
+            }
+
             build_response(Some(AuthorizationStatusEnumType::Accepted))
         }
         Err(e) => {
@@ -7,32 +7,51 @@
 use serde_json::Value;
 use tracing::{error, warn};
 
+use crate::application::charging::schema::PayloadViolation;
 use crate::application::OcppHandlerV201;
 
 mod handle_authorize;
 mod handle_boot_notification;
+mod handle_cleared_charging_limit;
 mod handle_data_transfer;
 mod handle_firmware_status_notification;
+mod handle_get_15118ev_certificate;
+mod handle_get_certificate_status;
 mod handle_heartbeat;
 mod handle_meter_values;
+mod handle_notify_charging_limit;
+mod handle_notify_customer_information;
+mod handle_notify_display_messages;
+mod handle_notify_ev_charging_needs;
+mod handle_notify_ev_charging_schedule;
 mod handle_notify_event;
 mod handle_notify_monitoring_report;
 mod handle_notify_report;
 mod handle_report_charging_profiles;
+mod handle_reservation_status_update;
 mod handle_security_event_notification;
 mod handle_status_notification;
 mod handle_transaction_event;
 
 pub use handle_authorize::handle_authorize;
 pub use handle_boot_notification::handle_boot_notification;
+pub use handle_cleared_charging_limit::handle_cleared_charging_limit;
 pub use handle_data_transfer::handle_data_transfer;
 pub use handle_firmware_status_notification::handle_firmware_status_notification;
+pub use handle_get_15118ev_certificate::handle_get_15118ev_certificate;
+pub use handle_get_certificate_status::handle_get_certificate_status;
 pub use handle_heartbeat::handle_heartbeat;
 pub use handle_meter_values::handle_meter_values;
+pub use handle_notify_charging_limit::handle_notify_charging_limit;
+pub use handle_notify_customer_information::handle_notify_customer_information;
+pub use handle_notify_display_messages::handle_notify_display_messages;
+pub use handle_notify_ev_charging_needs::handle_notify_ev_charging_needs;
+pub use handle_notify_ev_charging_schedule::handle_notify_ev_charging_schedule;
 pub use handle_notify_event::handle_notify_event;
 pub use handle_notify_monitoring_report::handle_notify_monitoring_report;
 pub use handle_notify_report::handle_notify_report;
 pub use handle_report_charging_profiles::handle_report_charging_profiles;
+pub use handle_reservation_status_update::handle_reservation_status_update;
 pub use handle_security_event_notification::handle_security_event_notification;
 pub use handle_status_notification::handle_status_notification;
 pub use handle_transaction_event::handle_transaction_event;
@@ -43,23 +62,33 @@ pub use handle_transaction_event::handle_transaction_event;
 /// `payload` is the raw JSON payload. Each handler deserializes it
 /// into the appropriate `rust_ocpp::v2_0_1` request type.
 ///
-/// Returns a `serde_json::Value` representing the response payload.
+/// Returns the response payload, or the CallError to answer with when the
+/// payload does not deserialize or the action is not accepted from a charge point.
 pub async fn v201_action_matcher(
     handler: &OcppHandlerV201,
     action: &str,
     payload: &Value,
-) -> Value {
+) -> Result<Value, PayloadViolation> {
     match action {
         "Authorize" => handle_authorize(handler, payload).await,
         "BootNotification" => handle_boot_notification(handler, payload).await,
+        "ClearedChargingLimit" => handle_cleared_charging_limit(handler, payload).await,
         "DataTransfer" => handle_data_transfer(handler, payload).await,
         "FirmwareStatusNotification" => handle_firmware_status_notification(handler, payload).await,
+        "Get15118EVCertificate" => handle_get_15118ev_certificate(handler, payload).await,
+        "GetCertificateStatus" => handle_get_certificate_status(handler, payload).await,
         "Heartbeat" => handle_heartbeat(handler, payload).await,
         "MeterValues" => handle_meter_values(handler, payload).await,
+        "NotifyChargingLimit" => handle_notify_charging_limit(handler, payload).await,
+        "NotifyCustomerInformation" => handle_notify_customer_information(handler, payload).await,
+        "NotifyDisplayMessages" => handle_notify_display_messages(handler, payload).await,
+        "NotifyEVChargingNeeds" => handle_notify_ev_charging_needs(handler, payload).await,
+        "NotifyEVChargingSchedule" => handle_notify_ev_charging_schedule(handler, payload).await,
         "NotifyEvent" => handle_notify_event(handler, payload).await,
         "NotifyMonitoringReport" => handle_notify_monitoring_report(handler, payload).await,
         "NotifyReport" => handle_notify_report(handler, payload).await,
         "ReportChargingProfiles" => handle_report_charging_profiles(handler, payload).await,
+        "ReservationStatusUpdate" => handle_reservation_status_update(handler, payload).await,
         "SecurityEventNotification" => handle_security_event_notification(handler, payload).await,
         "StatusNotification" => handle_status_notification(handler, payload).await,
         "TransactionEvent" => handle_transaction_event(handler, payload).await,
@@ -71,14 +100,15 @@ pub async fn v201_action_matcher(
                     action = unknown,
                     "V201: Received CSMS→CS action from charging station (protocol error)"
                 );
+                Err(PayloadViolation::not_supported(unknown))
             } else {
                 error!(
                     charge_point_id = handler.charge_point_id.as_str(),
                     action = unknown,
                     "Unknown OCPP 2.0.1 action"
                 );
+                Err(PayloadViolation::not_implemented(unknown))
             }
-            serde_json::json!({})
         }
     }
 }
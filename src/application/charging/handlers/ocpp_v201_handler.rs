@@ -1,7 +1,8 @@
 //! OCPP 2.0.1 message handler
 //!
-//! Parses raw OCPP-J frames, dispatches to action handlers,
-//! and serializes responses using `rust_ocpp::v2_0_1` types.
+//! Parses raw OCPP-J frames, validates Call payloads against the bundled
+//! schemas, dispatches to action handlers, and serializes responses using
+//! `rust_ocpp::v2_0_1` types.
 
 use std::sync::Arc;
 
@@ -12,11 +13,17 @@ use crate::{
     application::{
         charging::ocpp_v201::v201_action_matcher, BillingService, ChargePointService, CommandSender,
     },
+    domain::OcppVersion,
     shared::ocpp_frame::OcppFrame,
     SharedEventBus,
 };
 
+use crate::application::charging::schema::SharedSchemaValidator;
+use crate::application::charging::services::data_transfer::SharedDataTransferService;
+use crate::application::charging::services::customer_information::SharedCustomerInformationStore;
 use crate::application::charging::services::device_report::SharedDeviceReportStore;
+use crate::application::charging::services::plug_and_charge::SharedPlugAndChargeService;
+use crate::application::charging::services::smart_charging::SharedSmartChargingService;
 
 /// Handler for OCPP 2.0.1 messages
 pub struct OcppHandlerV201 {
@@ -25,10 +32,16 @@ pub struct OcppHandlerV201 {
     pub billing_service: Arc<BillingService>,
     pub command_sender: Arc<CommandSender>,
     pub event_bus: SharedEventBus,
+    pub schema_validator: SharedSchemaValidator,
     pub report_store: SharedDeviceReportStore,
+    pub customer_info_store: SharedCustomerInformationStore,
+    pub data_transfers: SharedDataTransferService,
+    pub plug_and_charge: SharedPlugAndChargeService,
+    pub smart_charging: SharedSmartChargingService,
 }
 
 impl OcppHandlerV201 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         charge_point_id: impl Into<String>,
         service: Arc<ChargePointService>,
@@ -36,6 +49,11 @@ impl OcppHandlerV201 {
         command_sender: Arc<CommandSender>,
         event_bus: SharedEventBus,
         report_store: SharedDeviceReportStore,
+        customer_info_store: SharedCustomerInformationStore,
+        schema_validator: SharedSchemaValidator,
+        data_transfers: SharedDataTransferService,
+        plug_and_charge: SharedPlugAndChargeService,
+        smart_charging: SharedSmartChargingService,
     ) -> Self {
         Self {
             charge_point_id: charge_point_id.into(),
@@ -44,6 +62,11 @@ impl OcppHandlerV201 {
             command_sender,
             event_bus,
             report_store,
+            customer_info_store,
+            schema_validator,
+            data_transfers,
+            plug_and_charge,
+            smart_charging,
         }
     }
 
@@ -155,11 +178,28 @@ impl OcppHandlerV201 {
             action, "V201 received Call"
         );
 
-        let response_payload = v201_action_matcher(self, action, &payload).await;
+        let result = match self
+            .schema_validator
+            .check_inbound(OcppVersion::V201, action, &payload)
+        {
+            Ok(()) => v201_action_matcher(self, action, &payload).await,
+            Err(violation) => Err(violation),
+        };
 
-        let response = OcppFrame::CallResult {
-            unique_id: unique_id.to_string(),
-            payload: response_payload,
+        let response = match result {
+            Ok(response_payload) => OcppFrame::CallResult {
+                unique_id: unique_id.to_string(),
+                payload: response_payload,
+            },
+            Err(violation) => {
+                warn!(
+                    charge_point_id = self.charge_point_id.as_str(),
+                    action,
+                    error_code = violation.error_code,
+                    "V201 Rejected Call: {}", violation.description
+                );
+                violation.to_frame(unique_id)
+            }
         };
 
         Some(response.serialize())
@@ -1,5 +1,7 @@
 pub mod commands;
 pub mod handlers;
+pub mod quirks;
+pub mod schema;
 pub mod services;
 pub mod session;
 
@@ -0,0 +1,451 @@
+//! Vendor quirk profiles
+//!
+//! Workarounds for OCPP 1.6 charge points that do not follow the
+//! specification, applied to incoming payloads before schema validation
+//! and deserialization. `OcppHandlerV16::sanitize_and_parse` still repairs
+//! broken frames for every station; a profile goes further, for the
+//! stations it is assigned to:
+//!
+//! - timestamps in a vendor-specific format instead of RFC 3339
+//! - energy readings in kWh where Wh is implied
+//! - MeterValues without a `transactionId` during a transaction
+//! - enum values in the wrong case (`"charging"` for `"Charging"`)
+//! - DataTransfer `data` sent as JSON instead of a string
+//!
+//! Profiles come from `[[ocpp.quirks]]` in the configuration. A profile
+//! listing the charge point id wins; otherwise the first profile whose
+//! vendor/model match the station's BootNotification applies.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
+use dashmap::DashMap;
+use serde_json::Value;
+use tracing::info;
+
+use crate::application::charging::schema::canonicalize_enums;
+use crate::domain::OcppVersion;
+
+/// Status values of OCPP 1.6 confirmations, for case repair of CallResults.
+const CONFIRMATION_STATUSES: &[&str] = &[
+    "Accepted",
+    "Blocked",
+    "ConcurrentTx",
+    "Expired",
+    "Failed",
+    "Faulted",
+    "Invalid",
+    "NotImplemented",
+    "NotSupported",
+    "Occupied",
+    "RebootRequired",
+    "Rejected",
+    "Scheduled",
+    "Unavailable",
+    "Unknown",
+    "UnknownMessageId",
+    "UnknownVendorId",
+    "UnlockFailed",
+    "Unlocked",
+    "VersionMismatch",
+];
+
+/// Unit a station reports energy readings in when it omits one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnergyUnit {
+    /// Watt-hours, as the specification prescribes
+    #[default]
+    Wh,
+    /// Kilowatt-hours: unit-less energy samples and `meterStart` /
+    /// `meterStop` are converted
+    KWh,
+}
+
+impl EnergyUnit {
+    /// Parse from the config string; anything but `kWh` means Wh.
+    pub fn from_config(value: &str) -> Self {
+        if value.eq_ignore_ascii_case("kwh") {
+            Self::KWh
+        } else {
+            Self::Wh
+        }
+    }
+}
+
+/// Workarounds for one family of charge points.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuirkProfile {
+    pub name: String,
+    /// `chargePointVendor` to match, case-insensitive; a trailing `*`
+    /// matches by prefix
+    pub vendor: Option<String>,
+    /// `chargePointModel` to match, same rules; any model when unset
+    pub model: Option<String>,
+    /// Charge points assigned explicitly, regardless of vendor/model
+    pub charge_point_ids: Vec<String>,
+    /// chrono format of non-RFC 3339 timestamps (`%s` for Unix seconds);
+    /// times without an offset are taken as UTC
+    pub timestamp_format: Option<String>,
+    pub energy_unit: EnergyUnit,
+    /// Attach the connector's active transaction to MeterValues without one
+    pub infer_meter_values_transaction_id: bool,
+    /// Repair the case of enum values
+    pub normalize_enum_case: bool,
+    /// Serialize non-string DataTransfer `data` to a JSON string
+    pub stringify_data_transfer_data: bool,
+}
+
+impl QuirkProfile {
+    /// Whether the profile applies to a station with this vendor and model.
+    pub fn matches(&self, vendor: Option<&str>, model: Option<&str>) -> bool {
+        let Some(pattern) = self.vendor.as_deref() else {
+            return false;
+        };
+        if !vendor.is_some_and(|v| pattern_matches(pattern, v)) {
+            return false;
+        }
+        match self.model.as_deref() {
+            Some(pattern) => model.is_some_and(|m| pattern_matches(pattern, m)),
+            None => true,
+        }
+    }
+
+    /// Repair a Call payload from the charge point. Returns the number of fixes.
+    pub fn apply_call(&self, action: &str, payload: &mut Value) -> usize {
+        let mut fixes = 0;
+
+        if let Some(format) = self.timestamp_format.as_deref() {
+            fixes += fix_timestamps(format, payload);
+        }
+
+        if self.energy_unit == EnergyUnit::KWh {
+            fixes += match action {
+                "StartTransaction" => kwh_to_wh(payload.get_mut("meterStart")),
+                "StopTransaction" => {
+                    kwh_to_wh(payload.get_mut("meterStop"))
+                        + label_energy_samples(payload.get_mut("transactionData"))
+                }
+                "MeterValues" => label_energy_samples(payload.get_mut("meterValue")),
+                _ => 0,
+            };
+        }
+
+        if self.normalize_enum_case {
+            fixes += canonicalize_enums(OcppVersion::V16, action, payload);
+        }
+
+        if self.stringify_data_transfer_data && action == "DataTransfer" {
+            fixes += stringify_data(payload);
+        }
+
+        fixes
+    }
+
+    /// Repair a CallResult payload answering our `action`. Returns the number of fixes.
+    pub fn apply_call_result(&self, action: &str, payload: &mut Value) -> usize {
+        let mut fixes = 0;
+
+        if self.normalize_enum_case {
+            if let Some(Value::String(status)) = payload.get_mut("status") {
+                if let Some(canonical) = CONFIRMATION_STATUSES
+                    .iter()
+                    .find(|s| **s != status.as_str() && s.eq_ignore_ascii_case(status))
+                {
+                    *status = canonical.to_string();
+                    fixes += 1;
+                }
+            }
+        }
+
+        if self.stringify_data_transfer_data && action == "DataTransfer" {
+            fixes += stringify_data(payload);
+        }
+
+        fixes
+    }
+}
+
+fn pattern_matches(pattern: &str, value: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => value
+            .get(..prefix.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(prefix)),
+        None => value.eq_ignore_ascii_case(pattern),
+    }
+}
+
+/// Rewrite every `timestamp` field that is not RFC 3339 but parses with `format`.
+fn fix_timestamps(format: &str, value: &mut Value) -> usize {
+    match value {
+        Value::Object(fields) => fields
+            .iter_mut()
+            .map(|(key, field)| {
+                if key == "timestamp" {
+                    fix_timestamp(format, field)
+                } else {
+                    fix_timestamps(format, field)
+                }
+            })
+            .sum(),
+        Value::Array(items) => items.iter_mut().map(|i| fix_timestamps(format, i)).sum(),
+        _ => 0,
+    }
+}
+
+fn fix_timestamp(format: &str, value: &mut Value) -> usize {
+    let raw = match value {
+        Value::String(s) if DateTime::parse_from_rfc3339(s).is_ok() => return 0,
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        _ => return 0,
+    };
+    let parsed = DateTime::parse_from_str(&raw, format)
+        .map(|t| t.with_timezone(&Utc))
+        .or_else(|_| NaiveDateTime::parse_from_str(&raw, format).map(|t| t.and_utc()));
+    match parsed {
+        Ok(timestamp) => {
+            *value = Value::String(timestamp.to_rfc3339_opts(SecondsFormat::Millis, true));
+            1
+        }
+        Err(_) => 0,
+    }
+}
+
+/// Scale a kWh register reading to integer Wh.
+fn kwh_to_wh(value: Option<&mut Value>) -> usize {
+    let Some(value) = value else {
+        return 0;
+    };
+    match value.as_f64() {
+        Some(kwh) => {
+            *value = Value::from((kwh * 1000.0).round() as i64);
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Label unit-less active energy samples in `meterValue[].sampledValue[]` as kWh.
+fn label_energy_samples(meter_values: Option<&mut Value>) -> usize {
+    let Some(Value::Array(meter_values)) = meter_values else {
+        return 0;
+    };
+    let mut fixes = 0;
+    for meter_value in meter_values {
+        let Some(Value::Array(samples)) = meter_value.get_mut("sampledValue") else {
+            continue;
+        };
+        for sample in samples.iter_mut().filter_map(Value::as_object_mut) {
+            // Energy.Active.Import.Register is the default measurand
+            let active_energy = sample
+                .get("measurand")
+                .and_then(Value::as_str)
+                .is_none_or(|m| m.starts_with("Energy.Active."));
+            if active_energy && !sample.contains_key("unit") {
+                sample.insert("unit".to_string(), Value::from("kWh"));
+                fixes += 1;
+            }
+        }
+    }
+    fixes
+}
+
+fn stringify_data(payload: &mut Value) -> usize {
+    match payload.get_mut("data") {
+        Some(data) if !data.is_string() && !data.is_null() => {
+            *data = Value::String(data.to_string());
+            1
+        }
+        _ => 0,
+    }
+}
+
+/// Configured profiles and which one each charge point uses.
+pub struct QuirkRegistry {
+    profiles: Vec<Arc<QuirkProfile>>,
+    assigned: DashMap<String, Option<Arc<QuirkProfile>>>,
+}
+
+pub type SharedQuirkRegistry = Arc<QuirkRegistry>;
+
+impl QuirkRegistry {
+    pub fn new(profiles: Vec<QuirkProfile>) -> Self {
+        Self {
+            profiles: profiles.into_iter().map(Arc::new).collect(),
+            assigned: DashMap::new(),
+        }
+    }
+
+    pub fn shared(profiles: Vec<QuirkProfile>) -> SharedQuirkRegistry {
+        Arc::new(Self::new(profiles))
+    }
+
+    /// No profiles configured — nothing to look up.
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.profiles.len()
+    }
+
+    /// The profile for a charge point with this identity, without assigning it.
+    pub fn select(
+        &self,
+        charge_point_id: &str,
+        vendor: Option<&str>,
+        model: Option<&str>,
+    ) -> Option<Arc<QuirkProfile>> {
+        self.profiles
+            .iter()
+            .find(|p| p.charge_point_ids.iter().any(|id| id == charge_point_id))
+            .or_else(|| self.profiles.iter().find(|p| p.matches(vendor, model)))
+            .cloned()
+    }
+
+    /// Select and remember the profile for a charge point.
+    pub fn assign(
+        &self,
+        charge_point_id: &str,
+        vendor: Option<&str>,
+        model: Option<&str>,
+    ) -> Option<Arc<QuirkProfile>> {
+        let profile = self.select(charge_point_id, vendor, model);
+        let previous = self
+            .assigned
+            .insert(charge_point_id.to_string(), profile.clone())
+            .flatten();
+        if let Some(p) = &profile {
+            if previous.is_none_or(|q| q.name != p.name) {
+                info!(
+                    charge_point_id,
+                    profile = p.name.as_str(),
+                    "Quirk profile assigned"
+                );
+            }
+        }
+        profile
+    }
+
+    /// The remembered assignment: `None` if the charge point was never
+    /// assigned, `Some(None)` if no profile applies to it.
+    pub fn assigned(&self, charge_point_id: &str) -> Option<Option<Arc<QuirkProfile>>> {
+        self.assigned.get(charge_point_id).map(|p| p.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn profile() -> QuirkProfile {
+        QuirkProfile {
+            name: "acme".into(),
+            vendor: Some("ACME*".into()),
+            model: Some("AC-22".into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn explicit_assignment_wins_over_vendor_match() {
+        let pinned = QuirkProfile {
+            name: "pinned".into(),
+            charge_point_ids: vec!["CP-7".into()],
+            ..Default::default()
+        };
+        let registry = QuirkRegistry::new(vec![profile(), pinned]);
+
+        let selected = registry.select("CP-7", Some("ACME Corp"), Some("AC-22"));
+        assert_eq!(selected.unwrap().name, "pinned");
+
+        let selected = registry.select("CP-1", Some("acme corp"), Some("ac-22"));
+        assert_eq!(selected.unwrap().name, "acme");
+
+        assert!(registry
+            .select("CP-1", Some("ACME"), Some("AC-11"))
+            .is_none());
+        assert!(registry
+            .select("CP-1", Some("Other"), Some("AC-22"))
+            .is_none());
+
+        assert!(registry.assigned("CP-1").is_none());
+        registry.assign("CP-1", Some("Other"), None);
+        assert_eq!(registry.assigned("CP-1"), Some(None));
+    }
+
+    #[test]
+    fn timestamps_are_rewritten_to_rfc3339() {
+        let quirks = QuirkProfile {
+            timestamp_format: Some("%Y-%m-%d %H:%M:%S".into()),
+            ..profile()
+        };
+        let mut payload = json!({
+            "connectorId": 1,
+            "meterValue": [
+                { "timestamp": "2024-05-01 10:00:00", "sampledValue": [] },
+                { "timestamp": "2024-05-01T10:00:00Z", "sampledValue": [] }
+            ]
+        });
+        assert_eq!(quirks.apply_call("MeterValues", &mut payload), 1);
+        assert_eq!(
+            payload["meterValue"][0]["timestamp"],
+            "2024-05-01T10:00:00.000Z"
+        );
+
+        let unix = QuirkProfile {
+            timestamp_format: Some("%s".into()),
+            ..profile()
+        };
+        let mut payload = json!({ "connectorId": 1, "timestamp": 1714557600 });
+        assert_eq!(unix.apply_call("StatusNotification", &mut payload), 1);
+        assert_eq!(payload["timestamp"], "2024-05-01T10:00:00.000Z");
+    }
+
+    #[test]
+    fn kwh_readings_are_converted() {
+        let quirks = QuirkProfile {
+            energy_unit: EnergyUnit::from_config("kWh"),
+            ..profile()
+        };
+        let mut start = json!({ "connectorId": 1, "meterStart": 12.345 });
+        quirks.apply_call("StartTransaction", &mut start);
+        assert_eq!(start["meterStart"], 12345);
+
+        let mut meter = json!({
+            "connectorId": 1,
+            "meterValue": [{ "timestamp": "2024-05-01T10:00:00Z", "sampledValue": [
+                { "value": "12.5" },
+                { "value": "7.2", "measurand": "Power.Active.Import" },
+                { "value": "12500", "unit": "Wh" }
+            ]}]
+        });
+        assert_eq!(quirks.apply_call("MeterValues", &mut meter), 1);
+        let samples = &meter["meterValue"][0]["sampledValue"];
+        assert_eq!(samples[0]["unit"], "kWh");
+        assert!(samples[1].get("unit").is_none());
+        assert_eq!(samples[2]["unit"], "Wh");
+    }
+
+    #[test]
+    fn enum_case_and_data_transfer_are_repaired() {
+        let quirks = QuirkProfile {
+            normalize_enum_case: true,
+            stringify_data_transfer_data: true,
+            ..profile()
+        };
+        let mut call = json!({ "vendorId": "acme", "data": { "level": 3 } });
+        assert_eq!(quirks.apply_call("DataTransfer", &mut call), 1);
+        assert_eq!(call["data"], r#"{"level":3}"#);
+
+        let mut result = json!({ "status": "accepted", "data": [1, 2] });
+        assert_eq!(quirks.apply_call_result("DataTransfer", &mut result), 2);
+        assert_eq!(result["status"], "Accepted");
+        assert_eq!(result["data"], "[1,2]");
+
+        // Profiles without the switches leave payloads alone
+        let mut untouched = json!({ "status": "accepted" });
+        assert_eq!(profile().apply_call_result("Reset", &mut untouched), 0);
+    }
+}
@@ -0,0 +1,573 @@
+//! OCPP-J payload schema validation
+//!
+//! Bundles the OCA JSON schemas for OCPP 1.6 (draft-04) and OCPP 2.0.1
+//! (draft-06) request payloads — descriptions stripped, constraints intact —
+//! and maps violations onto the OCPP-J CallError codes:
+//!
+//! | Violation                           | CallError code                  |
+//! |-------------------------------------|---------------------------------|
+//! | wrong JSON type of a field          | `TypeConstraintViolation`       |
+//! | missing required field, item counts | `OccurrenceConstraintViolation` |
+//! | enum, length, range or format       | `PropertyConstraintViolation`   |
+//! | unknown field, non-object payload   | `FormationViolation`            |
+//!
+//! OCPP 1.6 spells the occurrence code `OccurenceConstraintViolation`; the
+//! version decides which spelling goes on the wire.
+//!
+//! Schemas are compiled once, on first use. Actions without a bundled schema
+//! pass unvalidated.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+
+use jsonschema::error::ValidationErrorKind;
+use jsonschema::Validator;
+use serde_json::Value;
+use tracing::debug;
+
+use crate::domain::OcppVersion;
+use crate::shared::ocpp_frame::OcppFrame;
+
+/// Longest CallError description sent back to a charge point.
+const MAX_DESCRIPTION_LEN: usize = 255;
+
+// ── Bundled schemas ────────────────────────────────────────────
+
+macro_rules! bundled {
+    ($dir:literal, $suffix:literal: $($action:literal),* $(,)?) => {
+        &[$(($action, include_str!(concat!($dir, "/", $action, $suffix, ".json")))),*]
+    };
+}
+
+/// OCPP 1.6 request schemas, both directions.
+static V16_SCHEMAS: &[(&str, &str)] = bundled!("ocpp16", "":
+    "Authorize",
+    "BootNotification",
+    "CancelReservation",
+    "ChangeAvailability",
+    "ChangeConfiguration",
+    "ClearCache",
+    "ClearChargingProfile",
+    "DataTransfer",
+    "DiagnosticsStatusNotification",
+    "FirmwareStatusNotification",
+    "GetCompositeSchedule",
+    "GetConfiguration",
+    "GetDiagnostics",
+    "GetLocalListVersion",
+    "Heartbeat",
+    "MeterValues",
+    "RemoteStartTransaction",
+    "RemoteStopTransaction",
+    "ReserveNow",
+    "Reset",
+    "SecurityEventNotification",
+    "SendLocalList",
+    "SetChargingProfile",
+    "StartTransaction",
+    "StatusNotification",
+    "StopTransaction",
+    "TriggerMessage",
+    "UnlockConnector",
+    "UpdateFirmware",
+);
+
+/// OCPP 2.0.1 request schemas for every action the server handles or sends.
+static V201_SCHEMAS: &[(&str, &str)] = bundled!("ocpp201", "Request":
+    "Authorize",
+    "BootNotification",
+    "CancelReservation",
+    "ChangeAvailability",
+    "ClearCache",
+    "ClearChargingProfile",
+    "ClearDisplayMessage",
+    "ClearVariableMonitoring",
+    "ClearedChargingLimit",
+    "CustomerInformation",
+    "DataTransfer",
+    "FirmwareStatusNotification",
+    "Get15118EVCertificate",
+    "GetBaseReport",
+    "GetCertificateStatus",
+    "GetChargingProfiles",
+    "GetCompositeSchedule",
+    "GetDisplayMessages",
+    "GetLocalListVersion",
+    "GetLog",
+    "GetMonitoringReport",
+    "GetReport",
+    "GetTransactionStatus",
+    "GetVariables",
+    "Heartbeat",
+    "MeterValues",
+    "NotifyChargingLimit",
+    "NotifyCustomerInformation",
+    "NotifyDisplayMessages",
+    "NotifyEVChargingNeeds",
+    "NotifyEVChargingSchedule",
+    "NotifyEvent",
+    "NotifyMonitoringReport",
+    "NotifyReport",
+    "ReportChargingProfiles",
+    "ReservationStatusUpdate",
+    "RequestStartTransaction",
+    "RequestStopTransaction",
+    "ReserveNow",
+    "Reset",
+    "SecurityEventNotification",
+    "SendLocalList",
+    "SetChargingProfile",
+    "SetDisplayMessage",
+    "SetMonitoringBase",
+    "SetMonitoringLevel",
+    "SetVariableMonitoring",
+    "SetVariables",
+    "StatusNotification",
+    "TransactionEvent",
+    "TriggerMessage",
+    "UnlockConnector",
+    "UpdateFirmware",
+);
+
+type SchemaKey = (OcppVersion, &'static str);
+
+static PARSED: LazyLock<HashMap<SchemaKey, Value>> = LazyLock::new(|| {
+    let mut parsed = HashMap::new();
+    for (version, schemas) in [
+        (OcppVersion::V16, V16_SCHEMAS),
+        (OcppVersion::V201, V201_SCHEMAS),
+    ] {
+        for (action, source) in schemas {
+            let schema = serde_json::from_str(source)
+                .unwrap_or_else(|e| panic!("bundled {} schema is not JSON: {}", action, e));
+            parsed.insert((version, *action), schema);
+        }
+    }
+    parsed
+});
+
+static COMPILED: LazyLock<HashMap<SchemaKey, Validator>> = LazyLock::new(|| {
+    PARSED
+        .iter()
+        .map(|(key, schema)| (*key, compile(key.1, schema)))
+        .collect()
+});
+
+fn compile(action: &str, schema: &Value) -> Validator {
+    jsonschema::options()
+        .should_validate_formats(true)
+        .build(schema)
+        .unwrap_or_else(|e| panic!("bundled {} schema does not compile: {}", action, e))
+}
+
+// ── Violations ─────────────────────────────────────────────────
+
+/// A Call payload the Central System refuses, with the CallError to answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayloadViolation {
+    pub error_code: &'static str,
+    pub description: String,
+}
+
+impl PayloadViolation {
+    fn new(error_code: &'static str, description: impl Into<String>) -> Self {
+        let mut description = description.into();
+        if description.len() > MAX_DESCRIPTION_LEN {
+            let mut end = MAX_DESCRIPTION_LEN;
+            while !description.is_char_boundary(end) {
+                end -= 1;
+            }
+            description.truncate(end);
+        }
+        Self {
+            error_code,
+            description,
+        }
+    }
+
+    pub fn formation(description: impl Into<String>) -> Self {
+        Self::new("FormationViolation", description)
+    }
+
+    pub fn property_constraint(description: impl Into<String>) -> Self {
+        Self::new("PropertyConstraintViolation", description)
+    }
+
+    pub fn type_constraint(description: impl Into<String>) -> Self {
+        Self::new("TypeConstraintViolation", description)
+    }
+
+    pub fn occurrence_constraint(version: OcppVersion, description: impl Into<String>) -> Self {
+        let code = match version {
+            OcppVersion::V16 => "OccurenceConstraintViolation",
+            OcppVersion::V201 | OcppVersion::V21 => "OccurrenceConstraintViolation",
+        };
+        Self::new(code, description)
+    }
+
+    /// The action is not known to the Central System.
+    pub fn not_implemented(action: &str) -> Self {
+        Self::new("NotImplemented", format!("Unknown action '{}'", action))
+    }
+
+    /// The action is known but not accepted in this direction.
+    pub fn not_supported(action: &str) -> Self {
+        Self::new(
+            "NotSupported",
+            format!("'{}' is sent by the Central System, not to it", action),
+        )
+    }
+
+    /// Classify a payload that passed (or skipped) schema validation but
+    /// still failed to deserialize into the `rust_ocpp` request type.
+    pub fn from_serde(version: OcppVersion, error: &serde_json::Error) -> Self {
+        let message = error.to_string();
+        if message.starts_with("missing field") {
+            Self::occurrence_constraint(version, message)
+        } else if message.starts_with("invalid type") {
+            Self::type_constraint(message)
+        } else if message.starts_with("unknown variant") || message.starts_with("invalid value") {
+            Self::property_constraint(message)
+        } else {
+            Self::formation(message)
+        }
+    }
+
+    /// The CallError frame answering the Call with `unique_id`.
+    pub fn to_frame(&self, unique_id: &str) -> OcppFrame {
+        OcppFrame::error_response(unique_id, self.error_code, self.description.as_str())
+    }
+}
+
+impl std::fmt::Display for PayloadViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.error_code, self.description)
+    }
+}
+
+/// Validate `payload` against the bundled request schema for `action`.
+///
+/// Returns `Ok(())` for actions without a bundled schema.
+pub fn validate_payload(
+    version: OcppVersion,
+    action: &str,
+    payload: &Value,
+) -> Result<(), PayloadViolation> {
+    let Some(validator) = COMPILED.get(&(version, action)) else {
+        debug!(action, ?version, "No bundled schema, skipping validation");
+        return Ok(());
+    };
+
+    let Err(error) = validator.validate(payload) else {
+        return Ok(());
+    };
+
+    let path = error.instance_path.as_str();
+    let description = if path.is_empty() {
+        format!("{}: {}", action, error)
+    } else {
+        format!("{} {}: {}", action, path, error)
+    };
+
+    Err(match error.kind {
+        // The payload itself must be an object
+        ValidationErrorKind::Type { .. } if path.is_empty() => {
+            PayloadViolation::formation(description)
+        }
+        ValidationErrorKind::Type { .. } => PayloadViolation::type_constraint(description),
+        ValidationErrorKind::Required { .. }
+        | ValidationErrorKind::MinItems { .. }
+        | ValidationErrorKind::MaxItems { .. } => {
+            PayloadViolation::occurrence_constraint(version, description)
+        }
+        ValidationErrorKind::Enum { .. }
+        | ValidationErrorKind::Constant { .. }
+        | ValidationErrorKind::MaxLength { .. }
+        | ValidationErrorKind::MinLength { .. }
+        | ValidationErrorKind::Maximum { .. }
+        | ValidationErrorKind::Minimum { .. }
+        | ValidationErrorKind::ExclusiveMaximum { .. }
+        | ValidationErrorKind::ExclusiveMinimum { .. }
+        | ValidationErrorKind::MultipleOf { .. }
+        | ValidationErrorKind::Pattern { .. }
+        | ValidationErrorKind::Format { .. } => PayloadViolation::property_constraint(description),
+        _ => PayloadViolation::formation(description),
+    })
+}
+
+/// Rewrite string values that match an enum of the bundled request schema
+/// only case-insensitively (`"available"` → `"Available"`).
+///
+/// Returns how many values were rewritten; 0 for actions without a schema.
+pub fn canonicalize_enums(version: OcppVersion, action: &str, payload: &mut Value) -> usize {
+    match PARSED.get(&(version, action)) {
+        Some(schema) => canonicalize(schema, schema, payload),
+        None => 0,
+    }
+}
+
+fn canonicalize(root: &Value, node: &Value, value: &mut Value) -> usize {
+    let node = match node.get("$ref").and_then(Value::as_str) {
+        Some(reference) => match reference.strip_prefix('#').and_then(|p| root.pointer(p)) {
+            Some(target) => target,
+            None => return 0,
+        },
+        None => node,
+    };
+
+    match value {
+        Value::String(text) => {
+            let Some(variants) = node.get("enum").and_then(Value::as_array) else {
+                return 0;
+            };
+            let variants: Vec<&str> = variants.iter().filter_map(Value::as_str).collect();
+            if variants.contains(&text.as_str()) {
+                return 0;
+            }
+            match variants.iter().find(|v| v.eq_ignore_ascii_case(text)) {
+                Some(canonical) => {
+                    *text = canonical.to_string();
+                    1
+                }
+                None => 0,
+            }
+        }
+        Value::Object(fields) => {
+            let Some(properties) = node.get("properties").and_then(Value::as_object) else {
+                return 0;
+            };
+            fields
+                .iter_mut()
+                .filter_map(|(key, field)| Some((properties.get(key)?, field)))
+                .map(|(property, field)| canonicalize(root, property, field))
+                .sum()
+        }
+        Value::Array(items) => match node.get("items") {
+            Some(item) => items
+                .iter_mut()
+                .map(|value| canonicalize(root, item, value))
+                .sum(),
+            None => 0,
+        },
+        _ => 0,
+    }
+}
+
+// ── Validator ──────────────────────────────────────────────────
+
+/// Which payloads are validated, from `[ocpp].schema_validation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SchemaValidationMode {
+    /// No validation
+    Off,
+    /// Calls received from charge points
+    #[default]
+    Inbound,
+    /// Calls received from charge points and commands sent to them
+    Strict,
+}
+
+impl SchemaValidationMode {
+    /// Parse the config value; unknown values fall back to `Inbound`.
+    pub fn from_config(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "off" => Self::Off,
+            "strict" => Self::Strict,
+            _ => Self::Inbound,
+        }
+    }
+}
+
+/// Mode-aware entry point shared by the protocol handlers and the
+/// command sender.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SchemaValidator {
+    mode: SchemaValidationMode,
+}
+
+impl SchemaValidator {
+    pub fn new(mode: SchemaValidationMode) -> Self {
+        Self { mode }
+    }
+
+    pub fn mode(&self) -> SchemaValidationMode {
+        self.mode
+    }
+
+    /// Validate a Call received from a charge point.
+    pub fn check_inbound(
+        &self,
+        version: OcppVersion,
+        action: &str,
+        payload: &Value,
+    ) -> Result<(), PayloadViolation> {
+        match self.mode {
+            SchemaValidationMode::Off => Ok(()),
+            SchemaValidationMode::Inbound | SchemaValidationMode::Strict => {
+                validate_payload(version, action, payload)
+            }
+        }
+    }
+
+    /// Validate a command about to be sent to a charge point (strict mode only).
+    pub fn check_outbound(
+        &self,
+        version: OcppVersion,
+        action: &str,
+        payload: &Value,
+    ) -> Result<(), PayloadViolation> {
+        match self.mode {
+            SchemaValidationMode::Strict => validate_payload(version, action, payload),
+            SchemaValidationMode::Off | SchemaValidationMode::Inbound => Ok(()),
+        }
+    }
+}
+
+pub type SharedSchemaValidator = Arc<SchemaValidator>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn all_bundled_schemas_compile() {
+        assert_eq!(COMPILED.len(), V16_SCHEMAS.len() + V201_SCHEMAS.len());
+    }
+
+    #[test]
+    fn valid_payloads_pass() {
+        let boot = json!({ "chargePointVendor": "Texnouz", "chargePointModel": "T1" });
+        assert!(validate_payload(OcppVersion::V16, "BootNotification", &boot).is_ok());
+
+        let status = json!({
+            "timestamp": "2024-05-01T10:00:00Z",
+            "connectorStatus": "Available",
+            "evseId": 1,
+            "connectorId": 1
+        });
+        assert!(validate_payload(OcppVersion::V201, "StatusNotification", &status).is_ok());
+    }
+
+    #[test]
+    fn violations_map_to_call_error_codes() {
+        let code = |version, action, payload: Value| {
+            validate_payload(version, action, &payload)
+                .unwrap_err()
+                .error_code
+        };
+
+        assert_eq!(
+            code(OcppVersion::V16, "Authorize", json!({ "idTag": 42 })),
+            "TypeConstraintViolation"
+        );
+        assert_eq!(
+            code(
+                OcppVersion::V16,
+                "Authorize",
+                json!({ "idTag": "X".repeat(21) })
+            ),
+            "PropertyConstraintViolation"
+        );
+        assert_eq!(
+            code(OcppVersion::V16, "Authorize", json!({})),
+            "OccurenceConstraintViolation"
+        );
+        assert_eq!(
+            code(OcppVersion::V201, "Heartbeat", json!({ "foo": 1 })),
+            "FormationViolation"
+        );
+        assert_eq!(
+            code(
+                OcppVersion::V201,
+                "MeterValues",
+                json!({ "evseId": 1, "meterValue": [] })
+            ),
+            "OccurrenceConstraintViolation"
+        );
+        assert_eq!(
+            code(
+                OcppVersion::V201,
+                "StatusNotification",
+                json!({
+                    "timestamp": "yesterday",
+                    "connectorStatus": "Available",
+                    "evseId": 1,
+                    "connectorId": 1
+                })
+            ),
+            "PropertyConstraintViolation"
+        );
+        assert_eq!(
+            code(OcppVersion::V16, "Heartbeat", json!([])),
+            "FormationViolation"
+        );
+    }
+
+    #[test]
+    fn unknown_actions_are_not_validated() {
+        assert!(validate_payload(OcppVersion::V16, "Nonexistent", &json!(null)).is_ok());
+        assert!(validate_payload(OcppVersion::V21, "Heartbeat", &json!(null)).is_ok());
+    }
+
+    #[test]
+    fn enum_case_is_canonicalized() {
+        let mut status = json!({
+            "connectorId": 1,
+            "errorCode": "noerror",
+            "status": "charging",
+            "info": "charging"
+        });
+        assert_eq!(
+            canonicalize_enums(OcppVersion::V16, "StatusNotification", &mut status),
+            2
+        );
+        assert_eq!(status["errorCode"], "NoError");
+        assert_eq!(status["status"], "Charging");
+        assert_eq!(status["info"], "charging");
+
+        // Through `$ref` definitions and arrays
+        let mut event = json!({
+            "eventType": "started",
+            "timestamp": "2024-05-01T10:00:00Z",
+            "triggerReason": "Authorized",
+            "seqNo": 0,
+            "transactionInfo": { "transactionId": "t1" },
+            "meterValue": [{
+                "timestamp": "2024-05-01T10:00:00Z",
+                "sampledValue": [{ "value": 1.0, "measurand": "energy.active.import.register" }]
+            }]
+        });
+        assert_eq!(
+            canonicalize_enums(OcppVersion::V201, "TransactionEvent", &mut event),
+            2
+        );
+        assert_eq!(event["eventType"], "Started");
+        assert_eq!(
+            event["meterValue"][0]["sampledValue"][0]["measurand"],
+            "Energy.Active.Import.Register"
+        );
+    }
+
+    #[test]
+    fn modes_gate_directions() {
+        let bad = json!({});
+        let inbound = SchemaValidator::new(SchemaValidationMode::Inbound);
+        assert!(inbound
+            .check_inbound(OcppVersion::V16, "Authorize", &bad)
+            .is_err());
+        assert!(inbound
+            .check_outbound(OcppVersion::V16, "Reset", &bad)
+            .is_ok());
+
+        let strict = SchemaValidator::new(SchemaValidationMode::from_config("STRICT"));
+        assert!(strict
+            .check_outbound(OcppVersion::V16, "Reset", &bad)
+            .is_err());
+
+        let off = SchemaValidator::new(SchemaValidationMode::from_config("off"));
+        assert!(off
+            .check_inbound(OcppVersion::V16, "Authorize", &bad)
+            .is_ok());
+    }
+}
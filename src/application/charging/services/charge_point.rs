@@ -5,9 +5,10 @@ use std::sync::Arc;
 use dashmap::DashMap;
 use tracing::info;
 
+use super::heartbeat_monitor::SharedHeartbeatConfig;
 use crate::domain::{
-    ChargePoint, ChargingLimitType, ConnectorStatus, DomainResult, OcppVersion, RepositoryProvider,
-    Transaction,
+    AuthEvent, ChargePoint, ChargingLimitType, ChargingProfile, ConnectorStatus, DisplayMessage,
+    DomainResult, OcppVersion, RepositoryProvider, Reservation, Transaction, VariableMonitor,
 };
 use crate::shared::errors::DomainError;
 
@@ -18,10 +19,37 @@ pub struct PendingChargingLimit {
     pub limit_value: f64,
 }
 
+/// How a connector's reservations bear on an id_tag starting a transaction
+#[derive(Debug, Clone)]
+pub enum ReservationCheck {
+    /// No reservation applies
+    Free,
+    /// The tag holds this reservation; it becomes `Used` once charging starts
+    Held(Reservation),
+    /// Reserved for someone else
+    Blocked(Reservation),
+}
+
+/// Progress of a NotifyMonitoringReport that may span several parts
+#[derive(Debug, Clone, Default)]
+struct MonitoringReportProgress {
+    /// Requested without criteria, so it lists every monitor on the station
+    full: bool,
+    /// Monitor ids reported so far
+    monitor_ids: Vec<i32>,
+}
+
 /// Service for charge point business operations
 pub struct ChargePointService {
     repos: Arc<dyn RepositoryProvider>,
     pending_limits: DashMap<(String, u32), PendingChargingLimit>,
+    /// Message ids reported so far per `(charge_point_id, request_id)`
+    /// while a multi-part NotifyDisplayMessages is in progress.
+    display_reports: DashMap<(String, i32), Vec<i32>>,
+    /// Monitoring reports in progress per `(charge_point_id, request_id)`.
+    monitoring_reports: DashMap<(String, i32), MonitoringReportProgress>,
+    /// Heartbeat intervals given to booting stations
+    heartbeat: SharedHeartbeatConfig,
 }
 
 impl ChargePointService {
@@ -29,9 +57,17 @@ impl ChargePointService {
         Self {
             repos,
             pending_limits: DashMap::new(),
+            display_reports: DashMap::new(),
+            monitoring_reports: DashMap::new(),
+            heartbeat: SharedHeartbeatConfig::default(),
         }
     }
 
+    pub fn with_heartbeat_config(mut self, heartbeat: SharedHeartbeatConfig) -> Self {
+        self.heartbeat = heartbeat;
+        self
+    }
+
     pub fn set_pending_limit(
         &self,
         charge_point_id: &str,
@@ -104,6 +140,23 @@ impl ChargePointService {
         Ok(cp)
     }
 
+    /// Heartbeat interval to accept `charge_point_id` with (seconds)
+    pub fn heartbeat_interval_for(&self, charge_point_id: &str) -> i32 {
+        self.heartbeat.get().interval_for(charge_point_id)
+    }
+
+    /// Record the heartbeat interval the station was accepted with.
+    pub async fn record_heartbeat_interval(
+        &self,
+        charge_point_id: &str,
+        interval: i32,
+    ) -> DomainResult<()> {
+        self.repos
+            .charge_points()
+            .set_heartbeat_interval(charge_point_id, Some(interval))
+            .await
+    }
+
     pub async fn heartbeat(&self, charge_point_id: &str) -> DomainResult<()> {
         if let Some(mut cp) = self.repos.charge_points().find_by_id(charge_point_id).await? {
             cp.update_heartbeat();
@@ -185,11 +238,36 @@ impl ChargePointService {
     }
 
     pub async fn authorize(&self, id_tag: &str) -> DomainResult<bool> {
-        self.repos.id_tags().is_valid(id_tag).await
+        Ok(self.get_auth_status(id_tag).await?.as_deref() == Some("Accepted"))
     }
 
+    /// Resolve the authorization status of an id_tag.
+    ///
+    /// Local id_tags take precedence; unknown tags fall back to tokens
+    /// pushed by OCPI roaming partners.
     pub async fn get_auth_status(&self, id_tag: &str) -> DomainResult<Option<String>> {
-        self.repos.id_tags().get_auth_status(id_tag).await
+        if let Some(status) = self.repos.id_tags().get_auth_status(id_tag).await? {
+            return Ok(Some(status));
+        }
+        Ok(self
+            .repos
+            .ocpi_tokens()
+            .find_by_uid(id_tag)
+            .await?
+            .map(|token| token.auth_status().to_string()))
+    }
+
+    /// Persist the outcome of an Authorize request.
+    pub async fn record_auth_event(
+        &self,
+        charge_point_id: &str,
+        id_tag: &str,
+        status: &str,
+    ) -> DomainResult<()> {
+        self.repos
+            .auth_events()
+            .record(AuthEvent::new(charge_point_id, id_tag, status))
+            .await
     }
 
     pub async fn start_transaction(
@@ -198,6 +276,7 @@ impl ChargePointService {
         connector_id: u32,
         id_tag: &str,
         meter_start: i32,
+        station_transaction_id: Option<&str>,
     ) -> DomainResult<Transaction> {
         let transaction_id = self.repos.transactions().next_id().await;
 
@@ -208,6 +287,7 @@ impl ChargePointService {
             id_tag,
             meter_start,
         );
+        transaction.station_transaction_id = station_transaction_id.map(str::to_string);
 
         if let Some(limit) = self.take_pending_limit(charge_point_id, connector_id) {
             info!(
@@ -296,6 +376,276 @@ impl ChargePointService {
         self.repos.transactions().find_by_id(transaction_id).await
     }
 
+    /// Store display messages reported by a station (NotifyDisplayMessages).
+    ///
+    /// Reported messages are upserted into the catalogue. Once the last part
+    /// arrives (`tbc = false`), catalogue messages the station did not report
+    /// are marked `Missing`.
+    pub async fn record_display_messages(
+        &self,
+        charge_point_id: &str,
+        request_id: i32,
+        messages: Vec<DisplayMessage>,
+        tbc: bool,
+    ) -> DomainResult<()> {
+        let key = (charge_point_id.to_string(), request_id);
+        let ids: Vec<i32> = messages.iter().map(|m| m.message_id).collect();
+        self.display_reports.entry(key.clone()).or_default().extend(ids);
+
+        for message in messages {
+            self.repos.display_messages().upsert(message).await?;
+        }
+
+        if !tbc {
+            let reported = self
+                .display_reports
+                .remove(&key)
+                .map(|(_, ids)| ids)
+                .unwrap_or_default();
+            self.reconcile_display_messages(charge_point_id, &reported)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Mark catalogue messages not in `reported_ids` as missing from the station.
+    pub async fn reconcile_display_messages(
+        &self,
+        charge_point_id: &str,
+        reported_ids: &[i32],
+    ) -> DomainResult<u64> {
+        let missing = self
+            .repos
+            .display_messages()
+            .mark_missing_except(charge_point_id, reported_ids)
+            .await?;
+        if missing > 0 {
+            info!(
+                charge_point_id,
+                missing,
+                reported = reported_ids.len(),
+                "Display messages missing on station"
+            );
+        }
+        Ok(missing)
+    }
+
+    /// Register a GetMonitoringReport request before it is sent.
+    ///
+    /// `full` reports (no criteria, no component filter) list every monitor
+    /// on the station, so stored monitors missing from them are removed.
+    pub fn begin_monitoring_report(&self, charge_point_id: &str, request_id: i32, full: bool) {
+        self.monitoring_reports.insert(
+            (charge_point_id.to_string(), request_id),
+            MonitoringReportProgress {
+                full,
+                monitor_ids: Vec::new(),
+            },
+        );
+    }
+
+    /// Forget a monitoring report the station will not send.
+    pub fn cancel_monitoring_report(&self, charge_point_id: &str, request_id: i32) {
+        self.monitoring_reports
+            .remove(&(charge_point_id.to_string(), request_id));
+    }
+
+    /// Store monitors reported by a station (NotifyMonitoringReport).
+    ///
+    /// Reported monitors are upserted. Once the last part of a full report
+    /// arrives (`tbc = false`), stored monitors the station did not report
+    /// are removed. Reports that were not registered via
+    /// [`begin_monitoring_report`](Self::begin_monitoring_report) are
+    /// treated as partial.
+    pub async fn record_monitoring_report(
+        &self,
+        charge_point_id: &str,
+        request_id: i32,
+        monitors: Vec<VariableMonitor>,
+        tbc: bool,
+    ) -> DomainResult<()> {
+        let key = (charge_point_id.to_string(), request_id);
+        if let Some(mut progress) = self.monitoring_reports.get_mut(&key) {
+            progress
+                .monitor_ids
+                .extend(monitors.iter().map(|m| m.monitor_id));
+        }
+
+        for monitor in monitors {
+            self.repos.variable_monitors().upsert(monitor).await?;
+        }
+
+        if !tbc {
+            if let Some((_, progress)) = self.monitoring_reports.remove(&key) {
+                if progress.full {
+                    self.reconcile_variable_monitors(charge_point_id, &progress.monitor_ids)
+                        .await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove stored monitors not in `reported_ids`.
+    pub async fn reconcile_variable_monitors(
+        &self,
+        charge_point_id: &str,
+        reported_ids: &[i32],
+    ) -> DomainResult<u64> {
+        let removed = self
+            .repos
+            .variable_monitors()
+            .remove_except(charge_point_id, reported_ids)
+            .await?;
+        if removed > 0 {
+            info!(
+                charge_point_id,
+                removed,
+                reported = reported_ids.len(),
+                "Variable monitors no longer configured on station"
+            );
+        }
+        Ok(removed)
+    }
+
+    /// Check whether `id_tag` may start charging on a connector.
+    ///
+    /// A `reservation_id` reported by the station is honoured when the tag
+    /// matches it. Otherwise a reservation on the connector itself decides.
+    /// Reservations on connector 0 hold "any connector": other tags may
+    /// charge only while enough other connectors stay available for the
+    /// holders.
+    pub async fn check_reservation(
+        &self,
+        charge_point_id: &str,
+        connector_id: u32,
+        id_tag: &str,
+        reservation_id: Option<i32>,
+    ) -> DomainResult<ReservationCheck> {
+        let reservations: Vec<Reservation> = self
+            .repos
+            .reservations()
+            .find_active_for_charge_point(charge_point_id)
+            .await?
+            .into_iter()
+            .filter(|r| !r.is_expired())
+            .collect();
+        if reservations.is_empty() {
+            return Ok(ReservationCheck::Free);
+        }
+        let parent = self.get_id_tag_parent(id_tag).await?;
+        let holds = |r: &Reservation| r.matches_tag(id_tag, parent.as_deref());
+
+        if let Some(r) = reservation_id.and_then(|id| reservations.iter().find(|r| r.id == id)) {
+            if holds(r) {
+                return Ok(ReservationCheck::Held(r.clone()));
+            }
+        }
+
+        let connector = connector_id as i32;
+        if let Some(r) = reservations.iter().find(|r| r.connector_id == connector) {
+            return Ok(if holds(r) {
+                ReservationCheck::Held(r.clone())
+            } else {
+                ReservationCheck::Blocked(r.clone())
+            });
+        }
+
+        let any_connector: Vec<&Reservation> =
+            reservations.iter().filter(|r| r.connector_id == 0).collect();
+        if let Some(r) = any_connector.iter().find(|r| holds(r)) {
+            return Ok(ReservationCheck::Held((*r).clone()));
+        }
+        let Some(first) = any_connector.first() else {
+            return Ok(ReservationCheck::Free);
+        };
+
+        let connectors = self
+            .repos
+            .charge_points()
+            .find_by_id(charge_point_id)
+            .await?
+            .map(|cp| cp.connectors)
+            .unwrap_or_default();
+        let available_elsewhere = connectors
+            .iter()
+            .filter(|c| c.id != connector_id && c.status == ConnectorStatus::Available)
+            .filter(|c| !reservations.iter().any(|r| r.connector_id == c.id as i32))
+            .count();
+        Ok(if available_elsewhere >= any_connector.len() {
+            ReservationCheck::Free
+        } else {
+            ReservationCheck::Blocked((*first).clone())
+        })
+    }
+
+    /// Mark a reservation `Used` once its holder started charging.
+    pub async fn use_reservation(&self, mut reservation: Reservation) -> DomainResult<Reservation> {
+        reservation.mark_used();
+        self.repos.reservations().update(reservation.clone()).await?;
+        info!(
+            reservation_id = reservation.id,
+            charge_point_id = reservation.charge_point_id.as_str(),
+            "Reservation used"
+        );
+        Ok(reservation)
+    }
+
+    /// Apply a station-reported end of a reservation (ReservationStatusUpdate).
+    ///
+    /// Returns `None` when the reservation is unknown, belongs to another
+    /// station, or already left the `Accepted` state.
+    pub async fn end_reservation(
+        &self,
+        charge_point_id: &str,
+        reservation_id: i32,
+        expired: bool,
+    ) -> DomainResult<Option<Reservation>> {
+        let Some(mut reservation) = self.repos.reservations().find_by_id(reservation_id).await?
+        else {
+            return Ok(None);
+        };
+        if reservation.charge_point_id != charge_point_id || !reservation.is_active() {
+            return Ok(None);
+        }
+        if expired {
+            reservation.expire();
+        } else {
+            reservation.cancel();
+        }
+        self.repos.reservations().update(reservation.clone()).await?;
+        Ok(Some(reservation))
+    }
+
+    /// Store an external limit reported by a station (NotifyChargingLimit).
+    ///
+    /// A new limit replaces the one previously reported by the same source
+    /// on the same EVSE.
+    pub async fn record_external_limit(
+        &self,
+        limit: ChargingProfile,
+    ) -> DomainResult<ChargingProfile> {
+        let source = limit.limit_source.clone().unwrap_or_default();
+        self.repos
+            .charging_profiles()
+            .deactivate_by_source(&limit.charge_point_id, &source, Some(limit.evse_id))
+            .await?;
+        self.repos.charging_profiles().save(limit).await
+    }
+
+    /// Deactivate external limits lifted by their source (ClearedChargingLimit).
+    pub async fn clear_external_limits(
+        &self,
+        charge_point_id: &str,
+        source: &str,
+        evse_id: Option<i32>,
+    ) -> DomainResult<u64> {
+        self.repos
+            .charging_profiles()
+            .deactivate_by_source(charge_point_id, source, evse_id)
+            .await
+    }
+
     /// Get the parent id_tag for a given id_tag (used for group authorization).
     pub async fn get_id_tag_parent(&self, id_tag: &str) -> DomainResult<Option<String>> {
         self.repos.id_tags().get_parent_id_tag(id_tag).await
@@ -0,0 +1,143 @@
+//! Background task that records connection history for availability reports.
+//!
+//! Listens to local events and writes:
+//! - one `ConnectionSession` per WebSocket connection
+//!   (`ChargePointConnected` → `ChargePointDisconnected`)
+//! - a `ConnectorStatusInterval` whenever a connector changes status or
+//!   reports a different error, with the station's firmware at the time
+//!
+//! In cluster mode every node records the stations connected to it.
+
+use std::sync::Arc;
+
+use tracing::{info, warn};
+
+use crate::application::events::{Event, SharedEventBus};
+use crate::domain::{ConnectionSession, ConnectorStatusInterval, DomainResult, RepositoryProvider};
+use crate::shared::shutdown::ShutdownSignal;
+
+/// Start the connection history recorder.
+///
+/// With `close_stale_sessions`, sessions left open by an unclean shutdown
+/// are first closed at the station's last heartbeat. Only safe when no
+/// other node can hold those connections.
+pub fn start_connection_history_task(
+    repos: Arc<dyn RepositoryProvider>,
+    event_bus: SharedEventBus,
+    shutdown: ShutdownSignal,
+    close_stale_sessions: bool,
+) {
+    let mut subscriber = event_bus.subscribe();
+    tokio::spawn(async move {
+        info!("📈 Connection history recorder started");
+
+        if close_stale_sessions {
+            if let Err(e) = close_stale(&repos).await {
+                warn!(error = %e, "Failed to close stale connection sessions");
+            }
+        }
+
+        loop {
+            tokio::select! {
+                msg = subscriber.recv() => {
+                    let Some(msg) = msg else { break };
+                    if let Err(e) = record(&repos, &msg.event).await {
+                        warn!(error = %e, "Failed to record connection history");
+                    }
+                }
+                _ = shutdown.notified().wait() => {
+                    info!("📈 Connection history recorder shutting down");
+                    break;
+                }
+            }
+        }
+    });
+}
+
+async fn record(repos: &Arc<dyn RepositoryProvider>, event: &Event) -> DomainResult<()> {
+    let history = repos.availability();
+    match event {
+        Event::ChargePointConnected(e) => {
+            // A session still open here lost its disconnect (e.g. eviction)
+            for open in history
+                .find_open_sessions(Some(e.charge_point_id.as_str()))
+                .await?
+            {
+                history
+                    .close_session(
+                        open.id,
+                        e.timestamp,
+                        Some("Replaced by new connection".to_string()),
+                    )
+                    .await?;
+            }
+            history
+                .open_session(ConnectionSession::open(
+                    &e.charge_point_id,
+                    &e.ocpp_version,
+                    e.remote_addr.clone(),
+                    e.timestamp,
+                ))
+                .await?;
+        }
+        Event::ChargePointDisconnected(e) => {
+            for open in history
+                .find_open_sessions(Some(e.charge_point_id.as_str()))
+                .await?
+            {
+                history
+                    .close_session(open.id, e.timestamp, e.reason.clone())
+                    .await?;
+            }
+        }
+        Event::ConnectorStatusChanged(e) => {
+            let firmware_version = repos
+                .charge_points()
+                .find_by_id(&e.charge_point_id)
+                .await?
+                .and_then(|cp| cp.firmware_version);
+            history
+                .record_connector_status(ConnectorStatusInterval {
+                    info: e.info.clone(),
+                    vendor_id: e.vendor_id.clone(),
+                    vendor_error_code: e.vendor_error_code.clone(),
+                    firmware_version,
+                    ..ConnectorStatusInterval::new(
+                        &e.charge_point_id,
+                        e.connector_id,
+                        &e.status,
+                        e.error_code.clone().filter(|c| c != "NoError"),
+                        e.timestamp,
+                    )
+                })
+                .await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Close sessions a crashed process never closed, at the last sign of life.
+async fn close_stale(repos: &Arc<dyn RepositoryProvider>) -> DomainResult<()> {
+    let stale = repos.availability().find_open_sessions(None).await?;
+    if stale.is_empty() {
+        return Ok(());
+    }
+
+    info!(count = stale.len(), "Closing connection sessions left open");
+    for session in stale {
+        let last_heartbeat = repos
+            .charge_points()
+            .find_by_id(&session.charge_point_id)
+            .await?
+            .and_then(|cp| cp.last_heartbeat);
+        let ended = last_heartbeat
+            .unwrap_or(session.connected_at)
+            .max(session.connected_at);
+        repos
+            .availability()
+            .close_session(session.id, ended, Some("Server restarted".to_string()))
+            .await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,248 @@
+//! In-memory store for NotifyCustomerInformation aggregation.
+//!
+//! After a `CustomerInformation` request with `report = true`, the charge
+//! point sends the customer data it holds in one or more
+//! `NotifyCustomerInformation` messages (`tbc = true` until the last one,
+//! numbered by `seqNo`). This store collects all parts keyed by
+//! `(charge_point_id, request_id)` and assembles them in sequence order.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A single NotifyCustomerInformation part.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CustomerDataPart {
+    /// Sequence number of this part (starts at 0).
+    pub seq_no: i32,
+    /// When the charge point generated this part.
+    pub generated_at: DateTime<Utc>,
+    /// Raw customer data as sent by the charge point.
+    pub data: String,
+}
+
+/// Aggregated customer information for a single CustomerInformation request.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CustomerInformationReport {
+    /// The charge point ID.
+    pub charge_point_id: String,
+    /// The request ID from CustomerInformation.
+    pub request_id: i32,
+    /// When the request was sent (or the first part received).
+    pub started_at: DateTime<Utc>,
+    /// When the last part was received (tbc=false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Whether more parts are expected.
+    pub in_progress: bool,
+    /// Total number of parts received.
+    pub parts_received: i32,
+    /// Parts ordered by `seq_no`.
+    pub parts: Vec<CustomerDataPart>,
+}
+
+impl CustomerInformationReport {
+    /// All parts concatenated in sequence order.
+    pub fn data(&self) -> String {
+        self.parts.iter().map(|p| p.data.as_str()).collect()
+    }
+}
+
+/// Key for the customer information store.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct ReportKey {
+    charge_point_id: String,
+    request_id: i32,
+}
+
+/// Thread-safe in-memory store for customer information reports.
+#[derive(Debug, Clone)]
+pub struct CustomerInformationStore {
+    reports: Arc<DashMap<ReportKey, CustomerInformationReport>>,
+    /// charge_point_id → latest request_id.
+    latest: Arc<DashMap<String, i32>>,
+}
+
+impl Default for CustomerInformationStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CustomerInformationStore {
+    pub fn new() -> Self {
+        Self {
+            reports: Arc::new(DashMap::new()),
+            latest: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Initialize an empty report when CustomerInformation is sent.
+    pub fn init_report(&self, charge_point_id: &str, request_id: i32) {
+        let key = ReportKey {
+            charge_point_id: charge_point_id.to_string(),
+            request_id,
+        };
+        self.reports.insert(
+            key,
+            CustomerInformationReport {
+                charge_point_id: charge_point_id.to_string(),
+                request_id,
+                started_at: Utc::now(),
+                completed_at: None,
+                in_progress: true,
+                parts_received: 0,
+                parts: Vec::new(),
+            },
+        );
+        self.latest.insert(charge_point_id.to_string(), request_id);
+    }
+
+    /// Append a part from a NotifyCustomerInformation message.
+    ///
+    /// Parts may arrive out of order; they are kept sorted by `seq_no`.
+    /// `tbc` = false marks the report as complete.
+    pub fn append_part(
+        &self,
+        charge_point_id: &str,
+        request_id: i32,
+        part: CustomerDataPart,
+        tbc: bool,
+    ) {
+        let key = ReportKey {
+            charge_point_id: charge_point_id.to_string(),
+            request_id,
+        };
+
+        let mut report = self
+            .reports
+            .entry(key)
+            .or_insert_with(|| CustomerInformationReport {
+                charge_point_id: charge_point_id.to_string(),
+                request_id,
+                started_at: Utc::now(),
+                completed_at: None,
+                in_progress: true,
+                parts_received: 0,
+                parts: Vec::new(),
+            });
+
+        let pos = report.parts.partition_point(|p| p.seq_no <= part.seq_no);
+        report.parts.insert(pos, part);
+        report.parts_received += 1;
+        if !tbc {
+            report.in_progress = false;
+            report.completed_at = Some(Utc::now());
+        }
+        drop(report);
+
+        self.latest
+            .entry(charge_point_id.to_string())
+            .or_insert(request_id);
+    }
+
+    /// Get the latest report for a charge point.
+    pub fn get_latest_report(&self, charge_point_id: &str) -> Option<CustomerInformationReport> {
+        let request_id = *self.latest.get(charge_point_id)?;
+        self.get_report(charge_point_id, request_id)
+    }
+
+    /// Get a specific report by charge_point_id and request_id.
+    pub fn get_report(
+        &self,
+        charge_point_id: &str,
+        request_id: i32,
+    ) -> Option<CustomerInformationReport> {
+        let key = ReportKey {
+            charge_point_id: charge_point_id.to_string(),
+            request_id,
+        };
+        self.reports.get(&key).map(|r| r.clone())
+    }
+
+    /// Drop a report once it has been handed over (customer data should
+    /// not linger in memory longer than needed).
+    pub fn remove_report(&self, charge_point_id: &str, request_id: i32) -> bool {
+        let key = ReportKey {
+            charge_point_id: charge_point_id.to_string(),
+            request_id,
+        };
+        self.latest
+            .remove_if(charge_point_id, |_, latest| *latest == request_id);
+        self.reports.remove(&key).is_some()
+    }
+}
+
+/// Shared customer information store.
+pub type SharedCustomerInformationStore = Arc<CustomerInformationStore>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(seq_no: i32, data: &str) -> CustomerDataPart {
+        CustomerDataPart {
+            seq_no,
+            generated_at: Utc::now(),
+            data: data.to_string(),
+        }
+    }
+
+    #[test]
+    fn init_report_creates_empty() {
+        let store = CustomerInformationStore::new();
+        store.init_report("CP001", 7);
+
+        let report = store.get_latest_report("CP001").unwrap();
+        assert_eq!(report.request_id, 7);
+        assert!(report.in_progress);
+        assert_eq!(report.parts_received, 0);
+        assert_eq!(report.data(), "");
+    }
+
+    #[test]
+    fn multi_part_report_is_assembled_in_seq_order() {
+        let store = CustomerInformationStore::new();
+        store.init_report("CP001", 1);
+
+        store.append_part("CP001", 1, part(1, "world"), true);
+        store.append_part("CP001", 1, part(0, "hello "), true);
+
+        let report = store.get_report("CP001", 1).unwrap();
+        assert!(report.in_progress);
+        assert_eq!(report.data(), "hello world");
+
+        store.append_part("CP001", 1, part(2, "!"), false);
+
+        let report = store.get_report("CP001", 1).unwrap();
+        assert!(!report.in_progress);
+        assert!(report.completed_at.is_some());
+        assert_eq!(report.parts_received, 3);
+        assert_eq!(report.data(), "hello world!");
+    }
+
+    #[test]
+    fn append_without_init_creates_report() {
+        let store = CustomerInformationStore::new();
+        store.append_part("CP001", 3, part(0, "data"), false);
+
+        let report = store.get_latest_report("CP001").unwrap();
+        assert_eq!(report.request_id, 3);
+        assert!(!report.in_progress);
+    }
+
+    #[test]
+    fn remove_report_forgets_latest() {
+        let store = CustomerInformationStore::new();
+        store.init_report("CP001", 1);
+        store.append_part("CP001", 1, part(0, "data"), false);
+
+        assert!(store.remove_report("CP001", 1));
+        assert!(store.get_report("CP001", 1).is_none());
+        assert!(store.get_latest_report("CP001").is_none());
+        assert!(!store.remove_report("CP001", 1));
+    }
+}
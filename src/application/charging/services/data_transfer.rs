@@ -0,0 +1,272 @@
+//! Vendor DataTransfer routing.
+//!
+//! DataTransfer is how stations expose vendor features — QR-code payment,
+//! display text and the like. Extension handlers implement
+//! [`DataTransferHandler`] and are registered at startup under a `vendorId`,
+//! optionally narrowed to a single `messageId`. Each incoming request goes to
+//! the most specific handler; without one the station is answered
+//! `UnknownVendorId` or `UnknownMessageId`. Every exchange is persisted and
+//! published as [`Event::DataTransferReceived`].
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tracing::{info, warn};
+
+use crate::application::events::{DataTransferReceivedEvent, Event, SharedEventBus};
+use crate::domain::{DataTransferDirection, DataTransferRecord, OcppVersion, RepositoryProvider};
+
+/// Status answered to a DataTransfer request (same values in 1.6 and 2.0.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataTransferStatus {
+    Accepted,
+    Rejected,
+    UnknownMessageId,
+    UnknownVendorId,
+}
+
+impl DataTransferStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Accepted => "Accepted",
+            Self::Rejected => "Rejected",
+            Self::UnknownMessageId => "UnknownMessageId",
+            Self::UnknownVendorId => "UnknownVendorId",
+        }
+    }
+}
+
+/// A DataTransfer request received from a charge point.
+#[derive(Debug, Clone)]
+pub struct IncomingDataTransfer {
+    pub charge_point_id: String,
+    pub ocpp_version: OcppVersion,
+    pub vendor_id: String,
+    pub message_id: Option<String>,
+    pub data: Option<String>,
+}
+
+/// What a handler answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataTransferReply {
+    pub status: DataTransferStatus,
+    pub data: Option<String>,
+}
+
+impl DataTransferReply {
+    pub fn accepted(data: Option<String>) -> Self {
+        Self {
+            status: DataTransferStatus::Accepted,
+            data,
+        }
+    }
+
+    pub fn rejected() -> Self {
+        Self {
+            status: DataTransferStatus::Rejected,
+            data: None,
+        }
+    }
+}
+
+/// A vendor extension answering DataTransfer requests.
+#[async_trait]
+pub trait DataTransferHandler: Send + Sync {
+    async fn handle(&self, request: &IncomingDataTransfer) -> DataTransferReply;
+}
+
+type HandlerKey = (String, Option<String>);
+
+/// DataTransfer handlers by `(vendorId, messageId)`, filled at startup.
+#[derive(Default)]
+pub struct DataTransferRegistry {
+    handlers: HashMap<HandlerKey, Arc<dyn DataTransferHandler>>,
+}
+
+impl DataTransferRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Route `vendor_id` to `handler` — only `message_id` when given, every
+    /// message of the vendor otherwise. Replaces an earlier registration.
+    pub fn register(
+        &mut self,
+        vendor_id: impl Into<String>,
+        message_id: Option<&str>,
+        handler: Arc<dyn DataTransferHandler>,
+    ) -> &mut Self {
+        self.handlers
+            .insert((vendor_id.into(), message_id.map(str::to_string)), handler);
+        self
+    }
+
+    /// The handler for a request, or the status to answer when there is none.
+    pub fn resolve(
+        &self,
+        vendor_id: &str,
+        message_id: Option<&str>,
+    ) -> Result<Arc<dyn DataTransferHandler>, DataTransferStatus> {
+        let exact = message_id.and_then(|m| {
+            self.handlers
+                .get(&(vendor_id.to_string(), Some(m.to_string())))
+        });
+        if let Some(handler) = exact.or_else(|| self.handlers.get(&(vendor_id.to_string(), None))) {
+            return Ok(handler.clone());
+        }
+        if self.handlers.keys().any(|(vendor, _)| vendor == vendor_id) {
+            Err(DataTransferStatus::UnknownMessageId)
+        } else {
+            Err(DataTransferStatus::UnknownVendorId)
+        }
+    }
+}
+
+/// Routes incoming DataTransfer requests and keeps their history.
+pub struct DataTransferService {
+    registry: DataTransferRegistry,
+    repos: Arc<dyn RepositoryProvider>,
+    event_bus: SharedEventBus,
+}
+
+pub type SharedDataTransferService = Arc<DataTransferService>;
+
+impl DataTransferService {
+    pub fn new(
+        registry: DataTransferRegistry,
+        repos: Arc<dyn RepositoryProvider>,
+        event_bus: SharedEventBus,
+    ) -> Self {
+        Self {
+            registry,
+            repos,
+            event_bus,
+        }
+    }
+
+    /// Answer a DataTransfer from a charge point, then persist and publish it.
+    pub async fn handle_incoming(&self, request: IncomingDataTransfer) -> DataTransferReply {
+        let reply = match self
+            .registry
+            .resolve(&request.vendor_id, request.message_id.as_deref())
+        {
+            Ok(handler) => handler.handle(&request).await,
+            Err(status) => {
+                info!(
+                    charge_point_id = request.charge_point_id.as_str(),
+                    vendor_id = request.vendor_id.as_str(),
+                    message_id = ?request.message_id,
+                    status = status.as_str(),
+                    "No DataTransfer handler registered"
+                );
+                DataTransferReply { status, data: None }
+            }
+        };
+
+        let record = DataTransferRecord {
+            id: 0,
+            charge_point_id: request.charge_point_id.clone(),
+            direction: DataTransferDirection::Inbound,
+            vendor_id: request.vendor_id.clone(),
+            message_id: request.message_id.clone(),
+            request_data: request.data.clone(),
+            status: reply.status.as_str().to_string(),
+            response_data: reply.data.clone(),
+            created_at: Utc::now(),
+        };
+        if let Err(e) = self.repos.data_transfers().record(record).await {
+            warn!(
+                charge_point_id = request.charge_point_id.as_str(),
+                error = %e,
+                "Failed to persist DataTransfer"
+            );
+        }
+
+        self.event_bus
+            .publish(Event::DataTransferReceived(DataTransferReceivedEvent {
+                charge_point_id: request.charge_point_id,
+                vendor_id: request.vendor_id,
+                message_id: request.message_id,
+                data: request.data,
+                status: reply.status.as_str().to_string(),
+                response_data: reply.data.clone(),
+                timestamp: Utc::now(),
+            }));
+
+        reply
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo;
+
+    #[async_trait]
+    impl DataTransferHandler for Echo {
+        async fn handle(&self, request: &IncomingDataTransfer) -> DataTransferReply {
+            DataTransferReply::accepted(request.data.clone())
+        }
+    }
+
+    struct Refuse;
+
+    #[async_trait]
+    impl DataTransferHandler for Refuse {
+        async fn handle(&self, _request: &IncomingDataTransfer) -> DataTransferReply {
+            DataTransferReply::rejected()
+        }
+    }
+
+    fn request(vendor_id: &str, message_id: Option<&str>) -> IncomingDataTransfer {
+        IncomingDataTransfer {
+            charge_point_id: "CP001".into(),
+            ocpp_version: OcppVersion::V16,
+            vendor_id: vendor_id.into(),
+            message_id: message_id.map(str::to_string),
+            data: Some("payload".into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn most_specific_handler_wins() {
+        let mut registry = DataTransferRegistry::new();
+        registry
+            .register("com.acme", None, Arc::new(Refuse))
+            .register("com.acme", Some("QrPayment"), Arc::new(Echo));
+
+        let handler = registry.resolve("com.acme", Some("QrPayment")).unwrap();
+        let reply = handler
+            .handle(&request("com.acme", Some("QrPayment")))
+            .await;
+        assert_eq!(reply, DataTransferReply::accepted(Some("payload".into())));
+
+        let handler = registry.resolve("com.acme", Some("DisplayText")).unwrap();
+        let reply = handler
+            .handle(&request("com.acme", Some("DisplayText")))
+            .await;
+        assert_eq!(reply.status, DataTransferStatus::Rejected);
+    }
+
+    #[test]
+    fn unregistered_vendor_and_message_are_reported() {
+        let mut registry = DataTransferRegistry::new();
+        registry.register("com.acme", Some("QrPayment"), Arc::new(Echo));
+
+        assert_eq!(
+            registry.resolve("com.acme", Some("DisplayText")).err(),
+            Some(DataTransferStatus::UnknownMessageId)
+        );
+        assert_eq!(
+            registry.resolve("com.acme", None).err(),
+            Some(DataTransferStatus::UnknownMessageId)
+        );
+        assert_eq!(
+            registry.resolve("com.other", Some("QrPayment")).err(),
+            Some(DataTransferStatus::UnknownVendorId)
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! Pushing heartbeat interval changes to connected charge points
+//!
+//! Stations learn their interval from the BootNotification response. When
+//! the interval configured for a connected station no longer matches the one
+//! it accepted — after a configuration reload, or when it reconnects without
+//! booting — the new value is sent as the `HeartbeatInterval` configuration
+//! key (OCPP 1.6) or the `OCPPCommCtrlr.HeartbeatInterval` variable
+//! (OCPP 2.0.1). Accepted values are recorded on the charge point.
+//!
+//! In cluster mode every node updates the stations connected to it.
+
+use std::sync::Arc;
+
+use futures_util::future::join_all;
+use tracing::{info, warn};
+
+use super::heartbeat_monitor::SharedHeartbeatConfig;
+use crate::application::charging::commands::{CommandError, SharedCommandDispatcher};
+use crate::application::events::{Event, SharedEventBus};
+use crate::application::SharedSessionRegistry;
+use crate::domain::{OcppVersion, RepositoryProvider};
+use crate::shared::shutdown::ShutdownSignal;
+
+/// Keeps the heartbeat interval of connected stations in line with the
+/// configuration.
+pub struct HeartbeatIntervalSync {
+    repos: Arc<dyn RepositoryProvider>,
+    session_registry: SharedSessionRegistry,
+    command_dispatcher: SharedCommandDispatcher,
+    config: SharedHeartbeatConfig,
+}
+
+impl HeartbeatIntervalSync {
+    pub fn new(
+        repos: Arc<dyn RepositoryProvider>,
+        session_registry: SharedSessionRegistry,
+        command_dispatcher: SharedCommandDispatcher,
+        config: SharedHeartbeatConfig,
+    ) -> Self {
+        Self {
+            repos,
+            session_registry,
+            command_dispatcher,
+            config,
+        }
+    }
+
+    /// Push the configured interval to every station connected to this node
+    /// that accepted a different one. Returns how many accepted the change.
+    pub async fn sync_connected(&self) -> usize {
+        let ids = self.session_registry.local_ids();
+        join_all(ids.iter().map(|id| self.sync(id)))
+            .await
+            .into_iter()
+            .filter(|updated| *updated)
+            .count()
+    }
+
+    /// Push the configured interval to `charge_point_id` if it accepted a
+    /// different one. Stations that never booted are left to their
+    /// BootNotification. Returns whether a new interval was accepted.
+    pub async fn sync(&self, charge_point_id: &str) -> bool {
+        let Some(version) = self.session_registry.get_version(charge_point_id) else {
+            return false;
+        };
+        let accepted = match self.repos.charge_points().find_by_id(charge_point_id).await {
+            Ok(Some(cp)) => cp.heartbeat_interval,
+            Ok(None) => None,
+            Err(e) => {
+                warn!(charge_point_id, error = %e, "Failed to load charge point");
+                return false;
+            }
+        };
+        let interval = self.config.get().interval_for(charge_point_id);
+        if accepted.is_none_or(|accepted| accepted == interval) {
+            return false;
+        }
+
+        match self.push(charge_point_id, version, interval).await {
+            Ok(true) => {
+                info!(charge_point_id, interval, "💓 Heartbeat interval changed");
+                if let Err(e) = self
+                    .repos
+                    .charge_points()
+                    .set_heartbeat_interval(charge_point_id, Some(interval))
+                    .await
+                {
+                    warn!(charge_point_id, error = %e, "Failed to record heartbeat interval");
+                }
+                true
+            }
+            Ok(false) => false,
+            Err(e) => {
+                warn!(charge_point_id, error = %e, "Failed to change heartbeat interval");
+                false
+            }
+        }
+    }
+
+    /// Send the interval; `Ok(false)` when the station did not accept it.
+    async fn push(
+        &self,
+        charge_point_id: &str,
+        version: OcppVersion,
+        interval: i32,
+    ) -> Result<bool, CommandError> {
+        let status = match version {
+            OcppVersion::V16 => {
+                self.command_dispatcher
+                    .change_configuration(
+                        charge_point_id,
+                        "HeartbeatInterval".to_string(),
+                        interval.to_string(),
+                    )
+                    .await?
+            }
+            OcppVersion::V201 | OcppVersion::V21 => self
+                .command_dispatcher
+                .set_variables(
+                    charge_point_id,
+                    vec![(
+                        "OCPPCommCtrlr".to_string(),
+                        "HeartbeatInterval".to_string(),
+                        interval.to_string(),
+                    )],
+                )
+                .await?
+                .results
+                .into_iter()
+                .next()
+                .map(|r| r.status)
+                .unwrap_or_default(),
+        };
+
+        if status != "Accepted" {
+            warn!(
+                charge_point_id,
+                interval,
+                status = status.as_str(),
+                "Heartbeat interval not accepted"
+            );
+        }
+        Ok(status == "Accepted")
+    }
+
+    /// Update stations as they connect, until `shutdown`.
+    pub fn start(self: Arc<Self>, event_bus: SharedEventBus, shutdown: ShutdownSignal) {
+        let mut subscriber = event_bus.subscribe();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    msg = subscriber.recv() => {
+                        let Some(msg) = msg else { break };
+                        if let Event::ChargePointConnected(e) = &msg.event {
+                            let sync = self.clone();
+                            let charge_point_id = e.charge_point_id.clone();
+                            tokio::spawn(async move { sync.sync(&charge_point_id).await });
+                        }
+                    }
+                    _ = shutdown.notified().wait() => break,
+                }
+            }
+        });
+    }
+}
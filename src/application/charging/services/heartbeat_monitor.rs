@@ -1,6 +1,11 @@
 //! Heartbeat Monitor Service
 //!
 //! Monitors charge point heartbeats and marks stations as offline.
+//!
+//! How long a station may stay silent depends on the heartbeat interval it
+//! accepted: thresholds grow to cover a few missed heartbeats, so stations
+//! given a long interval (e.g. over cellular) are not flagged between
+//! heartbeats. Per-station overrides replace the interval or thresholds.
 
 use std::sync::Arc;
 use std::time::Duration;
@@ -10,7 +15,7 @@ use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 
 use crate::application::SharedSessionRegistry;
-use crate::domain::{ChargePointStatus, DomainResult, RepositoryProvider};
+use crate::domain::{ChargePoint, ChargePointStatus, DomainResult, RepositoryProvider};
 use crate::shared::shutdown::ShutdownSignal;
 
 /// Configuration for heartbeat monitoring
@@ -19,6 +24,16 @@ pub struct HeartbeatConfig {
     pub check_interval_secs: u64,
     pub offline_threshold_secs: i64,
     pub unavailable_threshold_secs: i64,
+    /// Heartbeat interval given to charge points on BootNotification (seconds)
+    pub interval_secs: i32,
+    /// Missed heartbeats after which a station counts as stale (0 = only
+    /// `offline_threshold_secs`)
+    pub offline_after_missed: u32,
+    /// Missed heartbeats after which a station is marked Unavailable
+    /// (0 = only `unavailable_threshold_secs`)
+    pub unavailable_after_missed: u32,
+    /// Per-charge-point settings; the first one listing a station applies
+    pub overrides: Vec<HeartbeatOverride>,
 }
 
 impl Default for HeartbeatConfig {
@@ -27,10 +42,97 @@ impl Default for HeartbeatConfig {
             check_interval_secs: 60,
             offline_threshold_secs: 180,
             unavailable_threshold_secs: 600,
+            interval_secs: 300,
+            offline_after_missed: 2,
+            unavailable_after_missed: 3,
+            overrides: Vec::new(),
+        }
+    }
+}
+
+/// Heartbeat settings for specific charge points
+#[derive(Debug, Clone, Default)]
+pub struct HeartbeatOverride {
+    pub charge_point_ids: Vec<String>,
+    pub interval_secs: Option<i32>,
+    /// Used as is, without growing with the interval
+    pub offline_threshold_secs: Option<i64>,
+    /// Used as is, without growing with the interval
+    pub unavailable_threshold_secs: Option<i64>,
+}
+
+/// Silence allowed before a station changes status (seconds)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeartbeatThresholds {
+    pub offline_secs: i64,
+    pub unavailable_secs: i64,
+}
+
+impl HeartbeatConfig {
+    fn override_for(&self, charge_point_id: &str) -> Option<&HeartbeatOverride> {
+        self.overrides
+            .iter()
+            .find(|o| o.charge_point_ids.iter().any(|id| id == charge_point_id))
+    }
+
+    /// Heartbeat interval `charge_point_id` should use
+    pub fn interval_for(&self, charge_point_id: &str) -> i32 {
+        self.override_for(charge_point_id)
+            .and_then(|o| o.interval_secs)
+            .unwrap_or(self.interval_secs)
+    }
+
+    /// Thresholds for `charge_point_id`, which last accepted
+    /// `accepted_interval` (the configured one when unknown).
+    pub fn thresholds_for(
+        &self,
+        charge_point_id: &str,
+        accepted_interval: Option<i32>,
+    ) -> HeartbeatThresholds {
+        let overridden = self.override_for(charge_point_id);
+        let interval = accepted_interval
+            .unwrap_or_else(|| self.interval_for(charge_point_id))
+            .max(0) as i64;
+
+        let offline_secs = overridden
+            .and_then(|o| o.offline_threshold_secs)
+            .unwrap_or_else(|| {
+                self.offline_threshold_secs
+                    .max(interval * self.offline_after_missed as i64)
+            });
+        let unavailable_secs = overridden
+            .and_then(|o| o.unavailable_threshold_secs)
+            .unwrap_or_else(|| {
+                self.unavailable_threshold_secs
+                    .max(interval * self.unavailable_after_missed as i64)
+            });
+
+        HeartbeatThresholds {
+            offline_secs,
+            unavailable_secs: unavailable_secs.max(offline_secs),
         }
     }
 }
 
+/// Heartbeat configuration shared by the monitor, BootNotification handling
+/// and interval pushes. Configuration reloads replace it.
+#[derive(Debug, Clone, Default)]
+pub struct SharedHeartbeatConfig(Arc<std::sync::RwLock<HeartbeatConfig>>);
+
+impl SharedHeartbeatConfig {
+    pub fn new(config: HeartbeatConfig) -> Self {
+        Self(Arc::new(std::sync::RwLock::new(config)))
+    }
+
+    pub fn get(&self) -> HeartbeatConfig {
+        self.0.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn set(&self, config: HeartbeatConfig) {
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = config;
+    }
+}
+
 /// Information about a charge point's heartbeat status
 #[derive(Debug, Clone)]
 pub struct HeartbeatStatus {
@@ -40,8 +142,10 @@ pub struct HeartbeatStatus {
     pub is_connected: bool,
     pub status: ChargePointStatus,
     pub seconds_since_heartbeat: Option<i64>,
+    /// Interval the station last accepted
+    pub heartbeat_interval: Option<i32>,
+    pub thresholds: HeartbeatThresholds,
 }
-
 /// Connection statistics
 #[derive(Debug, Clone)]
 pub struct ConnectionStats {
@@ -55,7 +159,8 @@ pub struct ConnectionStats {
 pub struct HeartbeatMonitor {
     repos: Arc<dyn RepositoryProvider>,
     session_registry: SharedSessionRegistry,
-    config: HeartbeatConfig,
+    /// Read on every check
+    config: SharedHeartbeatConfig,
     running: Arc<RwLock<bool>>,
 }
 
@@ -64,16 +169,31 @@ impl HeartbeatMonitor {
         Self {
             repos,
             session_registry,
-            config: HeartbeatConfig::default(),
+            config: SharedHeartbeatConfig::default(),
             running: Arc::new(RwLock::new(false)),
         }
     }
 
-    pub fn with_config(mut self, config: HeartbeatConfig) -> Self {
+    pub fn with_config(self, config: HeartbeatConfig) -> Self {
+        self.set_config(config);
+        self
+    }
+
+    /// Use a configuration shared with other services.
+    pub fn with_shared_config(mut self, config: SharedHeartbeatConfig) -> Self {
         self.config = config;
         self
     }
 
+    /// Change the thresholds; a running monitor uses them from its next check.
+    pub fn set_config(&self, config: HeartbeatConfig) {
+        self.config.set(config);
+    }
+
+    pub fn config(&self) -> HeartbeatConfig {
+        self.config.get()
+    }
+
     pub fn start(&self, shutdown: ShutdownSignal) {
         let repos = self.repos.clone();
         let session_registry = self.session_registry.clone();
@@ -86,22 +206,22 @@ impl HeartbeatMonitor {
                 *r = true;
             }
 
+            let initial = config.get();
             info!(
-                check_interval = config.check_interval_secs,
-                offline_threshold = config.offline_threshold_secs,
+                check_interval = initial.check_interval_secs,
+                offline_threshold = initial.offline_threshold_secs,
+                heartbeat_interval = initial.interval_secs,
                 "💓 Heartbeat monitor started"
             );
 
-            let mut interval =
-                tokio::time::interval(Duration::from_secs(config.check_interval_secs));
-
             loop {
+                let current = config.get();
+                if let Err(e) = check_heartbeats(&repos, &session_registry, &current).await {
+                    warn!(error = %e, "Heartbeat check error");
+                }
+
                 tokio::select! {
-                    _ = interval.tick() => {
-                        if let Err(e) = check_heartbeats(&repos, &session_registry, &config).await {
-                            warn!(error = %e, "Heartbeat check error");
-                        }
-                    }
+                    _ = tokio::time::sleep(Duration::from_secs(current.check_interval_secs)) => {}
                     _ = shutdown.notified().wait() => {
                         info!("💓 Heartbeat monitor shutting down");
                         break;
@@ -124,49 +244,44 @@ impl HeartbeatMonitor {
 
     pub async fn get_all_statuses(&self) -> DomainResult<Vec<HeartbeatStatus>> {
         let charge_points = self.repos.charge_points().find_all().await?;
+        let config = self.config();
         let now = Utc::now();
 
-        let statuses = charge_points
+        Ok(charge_points
             .into_iter()
-            .map(|cp| {
-                let is_connected = self.session_registry.is_connected(&cp.id);
-                let seconds_since = cp
-                    .last_heartbeat
-                    .map(|hb| now.signed_duration_since(hb).num_seconds());
-
-                HeartbeatStatus {
-                    charge_point_id: cp.id.clone(),
-                    last_heartbeat: cp.last_heartbeat,
-                    last_seen: cp.last_heartbeat,
-                    is_connected,
-                    status: cp.status,
-                    seconds_since_heartbeat: seconds_since,
-                }
-            })
-            .collect();
-
-        Ok(statuses)
+            .map(|cp| self.status_of(cp, &config, now))
+            .collect())
     }
 
     pub async fn get_status(&self, charge_point_id: &str) -> DomainResult<Option<HeartbeatStatus>> {
         let cp = self.repos.charge_points().find_by_id(charge_point_id).await?;
+        let config = self.config();
         let now = Utc::now();
 
-        Ok(cp.map(|cp| {
-            let is_connected = self.session_registry.is_connected(&cp.id);
-            let seconds_since = cp
-                .last_heartbeat
-                .map(|hb| now.signed_duration_since(hb).num_seconds());
+        Ok(cp.map(|cp| self.status_of(cp, &config, now)))
+    }
 
-            HeartbeatStatus {
-                charge_point_id: cp.id.clone(),
-                last_heartbeat: cp.last_heartbeat,
-                last_seen: cp.last_heartbeat,
-                is_connected,
-                status: cp.status,
-                seconds_since_heartbeat: seconds_since,
-            }
-        }))
+    fn status_of(
+        &self,
+        cp: ChargePoint,
+        config: &HeartbeatConfig,
+        now: DateTime<Utc>,
+    ) -> HeartbeatStatus {
+        let is_connected = self.session_registry.is_connected(&cp.id);
+        let seconds_since = cp
+            .last_heartbeat
+            .map(|hb| now.signed_duration_since(hb).num_seconds());
+
+        HeartbeatStatus {
+            thresholds: config.thresholds_for(&cp.id, cp.heartbeat_interval),
+            charge_point_id: cp.id,
+            last_heartbeat: cp.last_heartbeat,
+            last_seen: cp.last_heartbeat,
+            is_connected,
+            status: cp.status,
+            seconds_since_heartbeat: seconds_since,
+            heartbeat_interval: cp.heartbeat_interval,
+        }
     }
 
     pub async fn get_online_charge_points(&self) -> Vec<String> {
@@ -176,6 +291,7 @@ impl HeartbeatMonitor {
     pub async fn get_connection_stats(&self) -> DomainResult<ConnectionStats> {
         let charge_points = self.repos.charge_points().find_all().await?;
         let total = charge_points.len();
+        let config = self.config();
 
         let online = charge_points
             .iter()
@@ -189,7 +305,8 @@ impl HeartbeatMonitor {
             .filter(|cp| {
                 if let Some(hb) = cp.last_heartbeat {
                     let elapsed = Utc::now().signed_duration_since(hb).num_seconds();
-                    elapsed > self.config.offline_threshold_secs
+                    let thresholds = config.thresholds_for(&cp.id, cp.heartbeat_interval);
+                    elapsed > thresholds.offline_secs
                 } else {
                     true
                 }
@@ -218,11 +335,12 @@ async fn check_heartbeats(
     for cp in charge_points {
         let is_connected = session_registry.is_connected(&cp.id);
         let current_status = cp.status.clone();
+        let thresholds = config.thresholds_for(&cp.id, cp.heartbeat_interval);
 
         let new_status = if is_connected {
             if let Some(last_hb) = cp.last_heartbeat {
                 let elapsed = now.signed_duration_since(last_hb).num_seconds();
-                if elapsed > config.unavailable_threshold_secs {
+                if elapsed > thresholds.unavailable_secs {
                     ChargePointStatus::Unavailable
                 } else {
                     ChargePointStatus::Online
@@ -232,7 +350,7 @@ async fn check_heartbeats(
             }
         } else if let Some(last_hb) = cp.last_heartbeat {
             let elapsed = now.signed_duration_since(last_hb).num_seconds();
-            if elapsed > config.unavailable_threshold_secs {
+            if elapsed > thresholds.unavailable_secs {
                 ChargePointStatus::Unavailable
             } else {
                 ChargePointStatus::Offline
@@ -266,3 +384,66 @@ async fn check_heartbeats(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cellular() -> HeartbeatOverride {
+        HeartbeatOverride {
+            charge_point_ids: vec!["CELL-1".into()],
+            interval_secs: Some(1800),
+            ..HeartbeatOverride::default()
+        }
+    }
+
+    #[test]
+    fn thresholds_cover_missed_heartbeats_at_the_accepted_interval() {
+        let config = HeartbeatConfig {
+            overrides: vec![cellular()],
+            ..HeartbeatConfig::default()
+        };
+
+        // Configured thresholds already cover a short interval
+        let short = config.thresholds_for("CP-1", Some(60));
+        assert_eq!(short.offline_secs, 180);
+        assert_eq!(short.unavailable_secs, 600);
+
+        // The default interval of 300s: two and three missed heartbeats
+        let default = config.thresholds_for("CP-1", None);
+        assert_eq!(default.offline_secs, 600);
+        assert_eq!(default.unavailable_secs, 900);
+
+        assert_eq!(config.interval_for("CELL-1"), 1800);
+        assert_eq!(config.interval_for("CP-1"), 300);
+        let cell = config.thresholds_for("CELL-1", Some(1800));
+        assert_eq!(cell.offline_secs, 3600);
+        assert_eq!(cell.unavailable_secs, 5400);
+
+        // Until the station accepts the new interval, its old one counts
+        let pending = config.thresholds_for("CELL-1", Some(300));
+        assert_eq!(pending.offline_secs, 600);
+    }
+
+    #[test]
+    fn explicit_thresholds_are_used_as_is() {
+        let config = HeartbeatConfig {
+            offline_after_missed: 0,
+            unavailable_after_missed: 0,
+            overrides: vec![HeartbeatOverride {
+                offline_threshold_secs: Some(7200),
+                ..cellular()
+            }],
+            ..HeartbeatConfig::default()
+        };
+
+        let fixed = config.thresholds_for("CP-1", Some(3600));
+        assert_eq!(fixed.offline_secs, 180);
+        assert_eq!(fixed.unavailable_secs, 600);
+
+        // Unavailable never comes before stale
+        let cell = config.thresholds_for("CELL-1", None);
+        assert_eq!(cell.offline_secs, 7200);
+        assert_eq!(cell.unavailable_secs, 7200);
+    }
+}
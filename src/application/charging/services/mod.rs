@@ -1,12 +1,25 @@
 //! Application services
 
-pub mod device_report;
 mod billing;
 mod charge_point;
+mod connection_history;
+pub mod customer_information;
+pub mod data_transfer;
+pub mod device_report;
+mod heartbeat_interval;
 mod heartbeat_monitor;
+pub mod plug_and_charge;
 mod reservation_expiry;
+mod reservation_scheduler;
+pub mod smart_charging;
 
 pub use billing::BillingService;
-pub use charge_point::{ChargePointService, PendingChargingLimit};
-pub use heartbeat_monitor::{ConnectionStats, HeartbeatConfig, HeartbeatMonitor, HeartbeatStatus};
+pub use charge_point::{ChargePointService, PendingChargingLimit, ReservationCheck};
+pub use connection_history::start_connection_history_task;
+pub use heartbeat_interval::HeartbeatIntervalSync;
+pub use heartbeat_monitor::{
+    ConnectionStats, HeartbeatConfig, HeartbeatMonitor, HeartbeatOverride, HeartbeatStatus,
+    HeartbeatThresholds, SharedHeartbeatConfig,
+};
 pub use reservation_expiry::start_reservation_expiry_task;
+pub use reservation_scheduler::start_reservation_scheduler_task;
@@ -0,0 +1,494 @@
+//! Plug & Charge (ISO 15118) support for OCPP 2.0.1.
+//!
+//! With Plug & Charge the EV identifies itself with a contract certificate
+//! instead of an RFID card. The CSMS takes part in three flows:
+//!
+//! - `Get15118EVCertificate` — the station forwards the EV's EXI-encoded
+//!   CertificateInstallationReq/CertificateUpdateReq; the answer comes from
+//!   the [`CertificatePool`] (a contract certificate pool / eMSP backend).
+//! - `GetCertificateStatus` — the station asks for the OCSP status of a
+//!   certificate it cannot check itself; answered by the [`OcspResponder`].
+//! - `Authorize` with `iso15118CertificateHashData` — the contract chain
+//!   must be issued under a configured contract root and the OCSP responder
+//!   must report none of its certificates revoked. The eMAID in the id
+//!   token then authorizes like any other id tag (see [`normalize_emaid`]).
+//!
+//! Deployments plug their PKI provider in through the traits. Without a
+//! certificate pool or OCSP responder the requests are answered `Failed`,
+//! and a chain whose revocation status is unknown is rejected unless
+//! [`PlugAndChargeService::accept_unknown_revocation`] is set.
+//! [`LocalCertificatePool`] and [`LocalOcspResponder`] are in-process
+//! stand-ins for development and tests only.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use tracing::{info, warn};
+
+/// `OCSPRequestDataType`: identifies one certificate of a chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcspRequestData {
+    /// `SHA256`, `SHA384` or `SHA512`
+    pub hash_algorithm: String,
+    /// Hash of the issuer's distinguished name (hex)
+    pub issuer_name_hash: String,
+    /// Hash of the issuer's public key (hex)
+    pub issuer_key_hash: String,
+    pub serial_number: String,
+    pub responder_url: String,
+}
+
+/// Revocation status reported by an OCSP responder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevocationStatus {
+    Good,
+    Revoked,
+    Unknown,
+}
+
+/// An OCSP answer for one certificate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcspResult {
+    pub status: RevocationStatus,
+    /// Base64 `OCSPResponse`, relayed to the station as `ocspResult`
+    pub response: String,
+}
+
+/// Answers OCSP requests for contract and sub-CA certificates.
+#[async_trait]
+pub trait OcspResponder: Send + Sync {
+    async fn check(&self, request: &OcspRequestData) -> Result<OcspResult, String>;
+}
+
+/// Whether the EV installs a first contract certificate or renews one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvCertificateAction {
+    Install,
+    Update,
+}
+
+/// A `Get15118EVCertificate` request forwarded by a station.
+#[derive(Debug, Clone)]
+pub struct EvCertificateRequest {
+    pub charge_point_id: String,
+    pub iso15118_schema_version: String,
+    pub action: EvCertificateAction,
+    /// Base64 EXI CertificateInstallationReq / CertificateUpdateReq
+    pub exi_request: String,
+}
+
+/// Issues contract certificates to EVs.
+#[async_trait]
+pub trait CertificatePool: Send + Sync {
+    /// The base64 EXI response for the EV, or why there is none.
+    async fn ev_certificate(&self, request: &EvCertificateRequest) -> Result<String, String>;
+}
+
+/// A trusted contract certificate root, identified the way
+/// `iso15118CertificateHashData` refers to it: as the issuer of the
+/// topmost sub-CA of a contract chain.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractRoot {
+    /// Shown in logs
+    pub name: String,
+    pub hash_algorithm: String,
+    pub issuer_name_hash: String,
+    pub issuer_key_hash: String,
+}
+
+impl ContractRoot {
+    fn issued(&self, data: &OcspRequestData) -> bool {
+        self.hash_algorithm
+            .eq_ignore_ascii_case(&data.hash_algorithm)
+            && self
+                .issuer_name_hash
+                .eq_ignore_ascii_case(&data.issuer_name_hash)
+            && self
+                .issuer_key_hash
+                .eq_ignore_ascii_case(&data.issuer_key_hash)
+    }
+}
+
+/// Outcome of contract certificate validation, mapped onto
+/// `AuthorizeCertificateStatusEnumType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractCertificateStatus {
+    Accepted,
+    CertChainError,
+    CertificateRevoked,
+    NoCertificateAvailable,
+}
+
+/// Canonical form of an eMAID: separators dropped, upper case
+/// (`DE-ABC-C12345678-X` → `DEABCC12345678X`).
+///
+/// eMAIDs are matched against id tags in both the form received and this
+/// one, so contracts can be stored either way and mapped to users through
+/// the id tag's `user_id`.
+pub fn normalize_emaid(emaid: &str) -> String {
+    emaid
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Validates contract certificates and relays certificate requests.
+pub struct PlugAndChargeService {
+    contract_roots: Vec<ContractRoot>,
+    certificate_pool: Option<Arc<dyn CertificatePool>>,
+    ocsp_responder: Option<Arc<dyn OcspResponder>>,
+    accept_unknown_revocation: bool,
+}
+
+pub type SharedPlugAndChargeService = Arc<PlugAndChargeService>;
+
+impl PlugAndChargeService {
+    /// Trusting `contract_roots`, without a certificate pool or OCSP
+    /// responder until one is set.
+    pub fn new(contract_roots: Vec<ContractRoot>) -> Self {
+        Self {
+            contract_roots,
+            certificate_pool: None,
+            ocsp_responder: None,
+            accept_unknown_revocation: false,
+        }
+    }
+
+    /// No trusted roots: every contract is rejected.
+    pub fn disabled() -> Self {
+        Self::new(Vec::new())
+    }
+
+    pub fn with_certificate_pool(mut self, pool: Arc<dyn CertificatePool>) -> Self {
+        self.certificate_pool = Some(pool);
+        self
+    }
+
+    pub fn with_ocsp_responder(mut self, responder: Arc<dyn OcspResponder>) -> Self {
+        self.ocsp_responder = Some(responder);
+        self
+    }
+
+    /// Accept contracts whose revocation status cannot be determined (no
+    /// responder, responder unreachable or answering `Unknown`). Off by
+    /// default: such contracts are rejected.
+    pub fn accept_unknown_revocation(mut self, accept: bool) -> Self {
+        self.accept_unknown_revocation = accept;
+        self
+    }
+
+    pub fn contract_roots(&self) -> &[ContractRoot] {
+        &self.contract_roots
+    }
+
+    pub fn has_ocsp_responder(&self) -> bool {
+        self.ocsp_responder.is_some()
+    }
+
+    /// Answer a `Get15118EVCertificate` request.
+    pub async fn ev_certificate(&self, request: &EvCertificateRequest) -> Result<String, String> {
+        match &self.certificate_pool {
+            Some(pool) => pool.ev_certificate(request).await,
+            None => Err("No contract certificate pool is configured".to_string()),
+        }
+    }
+
+    /// Answer a `GetCertificateStatus` request.
+    pub async fn certificate_status(
+        &self,
+        request: &OcspRequestData,
+    ) -> Result<OcspResult, String> {
+        match &self.ocsp_responder {
+            Some(responder) => responder.check(request).await,
+            None => Err("No OCSP responder is configured".to_string()),
+        }
+    }
+
+    /// Validate the `iso15118CertificateHashData` of an Authorize request.
+    ///
+    /// The chain is accepted when one of its certificates was issued by a
+    /// configured contract root and the OCSP responder reports all of them
+    /// good. A certificate whose status cannot be determined fails the
+    /// chain with `CertChainError`, unless unknown revocation is accepted.
+    pub async fn verify_contract(&self, chain: &[OcspRequestData]) -> ContractCertificateStatus {
+        if chain.is_empty() {
+            return ContractCertificateStatus::NoCertificateAvailable;
+        }
+
+        let Some(root) = self
+            .contract_roots
+            .iter()
+            .find(|root| chain.iter().any(|data| root.issued(data)))
+        else {
+            info!(
+                certificates = chain.len(),
+                "Contract chain not issued under a trusted contract root"
+            );
+            return ContractCertificateStatus::CertChainError;
+        };
+
+        for data in chain {
+            let unknown = match self.certificate_status(data).await {
+                Ok(result) => match result.status {
+                    RevocationStatus::Good => continue,
+                    RevocationStatus::Revoked => {
+                        info!(
+                            root = root.name.as_str(),
+                            serial_number = data.serial_number.as_str(),
+                            "Contract chain contains a revoked certificate"
+                        );
+                        return ContractCertificateStatus::CertificateRevoked;
+                    }
+                    RevocationStatus::Unknown => "OCSP responder reports status unknown".into(),
+                },
+                Err(e) => e,
+            };
+            if !self.accept_unknown_revocation {
+                warn!(
+                    serial_number = data.serial_number.as_str(),
+                    reason = unknown.as_str(),
+                    "Revocation status unknown, rejecting contract chain"
+                );
+                return ContractCertificateStatus::CertChainError;
+            }
+            warn!(
+                serial_number = data.serial_number.as_str(),
+                reason = unknown.as_str(),
+                "Revocation status unknown, accepting certificate as configured"
+            );
+        }
+
+        ContractCertificateStatus::Accepted
+    }
+}
+
+// ── Local stand-ins ────────────────────────────────────────────
+
+/// Certificate pool answering from canned EXI responses.
+#[derive(Debug, Default)]
+pub struct LocalCertificatePool {
+    responses: HashMap<String, String>,
+    fallback: Option<String>,
+}
+
+impl LocalCertificatePool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer `exi_request` with `exi_response`.
+    pub fn insert(&mut self, exi_request: impl Into<String>, exi_response: impl Into<String>) {
+        self.responses
+            .insert(exi_request.into(), exi_response.into());
+    }
+
+    /// Answer requests without a canned response with `exi_response`.
+    pub fn with_fallback(mut self, exi_response: impl Into<String>) -> Self {
+        self.fallback = Some(exi_response.into());
+        self
+    }
+}
+
+#[async_trait]
+impl CertificatePool for LocalCertificatePool {
+    async fn ev_certificate(&self, request: &EvCertificateRequest) -> Result<String, String> {
+        self.responses
+            .get(&request.exi_request)
+            .or(self.fallback.as_ref())
+            .cloned()
+            .ok_or_else(|| "No contract certificate available for this EV".to_string())
+    }
+}
+
+/// OCSP responder reporting every certificate good except listed serials.
+///
+/// Its responses are not signed RFC 6960 structures, only a base64
+/// `serialNumber:status` marker, which is enough for stations under test.
+#[derive(Debug, Default)]
+pub struct LocalOcspResponder {
+    revoked: HashSet<String>,
+}
+
+impl LocalOcspResponder {
+    pub fn new<S: Into<String>>(revoked_serial_numbers: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            revoked: revoked_serial_numbers
+                .into_iter()
+                .map(|s| s.into().to_ascii_uppercase())
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl OcspResponder for LocalOcspResponder {
+    async fn check(&self, request: &OcspRequestData) -> Result<OcspResult, String> {
+        let status = if self
+            .revoked
+            .contains(&request.serial_number.to_ascii_uppercase())
+        {
+            RevocationStatus::Revoked
+        } else {
+            RevocationStatus::Good
+        };
+        let marker = format!("{}:{:?}", request.serial_number, status);
+        Ok(OcspResult {
+            status,
+            response: BASE64.encode(marker),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOT_NAME_HASH: &str = "aa11";
+    const ROOT_KEY_HASH: &str = "bb22";
+
+    fn roots() -> Vec<ContractRoot> {
+        vec![ContractRoot {
+            name: "V2G Root".into(),
+            hash_algorithm: "SHA256".into(),
+            issuer_name_hash: ROOT_NAME_HASH.into(),
+            issuer_key_hash: ROOT_KEY_HASH.into(),
+        }]
+    }
+
+    fn service(revoked: &[&str]) -> PlugAndChargeService {
+        PlugAndChargeService::new(roots())
+            .with_certificate_pool(Arc::new(LocalCertificatePool::new().with_fallback("ZXhp")))
+            .with_ocsp_responder(Arc::new(LocalOcspResponder::new(revoked.iter().copied())))
+    }
+
+    struct UnreachableResponder;
+
+    #[async_trait]
+    impl OcspResponder for UnreachableResponder {
+        async fn check(&self, _request: &OcspRequestData) -> Result<OcspResult, String> {
+            Err("connection refused".into())
+        }
+    }
+
+    fn cert(serial: &str, name_hash: &str, key_hash: &str) -> OcspRequestData {
+        OcspRequestData {
+            hash_algorithm: "SHA256".into(),
+            issuer_name_hash: name_hash.into(),
+            issuer_key_hash: key_hash.into(),
+            serial_number: serial.into(),
+            responder_url: "http://ocsp.local".into(),
+        }
+    }
+
+    fn chain() -> Vec<OcspRequestData> {
+        vec![
+            cert("0C01", "cc33", "dd44"),
+            cert("0A02", &ROOT_NAME_HASH.to_uppercase(), ROOT_KEY_HASH),
+        ]
+    }
+
+    #[tokio::test]
+    async fn chain_under_trusted_root_is_accepted() {
+        assert_eq!(
+            service(&[]).verify_contract(&chain()).await,
+            ContractCertificateStatus::Accepted
+        );
+    }
+
+    #[tokio::test]
+    async fn foreign_or_missing_chain_is_rejected() {
+        let foreign = vec![cert("0C01", "cc33", "dd44")];
+        assert_eq!(
+            service(&[]).verify_contract(&foreign).await,
+            ContractCertificateStatus::CertChainError
+        );
+        assert_eq!(
+            service(&[]).verify_contract(&[]).await,
+            ContractCertificateStatus::NoCertificateAvailable
+        );
+    }
+
+    #[tokio::test]
+    async fn revoked_certificate_in_chain_is_reported() {
+        let service = service(&["0c01"]);
+        assert_eq!(
+            service.verify_contract(&chain()).await,
+            ContractCertificateStatus::CertificateRevoked
+        );
+
+        let result = service
+            .certificate_status(&cert("0C01", "cc33", "dd44"))
+            .await
+            .unwrap();
+        assert_eq!(result.status, RevocationStatus::Revoked);
+    }
+
+    #[tokio::test]
+    async fn unknown_revocation_is_rejected_unless_accepted() {
+        let without_responder = PlugAndChargeService::new(roots());
+        assert_eq!(
+            without_responder.verify_contract(&chain()).await,
+            ContractCertificateStatus::CertChainError
+        );
+        assert!(without_responder
+            .certificate_status(&chain()[0])
+            .await
+            .is_err());
+
+        let unreachable =
+            PlugAndChargeService::new(roots()).with_ocsp_responder(Arc::new(UnreachableResponder));
+        assert_eq!(
+            unreachable.verify_contract(&chain()).await,
+            ContractCertificateStatus::CertChainError
+        );
+        assert_eq!(
+            unreachable
+                .accept_unknown_revocation(true)
+                .verify_contract(&chain())
+                .await,
+            ContractCertificateStatus::Accepted
+        );
+    }
+
+    #[tokio::test]
+    async fn without_pool_no_certificate_is_issued() {
+        let request = EvCertificateRequest {
+            charge_point_id: "CP001".into(),
+            iso15118_schema_version: "urn:iso:15118:2:2013:MsgDef".into(),
+            action: EvCertificateAction::Install,
+            exi_request: "cmVx".into(),
+        };
+        assert!(PlugAndChargeService::disabled()
+            .ev_certificate(&request)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn local_pool_answers_canned_responses() {
+        let mut pool = LocalCertificatePool::new();
+        pool.insert("cmVx", "cmVzcA==");
+        let request = |exi: &str| EvCertificateRequest {
+            charge_point_id: "CP001".into(),
+            iso15118_schema_version: "urn:iso:15118:2:2013:MsgDef".into(),
+            action: EvCertificateAction::Install,
+            exi_request: exi.into(),
+        };
+
+        assert_eq!(
+            pool.ev_certificate(&request("cmVx")).await.unwrap(),
+            "cmVzcA=="
+        );
+        assert!(pool.ev_certificate(&request("b3RoZXI=")).await.is_err());
+    }
+
+    #[test]
+    fn emaid_is_normalized() {
+        assert_eq!(normalize_emaid("de-abc-c12345678-x"), "DEABCC12345678X");
+        assert_eq!(normalize_emaid("DE*ABC*C12345678*X"), "DEABCC12345678X");
+    }
+}
@@ -2,12 +2,16 @@
 //!
 //! Runs in a tokio::spawn loop, checking every 60 seconds for active
 //! reservations past their `expiry_date` and marking them as `Expired`.
+//! Each expiry is published as a `ReservationStatusChanged` event.
+//! Booked slots are left to the reservation scheduler, which also cancels
+//! them on the charge point.
 
 use std::sync::Arc;
 
 use tokio::time::Duration;
 use tracing::{info, warn};
 
+use crate::application::events::{Event, ReservationStatusChangedEvent, SharedEventBus};
 use crate::domain::RepositoryProvider;
 use crate::shared::shutdown::ShutdownSignal;
 
@@ -18,6 +22,7 @@ use crate::shared::shutdown::ShutdownSignal;
 /// then updates them to "Expired".
 pub fn start_reservation_expiry_task(
     repos: Arc<dyn RepositoryProvider>,
+    event_bus: SharedEventBus,
     shutdown: ShutdownSignal,
     check_interval_secs: u64,
 ) {
@@ -32,7 +37,7 @@ pub fn start_reservation_expiry_task(
         loop {
             tokio::select! {
                 _ = interval.tick() => {
-                    if let Err(e) = expire_reservations(&repos).await {
+                    if let Err(e) = expire_reservations(&repos, &event_bus).await {
                         warn!(error = %e, "Reservation expiry check error");
                     }
                 }
@@ -49,8 +54,15 @@ pub fn start_reservation_expiry_task(
 
 async fn expire_reservations(
     repos: &Arc<dyn RepositoryProvider>,
+    event_bus: &SharedEventBus,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let expired = repos.reservations().find_expired().await?;
+    let expired: Vec<_> = repos
+        .reservations()
+        .find_expired()
+        .await?
+        .into_iter()
+        .filter(|r| r.start_date.is_none())
+        .collect();
 
     if expired.is_empty() {
         return Ok(());
@@ -60,8 +72,11 @@ async fn expire_reservations(
 
     for mut reservation in expired {
         reservation.expire();
-        if let Err(e) = repos.reservations().update(reservation).await {
-            warn!(error = %e, "Failed to expire reservation");
+        match repos.reservations().update(reservation.clone()).await {
+            Ok(()) => event_bus.publish(Event::ReservationStatusChanged(
+                ReservationStatusChangedEvent::from_reservation(&reservation),
+            )),
+            Err(e) => warn!(error = %e, "Failed to expire reservation"),
         }
     }
 
@@ -0,0 +1,166 @@
+//! Background task that activates booked reservation slots.
+//!
+//! Reservations booked for later are stored as `Scheduled`. Shortly before
+//! the slot begins the task sends ReserveNow; when the slot ends unused it
+//! sends CancelReservation and marks the reservation `Expired`.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use tokio::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::application::charging::commands::SharedCommandDispatcher;
+use crate::application::events::{Event, ReservationStatusChangedEvent, SharedEventBus};
+use crate::domain::{RepositoryProvider, Reservation};
+use crate::shared::shutdown::ShutdownSignal;
+
+/// Start the reservation scheduler background task.
+///
+/// Every `check_interval_secs` the task sends ReserveNow for slots that
+/// begin within `activation_lead_secs`, and cancels activated slots whose
+/// end has passed.
+pub fn start_reservation_scheduler_task(
+    repos: Arc<dyn RepositoryProvider>,
+    command_dispatcher: SharedCommandDispatcher,
+    event_bus: SharedEventBus,
+    shutdown: ShutdownSignal,
+    activation_lead_secs: u64,
+    check_interval_secs: u64,
+) {
+    tokio::spawn(async move {
+        info!(
+            activation_lead = activation_lead_secs,
+            check_interval = check_interval_secs,
+            "🗓️ Reservation scheduler started"
+        );
+
+        let lead = chrono::Duration::seconds(activation_lead_secs as i64);
+        let mut interval = tokio::time::interval(Duration::from_secs(check_interval_secs));
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    if let Err(e) = activate_due_slots(&repos, &command_dispatcher, &event_bus, lead).await {
+                        warn!(error = %e, "Reservation activation error");
+                    }
+                    if let Err(e) = cancel_lapsed_slots(&repos, &command_dispatcher, &event_bus).await {
+                        warn!(error = %e, "Reservation lapse check error");
+                    }
+                }
+                _ = shutdown.notified().wait() => {
+                    info!("🗓️ Reservation scheduler shutting down");
+                    break;
+                }
+            }
+        }
+
+        info!("🗓️ Reservation scheduler stopped");
+    });
+}
+
+async fn activate_due_slots(
+    repos: &Arc<dyn RepositoryProvider>,
+    dispatcher: &SharedCommandDispatcher,
+    event_bus: &SharedEventBus,
+    lead: chrono::Duration,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let now = Utc::now();
+
+    for mut reservation in repos.reservations().find_scheduled().await? {
+        if reservation.is_expired() {
+            // The slot passed before the charge point could be reached
+            reservation.expire();
+        } else if reservation.is_due(now, lead) {
+            let outcome = dispatcher
+                .reserve_now(
+                    &reservation.charge_point_id,
+                    reservation.id,
+                    reservation.connector_id,
+                    &reservation.id_tag,
+                    reservation.parent_id_tag.as_deref(),
+                    reservation.expiry_date,
+                )
+                .await;
+            match outcome.as_deref() {
+                Ok("Accepted") => reservation.activate(),
+                // Still in use by an earlier session; retry on the next tick
+                Ok("Occupied") => continue,
+                Ok(status) => {
+                    warn!(
+                        reservation_id = reservation.id,
+                        charge_point_id = reservation.charge_point_id.as_str(),
+                        status,
+                        "Booked slot rejected by charge point"
+                    );
+                    reservation.cancel();
+                }
+                Err(e) => {
+                    debug!(
+                        reservation_id = reservation.id,
+                        charge_point_id = reservation.charge_point_id.as_str(),
+                        error = %e,
+                        "ReserveNow for booked slot failed, will retry"
+                    );
+                    continue;
+                }
+            }
+        } else {
+            continue;
+        }
+
+        save(repos, event_bus, reservation).await;
+    }
+
+    Ok(())
+}
+
+async fn cancel_lapsed_slots(
+    repos: &Arc<dyn RepositoryProvider>,
+    dispatcher: &SharedCommandDispatcher,
+    event_bus: &SharedEventBus,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let lapsed: Vec<Reservation> = repos
+        .reservations()
+        .find_expired()
+        .await?
+        .into_iter()
+        .filter(|r| r.start_date.is_some())
+        .collect();
+
+    for mut reservation in lapsed {
+        if let Err(e) = dispatcher
+            .cancel_reservation(&reservation.charge_point_id, reservation.id)
+            .await
+        {
+            debug!(
+                reservation_id = reservation.id,
+                error = %e,
+                "CancelReservation for lapsed slot failed"
+            );
+        }
+        reservation.expire();
+        save(repos, event_bus, reservation).await;
+    }
+
+    Ok(())
+}
+
+async fn save(
+    repos: &Arc<dyn RepositoryProvider>,
+    event_bus: &SharedEventBus,
+    reservation: Reservation,
+) {
+    info!(
+        reservation_id = reservation.id,
+        charge_point_id = reservation.charge_point_id.as_str(),
+        status = reservation.status.as_str(),
+        "Booked slot updated"
+    );
+    match repos.reservations().update(reservation.clone()).await {
+        Ok(()) => event_bus.publish(Event::ReservationStatusChanged(
+            ReservationStatusChangedEvent::from_reservation(&reservation),
+        )),
+        Err(e) => warn!(error = %e, "Failed to update booked slot"),
+    }
+}
@@ -0,0 +1,597 @@
+//! Smart charging from ISO 15118 charging needs.
+//!
+//! A v2.0.1 station relays the EV's needs (`NotifyEVChargingNeeds`): energy
+//! to deliver, departure time and electrical limits. The scheduler splits the
+//! time until departure at the configured time-of-use price boundaries and
+//! fills the cheapest slots first at the highest power the EV and the site
+//! allow, until the requested energy is covered. The result is sent to the
+//! station as an absolute TxProfile in W. Without a departure time or energy
+//! amount the EV simply charges at full power.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveTime, TimeZone, Utc};
+use dashmap::DashMap;
+use rust_decimal::Decimal;
+use rust_ocpp::v2_0_1::datatypes::charging_profile_type::ChargingProfileType;
+use rust_ocpp::v2_0_1::datatypes::charging_schedule_period_type::ChargingSchedulePeriodType;
+use rust_ocpp::v2_0_1::datatypes::charging_schedule_type::ChargingScheduleType;
+use rust_ocpp::v2_0_1::enumerations::charging_profile_kind_enum_type::ChargingProfileKindEnumType;
+use rust_ocpp::v2_0_1::enumerations::charging_profile_purpose_enum_type::ChargingProfilePurposeEnumType;
+use rust_ocpp::v2_0_1::enumerations::charging_rate_unit_enum_type::ChargingRateUnitEnumType;
+use serde::Serialize;
+use serde_json::Value;
+use tracing::{info, warn};
+
+use crate::application::charging::commands::{v201, SharedCommandSender};
+use crate::domain::{ChargingProfile, DomainResult, EvChargingNeeds, RepositoryProvider};
+
+/// Generated TxProfiles are numbered from here on, away from operator profiles.
+const PROFILE_ID_OFFSET: i32 = 100_000;
+
+/// A daily time-of-use price window (UTC).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PricePeriod {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    /// Cents per kWh
+    pub price_per_kwh: i32,
+}
+
+impl PricePeriod {
+    /// Build a period from `"HH:MM"` times; `None` when either is malformed.
+    pub fn parse(start: &str, end: &str, price_per_kwh: i32) -> Option<Self> {
+        Some(Self {
+            start: NaiveTime::parse_from_str(start, "%H:%M").ok()?,
+            end: NaiveTime::parse_from_str(end, "%H:%M").ok()?,
+            price_per_kwh,
+        })
+    }
+
+    fn covers(&self, time: NaiveTime) -> bool {
+        if self.start < self.end {
+            self.start <= time && time < self.end
+        } else if self.start > self.end {
+            time >= self.start || time < self.end
+        } else {
+            true
+        }
+    }
+}
+
+/// Scheduler settings, built from `[smart_charging]`.
+#[derive(Debug, Clone, Default)]
+pub struct SmartChargingSettings {
+    pub enabled: bool,
+    pub site_max_power_w: Option<f64>,
+    pub stack_level: i32,
+    pub price_periods: Vec<PricePeriod>,
+}
+
+/// One period of a computed schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PlannedPeriod {
+    /// Seconds from the schedule start
+    pub start_period: i32,
+    /// Power limit (W)
+    pub limit_w: f64,
+}
+
+/// A schedule computed for one set of charging needs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChargingPlan {
+    pub start: DateTime<Utc>,
+    /// Seconds until departure; open-ended when charging as soon as possible
+    pub duration: Option<i32>,
+    pub periods: Vec<PlannedPeriod>,
+    /// Phases used on AC
+    pub number_phases: Option<i32>,
+}
+
+impl ChargingPlan {
+    /// Wrap the plan in an absolute TxProfile for `transaction_id`.
+    pub fn to_profile(
+        &self,
+        profile_id: i32,
+        stack_level: i32,
+        transaction_id: String,
+    ) -> ChargingProfileType {
+        ChargingProfileType {
+            id: profile_id,
+            stack_level,
+            charging_profile_purpose: ChargingProfilePurposeEnumType::TxProfile,
+            charging_profile_kind: ChargingProfileKindEnumType::Absolute,
+            recurrency_kind: None,
+            valid_from: None,
+            valid_to: None,
+            transaction_id: Some(transaction_id),
+            charging_schedule: vec![ChargingScheduleType {
+                id: profile_id,
+                start_schedule: Some(self.start),
+                duration: self.duration,
+                charging_rate_unit: ChargingRateUnitEnumType::W,
+                min_charging_rate: None,
+                charging_schedule_period: self
+                    .periods
+                    .iter()
+                    .map(|p| ChargingSchedulePeriodType {
+                        start_period: p.start_period,
+                        limit: Decimal::from(p.limit_w.ceil() as i64),
+                        number_phases: self.number_phases,
+                        phase_to_use: None,
+                    })
+                    .collect(),
+                sales_tariff: None,
+            }],
+        }
+    }
+}
+
+fn number_phases(energy_transfer_mode: &str) -> Option<i32> {
+    match energy_transfer_mode {
+        "AC_single_phase" => Some(1),
+        "AC_two_phase" => Some(2),
+        "AC_three_phase" => Some(3),
+        _ => None,
+    }
+}
+
+/// Highest power (W) the EV accepts.
+pub fn ev_max_power_w(needs: &EvChargingNeeds) -> f64 {
+    let current_times_voltage = needs.ev_max_current as f64 * needs.ev_max_voltage as f64;
+    match number_phases(&needs.energy_transfer_mode) {
+        Some(phases) => current_times_voltage * phases as f64,
+        None => needs
+            .ev_max_power
+            .map(|p| p as f64)
+            .unwrap_or(current_times_voltage),
+    }
+}
+
+/// Split `[from, to)` at every price boundary, pricing each slot.
+///
+/// Time not covered by any period is priced `i32::MAX` so it is used last.
+fn price_slots(
+    prices: &[PricePeriod],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>, i32)> {
+    let mut cuts = vec![from, to];
+    let mut day = from.date_naive();
+    while day <= to.date_naive() {
+        for period in prices {
+            for time in [period.start, period.end] {
+                let at = Utc.from_utc_datetime(&day.and_time(time));
+                if at > from && at < to {
+                    cuts.push(at);
+                }
+            }
+        }
+        match day.succ_opt() {
+            Some(next) => day = next,
+            None => break,
+        }
+    }
+    cuts.sort();
+    cuts.dedup();
+
+    cuts.windows(2)
+        .map(|w| {
+            let price = prices
+                .iter()
+                .find(|p| p.covers(w[0].time()))
+                .map(|p| p.price_per_kwh)
+                .unwrap_or(i32::MAX);
+            (w[0], w[1], price)
+        })
+        .collect()
+}
+
+/// Compute a schedule for `needs` starting at `now`, never above `max_power_w`.
+///
+/// When the energy cannot be delivered by the departure time, or the EV
+/// cannot take as many periods as the plan needs, it charges at full power.
+pub fn plan_schedule(
+    needs: &EvChargingNeeds,
+    max_power_w: f64,
+    prices: &[PricePeriod],
+    now: DateTime<Utc>,
+) -> ChargingPlan {
+    let asap = ChargingPlan {
+        start: now,
+        duration: None,
+        periods: vec![PlannedPeriod {
+            start_period: 0,
+            limit_w: max_power_w,
+        }],
+        number_phases: number_phases(&needs.energy_transfer_mode),
+    };
+
+    let (Some(departure), Some(energy_wh)) = (needs.departure_time, needs.energy_amount_wh) else {
+        return asap;
+    };
+    if departure <= now || energy_wh <= 0 || max_power_w <= 0.0 {
+        return asap;
+    }
+
+    let slots = price_slots(prices, now, departure);
+    let mut order: Vec<usize> = (0..slots.len()).collect();
+    order.sort_by_key(|&i| (slots[i].2, slots[i].0));
+
+    let mut remaining = energy_wh as f64;
+    let mut limits = vec![0.0; slots.len()];
+    for i in order {
+        if remaining <= 0.0 {
+            break;
+        }
+        let (start, end, _) = slots[i];
+        let hours = (end - start).num_seconds() as f64 / 3600.0;
+        if hours <= 0.0 {
+            continue;
+        }
+        let energy = (max_power_w * hours).min(remaining);
+        limits[i] = energy / hours;
+        remaining -= energy;
+    }
+    if remaining > 0.0 {
+        return asap;
+    }
+
+    let mut periods: Vec<PlannedPeriod> = Vec::new();
+    for ((start, _, _), limit_w) in slots.iter().zip(limits) {
+        if periods.last().map(|p| p.limit_w) == Some(limit_w) {
+            continue;
+        }
+        periods.push(PlannedPeriod {
+            start_period: (*start - now).num_seconds() as i32,
+            limit_w,
+        });
+    }
+    if let Some(max) = needs.max_schedule_tuples {
+        if periods.len() > max.max(1) as usize {
+            return asap;
+        }
+    }
+
+    ChargingPlan {
+        start: now,
+        duration: Some((departure - now).num_seconds() as i32),
+        periods,
+        number_phases: asap.number_phases,
+    }
+}
+
+/// Stores charging needs and answers them with TxProfiles.
+pub struct SmartChargingService {
+    repos: Arc<dyn RepositoryProvider>,
+    command_sender: SharedCommandSender,
+    settings: SmartChargingSettings,
+    /// Station-side transaction ids by (charge point, EVSE)
+    station_transactions: DashMap<(String, i32), String>,
+}
+
+pub type SharedSmartChargingService = Arc<SmartChargingService>;
+
+impl SmartChargingService {
+    pub fn new(
+        repos: Arc<dyn RepositoryProvider>,
+        command_sender: SharedCommandSender,
+        settings: SmartChargingSettings,
+    ) -> Self {
+        Self {
+            repos,
+            command_sender,
+            settings,
+            station_transactions: DashMap::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.settings.enabled
+    }
+
+    /// Remember the station's id for the transaction running on an EVSE.
+    pub fn track_transaction(&self, charge_point_id: &str, evse_id: i32, transaction_id: &str) {
+        self.station_transactions.insert(
+            (charge_point_id.to_string(), evse_id),
+            transaction_id.to_string(),
+        );
+    }
+
+    pub fn forget_transaction(&self, charge_point_id: &str, evse_id: i32) {
+        self.station_transactions
+            .remove(&(charge_point_id.to_string(), evse_id));
+    }
+
+    pub async fn record_needs(&self, needs: EvChargingNeeds) -> DomainResult<EvChargingNeeds> {
+        self.repos.ev_charging_needs().save(needs).await
+    }
+
+    /// Attach the schedule an EV planned to its latest needs on the EVSE.
+    ///
+    /// Returns `false` when the EVSE never reported needs.
+    pub async fn record_ev_schedule(
+        &self,
+        charge_point_id: &str,
+        evse_id: i32,
+        schedule: &Value,
+    ) -> DomainResult<bool> {
+        let Some(mut needs) = self
+            .repos
+            .ev_charging_needs()
+            .find_latest_for_evse(charge_point_id, evse_id)
+            .await?
+        else {
+            return Ok(false);
+        };
+        needs.ev_schedule_json = Some(schedule.to_string());
+        needs.updated_at = Utc::now();
+        self.repos.ev_charging_needs().update(needs).await?;
+        Ok(true)
+    }
+
+    /// Plan for `needs`, sharing the site limit among the station's
+    /// running transactions.
+    pub async fn plan(&self, needs: &EvChargingNeeds, now: DateTime<Utc>) -> ChargingPlan {
+        let mut max_power_w = ev_max_power_w(needs);
+        if let Some(site_max) = self.settings.site_max_power_w {
+            let active = self
+                .repos
+                .transactions()
+                .find_by_charge_point(&needs.charge_point_id)
+                .await
+                .map(|txs| txs.iter().filter(|tx| tx.is_active()).count())
+                .unwrap_or(0);
+            max_power_w = max_power_w.min(site_max / active.max(1) as f64);
+        }
+        plan_schedule(needs, max_power_w, &self.settings.price_periods, now)
+    }
+
+    /// The station's `transactionId` recorded on the transaction `needs`
+    /// belong to, for when it is not cached (e.g. after a restart).
+    async fn stored_station_transaction_id(&self, needs: &EvChargingNeeds) -> Option<String> {
+        let id = needs.transaction_id?;
+        match self.repos.transactions().find_by_id(id).await {
+            Ok(transaction) => transaction.and_then(|t| t.station_transaction_id),
+            Err(e) => {
+                warn!(transaction_id = id, error = %e, "Failed to load transaction");
+                None
+            }
+        }
+    }
+
+    /// Compute the TxProfile for stored `needs`, send it to the station and
+    /// record the outcome.
+    pub async fn apply(&self, mut needs: EvChargingNeeds) {
+        let key = (needs.charge_point_id.clone(), needs.evse_id);
+        let cached = self
+            .station_transactions
+            .get(&key)
+            .map(|id| id.value().clone());
+        let transaction_id = match cached {
+            Some(id) => Some(id),
+            None => self.stored_station_transaction_id(&needs).await,
+        };
+        let Some(transaction_id) = transaction_id else {
+            warn!(
+                charge_point_id = needs.charge_point_id.as_str(),
+                evse_id = needs.evse_id,
+                "No transaction on EVSE, charging needs left unscheduled"
+            );
+            return;
+        };
+
+        let now = Utc::now();
+        let plan = self.plan(&needs, now).await;
+        let profile_id = PROFILE_ID_OFFSET + needs.id;
+        let profile = plan.to_profile(profile_id, self.settings.stack_level, transaction_id);
+        let schedule_json =
+            serde_json::to_string(&profile.charging_schedule).unwrap_or_else(|_| "[]".into());
+
+        info!(
+            charge_point_id = needs.charge_point_id.as_str(),
+            evse_id = needs.evse_id,
+            profile_id,
+            periods = plan.periods.len(),
+            "Sending TxProfile for EV charging needs"
+        );
+
+        let status = match v201::set_charging_profile::set_charging_profile(
+            &self.command_sender,
+            &needs.charge_point_id,
+            needs.evse_id,
+            profile,
+        )
+        .await
+        {
+            Ok(status) => status,
+            Err(e) => {
+                warn!(
+                    charge_point_id = needs.charge_point_id.as_str(),
+                    error = %e,
+                    "Failed to send TxProfile for EV charging needs"
+                );
+                return;
+            }
+        };
+
+        if status == "Accepted" {
+            let profiles = self.repos.charging_profiles();
+            if let Err(e) = profiles
+                .deactivate_by_criteria(
+                    &needs.charge_point_id,
+                    Some(needs.evse_id),
+                    Some("TxProfile"),
+                    Some(self.settings.stack_level),
+                )
+                .await
+            {
+                warn!("Failed to deactivate previous TxProfile: {}", e);
+            }
+            let record = ChargingProfile {
+                id: 0,
+                charge_point_id: needs.charge_point_id.clone(),
+                evse_id: needs.evse_id,
+                profile_id,
+                stack_level: self.settings.stack_level,
+                purpose: "TxProfile".to_string(),
+                kind: "Absolute".to_string(),
+                recurrency_kind: None,
+                valid_from: None,
+                valid_to: None,
+                schedule_json: schedule_json.clone(),
+                is_active: true,
+                limit_source: None,
+                created_at: now,
+                updated_at: now,
+            };
+            if let Err(e) = profiles.save(record).await {
+                warn!("Failed to save charging profile to DB: {}", e);
+            }
+        }
+
+        needs.profile_id = Some(profile_id);
+        needs.profile_status = Some(status);
+        needs.schedule_json = Some(schedule_json);
+        needs.updated_at = Utc::now();
+        if let Err(e) = self.repos.ev_charging_needs().update(needs).await {
+            warn!("Failed to update EV charging needs: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn needs(departure: Option<&str>, energy_amount_wh: Option<i32>) -> EvChargingNeeds {
+        EvChargingNeeds {
+            id: 1,
+            charge_point_id: "CP001".into(),
+            evse_id: 1,
+            transaction_id: Some(7),
+            energy_transfer_mode: "AC_three_phase".into(),
+            departure_time: departure.map(at),
+            energy_amount_wh,
+            ev_max_current: 16,
+            ev_max_voltage: 230,
+            ev_max_power: None,
+            state_of_charge: None,
+            max_schedule_tuples: None,
+            profile_id: None,
+            profile_status: None,
+            schedule_json: None,
+            ev_schedule_json: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn night_tariff() -> Vec<PricePeriod> {
+        vec![
+            PricePeriod::parse("22:00", "06:00", 10).unwrap(),
+            PricePeriod::parse("06:00", "22:00", 30).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_ev_max_power() {
+        let ac = needs(None, None);
+        assert_eq!(ev_max_power_w(&ac), 16.0 * 230.0 * 3.0);
+
+        let mut dc = needs(None, None);
+        dc.energy_transfer_mode = "DC".into();
+        dc.ev_max_power = Some(50_000);
+        assert_eq!(ev_max_power_w(&dc), 50_000.0);
+    }
+
+    #[test]
+    fn test_without_departure_charges_at_full_power() {
+        let plan = plan_schedule(
+            &needs(None, Some(10_000)),
+            11_000.0,
+            &night_tariff(),
+            at("2024-01-01T20:00:00Z"),
+        );
+        assert_eq!(plan.duration, None);
+        assert_eq!(
+            plan.periods,
+            vec![PlannedPeriod {
+                start_period: 0,
+                limit_w: 11_000.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_charges_in_cheapest_hours_before_departure() {
+        let plan = plan_schedule(
+            &needs(Some("2024-01-02T07:00:00Z"), Some(22_000)),
+            11_000.0,
+            &night_tariff(),
+            at("2024-01-01T20:00:00Z"),
+        );
+        assert_eq!(plan.duration, Some(11 * 3600));
+        assert_eq!(plan.number_phases, Some(3));
+        // Two hours' worth of energy spread over the cheap night window
+        assert_eq!(
+            plan.periods,
+            vec![
+                PlannedPeriod {
+                    start_period: 0,
+                    limit_w: 0.0
+                },
+                PlannedPeriod {
+                    start_period: 2 * 3600,
+                    limit_w: 2_750.0
+                },
+                PlannedPeriod {
+                    start_period: 10 * 3600,
+                    limit_w: 0.0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_falls_back_to_full_power_when_time_is_short() {
+        let plan = plan_schedule(
+            &needs(Some("2024-01-01T21:00:00Z"), Some(50_000)),
+            11_000.0,
+            &night_tariff(),
+            at("2024-01-01T20:00:00Z"),
+        );
+        assert_eq!(plan.duration, None);
+        assert_eq!(plan.periods.len(), 1);
+    }
+
+    #[test]
+    fn test_respects_max_schedule_tuples() {
+        let mut n = needs(Some("2024-01-02T07:00:00Z"), Some(22_000));
+        n.max_schedule_tuples = Some(1);
+        let plan = plan_schedule(&n, 11_000.0, &night_tariff(), at("2024-01-01T20:00:00Z"));
+        assert_eq!(plan.periods.len(), 1);
+        assert_eq!(plan.periods[0].limit_w, 11_000.0);
+    }
+
+    #[test]
+    fn test_profile_is_absolute_tx_profile_in_watts() {
+        let plan = plan_schedule(&needs(None, None), 7_400.0, &[], at("2024-01-01T20:00:00Z"));
+        let profile = plan.to_profile(100_001, 1, "tx-1".into());
+        assert_eq!(
+            profile.charging_profile_purpose,
+            ChargingProfilePurposeEnumType::TxProfile
+        );
+        assert_eq!(profile.transaction_id.as_deref(), Some("tx-1"));
+        let schedule = &profile.charging_schedule[0];
+        assert_eq!(schedule.charging_rate_unit, ChargingRateUnitEnumType::W);
+        assert_eq!(
+            schedule.charging_schedule_period[0].limit,
+            Decimal::from(7_400)
+        );
+    }
+}
@@ -2,4 +2,4 @@ pub mod connection;
 pub mod registry;
 
 pub use connection::{Connection, EvictedSession};
-pub use registry::{RegisterResult, SessionRegistry, SharedSessionRegistry};
+pub use registry::{RegisterResult, RemoteSession, SessionRegistry, SharedSessionRegistry};
@@ -26,11 +26,25 @@ pub enum RegisterResult {
     },
 }
 
+/// A charge point whose WebSocket is held by another node of the cluster
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteSession {
+    pub charge_point_id: String,
+    pub node_id: String,
+    pub ocpp_version: OcppVersion,
+}
+
 /// Thread-safe registry of active OCPP charge point sessions
+///
+/// In cluster mode it also mirrors the sessions held by other nodes, so
+/// `is_connected`, `get_version` and `connected_ids` answer for the whole
+/// cluster; only `send_to` and `broadcast` are limited to local sockets.
 pub struct SessionRegistry {
     sessions: DashMap<String, Connection>,
     /// Tracks when a charge point was last disconnected (for debounce)
     last_disconnect: DashMap<String, DateTime<Utc>>,
+    /// Sessions held by other cluster nodes
+    remote: DashMap<String, RemoteSession>,
 }
 
 /// Shared, reference-counted session registry
@@ -41,6 +55,7 @@ impl SessionRegistry {
         Self {
             sessions: DashMap::new(),
             last_disconnect: DashMap::new(),
+            remote: DashMap::new(),
         }
     }
 
@@ -111,6 +126,8 @@ impl SessionRegistry {
 
         // Clear debounce timestamp (fresh session is now active)
         self.last_disconnect.remove(charge_point_id);
+        // The station moved here from another node
+        self.remote.remove(charge_point_id);
 
         // Update Prometheus gauge
         metrics::gauge!("ocpp_connected_stations").set(self.sessions.len() as f64);
@@ -149,13 +166,38 @@ impl SessionRegistry {
         }
     }
 
-    /// Check if a charge point is currently connected
+    /// Check if a charge point is currently connected (to any node)
     pub fn is_connected(&self, charge_point_id: &str) -> bool {
+        self.is_local(charge_point_id) || self.remote.contains_key(charge_point_id)
+    }
+
+    /// Check if the charge point's WebSocket is held by this node
+    pub fn is_local(&self, charge_point_id: &str) -> bool {
         self.sessions.contains_key(charge_point_id)
     }
 
-    /// Get all connected charge point IDs
+    /// When the local session of a charge point was opened.
+    ///
+    /// Tells a connection handler whether the registered session is still
+    /// its own or a newer one that evicted it.
+    pub fn connected_since(&self, charge_point_id: &str) -> Option<DateTime<Utc>> {
+        self.sessions.get(charge_point_id).map(|c| c.connected_at)
+    }
+
+    /// Get all connected charge point IDs (cluster-wide)
     pub fn connected_ids(&self) -> Vec<String> {
+        let mut ids = self.local_ids();
+        ids.extend(
+            self.remote
+                .iter()
+                .filter(|r| !self.sessions.contains_key(r.key()))
+                .map(|r| r.key().clone()),
+        );
+        ids
+    }
+
+    /// Get the IDs of charge points connected to this node
+    pub fn local_ids(&self) -> Vec<String> {
         self.sessions.iter().map(|r| r.key().clone()).collect()
     }
 
@@ -172,9 +214,9 @@ impl SessionRegistry {
         }
     }
 
-    /// Number of active sessions
+    /// Number of active sessions (cluster-wide)
     pub fn count(&self) -> usize {
-        self.sessions.len()
+        self.connected_ids().len()
     }
 
     /// Get the negotiated OCPP version for a charge point
@@ -182,6 +224,52 @@ impl SessionRegistry {
         self.sessions
             .get(charge_point_id)
             .map(|conn| conn.ocpp_version)
+            .or_else(|| {
+                self.remote
+                    .get(charge_point_id)
+                    .map(|remote| remote.ocpp_version)
+            })
+    }
+
+    // ── Cluster directory mirror ───────────────────────────────
+
+    /// Node holding the charge point's WebSocket, when it is not this one
+    pub fn remote_owner(&self, charge_point_id: &str) -> Option<String> {
+        if self.is_local(charge_point_id) {
+            return None;
+        }
+        self.remote
+            .get(charge_point_id)
+            .map(|remote| remote.node_id.clone())
+    }
+
+    /// Record that another node now holds a charge point's session
+    pub fn track_remote(&self, session: RemoteSession) {
+        self.remote.insert(session.charge_point_id.clone(), session);
+    }
+
+    /// Drop a remote session, unless the station has since moved to another node
+    pub fn forget_remote(&self, charge_point_id: &str, node_id: &str) {
+        self.remote
+            .remove_if(charge_point_id, |_, remote| remote.node_id == node_id);
+    }
+
+    /// Replace all remote sessions with the directory's current view
+    pub fn sync_remote(&self, sessions: Vec<RemoteSession>) {
+        let current: std::collections::HashSet<&str> = sessions
+            .iter()
+            .map(|s| s.charge_point_id.as_str())
+            .collect();
+        self.remote
+            .retain(|charge_point_id, _| current.contains(charge_point_id.as_str()));
+        for session in sessions {
+            self.remote.insert(session.charge_point_id.clone(), session);
+        }
+    }
+
+    /// Drop every session held by a node that left the cluster
+    pub fn forget_node(&self, node_id: &str) {
+        self.remote.retain(|_, remote| remote.node_id != node_id);
     }
 }
 
@@ -315,6 +403,55 @@ mod tests {
         assert!(matches!(result, RegisterResult::Debounced { .. }));
     }
 
+    fn remote(charge_point_id: &str, node_id: &str) -> RemoteSession {
+        RemoteSession {
+            charge_point_id: charge_point_id.into(),
+            node_id: node_id.into(),
+            ocpp_version: OcppVersion::V201,
+        }
+    }
+
+    #[test]
+    fn remote_sessions_count_as_connected_but_not_local() {
+        let reg = SessionRegistry::new();
+        reg.register("CP001", make_sender(), OcppVersion::V16);
+        reg.track_remote(remote("CP002", "node-b"));
+
+        assert!(reg.is_connected("CP002"));
+        assert!(!reg.is_local("CP002"));
+        assert_eq!(reg.get_version("CP002"), Some(OcppVersion::V201));
+        assert_eq!(reg.remote_owner("CP002").as_deref(), Some("node-b"));
+        assert_eq!(reg.remote_owner("CP001"), None);
+        assert_eq!(reg.local_ids(), vec!["CP001"]);
+        assert_eq!(reg.count(), 2);
+        assert!(reg.send_to("CP002", "msg".into()).is_err());
+    }
+
+    #[test]
+    fn forget_remote_ignores_stale_owner() {
+        let reg = SessionRegistry::new();
+        reg.track_remote(remote("CP001", "node-b"));
+        reg.forget_remote("CP001", "node-a");
+        assert!(reg.is_connected("CP001"));
+        reg.forget_remote("CP001", "node-b");
+        assert!(!reg.is_connected("CP001"));
+
+        reg.track_remote(remote("CP002", "node-c"));
+        reg.forget_node("node-c");
+        assert!(!reg.is_connected("CP002"));
+    }
+
+    #[test]
+    fn local_registration_replaces_remote_session() {
+        let reg = SessionRegistry::new();
+        reg.track_remote(remote("CP001", "node-b"));
+        reg.register("CP001", make_sender(), OcppVersion::V16);
+        assert_eq!(reg.remote_owner("CP001"), None);
+        assert_eq!(reg.get_version("CP001"), Some(OcppVersion::V16));
+        reg.unregister("CP001");
+        assert!(!reg.is_connected("CP001"));
+    }
+
     #[test]
     fn shared_creates_arc() {
         let shared = SessionRegistry::shared();
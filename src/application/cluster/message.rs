@@ -0,0 +1,76 @@
+//! Messages exchanged between cluster nodes
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::application::charging::commands::CommandError;
+use crate::domain::events::EventMessage;
+use crate::domain::OcppVersion;
+
+/// A message with the node that sent it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterEnvelope {
+    /// Node that published the message
+    pub from: String,
+    #[serde(flatten)]
+    pub message: ClusterMessage,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClusterMessage {
+    /// A charge point connected to the sending node
+    SessionOpened {
+        charge_point_id: String,
+        ocpp_version: OcppVersion,
+    },
+    /// A charge point disconnected from the sending node
+    SessionClosed { charge_point_id: String },
+    /// `node_id` shut down or stopped reporting; forget its sessions
+    NodeLeft { node_id: String },
+    /// Ask `target` to send a command to a charge point it holds
+    Command {
+        request_id: String,
+        target: String,
+        charge_point_id: String,
+        action: String,
+        payload: Value,
+    },
+    /// Outcome of a relayed command, addressed to the node that asked
+    CommandResult {
+        request_id: String,
+        target: String,
+        result: Result<Value, CommandError>,
+    },
+    /// An event raised on the sending node
+    Event { message: EventMessage },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn envelope_roundtrips_as_flat_json() {
+        let envelope = ClusterEnvelope {
+            from: "node-a".into(),
+            message: ClusterMessage::CommandResult {
+                request_id: "node-b-1".into(),
+                target: "node-b".into(),
+                result: Err(CommandError::Timeout),
+            },
+        };
+        let json = serde_json::to_value(&envelope).unwrap();
+        assert_eq!(json["from"], "node-a");
+        assert_eq!(json["type"], "command_result");
+
+        let parsed: ClusterEnvelope = serde_json::from_value(json).unwrap();
+        assert!(matches!(
+            parsed.message,
+            ClusterMessage::CommandResult {
+                result: Err(CommandError::Timeout),
+                ..
+            }
+        ));
+    }
+}
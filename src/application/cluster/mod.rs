@@ -0,0 +1,22 @@
+//! Cluster mode — several instances serving one fleet
+//!
+//! A charge point's WebSocket lands on whichever node the load balancer
+//! picked, while REST calls may hit any node. Each node therefore:
+//!
+//! - records the charge points it holds in a shared directory and mirrors
+//!   the other nodes' entries into its `SessionRegistry`;
+//! - relays commands for charge points held elsewhere to the owning node
+//!   (done transparently by `CommandSender`, so `CommandDispatcher` and
+//!   its callers are unchanged);
+//! - forwards its events to the other nodes, where they reach
+//!   `EventBus::subscribe_cluster` subscribers (e.g. the notification
+//!   WebSocket).
+//!
+//! Transport and directory are behind the
+//! [`ClusterBackplane`](crate::application::ports::ClusterBackplane) port.
+
+pub mod message;
+pub mod node;
+
+pub use message::{ClusterEnvelope, ClusterMessage};
+pub use node::{ClusterNode, SharedClusterNode};
@@ -0,0 +1,639 @@
+//! This instance's membership in the cluster
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use dashmap::DashMap;
+use serde_json::Value;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::timeout;
+use tracing::{debug, info, warn};
+
+use super::message::{ClusterEnvelope, ClusterMessage};
+use crate::application::charging::commands::{CommandError, SharedCommandSender};
+use crate::application::charging::session::{RemoteSession, SharedSessionRegistry};
+use crate::application::events::{Event, EventMessage, SharedEventBus};
+use crate::application::ports::ClusterBackplane;
+use crate::shared::shutdown::ShutdownSignal;
+
+/// How long a relayed command may take: the owning node's own response
+/// timeout plus a margin for the round trip through the backplane.
+const RELAY_TIMEOUT_SECS: u64 = 35;
+
+/// A node of the cluster.
+///
+/// Publishes the sessions of this node to the shared directory, mirrors the
+/// other nodes' sessions into the [`SessionRegistry`](crate::application::SessionRegistry),
+/// relays commands for charge points held elsewhere and fans events out.
+pub struct ClusterNode {
+    node_id: String,
+    backplane: Arc<dyn ClusterBackplane>,
+    session_registry: SharedSessionRegistry,
+    pending: DashMap<String, oneshot::Sender<Result<Value, CommandError>>>,
+    request_counter: AtomicU64,
+}
+
+pub type SharedClusterNode = Arc<ClusterNode>;
+
+impl ClusterNode {
+    pub fn new(
+        node_id: impl Into<String>,
+        backplane: Arc<dyn ClusterBackplane>,
+        session_registry: SharedSessionRegistry,
+    ) -> Self {
+        Self {
+            node_id: node_id.into(),
+            backplane,
+            session_registry,
+            pending: DashMap::new(),
+            request_counter: AtomicU64::new(1),
+        }
+    }
+
+    pub fn node_id(&self) -> &str {
+        &self.node_id
+    }
+
+    /// Send a command to a charge point connected to node `target` and
+    /// wait for the charge point's answer.
+    pub async fn relay_command(
+        &self,
+        target: &str,
+        charge_point_id: &str,
+        action: &str,
+        payload: Value,
+    ) -> Result<Value, CommandError> {
+        let id = self.request_counter.fetch_add(1, Ordering::SeqCst);
+        let request_id = format!("{}-{}", self.node_id, id);
+
+        let (tx, rx) = oneshot::channel();
+        self.pending.insert(request_id.clone(), tx);
+
+        info!(
+            charge_point_id,
+            action,
+            target,
+            request_id = request_id.as_str(),
+            "Relaying command to cluster node"
+        );
+
+        let message = ClusterMessage::Command {
+            request_id: request_id.clone(),
+            target: target.to_string(),
+            charge_point_id: charge_point_id.to_string(),
+            action: action.to_string(),
+            payload,
+        };
+        if let Err(e) = self.publish(message).await {
+            self.pending.remove(&request_id);
+            return Err(CommandError::SendFailed(format!(
+                "Cluster relay to {} failed: {}",
+                target, e
+            )));
+        }
+
+        match timeout(Duration::from_secs(RELAY_TIMEOUT_SECS), rx).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => {
+                self.pending.remove(&request_id);
+                Err(CommandError::InvalidResponse(
+                    "Relay channel closed".to_string(),
+                ))
+            }
+            Err(_) => {
+                self.pending.remove(&request_id);
+                warn!(charge_point_id, action, target, "Relayed command timed out");
+                Err(CommandError::Timeout)
+            }
+        }
+    }
+
+    /// Join the cluster and run it in the background until `shutdown`.
+    ///
+    /// Loads the session directory, then keeps it in sync, executes
+    /// commands relayed to this node, forwards this node's events and
+    /// reports liveness every `heartbeat_interval`. Nodes silent for
+    /// `node_timeout` are dropped from the directory.
+    pub async fn start(
+        self: &Arc<Self>,
+        command_sender: SharedCommandSender,
+        event_bus: SharedEventBus,
+        shutdown: ShutdownSignal,
+        heartbeat_interval: Duration,
+        node_timeout: Duration,
+    ) -> Result<(), String> {
+        self.backplane.heartbeat(&self.node_id).await?;
+        // Subscribe before loading the directory so no change is missed
+        let mut inbound = self.backplane.subscribe().await?;
+        self.resync(node_timeout).await?;
+
+        info!(node_id = self.node_id.as_str(), "🕸️ Joined cluster");
+
+        // ── Local events → directory + other nodes ─────────────
+        let (forward_tx, mut forward_rx) = mpsc::unbounded_channel::<EventMessage>();
+        event_bus.forward_to(forward_tx);
+        let node = self.clone();
+        tokio::spawn(async move {
+            while let Some(message) = forward_rx.recv().await {
+                node.announce_presence(&message.event).await;
+                if let Err(e) = node.publish(ClusterMessage::Event { message }).await {
+                    warn!(error = %e, "Failed to forward event to cluster");
+                }
+            }
+        });
+
+        // ── Messages from other nodes ──────────────────────────
+        let node = self.clone();
+        let inbound_shutdown = shutdown.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    envelope = inbound.recv() => {
+                        match envelope {
+                            Some(envelope) => node.handle(envelope, &command_sender, &event_bus),
+                            None => {
+                                warn!("Cluster backplane closed");
+                                break;
+                            }
+                        }
+                    }
+                    _ = inbound_shutdown.notified().wait() => break,
+                }
+            }
+        });
+
+        // ── Heartbeat, reaping and directory resync ────────────
+        let node = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(heartbeat_interval);
+            interval.tick().await; // joined just now
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => node.tick(node_timeout).await,
+                    _ = shutdown.notified().wait() => {
+                        node.leave().await;
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Run the cluster-wide background tasks on whichever node leads.
+    ///
+    /// Competes for leadership every `interval`. On winning, `start_tasks`
+    /// is called with a signal that fires when this node loses leadership
+    /// or shuts down; another node then takes over within its `interval`.
+    pub fn lead<F>(self: &Arc<Self>, shutdown: ShutdownSignal, interval: Duration, start_tasks: F)
+    where
+        F: Fn(ShutdownSignal) + Send + 'static,
+    {
+        let node = self.clone();
+        tokio::spawn(async move {
+            let mut tasks: Option<ShutdownSignal> = None;
+            let mut interval = tokio::time::interval(interval);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        let leading = match node.backplane.try_lead().await {
+                            Ok(leading) => leading,
+                            Err(e) => {
+                                warn!(error = %e, "Cluster leader election failed");
+                                false
+                            }
+                        };
+                        match (leading, tasks.take()) {
+                            (true, None) => {
+                                info!(node_id = node.node_id.as_str(), "👑 Leading the cluster, starting background tasks");
+                                let signal = ShutdownSignal::new();
+                                start_tasks(signal.clone());
+                                tasks = Some(signal);
+                            }
+                            (false, Some(signal)) => {
+                                warn!(node_id = node.node_id.as_str(), "Lost cluster leadership, stopping background tasks");
+                                signal.trigger();
+                            }
+                            (_, running) => tasks = running,
+                        }
+                    }
+                    _ = shutdown.notified().wait() => {
+                        if let Some(signal) = tasks.take() {
+                            signal.trigger();
+                        }
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    async fn publish(&self, message: ClusterMessage) -> Result<(), String> {
+        let envelope = ClusterEnvelope {
+            from: self.node_id.clone(),
+            message,
+        };
+        self.backplane.publish(&envelope).await
+    }
+
+    /// Replace the mirror of other nodes' sessions with the directory's view.
+    async fn resync(&self, node_timeout: Duration) -> Result<(), String> {
+        let sessions = self.backplane.live_sessions(node_timeout).await?;
+        let others: Vec<RemoteSession> = sessions
+            .into_iter()
+            .filter(|s| s.node_id != self.node_id)
+            .collect();
+        self.session_registry.sync_remote(others);
+        Ok(())
+    }
+
+    async fn tick(&self, node_timeout: Duration) {
+        if let Err(e) = self.backplane.heartbeat(&self.node_id).await {
+            warn!(error = %e, "Cluster heartbeat failed");
+            return;
+        }
+        match self.backplane.reap_nodes(node_timeout).await {
+            Ok(dead) => {
+                for node_id in dead {
+                    warn!(node_id = node_id.as_str(), "Cluster node timed out");
+                    self.session_registry.forget_node(&node_id);
+                    let _ = self.publish(ClusterMessage::NodeLeft { node_id }).await;
+                }
+            }
+            Err(e) => warn!(error = %e, "Failed to reap cluster nodes"),
+        }
+        if let Err(e) = self.resync(node_timeout).await {
+            warn!(error = %e, "Failed to resync cluster sessions");
+        }
+    }
+
+    async fn leave(&self) {
+        if let Err(e) = self.backplane.leave(&self.node_id).await {
+            warn!(error = %e, "Failed to leave cluster");
+        }
+        let node_id = self.node_id.clone();
+        let _ = self.publish(ClusterMessage::NodeLeft { node_id }).await;
+        info!(node_id = self.node_id.as_str(), "🕸️ Left cluster");
+    }
+
+    /// Claim or release a charge point in the directory as it connects to
+    /// or disconnects from this node.
+    async fn announce_presence(&self, event: &Event) {
+        match event {
+            Event::ChargePointConnected(e) => {
+                // Already gone again, or never ours
+                if !self.session_registry.is_local(&e.charge_point_id) {
+                    return;
+                }
+                let Some(ocpp_version) = self.session_registry.get_version(&e.charge_point_id)
+                else {
+                    return;
+                };
+                let session = RemoteSession {
+                    charge_point_id: e.charge_point_id.clone(),
+                    node_id: self.node_id.clone(),
+                    ocpp_version,
+                };
+                if let Err(err) = self.backplane.claim_session(&session).await {
+                    warn!(error = %err, "Failed to claim cluster session");
+                }
+                let message = ClusterMessage::SessionOpened {
+                    charge_point_id: e.charge_point_id.clone(),
+                    ocpp_version,
+                };
+                let _ = self.publish(message).await;
+            }
+            Event::ChargePointDisconnected(e) => {
+                // Evicted by a reconnect to this same node: still ours
+                if self.session_registry.is_local(&e.charge_point_id) {
+                    return;
+                }
+                if let Err(err) = self
+                    .backplane
+                    .release_session(&self.node_id, &e.charge_point_id)
+                    .await
+                {
+                    warn!(error = %err, "Failed to release cluster session");
+                }
+                let message = ClusterMessage::SessionClosed {
+                    charge_point_id: e.charge_point_id.clone(),
+                };
+                let _ = self.publish(message).await;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle(
+        self: &Arc<Self>,
+        envelope: ClusterEnvelope,
+        command_sender: &SharedCommandSender,
+        event_bus: &SharedEventBus,
+    ) {
+        if envelope.from == self.node_id {
+            return;
+        }
+        let from = envelope.from;
+
+        match envelope.message {
+            ClusterMessage::SessionOpened {
+                charge_point_id,
+                ocpp_version,
+            } => {
+                debug!(
+                    charge_point_id = charge_point_id.as_str(),
+                    node_id = from.as_str(),
+                    "Remote session opened"
+                );
+                self.session_registry.track_remote(RemoteSession {
+                    charge_point_id,
+                    node_id: from,
+                    ocpp_version,
+                });
+            }
+            ClusterMessage::SessionClosed { charge_point_id } => {
+                self.session_registry.forget_remote(&charge_point_id, &from);
+            }
+            ClusterMessage::NodeLeft { node_id } => {
+                info!(node_id = node_id.as_str(), "Cluster node left");
+                self.session_registry.forget_node(&node_id);
+            }
+            ClusterMessage::Command {
+                request_id,
+                target,
+                charge_point_id,
+                action,
+                payload,
+            } => {
+                if target != self.node_id {
+                    return;
+                }
+                let node = self.clone();
+                let command_sender = command_sender.clone();
+                tokio::spawn(async move {
+                    let result = command_sender
+                        .send_local(&charge_point_id, &action, payload)
+                        .await;
+                    let message = ClusterMessage::CommandResult {
+                        request_id,
+                        target: from,
+                        result,
+                    };
+                    if let Err(e) = node.publish(message).await {
+                        warn!(error = %e, "Failed to return relayed command result");
+                    }
+                });
+            }
+            ClusterMessage::CommandResult {
+                request_id,
+                target,
+                result,
+            } => {
+                if target != self.node_id {
+                    return;
+                }
+                if let Some((_, pending)) = self.pending.remove(&request_id) {
+                    let _ = pending.send(result);
+                } else {
+                    warn!(
+                        request_id = request_id.as_str(),
+                        "Result for unknown relayed command"
+                    );
+                }
+            }
+            ClusterMessage::Event { message } => event_bus.publish_remote(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::charging::schema::{SchemaValidationMode, SchemaValidator};
+    use crate::application::commands::create_command_sender;
+    use crate::application::events::{create_event_bus, ChargePointConnectedEvent};
+    use crate::application::SessionRegistry;
+    use crate::domain::OcppVersion;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Mutex;
+    use tokio::sync::broadcast;
+
+    /// Backplane shared by the nodes of one test, in memory.
+    struct MemoryBackplane {
+        bus: broadcast::Sender<ClusterEnvelope>,
+        sessions: Mutex<Vec<RemoteSession>>,
+        leader: AtomicBool,
+    }
+
+    impl MemoryBackplane {
+        fn new() -> Arc<Self> {
+            let (bus, _) = broadcast::channel(64);
+            Arc::new(Self {
+                bus,
+                sessions: Mutex::new(Vec::new()),
+                leader: AtomicBool::new(true),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl ClusterBackplane for MemoryBackplane {
+        async fn publish(&self, envelope: &ClusterEnvelope) -> Result<(), String> {
+            let _ = self.bus.send(envelope.clone());
+            Ok(())
+        }
+
+        async fn subscribe(&self) -> Result<mpsc::UnboundedReceiver<ClusterEnvelope>, String> {
+            let (tx, rx) = mpsc::unbounded_channel();
+            let mut bus = self.bus.subscribe();
+            tokio::spawn(async move {
+                while let Ok(envelope) = bus.recv().await {
+                    if tx.send(envelope).is_err() {
+                        break;
+                    }
+                }
+            });
+            Ok(rx)
+        }
+
+        async fn heartbeat(&self, _node_id: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn claim_session(&self, session: &RemoteSession) -> Result<(), String> {
+            let mut sessions = self.sessions.lock().unwrap();
+            sessions.retain(|s| s.charge_point_id != session.charge_point_id);
+            sessions.push(session.clone());
+            Ok(())
+        }
+
+        async fn release_session(
+            &self,
+            node_id: &str,
+            charge_point_id: &str,
+        ) -> Result<(), String> {
+            self.sessions
+                .lock()
+                .unwrap()
+                .retain(|s| !(s.node_id == node_id && s.charge_point_id == charge_point_id));
+            Ok(())
+        }
+
+        async fn live_sessions(
+            &self,
+            _node_timeout: Duration,
+        ) -> Result<Vec<RemoteSession>, String> {
+            Ok(self.sessions.lock().unwrap().clone())
+        }
+
+        async fn reap_nodes(&self, _node_timeout: Duration) -> Result<Vec<String>, String> {
+            Ok(Vec::new())
+        }
+
+        async fn leave(&self, node_id: &str) -> Result<(), String> {
+            self.sessions
+                .lock()
+                .unwrap()
+                .retain(|s| s.node_id != node_id);
+            Ok(())
+        }
+
+        async fn try_lead(&self) -> Result<bool, String> {
+            Ok(self.leader.load(Ordering::SeqCst))
+        }
+    }
+
+    struct TestNode {
+        registry: SharedSessionRegistry,
+        command_sender: SharedCommandSender,
+        event_bus: SharedEventBus,
+    }
+
+    async fn join(
+        backplane: &Arc<MemoryBackplane>,
+        node_id: &str,
+        shutdown: &ShutdownSignal,
+    ) -> TestNode {
+        let registry = SessionRegistry::shared();
+        let validator = Arc::new(SchemaValidator::new(SchemaValidationMode::Off));
+        let command_sender = create_command_sender(registry.clone(), validator);
+        let event_bus = create_event_bus();
+        let node = Arc::new(ClusterNode::new(
+            node_id,
+            backplane.clone(),
+            registry.clone(),
+        ));
+        command_sender.attach_cluster(node.clone());
+        node.start(
+            command_sender.clone(),
+            event_bus.clone(),
+            shutdown.clone(),
+            Duration::from_secs(60),
+            Duration::from_secs(120),
+        )
+        .await
+        .unwrap();
+        TestNode {
+            registry,
+            command_sender,
+            event_bus,
+        }
+    }
+
+    async fn eventually(check: impl Fn() -> bool) {
+        for _ in 0..100 {
+            if check() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("condition not met in time");
+    }
+
+    #[tokio::test]
+    async fn commands_are_relayed_to_the_owning_node() {
+        let backplane = MemoryBackplane::new();
+        let shutdown = ShutdownSignal::new();
+        let a = join(&backplane, "node-a", &shutdown).await;
+        let b = join(&backplane, "node-b", &shutdown).await;
+        let mut b_events = b.event_bus.subscribe_cluster();
+
+        // The station connects to node A
+        let (tx, mut station) = mpsc::unbounded_channel();
+        a.registry.register("CP001", tx, OcppVersion::V16);
+        a.event_bus
+            .publish(Event::ChargePointConnected(ChargePointConnectedEvent {
+                charge_point_id: "CP001".into(),
+                ocpp_version: "1.6".into(),
+                timestamp: Utc::now(),
+                remote_addr: None,
+            }));
+
+        eventually(|| b.registry.is_connected("CP001")).await;
+        assert_eq!(b.registry.get_version("CP001"), Some(OcppVersion::V16));
+        let event = tokio::time::timeout(Duration::from_secs(1), b_events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.event.charge_point_id(), Some("CP001"));
+
+        // A command sent on node B reaches the station through node A
+        let sender = b.command_sender.clone();
+        let call = tokio::spawn(async move {
+            sender
+                .send_command("CP001", "ClearCache", serde_json::json!({}))
+                .await
+        });
+        let frame = tokio::time::timeout(Duration::from_secs(1), station.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let frame: Value = serde_json::from_str(&frame).unwrap();
+        assert_eq!(frame[2], "ClearCache");
+        let message_id = frame[1].as_str().unwrap();
+        a.command_sender.handle_response(
+            "CP001",
+            message_id,
+            serde_json::json!({"status": "Accepted"}),
+        );
+
+        let response = call.await.unwrap().unwrap();
+        assert_eq!(response["status"], "Accepted");
+        shutdown.trigger();
+    }
+
+    #[tokio::test]
+    async fn background_tasks_run_only_while_leading() {
+        let backplane = MemoryBackplane::new();
+        let shutdown = ShutdownSignal::new();
+        let node = Arc::new(ClusterNode::new(
+            "node-a",
+            backplane.clone(),
+            SessionRegistry::shared(),
+        ));
+
+        let started = Arc::new(Mutex::new(Vec::<ShutdownSignal>::new()));
+        node.lead(shutdown.clone(), Duration::from_millis(10), {
+            let started = started.clone();
+            move |signal| started.lock().unwrap().push(signal)
+        });
+
+        eventually(|| started.lock().unwrap().len() == 1).await;
+        assert!(!started.lock().unwrap()[0].is_triggered());
+
+        // Leadership moves elsewhere: the tasks stop
+        backplane.leader.store(false, Ordering::SeqCst);
+        eventually(|| started.lock().unwrap()[0].is_triggered()).await;
+
+        // and start again once it comes back
+        backplane.leader.store(true, Ordering::SeqCst);
+        eventually(|| started.lock().unwrap().len() == 2).await;
+
+        shutdown.trigger();
+        eventually(|| started.lock().unwrap()[1].is_triggered()).await;
+    }
+}
@@ -1,9 +1,9 @@
 //! Event Bus for broadcasting events to subscribers
 
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, mpsc};
 use tracing::{debug, info, warn};
 
 use crate::domain::events::{Event, EventMessage};
@@ -11,9 +11,17 @@ use crate::domain::events::{Event, EventMessage};
 const DEFAULT_CAPACITY: usize = 1024;
 
 /// Event bus for broadcasting events to all subscribers
+///
+/// In cluster mode every node has its own bus. `subscribe` only sees events
+/// raised on this node (so side effects run once), `subscribe_cluster` also
+/// sees the ones relayed from other nodes.
 #[derive(Clone)]
 pub struct EventBus {
     sender: broadcast::Sender<EventMessage>,
+    /// Local events plus events relayed from other cluster nodes
+    cluster_sender: broadcast::Sender<EventMessage>,
+    /// Hands local events to the cluster relay (cluster mode only)
+    forwarder: Arc<OnceLock<mpsc::UnboundedSender<EventMessage>>>,
     subscriber_count: Arc<AtomicUsize>,
 }
 
@@ -24,8 +32,11 @@ impl EventBus {
 
     pub fn with_capacity(capacity: usize) -> Self {
         let (sender, _) = broadcast::channel(capacity);
+        let (cluster_sender, _) = broadcast::channel(capacity);
         Self {
             sender,
+            cluster_sender,
+            forwarder: Arc::new(OnceLock::new()),
             subscriber_count: Arc::new(AtomicUsize::new(0)),
         }
     }
@@ -52,6 +63,11 @@ impl EventBus {
             _ => {}
         }
 
+        let _ = self.cluster_sender.send(message.clone());
+        if let Some(forwarder) = self.forwarder.get() {
+            let _ = forwarder.send(message.clone());
+        }
+
         match self.sender.send(message) {
             Ok(count) => {
                 debug!(
@@ -71,8 +87,32 @@ impl EventBus {
         }
     }
 
+    /// Deliver an event raised on another cluster node to `subscribe_cluster`
+    /// subscribers. It is not forwarded again.
+    pub fn publish_remote(&self, message: EventMessage) {
+        let _ = self.cluster_sender.send(message);
+    }
+
+    /// Send every locally published event to `forwarder` as well (cluster
+    /// relay). Only the first call takes effect.
+    pub fn forward_to(&self, forwarder: mpsc::UnboundedSender<EventMessage>) {
+        if self.forwarder.set(forwarder).is_err() {
+            warn!("Event forwarder already set — ignoring");
+        }
+    }
+
+    /// Subscribe to events raised on this node.
     pub fn subscribe(&self) -> EventSubscriber {
-        let receiver = self.sender.subscribe();
+        self.subscribe_to(&self.sender)
+    }
+
+    /// Subscribe to events raised anywhere in the cluster.
+    pub fn subscribe_cluster(&self) -> EventSubscriber {
+        self.subscribe_to(&self.cluster_sender)
+    }
+
+    fn subscribe_to(&self, sender: &broadcast::Sender<EventMessage>) -> EventSubscriber {
+        let receiver = sender.subscribe();
         self.subscriber_count.fetch_add(1, Ordering::SeqCst);
         let count = self.subscriber_count.load(Ordering::SeqCst);
         info!(total = count, "New event subscriber");
@@ -192,6 +232,38 @@ mod tests {
         bus.publish(make_event()); // no subscriber — should not panic
     }
 
+    #[tokio::test]
+    async fn remote_events_reach_cluster_subscribers_only() {
+        let bus = EventBus::new();
+        let mut local = bus.subscribe();
+        let mut cluster = bus.subscribe_cluster();
+        let (tx, mut forwarded) = mpsc::unbounded_channel();
+        bus.forward_to(tx);
+
+        bus.publish_remote(EventMessage::new(make_event()));
+        bus.publish(make_event());
+
+        let timeout = std::time::Duration::from_secs(1);
+        let first = tokio::time::timeout(timeout, cluster.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let second = tokio::time::timeout(timeout, cluster.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let own = tokio::time::timeout(timeout, local.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_ne!(first.id, second.id);
+        assert_eq!(own.id, second.id);
+
+        // Only the local event goes out to the cluster
+        assert_eq!(forwarded.try_recv().unwrap().id, own.id);
+        assert!(forwarded.try_recv().is_err());
+    }
+
     #[test]
     fn with_capacity_creates_bus() {
         let bus = EventBus::with_capacity(16);
@@ -1,5 +1,9 @@
+pub mod bulk;
 pub mod charging;
+pub mod cluster;
 pub mod identity;
+pub mod privacy;
+pub mod retention;
 
 // pub mod dto;
 pub mod events;
@@ -0,0 +1,30 @@
+//! Archive store port — where expired rows go before they are deleted
+//!
+//! Rows are archived per data class and run; an archive only counts once
+//! [`ArchiveWriter::finish`] returns, so rows must not be deleted before.
+//! The production implementation writes gzip-compressed JSON Lines files,
+//! see [`JsonlArchiveStore`](crate::infrastructure::archive::JsonlArchiveStore).
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::retention::{DataClass, ExpiredRow};
+
+#[async_trait]
+pub trait ArchiveStore: Send + Sync {
+    /// Open a new archive for rows of `data_class` moved by run `run_id`.
+    async fn create(
+        &self,
+        data_class: DataClass,
+        run_id: i32,
+        started_at: DateTime<Utc>,
+    ) -> Result<Box<dyn ArchiveWriter>, String>;
+}
+
+#[async_trait]
+pub trait ArchiveWriter: Send {
+    async fn append(&mut self, rows: &[ExpiredRow]) -> Result<(), String>;
+
+    /// Make the archive durable; returns where it was stored.
+    async fn finish(self: Box<Self>) -> Result<String, String>;
+}
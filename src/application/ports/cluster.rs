@@ -0,0 +1,48 @@
+//! Cluster backplane port — how nodes of a cluster talk to each other
+//!
+//! A backplane carries [`ClusterEnvelope`]s between nodes, keeps the
+//! shared session directory (which node holds which charge point) and
+//! elects the node that runs the cluster-wide background tasks. The
+//! production implementation uses PostgreSQL LISTEN/NOTIFY and the
+//! `cluster_*` tables, see
+//! [`PgClusterBackplane`](crate::infrastructure::cluster::PgClusterBackplane).
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+use crate::application::charging::session::RemoteSession;
+use crate::application::cluster::ClusterEnvelope;
+
+#[async_trait]
+pub trait ClusterBackplane: Send + Sync {
+    /// Deliver a message to every node (the sender receives it as well).
+    async fn publish(&self, envelope: &ClusterEnvelope) -> Result<(), String>;
+
+    /// Start receiving messages published by any node.
+    async fn subscribe(&self) -> Result<mpsc::UnboundedReceiver<ClusterEnvelope>, String>;
+
+    /// Register the node, or refresh its liveness.
+    async fn heartbeat(&self, node_id: &str) -> Result<(), String>;
+
+    /// Record that `session.node_id` now holds the charge point's WebSocket.
+    async fn claim_session(&self, session: &RemoteSession) -> Result<(), String>;
+
+    /// Remove the charge point's directory entry if it still belongs to `node_id`.
+    async fn release_session(&self, node_id: &str, charge_point_id: &str) -> Result<(), String>;
+
+    /// Sessions held by nodes seen within `node_timeout`.
+    async fn live_sessions(&self, node_timeout: Duration) -> Result<Vec<RemoteSession>, String>;
+
+    /// Remove nodes silent for longer than `node_timeout`, with their
+    /// sessions; returns the removed node IDs.
+    async fn reap_nodes(&self, node_timeout: Duration) -> Result<Vec<String>, String>;
+
+    /// Remove the node and its sessions (clean shutdown).
+    async fn leave(&self, node_id: &str) -> Result<(), String>;
+
+    /// Become the cluster leader if no other node is, or confirm this node
+    /// still is; at most one backplane in the cluster leads at a time.
+    async fn try_lead(&self) -> Result<bool, String>;
+}
@@ -12,13 +12,17 @@ use async_trait::async_trait;
 
 use crate::application::charging::commands::dispatcher::ClearChargingProfileCriteria;
 use crate::application::charging::commands::dispatcher::ClearVariableMonitoringResult;
+use crate::application::charging::commands::dispatcher::ComponentVariableSelector;
 use crate::application::charging::commands::dispatcher::GetChargingProfilesCriteria;
 use crate::application::charging::commands::dispatcher::GetChargingProfilesResult;
 use crate::application::charging::commands::dispatcher::GetDiagnosticsResult;
 use crate::application::charging::commands::dispatcher::GetBaseReportResult;
+use crate::application::charging::commands::dispatcher::GetMonitoringReportResult;
+use crate::application::charging::commands::dispatcher::GetReportResult;
 use crate::application::charging::commands::dispatcher::GetTransactionStatusResult;
 use crate::application::charging::commands::dispatcher::MonitorDescriptor;
 use crate::application::charging::commands::dispatcher::SetMonitoringBaseResult;
+use crate::application::charging::commands::dispatcher::SetMonitoringLevelResult;
 use crate::application::charging::commands::dispatcher::SetVariableMonitoringResult;
 use crate::application::charging::commands::{
     Availability, CommandError, CompositeScheduleResult, ConfigurationResult, DataTransferResult,
@@ -291,6 +295,16 @@ pub trait OcppOutboundPort: Send + Sync {
         report_base: &str,
     ) -> Result<GetBaseReportResult, CommandError>;
 
+    /// GetReport (v2.0.1 only) — request a report filtered by component
+    /// criteria and/or component-variable pairs.
+    async fn get_report(
+        &self,
+        charge_point_id: &str,
+        request_id: i32,
+        component_criteria: &[String],
+        component_variables: &[ComponentVariableSelector],
+    ) -> Result<GetReportResult, CommandError>;
+
     // ─── Variable Monitoring (v2.0.1 only) ─────────────────────
 
     /// SetVariableMonitoring — configure variable monitors on a charge point.
@@ -307,6 +321,22 @@ pub trait OcppOutboundPort: Send + Sync {
         monitoring_base: &str,
     ) -> Result<SetMonitoringBaseResult, CommandError>;
 
+    /// SetMonitoringLevel — report only events up to this severity.
+    async fn set_monitoring_level(
+        &self,
+        charge_point_id: &str,
+        severity: u8,
+    ) -> Result<SetMonitoringLevelResult, CommandError>;
+
+    /// GetMonitoringReport — request a report of configured monitors.
+    async fn get_monitoring_report(
+        &self,
+        charge_point_id: &str,
+        request_id: i32,
+        monitoring_criteria: &[String],
+        component_variables: &[ComponentVariableSelector],
+    ) -> Result<GetMonitoringReportResult, CommandError>;
+
     /// ClearVariableMonitoring — remove variable monitors by ID.
     async fn clear_variable_monitoring(
         &self,
@@ -0,0 +1,12 @@
+//! Privacy module — customer personal data (GDPR) use-cases
+//!
+//! Contains the `CustomerDataService`, which exports everything the CSMS
+//! stores about a driver (by id tag or user account) and pseudonymises it
+//! on an erasure request.
+
+pub mod service;
+
+pub use service::{
+    pseudonym, CustomerDataService, ErasureReport, PersonalData, TransactionRecord,
+    UserErasureReport,
+};
@@ -0,0 +1,320 @@
+//! Customer data service — export and erasure of personal data
+//!
+//! A driver is identified by an id tag (RFID uid / id token) or by the user
+//! account the tags are assigned to. Personal data held by the CSMS:
+//! the id tag record, transactions (including meter readings and billing),
+//! reservations, authorization events and cached OCPI tokens.
+//!
+//! Erasure replaces the id tag with a random pseudonym on every historical
+//! record (so energy and revenue statistics survive), deletes the id tag
+//! record, its OCPI tokens and, for a user, the account itself. Tags that
+//! had the erased tag as their parent are detached from it.
+
+use std::sync::Arc;
+
+use tracing::info;
+
+use crate::domain::{
+    AuthEvent, DomainError, DomainResult, IdTag, OcpiToken, RepositoryProvider, Reservation,
+    Transaction, TransactionBilling, TransactionStatus, User, UserRepositoryInterface,
+};
+
+/// Prefix of pseudonyms written over erased id tags.
+pub const PSEUDONYM_PREFIX: &str = "ANON-";
+
+/// A transaction together with its billing record.
+#[derive(Debug, Clone)]
+pub struct TransactionRecord {
+    pub transaction: Transaction,
+    pub billing: Option<TransactionBilling>,
+}
+
+/// Everything stored about a driver.
+#[derive(Debug, Clone, Default)]
+pub struct PersonalData {
+    /// User account, when exporting by user.
+    pub user: Option<User>,
+    /// Id tag values covered by this export.
+    pub id_tags: Vec<String>,
+    /// Id tag records known to the CSMS.
+    pub id_tag_records: Vec<IdTag>,
+    pub transactions: Vec<TransactionRecord>,
+    pub reservations: Vec<Reservation>,
+    pub auth_events: Vec<AuthEvent>,
+    /// Tokens pushed by OCPI eMSPs whose uid matches one of the id tags.
+    pub ocpi_tokens: Vec<OcpiToken>,
+}
+
+impl PersonalData {
+    /// Whether nothing at all is stored.
+    pub fn is_empty(&self) -> bool {
+        self.user.is_none()
+            && self.id_tag_records.is_empty()
+            && self.transactions.is_empty()
+            && self.reservations.is_empty()
+            && self.auth_events.is_empty()
+            && self.ocpi_tokens.is_empty()
+    }
+}
+
+/// Outcome of erasing a single id tag.
+#[derive(Debug, Clone)]
+pub struct ErasureReport {
+    pub id_tag: String,
+    /// Pseudonym written in place of the id tag.
+    pub pseudonym: String,
+    pub transactions: u64,
+    pub reservations: u64,
+    pub auth_events: u64,
+    /// OCPI tokens deleted.
+    pub ocpi_tokens: u64,
+    /// Child id tags detached from the erased tag.
+    pub child_id_tags: u64,
+    /// Whether an id tag record was deleted.
+    pub id_tag_removed: bool,
+}
+
+/// Outcome of erasing a user and all of their id tags.
+#[derive(Debug, Clone)]
+pub struct UserErasureReport {
+    pub user_id: String,
+    pub id_tags: Vec<ErasureReport>,
+}
+
+/// Customer data service — orchestrates GDPR export / erasure use-cases.
+pub struct CustomerDataService {
+    repos: Arc<dyn RepositoryProvider>,
+    users: Arc<dyn UserRepositoryInterface>,
+}
+
+impl CustomerDataService {
+    pub fn new(
+        repos: Arc<dyn RepositoryProvider>,
+        users: Arc<dyn UserRepositoryInterface>,
+    ) -> Self {
+        Self { repos, users }
+    }
+
+    // ── Export ──────────────────────────────────────────────────
+
+    /// Export all data stored for an id tag.
+    pub async fn export_id_tag(&self, id_tag: &str) -> DomainResult<PersonalData> {
+        let mut data = PersonalData::default();
+        self.collect_id_tag(id_tag, &mut data).await?;
+
+        if data.is_empty() {
+            return Err(not_found("IdTag", "id_tag", id_tag));
+        }
+        Ok(data)
+    }
+
+    /// Export the user account and all data stored for its id tags.
+    pub async fn export_user(&self, user_id: &str) -> DomainResult<PersonalData> {
+        let user = self
+            .users
+            .get_user_by_id(user_id)
+            .await?
+            .ok_or_else(|| not_found("User", "id", user_id))?;
+
+        let mut data = PersonalData {
+            user: Some(user),
+            ..Default::default()
+        };
+        for tag in self.repos.id_tags().find_by_user(user_id).await? {
+            self.collect_id_tag(&tag.id_tag, &mut data).await?;
+        }
+        Ok(data)
+    }
+
+    async fn collect_id_tag(&self, id_tag: &str, data: &mut PersonalData) -> DomainResult<()> {
+        data.id_tags.push(id_tag.to_string());
+
+        if let Some(record) = self.repos.id_tags().find(id_tag).await? {
+            data.id_tag_records.push(record);
+        }
+
+        for transaction in self.repos.transactions().find_by_id_tag(id_tag).await? {
+            let billing = self.repos.billing().get_billing(transaction.id).await?;
+            data.transactions.push(TransactionRecord {
+                transaction,
+                billing,
+            });
+        }
+
+        data.reservations
+            .extend(self.repos.reservations().find_by_id_tag(id_tag).await?);
+        data.auth_events
+            .extend(self.repos.auth_events().find_by_id_tag(id_tag).await?);
+        data.ocpi_tokens
+            .extend(self.repos.ocpi_tokens().find_by_uid(id_tag).await?);
+        Ok(())
+    }
+
+    // ── Erasure ─────────────────────────────────────────────────
+
+    /// Pseudonymise every record referencing `id_tag` and delete the tag.
+    ///
+    /// Refused while the tag has a transaction in progress.
+    pub async fn erase_id_tag(&self, id_tag: &str) -> DomainResult<ErasureReport> {
+        let data = self.export_id_tag(id_tag).await?;
+        ensure_no_active_transactions(&data)?;
+        self.erase_id_tag_unchecked(id_tag).await
+    }
+
+    /// Erase all id tags of a user, then delete the account.
+    ///
+    /// Refused while any of the user's tags has a transaction in progress.
+    pub async fn erase_user(&self, user_id: &str) -> DomainResult<UserErasureReport> {
+        let data = self.export_user(user_id).await?;
+        ensure_no_active_transactions(&data)?;
+
+        let mut id_tags = Vec::with_capacity(data.id_tags.len());
+        for id_tag in &data.id_tags {
+            id_tags.push(self.erase_id_tag_unchecked(id_tag).await?);
+        }
+        self.users.delete_user(user_id).await?;
+
+        info!(
+            user_id,
+            id_tags = id_tags.len(),
+            "User personal data erased"
+        );
+        Ok(UserErasureReport {
+            user_id: user_id.to_string(),
+            id_tags,
+        })
+    }
+
+    async fn erase_id_tag_unchecked(&self, id_tag: &str) -> DomainResult<ErasureReport> {
+        let pseudonym = pseudonym();
+
+        let transactions = self
+            .repos
+            .transactions()
+            .replace_id_tag(id_tag, &pseudonym)
+            .await?;
+        let reservations = self
+            .repos
+            .reservations()
+            .replace_id_tag(id_tag, &pseudonym)
+            .await?;
+        let auth_events = self
+            .repos
+            .auth_events()
+            .replace_id_tag(id_tag, &pseudonym)
+            .await?;
+
+        let ocpi_tokens = self.repos.ocpi_tokens().delete_by_uid(id_tag).await?;
+        let child_id_tags = self.repos.id_tags().clear_parent(id_tag).await?;
+
+        let id_tag_removed = self.repos.id_tags().find(id_tag).await?.is_some();
+        if id_tag_removed {
+            self.repos.id_tags().remove(id_tag).await?;
+        }
+
+        info!(
+            pseudonym = pseudonym.as_str(),
+            transactions,
+            reservations,
+            auth_events,
+            ocpi_tokens,
+            child_id_tags,
+            id_tag_removed,
+            "Id tag personal data erased"
+        );
+
+        Ok(ErasureReport {
+            id_tag: id_tag.to_string(),
+            pseudonym,
+            transactions,
+            reservations,
+            auth_events,
+            ocpi_tokens,
+            child_id_tags,
+            id_tag_removed,
+        })
+    }
+}
+
+/// Random, non-reversible replacement for an id tag.
+///
+/// 20 characters, the OCPP 1.6 idTag length limit.
+pub fn pseudonym() -> String {
+    let random = uuid::Uuid::new_v4().simple().to_string();
+    format!(
+        "{}{}",
+        PSEUDONYM_PREFIX,
+        &random[..20 - PSEUDONYM_PREFIX.len()]
+    )
+}
+
+fn ensure_no_active_transactions(data: &PersonalData) -> DomainResult<()> {
+    match data
+        .transactions
+        .iter()
+        .find(|r| r.transaction.status == TransactionStatus::Active)
+    {
+        Some(r) => Err(DomainError::Conflict(format!(
+            "Transaction {} is still in progress; stop it before erasing customer data",
+            r.transaction.id
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn not_found(entity: &'static str, field: &'static str, value: &str) -> DomainError {
+    DomainError::NotFound {
+        entity,
+        field,
+        value: value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transaction(status: TransactionStatus) -> TransactionRecord {
+        let mut transaction = Transaction::new(7, "CP001", 1, "TAG1", 0);
+        transaction.status = status;
+        TransactionRecord {
+            transaction,
+            billing: None,
+        }
+    }
+
+    #[test]
+    fn pseudonym_fits_id_tag_length_and_is_random() {
+        let a = pseudonym();
+        let b = pseudonym();
+        assert_eq!(a.len(), 20);
+        assert!(a.starts_with(PSEUDONYM_PREFIX));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn empty_personal_data() {
+        let mut data = PersonalData::default();
+        data.id_tags.push("TAG1".into());
+        assert!(data.is_empty());
+
+        data.transactions
+            .push(transaction(TransactionStatus::Completed));
+        assert!(!data.is_empty());
+    }
+
+    #[test]
+    fn active_transaction_blocks_erasure() {
+        let mut data = PersonalData::default();
+        data.transactions
+            .push(transaction(TransactionStatus::Completed));
+        assert!(ensure_no_active_transactions(&data).is_ok());
+
+        data.transactions
+            .push(transaction(TransactionStatus::Active));
+        assert!(matches!(
+            ensure_no_active_transactions(&data),
+            Err(DomainError::Conflict(_))
+        ));
+    }
+}
@@ -0,0 +1,11 @@
+//! Retention module — archival of historical data past its retention
+//!
+//! Contains the `ArchiveService`, which moves expired rows of each data
+//! class to an archive store and deletes them, and the background task
+//! running it on a schedule.
+
+pub mod service;
+mod task;
+
+pub use service::{ArchiveService, ArchiveSettings};
+pub use task::start_archival_task;
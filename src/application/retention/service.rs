@@ -0,0 +1,230 @@
+//! Archive service — moves expired rows to the archive, then deletes them
+//!
+//! Per data class, rows that ended before the class's cutoff are read in
+//! batches by ascending id and appended to one archive. Only once the
+//! archive is durable are the rows deleted, so a failure leaves them in
+//! place for the next run. Every run is recorded as an `ArchiveRun`.
+//!
+//! Id tags are replaced by pseudonyms before rows reach the archive, so a
+//! later erasure of a driver does not have to touch archive files. Within
+//! one archive a tag always maps to the same pseudonym.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use tracing::{info, warn};
+
+use crate::application::ports::ArchiveStore;
+use crate::application::privacy::pseudonym;
+use crate::domain::retention::{ArchivedClass, ExpiredRow, RetentionPolicy};
+use crate::domain::{
+    ArchiveRun, ArchiveRunStatus, ArchiveTrigger, DataClass, DomainError, DomainResult,
+    RepositoryProvider,
+};
+
+/// Rows read, archived and deleted at a time.
+const BATCH_SIZE: u64 = 500;
+
+/// Retention policies; classes without one are kept forever.
+#[derive(Debug, Clone, Default)]
+pub struct ArchiveSettings {
+    pub policies: Vec<RetentionPolicy>,
+}
+
+pub struct ArchiveService {
+    repos: Arc<dyn RepositoryProvider>,
+    store: Arc<dyn ArchiveStore>,
+    settings: ArchiveSettings,
+    /// Held for the duration of a run
+    running: Arc<Mutex<()>>,
+}
+
+impl ArchiveService {
+    pub fn new(
+        repos: Arc<dyn RepositoryProvider>,
+        store: Arc<dyn ArchiveStore>,
+        settings: ArchiveSettings,
+    ) -> Self {
+        Self {
+            repos,
+            store,
+            settings,
+            running: Arc::new(Mutex::new(())),
+        }
+    }
+
+    pub fn policies(&self) -> &[RetentionPolicy] {
+        &self.settings.policies
+    }
+
+    /// Archive everything past its retention and wait for the run to end.
+    pub async fn run(&self, trigger: ArchiveTrigger) -> DomainResult<ArchiveRun> {
+        let _running = self.lock()?;
+        let run = self.begin(trigger).await?;
+        Ok(self.execute(run).await)
+    }
+
+    /// Start a run in the background; returns it while still running.
+    pub async fn start(self: &Arc<Self>, trigger: ArchiveTrigger) -> DomainResult<ArchiveRun> {
+        let running = self.lock()?;
+        let run = self.begin(trigger).await?;
+
+        let service = self.clone();
+        let started = run.clone();
+        tokio::spawn(async move {
+            let _running = running;
+            service.execute(run).await;
+        });
+        Ok(started)
+    }
+
+    /// Mark runs a previous process never finished as failed.
+    pub async fn fail_interrupted(&self) -> DomainResult<()> {
+        let _running = self.lock()?;
+        for mut run in self.repos.retention().find_runs(100).await? {
+            if run.status == ArchiveRunStatus::Running {
+                warn!(run_id = run.id, "Archival run was interrupted");
+                run.finish(Utc::now(), Some("Interrupted by a restart".to_string()));
+                self.repos.retention().update_run(run).await?;
+            }
+        }
+        Ok(())
+    }
+
+    fn lock(&self) -> DomainResult<OwnedMutexGuard<()>> {
+        self.running
+            .clone()
+            .try_lock_owned()
+            .map_err(|_| DomainError::Conflict("an archival run is in progress".to_string()))
+    }
+
+    async fn begin(&self, trigger: ArchiveTrigger) -> DomainResult<ArchiveRun> {
+        self.repos
+            .retention()
+            .save_run(ArchiveRun::start(trigger, Utc::now()))
+            .await
+    }
+
+    async fn execute(&self, mut run: ArchiveRun) -> ArchiveRun {
+        info!(
+            run_id = run.id,
+            trigger = run.trigger.as_str(),
+            "🗄️ Archival run started"
+        );
+
+        let mut error = None;
+        for policy in &self.settings.policies {
+            let Some(cutoff) = policy.cutoff(run.started_at) else {
+                continue;
+            };
+            match self.archive_class(&run, policy.data_class, cutoff).await {
+                Ok(archived) => run.record(archived),
+                Err(e) => {
+                    error = Some(format!("{}: {}", policy.data_class, e));
+                    break;
+                }
+            }
+        }
+        run.finish(Utc::now(), error);
+
+        match &run.error {
+            Some(e) => warn!(run_id = run.id, error = %e, "Archival run failed"),
+            None => info!(
+                run_id = run.id,
+                rows = run.rows_archived,
+                "🗄️ Archival run completed"
+            ),
+        }
+        if let Err(e) = self.repos.retention().update_run(run.clone()).await {
+            warn!(run_id = run.id, error = %e, "Failed to record archival run");
+        }
+        run
+    }
+
+    async fn archive_class(
+        &self,
+        run: &ArchiveRun,
+        data_class: DataClass,
+        cutoff: DateTime<Utc>,
+    ) -> Result<ArchivedClass, String> {
+        let retention = self.repos.retention();
+        let mut writer = None;
+        let mut ids = Vec::new();
+        let mut after_id = 0;
+        let mut pseudonyms = HashMap::new();
+
+        loop {
+            let mut rows = retention
+                .find_expired(data_class, cutoff, after_id, BATCH_SIZE)
+                .await
+                .map_err(|e| e.to_string())?;
+            let Some(last) = rows.last() else {
+                break;
+            };
+            after_id = last.id;
+
+            if writer.is_none() {
+                writer = Some(
+                    self.store
+                        .create(data_class, run.id, run.started_at)
+                        .await?,
+                );
+            }
+            pseudonymise(&mut rows, data_class.id_tag_fields(), &mut pseudonyms);
+            if let Some(writer) = writer.as_mut() {
+                writer.append(&rows).await?;
+            }
+            ids.extend(rows.iter().map(|r| r.id));
+
+            if (rows.len() as u64) < BATCH_SIZE {
+                break;
+            }
+        }
+
+        let file = match writer {
+            Some(writer) => Some(writer.finish().await?),
+            None => None,
+        };
+        for batch in ids.chunks(BATCH_SIZE as usize) {
+            retention
+                .delete_rows(data_class, batch)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        if !ids.is_empty() {
+            info!(data_class = %data_class, rows = ids.len(), "Archived expired rows");
+        }
+        Ok(ArchivedClass {
+            data_class: data_class.as_str().to_string(),
+            cutoff,
+            rows: ids.len() as u64,
+            file,
+        })
+    }
+}
+
+/// Replace the id tags in `fields` of each row, reusing the pseudonym
+/// already given to a tag.
+fn pseudonymise(
+    rows: &mut [ExpiredRow],
+    fields: &[&str],
+    pseudonyms: &mut HashMap<String, String>,
+) {
+    for row in rows {
+        for field in fields {
+            let Some(value) = row.data.get_mut(*field) else {
+                continue;
+            };
+            if let Some(id_tag) = value.as_str() {
+                let replacement = pseudonyms
+                    .entry(id_tag.to_string())
+                    .or_insert_with(pseudonym)
+                    .clone();
+                *value = serde_json::Value::String(replacement);
+            }
+        }
+    }
+}
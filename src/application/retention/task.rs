@@ -0,0 +1,51 @@
+//! Background task that periodically archives expired data.
+//!
+//! Runs in a tokio::spawn loop, starting an archival run every
+//! `check_interval_secs`. The first run starts right away.
+
+use std::sync::Arc;
+
+use tokio::time::Duration;
+use tracing::{info, warn};
+
+use super::service::ArchiveService;
+use crate::domain::ArchiveTrigger;
+use crate::shared::shutdown::ShutdownSignal;
+
+/// Start the archival background task.
+///
+/// Runs left unfinished by a previous process are marked failed first.
+pub fn start_archival_task(
+    service: Arc<ArchiveService>,
+    shutdown: ShutdownSignal,
+    check_interval_secs: u64,
+) {
+    tokio::spawn(async move {
+        info!(
+            check_interval = check_interval_secs,
+            "🗄️ Archival task started"
+        );
+
+        if let Err(e) = service.fail_interrupted().await {
+            warn!(error = %e, "Failed to close interrupted archival runs");
+        }
+
+        let mut interval = tokio::time::interval(Duration::from_secs(check_interval_secs));
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    if let Err(e) = service.run(ArchiveTrigger::Scheduled).await {
+                        warn!(error = %e, "Archival run not started");
+                    }
+                }
+                _ = shutdown.notified().wait() => {
+                    info!("🗄️ Archival task shutting down");
+                    break;
+                }
+            }
+        }
+
+        info!("🗄️ Archival task stopped");
+    });
+}
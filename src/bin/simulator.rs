@@ -0,0 +1,125 @@
+//! OCPP charge point simulator
+//!
+//! Connects one or many simulated stations to a running Central System,
+//! boots them, runs scripted charging sessions and keeps answering CSMS
+//! commands until Ctrl+C (or `--hold-secs` elapses).
+//!
+//! ```text
+//! simulator --url ws://127.0.0.1:9000/ocpp --id CP001 --protocol ocpp2.0.1
+//! simulator --id LOAD- --count 500 --ramp-ms 20 --sessions 3 --session-secs 30
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use clap::Parser;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+use texnouz_ocpp::domain::OcppVersion;
+use texnouz_ocpp::shared::shutdown::{listen_for_shutdown_signals, ShutdownSignal};
+use texnouz_ocpp::simulator::{run_fleet, FleetConfig, FleetStats, SessionScript, StationConfig};
+
+#[derive(Debug, Parser)]
+#[command(name = "simulator", about = "Simulated OCPP 1.6 / 2.0.1 charge points")]
+struct Args {
+    /// Base WebSocket URL; the charge point ID is appended
+    #[arg(long, default_value = "ws://127.0.0.1:9000/ocpp")]
+    url: String,
+
+    /// Charge point ID (prefix when --count > 1)
+    #[arg(long, default_value = "SIM-")]
+    id: String,
+
+    /// WebSocket subprotocol: ocpp1.6, ocpp2.0.1 or ocpp2.1
+    #[arg(long, default_value = "ocpp1.6", value_parser = parse_protocol)]
+    protocol: OcppVersion,
+
+    /// Number of stations to simulate
+    #[arg(long, default_value_t = 1)]
+    count: u32,
+
+    /// Delay between starting stations, in ms
+    #[arg(long, default_value_t = 50)]
+    ramp_ms: u64,
+
+    /// Connectors (EVSEs) per station
+    #[arg(long, default_value_t = 2)]
+    connectors: u32,
+
+    /// Basic Auth password (Security Profile 1)
+    #[arg(long)]
+    password: Option<String>,
+
+    /// Scripted sessions per station
+    #[arg(long, default_value_t = 1)]
+    sessions: u32,
+
+    /// Id tag presented for scripted sessions
+    #[arg(long, default_value = "SIMTAG01")]
+    id_tag: String,
+
+    /// Duration of each scripted session, in seconds
+    #[arg(long, default_value_t = 60)]
+    session_secs: u64,
+
+    /// Interval between meter values, in seconds
+    #[arg(long, default_value_t = 10)]
+    meter_interval_secs: u64,
+
+    /// Charging power, in kW
+    #[arg(long, default_value_t = 11.0)]
+    power_kw: f64,
+
+    /// Pause between sessions on the same station, in seconds
+    #[arg(long, default_value_t = 5)]
+    pause_secs: u64,
+
+    /// Disconnect this many seconds after the sessions (default: stay until Ctrl+C)
+    #[arg(long)]
+    hold_secs: Option<u64>,
+}
+
+fn parse_protocol(s: &str) -> Result<OcppVersion, String> {
+    OcppVersion::from_subprotocol(s).ok_or_else(|| format!("unsupported subprotocol '{}'", s))
+}
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let args = Args::parse();
+
+    let mut station = StationConfig::new(&args.url, &args.id, args.protocol);
+    station.connectors = args.connectors.max(1);
+    station.password = args.password;
+
+    let config = FleetConfig {
+        station,
+        stations: args.count.max(1),
+        ramp_up: Duration::from_millis(args.ramp_ms),
+        sessions_per_station: args.sessions,
+        script: SessionScript {
+            id_tag: args.id_tag,
+            connector_id: 1,
+            duration: Duration::from_secs(args.session_secs),
+            meter_interval: Duration::from_secs(args.meter_interval_secs.max(1)),
+            power_w: args.power_kw * 1000.0,
+        },
+        pause_between_sessions: Duration::from_secs(args.pause_secs),
+        hold: args.hold_secs.map(Duration::from_secs),
+    };
+
+    let shutdown = ShutdownSignal::new();
+    tokio::spawn(listen_for_shutdown_signals(shutdown.clone()));
+
+    let stats = Arc::new(FleetStats::default());
+    let report = run_fleet(config, stats, shutdown).await;
+    println!("{}", report);
+}
@@ -0,0 +1,277 @@
+//! Configuration hot reload
+//!
+//! Settings read per request, connection or check (log level, CORS, rate
+//! limits, WebSocket auth, heartbeat intervals and thresholds) are applied
+//! from the configuration file while the server runs. The rest — ports,
+//! database, protocol options — are reported as still needing a restart.
+//!
+//! A reload is triggered by the file changing (see
+//! [`ConfigReloader::start_watcher`]) or through the admin API. In cluster
+//! mode every node reloads its own file.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use tokio::sync::watch;
+use tracing::{info, warn};
+
+use super::AppConfig;
+use crate::shared::shutdown::ShutdownSignal;
+
+/// The running configuration, updated whenever a reload applies something.
+pub type LiveConfig = watch::Receiver<Arc<AppConfig>>;
+
+/// Settings applied without a restart: whole sections or dotted paths.
+pub const LIVE_SETTINGS: &[&str] = &[
+    "logging.level",
+    "server.heartbeat_interval",
+    "cors",
+    "rate_limit",
+    "ws_auth",
+    "heartbeat",
+];
+
+/// Whether a setting path (as from [`AppConfig::changed_settings`]) takes
+/// effect without a restart.
+pub fn is_live(setting: &str) -> bool {
+    LIVE_SETTINGS.iter().any(|live| {
+        setting == *live
+            || setting
+                .strip_prefix(live)
+                .is_some_and(|rest| rest.starts_with('.'))
+    })
+}
+
+/// Outcome of a reload.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReloadReport {
+    /// Settings now in effect
+    pub applied: Vec<String>,
+    /// Settings that differ from the running ones but need a restart
+    pub restart_required: Vec<String>,
+}
+
+/// Re-reads the configuration file and publishes the live settings.
+pub struct ConfigReloader {
+    path: PathBuf,
+    live: watch::Sender<Arc<AppConfig>>,
+}
+
+impl ConfigReloader {
+    /// `initial` is the configuration the server started with.
+    pub fn new(path: impl Into<PathBuf>, initial: AppConfig) -> Self {
+        let (live, _) = watch::channel(Arc::new(initial));
+        Self {
+            path: path.into(),
+            live,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The running configuration
+    pub fn current(&self) -> Arc<AppConfig> {
+        self.live.borrow().clone()
+    }
+
+    pub fn subscribe(&self) -> LiveConfig {
+        self.live.subscribe()
+    }
+
+    /// Call `apply` with the new configuration after every reload that
+    /// changed a live setting.
+    pub fn on_change<F>(&self, apply: F)
+    where
+        F: Fn(&AppConfig) + Send + 'static,
+    {
+        let mut live = self.subscribe();
+        tokio::spawn(async move {
+            while live.changed().await.is_ok() {
+                let config = live.borrow_and_update().clone();
+                apply(&config);
+            }
+        });
+    }
+
+    /// Read the file and apply its live settings.
+    ///
+    /// A file that is missing or fails validation changes nothing.
+    pub fn reload(&self) -> Result<ReloadReport, String> {
+        if !self.path.exists() {
+            return Err(format!("{} does not exist", self.path.display()));
+        }
+        let loaded = AppConfig::load(&self.path)?;
+
+        let mut report = ReloadReport::default();
+        self.live.send_if_modified(|current| {
+            for setting in current.changed_settings(&loaded) {
+                if is_live(&setting) {
+                    report.applied.push(setting);
+                } else {
+                    report.restart_required.push(setting);
+                }
+            }
+            if report.applied.is_empty() {
+                return false;
+            }
+            *current = Arc::new(with_live_settings(current, &loaded));
+            true
+        });
+        Ok(report)
+    }
+
+    /// Reload whenever the file's modification time changes, checking every
+    /// `poll_interval`, until `shutdown`.
+    pub fn start_watcher(self: Arc<Self>, shutdown: ShutdownSignal, poll_interval: Duration) {
+        tokio::spawn(async move {
+            info!(
+                path = %self.path.display(),
+                "🔄 Watching configuration file for changes"
+            );
+            let mut last_modified = modified(&self.path);
+            let mut interval = tokio::time::interval(poll_interval);
+            interval.tick().await;
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        let modified = modified(&self.path);
+                        if modified == last_modified {
+                            continue;
+                        }
+                        last_modified = modified;
+                        match self.reload() {
+                            Ok(report) => log_report(&report),
+                            Err(e) => warn!("Configuration not reloaded: {}", e),
+                        }
+                    }
+                    _ = shutdown.notified().wait() => break,
+                }
+            }
+        });
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn log_report(report: &ReloadReport) {
+    if !report.applied.is_empty() {
+        info!("🔄 Configuration reloaded: {}", report.applied.join(", "));
+    }
+    if !report.restart_required.is_empty() {
+        warn!(
+            "Changed settings take effect after a restart: {}",
+            report.restart_required.join(", ")
+        );
+    }
+}
+
+/// `current` with the live settings taken from `loaded`.
+fn with_live_settings(current: &AppConfig, loaded: &AppConfig) -> AppConfig {
+    let mut next = current.clone();
+    next.logging.level = loaded.logging.level.clone();
+    next.server.heartbeat_interval = loaded.server.heartbeat_interval;
+    next.cors = loaded.cors.clone();
+    next.rate_limit = loaded.rate_limit.clone();
+    next.ws_auth = loaded.ws_auth.clone();
+    next.heartbeat = loaded.heartbeat.clone();
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_file(name: &str, cfg: &AppConfig) -> PathBuf {
+        let path = std::env::temp_dir()
+            .join(format!(
+                "texnouz-reload-{}-{}",
+                name,
+                uuid::Uuid::new_v4().simple()
+            ))
+            .join("config.toml");
+        cfg.save(&path).unwrap();
+        path
+    }
+
+    #[test]
+    fn live_settings_match_by_section_or_path() {
+        assert!(is_live("cors.allowed_origins"));
+        assert!(is_live("logging.level"));
+        assert!(is_live("server.heartbeat_interval"));
+        assert!(is_live("heartbeat.offline_threshold_secs"));
+        assert!(!is_live("logging.format"));
+        assert!(!is_live("rate_limits.api"));
+        assert!(!is_live("server.api_port"));
+    }
+
+    #[test]
+    fn every_live_setting_is_copied() {
+        let current = AppConfig::default();
+        let mut loaded = current.clone();
+        loaded.logging.level = "debug".into();
+        loaded.cors.allowed_origins = vec!["https://example.com".into()];
+        loaded.rate_limit.api_requests_per_minute = 5;
+        loaded.ws_auth.mode = "basic".into();
+        loaded.heartbeat.offline_threshold_secs = 240;
+        loaded.server.heartbeat_interval = 900;
+
+        let next = with_live_settings(&current, &loaded);
+        let missed: Vec<String> = next
+            .changed_settings(&loaded)
+            .into_iter()
+            .filter(|s| is_live(s))
+            .collect();
+        assert!(missed.is_empty(), "not copied: {:?}", missed);
+    }
+
+    #[tokio::test]
+    async fn reload_applies_live_settings_and_reports_the_rest() {
+        let initial = AppConfig::default();
+        let mut edited = initial.clone();
+        edited.rate_limit.api_requests_per_minute = 5;
+        edited.server.api_port = 9090;
+        let path = config_file("apply", &edited);
+
+        let reloader = ConfigReloader::new(&path, initial);
+        let mut live = reloader.subscribe();
+        let report = reloader.reload().unwrap();
+        assert_eq!(report.applied, vec!["rate_limit.api_requests_per_minute"]);
+        assert_eq!(report.restart_required, vec!["server.api_port"]);
+
+        assert!(live.has_changed().unwrap());
+        let current = live.borrow_and_update().clone();
+        assert_eq!(current.rate_limit.api_requests_per_minute, 5);
+        assert_eq!(
+            current.server.api_port,
+            AppConfig::default().server.api_port
+        );
+
+        // Nothing new to apply: subscribers are not woken again
+        let report = reloader.reload().unwrap();
+        assert!(report.applied.is_empty());
+        assert_eq!(report.restart_required, vec!["server.api_port"]);
+        assert!(!live.has_changed().unwrap());
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn invalid_files_change_nothing() {
+        let mut edited = AppConfig::default();
+        edited.logging.level = "loud".into();
+        let path = config_file("invalid", &edited);
+
+        let reloader = ConfigReloader::new(&path, AppConfig::default());
+        assert!(reloader.reload().unwrap_err().contains("Invalid log level"));
+        assert_eq!(reloader.current().logging.level, "info");
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert!(reloader.reload().unwrap_err().contains("does not exist"));
+    }
+}
@@ -0,0 +1,11 @@
+//! Authorization event aggregate
+//!
+//! Contains the AuthEvent entity (a persisted Authorize outcome) and its
+//! repository interface. Kept so a driver's authorization history can be
+//! exported and pseudonymised on request.
+
+pub mod model;
+pub mod repository;
+
+pub use model::AuthEvent;
+pub use repository::AuthEventRepository;
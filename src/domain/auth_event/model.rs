@@ -0,0 +1,34 @@
+//! AuthEvent domain entity
+
+use chrono::{DateTime, Utc};
+
+/// Outcome of a single Authorize request from a charge point.
+#[derive(Debug, Clone)]
+pub struct AuthEvent {
+    /// Database id (0 until persisted)
+    pub id: i32,
+    /// Charge point that asked for authorization
+    pub charge_point_id: String,
+    /// Presented id tag / id token
+    pub id_tag: String,
+    /// Authorization status returned (Accepted, Blocked, Invalid, ...)
+    pub status: String,
+    /// When the request was handled
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl AuthEvent {
+    pub fn new(
+        charge_point_id: impl Into<String>,
+        id_tag: impl Into<String>,
+        status: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: 0,
+            charge_point_id: charge_point_id.into(),
+            id_tag: id_tag.into(),
+            status: status.into(),
+            occurred_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! AuthEvent repository interface
+
+use async_trait::async_trait;
+
+use super::model::AuthEvent;
+use crate::domain::DomainResult;
+
+#[async_trait]
+pub trait AuthEventRepository: Send + Sync {
+    /// Persist an authorization outcome.
+    async fn record(&self, event: AuthEvent) -> DomainResult<()>;
+
+    /// All events for an id tag, newest first.
+    async fn find_by_id_tag(&self, id_tag: &str) -> DomainResult<Vec<AuthEvent>>;
+
+    /// Replace `id_tag` with `replacement` on every event.
+    ///
+    /// Returns the number of rows changed.
+    async fn replace_id_tag(&self, id_tag: &str, replacement: &str) -> DomainResult<u64>;
+}
@@ -0,0 +1,14 @@
+//! Availability aggregate
+//!
+//! Contains the connection history (one ConnectionSession per WebSocket
+//! connection), connector status intervals, and the availability
+//! calculation used for uptime / SLA reporting.
+
+pub mod model;
+pub mod repository;
+
+pub use model::{
+    intersect_periods, merge_periods, time_in_state, AvailabilityStats, ConnectionSession,
+    ConnectorStatusInterval, Period,
+};
+pub use repository::AvailabilityRepository;
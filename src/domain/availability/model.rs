@@ -0,0 +1,340 @@
+//! Availability domain entities and uptime calculation
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+/// A half-open time range `[start, end)`.
+pub type Period = (DateTime<Utc>, DateTime<Utc>);
+
+/// One WebSocket connection of a charge point.
+#[derive(Debug, Clone)]
+pub struct ConnectionSession {
+    /// Database id (0 until persisted)
+    pub id: i32,
+    pub charge_point_id: String,
+    /// Negotiated OCPP version (e.g. "1.6", "2.0.1")
+    pub ocpp_version: String,
+    /// Remote socket address of the station
+    pub remote_addr: Option<String>,
+    pub connected_at: DateTime<Utc>,
+    /// None while the connection is open
+    pub disconnected_at: Option<DateTime<Utc>>,
+    /// Why the connection ended (ping timeout, closed by station, ...)
+    pub disconnect_reason: Option<String>,
+}
+
+impl ConnectionSession {
+    pub fn open(
+        charge_point_id: impl Into<String>,
+        ocpp_version: impl Into<String>,
+        remote_addr: Option<String>,
+        connected_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: 0,
+            charge_point_id: charge_point_id.into(),
+            ocpp_version: ocpp_version.into(),
+            remote_addr,
+            connected_at,
+            disconnected_at: None,
+            disconnect_reason: None,
+        }
+    }
+
+    /// Time the station was connected; an open session lasts until `now`.
+    pub fn period(&self, now: DateTime<Utc>) -> Period {
+        (self.connected_at, self.disconnected_at.unwrap_or(now))
+    }
+}
+
+/// A stretch of time during which a connector reported one status.
+#[derive(Debug, Clone)]
+pub struct ConnectorStatusInterval {
+    /// Database id (0 until persisted)
+    pub id: i32,
+    pub charge_point_id: String,
+    pub connector_id: u32,
+    /// Reported status as sent by the station (Available, Charging, Faulted, ...)
+    pub status: String,
+    pub error_code: Option<String>,
+    /// Free-text info reported with the status
+    pub info: Option<String>,
+    pub vendor_id: Option<String>,
+    pub vendor_error_code: Option<String>,
+    /// Firmware the station ran at the time
+    pub firmware_version: Option<String>,
+    pub started_at: DateTime<Utc>,
+    /// None while this is the connector's current status
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl ConnectorStatusInterval {
+    pub fn new(
+        charge_point_id: impl Into<String>,
+        connector_id: u32,
+        status: impl Into<String>,
+        error_code: Option<String>,
+        started_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: 0,
+            charge_point_id: charge_point_id.into(),
+            connector_id,
+            status: status.into(),
+            error_code,
+            info: None,
+            vendor_id: None,
+            vendor_error_code: None,
+            firmware_version: None,
+            started_at,
+            ended_at: None,
+        }
+    }
+
+    pub fn is_fault(&self) -> bool {
+        self.status == "Faulted"
+    }
+
+    /// Whether `other` reports the same condition (status and error codes).
+    pub fn same_condition(&self, other: &ConnectorStatusInterval) -> bool {
+        self.status == other.status
+            && self.error_code == other.error_code
+            && self.vendor_error_code == other.vendor_error_code
+    }
+
+    /// Whether the connector could serve a driver in this status.
+    ///
+    /// Occupied, reserved and charging connectors count as available:
+    /// only faults and unavailability are downtime.
+    pub fn is_operative(&self) -> bool {
+        !matches!(self.status.as_str(), "Faulted" | "Unavailable")
+    }
+
+    /// Time spent in this status; the current status lasts until `now`.
+    pub fn period(&self, now: DateTime<Utc>) -> Period {
+        (self.started_at, self.ended_at.unwrap_or(now))
+    }
+}
+
+/// Availability of a station or connector over a reporting window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvailabilityStats {
+    /// Length of the reporting window
+    pub period_seconds: i64,
+    /// Time available within the window
+    pub available_seconds: i64,
+    /// `available / period` in percent, two decimals
+    pub uptime_percent: f64,
+    /// Transitions from available to unavailable within the window
+    pub failures: u32,
+    /// Mean time between failures (available time / failures)
+    pub mtbf_seconds: Option<i64>,
+    /// Longest unavailable stretch, including outages at the window edges
+    pub longest_outage_seconds: i64,
+}
+
+impl AvailabilityStats {
+    /// Compute availability over `[from, to)` from the periods the subject
+    /// was available. Periods may overlap and extend past the window.
+    pub fn compute(available: &[Period], from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        let period_seconds = (to - from).num_seconds().max(0);
+        let up = merge_periods(available, from, to);
+
+        let available_seconds: i64 = up.iter().map(|(s, e)| (*e - *s).num_seconds()).sum();
+
+        let mut longest_outage_seconds = 0;
+        let mut cursor = from;
+        for (start, end) in &up {
+            longest_outage_seconds = longest_outage_seconds.max((*start - cursor).num_seconds());
+            cursor = *end;
+        }
+        if cursor < to {
+            longest_outage_seconds = longest_outage_seconds.max((to - cursor).num_seconds());
+        }
+
+        let failures = up.iter().filter(|(_, end)| *end < to).count() as u32;
+        let uptime_percent = if period_seconds > 0 {
+            (available_seconds as f64 * 10_000.0 / period_seconds as f64).round() / 100.0
+        } else {
+            0.0
+        };
+
+        Self {
+            period_seconds,
+            available_seconds,
+            uptime_percent,
+            failures,
+            mtbf_seconds: (failures > 0).then(|| available_seconds / failures as i64),
+            longest_outage_seconds,
+        }
+    }
+}
+
+/// Clip periods to `[from, to)` and merge overlapping or touching ones.
+///
+/// The result is sorted and non-overlapping.
+pub fn merge_periods(periods: &[Period], from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<Period> {
+    let mut clipped: Vec<Period> = periods
+        .iter()
+        .map(|(s, e)| ((*s).max(from), (*e).min(to)))
+        .filter(|(s, e)| s < e)
+        .collect();
+    clipped.sort_by_key(|(s, _)| *s);
+
+    let mut merged: Vec<Period> = Vec::with_capacity(clipped.len());
+    for (start, end) in clipped {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Overlap of two sorted, non-overlapping period lists.
+pub fn intersect_periods(a: &[Period], b: &[Period]) -> Vec<Period> {
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let start = a[i].0.max(b[j].0);
+        let end = a[i].1.min(b[j].1);
+        if start < end {
+            result.push((start, end));
+        }
+        if a[i].1 < b[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
+}
+
+/// Seconds spent in each reported status within `[from, to)`.
+///
+/// Time while the station was not connected counts as "Offline", whatever
+/// the connector last reported. `online` must be sorted and non-overlapping
+/// (see [`merge_periods`]).
+pub fn time_in_state(
+    intervals: &[ConnectorStatusInterval],
+    online: &[Period],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> BTreeMap<String, i64> {
+    let mut seconds: BTreeMap<String, i64> = BTreeMap::new();
+    for interval in intervals {
+        let held = merge_periods(&[interval.period(to)], from, to);
+        let total: i64 = held.iter().map(|(s, e)| (*e - *s).num_seconds()).sum();
+        let connected: i64 = intersect_periods(&held, online)
+            .iter()
+            .map(|(s, e)| (*e - *s).num_seconds())
+            .sum();
+
+        if connected > 0 {
+            *seconds.entry(interval.status.clone()).or_default() += connected;
+        }
+        if total > connected {
+            *seconds.entry("Offline".to_string()).or_default() += total - connected;
+        }
+    }
+    seconds
+}
+
+// ── Tests ──────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn at(hour: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::hours(hour)
+    }
+
+    #[test]
+    fn always_up_is_full_uptime() {
+        let stats = AvailabilityStats::compute(&[(at(-5), at(30))], at(0), at(24));
+        assert_eq!(stats.uptime_percent, 100.0);
+        assert_eq!(stats.failures, 0);
+        assert_eq!(stats.mtbf_seconds, None);
+        assert_eq!(stats.longest_outage_seconds, 0);
+    }
+
+    #[test]
+    fn outages_count_failures_and_longest_gap() {
+        // up 0-6, down 6-8, up 8-20, down 20-24
+        let up = [(at(0), at(6)), (at(8), at(20))];
+        let stats = AvailabilityStats::compute(&up, at(0), at(24));
+
+        assert_eq!(stats.period_seconds, 24 * 3600);
+        assert_eq!(stats.available_seconds, 18 * 3600);
+        assert_eq!(stats.uptime_percent, 75.0);
+        assert_eq!(stats.failures, 2);
+        assert_eq!(stats.mtbf_seconds, Some(9 * 3600));
+        assert_eq!(stats.longest_outage_seconds, 4 * 3600);
+    }
+
+    #[test]
+    fn outage_at_window_start_is_not_a_failure() {
+        let stats = AvailabilityStats::compute(&[(at(3), at(24))], at(0), at(24));
+        assert_eq!(stats.failures, 0);
+        assert_eq!(stats.longest_outage_seconds, 3 * 3600);
+    }
+
+    #[test]
+    fn no_history_is_zero_uptime() {
+        let stats = AvailabilityStats::compute(&[], at(0), at(10));
+        assert_eq!(stats.uptime_percent, 0.0);
+        assert_eq!(stats.longest_outage_seconds, 10 * 3600);
+    }
+
+    #[test]
+    fn merge_joins_overlapping_and_touching_periods() {
+        let merged = merge_periods(
+            &[
+                (at(5), at(7)),
+                (at(0), at(2)),
+                (at(1), at(3)),
+                (at(3), at(4)),
+            ],
+            at(0),
+            at(6),
+        );
+        assert_eq!(merged, vec![(at(0), at(4)), (at(5), at(6))]);
+    }
+
+    #[test]
+    fn intersect_keeps_common_time() {
+        let online = [(at(0), at(10)), (at(12), at(20))];
+        let operative = [(at(2), at(14)), (at(16), at(24))];
+        assert_eq!(
+            intersect_periods(&online, &operative),
+            vec![(at(2), at(10)), (at(12), at(14)), (at(16), at(20))]
+        );
+    }
+
+    #[test]
+    fn time_in_state_splits_offline_time() {
+        let mut available = ConnectorStatusInterval::new("CP001", 1, "Available", None, at(0));
+        available.ended_at = Some(at(10));
+        let faulted = ConnectorStatusInterval::new("CP001", 1, "Faulted", None, at(10));
+        // station offline 4-6 and after 20
+        let online = [(at(0), at(4)), (at(6), at(20))];
+
+        let seconds = time_in_state(&[available, faulted], &online, at(0), at(24));
+        assert_eq!(seconds["Available"], 8 * 3600);
+        assert_eq!(seconds["Faulted"], 10 * 3600);
+        assert_eq!(seconds["Offline"], 6 * 3600);
+    }
+
+    #[test]
+    fn faulted_and_unavailable_are_not_operative() {
+        let status = |s: &str| ConnectorStatusInterval::new("CP001", 1, s, None, at(0));
+        assert!(status("Available").is_operative());
+        assert!(status("Charging").is_operative());
+        assert!(status("Occupied").is_operative());
+        assert!(!status("Faulted").is_operative());
+        assert!(!status("Unavailable").is_operative());
+    }
+}
@@ -0,0 +1,58 @@
+//! Availability repository interface
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use super::model::{ConnectionSession, ConnectorStatusInterval};
+use crate::domain::DomainResult;
+
+#[async_trait]
+pub trait AvailabilityRepository: Send + Sync {
+    /// Persist a new connection session; returns it with its id set.
+    async fn open_session(&self, session: ConnectionSession) -> DomainResult<ConnectionSession>;
+
+    /// Close a connection session.
+    async fn close_session(
+        &self,
+        id: i32,
+        disconnected_at: DateTime<Utc>,
+        reason: Option<String>,
+    ) -> DomainResult<()>;
+
+    /// Sessions not closed yet, for one charge point or all of them.
+    async fn find_open_sessions(
+        &self,
+        charge_point_id: Option<&str>,
+    ) -> DomainResult<Vec<ConnectionSession>>;
+
+    /// Sessions overlapping `[from, to)`, oldest first.
+    async fn find_sessions(
+        &self,
+        charge_point_id: Option<&str>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<ConnectionSession>>;
+
+    /// Record a connector status report.
+    ///
+    /// Ends the connector's open interval and starts a new one, unless the
+    /// connector is already in the reported status.
+    async fn record_connector_status(&self, interval: ConnectorStatusInterval) -> DomainResult<()>;
+
+    /// Status intervals overlapping `[from, to)`, oldest first.
+    async fn find_status_intervals(
+        &self,
+        charge_point_id: Option<&str>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<ConnectorStatusInterval>>;
+
+    /// Faulted intervals that started within `[from, to)`, oldest first.
+    async fn find_faults(
+        &self,
+        charge_point_id: Option<&str>,
+        connector_id: Option<u32>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<ConnectorStatusInterval>>;
+}
@@ -128,6 +128,8 @@ pub struct ChargePoint {
     pub registered_at: DateTime<Utc>,
     /// Last heartbeat received
     pub last_heartbeat: Option<DateTime<Utc>>,
+    /// Heartbeat interval the station last accepted (seconds)
+    pub heartbeat_interval: Option<i32>,
 }
 
 impl ChargePoint {
@@ -148,6 +150,7 @@ impl ChargePoint {
             connectors: Vec::new(),
             registered_at: Utc::now(),
             last_heartbeat: None,
+            heartbeat_interval: None,
         }
     }
 
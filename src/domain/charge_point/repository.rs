@@ -11,8 +11,13 @@ pub trait ChargePointRepository: Send + Sync {
     async fn find_by_id(&self, id: &str) -> DomainResult<Option<ChargePoint>>;
     async fn find_all(&self) -> DomainResult<Vec<ChargePoint>>;
     async fn update(&self, charge_point: ChargePoint) -> DomainResult<()>;
+    /// Insert new charge points and update stored ones, in one database
+    /// transaction: either all are stored or none is.
+    async fn save_all(&self, charge_points: Vec<ChargePoint>) -> DomainResult<()>;
     async fn update_status(&self, id: &str, status: ChargePointStatus) -> DomainResult<()>;
     async fn delete(&self, id: &str) -> DomainResult<()>;
     /// Set or clear the WS authentication password hash for a charge point.
     async fn set_password_hash(&self, id: &str, hash: Option<String>) -> DomainResult<()>;
+    /// Record the heartbeat interval the charge point accepted.
+    async fn set_heartbeat_interval(&self, id: &str, interval: Option<i32>) -> DomainResult<()>;
 }
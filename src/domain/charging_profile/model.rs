@@ -34,12 +34,23 @@ pub struct ChargingProfile {
     pub schedule_json: String,
     /// Whether this profile is currently active on the charge point.
     pub is_active: bool,
+    /// Source of an external limit reported by the station (EMS, SO, Other);
+    /// `None` for profiles set by this CSMS.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_source: Option<String>,
     /// When the profile was first sent.
     pub created_at: DateTime<Utc>,
     /// When the profile was last updated (e.g. deactivated).
     pub updated_at: DateTime<Utc>,
 }
 
+impl ChargingProfile {
+    /// Whether this is a limit imposed on the station from outside the CSMS.
+    pub fn is_external_limit(&self) -> bool {
+        self.limit_source.is_some()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,6 +70,7 @@ mod tests {
             valid_to: None,
             schedule_json: r#"[{"id":1,"chargingRateUnit":"W"}]"#.to_string(),
             is_active: true,
+            limit_source: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
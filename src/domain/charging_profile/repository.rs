@@ -23,6 +23,9 @@ pub trait ChargingProfileRepository: Send + Sync {
     ) -> DomainResult<Vec<ChargingProfile>>;
 
     /// Deactivate a specific profile by its OCPP profile_id on a charge point.
+    ///
+    /// This and the other CSMS-side deactivations leave external limits
+    /// alone; only [`deactivate_by_source`](Self::deactivate_by_source) lifts them.
     async fn deactivate_by_profile_id(
         &self,
         charge_point_id: &str,
@@ -41,6 +44,15 @@ pub trait ChargingProfileRepository: Send + Sync {
         stack_level: Option<i32>,
     ) -> DomainResult<u64>;
 
+    /// Deactivate external limits from `source` (EMS, SO, Other), on one
+    /// EVSE or, with `None`, on all of them.
+    async fn deactivate_by_source(
+        &self,
+        charge_point_id: &str,
+        source: &str,
+        evse_id: Option<i32>,
+    ) -> DomainResult<u64>;
+
     /// Deactivate ALL active profiles for a charge point.
     async fn deactivate_all(&self, charge_point_id: &str) -> DomainResult<u64>;
 }
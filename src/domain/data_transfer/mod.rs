@@ -0,0 +1,10 @@
+//! DataTransfer aggregate
+//!
+//! Contains the DataTransferRecord entity (one vendor-specific DataTransfer
+//! exchange, in either direction) and its repository interface.
+
+pub mod model;
+pub mod repository;
+
+pub use model::{DataTransferDirection, DataTransferRecord};
+pub use repository::DataTransferRepository;
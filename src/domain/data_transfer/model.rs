@@ -0,0 +1,52 @@
+//! DataTransferRecord domain entity
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Who initiated the exchange
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum DataTransferDirection {
+    /// Sent by the charge point, answered by the Central System
+    Inbound,
+    /// Sent by the Central System (REST command), answered by the charge point
+    Outbound,
+}
+
+impl DataTransferDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Inbound => "Inbound",
+            Self::Outbound => "Outbound",
+        }
+    }
+}
+
+impl From<&str> for DataTransferDirection {
+    fn from(s: &str) -> Self {
+        match s {
+            "Outbound" => Self::Outbound,
+            _ => Self::Inbound,
+        }
+    }
+}
+
+/// A DataTransfer request and the answer it got.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DataTransferRecord {
+    /// Database id (0 until persisted)
+    pub id: i32,
+    pub charge_point_id: String,
+    pub direction: DataTransferDirection,
+    /// `vendorId` of the request
+    pub vendor_id: String,
+    /// `messageId` of the request
+    pub message_id: Option<String>,
+    /// `data` of the request
+    pub request_data: Option<String>,
+    /// Status answered (Accepted, Rejected, UnknownVendorId, UnknownMessageId)
+    pub status: String,
+    /// `data` of the response
+    pub response_data: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,21 @@
+//! DataTransfer repository interface
+
+use async_trait::async_trait;
+
+use super::model::DataTransferRecord;
+use crate::domain::DomainResult;
+
+#[async_trait]
+pub trait DataTransferRepository: Send + Sync {
+    /// Persist an exchange; returns it with its database id.
+    async fn record(&self, record: DataTransferRecord) -> DomainResult<DataTransferRecord>;
+
+    /// Latest exchanges of a charge point, newest first, optionally
+    /// restricted to one vendor.
+    async fn find_for_charge_point(
+        &self,
+        charge_point_id: &str,
+        vendor_id: Option<&str>,
+        limit: u64,
+    ) -> DomainResult<Vec<DataTransferRecord>>;
+}
@@ -0,0 +1,10 @@
+//! Display message aggregate
+//!
+//! Contains the DisplayMessage entity (OCPP 2.0.1 station screen messages)
+//! and its repository interface.
+
+pub mod model;
+pub mod repository;
+
+pub use model::{DisplayMessage, DisplayMessageStatus};
+pub use repository::DisplayMessageRepository;
@@ -0,0 +1,142 @@
+//! DisplayMessage domain entity
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Lifecycle of a message in the CSMS catalogue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum DisplayMessageStatus {
+    /// Accepted by the station (SetDisplayMessage) or reported by it
+    Accepted,
+    /// Rejected by the station; see `status_reason`
+    Rejected,
+    /// Removed via ClearDisplayMessage
+    Cleared,
+    /// Expected on the station but absent from its last reported list
+    Missing,
+}
+
+impl DisplayMessageStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Accepted => "Accepted",
+            Self::Rejected => "Rejected",
+            Self::Cleared => "Cleared",
+            Self::Missing => "Missing",
+        }
+    }
+}
+
+impl From<&str> for DisplayMessageStatus {
+    fn from(s: &str) -> Self {
+        match s {
+            "Rejected" => Self::Rejected,
+            "Cleared" => Self::Cleared,
+            "Missing" => Self::Missing,
+            _ => Self::Accepted,
+        }
+    }
+}
+
+impl std::fmt::Display for DisplayMessageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A message shown on a charging station's display (OCPP 2.0.1 MessageInfo).
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DisplayMessage {
+    /// Internal auto-increment ID (DB row).
+    pub id: i32,
+    /// Charge point the message belongs to.
+    pub charge_point_id: String,
+    /// Message ID as known by the station (MessageInfo.id).
+    pub message_id: i32,
+    /// AlwaysFront, InFront or NormalCycle.
+    pub priority: String,
+    /// Station state in which the message is shown (Charging, Faulted, Idle, Unavailable).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Start of the display window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date_time: Option<DateTime<Utc>>,
+    /// End of the display window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date_time: Option<DateTime<Utc>>,
+    /// Show only during this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<String>,
+    /// ASCII, HTML, URI or UTF8.
+    pub format: String,
+    /// RFC 5646 language code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Message content.
+    pub content: String,
+    pub status: DisplayMessageStatus,
+    /// Station response when the message was rejected (e.g. NotSupportedPriority).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_reason: Option<String>,
+    /// Last time the station included the message in NotifyDisplayMessages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_reported_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl DisplayMessage {
+    /// New UTF-8 message with normal priority, not yet sent.
+    pub fn new(charge_point_id: impl Into<String>, message_id: i32, content: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: 0,
+            charge_point_id: charge_point_id.into(),
+            message_id,
+            priority: "NormalCycle".to_string(),
+            state: None,
+            start_date_time: None,
+            end_date_time: None,
+            transaction_id: None,
+            format: "UTF8".to_string(),
+            language: None,
+            content: content.into(),
+            status: DisplayMessageStatus::Accepted,
+            status_reason: None,
+            last_reported_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Whether the message is expected to be installed on the station.
+    pub fn is_installed(&self) -> bool {
+        self.status == DisplayMessageStatus::Accepted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_round_trip() {
+        for status in [
+            DisplayMessageStatus::Accepted,
+            DisplayMessageStatus::Rejected,
+            DisplayMessageStatus::Cleared,
+            DisplayMessageStatus::Missing,
+        ] {
+            assert_eq!(DisplayMessageStatus::from(status.as_str()), status);
+        }
+    }
+
+    #[test]
+    fn new_message_defaults() {
+        let msg = DisplayMessage::new("CP001", 7, "Welcome");
+        assert_eq!(msg.priority, "NormalCycle");
+        assert_eq!(msg.format, "UTF8");
+        assert!(msg.is_installed());
+    }
+}
@@ -0,0 +1,41 @@
+//! DisplayMessage repository interface
+
+use async_trait::async_trait;
+
+use super::model::{DisplayMessage, DisplayMessageStatus};
+use crate::domain::DomainResult;
+
+#[async_trait]
+pub trait DisplayMessageRepository: Send + Sync {
+    /// Insert or replace a message, keyed by `(charge_point_id, message_id)`.
+    async fn upsert(&self, message: DisplayMessage) -> DomainResult<DisplayMessage>;
+
+    /// Find a message by its OCPP message id on a charge point.
+    async fn find(
+        &self,
+        charge_point_id: &str,
+        message_id: i32,
+    ) -> DomainResult<Option<DisplayMessage>>;
+
+    /// All catalogue entries for a charge point, ordered by message id.
+    async fn find_for_charge_point(&self, charge_point_id: &str)
+        -> DomainResult<Vec<DisplayMessage>>;
+
+    /// Change the status of a single message.
+    async fn update_status(
+        &self,
+        charge_point_id: &str,
+        message_id: i32,
+        status: DisplayMessageStatus,
+    ) -> DomainResult<u64>;
+
+    /// Mark every message believed to be on the station (`Accepted`)
+    /// as `Missing`, except those in `reported_ids`.
+    ///
+    /// Returns the number of messages marked missing.
+    async fn mark_missing_except(
+        &self,
+        charge_point_id: &str,
+        reported_ids: &[i32],
+    ) -> DomainResult<u64>;
+}
@@ -0,0 +1,11 @@
+//! EvChargingNeeds aggregate
+//!
+//! Contains the EvChargingNeeds entity (what an ISO 15118 EV asked for in
+//! NotifyEVChargingNeeds, and the schedules exchanged in answer) and its
+//! repository interface.
+
+pub mod model;
+pub mod repository;
+
+pub use model::EvChargingNeeds;
+pub use repository::EvChargingNeedsRepository;
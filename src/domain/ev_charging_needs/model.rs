@@ -0,0 +1,52 @@
+//! EvChargingNeeds domain entity
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Charging needs reported by an EV for one transaction.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EvChargingNeeds {
+    /// Database id (0 until persisted)
+    pub id: i32,
+    pub charge_point_id: String,
+    pub evse_id: i32,
+    /// Transaction active on the EVSE when the needs arrived
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<i32>,
+    /// `DC`, `AC_single_phase`, `AC_two_phase` or `AC_three_phase`
+    pub energy_transfer_mode: String,
+    /// When the EV driver wants to leave
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub departure_time: Option<DateTime<Utc>>,
+    /// Energy requested (Wh)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub energy_amount_wh: Option<i32>,
+    /// Maximum current the EV accepts (A)
+    pub ev_max_current: i32,
+    /// Maximum voltage the EV accepts (V)
+    pub ev_max_voltage: i32,
+    /// Maximum power the EV accepts (W, DC only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ev_max_power: Option<i32>,
+    /// State of charge when the needs were sent (%, DC only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_of_charge: Option<i32>,
+    /// Most schedule periods the EV can handle
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_schedule_tuples: Option<i32>,
+    /// Id of the TxProfile computed for these needs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_id: Option<i32>,
+    /// SetChargingProfile status answered by the station
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_status: Option<String>,
+    /// Schedule periods sent, as JSON
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_json: Option<String>,
+    /// Schedule the EV planned in response (NotifyEVChargingSchedule), as JSON
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ev_schedule_json: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
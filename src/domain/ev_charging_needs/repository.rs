@@ -0,0 +1,26 @@
+//! EvChargingNeeds repository interface
+
+use async_trait::async_trait;
+
+use super::model::EvChargingNeeds;
+use crate::domain::DomainResult;
+
+#[async_trait]
+pub trait EvChargingNeedsRepository: Send + Sync {
+    /// Persist new needs; returns them with their database id.
+    async fn save(&self, needs: EvChargingNeeds) -> DomainResult<EvChargingNeeds>;
+
+    /// Update a stored record (profile sent, EV schedule received).
+    async fn update(&self, needs: EvChargingNeeds) -> DomainResult<()>;
+
+    /// Most recent needs reported on an EVSE.
+    async fn find_latest_for_evse(
+        &self,
+        charge_point_id: &str,
+        evse_id: i32,
+    ) -> DomainResult<Option<EvChargingNeeds>>;
+
+    /// All needs reported during a transaction, oldest first.
+    async fn find_for_transaction(&self, transaction_id: i32)
+        -> DomainResult<Vec<EvChargingNeeds>>;
+}
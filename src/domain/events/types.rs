@@ -21,6 +21,10 @@ pub enum Event {
     AuthorizationResult(AuthorizationEvent),
     BootNotification(BootNotificationEvent),
     DeviceAlert(DeviceAlertEvent),
+    DataTransferReceived(DataTransferReceivedEvent),
+    ExternalChargingLimitSet(ExternalChargingLimitEvent),
+    ExternalChargingLimitCleared(ExternalChargingLimitEvent),
+    ReservationStatusChanged(ReservationStatusChangedEvent),
     Error(ErrorEvent),
 }
 
@@ -39,6 +43,10 @@ impl Event {
             Event::AuthorizationResult(_) => "authorization_result",
             Event::BootNotification(_) => "boot_notification",
             Event::DeviceAlert(_) => "device_alert",
+            Event::DataTransferReceived(_) => "data_transfer_received",
+            Event::ExternalChargingLimitSet(_) => "external_charging_limit_set",
+            Event::ExternalChargingLimitCleared(_) => "external_charging_limit_cleared",
+            Event::ReservationStatusChanged(_) => "reservation_status_changed",
             Event::Error(_) => "error",
         }
     }
@@ -57,6 +65,10 @@ impl Event {
             Event::AuthorizationResult(e) => Some(&e.charge_point_id),
             Event::BootNotification(e) => Some(&e.charge_point_id),
             Event::DeviceAlert(e) => Some(&e.charge_point_id),
+            Event::DataTransferReceived(e) => Some(&e.charge_point_id),
+            Event::ExternalChargingLimitSet(e) => Some(&e.charge_point_id),
+            Event::ExternalChargingLimitCleared(e) => Some(&e.charge_point_id),
+            Event::ReservationStatusChanged(e) => Some(&e.charge_point_id),
             Event::Error(e) => e.charge_point_id.as_deref(),
         }
     }
@@ -92,6 +104,8 @@ pub struct ConnectorStatusChangedEvent {
     pub status: String,
     pub error_code: Option<String>,
     pub info: Option<String>,
+    pub vendor_id: Option<String>,
+    pub vendor_error_code: Option<String>,
     pub timestamp: DateTime<Utc>,
 }
 
@@ -187,6 +201,57 @@ pub struct DeviceAlertEvent {
     pub timestamp: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataTransferReceivedEvent {
+    pub charge_point_id: String,
+    pub vendor_id: String,
+    pub message_id: Option<String>,
+    pub data: Option<String>,
+    pub status: String,
+    pub response_data: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// A limit imposed on a station by an EMS or system operator (v2.0.1).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalChargingLimitEvent {
+    pub charge_point_id: String,
+    /// `None` when the limit covers the whole station or, when cleared,
+    /// every EVSE
+    pub evse_id: Option<i32>,
+    /// EMS, SO or Other
+    pub source: String,
+    pub is_grid_critical: Option<bool>,
+    /// Reported schedules, as JSON (empty when cleared)
+    pub schedule_json: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReservationStatusChangedEvent {
+    pub charge_point_id: String,
+    pub reservation_id: i32,
+    /// 0 = any connector
+    pub connector_id: i32,
+    pub id_tag: String,
+    /// Scheduled, Accepted, Cancelled, Expired or Used
+    pub status: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl ReservationStatusChangedEvent {
+    pub fn from_reservation(reservation: &crate::domain::Reservation) -> Self {
+        Self {
+            charge_point_id: reservation.charge_point_id.clone(),
+            reservation_id: reservation.id,
+            connector_id: reservation.connector_id,
+            id_tag: reservation.id_tag.clone(),
+            status: reservation.status.as_str().to_string(),
+            timestamp: Utc::now(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorEvent {
     pub charge_point_id: Option<String>,
@@ -2,6 +2,7 @@
 
 use async_trait::async_trait;
 
+use super::model::IdTag;
 use crate::domain::DomainResult;
 
 #[async_trait]
@@ -10,6 +11,18 @@ pub trait IdTagRepository: Send + Sync {
     async fn get_auth_status(&self, id_tag: &str) -> DomainResult<Option<String>>;
     async fn add(&self, id_tag: String) -> DomainResult<()>;
     async fn remove(&self, id_tag: &str) -> DomainResult<()>;
+    async fn find(&self, id_tag: &str) -> DomainResult<Option<IdTag>>;
+    /// Insert the tag, or overwrite the stored tag with the same value.
+    async fn save(&self, tag: IdTag) -> DomainResult<()>;
+    /// Save every tag, in order, in one database transaction: either all
+    /// are stored or none is.
+    async fn save_all(&self, tags: Vec<IdTag>) -> DomainResult<()>;
+    /// Up to `limit` tags with a value above `after`, by ascending value.
+    async fn find_page(&self, after: &str, limit: u64) -> DomainResult<Vec<IdTag>>;
+    /// All tags assigned to a user.
+    async fn find_by_user(&self, user_id: &str) -> DomainResult<Vec<IdTag>>;
     /// Get the parent id_tag for a given id_tag (for group authorization).
     async fn get_parent_id_tag(&self, id_tag: &str) -> DomainResult<Option<String>>;
+    /// Detach every tag whose parent is `parent_id_tag`; returns how many.
+    async fn clear_parent(&self, parent_id_tag: &str) -> DomainResult<u64>;
 }
@@ -4,14 +4,22 @@
 //! the entity, its DTOs, and repository interface.
 
 // ── Aggregates ──────────────────────────────────────────────────
+pub mod auth_event;
+pub mod availability;
 pub mod charge_point;
 pub mod charging_profile;
+pub mod data_transfer;
+pub mod display_message;
+pub mod ev_charging_needs;
 pub mod id_tag;
+pub mod ocpi;
 pub mod ocpp;
 pub mod reservation;
+pub mod retention;
 pub mod tariff;
 pub mod transaction;
 pub mod user;
+pub mod variable_monitor;
 
 // ── Cross-cutting domain concerns ──────────────────────────────
 pub mod events;
@@ -22,8 +30,8 @@ pub mod repositories; // Storage trait (spans multiple aggregates)
 
 // User aggregate
 pub use user::{
-    CreateUserDto, GetUserDto, UpdateUserDto, User, UserChangePasswordDto,
-    UserRepositoryInterface, UserRole,
+    CreateUserDto, GetUserDto, UpdateUserDto, User, UserChangePasswordDto, UserRepositoryInterface,
+    UserRole,
 };
 
 // ChargePoint aggregate
@@ -43,12 +51,38 @@ pub use tariff::{
 // IdTag aggregate
 pub use id_tag::{IdTag, IdTagRepository, IdTagStatus};
 
+// AuthEvent aggregate
+pub use auth_event::{AuthEvent, AuthEventRepository};
+
+// Availability aggregate
+pub use availability::{
+    AvailabilityRepository, AvailabilityStats, ConnectionSession, ConnectorStatusInterval,
+};
+
 // Reservation aggregate
 pub use reservation::{Reservation, ReservationRepository, ReservationStatus};
 
+// Retention aggregate
+pub use retention::{ArchiveRun, ArchiveRunStatus, ArchiveTrigger, DataClass, RetentionRepository};
+
 // ChargingProfile aggregate
 pub use charging_profile::{ChargingProfile, ChargingProfileRepository};
 
+// DataTransfer aggregate
+pub use data_transfer::{DataTransferDirection, DataTransferRecord, DataTransferRepository};
+
+// DisplayMessage aggregate
+pub use display_message::{DisplayMessage, DisplayMessageRepository, DisplayMessageStatus};
+
+// EvChargingNeeds aggregate
+pub use ev_charging_needs::{EvChargingNeeds, EvChargingNeedsRepository};
+
+// VariableMonitor aggregate
+pub use variable_monitor::{VariableMonitor, VariableMonitorRepository};
+
+// OCPI aggregate
+pub use ocpi::{OcpiParty, OcpiPartyRepository, OcpiPartyStatus, OcpiToken, OcpiTokenRepository};
+
 // OCPP shared types
 pub use ocpp::{ApiKey, OcppVersion};
 
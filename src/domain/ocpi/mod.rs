@@ -0,0 +1,10 @@
+//! OCPI aggregate
+//!
+//! Roaming partners (eMSPs) connected via the OCPI credentials handshake
+//! and the tokens they push to this CPO.
+
+pub mod model;
+pub mod repository;
+
+pub use model::{OcpiParty, OcpiPartyStatus, OcpiToken};
+pub use repository::{OcpiPartyRepository, OcpiTokenRepository};
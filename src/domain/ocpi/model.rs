@@ -0,0 +1,203 @@
+//! OCPI domain entities
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Registration state of a roaming partner
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum OcpiPartyStatus {
+    /// Token A issued, credentials handshake not completed yet
+    Pending,
+    /// Handshake completed, token C in use
+    Registered,
+    /// Partner unregistered via `DELETE /credentials`
+    Unregistered,
+}
+
+impl OcpiPartyStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Registered => "Registered",
+            Self::Unregistered => "Unregistered",
+        }
+    }
+}
+
+impl From<&str> for OcpiPartyStatus {
+    fn from(s: &str) -> Self {
+        match s {
+            "Registered" => Self::Registered,
+            "Unregistered" => Self::Unregistered,
+            _ => Self::Pending,
+        }
+    }
+}
+
+impl std::fmt::Display for OcpiPartyStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A roaming partner (usually an eMSP) talking OCPI to this CPO.
+///
+/// Token naming follows the OCPI spec:
+/// - `token_a` — one-time token we hand out to bootstrap the handshake
+/// - `token_b` — token the partner gave us, used for calls to *their* API
+/// - `token_c` — token we issued, used by the partner for calls to *our* API
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OcpiParty {
+    /// Internal auto-increment ID (DB row).
+    pub id: i32,
+    /// ISO 3166-1 alpha-2 country code of the partner.
+    pub country_code: String,
+    /// Partner party ID (3 characters).
+    pub party_id: String,
+    /// OCPI role of the partner (EMSP, HUB, ...).
+    pub role: String,
+    /// Business name.
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_a: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_b: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_c: Option<String>,
+    /// Partner's versions endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub versions_url: Option<String>,
+    /// Negotiated OCPI version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Partner's endpoint list (module identifier/role → URL) as JSON string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoints_json: Option<String>,
+    pub status: OcpiPartyStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OcpiParty {
+    /// New pending party with a freshly generated token A.
+    pub fn new(
+        country_code: impl Into<String>,
+        party_id: impl Into<String>,
+        role: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: 0,
+            country_code: country_code.into().to_uppercase(),
+            party_id: party_id.into().to_uppercase(),
+            role: role.into(),
+            name: name.into(),
+            token_a: Some(uuid::Uuid::new_v4().to_string()),
+            token_b: None,
+            token_c: None,
+            versions_url: None,
+            version: None,
+            endpoints_json: None,
+            status: OcpiPartyStatus::Pending,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Whether this party owns the given country_code/party_id pair.
+    pub fn owns(&self, country_code: &str, party_id: &str) -> bool {
+        self.country_code.eq_ignore_ascii_case(country_code)
+            && self.party_id.eq_ignore_ascii_case(party_id)
+    }
+
+    /// URL of a partner module endpoint, e.g. `("cdrs", "RECEIVER")`.
+    pub fn endpoint_url(&self, identifier: &str, role: &str) -> Option<String> {
+        let endpoints: Vec<serde_json::Value> =
+            serde_json::from_str(self.endpoints_json.as_deref()?).ok()?;
+        endpoints
+            .iter()
+            .find(|e| {
+                e["identifier"].as_str() == Some(identifier)
+                    && e["role"].as_str().is_none_or(|r| r == role)
+            })
+            .and_then(|e| e["url"].as_str())
+            .map(String::from)
+    }
+}
+
+/// Token pushed by an eMSP (OCPI Tokens module, CPO receiver interface).
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OcpiToken {
+    /// Country code of the eMSP owning the token.
+    pub country_code: String,
+    /// Party ID of the eMSP owning the token.
+    pub party_id: String,
+    /// Token UID — matched against the OCPP idTag.
+    pub uid: String,
+    /// AD_HOC_USER, APP_USER, OTHER, RFID.
+    pub token_type: String,
+    /// eMA ID / contract ID.
+    pub contract_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visual_number: Option<String>,
+    /// Issuing company name.
+    pub issuer: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<String>,
+    /// Whether the eMSP considers the token valid.
+    pub valid: bool,
+    /// ALWAYS, ALLOWED, ALLOWED_OFFLINE, NEVER.
+    pub whitelist: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    pub last_updated: DateTime<Utc>,
+}
+
+impl OcpiToken {
+    /// OCPP authorization status for this token.
+    ///
+    /// Real-time authorization against the eMSP is not performed, so the
+    /// `valid` flag pushed by the eMSP is authoritative regardless of the
+    /// whitelist mode.
+    pub fn auth_status(&self) -> &'static str {
+        if self.valid {
+            "Accepted"
+        } else {
+            "Blocked"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_party_is_pending_with_token_a() {
+        let party = OcpiParty::new("nl", "abc", "EMSP", "Test eMSP");
+        assert_eq!(party.status, OcpiPartyStatus::Pending);
+        assert_eq!(party.country_code, "NL");
+        assert_eq!(party.party_id, "ABC");
+        assert!(party.token_a.is_some());
+        assert!(party.token_c.is_none());
+        assert!(party.owns("NL", "abc"));
+    }
+
+    #[test]
+    fn endpoint_lookup_by_identifier_and_role() {
+        let mut party = OcpiParty::new("NL", "ABC", "EMSP", "Test");
+        party.endpoints_json = Some(
+            r#"[{"identifier":"cdrs","role":"RECEIVER","url":"https://emsp/cdrs"},
+                {"identifier":"tokens","role":"SENDER","url":"https://emsp/tokens"}]"#
+                .to_string(),
+        );
+        assert_eq!(
+            party.endpoint_url("cdrs", "RECEIVER").as_deref(),
+            Some("https://emsp/cdrs")
+        );
+        assert!(party.endpoint_url("tokens", "RECEIVER").is_none());
+        assert!(party.endpoint_url("sessions", "RECEIVER").is_none());
+    }
+}
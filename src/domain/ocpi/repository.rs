@@ -0,0 +1,50 @@
+//! OCPI repository interfaces
+
+use async_trait::async_trait;
+
+use super::model::{OcpiParty, OcpiToken};
+use crate::domain::DomainResult;
+
+#[async_trait]
+pub trait OcpiPartyRepository: Send + Sync {
+    /// Save a new party and return it with its assigned ID
+    async fn save(&self, party: OcpiParty) -> DomainResult<OcpiParty>;
+
+    /// Update an existing party
+    async fn update(&self, party: OcpiParty) -> DomainResult<()>;
+
+    async fn find_by_id(&self, id: i32) -> DomainResult<Option<OcpiParty>>;
+
+    /// Find a party by the credentials token it presents to us (token A or C)
+    async fn find_by_token(&self, token: &str) -> DomainResult<Option<OcpiParty>>;
+
+    async fn find_all(&self) -> DomainResult<Vec<OcpiParty>>;
+
+    async fn delete(&self, id: i32) -> DomainResult<()>;
+}
+
+#[async_trait]
+pub trait OcpiTokenRepository: Send + Sync {
+    /// Insert or replace a token keyed by (country_code, party_id, uid)
+    async fn upsert(&self, token: OcpiToken) -> DomainResult<()>;
+
+    async fn find(
+        &self,
+        country_code: &str,
+        party_id: &str,
+        uid: &str,
+    ) -> DomainResult<Option<OcpiToken>>;
+
+    /// Find a token by UID regardless of owner (used for OCPP authorization)
+    async fn find_by_uid(&self, uid: &str) -> DomainResult<Option<OcpiToken>>;
+
+    /// All tokens owned by a party
+    async fn find_for_party(
+        &self,
+        country_code: &str,
+        party_id: &str,
+    ) -> DomainResult<Vec<OcpiToken>>;
+
+    /// Delete the tokens with this UID, whichever party owns them; returns how many.
+    async fn delete_by_uid(&self, uid: &str) -> DomainResult<u64>;
+}
@@ -5,12 +5,20 @@
 //! - `Storage` — legacy monolithic trait (kept for backward compatibility during migration)
 //! - `DomainResult` — standard result type for domain operations
 
+use super::auth_event::AuthEventRepository;
+use super::availability::AvailabilityRepository;
 use super::charge_point::ChargePointRepository;
 use super::charging_profile::ChargingProfileRepository;
+use super::data_transfer::DataTransferRepository;
+use super::display_message::DisplayMessageRepository;
+use super::ev_charging_needs::EvChargingNeedsRepository;
 use super::id_tag::IdTagRepository;
+use super::ocpi::{OcpiPartyRepository, OcpiTokenRepository};
 use super::reservation::ReservationRepository;
+use super::retention::RetentionRepository;
 use super::tariff::{BillingRepository, TariffRepository};
 use super::transaction::TransactionRepository;
+use super::variable_monitor::VariableMonitorRepository;
 use crate::shared::errors::DomainError;
 
 /// Result type for domain operations
@@ -33,10 +41,19 @@ pub trait RepositoryProvider: Send + Sync {
     fn charge_points(&self) -> &dyn ChargePointRepository;
     fn transactions(&self) -> &dyn TransactionRepository;
     fn id_tags(&self) -> &dyn IdTagRepository;
+    fn auth_events(&self) -> &dyn AuthEventRepository;
+    fn availability(&self) -> &dyn AvailabilityRepository;
     fn tariffs(&self) -> &dyn TariffRepository;
     fn billing(&self) -> &dyn BillingRepository;
     fn reservations(&self) -> &dyn ReservationRepository;
+    fn retention(&self) -> &dyn RetentionRepository;
     fn charging_profiles(&self) -> &dyn ChargingProfileRepository;
+    fn display_messages(&self) -> &dyn DisplayMessageRepository;
+    fn data_transfers(&self) -> &dyn DataTransferRepository;
+    fn ev_charging_needs(&self) -> &dyn EvChargingNeedsRepository;
+    fn variable_monitors(&self) -> &dyn VariableMonitorRepository;
+    fn ocpi_parties(&self) -> &dyn OcpiPartyRepository;
+    fn ocpi_tokens(&self) -> &dyn OcpiTokenRepository;
 }
 
 // ── Legacy Storage trait removed ────────────────────────────────
@@ -5,5 +5,5 @@
 pub mod model;
 pub mod repository;
 
-pub use model::{Reservation, ReservationStatus};
+pub use model::{connector_availability, ConnectorAvailability, Reservation, ReservationStatus};
 pub use repository::ReservationRepository;
@@ -1,10 +1,12 @@
 //! Reservation domain entity
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 
 /// Reservation status
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ReservationStatus {
+    /// Slot booked for later; ReserveNow not sent yet
+    Scheduled,
     /// Reservation accepted by the charge point
     Accepted,
     /// Reservation cancelled by user or system
@@ -18,6 +20,7 @@ pub enum ReservationStatus {
 impl ReservationStatus {
     pub fn as_str(&self) -> &'static str {
         match self {
+            Self::Scheduled => "Scheduled",
             Self::Accepted => "Accepted",
             Self::Cancelled => "Cancelled",
             Self::Expired => "Expired",
@@ -27,6 +30,7 @@ impl ReservationStatus {
 
     pub fn from_str(s: &str) -> Self {
         match s {
+            "Scheduled" => Self::Scheduled,
             "Accepted" => Self::Accepted,
             "Cancelled" => Self::Cancelled,
             "Expired" => Self::Expired,
@@ -55,7 +59,9 @@ pub struct Reservation {
     pub id_tag: String,
     /// Parent ID tag (group)
     pub parent_id_tag: Option<String>,
-    /// Reservation expiry date
+    /// Start of a booked slot (None = starts immediately)
+    pub start_date: Option<DateTime<Utc>>,
+    /// Reservation expiry date (end of the slot)
     pub expiry_date: DateTime<Utc>,
     /// Current status
     pub status: ReservationStatus,
@@ -78,12 +84,43 @@ impl Reservation {
             connector_id,
             id_tag: id_tag.into(),
             parent_id_tag,
+            start_date: None,
             expiry_date,
             status: ReservationStatus::Accepted,
             created_at: Utc::now(),
         }
     }
 
+    /// Book a slot from `start_date` to `expiry_date`; the charge point is
+    /// only asked to reserve shortly before the slot begins
+    pub fn scheduled(
+        id: i32,
+        charge_point_id: impl Into<String>,
+        connector_id: i32,
+        id_tag: impl Into<String>,
+        parent_id_tag: Option<String>,
+        start_date: DateTime<Utc>,
+        expiry_date: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            start_date: Some(start_date),
+            status: ReservationStatus::Scheduled,
+            ..Self::new(
+                id,
+                charge_point_id,
+                connector_id,
+                id_tag,
+                parent_id_tag,
+                expiry_date,
+            )
+        }
+    }
+
+    /// Mark as accepted by the charge point (ReserveNow sent for a booked slot)
+    pub fn activate(&mut self) {
+        self.status = ReservationStatus::Accepted;
+    }
+
     /// Cancel this reservation
     pub fn cancel(&mut self) {
         self.status = ReservationStatus::Cancelled;
@@ -104,10 +141,93 @@ impl Reservation {
         self.status == ReservationStatus::Accepted
     }
 
+    /// Check if this reservation holds, or will hold, its connector
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self.status,
+            ReservationStatus::Scheduled | ReservationStatus::Accepted
+        )
+    }
+
+    /// Check if a booked slot should be sent to the charge point: it begins
+    /// within `lead` of `now`
+    pub fn is_due(&self, now: DateTime<Utc>, lead: Duration) -> bool {
+        self.status == ReservationStatus::Scheduled && self.starts_at() - lead <= now
+    }
+
+    /// When the reservation starts holding its connector
+    pub fn starts_at(&self) -> DateTime<Utc> {
+        self.start_date.unwrap_or(self.created_at)
+    }
+
+    /// Whether the reserved window intersects `[from, to)`
+    pub fn overlaps(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> bool {
+        self.starts_at() < to && from < self.expiry_date
+    }
+
     /// Check if this reservation has expired
     pub fn is_expired(&self) -> bool {
         self.status == ReservationStatus::Expired || Utc::now() > self.expiry_date
     }
+
+    /// Whether `id_tag` may use this reservation: the reserved tag itself, or
+    /// any tag in the reserved parent group (ID tags are case-insensitive)
+    pub fn matches_tag(&self, id_tag: &str, parent_id_tag: Option<&str>) -> bool {
+        if self.id_tag.eq_ignore_ascii_case(id_tag) {
+            return true;
+        }
+        match (&self.parent_id_tag, parent_id_tag) {
+            (Some(reserved), Some(parent)) => reserved.eq_ignore_ascii_case(parent),
+            _ => false,
+        }
+    }
+}
+
+/// Whether a connector can be reserved for a time window
+#[derive(Debug, Clone)]
+pub struct ConnectorAvailability {
+    pub connector_id: i32,
+    /// False when a reservation holds this connector, or when the
+    /// "any connector" reservations would need it
+    pub available: bool,
+    /// Reservations held on this connector during the window
+    pub reservations: Vec<Reservation>,
+}
+
+/// Availability of `connector_ids` between `from` and `to`.
+///
+/// Reservations on connector 0 take whichever connector is free, so they
+/// only make connectors unavailable once they outnumber the free ones.
+pub fn connector_availability(
+    connector_ids: &[i32],
+    reservations: &[Reservation],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<ConnectorAvailability> {
+    let overlapping: Vec<&Reservation> = reservations
+        .iter()
+        .filter(|r| r.is_pending() && r.overlaps(from, to))
+        .collect();
+    let any_connector = overlapping.iter().filter(|r| r.connector_id == 0).count();
+
+    let mut result: Vec<ConnectorAvailability> = connector_ids
+        .iter()
+        .map(|&connector_id| ConnectorAvailability {
+            connector_id,
+            available: true,
+            reservations: overlapping
+                .iter()
+                .filter(|r| r.connector_id == connector_id)
+                .map(|r| (*r).clone())
+                .collect(),
+        })
+        .collect();
+
+    let free = result.iter().filter(|a| a.reservations.is_empty()).count();
+    for a in &mut result {
+        a.available = a.reservations.is_empty() && any_connector < free;
+    }
+    result
 }
 
 // ── Tests ──────────────────────────────────────────────────────
@@ -115,7 +235,6 @@ impl Reservation {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Duration;
 
     fn sample_reservation() -> Reservation {
         Reservation::new(
@@ -177,6 +296,7 @@ mod tests {
     #[test]
     fn status_display_roundtrip() {
         for status in &[
+            ReservationStatus::Scheduled,
             ReservationStatus::Accepted,
             ReservationStatus::Cancelled,
             ReservationStatus::Expired,
@@ -194,6 +314,87 @@ mod tests {
         assert_eq!(s, ReservationStatus::Cancelled);
     }
 
+    #[test]
+    fn matches_reserved_tag_or_parent_group() {
+        let r = Reservation::new(
+            4,
+            "CP001",
+            1,
+            "TAG-001",
+            Some("FLEET".into()),
+            Utc::now() + Duration::hours(1),
+        );
+        assert!(r.matches_tag("tag-001", None));
+        assert!(r.matches_tag("TAG-999", Some("FLEET")));
+        assert!(!r.matches_tag("TAG-999", Some("OTHER")));
+        assert!(!r.matches_tag("TAG-999", None));
+    }
+
+    #[test]
+    fn scheduled_slot_is_due_within_lead_time() {
+        let start = Utc::now() + Duration::hours(2);
+        let mut r = Reservation::scheduled(
+            5,
+            "CP001",
+            1,
+            "TAG-001",
+            None,
+            start,
+            start + Duration::hours(1),
+        );
+        assert_eq!(r.status, ReservationStatus::Scheduled);
+        assert!(r.is_pending());
+        assert!(!r.is_active());
+        assert!(!r.is_due(Utc::now(), Duration::minutes(5)));
+        assert!(r.is_due(start - Duration::minutes(4), Duration::minutes(5)));
+
+        r.activate();
+        assert!(r.is_active());
+        assert!(!r.is_due(start, Duration::minutes(5)));
+    }
+
+    #[test]
+    fn availability_accounts_for_overlapping_slots() {
+        let start = Utc::now() + Duration::hours(2);
+        let slot = |id, connector_id, offset_min| {
+            let from = start + Duration::minutes(offset_min);
+            Reservation::scheduled(
+                id,
+                "CP001",
+                connector_id,
+                "TAG",
+                None,
+                from,
+                from + Duration::hours(1),
+            )
+        };
+        let window = |offset_min| {
+            let from = start + Duration::minutes(offset_min);
+            (from, from + Duration::hours(1))
+        };
+        let available = |reservations: &[Reservation], offset_min| {
+            let (from, to) = window(offset_min);
+            connector_availability(&[1, 2], reservations, from, to)
+                .into_iter()
+                .map(|a| a.available)
+                .collect::<Vec<_>>()
+        };
+        let booked = vec![slot(1, 1, 0)];
+
+        assert_eq!(available(&booked, 30), vec![false, true]);
+        // back-to-back slots do not overlap
+        assert_eq!(available(&booked, 60), vec![true, true]);
+        assert_eq!(available(&booked, -60), vec![true, true]);
+
+        // an "any connector" slot takes the last free connector
+        let both = vec![slot(1, 1, 0), slot(2, 0, 15)];
+        assert_eq!(available(&both, 30), vec![false, false]);
+
+        let mut cancelled = slot(1, 1, 0);
+        cancelled.cancel();
+        assert_eq!(available(&[cancelled], 30), vec![true, true]);
+    }
+
     #[test]
     fn with_parent_id_tag() {
         let r = Reservation::new(
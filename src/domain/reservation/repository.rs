@@ -29,9 +29,25 @@ pub trait ReservationRepository: Send + Sync {
         connector_id: i32,
     ) -> DomainResult<Option<Reservation>>;
 
+    /// Find reservations of a charge point that hold or will hold a
+    /// connector (status Accepted or Scheduled)
+    async fn find_pending_for_charge_point(
+        &self,
+        charge_point_id: &str,
+    ) -> DomainResult<Vec<Reservation>>;
+
+    /// Find all booked slots not yet sent to their charge point (status = Scheduled)
+    async fn find_scheduled(&self) -> DomainResult<Vec<Reservation>>;
+
     /// Find all reservations (any status)
     async fn find_all(&self) -> DomainResult<Vec<Reservation>>;
 
+    /// Find all reservations made for `id_tag` (directly or as parent), newest first
+    async fn find_by_id_tag(&self, id_tag: &str) -> DomainResult<Vec<Reservation>>;
+
+    /// Replace `id_tag` with `replacement` in id_tag and parent_id_tag; returns rows changed
+    async fn replace_id_tag(&self, id_tag: &str, replacement: &str) -> DomainResult<u64>;
+
     /// Find all expired active reservations (expiry_date < now, status = Accepted)
     async fn find_expired(&self) -> DomainResult<Vec<Reservation>>;
 
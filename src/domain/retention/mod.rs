@@ -0,0 +1,13 @@
+//! Retention aggregate
+//!
+//! Contains the data classes subject to retention, the per-class
+//! retention policy and the record of archival runs.
+
+pub mod model;
+pub mod repository;
+
+pub use model::{
+    ArchiveRun, ArchiveRunStatus, ArchiveTrigger, ArchivedClass, DataClass, ExpiredRow,
+    RetentionPolicy,
+};
+pub use repository::RetentionRepository;
@@ -0,0 +1,255 @@
+//! Retention domain entities
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A kind of historical data with its own retention period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataClass {
+    /// Finished transactions (active ones are never archived)
+    Transactions,
+    /// Authorization attempts
+    AuthEvents,
+    /// Vendor DataTransfer messages
+    DataTransfers,
+    /// Closed WebSocket connections of stations
+    ConnectionSessions,
+    /// Ended connector status intervals
+    ConnectorStatusIntervals,
+}
+
+impl DataClass {
+    pub const ALL: [DataClass; 5] = [
+        Self::Transactions,
+        Self::AuthEvents,
+        Self::DataTransfers,
+        Self::ConnectionSessions,
+        Self::ConnectorStatusIntervals,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Transactions => "transactions",
+            Self::AuthEvents => "auth_events",
+            Self::DataTransfers => "data_transfers",
+            Self::ConnectionSessions => "connection_sessions",
+            Self::ConnectorStatusIntervals => "connector_status_intervals",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == s)
+    }
+
+    /// Row fields holding a driver's id tag, pseudonymised when archived.
+    pub fn id_tag_fields(&self) -> &'static [&'static str] {
+        match self {
+            Self::Transactions | Self::AuthEvents => &["id_tag"],
+            _ => &[],
+        }
+    }
+}
+
+impl std::fmt::Display for DataClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// How long rows of a data class stay in the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    pub data_class: DataClass,
+    /// Days to keep rows; 0 keeps them forever
+    pub retain_days: u32,
+}
+
+impl RetentionPolicy {
+    pub fn new(data_class: DataClass, retain_days: u32) -> Self {
+        Self {
+            data_class,
+            retain_days,
+        }
+    }
+
+    /// Rows that ended before this instant are due for archival.
+    /// `None` when the class is kept forever.
+    pub fn cutoff(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        (self.retain_days > 0).then(|| now - Duration::days(self.retain_days as i64))
+    }
+}
+
+/// A row due for archival, as it is stored.
+#[derive(Debug, Clone)]
+pub struct ExpiredRow {
+    pub id: i32,
+    /// The full row, written to the archive as is
+    pub data: serde_json::Value,
+}
+
+/// What started an archival run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveTrigger {
+    /// The periodic retention task
+    Scheduled,
+    /// An administrator through the API
+    Manual,
+}
+
+impl ArchiveTrigger {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Scheduled => "Scheduled",
+            Self::Manual => "Manual",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "Scheduled" => Self::Scheduled,
+            _ => Self::Manual,
+        }
+    }
+}
+
+/// Archival run status
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveRunStatus {
+    Running,
+    Completed,
+    /// Stopped on an error; classes archived before it stay archived
+    Failed,
+}
+
+impl ArchiveRunStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Running => "Running",
+            Self::Completed => "Completed",
+            Self::Failed => "Failed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "Running" => Self::Running,
+            "Completed" => Self::Completed,
+            _ => Self::Failed,
+        }
+    }
+}
+
+/// Outcome of one data class within a run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchivedClass {
+    pub data_class: String,
+    /// Rows that ended before this instant were archived
+    pub cutoff: DateTime<Utc>,
+    pub rows: u64,
+    /// Archive file, absent when nothing was due
+    pub file: Option<String>,
+}
+
+/// One archival run over all data classes.
+#[derive(Debug, Clone)]
+pub struct ArchiveRun {
+    /// Database id (0 until persisted)
+    pub id: i32,
+    pub trigger: ArchiveTrigger,
+    pub status: ArchiveRunStatus,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Rows archived and deleted over all classes
+    pub rows_archived: u64,
+    pub classes: Vec<ArchivedClass>,
+    pub error: Option<String>,
+}
+
+impl ArchiveRun {
+    pub fn start(trigger: ArchiveTrigger, started_at: DateTime<Utc>) -> Self {
+        Self {
+            id: 0,
+            trigger,
+            status: ArchiveRunStatus::Running,
+            started_at,
+            finished_at: None,
+            rows_archived: 0,
+            classes: Vec::new(),
+            error: None,
+        }
+    }
+
+    pub fn record(&mut self, archived: ArchivedClass) {
+        self.rows_archived += archived.rows;
+        self.classes.push(archived);
+    }
+
+    /// Mark the run finished, failed if `error` is set.
+    pub fn finish(&mut self, finished_at: DateTime<Utc>, error: Option<String>) {
+        self.status = if error.is_some() {
+            ArchiveRunStatus::Failed
+        } else {
+            ArchiveRunStatus::Completed
+        };
+        self.finished_at = Some(finished_at);
+        self.error = error;
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_days_keeps_forever() {
+        let now = Utc::now();
+        assert_eq!(
+            RetentionPolicy::new(DataClass::Transactions, 0).cutoff(now),
+            None
+        );
+        assert_eq!(
+            RetentionPolicy::new(DataClass::AuthEvents, 90).cutoff(now),
+            Some(now - Duration::days(90))
+        );
+    }
+
+    #[test]
+    fn data_class_names_roundtrip() {
+        for class in DataClass::ALL {
+            assert_eq!(DataClass::parse(class.as_str()), Some(class));
+        }
+        assert_eq!(DataClass::parse("meter_values"), None);
+    }
+
+    #[test]
+    fn run_trigger_and_status_roundtrip() {
+        for trigger in [ArchiveTrigger::Scheduled, ArchiveTrigger::Manual] {
+            assert_eq!(ArchiveTrigger::parse(trigger.as_str()), trigger);
+        }
+        for status in [
+            ArchiveRunStatus::Running,
+            ArchiveRunStatus::Completed,
+            ArchiveRunStatus::Failed,
+        ] {
+            assert_eq!(ArchiveRunStatus::parse(status.as_str()), status);
+        }
+    }
+
+    #[test]
+    fn run_totals_rows_and_fails_on_error() {
+        let mut run = ArchiveRun::start(ArchiveTrigger::Manual, Utc::now());
+        for rows in [3, 4] {
+            run.record(ArchivedClass {
+                data_class: "auth_events".into(),
+                cutoff: Utc::now(),
+                rows,
+                file: None,
+            });
+        }
+        run.finish(Utc::now(), Some("disk full".into()));
+        assert_eq!(run.rows_archived, 7);
+        assert_eq!(run.status, ArchiveRunStatus::Failed);
+    }
+}
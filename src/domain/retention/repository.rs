@@ -0,0 +1,33 @@
+//! Retention repository interface
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use super::model::{ArchiveRun, DataClass, ExpiredRow};
+use crate::domain::DomainResult;
+
+#[async_trait]
+pub trait RetentionRepository: Send + Sync {
+    /// Up to `limit` rows of a class that ended before `before`, with an id
+    /// above `after_id`, by ascending id.
+    async fn find_expired(
+        &self,
+        data_class: DataClass,
+        before: DateTime<Utc>,
+        after_id: i32,
+        limit: u64,
+    ) -> DomainResult<Vec<ExpiredRow>>;
+
+    /// Delete rows of a class by id; returns the number deleted.
+    async fn delete_rows(&self, data_class: DataClass, ids: &[i32]) -> DomainResult<u64>;
+
+    /// Persist a new run; returns it with its id set.
+    async fn save_run(&self, run: ArchiveRun) -> DomainResult<ArchiveRun>;
+
+    async fn update_run(&self, run: ArchiveRun) -> DomainResult<()>;
+
+    async fn find_run(&self, id: i32) -> DomainResult<Option<ArchiveRun>>;
+
+    /// Most recent runs first.
+    async fn find_runs(&self, limit: u64) -> DomainResult<Vec<ArchiveRun>>;
+}
@@ -12,6 +12,9 @@ pub trait TariffRepository: Send + Sync {
     async fn find_all(&self) -> DomainResult<Vec<Tariff>>;
     async fn save(&self, tariff: Tariff) -> DomainResult<Tariff>;
     async fn update(&self, tariff: Tariff) -> DomainResult<()>;
+    /// Insert tariffs with id 0 and update the others, in one database
+    /// transaction: either all are stored or none is.
+    async fn save_all(&self, tariffs: Vec<Tariff>) -> DomainResult<()>;
     async fn delete(&self, id: i32) -> DomainResult<()>;
 }
 
@@ -19,4 +22,6 @@ pub trait TariffRepository: Send + Sync {
 pub trait BillingRepository: Send + Sync {
     async fn update_billing(&self, billing: TransactionBilling) -> DomainResult<()>;
     async fn get_billing(&self, transaction_id: i32) -> DomainResult<Option<TransactionBilling>>;
+    /// Billing records of those of the given transactions that have been billed.
+    async fn find_billings(&self, ids: &[i32]) -> DomainResult<Vec<TransactionBilling>>;
 }
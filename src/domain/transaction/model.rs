@@ -78,6 +78,8 @@ pub struct Transaction {
     pub limit_type: Option<ChargingLimitType>,
     /// Charging limit value
     pub limit_value: Option<f64>,
+    /// `transactionId` the station assigned (OCPP 2.0.1 only)
+    pub station_transaction_id: Option<String>,
 }
 
 impl Transaction {
@@ -105,6 +107,7 @@ impl Transaction {
             last_meter_update: None,
             limit_type: None,
             limit_value: None,
+            station_transaction_id: None,
         }
     }
 
@@ -1,6 +1,7 @@
 //! Transaction repository interface
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use super::model::Transaction;
 use crate::domain::DomainResult;
@@ -17,6 +18,19 @@ pub trait TransactionRepository: Send + Sync {
     ) -> DomainResult<Option<Transaction>>;
     async fn find_by_charge_point(&self, charge_point_id: &str) -> DomainResult<Vec<Transaction>>;
     async fn find_all(&self) -> DomainResult<Vec<Transaction>>;
+    /// Up to `limit` transactions with an id above `after_id`, by ascending id,
+    /// optionally limited to those started in `[from, to)`.
+    async fn find_page(
+        &self,
+        after_id: i32,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        limit: u64,
+    ) -> DomainResult<Vec<Transaction>>;
+    /// All transactions started with `id_tag`, newest first.
+    async fn find_by_id_tag(&self, id_tag: &str) -> DomainResult<Vec<Transaction>>;
+    /// Replace `id_tag` with `replacement` on every transaction; returns rows changed.
+    async fn replace_id_tag(&self, id_tag: &str, replacement: &str) -> DomainResult<u64>;
     async fn update_meter_data(
         &self,
         transaction_id: i32,
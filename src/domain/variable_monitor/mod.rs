@@ -0,0 +1,11 @@
+//! Variable monitor aggregate
+//!
+//! Contains the VariableMonitor entity (OCPP 2.0.1 monitors configured on
+//! a station, as last reported via NotifyMonitoringReport) and its
+//! repository interface.
+
+pub mod model;
+pub mod repository;
+
+pub use model::VariableMonitor;
+pub use repository::VariableMonitorRepository;
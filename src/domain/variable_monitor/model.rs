@@ -0,0 +1,48 @@
+//! VariableMonitor domain entity
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A monitor configured on a charging station (OCPP 2.0.1 VariableMonitoring).
+///
+/// Rows mirror what the station reported in NotifyMonitoringReport, so they
+/// describe the monitors actually active on the station rather than the ones
+/// the CSMS asked for.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct VariableMonitor {
+    /// Internal auto-increment ID (DB row).
+    pub id: i32,
+    /// Charge point the monitor is configured on.
+    pub charge_point_id: String,
+    /// Monitor ID as known by the station (VariableMonitoring.id).
+    pub monitor_id: i32,
+    /// Component name (e.g. "EVSE", "Connector", "ChargingStation").
+    pub component: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component_instance: Option<String>,
+    /// EVSE ID, if the component is scoped to an EVSE.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evse_id: Option<i32>,
+    /// Connector ID within the EVSE, if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_id: Option<i32>,
+    /// Monitored variable name.
+    pub variable: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variable_instance: Option<String>,
+    /// UpperThreshold, LowerThreshold, Delta, Periodic or PeriodicClockAligned.
+    pub monitor_type: String,
+    /// Threshold, delta or interval, depending on the monitor type.
+    pub value: f64,
+    /// Severity 0 (Danger) – 9 (Debug).
+    pub severity: i32,
+    /// Active only during a transaction.
+    pub transaction: bool,
+    /// Request ID of the monitoring report that last included this monitor.
+    pub request_id: i32,
+    /// When the station generated that report.
+    pub last_reported_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,27 @@
+//! VariableMonitor repository interface
+
+use async_trait::async_trait;
+
+use super::model::VariableMonitor;
+use crate::domain::DomainResult;
+
+#[async_trait]
+pub trait VariableMonitorRepository: Send + Sync {
+    /// Insert or replace a monitor, keyed by `(charge_point_id, monitor_id)`.
+    async fn upsert(&self, monitor: VariableMonitor) -> DomainResult<VariableMonitor>;
+
+    /// All monitors known for a charge point, ordered by monitor id.
+    async fn find_for_charge_point(&self, charge_point_id: &str)
+        -> DomainResult<Vec<VariableMonitor>>;
+
+    /// Delete the given monitors of a charge point.
+    ///
+    /// Returns the number of rows deleted.
+    async fn remove(&self, charge_point_id: &str, monitor_ids: &[i32]) -> DomainResult<u64>;
+
+    /// Delete every monitor of a charge point except those in `monitor_ids`.
+    ///
+    /// Returns the number of rows deleted.
+    async fn remove_except(&self, charge_point_id: &str, monitor_ids: &[i32])
+        -> DomainResult<u64>;
+}
@@ -0,0 +1,151 @@
+//! Gzip-compressed JSON Lines archive files
+//!
+//! Layout: `<dir>/<data class>/<data class>-<run start>-run<id>.jsonl.gz`,
+//! one stored row per line. A file is written under a `.partial` name and
+//! renamed once complete, so every `.jsonl.gz` file is whole.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use crate::application::ports::{ArchiveStore, ArchiveWriter};
+use crate::domain::retention::{DataClass, ExpiredRow};
+
+pub struct JsonlArchiveStore {
+    dir: PathBuf,
+}
+
+impl JsonlArchiveStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+#[async_trait]
+impl ArchiveStore for JsonlArchiveStore {
+    async fn create(
+        &self,
+        data_class: DataClass,
+        run_id: i32,
+        started_at: DateTime<Utc>,
+    ) -> Result<Box<dyn ArchiveWriter>, String> {
+        let dir = self.dir.join(data_class.as_str());
+        let path = dir.join(format!(
+            "{}-{}-run{}.jsonl.gz",
+            data_class,
+            started_at.format("%Y%m%dT%H%M%SZ"),
+            run_id
+        ));
+        let partial = path.with_extension("gz.partial");
+
+        let target = partial.clone();
+        let file = blocking(move || {
+            std::fs::create_dir_all(&dir)?;
+            File::create_new(&target)
+        })
+        .await
+        .map_err(|e| format!("Cannot create {}: {}", partial.display(), e))?;
+
+        Ok(Box::new(JsonlArchiveWriter {
+            encoder: Some(GzEncoder::new(BufWriter::new(file), Compression::default())),
+            partial,
+            path,
+        }))
+    }
+}
+
+struct JsonlArchiveWriter {
+    /// Taken while a blocking write is in flight
+    encoder: Option<GzEncoder<BufWriter<File>>>,
+    partial: PathBuf,
+    path: PathBuf,
+}
+
+#[async_trait]
+impl ArchiveWriter for JsonlArchiveWriter {
+    async fn append(&mut self, rows: &[ExpiredRow]) -> Result<(), String> {
+        let mut lines = Vec::new();
+        for row in rows {
+            serde_json::to_writer(&mut lines, &row.data).map_err(|e| e.to_string())?;
+            lines.push(b'\n');
+        }
+
+        let mut encoder = self.encoder.take().ok_or("Archive writer failed earlier")?;
+        let encoder = blocking(move || encoder.write_all(&lines).map(|_| encoder))
+            .await
+            .map_err(|e| format!("Cannot write {}: {}", self.partial.display(), e))?;
+        self.encoder = Some(encoder);
+        Ok(())
+    }
+
+    async fn finish(self: Box<Self>) -> Result<String, String> {
+        let Self {
+            encoder,
+            partial,
+            path,
+        } = *self;
+        let encoder = encoder.ok_or("Archive writer failed earlier")?;
+
+        let (from, to) = (partial.clone(), path.clone());
+        blocking(move || {
+            let file = encoder.finish()?.into_inner().map_err(|e| e.into_error())?;
+            file.sync_all()?;
+            std::fs::rename(&from, &to)
+        })
+        .await
+        .map_err(|e| format!("Cannot finish {}: {}", partial.display(), e))?;
+
+        Ok(path.display().to_string())
+    }
+}
+
+/// Run file I/O off the async runtime.
+async fn blocking<T: Send + 'static>(
+    f: impl FnOnce() -> std::io::Result<T> + Send + 'static,
+) -> std::io::Result<T> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .unwrap_or_else(|e| Err(std::io::Error::other(e)))
+}
+
+// ── Tests ──────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    #[tokio::test]
+    async fn writes_one_json_row_per_line() {
+        let dir = std::env::temp_dir().join(format!("archive-{}", uuid::Uuid::new_v4()));
+        let store = JsonlArchiveStore::new(&dir);
+
+        let mut writer = store
+            .create(DataClass::AuthEvents, 7, Utc::now())
+            .await
+            .unwrap();
+        for id in [1, 2] {
+            let row = ExpiredRow {
+                id,
+                data: serde_json::json!({ "id": id }),
+            };
+            writer.append(&[row]).await.unwrap();
+        }
+        let path = writer.finish().await.unwrap();
+        assert!(path.ends_with("-run7.jsonl.gz"), "{}", path);
+
+        let mut content = String::new();
+        GzDecoder::new(File::open(&path).unwrap())
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "{\"id\":1}\n{\"id\":2}\n");
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
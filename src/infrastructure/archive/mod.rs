@@ -0,0 +1,8 @@
+//! Archive store implementations
+//!
+//! Expired rows are written to local gzip-compressed JSON Lines files,
+//! one file per data class and archival run.
+
+pub mod jsonl;
+
+pub use jsonl::JsonlArchiveStore;
@@ -0,0 +1,10 @@
+//! Cluster backplane implementations
+//!
+//! Only PostgreSQL is supported: nodes share its tables for the session
+//! directory and talk over LISTEN/NOTIFY, so no extra broker is needed.
+
+#[cfg(feature = "postgres")]
+pub mod postgres;
+
+#[cfg(feature = "postgres")]
+pub use postgres::PgClusterBackplane;
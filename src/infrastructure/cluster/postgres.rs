@@ -0,0 +1,287 @@
+//! PostgreSQL cluster backplane (LISTEN/NOTIFY + `cluster_*` tables)
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::sqlx::postgres::{PgConnection, PgListener};
+use sea_orm::sqlx::{self, Connection};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, EntityTrait,
+    QueryFilter, Statement,
+};
+use tokio::sync::{mpsc, Mutex};
+use tracing::{info, warn};
+
+use crate::application::charging::session::RemoteSession;
+use crate::application::cluster::ClusterEnvelope;
+use crate::application::ports::ClusterBackplane;
+use crate::domain::OcppVersion;
+use crate::infrastructure::database::entities::{cluster_message, cluster_node, cluster_session};
+
+/// NOTIFY channel shared by all nodes.
+const CHANNEL: &str = "ocpp_cluster";
+
+/// PostgreSQL rejects NOTIFY payloads of 8000 bytes or more; larger
+/// messages are stored in `cluster_messages` and sent by reference.
+const MAX_NOTIFY_PAYLOAD: usize = 7900;
+const REFERENCE_PREFIX: &str = "ref:";
+
+/// How long stored message bodies are kept for slow receivers.
+const MESSAGE_RETENTION_SECS: i64 = 300;
+
+/// Session-level advisory lock held by the cluster leader.
+const LEADER_LOCK_KEY: i64 = 0x6f63_7070; // "ocpp"
+
+pub struct PgClusterBackplane {
+    db: DatabaseConnection,
+    /// The connection holding the leader lock; the lock is released when
+    /// its session ends.
+    leader: Mutex<Option<PgConnection>>,
+}
+
+impl PgClusterBackplane {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self {
+            db,
+            leader: Mutex::new(None),
+        }
+    }
+}
+
+fn db_err(e: sea_orm::DbErr) -> String {
+    e.to_string()
+}
+
+fn cutoff(node_timeout: Duration) -> chrono::DateTime<Utc> {
+    Utc::now() - chrono::Duration::seconds(node_timeout.as_secs() as i64)
+}
+
+fn session_from_model(model: cluster_session::Model) -> Option<RemoteSession> {
+    Some(RemoteSession {
+        ocpp_version: OcppVersion::from_subprotocol(&model.ocpp_version)?,
+        charge_point_id: model.charge_point_id,
+        node_id: model.node_id,
+    })
+}
+
+/// Resolve a notification payload to the envelope it carries.
+async fn decode(db: &DatabaseConnection, payload: &str) -> Result<ClusterEnvelope, String> {
+    let body = match payload.strip_prefix(REFERENCE_PREFIX) {
+        Some(id) => {
+            let id: i32 = id.parse().map_err(|_| format!("bad reference '{}'", id))?;
+            cluster_message::Entity::find_by_id(id)
+                .one(db)
+                .await
+                .map_err(db_err)?
+                .ok_or_else(|| format!("message {} no longer stored", id))?
+                .body
+        }
+        None => payload.to_string(),
+    };
+    serde_json::from_str(&body).map_err(|e| e.to_string())
+}
+
+#[async_trait]
+impl ClusterBackplane for PgClusterBackplane {
+    async fn publish(&self, envelope: &ClusterEnvelope) -> Result<(), String> {
+        let body = serde_json::to_string(envelope).map_err(|e| e.to_string())?;
+
+        let payload = if body.len() > MAX_NOTIFY_PAYLOAD {
+            let stored = cluster_message::Entity::insert(cluster_message::ActiveModel {
+                id: Default::default(), // auto-increment
+                body: Set(body),
+                created_at: Set(Utc::now()),
+            })
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+            format!("{}{}", REFERENCE_PREFIX, stored.last_insert_id)
+        } else {
+            body
+        };
+
+        self.db
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT pg_notify($1, $2)",
+                [CHANNEL.into(), payload.into()],
+            ))
+            .await
+            .map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn subscribe(&self) -> Result<mpsc::UnboundedReceiver<ClusterEnvelope>, String> {
+        let mut listener = PgListener::connect_with(self.db.get_postgres_connection_pool())
+            .await
+            .map_err(|e| e.to_string())?;
+        listener.listen(CHANNEL).await.map_err(|e| e.to_string())?;
+        info!(channel = CHANNEL, "Listening for cluster messages");
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            loop {
+                // Reconnects by itself; messages sent meanwhile are lost and
+                // the directory catches up on the next resync
+                let notification = match listener.recv().await {
+                    Ok(notification) => notification,
+                    Err(e) => {
+                        warn!(error = %e, "Cluster listener error");
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        continue;
+                    }
+                };
+                match decode(&db, notification.payload()).await {
+                    Ok(envelope) => {
+                        if tx.send(envelope).is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => warn!(error = %e, "Dropping unreadable cluster message"),
+                }
+            }
+        });
+        Ok(rx)
+    }
+
+    async fn heartbeat(&self, node_id: &str) -> Result<(), String> {
+        let now = Utc::now();
+        cluster_node::Entity::insert(cluster_node::ActiveModel {
+            node_id: Set(node_id.to_string()),
+            started_at: Set(now),
+            last_seen_at: Set(now),
+        })
+        .on_conflict(
+            OnConflict::column(cluster_node::Column::NodeId)
+                .update_column(cluster_node::Column::LastSeenAt)
+                .to_owned(),
+        )
+        .exec(&self.db)
+        .await
+        .map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn claim_session(&self, session: &RemoteSession) -> Result<(), String> {
+        cluster_session::Entity::insert(cluster_session::ActiveModel {
+            charge_point_id: Set(session.charge_point_id.clone()),
+            node_id: Set(session.node_id.clone()),
+            ocpp_version: Set(session.ocpp_version.subprotocol().to_string()),
+            connected_at: Set(Utc::now()),
+        })
+        .on_conflict(
+            OnConflict::column(cluster_session::Column::ChargePointId)
+                .update_columns([
+                    cluster_session::Column::NodeId,
+                    cluster_session::Column::OcppVersion,
+                    cluster_session::Column::ConnectedAt,
+                ])
+                .to_owned(),
+        )
+        .exec(&self.db)
+        .await
+        .map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn release_session(&self, node_id: &str, charge_point_id: &str) -> Result<(), String> {
+        cluster_session::Entity::delete_many()
+            .filter(cluster_session::Column::ChargePointId.eq(charge_point_id))
+            .filter(cluster_session::Column::NodeId.eq(node_id))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn live_sessions(&self, node_timeout: Duration) -> Result<Vec<RemoteSession>, String> {
+        let live: Vec<String> = cluster_node::Entity::find()
+            .filter(cluster_node::Column::LastSeenAt.gte(cutoff(node_timeout)))
+            .all(&self.db)
+            .await
+            .map_err(db_err)?
+            .into_iter()
+            .map(|node| node.node_id)
+            .collect();
+
+        let sessions = cluster_session::Entity::find()
+            .filter(cluster_session::Column::NodeId.is_in(live))
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(sessions
+            .into_iter()
+            .filter_map(session_from_model)
+            .collect())
+    }
+
+    async fn reap_nodes(&self, node_timeout: Duration) -> Result<Vec<String>, String> {
+        let dead: Vec<String> = cluster_node::Entity::find()
+            .filter(cluster_node::Column::LastSeenAt.lt(cutoff(node_timeout)))
+            .all(&self.db)
+            .await
+            .map_err(db_err)?
+            .into_iter()
+            .map(|node| node.node_id)
+            .collect();
+
+        for node_id in &dead {
+            self.leave(node_id).await?;
+        }
+
+        let retention = Utc::now() - chrono::Duration::seconds(MESSAGE_RETENTION_SECS);
+        cluster_message::Entity::delete_many()
+            .filter(cluster_message::Column::CreatedAt.lt(retention))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+
+        Ok(dead)
+    }
+
+    async fn leave(&self, node_id: &str) -> Result<(), String> {
+        cluster_session::Entity::delete_many()
+            .filter(cluster_session::Column::NodeId.eq(node_id))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+        cluster_node::Entity::delete_by_id(node_id.to_string())
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn try_lead(&self) -> Result<bool, String> {
+        let mut leader = self.leader.lock().await;
+        if let Some(conn) = leader.as_mut() {
+            if conn.ping().await.is_ok() {
+                return Ok(true);
+            }
+            // The session and the lock with it are gone
+            warn!("Lost the database session holding the cluster leader lock");
+            *leader = None;
+            return Ok(false);
+        }
+
+        let mut conn = self
+            .db
+            .get_postgres_connection_pool()
+            .acquire()
+            .await
+            .map_err(|e| e.to_string())?
+            .detach();
+        let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_lock($1)")
+            .bind(LEADER_LOCK_KEY)
+            .fetch_one(&mut conn)
+            .await
+            .map_err(|e| e.to_string())?;
+        if locked {
+            *leader = Some(conn);
+        }
+        Ok(locked)
+    }
+}
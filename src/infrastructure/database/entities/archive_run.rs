@@ -0,0 +1,35 @@
+//! Archive run entity — one data retention run
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "archive_runs")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    /// "Scheduled" or "Manual".
+    pub triggered_by: String,
+
+    /// "Running", "Completed" or "Failed".
+    pub status: String,
+
+    pub started_at: DateTimeUtc,
+
+    pub finished_at: Option<DateTimeUtc>,
+
+    pub rows_archived: i64,
+
+    /// JSON array with the outcome per data class.
+    #[sea_orm(column_type = "Text")]
+    pub details: String,
+
+    #[sea_orm(column_type = "Text", nullable)]
+    pub error: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
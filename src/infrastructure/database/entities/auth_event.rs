@@ -0,0 +1,39 @@
+//! AuthEvent entity
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "auth_events")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    pub charge_point_id: String,
+
+    /// Presented id tag / id token.
+    pub id_tag: String,
+
+    /// Authorization status returned to the station.
+    pub status: String,
+
+    pub occurred_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::charge_point::Entity",
+        from = "Column::ChargePointId",
+        to = "super::charge_point::Column::Id"
+    )]
+    ChargePoint,
+}
+
+impl Related<super::charge_point::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChargePoint.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
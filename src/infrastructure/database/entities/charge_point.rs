@@ -45,6 +45,10 @@ pub struct Model {
     #[sea_orm(nullable)]
     pub last_heartbeat: Option<DateTimeUtc>,
 
+    /// Heartbeat interval the station last accepted (seconds)
+    #[sea_orm(nullable)]
+    pub heartbeat_interval: Option<i32>,
+
     pub registered_at: DateTimeUtc,
 
     #[sea_orm(nullable)]
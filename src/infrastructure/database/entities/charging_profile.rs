@@ -42,6 +42,10 @@ pub struct Model {
     /// Whether this profile is still active on the charge point.
     pub is_active: bool,
 
+    /// Source of an external limit reported by the station (EMS, SO, Other).
+    #[sea_orm(nullable)]
+    pub limit_source: Option<String>,
+
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
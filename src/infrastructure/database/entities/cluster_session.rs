@@ -0,0 +1,23 @@
+//! Cluster session entity — which node holds a charge point's WebSocket
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "cluster_sessions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub charge_point_id: String,
+
+    pub node_id: String,
+
+    /// Negotiated subprotocol, e.g. "ocpp1.6", "ocpp2.0.1".
+    pub ocpp_version: String,
+
+    pub connected_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,30 @@
+//! Connection session entity — one WebSocket connection of a station
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "connection_sessions")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    pub charge_point_id: String,
+
+    /// Negotiated OCPP version, e.g. "1.6", "2.0.1".
+    pub ocpp_version: String,
+
+    pub remote_addr: Option<String>,
+
+    pub connected_at: DateTimeUtc,
+
+    /// Null while the connection is open.
+    pub disconnected_at: Option<DateTimeUtc>,
+
+    pub disconnect_reason: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
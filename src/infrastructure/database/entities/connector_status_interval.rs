@@ -0,0 +1,40 @@
+//! Connector status interval entity — time a connector spent in one status
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "connector_status_intervals")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    pub charge_point_id: String,
+
+    pub connector_id: i32,
+
+    /// Status as reported by the station.
+    pub status: String,
+
+    pub error_code: Option<String>,
+
+    /// Free-text info reported with the status.
+    pub info: Option<String>,
+
+    pub vendor_id: Option<String>,
+
+    pub vendor_error_code: Option<String>,
+
+    /// Firmware the station ran when it reported the status.
+    pub firmware_version: Option<String>,
+
+    pub started_at: DateTimeUtc,
+
+    /// Null for the connector's current status.
+    pub ended_at: Option<DateTimeUtc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
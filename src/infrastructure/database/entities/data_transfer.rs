@@ -0,0 +1,50 @@
+//! DataTransfer entity
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "data_transfers")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    pub charge_point_id: String,
+
+    /// "Inbound" (from the station) or "Outbound" (to the station).
+    pub direction: String,
+
+    pub vendor_id: String,
+
+    #[sea_orm(nullable)]
+    pub message_id: Option<String>,
+
+    #[sea_orm(column_type = "Text", nullable)]
+    pub request_data: Option<String>,
+
+    /// Status answered to the request.
+    pub status: String,
+
+    #[sea_orm(column_type = "Text", nullable)]
+    pub response_data: Option<String>,
+
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::charge_point::Entity",
+        from = "Column::ChargePointId",
+        to = "super::charge_point::Column::Id"
+    )]
+    ChargePoint,
+}
+
+impl Related<super::charge_point::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChargePoint.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
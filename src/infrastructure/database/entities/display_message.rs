@@ -0,0 +1,71 @@
+//! DisplayMessage entity
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "display_messages")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    pub charge_point_id: String,
+
+    /// MessageInfo.id as known by the station.
+    pub message_id: i32,
+
+    /// MessagePriority: AlwaysFront, InFront, NormalCycle.
+    pub priority: String,
+
+    /// MessageState: Charging, Faulted, Idle, Unavailable (nullable).
+    #[sea_orm(nullable)]
+    pub state: Option<String>,
+
+    #[sea_orm(nullable)]
+    pub start_date_time: Option<DateTimeUtc>,
+
+    #[sea_orm(nullable)]
+    pub end_date_time: Option<DateTimeUtc>,
+
+    #[sea_orm(nullable)]
+    pub transaction_id: Option<String>,
+
+    /// MessageFormat: ASCII, HTML, URI, UTF8.
+    pub format: String,
+
+    #[sea_orm(nullable)]
+    pub language: Option<String>,
+
+    #[sea_orm(column_type = "Text")]
+    pub content: String,
+
+    /// Catalogue status: Accepted, Rejected, Cleared, Missing.
+    pub status: String,
+
+    #[sea_orm(nullable)]
+    pub status_reason: Option<String>,
+
+    #[sea_orm(nullable)]
+    pub last_reported_at: Option<DateTimeUtc>,
+
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::charge_point::Entity",
+        from = "Column::ChargePointId",
+        to = "super::charge_point::Column::Id"
+    )]
+    ChargePoint,
+}
+
+impl Related<super::charge_point::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChargePoint.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,74 @@
+//! EvChargingNeeds entity
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "ev_charging_needs")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    pub charge_point_id: String,
+
+    pub evse_id: i32,
+
+    #[sea_orm(nullable)]
+    pub transaction_id: Option<i32>,
+
+    /// `DC`, `AC_single_phase`, `AC_two_phase` or `AC_three_phase`.
+    pub energy_transfer_mode: String,
+
+    #[sea_orm(nullable)]
+    pub departure_time: Option<DateTimeUtc>,
+
+    #[sea_orm(nullable)]
+    pub energy_amount_wh: Option<i32>,
+
+    pub ev_max_current: i32,
+
+    pub ev_max_voltage: i32,
+
+    #[sea_orm(nullable)]
+    pub ev_max_power: Option<i32>,
+
+    #[sea_orm(nullable)]
+    pub state_of_charge: Option<i32>,
+
+    #[sea_orm(nullable)]
+    pub max_schedule_tuples: Option<i32>,
+
+    #[sea_orm(nullable)]
+    pub profile_id: Option<i32>,
+
+    #[sea_orm(nullable)]
+    pub profile_status: Option<String>,
+
+    #[sea_orm(column_type = "Text", nullable)]
+    pub schedule_json: Option<String>,
+
+    #[sea_orm(column_type = "Text", nullable)]
+    pub ev_schedule_json: Option<String>,
+
+    pub created_at: DateTimeUtc,
+
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::charge_point::Entity",
+        from = "Column::ChargePointId",
+        to = "super::charge_point::Column::Id"
+    )]
+    ChargePoint,
+}
+
+impl Related<super::charge_point::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChargePoint.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,21 +1,47 @@
 //! Database entities module
 
 pub mod api_key;
+pub mod archive_run;
+pub mod auth_event;
 pub mod charge_point;
 pub mod charging_profile;
+pub mod cluster_message;
+pub mod cluster_node;
+pub mod cluster_session;
+pub mod connection_session;
 pub mod connector;
+pub mod connector_status_interval;
+pub mod data_transfer;
+pub mod display_message;
+pub mod ev_charging_needs;
 pub mod id_tag;
+pub mod ocpi_party;
+pub mod ocpi_token;
 pub mod reservation;
 pub mod tariff;
 pub mod transaction;
 pub mod user;
+pub mod variable_monitor;
 
 pub use api_key::Entity as ApiKey;
+pub use archive_run::Entity as ArchiveRun;
+pub use auth_event::Entity as AuthEvent;
 pub use charge_point::Entity as ChargePoint;
 pub use charging_profile::Entity as ChargingProfile;
+pub use cluster_message::Entity as ClusterMessage;
+pub use cluster_node::Entity as ClusterNode;
+pub use cluster_session::Entity as ClusterSession;
+pub use connection_session::Entity as ConnectionSession;
 pub use connector::Entity as Connector;
+pub use connector_status_interval::Entity as ConnectorStatusInterval;
+pub use data_transfer::Entity as DataTransfer;
+pub use display_message::Entity as DisplayMessage;
+pub use ev_charging_needs::Entity as EvChargingNeeds;
 pub use id_tag::Entity as IdTag;
+pub use ocpi_party::Entity as OcpiParty;
+pub use ocpi_token::Entity as OcpiToken;
 pub use reservation::Entity as Reservation;
 pub use tariff::Entity as Tariff;
 pub use transaction::Entity as Transaction;
 pub use user::Entity as User;
+pub use variable_monitor::Entity as VariableMonitor;
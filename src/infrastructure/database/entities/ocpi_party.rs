@@ -0,0 +1,49 @@
+//! OCPI party entity
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "ocpi_parties")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    pub country_code: String,
+    pub party_id: String,
+
+    /// OCPI role: EMSP, HUB, NSP, ...
+    pub role: String,
+
+    pub name: String,
+
+    #[sea_orm(nullable)]
+    pub token_a: Option<String>,
+
+    #[sea_orm(nullable)]
+    pub token_b: Option<String>,
+
+    #[sea_orm(nullable)]
+    pub token_c: Option<String>,
+
+    #[sea_orm(nullable)]
+    pub versions_url: Option<String>,
+
+    #[sea_orm(nullable)]
+    pub version: Option<String>,
+
+    /// Partner endpoints as JSON array (identifier, role, url).
+    #[sea_orm(column_type = "Text", nullable)]
+    pub endpoints_json: Option<String>,
+
+    /// Pending, Registered, Unregistered
+    pub status: String,
+
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,43 @@
+//! OCPI token entity
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "ocpi_tokens")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    pub country_code: String,
+    pub party_id: String,
+    pub uid: String,
+
+    /// AD_HOC_USER, APP_USER, OTHER, RFID
+    pub token_type: String,
+
+    pub contract_id: String,
+
+    #[sea_orm(nullable)]
+    pub visual_number: Option<String>,
+
+    pub issuer: String,
+
+    #[sea_orm(nullable)]
+    pub group_id: Option<String>,
+
+    pub valid: bool,
+
+    /// ALWAYS, ALLOWED, ALLOWED_OFFLINE, NEVER
+    pub whitelist: String,
+
+    #[sea_orm(nullable)]
+    pub language: Option<String>,
+
+    pub last_updated: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
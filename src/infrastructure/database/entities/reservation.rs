@@ -16,9 +16,13 @@ pub struct Model {
     #[sea_orm(nullable)]
     pub parent_id_tag: Option<String>,
 
+    /// Start of a booked slot (None = starts immediately)
+    #[sea_orm(nullable)]
+    pub start_date: Option<DateTimeUtc>,
+
     pub expiry_date: DateTimeUtc,
 
-    /// Reservation status: Accepted, Cancelled, Expired, Used
+    /// Reservation status: Scheduled, Accepted, Cancelled, Expired, Used
     pub status: String,
 
     pub created_at: DateTimeUtc,
@@ -123,6 +123,10 @@ pub struct Model {
     /// Limit value (kWh for energy, smallest currency unit for amount, % for soc)
     #[sea_orm(nullable, column_type = "Double")]
     pub limit_value: Option<f64>,
+
+    /// Transaction id assigned by the station (OCPP 2.0.1)
+    #[sea_orm(nullable)]
+    pub station_transaction_id: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,66 @@
+//! VariableMonitor entity
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "variable_monitors")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    pub charge_point_id: String,
+
+    /// VariableMonitoring.id as known by the station.
+    pub monitor_id: i32,
+
+    pub component: String,
+
+    #[sea_orm(nullable)]
+    pub component_instance: Option<String>,
+
+    #[sea_orm(nullable)]
+    pub evse_id: Option<i32>,
+
+    #[sea_orm(nullable)]
+    pub connector_id: Option<i32>,
+
+    pub variable: String,
+
+    #[sea_orm(nullable)]
+    pub variable_instance: Option<String>,
+
+    /// MonitorEnumType: UpperThreshold, LowerThreshold, Delta, Periodic, PeriodicClockAligned.
+    pub monitor_type: String,
+
+    pub value: f64,
+
+    pub severity: i32,
+
+    pub transaction: bool,
+
+    /// Request ID of the NotifyMonitoringReport that last included the monitor.
+    pub request_id: i32,
+
+    pub last_reported_at: DateTimeUtc,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::charge_point::Entity",
+        from = "Column::ChargePointId",
+        to = "super::charge_point::Column::Id"
+    )]
+    ChargePoint,
+}
+
+impl Related<super::charge_point::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChargePoint.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,184 @@
+//! Create ocpi_parties and ocpi_tokens tables
+//!
+//! Stores OCPI roaming partners (credentials, endpoints) and the
+//! tokens eMSPs push to us via the Tokens receiver interface.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(OcpiParties::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OcpiParties::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(OcpiParties::CountryCode).string_len(2).not_null())
+                    .col(ColumnDef::new(OcpiParties::PartyId).string_len(3).not_null())
+                    .col(
+                        ColumnDef::new(OcpiParties::Role)
+                            .string()
+                            .not_null()
+                            .default("EMSP"),
+                    )
+                    .col(ColumnDef::new(OcpiParties::Name).string().not_null())
+                    .col(ColumnDef::new(OcpiParties::TokenA).string().null())
+                    .col(ColumnDef::new(OcpiParties::TokenB).string().null())
+                    .col(ColumnDef::new(OcpiParties::TokenC).string().null())
+                    .col(ColumnDef::new(OcpiParties::VersionsUrl).string().null())
+                    .col(ColumnDef::new(OcpiParties::Version).string().null())
+                    .col(ColumnDef::new(OcpiParties::EndpointsJson).text().null())
+                    .col(
+                        ColumnDef::new(OcpiParties::Status)
+                            .string()
+                            .not_null()
+                            .default("Pending"),
+                    )
+                    .col(
+                        ColumnDef::new(OcpiParties::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OcpiParties::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ocpi_parties_party")
+                    .table(OcpiParties::Table)
+                    .col(OcpiParties::CountryCode)
+                    .col(OcpiParties::PartyId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(OcpiTokens::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OcpiTokens::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(OcpiTokens::CountryCode).string_len(2).not_null())
+                    .col(ColumnDef::new(OcpiTokens::PartyId).string_len(3).not_null())
+                    .col(ColumnDef::new(OcpiTokens::Uid).string().not_null())
+                    .col(ColumnDef::new(OcpiTokens::TokenType).string().not_null())
+                    .col(ColumnDef::new(OcpiTokens::ContractId).string().not_null())
+                    .col(ColumnDef::new(OcpiTokens::VisualNumber).string().null())
+                    .col(ColumnDef::new(OcpiTokens::Issuer).string().not_null())
+                    .col(ColumnDef::new(OcpiTokens::GroupId).string().null())
+                    .col(
+                        ColumnDef::new(OcpiTokens::Valid)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(OcpiTokens::Whitelist)
+                            .string()
+                            .not_null()
+                            .default("ALLOWED"),
+                    )
+                    .col(ColumnDef::new(OcpiTokens::Language).string().null())
+                    .col(
+                        ColumnDef::new(OcpiTokens::LastUpdated)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ocpi_tokens_owner_uid")
+                    .table(OcpiTokens::Table)
+                    .col(OcpiTokens::CountryCode)
+                    .col(OcpiTokens::PartyId)
+                    .col(OcpiTokens::Uid)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ocpi_tokens_uid")
+                    .table(OcpiTokens::Table)
+                    .col(OcpiTokens::Uid)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OcpiTokens::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(OcpiParties::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum OcpiParties {
+    Table,
+    Id,
+    CountryCode,
+    PartyId,
+    Role,
+    Name,
+    TokenA,
+    TokenB,
+    TokenC,
+    VersionsUrl,
+    Version,
+    EndpointsJson,
+    Status,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+pub enum OcpiTokens {
+    Table,
+    Id,
+    CountryCode,
+    PartyId,
+    Uid,
+    TokenType,
+    ContractId,
+    VisualNumber,
+    Issuer,
+    GroupId,
+    Valid,
+    Whitelist,
+    Language,
+    LastUpdated,
+}
@@ -0,0 +1,145 @@
+//! Create display_messages table
+//!
+//! Catalogue of OCPP 2.0.1 display messages per station. Rows are written
+//! on SetDisplayMessage / ClearDisplayMessage and reconciled against
+//! NotifyDisplayMessages reports.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20240101_000001_create_charge_points::ChargePoints;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DisplayMessages::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DisplayMessages::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(DisplayMessages::ChargePointId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DisplayMessages::MessageId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DisplayMessages::Priority)
+                            .string()
+                            .not_null()
+                            .default("NormalCycle"),
+                    )
+                    .col(ColumnDef::new(DisplayMessages::State).string().null())
+                    .col(
+                        ColumnDef::new(DisplayMessages::StartDateTime)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(DisplayMessages::EndDateTime)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(DisplayMessages::TransactionId)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(DisplayMessages::Format)
+                            .string()
+                            .not_null()
+                            .default("UTF8"),
+                    )
+                    .col(ColumnDef::new(DisplayMessages::Language).string().null())
+                    .col(ColumnDef::new(DisplayMessages::Content).text().not_null())
+                    .col(
+                        ColumnDef::new(DisplayMessages::Status)
+                            .string()
+                            .not_null()
+                            .default("Accepted"),
+                    )
+                    .col(
+                        ColumnDef::new(DisplayMessages::StatusReason)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(DisplayMessages::LastReportedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(DisplayMessages::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DisplayMessages::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_display_messages_charge_point")
+                            .from(DisplayMessages::Table, DisplayMessages::ChargePointId)
+                            .to(ChargePoints::Table, ChargePoints::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_display_messages_cp_message")
+                    .table(DisplayMessages::Table)
+                    .col(DisplayMessages::ChargePointId)
+                    .col(DisplayMessages::MessageId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DisplayMessages::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum DisplayMessages {
+    Table,
+    Id,
+    ChargePointId,
+    MessageId,
+    Priority,
+    State,
+    StartDateTime,
+    EndDateTime,
+    TransactionId,
+    Format,
+    Language,
+    Content,
+    Status,
+    StatusReason,
+    LastReportedAt,
+    CreatedAt,
+    UpdatedAt,
+}
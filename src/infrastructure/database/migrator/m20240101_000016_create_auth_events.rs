@@ -0,0 +1,77 @@
+//! Create auth_events table
+//!
+//! One row per Authorize request handled, so a driver's authorization
+//! history can be exported and pseudonymised on request.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20240101_000001_create_charge_points::ChargePoints;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthEvents::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthEvents::ChargePointId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthEvents::IdTag).string().not_null())
+                    .col(ColumnDef::new(AuthEvents::Status).string().not_null())
+                    .col(
+                        ColumnDef::new(AuthEvents::OccurredAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_auth_events_charge_point")
+                            .from(AuthEvents::Table, AuthEvents::ChargePointId)
+                            .to(ChargePoints::Table, ChargePoints::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_auth_events_id_tag")
+                    .table(AuthEvents::Table)
+                    .col(AuthEvents::IdTag)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuthEvents::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum AuthEvents {
+    Table,
+    Id,
+    ChargePointId,
+    IdTag,
+    Status,
+    OccurredAt,
+}
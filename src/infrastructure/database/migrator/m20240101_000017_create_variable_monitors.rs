@@ -0,0 +1,152 @@
+//! Create variable_monitors table
+//!
+//! Monitors configured on OCPP 2.0.1 stations, as reported via
+//! NotifyMonitoringReport. Used to audit which monitors are actually active.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20240101_000001_create_charge_points::ChargePoints;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(VariableMonitors::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VariableMonitors::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(VariableMonitors::ChargePointId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VariableMonitors::MonitorId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VariableMonitors::Component)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VariableMonitors::ComponentInstance)
+                            .string()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(VariableMonitors::EvseId).integer().null())
+                    .col(
+                        ColumnDef::new(VariableMonitors::ConnectorId)
+                            .integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(VariableMonitors::Variable)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VariableMonitors::VariableInstance)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(VariableMonitors::MonitorType)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(VariableMonitors::Value).double().not_null())
+                    .col(
+                        ColumnDef::new(VariableMonitors::Severity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VariableMonitors::Transaction)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(VariableMonitors::RequestId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VariableMonitors::LastReportedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VariableMonitors::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VariableMonitors::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_variable_monitors_charge_point")
+                            .from(VariableMonitors::Table, VariableMonitors::ChargePointId)
+                            .to(ChargePoints::Table, ChargePoints::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_variable_monitors_cp_monitor")
+                    .table(VariableMonitors::Table)
+                    .col(VariableMonitors::ChargePointId)
+                    .col(VariableMonitors::MonitorId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(VariableMonitors::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum VariableMonitors {
+    Table,
+    Id,
+    ChargePointId,
+    MonitorId,
+    Component,
+    ComponentInstance,
+    EvseId,
+    ConnectorId,
+    Variable,
+    VariableInstance,
+    MonitorType,
+    Value,
+    Severity,
+    Transaction,
+    RequestId,
+    LastReportedAt,
+    CreatedAt,
+    UpdatedAt,
+}
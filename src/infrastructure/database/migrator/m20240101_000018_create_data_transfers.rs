@@ -0,0 +1,86 @@
+//! Create data_transfers table
+//!
+//! One row per vendor-specific DataTransfer exchange, in either direction,
+//! with the data sent and the answer received.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20240101_000001_create_charge_points::ChargePoints;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DataTransfers::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DataTransfers::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(DataTransfers::ChargePointId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(DataTransfers::Direction).string().not_null())
+                    .col(ColumnDef::new(DataTransfers::VendorId).string().not_null())
+                    .col(ColumnDef::new(DataTransfers::MessageId).string().null())
+                    .col(ColumnDef::new(DataTransfers::RequestData).text().null())
+                    .col(ColumnDef::new(DataTransfers::Status).string().not_null())
+                    .col(ColumnDef::new(DataTransfers::ResponseData).text().null())
+                    .col(
+                        ColumnDef::new(DataTransfers::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_data_transfers_charge_point")
+                            .from(DataTransfers::Table, DataTransfers::ChargePointId)
+                            .to(ChargePoints::Table, ChargePoints::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_data_transfers_charge_point_created")
+                    .table(DataTransfers::Table)
+                    .col(DataTransfers::ChargePointId)
+                    .col(DataTransfers::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DataTransfers::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum DataTransfers {
+    Table,
+    Id,
+    ChargePointId,
+    Direction,
+    VendorId,
+    MessageId,
+    RequestData,
+    Status,
+    ResponseData,
+    CreatedAt,
+}
@@ -0,0 +1,158 @@
+//! Create ev_charging_needs table
+//!
+//! One row per NotifyEVChargingNeeds from an ISO 15118 EV, with the
+//! TxProfile computed for it and the schedule the EV planned in return.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20240101_000001_create_charge_points::ChargePoints;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EvChargingNeeds::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::ChargePointId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(EvChargingNeeds::EvseId).integer().not_null())
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::TransactionId)
+                            .integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::EnergyTransferMode)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::DepartureTime)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::EnergyAmountWh)
+                            .integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::EvMaxCurrent)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::EvMaxVoltage)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(EvChargingNeeds::EvMaxPower).integer().null())
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::StateOfCharge)
+                            .integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::MaxScheduleTuples)
+                            .integer()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(EvChargingNeeds::ProfileId).integer().null())
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::ProfileStatus)
+                            .string()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(EvChargingNeeds::ScheduleJson).text().null())
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::EvScheduleJson)
+                            .text()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(EvChargingNeeds::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_ev_charging_needs_charge_point")
+                            .from(EvChargingNeeds::Table, EvChargingNeeds::ChargePointId)
+                            .to(ChargePoints::Table, ChargePoints::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ev_charging_needs_charge_point_evse")
+                    .table(EvChargingNeeds::Table)
+                    .col(EvChargingNeeds::ChargePointId)
+                    .col(EvChargingNeeds::EvseId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ev_charging_needs_transaction")
+                    .table(EvChargingNeeds::Table)
+                    .col(EvChargingNeeds::TransactionId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EvChargingNeeds::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum EvChargingNeeds {
+    Table,
+    Id,
+    ChargePointId,
+    EvseId,
+    TransactionId,
+    EnergyTransferMode,
+    DepartureTime,
+    EnergyAmountWh,
+    EvMaxCurrent,
+    EvMaxVoltage,
+    EvMaxPower,
+    StateOfCharge,
+    MaxScheduleTuples,
+    ProfileId,
+    ProfileStatus,
+    ScheduleJson,
+    EvScheduleJson,
+    CreatedAt,
+    UpdatedAt,
+}
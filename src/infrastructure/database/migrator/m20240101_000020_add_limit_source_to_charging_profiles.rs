@@ -0,0 +1,36 @@
+//! Add limit_source column to charging_profiles table
+//!
+//! External limits reported by stations (NotifyChargingLimit) are stored as
+//! charging profiles tagged with their source: EMS, SO or Other.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20240101_000013_create_charging_profiles::ChargingProfiles;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ChargingProfiles::Table)
+                    .add_column(ColumnDef::new(Alias::new("limit_source")).string())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ChargingProfiles::Table)
+                    .drop_column(Alias::new("limit_source"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
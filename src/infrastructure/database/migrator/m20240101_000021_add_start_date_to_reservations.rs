@@ -0,0 +1,36 @@
+//! Add start_date column to reservations table
+//!
+//! Reservations may book a future slot; ReserveNow is only sent shortly
+//! before `start_date`. Immediate reservations leave it empty.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20240101_000012_create_reservations::Reservations;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Reservations::Table)
+                    .add_column(ColumnDef::new(Alias::new("start_date")).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Reservations::Table)
+                    .drop_column(Alias::new("start_date"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
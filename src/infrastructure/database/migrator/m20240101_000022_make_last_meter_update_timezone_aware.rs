@@ -0,0 +1,43 @@
+//! Make transactions.last_meter_update timezone-aware on PostgreSQL
+//!
+//! `m20240101_000009` added the column as a plain timestamp. SQLite stores
+//! either kind as text, but PostgreSQL won't read `timestamp without time
+//! zone` into the entity's UTC datetime.
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DbBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        // Existing values were written as UTC
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE transactions ALTER COLUMN last_meter_update \
+                 TYPE TIMESTAMP WITH TIME ZONE USING last_meter_update AT TIME ZONE 'UTC'",
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE transactions ALTER COLUMN last_meter_update \
+                 TYPE TIMESTAMP USING last_meter_update AT TIME ZONE 'UTC'",
+            )
+            .await?;
+        Ok(())
+    }
+}
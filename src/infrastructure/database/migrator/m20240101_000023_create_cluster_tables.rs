@@ -0,0 +1,136 @@
+//! Create cluster tables
+//!
+//! - `cluster_nodes`: instances of the service and when they were last alive
+//! - `cluster_sessions`: which node holds each charge point's WebSocket
+//! - `cluster_messages`: bodies of node-to-node messages too large for a
+//!   PostgreSQL NOTIFY payload
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ClusterNodes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ClusterNodes::NodeId)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ClusterNodes::StartedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ClusterNodes::LastSeenAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ClusterSessions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ClusterSessions::ChargePointId)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ClusterSessions::NodeId).string().not_null())
+                    .col(
+                        ColumnDef::new(ClusterSessions::OcppVersion)
+                            .string_len(10)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ClusterSessions::ConnectedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_cluster_sessions_node")
+                    .table(ClusterSessions::Table)
+                    .col(ClusterSessions::NodeId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ClusterMessages::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ClusterMessages::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ClusterMessages::Body).text().not_null())
+                    .col(
+                        ColumnDef::new(ClusterMessages::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ClusterMessages::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(ClusterSessions::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(ClusterNodes::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum ClusterNodes {
+    Table,
+    NodeId,
+    StartedAt,
+    LastSeenAt,
+}
+
+#[derive(Iden)]
+pub enum ClusterSessions {
+    Table,
+    ChargePointId,
+    NodeId,
+    OcppVersion,
+    ConnectedAt,
+}
+
+#[derive(Iden)]
+pub enum ClusterMessages {
+    Table,
+    Id,
+    Body,
+    CreatedAt,
+}
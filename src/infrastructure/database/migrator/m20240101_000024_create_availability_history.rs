@@ -0,0 +1,169 @@
+//! Create availability history tables
+//!
+//! - `connection_sessions`: one row per WebSocket connection of a station
+//! - `connector_status_intervals`: how long each connector stayed in each
+//!   reported status
+//!
+//! No foreign keys to `charge_points`: a station connects before its
+//! BootNotification registers it.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ConnectionSessions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ConnectionSessions::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ConnectionSessions::ChargePointId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ConnectionSessions::OcppVersion)
+                            .string_len(10)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ConnectionSessions::RemoteAddr)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(ConnectionSessions::ConnectedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ConnectionSessions::DisconnectedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(ConnectionSessions::DisconnectReason)
+                            .string()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_connection_sessions_cp_connected")
+                    .table(ConnectionSessions::Table)
+                    .col(ConnectionSessions::ChargePointId)
+                    .col(ConnectionSessions::ConnectedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ConnectorStatusIntervals::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ConnectorStatusIntervals::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ConnectorStatusIntervals::ChargePointId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ConnectorStatusIntervals::ConnectorId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ConnectorStatusIntervals::Status)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ConnectorStatusIntervals::ErrorCode)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(ConnectorStatusIntervals::StartedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ConnectorStatusIntervals::EndedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_connector_status_intervals_cp_started")
+                    .table(ConnectorStatusIntervals::Table)
+                    .col(ConnectorStatusIntervals::ChargePointId)
+                    .col(ConnectorStatusIntervals::StartedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(ConnectorStatusIntervals::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(ConnectionSessions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum ConnectionSessions {
+    Table,
+    Id,
+    ChargePointId,
+    OcppVersion,
+    RemoteAddr,
+    ConnectedAt,
+    DisconnectedAt,
+    DisconnectReason,
+}
+
+#[derive(Iden)]
+pub enum ConnectorStatusIntervals {
+    Table,
+    Id,
+    ChargePointId,
+    ConnectorId,
+    Status,
+    ErrorCode,
+    StartedAt,
+    EndedAt,
+}
@@ -0,0 +1,58 @@
+//! Add fault details to connector_status_intervals table
+//!
+//! Keeps what the station reported with each status (info, vendor id and
+//! vendor error code) and the firmware it was running, for fault analytics.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20240101_000024_create_availability_history::ConnectorStatusIntervals;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const COLUMNS: [FaultDetails; 4] = [
+    FaultDetails::Info,
+    FaultDetails::VendorId,
+    FaultDetails::VendorErrorCode,
+    FaultDetails::FirmwareVersion,
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One column per statement: SQLite cannot add several at once
+        for column in COLUMNS {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(ConnectorStatusIntervals::Table)
+                        .add_column(ColumnDef::new(column).string().null())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in COLUMNS {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(ConnectorStatusIntervals::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden, Clone, Copy)]
+enum FaultDetails {
+    Info,
+    VendorId,
+    VendorErrorCode,
+    FirmwareVersion,
+}
@@ -0,0 +1,87 @@
+//! Create `archive_runs` table
+//!
+//! One row per data retention run: when it ran, what triggered it and,
+//! per data class, how many rows went to which archive file.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ArchiveRuns::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ArchiveRuns::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ArchiveRuns::TriggeredBy)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ArchiveRuns::Status)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ArchiveRuns::StartedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ArchiveRuns::FinishedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(ArchiveRuns::RowsArchived)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(ArchiveRuns::Details).text().not_null())
+                    .col(ColumnDef::new(ArchiveRuns::Error).text().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_archive_runs_started_at")
+                    .table(ArchiveRuns::Table)
+                    .col(ArchiveRuns::StartedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ArchiveRuns::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum ArchiveRuns {
+    Table,
+    Id,
+    TriggeredBy,
+    Status,
+    StartedAt,
+    FinishedAt,
+    RowsArchived,
+    Details,
+    Error,
+}
@@ -0,0 +1,36 @@
+//! Add heartbeat_interval column to charge_points table
+//!
+//! The interval each station last accepted, from its BootNotification
+//! response or a later configuration change. Offline thresholds follow it.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20240101_000001_create_charge_points::ChargePoints;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ChargePoints::Table)
+                    .add_column(ColumnDef::new(Alias::new("heartbeat_interval")).integer().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ChargePoints::Table)
+                    .drop_column(Alias::new("heartbeat_interval"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
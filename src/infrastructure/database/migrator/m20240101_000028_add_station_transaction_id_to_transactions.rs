@@ -0,0 +1,40 @@
+//! Add station_transaction_id column to transactions table
+//!
+//! OCPP 2.0.1 stations name transactions themselves; the `transactionId`
+//! they sent is kept so later requests (TxProfiles) can refer to it.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20240101_000003_create_transactions::Transactions;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .add_column(
+                        ColumnDef::new(Alias::new("station_transaction_id"))
+                            .string_len(36)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transactions::Table)
+                    .drop_column(Alias::new("station_transaction_id"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
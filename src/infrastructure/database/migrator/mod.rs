@@ -15,6 +15,22 @@ mod m20240101_000010_add_ocpp_version_to_charge_points;
 mod m20240101_000011_add_password_to_charge_points;
 mod m20240101_000012_create_reservations;
 mod m20240101_000013_create_charging_profiles;
+mod m20240101_000014_create_ocpi_tables;
+mod m20240101_000015_create_display_messages;
+mod m20240101_000016_create_auth_events;
+mod m20240101_000017_create_variable_monitors;
+mod m20240101_000018_create_data_transfers;
+mod m20240101_000019_create_ev_charging_needs;
+mod m20240101_000020_add_limit_source_to_charging_profiles;
+mod m20240101_000021_add_start_date_to_reservations;
+mod m20240101_000022_make_last_meter_update_timezone_aware;
+mod m20240101_000023_create_cluster_tables;
+mod m20240101_000024_create_availability_history;
+mod m20240101_000025_add_fault_details_to_connector_status_intervals;
+mod m20240101_000026_create_archive_runs;
+mod m20240101_000027_add_heartbeat_interval_to_charge_points;
+mod m20240101_000028_add_station_transaction_id_to_transactions;
+mod postgres;
 
 pub struct Migrator;
 
@@ -28,13 +44,29 @@ impl MigratorTrait for Migrator {
             Box::new(m20240101_000004_create_users::Migration),
             Box::new(m20240101_000005_create_api_keys::Migration),
             Box::new(m20240101_000006_create_id_tags::Migration),
-            Box::new(m20240101_000007_create_tariffs::Migration),
+            // Runs m20240101_000007_create_tariffs, or its PostgreSQL variant
+            Box::new(postgres::CreateTariffs),
             Box::new(m20240101_000008_add_billing_to_transactions::Migration),
             Box::new(m20240101_000009_add_meter_data_to_transactions::Migration),
             Box::new(m20240101_000010_add_ocpp_version_to_charge_points::Migration),
             Box::new(m20240101_000011_add_password_to_charge_points::Migration),
             Box::new(m20240101_000012_create_reservations::Migration),
             Box::new(m20240101_000013_create_charging_profiles::Migration),
+            Box::new(m20240101_000014_create_ocpi_tables::Migration),
+            Box::new(m20240101_000015_create_display_messages::Migration),
+            Box::new(m20240101_000016_create_auth_events::Migration),
+            Box::new(m20240101_000017_create_variable_monitors::Migration),
+            Box::new(m20240101_000018_create_data_transfers::Migration),
+            Box::new(m20240101_000019_create_ev_charging_needs::Migration),
+            Box::new(m20240101_000020_add_limit_source_to_charging_profiles::Migration),
+            Box::new(m20240101_000021_add_start_date_to_reservations::Migration),
+            Box::new(m20240101_000022_make_last_meter_update_timezone_aware::Migration),
+            Box::new(m20240101_000023_create_cluster_tables::Migration),
+            Box::new(m20240101_000024_create_availability_history::Migration),
+            Box::new(m20240101_000025_add_fault_details_to_connector_status_intervals::Migration),
+            Box::new(m20240101_000026_create_archive_runs::Migration),
+            Box::new(m20240101_000027_add_heartbeat_interval_to_charge_points::Migration),
+            Box::new(m20240101_000028_add_station_transaction_id_to_transactions::Migration),
         ]
     }
 }
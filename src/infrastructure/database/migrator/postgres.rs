@@ -0,0 +1,92 @@
+//! PostgreSQL variants of shipped migrations
+//!
+//! Some migrations shipped before PostgreSQL was supported run SQL that only
+//! SQLite accepts. They stay untouched; on PostgreSQL the variants below run
+//! in their place under the same name, so a database records the same
+//! migration history on either backend.
+
+use sea_orm_migration::prelude::*;
+
+use super::m20240101_000007_create_tariffs as create_tariffs;
+
+/// `m20240101_000007_create_tariffs`, whose default tariff binds its
+/// timestamps as text: PostgreSQL won't assign a text parameter to a
+/// timestamptz column. Its statements are replayed with the timestamps
+/// rebound, so the table keeps a single definition.
+pub struct CreateTariffs;
+
+impl MigrationName for CreateTariffs {
+    fn name(&self) -> &str {
+        create_tariffs::Migration.name()
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for CreateTariffs {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        #[cfg(feature = "postgres")]
+        if manager.get_database_backend() == sea_orm::DbBackend::Postgres {
+            return replay_with_timestamps(&create_tariffs::Migration, manager).await;
+        }
+        create_tariffs::Migration.up(manager).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        create_tariffs::Migration.down(manager).await
+    }
+}
+
+/// Run `migration` on PostgreSQL with its RFC 3339 text values bound as
+/// timestamps. Its statements are first recorded through a proxy
+/// connection, then executed for real.
+#[cfg(feature = "postgres")]
+async fn replay_with_timestamps(
+    migration: &dyn MigrationTrait,
+    manager: &SchemaManager<'_>,
+) -> Result<(), DbErr> {
+    use sea_orm::{ConnectionTrait, Database, DbBackend, Value};
+
+    let recorder = StatementRecorder::default();
+    let proxy = Database::connect_proxy(
+        DbBackend::Postgres,
+        std::sync::Arc::new(Box::new(recorder.clone())),
+    )
+    .await?;
+    migration.up(&SchemaManager::new(&proxy)).await?;
+
+    let statements = std::mem::take(&mut *recorder.0.lock().unwrap());
+    for mut statement in statements {
+        for value in statement.values.iter_mut().flat_map(|v| v.0.iter_mut()) {
+            let timestamp = match value {
+                Value::String(Some(text)) => chrono::DateTime::parse_from_rfc3339(text).ok(),
+                _ => None,
+            };
+            if let Some(timestamp) = timestamp {
+                *value = Value::ChronoDateTimeWithTimeZone(Some(Box::new(timestamp)));
+            }
+        }
+        manager.get_connection().execute(statement).await?;
+    }
+    Ok(())
+}
+
+/// Collects the statements a migration executes without running them.
+#[cfg(feature = "postgres")]
+#[derive(Debug, Default, Clone)]
+struct StatementRecorder(std::sync::Arc<std::sync::Mutex<Vec<sea_orm::Statement>>>);
+
+#[cfg(feature = "postgres")]
+#[async_trait::async_trait]
+impl sea_orm::ProxyDatabaseTrait for StatementRecorder {
+    async fn query(&self, _statement: sea_orm::Statement) -> Result<Vec<sea_orm::ProxyRow>, DbErr> {
+        Ok(Vec::new())
+    }
+
+    async fn execute(
+        &self,
+        statement: sea_orm::Statement,
+    ) -> Result<sea_orm::ProxyExecResult, DbErr> {
+        self.0.lock().unwrap().push(statement);
+        Ok(sea_orm::ProxyExecResult::default())
+    }
+}
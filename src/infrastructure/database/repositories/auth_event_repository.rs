@@ -0,0 +1,76 @@
+//! SeaORM implementation of AuthEventRepository
+
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+
+use crate::domain::auth_event::{AuthEvent, AuthEventRepository};
+use crate::domain::{DomainError, DomainResult};
+use crate::infrastructure::database::entities::auth_event;
+
+pub struct SeaOrmAuthEventRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmAuthEventRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+// ── Conversion helpers ──────────────────────────────────────────
+
+fn model_to_domain(m: auth_event::Model) -> AuthEvent {
+    AuthEvent {
+        id: m.id,
+        charge_point_id: m.charge_point_id,
+        id_tag: m.id_tag,
+        status: m.status,
+        occurred_at: m.occurred_at,
+    }
+}
+
+fn db_err(e: sea_orm::DbErr) -> DomainError {
+    DomainError::Validation(format!("Database error: {}", e))
+}
+
+// ── AuthEventRepository impl ────────────────────────────────────
+
+#[async_trait]
+impl AuthEventRepository for SeaOrmAuthEventRepository {
+    async fn record(&self, event: AuthEvent) -> DomainResult<()> {
+        let model = auth_event::ActiveModel {
+            id: Default::default(), // auto-increment
+            charge_point_id: Set(event.charge_point_id),
+            id_tag: Set(event.id_tag),
+            status: Set(event.status),
+            occurred_at: Set(event.occurred_at),
+        };
+        model.insert(&self.db).await.map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn find_by_id_tag(&self, id_tag: &str) -> DomainResult<Vec<AuthEvent>> {
+        let models = auth_event::Entity::find()
+            .filter(auth_event::Column::IdTag.eq(id_tag))
+            .order_by_desc(auth_event::Column::OccurredAt)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn replace_id_tag(&self, id_tag: &str, replacement: &str) -> DomainResult<u64> {
+        let result = auth_event::Entity::update_many()
+            .col_expr(
+                auth_event::Column::IdTag,
+                sea_orm::sea_query::Expr::value(replacement),
+            )
+            .filter(auth_event::Column::IdTag.eq(id_tag))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,243 @@
+//! SeaORM implementation of AvailabilityRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+
+use crate::domain::availability::{
+    AvailabilityRepository, ConnectionSession, ConnectorStatusInterval,
+};
+use crate::domain::{DomainError, DomainResult};
+use crate::infrastructure::database::entities::{connection_session, connector_status_interval};
+
+pub struct SeaOrmAvailabilityRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmAvailabilityRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+// ── Conversion helpers ──────────────────────────────────────────
+
+fn session_to_domain(m: connection_session::Model) -> ConnectionSession {
+    ConnectionSession {
+        id: m.id,
+        charge_point_id: m.charge_point_id,
+        ocpp_version: m.ocpp_version,
+        remote_addr: m.remote_addr,
+        connected_at: m.connected_at,
+        disconnected_at: m.disconnected_at,
+        disconnect_reason: m.disconnect_reason,
+    }
+}
+
+fn interval_to_domain(m: connector_status_interval::Model) -> ConnectorStatusInterval {
+    ConnectorStatusInterval {
+        id: m.id,
+        charge_point_id: m.charge_point_id,
+        connector_id: m.connector_id as u32,
+        status: m.status,
+        error_code: m.error_code,
+        info: m.info,
+        vendor_id: m.vendor_id,
+        vendor_error_code: m.vendor_error_code,
+        firmware_version: m.firmware_version,
+        started_at: m.started_at,
+        ended_at: m.ended_at,
+    }
+}
+
+fn db_err(e: sea_orm::DbErr) -> DomainError {
+    DomainError::Validation(format!("Database error: {}", e))
+}
+
+// ── AvailabilityRepository impl ─────────────────────────────────
+
+#[async_trait]
+impl AvailabilityRepository for SeaOrmAvailabilityRepository {
+    async fn open_session(&self, session: ConnectionSession) -> DomainResult<ConnectionSession> {
+        let model = connection_session::ActiveModel {
+            id: Default::default(), // auto-increment
+            charge_point_id: Set(session.charge_point_id),
+            ocpp_version: Set(session.ocpp_version),
+            remote_addr: Set(session.remote_addr),
+            connected_at: Set(session.connected_at),
+            disconnected_at: Set(session.disconnected_at),
+            disconnect_reason: Set(session.disconnect_reason),
+        };
+        let saved = model.insert(&self.db).await.map_err(db_err)?;
+        Ok(session_to_domain(saved))
+    }
+
+    async fn close_session(
+        &self,
+        id: i32,
+        disconnected_at: DateTime<Utc>,
+        reason: Option<String>,
+    ) -> DomainResult<()> {
+        connection_session::Entity::update_many()
+            .col_expr(
+                connection_session::Column::DisconnectedAt,
+                Expr::value(disconnected_at),
+            )
+            .col_expr(
+                connection_session::Column::DisconnectReason,
+                Expr::value(reason),
+            )
+            .filter(connection_session::Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn find_open_sessions(
+        &self,
+        charge_point_id: Option<&str>,
+    ) -> DomainResult<Vec<ConnectionSession>> {
+        let mut query = connection_session::Entity::find()
+            .filter(connection_session::Column::DisconnectedAt.is_null());
+        if let Some(cp_id) = charge_point_id {
+            query = query.filter(connection_session::Column::ChargePointId.eq(cp_id));
+        }
+        let models = query
+            .order_by_asc(connection_session::Column::ConnectedAt)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(session_to_domain).collect())
+    }
+
+    async fn find_sessions(
+        &self,
+        charge_point_id: Option<&str>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<ConnectionSession>> {
+        let mut query = connection_session::Entity::find().filter(
+            Condition::all()
+                .add(connection_session::Column::ConnectedAt.lt(to))
+                .add(
+                    Condition::any()
+                        .add(connection_session::Column::DisconnectedAt.is_null())
+                        .add(connection_session::Column::DisconnectedAt.gt(from)),
+                ),
+        );
+        if let Some(cp_id) = charge_point_id {
+            query = query.filter(connection_session::Column::ChargePointId.eq(cp_id));
+        }
+        let models = query
+            .order_by_asc(connection_session::Column::ConnectedAt)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(session_to_domain).collect())
+    }
+
+    async fn record_connector_status(&self, interval: ConnectorStatusInterval) -> DomainResult<()> {
+        let current = connector_status_interval::Entity::find()
+            .filter(connector_status_interval::Column::ChargePointId.eq(&interval.charge_point_id))
+            .filter(connector_status_interval::Column::ConnectorId.eq(interval.connector_id as i32))
+            .filter(connector_status_interval::Column::EndedAt.is_null())
+            .order_by_desc(connector_status_interval::Column::StartedAt)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+
+        if current
+            .first()
+            .is_some_and(|c| interval.same_condition(&interval_to_domain(c.clone())))
+        {
+            return Ok(());
+        }
+
+        for open in current {
+            // A late report must not end an interval before it started
+            let ended_at = interval.started_at.max(open.started_at);
+            connector_status_interval::Entity::update_many()
+                .col_expr(
+                    connector_status_interval::Column::EndedAt,
+                    Expr::value(ended_at),
+                )
+                .filter(connector_status_interval::Column::Id.eq(open.id))
+                .exec(&self.db)
+                .await
+                .map_err(db_err)?;
+        }
+
+        let model = connector_status_interval::ActiveModel {
+            id: Default::default(), // auto-increment
+            charge_point_id: Set(interval.charge_point_id),
+            connector_id: Set(interval.connector_id as i32),
+            status: Set(interval.status),
+            error_code: Set(interval.error_code),
+            info: Set(interval.info),
+            vendor_id: Set(interval.vendor_id),
+            vendor_error_code: Set(interval.vendor_error_code),
+            firmware_version: Set(interval.firmware_version),
+            started_at: Set(interval.started_at),
+            ended_at: Set(interval.ended_at),
+        };
+        model.insert(&self.db).await.map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn find_status_intervals(
+        &self,
+        charge_point_id: Option<&str>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<ConnectorStatusInterval>> {
+        let mut query = connector_status_interval::Entity::find().filter(
+            Condition::all()
+                .add(connector_status_interval::Column::StartedAt.lt(to))
+                .add(
+                    Condition::any()
+                        .add(connector_status_interval::Column::EndedAt.is_null())
+                        .add(connector_status_interval::Column::EndedAt.gt(from)),
+                ),
+        );
+        if let Some(cp_id) = charge_point_id {
+            query = query.filter(connector_status_interval::Column::ChargePointId.eq(cp_id));
+        }
+        let models = query
+            .order_by_asc(connector_status_interval::Column::StartedAt)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(interval_to_domain).collect())
+    }
+
+    async fn find_faults(
+        &self,
+        charge_point_id: Option<&str>,
+        connector_id: Option<u32>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<ConnectorStatusInterval>> {
+        let mut query = connector_status_interval::Entity::find()
+            .filter(connector_status_interval::Column::Status.eq("Faulted"))
+            .filter(connector_status_interval::Column::StartedAt.gte(from))
+            .filter(connector_status_interval::Column::StartedAt.lt(to));
+        if let Some(cp_id) = charge_point_id {
+            query = query.filter(connector_status_interval::Column::ChargePointId.eq(cp_id));
+        }
+        if let Some(connector_id) = connector_id {
+            query = query
+                .filter(connector_status_interval::Column::ConnectorId.eq(connector_id as i32));
+        }
+        let models = query
+            .order_by_asc(connector_status_interval::Column::StartedAt)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(interval_to_domain).collect())
+    }
+}
@@ -4,8 +4,8 @@ use async_trait::async_trait;
 use chrono::Utc;
 use log::{debug, info};
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::NotSet, ColumnTrait, DatabaseConnection, EntityTrait,
-    QueryFilter, Set,
+    ActiveModelTrait, ActiveValue::NotSet, ColumnTrait, ConnectionTrait, DatabaseConnection,
+    EntityTrait, QueryFilter, Set, TransactionTrait,
 };
 
 use crate::domain::charge_point::{
@@ -117,6 +117,7 @@ fn cp_from_model(model: charge_point::Model, connectors: Vec<Connector>) -> Char
         connectors,
         registered_at: model.registered_at,
         last_heartbeat: model.last_heartbeat,
+        heartbeat_interval: model.heartbeat_interval,
     }
 }
 
@@ -132,8 +133,8 @@ async fn load_connectors(
     Ok(connectors_from_models(models))
 }
 
-async fn save_connectors(
-    db: &DatabaseConnection,
+async fn save_connectors<C: ConnectionTrait>(
+    db: &C,
     charge_point_id: &str,
     connectors: &[Connector],
 ) -> DomainResult<()> {
@@ -154,7 +155,7 @@ async fn save_connectors(
     Ok(())
 }
 
-async fn delete_connectors(db: &DatabaseConnection, charge_point_id: &str) -> DomainResult<()> {
+async fn delete_connectors<C: ConnectionTrait>(db: &C, charge_point_id: &str) -> DomainResult<()> {
     connector::Entity::delete_many()
         .filter(connector::Column::ChargePointId.eq(charge_point_id))
         .exec(db)
@@ -163,48 +164,114 @@ async fn delete_connectors(db: &DatabaseConnection, charge_point_id: &str) -> Do
     Ok(())
 }
 
+/// Insert a new charge point with its connectors on `db`.
+async fn insert_charge_point<C: ConnectionTrait>(db: &C, cp: ChargePoint) -> DomainResult<()> {
+    debug!("Saving charge point: {}", cp.id);
+
+    let existing = charge_point::Entity::find_by_id(&cp.id)
+        .one(db)
+        .await
+        .map_err(db_err)?;
+
+    if existing.is_some() {
+        return Err(DomainError::Conflict(format!(
+            "Charge point '{}' already exists",
+            cp.id
+        )));
+    }
+
+    let model = charge_point::ActiveModel {
+        id: Set(cp.id.clone()),
+        vendor: Set(cp.vendor.unwrap_or_default()),
+        model: Set(cp.model.unwrap_or_default()),
+        serial_number: Set(cp.serial_number),
+        firmware_version: Set(cp.firmware_version),
+        iccid: Set(cp.iccid),
+        imsi: Set(cp.imsi),
+        ocpp_version: Set(cp.ocpp_version.as_ref().map(ocpp_version_to_string)),
+        meter_type: Set(cp.meter_type),
+        meter_serial_number: Set(cp.meter_serial_number),
+        password_hash: Set(cp.password_hash),
+        status: Set(status_to_string(&cp.status)),
+        last_heartbeat: Set(cp.last_heartbeat),
+        heartbeat_interval: Set(cp.heartbeat_interval),
+        registered_at: Set(cp.registered_at),
+        updated_at: Set(Some(Utc::now())),
+    };
+    model.insert(db).await.map_err(db_err)?;
+
+    save_connectors(db, &cp.id, &cp.connectors).await?;
+
+    info!("Charge point saved: {}", cp.id);
+    Ok(())
+}
+
+/// Overwrite a stored charge point and its connectors on `db`.
+async fn update_charge_point<C: ConnectionTrait>(db: &C, cp: ChargePoint) -> DomainResult<()> {
+    debug!("Updating charge point: {}", cp.id);
+
+    let existing = charge_point::Entity::find_by_id(&cp.id)
+        .one(db)
+        .await
+        .map_err(db_err)?;
+
+    if existing.is_none() {
+        return Err(DomainError::NotFound {
+            entity: "ChargePoint",
+            field: "id",
+            value: cp.id,
+        });
+    }
+
+    let model = charge_point::ActiveModel {
+        id: Set(cp.id.clone()),
+        vendor: Set(cp.vendor.unwrap_or_default()),
+        model: Set(cp.model.unwrap_or_default()),
+        serial_number: Set(cp.serial_number),
+        firmware_version: Set(cp.firmware_version),
+        iccid: Set(cp.iccid),
+        imsi: Set(cp.imsi),
+        ocpp_version: Set(cp.ocpp_version.as_ref().map(ocpp_version_to_string)),
+        meter_type: Set(cp.meter_type),
+        meter_serial_number: Set(cp.meter_serial_number),
+        password_hash: Set(cp.password_hash),
+        status: Set(status_to_string(&cp.status)),
+        last_heartbeat: Set(cp.last_heartbeat),
+        heartbeat_interval: Set(cp.heartbeat_interval),
+        registered_at: Set(cp.registered_at),
+        updated_at: Set(Some(Utc::now())),
+    };
+    model.update(db).await.map_err(db_err)?;
+
+    delete_connectors(db, &cp.id).await?;
+    save_connectors(db, &cp.id, &cp.connectors).await?;
+
+    Ok(())
+}
+
 // ── ChargePointRepository impl ──────────────────────────────────
 
 #[async_trait]
 impl ChargePointRepository for SeaOrmChargePointRepository {
     async fn save(&self, cp: ChargePoint) -> DomainResult<()> {
-        debug!("Saving charge point: {}", cp.id);
-
-        let existing = charge_point::Entity::find_by_id(&cp.id)
-            .one(&self.db)
-            .await
-            .map_err(db_err)?;
+        insert_charge_point(&self.db, cp).await
+    }
 
-        if existing.is_some() {
-            return Err(DomainError::Conflict(format!(
-                "Charge point '{}' already exists",
-                cp.id
-            )));
+    async fn save_all(&self, charge_points: Vec<ChargePoint>) -> DomainResult<()> {
+        let txn = self.db.begin().await.map_err(db_err)?;
+        for cp in charge_points {
+            let exists = charge_point::Entity::find_by_id(&cp.id)
+                .one(&txn)
+                .await
+                .map_err(db_err)?
+                .is_some();
+            if exists {
+                update_charge_point(&txn, cp).await?;
+            } else {
+                insert_charge_point(&txn, cp).await?;
+            }
         }
-
-        let model = charge_point::ActiveModel {
-            id: Set(cp.id.clone()),
-            vendor: Set(cp.vendor.unwrap_or_default()),
-            model: Set(cp.model.unwrap_or_default()),
-            serial_number: Set(cp.serial_number),
-            firmware_version: Set(cp.firmware_version),
-            iccid: Set(cp.iccid),
-            imsi: Set(cp.imsi),
-            ocpp_version: Set(cp.ocpp_version.as_ref().map(ocpp_version_to_string)),
-            meter_type: Set(cp.meter_type),
-            meter_serial_number: Set(cp.meter_serial_number),
-            password_hash: Set(cp.password_hash),
-            status: Set(status_to_string(&cp.status)),
-            last_heartbeat: Set(cp.last_heartbeat),
-            registered_at: Set(cp.registered_at),
-            updated_at: Set(Some(Utc::now())),
-        };
-        model.insert(&self.db).await.map_err(db_err)?;
-
-        save_connectors(&self.db, &cp.id, &cp.connectors).await?;
-
-        info!("Charge point saved: {}", cp.id);
-        Ok(())
+        txn.commit().await.map_err(db_err)
     }
 
     async fn find_by_id(&self, id: &str) -> DomainResult<Option<ChargePoint>> {
@@ -236,44 +303,7 @@ impl ChargePointRepository for SeaOrmChargePointRepository {
     }
 
     async fn update(&self, cp: ChargePoint) -> DomainResult<()> {
-        debug!("Updating charge point: {}", cp.id);
-
-        let existing = charge_point::Entity::find_by_id(&cp.id)
-            .one(&self.db)
-            .await
-            .map_err(db_err)?;
-
-        if existing.is_none() {
-            return Err(DomainError::NotFound {
-                entity: "ChargePoint",
-                field: "id",
-                value: cp.id,
-            });
-        }
-
-        let model = charge_point::ActiveModel {
-            id: Set(cp.id.clone()),
-            vendor: Set(cp.vendor.unwrap_or_default()),
-            model: Set(cp.model.unwrap_or_default()),
-            serial_number: Set(cp.serial_number),
-            firmware_version: Set(cp.firmware_version),
-            iccid: Set(cp.iccid),
-            imsi: Set(cp.imsi),
-            ocpp_version: Set(cp.ocpp_version.as_ref().map(ocpp_version_to_string)),
-            meter_type: Set(cp.meter_type),
-            meter_serial_number: Set(cp.meter_serial_number),
-            password_hash: Set(cp.password_hash),
-            status: Set(status_to_string(&cp.status)),
-            last_heartbeat: Set(cp.last_heartbeat),
-            registered_at: Set(cp.registered_at),
-            updated_at: Set(Some(Utc::now())),
-        };
-        model.update(&self.db).await.map_err(db_err)?;
-
-        delete_connectors(&self.db, &cp.id).await?;
-        save_connectors(&self.db, &cp.id, &cp.connectors).await?;
-
-        Ok(())
+        update_charge_point(&self.db, cp).await
     }
 
     async fn update_status(&self, id: &str, status: ChargePointStatus) -> DomainResult<()> {
@@ -307,6 +337,7 @@ impl ChargePointRepository for SeaOrmChargePointRepository {
             meter_serial_number: NotSet,
             password_hash: NotSet,
             last_heartbeat: NotSet,
+            heartbeat_interval: NotSet,
             registered_at: NotSet,
         };
         model.update(&self.db).await.map_err(db_err)?;
@@ -360,6 +391,7 @@ impl ChargePointRepository for SeaOrmChargePointRepository {
             meter_serial_number: NotSet,
             status: NotSet,
             last_heartbeat: NotSet,
+            heartbeat_interval: NotSet,
             registered_at: NotSet,
         };
         model.update(&self.db).await.map_err(db_err)?;
@@ -367,4 +399,45 @@ impl ChargePointRepository for SeaOrmChargePointRepository {
         info!("Charge point {} password hash updated", id);
         Ok(())
     }
+
+    async fn set_heartbeat_interval(&self, id: &str, interval: Option<i32>) -> DomainResult<()> {
+        let existing = charge_point::Entity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(db_err)?;
+
+        if existing.is_none() {
+            return Err(DomainError::NotFound {
+                entity: "ChargePoint",
+                field: "id",
+                value: id.to_string(),
+            });
+        }
+
+        let model = charge_point::ActiveModel {
+            id: Set(id.to_string()),
+            heartbeat_interval: Set(interval),
+            updated_at: Set(Some(Utc::now())),
+            vendor: NotSet,
+            model: NotSet,
+            serial_number: NotSet,
+            firmware_version: NotSet,
+            iccid: NotSet,
+            imsi: NotSet,
+            ocpp_version: NotSet,
+            meter_type: NotSet,
+            meter_serial_number: NotSet,
+            password_hash: NotSet,
+            status: NotSet,
+            last_heartbeat: NotSet,
+            registered_at: NotSet,
+        };
+        model.update(&self.db).await.map_err(db_err)?;
+
+        debug!(
+            "Charge point {} heartbeat interval set to {:?}",
+            id, interval
+        );
+        Ok(())
+    }
 }
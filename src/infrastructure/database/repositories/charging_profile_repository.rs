@@ -38,6 +38,7 @@ fn model_to_domain(m: charging_profile::Model) -> ChargingProfile {
         valid_to: m.valid_to,
         schedule_json: m.schedule_json,
         is_active: m.is_active,
+        limit_source: m.limit_source,
         created_at: m.created_at,
         updated_at: m.updated_at,
     }
@@ -71,6 +72,7 @@ impl ChargingProfileRepository for SeaOrmChargingProfileRepository {
             valid_to: Set(profile.valid_to),
             schedule_json: Set(profile.schedule_json),
             is_active: Set(true),
+            limit_source: Set(profile.limit_source),
             created_at: Set(now),
             updated_at: Set(now),
         };
@@ -128,6 +130,7 @@ impl ChargingProfileRepository for SeaOrmChargingProfileRepository {
             .filter(charging_profile::Column::ChargePointId.eq(charge_point_id))
             .filter(charging_profile::Column::ProfileId.eq(profile_id))
             .filter(charging_profile::Column::IsActive.eq(true))
+            .filter(charging_profile::Column::LimitSource.is_null())
             .exec(&self.db)
             .await
             .map_err(db_err)?;
@@ -149,7 +152,8 @@ impl ChargingProfileRepository for SeaOrmChargingProfileRepository {
 
         let mut condition = Condition::all()
             .add(charging_profile::Column::ChargePointId.eq(charge_point_id))
-            .add(charging_profile::Column::IsActive.eq(true));
+            .add(charging_profile::Column::IsActive.eq(true))
+            .add(charging_profile::Column::LimitSource.is_null());
 
         if let Some(eid) = evse_id {
             condition = condition.add(charging_profile::Column::EvseId.eq(eid));
@@ -178,6 +182,43 @@ impl ChargingProfileRepository for SeaOrmChargingProfileRepository {
         Ok(result.rows_affected)
     }
 
+    async fn deactivate_by_source(
+        &self,
+        charge_point_id: &str,
+        source: &str,
+        evse_id: Option<i32>,
+    ) -> DomainResult<u64> {
+        debug!(
+            "Deactivating external limits: cp={}, source={}, evse={:?}",
+            charge_point_id, source, evse_id
+        );
+
+        let mut condition = Condition::all()
+            .add(charging_profile::Column::ChargePointId.eq(charge_point_id))
+            .add(charging_profile::Column::LimitSource.eq(source))
+            .add(charging_profile::Column::IsActive.eq(true));
+
+        if let Some(eid) = evse_id {
+            condition = condition.add(charging_profile::Column::EvseId.eq(eid));
+        }
+
+        let result: UpdateResult = charging_profile::Entity::update_many()
+            .col_expr(
+                charging_profile::Column::IsActive,
+                sea_orm::sea_query::Expr::value(false),
+            )
+            .col_expr(
+                charging_profile::Column::UpdatedAt,
+                sea_orm::sea_query::Expr::value(Utc::now()),
+            )
+            .filter(condition)
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+
+        Ok(result.rows_affected)
+    }
+
     async fn deactivate_all(&self, charge_point_id: &str) -> DomainResult<u64> {
         debug!("Deactivating ALL profiles for cp={}", charge_point_id);
 
@@ -192,6 +233,7 @@ impl ChargingProfileRepository for SeaOrmChargingProfileRepository {
             )
             .filter(charging_profile::Column::ChargePointId.eq(charge_point_id))
             .filter(charging_profile::Column::IsActive.eq(true))
+            .filter(charging_profile::Column::LimitSource.is_null())
             .exec(&self.db)
             .await
             .map_err(db_err)?;
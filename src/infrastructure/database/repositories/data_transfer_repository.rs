@@ -0,0 +1,85 @@
+//! SeaORM implementation of DataTransferRepository
+
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+
+use crate::domain::data_transfer::{
+    DataTransferDirection, DataTransferRecord, DataTransferRepository,
+};
+use crate::domain::{DomainError, DomainResult};
+use crate::infrastructure::database::entities::data_transfer;
+
+pub struct SeaOrmDataTransferRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmDataTransferRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+// ── Conversion helpers ──────────────────────────────────────────
+
+fn model_to_domain(m: data_transfer::Model) -> DataTransferRecord {
+    DataTransferRecord {
+        id: m.id,
+        charge_point_id: m.charge_point_id,
+        direction: DataTransferDirection::from(m.direction.as_str()),
+        vendor_id: m.vendor_id,
+        message_id: m.message_id,
+        request_data: m.request_data,
+        status: m.status,
+        response_data: m.response_data,
+        created_at: m.created_at,
+    }
+}
+
+fn db_err(e: sea_orm::DbErr) -> DomainError {
+    DomainError::Validation(format!("Database error: {}", e))
+}
+
+// ── DataTransferRepository impl ─────────────────────────────────
+
+#[async_trait]
+impl DataTransferRepository for SeaOrmDataTransferRepository {
+    async fn record(&self, record: DataTransferRecord) -> DomainResult<DataTransferRecord> {
+        let model = data_transfer::ActiveModel {
+            id: Default::default(), // auto-increment
+            charge_point_id: Set(record.charge_point_id),
+            direction: Set(record.direction.as_str().to_string()),
+            vendor_id: Set(record.vendor_id),
+            message_id: Set(record.message_id),
+            request_data: Set(record.request_data),
+            status: Set(record.status),
+            response_data: Set(record.response_data),
+            created_at: Set(record.created_at),
+        };
+        let model = model.insert(&self.db).await.map_err(db_err)?;
+        Ok(model_to_domain(model))
+    }
+
+    async fn find_for_charge_point(
+        &self,
+        charge_point_id: &str,
+        vendor_id: Option<&str>,
+        limit: u64,
+    ) -> DomainResult<Vec<DataTransferRecord>> {
+        let mut query = data_transfer::Entity::find()
+            .filter(data_transfer::Column::ChargePointId.eq(charge_point_id));
+        if let Some(vendor_id) = vendor_id {
+            query = query.filter(data_transfer::Column::VendorId.eq(vendor_id));
+        }
+        let models = query
+            .order_by_desc(data_transfer::Column::CreatedAt)
+            .order_by_desc(data_transfer::Column::Id)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+}
@@ -0,0 +1,191 @@
+//! SeaORM implementation of DisplayMessageRepository
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use tracing::debug;
+
+use crate::domain::display_message::{
+    DisplayMessage, DisplayMessageRepository, DisplayMessageStatus,
+};
+use crate::domain::{DomainError, DomainResult};
+use crate::infrastructure::database::entities::display_message;
+
+pub struct SeaOrmDisplayMessageRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmDisplayMessageRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    async fn find_model(
+        &self,
+        charge_point_id: &str,
+        message_id: i32,
+    ) -> DomainResult<Option<display_message::Model>> {
+        display_message::Entity::find()
+            .filter(display_message::Column::ChargePointId.eq(charge_point_id))
+            .filter(display_message::Column::MessageId.eq(message_id))
+            .one(&self.db)
+            .await
+            .map_err(db_err)
+    }
+}
+
+// ── Conversion helpers ──────────────────────────────────────────
+
+fn model_to_domain(m: display_message::Model) -> DisplayMessage {
+    DisplayMessage {
+        id: m.id,
+        charge_point_id: m.charge_point_id,
+        message_id: m.message_id,
+        priority: m.priority,
+        state: m.state,
+        start_date_time: m.start_date_time,
+        end_date_time: m.end_date_time,
+        transaction_id: m.transaction_id,
+        format: m.format,
+        language: m.language,
+        content: m.content,
+        status: DisplayMessageStatus::from(m.status.as_str()),
+        status_reason: m.status_reason,
+        last_reported_at: m.last_reported_at,
+        created_at: m.created_at,
+        updated_at: m.updated_at,
+    }
+}
+
+fn db_err(e: sea_orm::DbErr) -> DomainError {
+    DomainError::Validation(format!("Database error: {}", e))
+}
+
+// ── DisplayMessageRepository impl ──────────────────────────────
+
+#[async_trait]
+impl DisplayMessageRepository for SeaOrmDisplayMessageRepository {
+    async fn upsert(&self, message: DisplayMessage) -> DomainResult<DisplayMessage> {
+        debug!(
+            "Upserting display message: cp={}, message_id={}, status={}",
+            message.charge_point_id, message.message_id, message.status
+        );
+
+        let existing = self
+            .find_model(&message.charge_point_id, message.message_id)
+            .await?;
+
+        let mut model = display_message::ActiveModel {
+            id: Default::default(), // auto-increment
+            charge_point_id: Set(message.charge_point_id),
+            message_id: Set(message.message_id),
+            priority: Set(message.priority),
+            state: Set(message.state),
+            start_date_time: Set(message.start_date_time),
+            end_date_time: Set(message.end_date_time),
+            transaction_id: Set(message.transaction_id),
+            format: Set(message.format),
+            language: Set(message.language),
+            content: Set(message.content),
+            status: Set(message.status.as_str().to_string()),
+            status_reason: Set(message.status_reason),
+            last_reported_at: Set(message.last_reported_at),
+            created_at: Set(message.created_at),
+            updated_at: Set(Utc::now()),
+        };
+
+        let result = match existing {
+            Some(m) => {
+                model.id = Set(m.id);
+                model.created_at = Set(m.created_at);
+                model.update(&self.db).await.map_err(db_err)?
+            }
+            None => model.insert(&self.db).await.map_err(db_err)?,
+        };
+        Ok(model_to_domain(result))
+    }
+
+    async fn find(
+        &self,
+        charge_point_id: &str,
+        message_id: i32,
+    ) -> DomainResult<Option<DisplayMessage>> {
+        Ok(self
+            .find_model(charge_point_id, message_id)
+            .await?
+            .map(model_to_domain))
+    }
+
+    async fn find_for_charge_point(
+        &self,
+        charge_point_id: &str,
+    ) -> DomainResult<Vec<DisplayMessage>> {
+        let models = display_message::Entity::find()
+            .filter(display_message::Column::ChargePointId.eq(charge_point_id))
+            .order_by_asc(display_message::Column::MessageId)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn update_status(
+        &self,
+        charge_point_id: &str,
+        message_id: i32,
+        status: DisplayMessageStatus,
+    ) -> DomainResult<u64> {
+        debug!(
+            "Updating display message status: cp={}, message_id={}, status={}",
+            charge_point_id, message_id, status
+        );
+
+        let result = display_message::Entity::update_many()
+            .col_expr(
+                display_message::Column::Status,
+                sea_orm::sea_query::Expr::value(status.as_str()),
+            )
+            .col_expr(
+                display_message::Column::UpdatedAt,
+                sea_orm::sea_query::Expr::value(Utc::now()),
+            )
+            .filter(display_message::Column::ChargePointId.eq(charge_point_id))
+            .filter(display_message::Column::MessageId.eq(message_id))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn mark_missing_except(
+        &self,
+        charge_point_id: &str,
+        reported_ids: &[i32],
+    ) -> DomainResult<u64> {
+        debug!(
+            "Marking unreported display messages missing: cp={}, reported={:?}",
+            charge_point_id, reported_ids
+        );
+
+        let result = display_message::Entity::update_many()
+            .col_expr(
+                display_message::Column::Status,
+                sea_orm::sea_query::Expr::value(DisplayMessageStatus::Missing.as_str()),
+            )
+            .col_expr(
+                display_message::Column::UpdatedAt,
+                sea_orm::sea_query::Expr::value(Utc::now()),
+            )
+            .filter(display_message::Column::ChargePointId.eq(charge_point_id))
+            .filter(display_message::Column::Status.eq(DisplayMessageStatus::Accepted.as_str()))
+            .filter(display_message::Column::MessageId.is_not_in(reported_ids.iter().copied()))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+
+        Ok(result.rows_affected)
+    }
+}
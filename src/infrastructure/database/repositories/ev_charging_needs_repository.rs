@@ -0,0 +1,121 @@
+//! SeaORM implementation of EvChargingNeedsRepository
+
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+
+use crate::domain::ev_charging_needs::{EvChargingNeeds, EvChargingNeedsRepository};
+use crate::domain::{DomainError, DomainResult};
+use crate::infrastructure::database::entities::ev_charging_needs;
+
+pub struct SeaOrmEvChargingNeedsRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmEvChargingNeedsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+// ── Conversion helpers ──────────────────────────────────────────
+
+fn model_to_domain(m: ev_charging_needs::Model) -> EvChargingNeeds {
+    EvChargingNeeds {
+        id: m.id,
+        charge_point_id: m.charge_point_id,
+        evse_id: m.evse_id,
+        transaction_id: m.transaction_id,
+        energy_transfer_mode: m.energy_transfer_mode,
+        departure_time: m.departure_time,
+        energy_amount_wh: m.energy_amount_wh,
+        ev_max_current: m.ev_max_current,
+        ev_max_voltage: m.ev_max_voltage,
+        ev_max_power: m.ev_max_power,
+        state_of_charge: m.state_of_charge,
+        max_schedule_tuples: m.max_schedule_tuples,
+        profile_id: m.profile_id,
+        profile_status: m.profile_status,
+        schedule_json: m.schedule_json,
+        ev_schedule_json: m.ev_schedule_json,
+        created_at: m.created_at,
+        updated_at: m.updated_at,
+    }
+}
+
+fn domain_to_active(n: EvChargingNeeds) -> ev_charging_needs::ActiveModel {
+    ev_charging_needs::ActiveModel {
+        id: Default::default(),
+        charge_point_id: Set(n.charge_point_id),
+        evse_id: Set(n.evse_id),
+        transaction_id: Set(n.transaction_id),
+        energy_transfer_mode: Set(n.energy_transfer_mode),
+        departure_time: Set(n.departure_time),
+        energy_amount_wh: Set(n.energy_amount_wh),
+        ev_max_current: Set(n.ev_max_current),
+        ev_max_voltage: Set(n.ev_max_voltage),
+        ev_max_power: Set(n.ev_max_power),
+        state_of_charge: Set(n.state_of_charge),
+        max_schedule_tuples: Set(n.max_schedule_tuples),
+        profile_id: Set(n.profile_id),
+        profile_status: Set(n.profile_status),
+        schedule_json: Set(n.schedule_json),
+        ev_schedule_json: Set(n.ev_schedule_json),
+        created_at: Set(n.created_at),
+        updated_at: Set(n.updated_at),
+    }
+}
+
+fn db_err(e: sea_orm::DbErr) -> DomainError {
+    DomainError::Validation(format!("Database error: {}", e))
+}
+
+// ── EvChargingNeedsRepository impl ──────────────────────────────
+
+#[async_trait]
+impl EvChargingNeedsRepository for SeaOrmEvChargingNeedsRepository {
+    async fn save(&self, needs: EvChargingNeeds) -> DomainResult<EvChargingNeeds> {
+        let model = domain_to_active(needs)
+            .insert(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(model_to_domain(model))
+    }
+
+    async fn update(&self, needs: EvChargingNeeds) -> DomainResult<()> {
+        let id = needs.id;
+        let mut model = domain_to_active(needs);
+        model.id = Set(id);
+        model.update(&self.db).await.map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn find_latest_for_evse(
+        &self,
+        charge_point_id: &str,
+        evse_id: i32,
+    ) -> DomainResult<Option<EvChargingNeeds>> {
+        let model = ev_charging_needs::Entity::find()
+            .filter(ev_charging_needs::Column::ChargePointId.eq(charge_point_id))
+            .filter(ev_charging_needs::Column::EvseId.eq(evse_id))
+            .order_by_desc(ev_charging_needs::Column::Id)
+            .one(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(model.map(model_to_domain))
+    }
+
+    async fn find_for_transaction(
+        &self,
+        transaction_id: i32,
+    ) -> DomainResult<Vec<EvChargingNeeds>> {
+        let models = ev_charging_needs::Entity::find()
+            .filter(ev_charging_needs::Column::TransactionId.eq(transaction_id))
+            .order_by_asc(ev_charging_needs::Column::Id)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+}
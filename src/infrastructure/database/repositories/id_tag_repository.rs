@@ -3,9 +3,12 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use log::debug;
-use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, Set};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
 
-use crate::domain::id_tag::IdTagRepository;
+use crate::domain::id_tag::{IdTag, IdTagRepository, IdTagStatus};
 use crate::domain::{DomainError, DomainResult};
 use crate::infrastructure::database::entities::id_tag;
 
@@ -19,10 +22,64 @@ impl SeaOrmIdTagRepository {
     }
 }
 
+fn model_to_domain(m: id_tag::Model) -> IdTag {
+    IdTag {
+        id_tag: m.id_tag,
+        parent_id_tag: m.parent_id_tag,
+        status: IdTagStatus::from(m.status.to_string().as_str()),
+        user_id: m.user_id,
+        name: m.name,
+        expiry_date: m.expiry_date,
+        max_active_transactions: m.max_active_transactions,
+        is_active: m.is_active,
+        created_at: m.created_at,
+        updated_at: m.updated_at,
+        last_used_at: m.last_used_at,
+    }
+}
+
+fn status_to_entity(status: &IdTagStatus) -> id_tag::IdTagStatus {
+    match status {
+        IdTagStatus::Accepted => id_tag::IdTagStatus::Accepted,
+        IdTagStatus::Blocked => id_tag::IdTagStatus::Blocked,
+        IdTagStatus::Expired => id_tag::IdTagStatus::Expired,
+        IdTagStatus::Invalid => id_tag::IdTagStatus::Invalid,
+        IdTagStatus::ConcurrentTx => id_tag::IdTagStatus::ConcurrentTx,
+    }
+}
+
 fn db_err(e: sea_orm::DbErr) -> DomainError {
     DomainError::Validation(format!("Database error: {}", e))
 }
 
+/// Insert the tag, or overwrite the stored one, on `db`.
+async fn save_tag<C: ConnectionTrait>(db: &C, tag: IdTag) -> DomainResult<()> {
+    let existing = id_tag::Entity::find_by_id(&tag.id_tag)
+        .one(db)
+        .await
+        .map_err(db_err)?;
+
+    let model = id_tag::ActiveModel {
+        id_tag: Set(tag.id_tag),
+        parent_id_tag: Set(tag.parent_id_tag),
+        status: Set(status_to_entity(&tag.status)),
+        user_id: Set(tag.user_id),
+        name: Set(tag.name),
+        expiry_date: Set(tag.expiry_date),
+        max_active_transactions: Set(tag.max_active_transactions),
+        is_active: Set(tag.is_active),
+        created_at: Set(tag.created_at),
+        updated_at: Set(Utc::now()),
+        last_used_at: Set(tag.last_used_at),
+    };
+    if existing.is_some() {
+        model.update(db).await.map_err(db_err)?;
+    } else {
+        model.insert(db).await.map_err(db_err)?;
+    }
+    Ok(())
+}
+
 #[async_trait]
 impl IdTagRepository for SeaOrmIdTagRepository {
     async fn is_valid(&self, id_tag_value: &str) -> DomainResult<bool> {
@@ -112,6 +169,47 @@ impl IdTagRepository for SeaOrmIdTagRepository {
         Ok(())
     }
 
+    async fn find(&self, id_tag_value: &str) -> DomainResult<Option<IdTag>> {
+        let tag = id_tag::Entity::find_by_id(id_tag_value)
+            .one(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(tag.map(model_to_domain))
+    }
+
+    async fn save(&self, tag: IdTag) -> DomainResult<()> {
+        save_tag(&self.db, tag).await
+    }
+
+    async fn save_all(&self, tags: Vec<IdTag>) -> DomainResult<()> {
+        let txn = self.db.begin().await.map_err(db_err)?;
+        for tag in tags {
+            save_tag(&txn, tag).await?;
+        }
+        txn.commit().await.map_err(db_err)
+    }
+
+    async fn find_page(&self, after: &str, limit: u64) -> DomainResult<Vec<IdTag>> {
+        let tags = id_tag::Entity::find()
+            .filter(id_tag::Column::IdTag.gt(after))
+            .order_by_asc(id_tag::Column::IdTag)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(tags.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn find_by_user(&self, user_id: &str) -> DomainResult<Vec<IdTag>> {
+        let tags = id_tag::Entity::find()
+            .filter(id_tag::Column::UserId.eq(user_id))
+            .order_by_asc(id_tag::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(tags.into_iter().map(model_to_domain).collect())
+    }
+
     async fn get_parent_id_tag(&self, id_tag_value: &str) -> DomainResult<Option<String>> {
         if id_tag_value.is_empty() {
             return Ok(None);
@@ -124,4 +222,21 @@ impl IdTagRepository for SeaOrmIdTagRepository {
 
         Ok(tag.and_then(|t| t.parent_id_tag))
     }
+
+    async fn clear_parent(&self, parent_id_tag: &str) -> DomainResult<u64> {
+        let result = id_tag::Entity::update_many()
+            .col_expr(
+                id_tag::Column::ParentIdTag,
+                sea_orm::sea_query::Expr::value(Option::<String>::None),
+            )
+            .col_expr(
+                id_tag::Column::UpdatedAt,
+                sea_orm::sea_query::Expr::value(Utc::now()),
+            )
+            .filter(id_tag::Column::ParentIdTag.eq(parent_id_tag))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(result.rows_affected)
+    }
 }
@@ -2,13 +2,21 @@
 //!
 //! Per-aggregate SeaORM repositories + unified RepositoryProvider.
 
+pub mod auth_event_repository;
+pub mod availability_repository;
 pub mod charge_point_repository;
 pub mod charging_profile_repository;
+pub mod data_transfer_repository;
+pub mod display_message_repository;
+pub mod ev_charging_needs_repository;
 pub mod id_tag_repository;
+pub mod ocpi_repository;
 pub mod repository_provider;
 pub mod reservation_repository;
+pub mod retention_repository;
 pub mod tariff_repository;
 pub mod transaction_repository;
 pub mod user_repository;
+pub mod variable_monitor_repository;
 
 pub use repository_provider::SeaOrmRepositoryProvider;
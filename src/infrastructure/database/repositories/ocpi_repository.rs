@@ -0,0 +1,274 @@
+//! SeaORM implementations of OcpiPartyRepository and OcpiTokenRepository
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use tracing::debug;
+
+use crate::domain::ocpi::{
+    OcpiParty, OcpiPartyRepository, OcpiPartyStatus, OcpiToken, OcpiTokenRepository,
+};
+use crate::domain::{DomainError, DomainResult};
+use crate::infrastructure::database::entities::{ocpi_party, ocpi_token};
+
+// ── Conversion helpers ──────────────────────────────────────────
+
+fn db_err(e: sea_orm::DbErr) -> DomainError {
+    DomainError::Validation(format!("Database error: {}", e))
+}
+
+fn party_to_domain(m: ocpi_party::Model) -> OcpiParty {
+    OcpiParty {
+        id: m.id,
+        country_code: m.country_code,
+        party_id: m.party_id,
+        role: m.role,
+        name: m.name,
+        token_a: m.token_a,
+        token_b: m.token_b,
+        token_c: m.token_c,
+        versions_url: m.versions_url,
+        version: m.version,
+        endpoints_json: m.endpoints_json,
+        status: OcpiPartyStatus::from(m.status.as_str()),
+        created_at: m.created_at,
+        updated_at: m.updated_at,
+    }
+}
+
+fn token_to_domain(m: ocpi_token::Model) -> OcpiToken {
+    OcpiToken {
+        country_code: m.country_code,
+        party_id: m.party_id,
+        uid: m.uid,
+        token_type: m.token_type,
+        contract_id: m.contract_id,
+        visual_number: m.visual_number,
+        issuer: m.issuer,
+        group_id: m.group_id,
+        valid: m.valid,
+        whitelist: m.whitelist,
+        language: m.language,
+        last_updated: m.last_updated,
+    }
+}
+
+// ── SeaOrmOcpiPartyRepository ───────────────────────────────────
+
+pub struct SeaOrmOcpiPartyRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmOcpiPartyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl OcpiPartyRepository for SeaOrmOcpiPartyRepository {
+    async fn save(&self, party: OcpiParty) -> DomainResult<OcpiParty> {
+        debug!(
+            "Saving OCPI party: {}/{} ({})",
+            party.country_code, party.party_id, party.role
+        );
+
+        let now = Utc::now();
+        let model = ocpi_party::ActiveModel {
+            id: Default::default(), // auto-increment
+            country_code: Set(party.country_code),
+            party_id: Set(party.party_id),
+            role: Set(party.role),
+            name: Set(party.name),
+            token_a: Set(party.token_a),
+            token_b: Set(party.token_b),
+            token_c: Set(party.token_c),
+            versions_url: Set(party.versions_url),
+            version: Set(party.version),
+            endpoints_json: Set(party.endpoints_json),
+            status: Set(party.status.as_str().to_string()),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        let result = model.insert(&self.db).await.map_err(db_err)?;
+        Ok(party_to_domain(result))
+    }
+
+    async fn update(&self, party: OcpiParty) -> DomainResult<()> {
+        let model = ocpi_party::ActiveModel {
+            id: Set(party.id),
+            country_code: Set(party.country_code),
+            party_id: Set(party.party_id),
+            role: Set(party.role),
+            name: Set(party.name),
+            token_a: Set(party.token_a),
+            token_b: Set(party.token_b),
+            token_c: Set(party.token_c),
+            versions_url: Set(party.versions_url),
+            version: Set(party.version),
+            endpoints_json: Set(party.endpoints_json),
+            status: Set(party.status.as_str().to_string()),
+            created_at: Set(party.created_at),
+            updated_at: Set(Utc::now()),
+        };
+
+        model.update(&self.db).await.map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: i32) -> DomainResult<Option<OcpiParty>> {
+        let model = ocpi_party::Entity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(model.map(party_to_domain))
+    }
+
+    async fn find_by_token(&self, token: &str) -> DomainResult<Option<OcpiParty>> {
+        let model = ocpi_party::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(ocpi_party::Column::TokenA.eq(token))
+                    .add(ocpi_party::Column::TokenC.eq(token)),
+            )
+            .one(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(model.map(party_to_domain))
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<OcpiParty>> {
+        let models = ocpi_party::Entity::find()
+            .order_by_asc(ocpi_party::Column::Id)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(party_to_domain).collect())
+    }
+
+    async fn delete(&self, id: i32) -> DomainResult<()> {
+        ocpi_party::Entity::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(())
+    }
+}
+
+// ── SeaOrmOcpiTokenRepository ───────────────────────────────────
+
+pub struct SeaOrmOcpiTokenRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmOcpiTokenRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    async fn find_model(
+        &self,
+        country_code: &str,
+        party_id: &str,
+        uid: &str,
+    ) -> DomainResult<Option<ocpi_token::Model>> {
+        ocpi_token::Entity::find()
+            .filter(ocpi_token::Column::CountryCode.eq(country_code))
+            .filter(ocpi_token::Column::PartyId.eq(party_id))
+            .filter(ocpi_token::Column::Uid.eq(uid))
+            .one(&self.db)
+            .await
+            .map_err(db_err)
+    }
+}
+
+#[async_trait]
+impl OcpiTokenRepository for SeaOrmOcpiTokenRepository {
+    async fn upsert(&self, token: OcpiToken) -> DomainResult<()> {
+        debug!(
+            "Upserting OCPI token: {}/{}/{}",
+            token.country_code, token.party_id, token.uid
+        );
+
+        let existing = self
+            .find_model(&token.country_code, &token.party_id, &token.uid)
+            .await?;
+
+        let mut model = ocpi_token::ActiveModel {
+            id: Default::default(),
+            country_code: Set(token.country_code),
+            party_id: Set(token.party_id),
+            uid: Set(token.uid),
+            token_type: Set(token.token_type),
+            contract_id: Set(token.contract_id),
+            visual_number: Set(token.visual_number),
+            issuer: Set(token.issuer),
+            group_id: Set(token.group_id),
+            valid: Set(token.valid),
+            whitelist: Set(token.whitelist),
+            language: Set(token.language),
+            last_updated: Set(token.last_updated),
+        };
+
+        match existing {
+            Some(m) => {
+                model.id = Set(m.id);
+                model.update(&self.db).await.map_err(db_err)?;
+            }
+            None => {
+                model.insert(&self.db).await.map_err(db_err)?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        country_code: &str,
+        party_id: &str,
+        uid: &str,
+    ) -> DomainResult<Option<OcpiToken>> {
+        Ok(self
+            .find_model(country_code, party_id, uid)
+            .await?
+            .map(token_to_domain))
+    }
+
+    async fn find_by_uid(&self, uid: &str) -> DomainResult<Option<OcpiToken>> {
+        let model = ocpi_token::Entity::find()
+            .filter(ocpi_token::Column::Uid.eq(uid))
+            .order_by_desc(ocpi_token::Column::LastUpdated)
+            .one(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(model.map(token_to_domain))
+    }
+
+    async fn find_for_party(
+        &self,
+        country_code: &str,
+        party_id: &str,
+    ) -> DomainResult<Vec<OcpiToken>> {
+        let models = ocpi_token::Entity::find()
+            .filter(ocpi_token::Column::CountryCode.eq(country_code))
+            .filter(ocpi_token::Column::PartyId.eq(party_id))
+            .order_by_asc(ocpi_token::Column::Uid)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(token_to_domain).collect())
+    }
+
+    async fn delete_by_uid(&self, uid: &str) -> DomainResult<u64> {
+        let result = ocpi_token::Entity::delete_many()
+            .filter(ocpi_token::Column::Uid.eq(uid))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(result.rows_affected)
+    }
+}
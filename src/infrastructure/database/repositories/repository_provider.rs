@@ -2,20 +2,36 @@
 
 use sea_orm::DatabaseConnection;
 
+use crate::domain::auth_event::AuthEventRepository;
+use crate::domain::availability::AvailabilityRepository;
 use crate::domain::charge_point::ChargePointRepository;
 use crate::domain::charging_profile::ChargingProfileRepository;
+use crate::domain::data_transfer::DataTransferRepository;
+use crate::domain::display_message::DisplayMessageRepository;
+use crate::domain::ev_charging_needs::EvChargingNeedsRepository;
 use crate::domain::id_tag::IdTagRepository;
+use crate::domain::ocpi::{OcpiPartyRepository, OcpiTokenRepository};
 use crate::domain::repositories::RepositoryProvider;
 use crate::domain::reservation::ReservationRepository;
+use crate::domain::retention::RetentionRepository;
 use crate::domain::tariff::{BillingRepository, TariffRepository};
 use crate::domain::transaction::TransactionRepository;
+use crate::domain::variable_monitor::VariableMonitorRepository;
 
+use super::auth_event_repository::SeaOrmAuthEventRepository;
+use super::availability_repository::SeaOrmAvailabilityRepository;
 use super::charge_point_repository::SeaOrmChargePointRepository;
 use super::charging_profile_repository::SeaOrmChargingProfileRepository;
+use super::data_transfer_repository::SeaOrmDataTransferRepository;
+use super::display_message_repository::SeaOrmDisplayMessageRepository;
+use super::ev_charging_needs_repository::SeaOrmEvChargingNeedsRepository;
 use super::id_tag_repository::SeaOrmIdTagRepository;
+use super::ocpi_repository::{SeaOrmOcpiPartyRepository, SeaOrmOcpiTokenRepository};
 use super::reservation_repository::SeaOrmReservationRepository;
+use super::retention_repository::SeaOrmRetentionRepository;
 use super::tariff_repository::{SeaOrmBillingRepository, SeaOrmTariffRepository};
 use super::transaction_repository::SeaOrmTransactionRepository;
+use super::variable_monitor_repository::SeaOrmVariableMonitorRepository;
 
 /// Unified repository provider backed by SeaORM.
 ///
@@ -29,11 +45,20 @@ use super::transaction_repository::SeaOrmTransactionRepository;
 pub struct SeaOrmRepositoryProvider {
     charge_points: SeaOrmChargePointRepository,
     charging_profiles: SeaOrmChargingProfileRepository,
+    display_messages: SeaOrmDisplayMessageRepository,
+    data_transfers: SeaOrmDataTransferRepository,
+    ev_charging_needs: SeaOrmEvChargingNeedsRepository,
+    variable_monitors: SeaOrmVariableMonitorRepository,
     transactions: SeaOrmTransactionRepository,
     id_tags: SeaOrmIdTagRepository,
+    auth_events: SeaOrmAuthEventRepository,
+    availability: SeaOrmAvailabilityRepository,
     tariffs: SeaOrmTariffRepository,
     billing: SeaOrmBillingRepository,
     reservations: SeaOrmReservationRepository,
+    retention: SeaOrmRetentionRepository,
+    ocpi_parties: SeaOrmOcpiPartyRepository,
+    ocpi_tokens: SeaOrmOcpiTokenRepository,
 }
 
 impl SeaOrmRepositoryProvider {
@@ -41,11 +66,20 @@ impl SeaOrmRepositoryProvider {
         Self {
             charge_points: SeaOrmChargePointRepository::new(db.clone()),
             charging_profiles: SeaOrmChargingProfileRepository::new(db.clone()),
+            display_messages: SeaOrmDisplayMessageRepository::new(db.clone()),
+            data_transfers: SeaOrmDataTransferRepository::new(db.clone()),
+            ev_charging_needs: SeaOrmEvChargingNeedsRepository::new(db.clone()),
+            variable_monitors: SeaOrmVariableMonitorRepository::new(db.clone()),
             transactions: SeaOrmTransactionRepository::new(db.clone()),
             id_tags: SeaOrmIdTagRepository::new(db.clone()),
+            auth_events: SeaOrmAuthEventRepository::new(db.clone()),
+            availability: SeaOrmAvailabilityRepository::new(db.clone()),
             tariffs: SeaOrmTariffRepository::new(db.clone()),
             billing: SeaOrmBillingRepository::new(db.clone()),
-            reservations: SeaOrmReservationRepository::new(db),
+            reservations: SeaOrmReservationRepository::new(db.clone()),
+            retention: SeaOrmRetentionRepository::new(db.clone()),
+            ocpi_parties: SeaOrmOcpiPartyRepository::new(db.clone()),
+            ocpi_tokens: SeaOrmOcpiTokenRepository::new(db),
         }
     }
 }
@@ -63,6 +97,14 @@ impl RepositoryProvider for SeaOrmRepositoryProvider {
         &self.id_tags
     }
 
+    fn auth_events(&self) -> &dyn AuthEventRepository {
+        &self.auth_events
+    }
+
+    fn availability(&self) -> &dyn AvailabilityRepository {
+        &self.availability
+    }
+
     fn tariffs(&self) -> &dyn TariffRepository {
         &self.tariffs
     }
@@ -75,7 +117,35 @@ impl RepositoryProvider for SeaOrmRepositoryProvider {
         &self.reservations
     }
 
+    fn retention(&self) -> &dyn RetentionRepository {
+        &self.retention
+    }
+
     fn charging_profiles(&self) -> &dyn ChargingProfileRepository {
         &self.charging_profiles
     }
+
+    fn display_messages(&self) -> &dyn DisplayMessageRepository {
+        &self.display_messages
+    }
+
+    fn data_transfers(&self) -> &dyn DataTransferRepository {
+        &self.data_transfers
+    }
+
+    fn ev_charging_needs(&self) -> &dyn EvChargingNeedsRepository {
+        &self.ev_charging_needs
+    }
+
+    fn variable_monitors(&self) -> &dyn VariableMonitorRepository {
+        &self.variable_monitors
+    }
+
+    fn ocpi_parties(&self) -> &dyn OcpiPartyRepository {
+        &self.ocpi_parties
+    }
+
+    fn ocpi_tokens(&self) -> &dyn OcpiTokenRepository {
+        &self.ocpi_tokens
+    }
 }
@@ -3,7 +3,8 @@
 use async_trait::async_trait;
 use log::debug;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set,
 };
 
 use crate::domain::reservation::{Reservation, ReservationRepository, ReservationStatus};
@@ -29,6 +30,7 @@ fn model_to_domain(m: reservation::Model) -> Reservation {
         connector_id: m.connector_id,
         id_tag: m.id_tag,
         parent_id_tag: m.parent_id_tag,
+        start_date: m.start_date,
         expiry_date: m.expiry_date,
         status: ReservationStatus::from_str(&m.status),
         created_at: m.created_at,
@@ -52,6 +54,7 @@ impl ReservationRepository for SeaOrmReservationRepository {
             connector_id: Set(r.connector_id),
             id_tag: Set(r.id_tag),
             parent_id_tag: Set(r.parent_id_tag),
+            start_date: Set(r.start_date),
             expiry_date: Set(r.expiry_date),
             status: Set(r.status.as_str().to_string()),
             created_at: Set(r.created_at),
@@ -90,6 +93,7 @@ impl ReservationRepository for SeaOrmReservationRepository {
             connector_id: Set(r.connector_id),
             id_tag: Set(r.id_tag),
             parent_id_tag: Set(r.parent_id_tag),
+            start_date: Set(r.start_date),
             expiry_date: Set(r.expiry_date),
             status: Set(r.status.as_str().to_string()),
             created_at: Set(r.created_at),
@@ -127,6 +131,30 @@ impl ReservationRepository for SeaOrmReservationRepository {
         Ok(model.map(model_to_domain))
     }
 
+    async fn find_pending_for_charge_point(
+        &self,
+        charge_point_id: &str,
+    ) -> DomainResult<Vec<Reservation>> {
+        let models = reservation::Entity::find()
+            .filter(reservation::Column::ChargePointId.eq(charge_point_id))
+            .filter(reservation::Column::Status.is_in(["Accepted", "Scheduled"]))
+            .order_by_asc(reservation::Column::ExpiryDate)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn find_scheduled(&self) -> DomainResult<Vec<Reservation>> {
+        let models = reservation::Entity::find()
+            .filter(reservation::Column::Status.eq("Scheduled"))
+            .order_by_asc(reservation::Column::StartDate)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
     async fn find_all(&self) -> DomainResult<Vec<Reservation>> {
         let models = reservation::Entity::find()
             .order_by_desc(reservation::Column::Id)
@@ -136,6 +164,43 @@ impl ReservationRepository for SeaOrmReservationRepository {
         Ok(models.into_iter().map(model_to_domain).collect())
     }
 
+    async fn find_by_id_tag(&self, id_tag: &str) -> DomainResult<Vec<Reservation>> {
+        let models = reservation::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(reservation::Column::IdTag.eq(id_tag))
+                    .add(reservation::Column::ParentIdTag.eq(id_tag)),
+            )
+            .order_by_desc(reservation::Column::Id)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn replace_id_tag(&self, id_tag: &str, replacement: &str) -> DomainResult<u64> {
+        debug!("Replacing id_tag on reservations");
+        let direct = reservation::Entity::update_many()
+            .col_expr(
+                reservation::Column::IdTag,
+                sea_orm::sea_query::Expr::value(replacement),
+            )
+            .filter(reservation::Column::IdTag.eq(id_tag))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+        let parent = reservation::Entity::update_many()
+            .col_expr(
+                reservation::Column::ParentIdTag,
+                sea_orm::sea_query::Expr::value(replacement),
+            )
+            .filter(reservation::Column::ParentIdTag.eq(id_tag))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(direct.rows_affected + parent.rows_affected)
+    }
+
     async fn find_expired(&self) -> DomainResult<Vec<Reservation>> {
         use chrono::Utc;
         let models = reservation::Entity::find()
@@ -0,0 +1,245 @@
+//! SeaORM implementation of RetentionRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::SimpleExpr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set,
+};
+use serde::Serialize;
+
+use crate::domain::retention::{
+    ArchiveRun, ArchiveRunStatus, ArchiveTrigger, DataClass, ExpiredRow, RetentionRepository,
+};
+use crate::domain::{DomainError, DomainResult};
+use crate::infrastructure::database::entities::{
+    archive_run, auth_event, connection_session, connector_status_interval, data_transfer,
+    transaction,
+};
+
+pub struct SeaOrmRetentionRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmRetentionRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+// ── Conversion helpers ──────────────────────────────────────────
+
+fn run_to_domain(m: archive_run::Model) -> ArchiveRun {
+    ArchiveRun {
+        id: m.id,
+        trigger: ArchiveTrigger::parse(&m.triggered_by),
+        status: ArchiveRunStatus::parse(&m.status),
+        started_at: m.started_at,
+        finished_at: m.finished_at,
+        rows_archived: m.rows_archived.max(0) as u64,
+        classes: serde_json::from_str(&m.details).unwrap_or_default(),
+        error: m.error,
+    }
+}
+
+fn run_details(run: &ArchiveRun) -> String {
+    serde_json::to_string(&run.classes).unwrap_or_else(|_| "[]".to_string())
+}
+
+fn db_err(e: sea_orm::DbErr) -> DomainError {
+    DomainError::Validation(format!("Database error: {}", e))
+}
+
+/// Rows of `E` matching `expired`, by ascending id, serialized as stored.
+async fn find_rows<E>(
+    db: &DatabaseConnection,
+    id: E::Column,
+    expired: SimpleExpr,
+    after_id: i32,
+    limit: u64,
+) -> DomainResult<Vec<ExpiredRow>>
+where
+    E: EntityTrait,
+    E::Model: Serialize,
+{
+    let models = E::find()
+        .filter(Condition::all().add(expired).add(id.gt(after_id)))
+        .order_by_asc(id)
+        .limit(limit)
+        .all(db)
+        .await
+        .map_err(db_err)?;
+
+    models
+        .into_iter()
+        .map(|m| {
+            let data = serde_json::to_value(&m)
+                .map_err(|e| DomainError::Validation(format!("Cannot serialize row: {}", e)))?;
+            let id = data["id"].as_i64().unwrap_or_default() as i32;
+            Ok(ExpiredRow { id, data })
+        })
+        .collect()
+}
+
+async fn delete_ids<E: EntityTrait>(
+    db: &DatabaseConnection,
+    id: E::Column,
+    ids: &[i32],
+) -> DomainResult<u64> {
+    let result = E::delete_many()
+        .filter(id.is_in(ids.iter().copied()))
+        .exec(db)
+        .await
+        .map_err(db_err)?;
+    Ok(result.rows_affected)
+}
+
+// ── RetentionRepository impl ────────────────────────────────────
+
+#[async_trait]
+impl RetentionRepository for SeaOrmRetentionRepository {
+    async fn find_expired(
+        &self,
+        data_class: DataClass,
+        before: DateTime<Utc>,
+        after_id: i32,
+        limit: u64,
+    ) -> DomainResult<Vec<ExpiredRow>> {
+        let db = &self.db;
+        match data_class {
+            DataClass::Transactions => {
+                let finished = transaction::Column::Status
+                    .ne("Active")
+                    .and(transaction::Column::StoppedAt.lt(before));
+                find_rows::<transaction::Entity>(
+                    db,
+                    transaction::Column::Id,
+                    finished,
+                    after_id,
+                    limit,
+                )
+                .await
+            }
+            DataClass::AuthEvents => {
+                find_rows::<auth_event::Entity>(
+                    db,
+                    auth_event::Column::Id,
+                    auth_event::Column::OccurredAt.lt(before),
+                    after_id,
+                    limit,
+                )
+                .await
+            }
+            DataClass::DataTransfers => {
+                find_rows::<data_transfer::Entity>(
+                    db,
+                    data_transfer::Column::Id,
+                    data_transfer::Column::CreatedAt.lt(before),
+                    after_id,
+                    limit,
+                )
+                .await
+            }
+            DataClass::ConnectionSessions => {
+                find_rows::<connection_session::Entity>(
+                    db,
+                    connection_session::Column::Id,
+                    connection_session::Column::DisconnectedAt.lt(before),
+                    after_id,
+                    limit,
+                )
+                .await
+            }
+            DataClass::ConnectorStatusIntervals => {
+                find_rows::<connector_status_interval::Entity>(
+                    db,
+                    connector_status_interval::Column::Id,
+                    connector_status_interval::Column::EndedAt.lt(before),
+                    after_id,
+                    limit,
+                )
+                .await
+            }
+        }
+    }
+
+    async fn delete_rows(&self, data_class: DataClass, ids: &[i32]) -> DomainResult<u64> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        let db = &self.db;
+        match data_class {
+            DataClass::Transactions => {
+                delete_ids::<transaction::Entity>(db, transaction::Column::Id, ids).await
+            }
+            DataClass::AuthEvents => {
+                delete_ids::<auth_event::Entity>(db, auth_event::Column::Id, ids).await
+            }
+            DataClass::DataTransfers => {
+                delete_ids::<data_transfer::Entity>(db, data_transfer::Column::Id, ids).await
+            }
+            DataClass::ConnectionSessions => {
+                delete_ids::<connection_session::Entity>(db, connection_session::Column::Id, ids)
+                    .await
+            }
+            DataClass::ConnectorStatusIntervals => {
+                delete_ids::<connector_status_interval::Entity>(
+                    db,
+                    connector_status_interval::Column::Id,
+                    ids,
+                )
+                .await
+            }
+        }
+    }
+
+    async fn save_run(&self, run: ArchiveRun) -> DomainResult<ArchiveRun> {
+        let model = archive_run::ActiveModel {
+            id: Default::default(), // auto-increment
+            triggered_by: Set(run.trigger.as_str().to_string()),
+            status: Set(run.status.as_str().to_string()),
+            started_at: Set(run.started_at),
+            finished_at: Set(run.finished_at),
+            rows_archived: Set(run.rows_archived as i64),
+            details: Set(run_details(&run)),
+            error: Set(run.error),
+        };
+        let saved = model.insert(&self.db).await.map_err(db_err)?;
+        Ok(run_to_domain(saved))
+    }
+
+    async fn update_run(&self, run: ArchiveRun) -> DomainResult<()> {
+        let model = archive_run::ActiveModel {
+            id: Set(run.id),
+            triggered_by: Set(run.trigger.as_str().to_string()),
+            status: Set(run.status.as_str().to_string()),
+            started_at: Set(run.started_at),
+            finished_at: Set(run.finished_at),
+            rows_archived: Set(run.rows_archived as i64),
+            details: Set(run_details(&run)),
+            error: Set(run.error),
+        };
+        model.update(&self.db).await.map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn find_run(&self, id: i32) -> DomainResult<Option<ArchiveRun>> {
+        let model = archive_run::Entity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(model.map(run_to_domain))
+    }
+
+    async fn find_runs(&self, limit: u64) -> DomainResult<Vec<ArchiveRun>> {
+        let models = archive_run::Entity::find()
+            .order_by_desc(archive_run::Column::StartedAt)
+            .order_by_desc(archive_run::Column::Id)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(run_to_domain).collect())
+    }
+}
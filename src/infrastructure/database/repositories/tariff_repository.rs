@@ -4,7 +4,8 @@ use async_trait::async_trait;
 use chrono::Utc;
 use log::info;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait,
 };
 
 use crate::domain::tariff::{
@@ -65,6 +66,31 @@ fn string_to_billing_status(s: &str) -> BillingStatus {
     }
 }
 
+/// Billing stored on a transaction row; `None` until it has been billed.
+fn billing_from_model(tx: transaction::Model) -> Option<TransactionBilling> {
+    if tx.billing_status.is_none() || tx.total_cost.is_none() {
+        return None;
+    }
+
+    let duration_seconds = tx
+        .stopped_at
+        .map(|stop| (stop - tx.started_at).num_seconds())
+        .unwrap_or(0);
+
+    Some(TransactionBilling {
+        transaction_id: tx.id,
+        tariff_id: tx.tariff_id,
+        energy_wh: tx.energy_consumed.unwrap_or(0),
+        duration_seconds,
+        energy_cost: tx.energy_cost.unwrap_or(0),
+        time_cost: tx.time_cost.unwrap_or(0),
+        session_fee: tx.session_fee.unwrap_or(0),
+        total_cost: tx.total_cost.unwrap_or(0),
+        currency: tx.currency.unwrap_or_else(|| "UZS".to_string()),
+        status: string_to_billing_status(&tx.billing_status.unwrap_or_default()),
+    })
+}
+
 // ── SeaOrmTariffRepository ──────────────────────────────────────
 
 pub struct SeaOrmTariffRepository {
@@ -77,6 +103,69 @@ impl SeaOrmTariffRepository {
     }
 }
 
+/// Insert a new tariff on `db`; returns it with its id set.
+async fn insert_tariff<C: ConnectionTrait>(db: &C, t: Tariff) -> DomainResult<Tariff> {
+    let now = Utc::now();
+    let model = tariff::ActiveModel {
+        id: Default::default(), // auto-increment
+        name: Set(t.name),
+        description: Set(t.description),
+        tariff_type: Set(type_to_entity(&t.tariff_type)),
+        price_per_kwh: Set(t.price_per_kwh),
+        price_per_minute: Set(t.price_per_minute),
+        session_fee: Set(t.session_fee),
+        currency: Set(t.currency),
+        min_fee: Set(t.min_fee),
+        max_fee: Set(t.max_fee),
+        is_active: Set(t.is_active),
+        is_default: Set(t.is_default),
+        valid_from: Set(t.valid_from),
+        valid_until: Set(t.valid_until),
+        created_at: Set(now),
+        updated_at: Set(now),
+    };
+    let result = model.insert(db).await.map_err(db_err)?;
+    info!("Tariff saved: {} ({})", result.name, result.id);
+    Ok(entity_to_domain(result))
+}
+
+/// Overwrite a stored tariff on `db`.
+async fn update_tariff<C: ConnectionTrait>(db: &C, t: Tariff) -> DomainResult<()> {
+    let existing = tariff::Entity::find_by_id(t.id)
+        .one(db)
+        .await
+        .map_err(db_err)?;
+
+    let Some(existing) = existing else {
+        return Err(DomainError::NotFound {
+            entity: "Tariff",
+            field: "id",
+            value: t.id.to_string(),
+        });
+    };
+
+    let model = tariff::ActiveModel {
+        id: Set(t.id),
+        name: Set(t.name),
+        description: Set(t.description),
+        tariff_type: Set(type_to_entity(&t.tariff_type)),
+        price_per_kwh: Set(t.price_per_kwh),
+        price_per_minute: Set(t.price_per_minute),
+        session_fee: Set(t.session_fee),
+        currency: Set(t.currency),
+        min_fee: Set(t.min_fee),
+        max_fee: Set(t.max_fee),
+        is_active: Set(t.is_active),
+        is_default: Set(t.is_default),
+        valid_from: Set(t.valid_from),
+        valid_until: Set(t.valid_until),
+        created_at: Set(existing.created_at),
+        updated_at: Set(Utc::now()),
+    };
+    model.update(db).await.map_err(db_err)?;
+    Ok(())
+}
+
 #[async_trait]
 impl TariffRepository for SeaOrmTariffRepository {
     async fn find_by_id(&self, id: i32) -> DomainResult<Option<Tariff>> {
@@ -107,64 +196,23 @@ impl TariffRepository for SeaOrmTariffRepository {
     }
 
     async fn save(&self, t: Tariff) -> DomainResult<Tariff> {
-        let now = Utc::now();
-        let model = tariff::ActiveModel {
-            id: Set(0),
-            name: Set(t.name),
-            description: Set(t.description),
-            tariff_type: Set(type_to_entity(&t.tariff_type)),
-            price_per_kwh: Set(t.price_per_kwh),
-            price_per_minute: Set(t.price_per_minute),
-            session_fee: Set(t.session_fee),
-            currency: Set(t.currency),
-            min_fee: Set(t.min_fee),
-            max_fee: Set(t.max_fee),
-            is_active: Set(t.is_active),
-            is_default: Set(t.is_default),
-            valid_from: Set(t.valid_from),
-            valid_until: Set(t.valid_until),
-            created_at: Set(now),
-            updated_at: Set(now),
-        };
-        let result = model.insert(&self.db).await.map_err(db_err)?;
-        info!("Tariff saved: {} ({})", result.name, result.id);
-        Ok(entity_to_domain(result))
+        insert_tariff(&self.db, t).await
     }
 
     async fn update(&self, t: Tariff) -> DomainResult<()> {
-        let existing = tariff::Entity::find_by_id(t.id)
-            .one(&self.db)
-            .await
-            .map_err(db_err)?;
-
-        let Some(existing) = existing else {
-            return Err(DomainError::NotFound {
-                entity: "Tariff",
-                field: "id",
-                value: t.id.to_string(),
-            });
-        };
+        update_tariff(&self.db, t).await
+    }
 
-        let model = tariff::ActiveModel {
-            id: Set(t.id),
-            name: Set(t.name),
-            description: Set(t.description),
-            tariff_type: Set(type_to_entity(&t.tariff_type)),
-            price_per_kwh: Set(t.price_per_kwh),
-            price_per_minute: Set(t.price_per_minute),
-            session_fee: Set(t.session_fee),
-            currency: Set(t.currency),
-            min_fee: Set(t.min_fee),
-            max_fee: Set(t.max_fee),
-            is_active: Set(t.is_active),
-            is_default: Set(t.is_default),
-            valid_from: Set(t.valid_from),
-            valid_until: Set(t.valid_until),
-            created_at: Set(existing.created_at),
-            updated_at: Set(Utc::now()),
-        };
-        model.update(&self.db).await.map_err(db_err)?;
-        Ok(())
+    async fn save_all(&self, tariffs: Vec<Tariff>) -> DomainResult<()> {
+        let txn = self.db.begin().await.map_err(db_err)?;
+        for t in tariffs {
+            if t.id == 0 {
+                insert_tariff(&txn, t).await?;
+            } else {
+                update_tariff(&txn, t).await?;
+            }
+        }
+        txn.commit().await.map_err(db_err)
     }
 
     async fn delete(&self, id: i32) -> DomainResult<()> {
@@ -233,31 +281,18 @@ impl BillingRepository for SeaOrmBillingRepository {
             .one(&self.db)
             .await
             .map_err(db_err)?;
+        Ok(tx.and_then(billing_from_model))
+    }
 
-        let Some(tx) = tx else {
-            return Ok(None);
-        };
-
-        if tx.billing_status.is_none() || tx.total_cost.is_none() {
-            return Ok(None);
+    async fn find_billings(&self, ids: &[i32]) -> DomainResult<Vec<TransactionBilling>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
         }
-
-        let duration_seconds = tx
-            .stopped_at
-            .map(|stop| (stop - tx.started_at).num_seconds())
-            .unwrap_or(0);
-
-        Ok(Some(TransactionBilling {
-            transaction_id: tx.id,
-            tariff_id: tx.tariff_id,
-            energy_wh: tx.energy_consumed.unwrap_or(0),
-            duration_seconds,
-            energy_cost: tx.energy_cost.unwrap_or(0),
-            time_cost: tx.time_cost.unwrap_or(0),
-            session_fee: tx.session_fee.unwrap_or(0),
-            total_cost: tx.total_cost.unwrap_or(0),
-            currency: tx.currency.unwrap_or_else(|| "UZS".to_string()),
-            status: string_to_billing_status(&tx.billing_status.unwrap_or_default()),
-        }))
+        let models = transaction::Entity::find()
+            .filter(transaction::Column::Id.is_in(ids.iter().copied()))
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().filter_map(billing_from_model).collect())
     }
 }
@@ -1,10 +1,11 @@
 //! SeaORM implementation of TransactionRepository
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use log::debug;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
 };
 
 use crate::domain::transaction::{ChargingLimitType, Transaction, TransactionRepository, TransactionStatus};
@@ -59,6 +60,7 @@ fn model_to_domain(t: transaction::Model) -> Transaction {
         last_meter_update: t.last_meter_update,
         limit_type: t.limit_type.as_deref().and_then(ChargingLimitType::from_str),
         limit_value: t.limit_value,
+        station_transaction_id: t.station_transaction_id,
     }
 }
 
@@ -99,6 +101,7 @@ impl TransactionRepository for SeaOrmTransactionRepository {
             last_meter_update: Set(tx.last_meter_update),
             limit_type: Set(tx.limit_type.as_ref().map(|lt| lt.as_str().to_string())),
             limit_value: Set(tx.limit_value),
+            station_transaction_id: Set(tx.station_transaction_id),
         };
         model.insert(&self.db).await.map_err(db_err)?;
         Ok(())
@@ -155,6 +158,7 @@ impl TransactionRepository for SeaOrmTransactionRepository {
             last_meter_update: Set(tx.last_meter_update),
             limit_type: Set(tx.limit_type.as_ref().map(|lt| lt.as_str().to_string())),
             limit_value: Set(tx.limit_value),
+            station_transaction_id: Set(tx.station_transaction_id),
         };
         model.update(&self.db).await.map_err(db_err)?;
         Ok(())
@@ -193,6 +197,53 @@ impl TransactionRepository for SeaOrmTransactionRepository {
         Ok(models.into_iter().map(model_to_domain).collect())
     }
 
+    async fn find_page(
+        &self,
+        after_id: i32,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        limit: u64,
+    ) -> DomainResult<Vec<Transaction>> {
+        let mut query = transaction::Entity::find().filter(transaction::Column::Id.gt(after_id));
+        if let Some(from) = from {
+            query = query.filter(transaction::Column::StartedAt.gte(from));
+        }
+        if let Some(to) = to {
+            query = query.filter(transaction::Column::StartedAt.lt(to));
+        }
+        let models = query
+            .order_by_asc(transaction::Column::Id)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn find_by_id_tag(&self, id_tag: &str) -> DomainResult<Vec<Transaction>> {
+        let models = transaction::Entity::find()
+            .filter(transaction::Column::IdTag.eq(id_tag))
+            .order_by_desc(transaction::Column::Id)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn replace_id_tag(&self, id_tag: &str, replacement: &str) -> DomainResult<u64> {
+        debug!("Replacing id_tag on transactions");
+        let result = transaction::Entity::update_many()
+            .col_expr(
+                transaction::Column::IdTag,
+                sea_orm::sea_query::Expr::value(replacement),
+            )
+            .filter(transaction::Column::IdTag.eq(id_tag))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(result.rows_affected)
+    }
+
     async fn update_meter_data(
         &self,
         transaction_id: i32,
@@ -1,8 +1,9 @@
 use async_trait::async_trait;
 use chrono::Utc;
+use sea_orm::sea_query::{Expr, Func};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set,
 };
 
 use crate::domain::{
@@ -105,12 +106,20 @@ impl UserRepositoryInterface for UserRepository {
 
         let mut query = user::Entity::find();
 
-        // Apply search filter (username or email)
+        // Apply search filter (username or email). LIKE is case-insensitive on
+        // SQLite but not on PostgreSQL, so compare lowercased values explicitly.
         if let Some(ref search) = dto.search {
+            let pattern = format!("%{}%", search.to_lowercase());
             query = query.filter(
-                user::Column::Username
-                    .contains(search)
-                    .or(user::Column::Email.contains(search)),
+                Condition::any()
+                    .add(
+                        Expr::expr(Func::lower(Expr::col(user::Column::Username)))
+                            .like(pattern.as_str()),
+                    )
+                    .add(
+                        Expr::expr(Func::lower(Expr::col(user::Column::Email)))
+                            .like(pattern.as_str()),
+                    ),
             );
         }
 
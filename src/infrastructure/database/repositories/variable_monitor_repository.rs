@@ -0,0 +1,148 @@
+//! SeaORM implementation of VariableMonitorRepository
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use tracing::debug;
+
+use crate::domain::variable_monitor::{VariableMonitor, VariableMonitorRepository};
+use crate::domain::{DomainError, DomainResult};
+use crate::infrastructure::database::entities::variable_monitor;
+
+pub struct SeaOrmVariableMonitorRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmVariableMonitorRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+// ── Conversion helpers ──────────────────────────────────────────
+
+fn model_to_domain(m: variable_monitor::Model) -> VariableMonitor {
+    VariableMonitor {
+        id: m.id,
+        charge_point_id: m.charge_point_id,
+        monitor_id: m.monitor_id,
+        component: m.component,
+        component_instance: m.component_instance,
+        evse_id: m.evse_id,
+        connector_id: m.connector_id,
+        variable: m.variable,
+        variable_instance: m.variable_instance,
+        monitor_type: m.monitor_type,
+        value: m.value,
+        severity: m.severity,
+        transaction: m.transaction,
+        request_id: m.request_id,
+        last_reported_at: m.last_reported_at,
+        created_at: m.created_at,
+        updated_at: m.updated_at,
+    }
+}
+
+fn db_err(e: sea_orm::DbErr) -> DomainError {
+    DomainError::Validation(format!("Database error: {}", e))
+}
+
+// ── VariableMonitorRepository impl ─────────────────────────────
+
+#[async_trait]
+impl VariableMonitorRepository for SeaOrmVariableMonitorRepository {
+    async fn upsert(&self, monitor: VariableMonitor) -> DomainResult<VariableMonitor> {
+        debug!(
+            "Upserting variable monitor: cp={}, monitor_id={}",
+            monitor.charge_point_id, monitor.monitor_id
+        );
+
+        let existing = variable_monitor::Entity::find()
+            .filter(variable_monitor::Column::ChargePointId.eq(monitor.charge_point_id.as_str()))
+            .filter(variable_monitor::Column::MonitorId.eq(monitor.monitor_id))
+            .one(&self.db)
+            .await
+            .map_err(db_err)?;
+
+        let mut model = variable_monitor::ActiveModel {
+            id: Default::default(), // auto-increment
+            charge_point_id: Set(monitor.charge_point_id),
+            monitor_id: Set(monitor.monitor_id),
+            component: Set(monitor.component),
+            component_instance: Set(monitor.component_instance),
+            evse_id: Set(monitor.evse_id),
+            connector_id: Set(monitor.connector_id),
+            variable: Set(monitor.variable),
+            variable_instance: Set(monitor.variable_instance),
+            monitor_type: Set(monitor.monitor_type),
+            value: Set(monitor.value),
+            severity: Set(monitor.severity),
+            transaction: Set(monitor.transaction),
+            request_id: Set(monitor.request_id),
+            last_reported_at: Set(monitor.last_reported_at),
+            created_at: Set(monitor.created_at),
+            updated_at: Set(Utc::now()),
+        };
+
+        let result = match existing {
+            Some(m) => {
+                model.id = Set(m.id);
+                model.created_at = Set(m.created_at);
+                model.update(&self.db).await.map_err(db_err)?
+            }
+            None => model.insert(&self.db).await.map_err(db_err)?,
+        };
+        Ok(model_to_domain(result))
+    }
+
+    async fn find_for_charge_point(
+        &self,
+        charge_point_id: &str,
+    ) -> DomainResult<Vec<VariableMonitor>> {
+        let models = variable_monitor::Entity::find()
+            .filter(variable_monitor::Column::ChargePointId.eq(charge_point_id))
+            .order_by_asc(variable_monitor::Column::MonitorId)
+            .all(&self.db)
+            .await
+            .map_err(db_err)?;
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn remove(&self, charge_point_id: &str, monitor_ids: &[i32]) -> DomainResult<u64> {
+        debug!(
+            "Removing variable monitors: cp={}, ids={:?}",
+            charge_point_id, monitor_ids
+        );
+
+        let result = variable_monitor::Entity::delete_many()
+            .filter(variable_monitor::Column::ChargePointId.eq(charge_point_id))
+            .filter(variable_monitor::Column::MonitorId.is_in(monitor_ids.iter().copied()))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn remove_except(
+        &self,
+        charge_point_id: &str,
+        monitor_ids: &[i32],
+    ) -> DomainResult<u64> {
+        debug!(
+            "Removing unreported variable monitors: cp={}, reported={:?}",
+            charge_point_id, monitor_ids
+        );
+
+        let result = variable_monitor::Entity::delete_many()
+            .filter(variable_monitor::Column::ChargePointId.eq(charge_point_id))
+            .filter(variable_monitor::Column::MonitorId.is_not_in(monitor_ids.iter().copied()))
+            .exec(&self.db)
+            .await
+            .map_err(db_err)?;
+
+        Ok(result.rows_affected)
+    }
+}
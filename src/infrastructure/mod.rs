@@ -1,5 +1,8 @@
+pub mod archive;
+pub mod cluster;
 pub mod crypto;
 pub mod database;
+pub mod tabular;
 
 // Re-export commonly used types
 pub use database::SeaOrmRepositoryProvider;
@@ -0,0 +1,95 @@
+//! CSV reading and writing
+
+use csv::{ReaderBuilder, Trim, WriterBuilder};
+
+use crate::application::bulk::{Cell, Table};
+
+/// Read a CSV file with a header line.
+///
+/// Accepts the UTF-8 byte order mark and the `;` separator that
+/// spreadsheet applications write in some locales.
+pub fn read_csv(bytes: &[u8]) -> Result<Table, String> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    let header_line = bytes.split(|b| *b == b'\n').next().unwrap_or_default();
+    let delimiter = if header_line.contains(&b';') && !header_line.contains(&b',') {
+        b';'
+    } else {
+        b','
+    };
+
+    let mut reader = ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .trim(Trim::All)
+        .from_reader(bytes);
+    let headers = reader
+        .headers()
+        .map_err(|e| format!("Invalid CSV header: {}", e))?
+        .clone();
+
+    let mut table = Table::new(headers.iter());
+    for record in reader.records() {
+        let record = record.map_err(|e| format!("Invalid CSV: {}", e))?;
+        let line = record
+            .position()
+            .map(|p| p.line() as usize)
+            .unwrap_or_default();
+        table.push(line, record.iter().map(String::from).collect());
+    }
+    Ok(table)
+}
+
+/// The header line of an export.
+pub fn csv_header(columns: &[&str]) -> Result<Vec<u8>, String> {
+    encode(std::iter::once(
+        columns.iter().map(|c| c.to_string()).collect(),
+    ))
+}
+
+/// Export rows as CSV lines.
+pub fn csv_rows(rows: &[Vec<Cell>]) -> Result<Vec<u8>, String> {
+    encode(
+        rows.iter()
+            .map(|row| row.iter().map(Cell::to_text).collect()),
+    )
+}
+
+fn encode(records: impl Iterator<Item = Vec<String>>) -> Result<Vec<u8>, String> {
+    let mut writer = WriterBuilder::new().from_writer(Vec::new());
+    for record in records {
+        writer
+            .write_record(&record)
+            .map_err(|e| format!("Cannot write CSV: {}", e))?;
+    }
+    writer
+        .into_inner()
+        .map_err(|e| format!("Cannot write CSV: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_excel_flavoured_csv() {
+        let file = "\u{feff}ID_Tag;Name\nTAG-1;\"Doe; Jane\"\n;\nTAG-2;Bob\n";
+        let table = read_csv(file.as_bytes()).unwrap();
+
+        assert_eq!(table.headers, ["id_tag", "name"]);
+        let rows: Vec<_> = table.records().map(|r| (r.line(), r.get("name"))).collect();
+        assert_eq!(rows, [(2, Some("Doe; Jane")), (4, Some("Bob"))]);
+    }
+
+    #[test]
+    fn writes_quoted_rows() {
+        let mut out = csv_header(&["id", "name"]).unwrap();
+        out.extend(
+            csv_rows(&[
+                vec![Cell::Number(1), "a, b".into()],
+                vec![Cell::Number(2), Cell::Empty],
+            ])
+            .unwrap(),
+        );
+        assert_eq!(String::from_utf8(out).unwrap(), "id,name\n1,\"a, b\"\n2,\n");
+    }
+}
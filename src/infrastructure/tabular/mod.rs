@@ -0,0 +1,63 @@
+//! Spreadsheet file formats for bulk import and export
+//!
+//! Reads CSV and XLSX files into format-neutral [`Table`]s and writes
+//! export rows back out. Only the first worksheet of a workbook is read.
+
+pub mod csv;
+pub mod xlsx;
+
+use crate::application::bulk::Table;
+
+pub use self::csv::{csv_header, csv_rows, read_csv};
+pub use self::xlsx::{read_xlsx, write_xlsx};
+
+/// A supported spreadsheet file format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    Csv,
+    Xlsx,
+}
+
+impl TableFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "xlsx" => Some(Self::Xlsx),
+            _ => None,
+        }
+    }
+
+    /// The format of a request body with the given `Content-Type`.
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next().unwrap_or_default().trim();
+        if mime.eq_ignore_ascii_case(Self::Csv.content_type()) {
+            Some(Self::Csv)
+        } else if mime.eq_ignore_ascii_case(Self::Xlsx.content_type()) {
+            Some(Self::Xlsx)
+        } else {
+            None
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Xlsx => "xlsx",
+        }
+    }
+
+    /// Read a whole file in this format.
+    pub fn read(&self, bytes: &[u8]) -> Result<Table, String> {
+        match self {
+            Self::Csv => read_csv(bytes),
+            Self::Xlsx => read_xlsx(bytes),
+        }
+    }
+}
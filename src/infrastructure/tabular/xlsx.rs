@@ -0,0 +1,124 @@
+//! XLSX reading and writing
+
+use std::io::Cursor;
+
+use calamine::{Data, Reader, Xlsx};
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+use crate::application::bulk::{Cell, Table};
+
+/// Read the first worksheet of a workbook; its first row is the header.
+pub fn read_xlsx(bytes: &[u8]) -> Result<Table, String> {
+    let mut workbook: Xlsx<_> =
+        Xlsx::new(Cursor::new(bytes)).map_err(|e| format!("Invalid XLSX file: {}", e))?;
+    let range = workbook
+        .worksheet_range_at(0)
+        .ok_or("The workbook has no worksheets")?
+        .map_err(|e| format!("Invalid XLSX worksheet: {}", e))?;
+
+    let first_row = range
+        .start()
+        .map(|(row, _)| row as usize)
+        .unwrap_or_default();
+    let mut rows = range.rows();
+    let Some(header) = rows.next() else {
+        return Ok(Table::default());
+    };
+    let mut table = Table::new(header.iter().map(cell_text));
+    for (i, row) in rows.enumerate() {
+        // Row numbers as the spreadsheet shows them, header included
+        table.push(first_row + i + 2, row.iter().map(cell_text).collect());
+    }
+    Ok(table)
+}
+
+fn cell_text(cell: &Data) -> String {
+    match cell {
+        Data::Empty => String::new(),
+        Data::String(s) | Data::DateTimeIso(s) | Data::DurationIso(s) => s.clone(),
+        // Numbers are stored as floats; ids and amounts are whole
+        Data::Float(f) if f.fract() == 0.0 && f.abs() < 1e15 => (*f as i64).to_string(),
+        Data::Float(f) => f.to_string(),
+        Data::Int(i) => i.to_string(),
+        Data::Bool(b) => b.to_string(),
+        Data::DateTime(at) => at
+            .as_datetime()
+            .map(|at| at.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| at.as_f64().to_string()),
+        Data::Error(e) => e.to_string(),
+    }
+}
+
+/// Write export rows to a single-sheet workbook with a bold header row.
+pub fn write_xlsx(sheet: &str, columns: &[&str], rows: &[Vec<Cell>]) -> Result<Vec<u8>, String> {
+    let mut workbook = Workbook::new();
+    let bold = Format::new().set_bold();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_name(sheet).map_err(xlsx_err)?;
+
+    for (col, name) in columns.iter().enumerate() {
+        worksheet
+            .write_string_with_format(0, col as u16, *name, &bold)
+            .map_err(xlsx_err)?;
+    }
+    for (i, row) in rows.iter().enumerate() {
+        let r = i as u32 + 1;
+        for (col, cell) in row.iter().enumerate() {
+            let col = col as u16;
+            match cell {
+                Cell::Empty => {}
+                Cell::Text(s) => {
+                    worksheet.write_string(r, col, s).map_err(xlsx_err)?;
+                }
+                Cell::Number(n) => {
+                    worksheet
+                        .write_number(r, col, *n as f64)
+                        .map_err(xlsx_err)?;
+                }
+            }
+        }
+    }
+    worksheet.set_freeze_panes(1, 0).map_err(xlsx_err)?;
+
+    workbook.save_to_buffer().map_err(xlsx_err)
+}
+
+fn xlsx_err(e: XlsxError) -> String {
+    format!("Cannot write XLSX: {}", e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_back_what_it_writes() {
+        let rows = vec![
+            vec!["TAG-1".into(), Cell::Number(3), Cell::Empty],
+            vec!["TAG-2".into(), Cell::Empty, "2025-03-01T00:00:00Z".into()],
+        ];
+        let bytes = write_xlsx(
+            "id-tags",
+            &["id_tag", "max_active_transactions", "expiry_date"],
+            &rows,
+        )
+        .unwrap();
+
+        let table = read_xlsx(&bytes).unwrap();
+        assert_eq!(
+            table.headers,
+            ["id_tag", "max_active_transactions", "expiry_date"]
+        );
+        let records: Vec<_> = table.records().collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].line(), 2);
+        assert_eq!(records[0].get("max_active_transactions"), Some("3"));
+        assert_eq!(records[1].get("max_active_transactions"), None);
+        assert_eq!(records[1].get("expiry_date"), Some("2025-03-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn rejects_files_that_are_not_workbooks() {
+        assert!(read_xlsx(b"id_tag\nTAG-1\n").is_err());
+    }
+}
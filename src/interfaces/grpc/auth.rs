@@ -0,0 +1,30 @@
+//! Authentication for gRPC calls
+//!
+//! The `authorization` metadata entry carries the same credentials as the
+//! REST `Authorization` header — `Bearer <JWT>` or an API key — and is
+//! checked by the same code.
+
+use tonic::{Request, Status};
+
+use crate::interfaces::http::middleware::{authenticate, AuthError, AuthState, AuthenticatedUser};
+
+/// The user a call is made by; `UNAUTHENTICATED` without valid credentials.
+pub async fn authenticate_request<T>(
+    auth_state: &AuthState,
+    request: &Request<T>,
+) -> Result<AuthenticatedUser, Status> {
+    let header = request
+        .metadata()
+        .get("authorization")
+        .ok_or_else(|| auth_status(AuthError::MissingToken))?
+        .to_str()
+        .map_err(|_| auth_status(AuthError::InvalidToken))?;
+    authenticate(header, auth_state).await.map_err(auth_status)
+}
+
+fn auth_status(error: AuthError) -> Status {
+    match error {
+        AuthError::InsufficientPermissions => Status::permission_denied(error.message()),
+        _ => Status::unauthenticated(error.message()),
+    }
+}
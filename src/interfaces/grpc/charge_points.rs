@@ -0,0 +1,98 @@
+//! Charge point queries
+
+use tonic::{Request, Response, Status};
+
+use super::auth::authenticate_request;
+use super::proto::charge_points_server::ChargePoints;
+use super::proto::{
+    ChargePoint, Connector, GetChargePointRequest, ListChargePointsRequest,
+    ListChargePointsResponse,
+};
+use super::{timestamp, GrpcState};
+use crate::interfaces::http::modules::charge_points::ChargePointDto;
+
+pub struct ChargePointsApi {
+    state: GrpcState,
+}
+
+impl ChargePointsApi {
+    pub fn new(state: GrpcState) -> Self {
+        Self { state }
+    }
+
+    fn to_proto(&self, cp: crate::domain::ChargePoint) -> ChargePoint {
+        let is_online = self.state.session_registry.is_connected(&cp.id);
+        // Same representation as the REST API
+        let dto = ChargePointDto::from_domain(cp, is_online);
+        ChargePoint {
+            id: dto.id,
+            vendor: dto.vendor,
+            model: dto.model,
+            serial_number: dto.serial_number,
+            firmware_version: dto.firmware_version,
+            ocpp_version: dto.ocpp_version,
+            status: dto.status,
+            is_online: dto.is_online,
+            connectors: dto
+                .connectors
+                .into_iter()
+                .map(|c| Connector {
+                    id: c.id,
+                    status: c.status,
+                    error_code: c.error_code,
+                    info: c.error_info,
+                })
+                .collect(),
+            registered_at: Some(timestamp(dto.registered_at)),
+            last_heartbeat: dto.last_heartbeat.map(timestamp),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ChargePoints for ChargePointsApi {
+    async fn list_charge_points(
+        &self,
+        request: Request<ListChargePointsRequest>,
+    ) -> Result<Response<ListChargePointsResponse>, Status> {
+        authenticate_request(&self.state.auth, &request).await?;
+        let online_only = request.into_inner().online_only;
+
+        let charge_points = self
+            .state
+            .repos
+            .charge_points()
+            .find_all()
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let charge_points = charge_points
+            .into_iter()
+            .map(|cp| self.to_proto(cp))
+            .filter(|cp| cp.is_online || !online_only)
+            .collect();
+        Ok(Response::new(ListChargePointsResponse { charge_points }))
+    }
+
+    async fn get_charge_point(
+        &self,
+        request: Request<GetChargePointRequest>,
+    ) -> Result<Response<ChargePoint>, Status> {
+        authenticate_request(&self.state.auth, &request).await?;
+        let charge_point_id = request.into_inner().charge_point_id;
+
+        match self
+            .state
+            .repos
+            .charge_points()
+            .find_by_id(&charge_point_id)
+            .await
+        {
+            Ok(Some(cp)) => Ok(Response::new(self.to_proto(cp))),
+            Ok(None) => Err(Status::not_found(format!(
+                "Charge point '{}' not found",
+                charge_point_id
+            ))),
+            Err(e) => Err(Status::internal(e.to_string())),
+        }
+    }
+}
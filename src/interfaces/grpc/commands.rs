@@ -0,0 +1,188 @@
+//! Station commands through [`OcppOutboundPort`]
+//!
+//! Each call waits for the station's reply. Unlike the REST handlers these
+//! do not update stored state themselves (a stopped transaction, a saved
+//! profile); the station reports the outcome over OCPP as usual.
+//!
+//! [`OcppOutboundPort`]: crate::application::ports::OcppOutboundPort
+
+use tonic::{Request, Response, Status};
+
+use super::auth::authenticate_request;
+use super::proto::commands_server::Commands;
+use super::proto::{
+    self, ChangeAvailabilityRequest, ClearChargingProfileRequest, CommandReply,
+    RemoteStartTransactionRequest, RemoteStopTransactionRequest, ResetRequest,
+    SetChargingProfileRequest, UnlockConnectorRequest,
+};
+use super::GrpcState;
+use crate::application::charging::commands::dispatcher::ClearChargingProfileCriteria;
+use crate::application::{Availability, CommandError, ResetKind};
+
+pub struct CommandsApi {
+    state: GrpcState,
+}
+
+impl CommandsApi {
+    pub fn new(state: GrpcState) -> Self {
+        Self { state }
+    }
+
+    /// Authenticate the call and make sure the station is connected.
+    async fn check<T>(&self, request: &Request<T>, charge_point_id: &str) -> Result<(), Status> {
+        authenticate_request(&self.state.auth, request).await?;
+        if !self.state.session_registry.is_connected(charge_point_id) {
+            return Err(Status::not_found(format!(
+                "Charge point '{}' is not connected",
+                charge_point_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Turn a station's reply into the call's response.
+fn reply(result: Result<String, CommandError>) -> Result<Response<CommandReply>, Status> {
+    let status = result.map_err(command_status)?;
+    let accepted = status.contains("Accepted") || status.contains("Unlocked");
+    Ok(Response::new(CommandReply { status, accepted }))
+}
+
+fn command_status(error: CommandError) -> Status {
+    let message = error.to_string();
+    match error {
+        CommandError::NotConnected(_) => Status::not_found(message),
+        CommandError::Timeout => Status::deadline_exceeded(message),
+        CommandError::UnsupportedVersion(_) => Status::failed_precondition(message),
+        CommandError::InvalidPayload(_) => Status::invalid_argument(message),
+        CommandError::SendFailed(_) => Status::unavailable(message),
+        CommandError::CallError { .. } | CommandError::InvalidResponse(_) => {
+            Status::internal(message)
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl Commands for CommandsApi {
+    async fn remote_start_transaction(
+        &self,
+        request: Request<RemoteStartTransactionRequest>,
+    ) -> Result<Response<CommandReply>, Status> {
+        self.check(&request, &request.get_ref().charge_point_id)
+            .await?;
+        let req = request.into_inner();
+        reply(
+            self.state
+                .commands
+                .remote_start_transaction(&req.charge_point_id, &req.id_tag, req.connector_id)
+                .await,
+        )
+    }
+
+    async fn remote_stop_transaction(
+        &self,
+        request: Request<RemoteStopTransactionRequest>,
+    ) -> Result<Response<CommandReply>, Status> {
+        self.check(&request, &request.get_ref().charge_point_id)
+            .await?;
+        let req = request.into_inner();
+        reply(
+            self.state
+                .commands
+                .remote_stop_transaction(&req.charge_point_id, req.transaction_id)
+                .await,
+        )
+    }
+
+    async fn reset(
+        &self,
+        request: Request<ResetRequest>,
+    ) -> Result<Response<CommandReply>, Status> {
+        self.check(&request, &request.get_ref().charge_point_id)
+            .await?;
+        let req = request.into_inner();
+        let reset_type = match req.reset_type() {
+            proto::ResetType::Soft => ResetKind::Soft,
+            proto::ResetType::Hard => ResetKind::Hard,
+        };
+        reply(
+            self.state
+                .commands
+                .reset(&req.charge_point_id, reset_type)
+                .await,
+        )
+    }
+
+    async fn unlock_connector(
+        &self,
+        request: Request<UnlockConnectorRequest>,
+    ) -> Result<Response<CommandReply>, Status> {
+        self.check(&request, &request.get_ref().charge_point_id)
+            .await?;
+        let req = request.into_inner();
+        reply(
+            self.state
+                .commands
+                .unlock_connector(&req.charge_point_id, req.connector_id)
+                .await,
+        )
+    }
+
+    async fn change_availability(
+        &self,
+        request: Request<ChangeAvailabilityRequest>,
+    ) -> Result<Response<CommandReply>, Status> {
+        self.check(&request, &request.get_ref().charge_point_id)
+            .await?;
+        let req = request.into_inner();
+        let availability = match req.availability() {
+            proto::Availability::Operative => Availability::Operative,
+            proto::Availability::Inoperative => Availability::Inoperative,
+        };
+        reply(
+            self.state
+                .commands
+                .change_availability(&req.charge_point_id, req.connector_id, availability)
+                .await,
+        )
+    }
+
+    async fn set_charging_profile(
+        &self,
+        request: Request<SetChargingProfileRequest>,
+    ) -> Result<Response<CommandReply>, Status> {
+        self.check(&request, &request.get_ref().charge_point_id)
+            .await?;
+        let req = request.into_inner();
+        let profile = serde_json::from_str(&req.charging_profile_json).map_err(|e| {
+            Status::invalid_argument(format!("Invalid charging_profile_json: {}", e))
+        })?;
+        reply(
+            self.state
+                .commands
+                .set_charging_profile(&req.charge_point_id, req.evse_id, profile)
+                .await,
+        )
+    }
+
+    async fn clear_charging_profile(
+        &self,
+        request: Request<ClearChargingProfileRequest>,
+    ) -> Result<Response<CommandReply>, Status> {
+        self.check(&request, &request.get_ref().charge_point_id)
+            .await?;
+        let req = request.into_inner();
+        let criteria = ClearChargingProfileCriteria {
+            charging_profile_id: req.charging_profile_id,
+            evse_id: req.evse_id,
+            charging_profile_purpose: req.charging_profile_purpose,
+            stack_level: req.stack_level,
+        };
+        reply(
+            self.state
+                .commands
+                .clear_charging_profile(&req.charge_point_id, criteria)
+                .await,
+        )
+    }
+}
@@ -0,0 +1,80 @@
+//! Event subscription
+//!
+//! Streams the same events, with the same filter semantics, as the
+//! notification WebSocket.
+
+use std::pin::Pin;
+
+use futures_util::{stream, Stream};
+use tonic::{Request, Response, Status};
+use tracing::{error, info};
+
+use super::auth::authenticate_request;
+use super::proto::events_server::Events;
+use super::proto::{Event, SubscribeEventsRequest};
+use super::{timestamp, GrpcState};
+use crate::application::events::EventMessage;
+use crate::interfaces::ws::notifications::EventFilter;
+
+pub struct EventsApi {
+    state: GrpcState,
+}
+
+impl EventsApi {
+    pub fn new(state: GrpcState) -> Self {
+        Self { state }
+    }
+}
+
+fn to_proto(message: EventMessage) -> Result<Event, Status> {
+    // The event's data, without the "type" tag that event_type carries
+    let mut value = serde_json::to_value(&message.event)
+        .map_err(|e| Status::internal(format!("Failed to serialize event: {}", e)))?;
+    let data = value.get_mut("data").map(serde_json::Value::take);
+    Ok(Event {
+        id: message.id,
+        timestamp: Some(timestamp(message.timestamp)),
+        event_type: message.event.event_type().to_string(),
+        charge_point_id: message.event.charge_point_id().map(String::from),
+        data_json: data.unwrap_or_default().to_string(),
+    })
+}
+
+#[tonic::async_trait]
+impl Events for EventsApi {
+    type SubscribeEventsStream = Pin<Box<dyn Stream<Item = Result<Event, Status>> + Send>>;
+
+    async fn subscribe_events(
+        &self,
+        request: Request<SubscribeEventsRequest>,
+    ) -> Result<Response<Self::SubscribeEventsStream>, Status> {
+        let user = authenticate_request(&self.state.auth, &request).await?;
+        let req = request.into_inner();
+        let filter = EventFilter {
+            charge_point_id: req.charge_point_id,
+            event_types: (!req.event_types.is_empty()).then(|| req.event_types.join(",")),
+        };
+        info!(
+            user = %user.username,
+            charge_point = ?filter.charge_point_id,
+            event_types = ?filter.event_types,
+            "gRPC event subscription"
+        );
+
+        // Ends when the client goes away and the stream is dropped
+        let subscriber = self.state.event_bus.subscribe_cluster();
+        let events = stream::unfold((subscriber, filter), |(mut subscriber, filter)| async move {
+            loop {
+                let message = subscriber.recv().await?;
+                if !filter.matches(&message) {
+                    continue;
+                }
+                match to_proto(message) {
+                    Ok(event) => return Some((Ok(event), (subscriber, filter))),
+                    Err(status) => error!("{}", status.message()),
+                }
+            }
+        });
+        Ok(Response::new(Box::pin(events)))
+    }
+}
@@ -1 +1,38 @@
-//! gRPC interfaces (placeholder for future implementation)
+//! gRPC interface
+//!
+//! A tonic server exposing charge point and transaction queries, station
+//! commands through [`OcppOutboundPort`] and a server-streaming event
+//! subscription fed by the [`EventBus`](crate::application::EventBus).
+//! The API is defined in `proto/ocpp.proto`; calls authenticate like REST
+//! requests (see [`auth`]).
+//!
+//! [`OcppOutboundPort`]: crate::application::ports::OcppOutboundPort
+
+pub mod auth;
+pub mod charge_points;
+pub mod commands;
+pub mod events;
+pub mod server;
+pub mod transactions;
+
+/// Messages, servers and clients generated from `proto/ocpp.proto`.
+pub mod proto {
+    tonic::include_proto!("texnouz.ocpp.v1");
+}
+
+pub use server::{serve, GrpcState};
+
+use chrono::{DateTime, Utc};
+
+/// A protobuf timestamp for `at`.
+pub(crate) fn timestamp(at: DateTime<Utc>) -> prost_types::Timestamp {
+    prost_types::Timestamp {
+        seconds: at.timestamp(),
+        nanos: at.timestamp_subsec_nanos() as i32,
+    }
+}
+
+/// `ts` as a UTC time; `None` when it is out of range.
+pub(crate) fn from_timestamp(ts: &prost_types::Timestamp) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(ts.seconds, ts.nanos.try_into().ok()?)
+}
@@ -0,0 +1,54 @@
+//! gRPC server
+
+use std::sync::Arc;
+
+use tokio::net::TcpListener;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::Server;
+use tracing::info;
+
+use super::charge_points::ChargePointsApi;
+use super::commands::CommandsApi;
+use super::events::EventsApi;
+use super::proto::charge_points_server::ChargePointsServer;
+use super::proto::commands_server::CommandsServer;
+use super::proto::events_server::EventsServer;
+use super::proto::transactions_server::TransactionsServer;
+use super::transactions::TransactionsApi;
+use crate::application::ports::OcppOutboundPort;
+use crate::application::{SharedEventBus, SharedSessionRegistry};
+use crate::domain::RepositoryProvider;
+use crate::interfaces::http::middleware::AuthState;
+use crate::shared::shutdown::ShutdownSignal;
+
+/// Everything the gRPC services need
+#[derive(Clone)]
+pub struct GrpcState {
+    pub repos: Arc<dyn RepositoryProvider>,
+    pub session_registry: SharedSessionRegistry,
+    pub commands: Arc<dyn OcppOutboundPort>,
+    pub event_bus: SharedEventBus,
+    pub auth: AuthState,
+}
+
+/// Serve the gRPC API on `listener` until `shutdown` fires.
+pub async fn serve(
+    listener: TcpListener,
+    state: GrpcState,
+    shutdown: ShutdownSignal,
+) -> Result<(), tonic::transport::Error> {
+    if let Ok(addr) = listener.local_addr() {
+        info!("gRPC server listening on {}", addr);
+    }
+
+    Server::builder()
+        .add_service(ChargePointsServer::new(ChargePointsApi::new(state.clone())))
+        .add_service(CommandsServer::new(CommandsApi::new(state.clone())))
+        .add_service(TransactionsServer::new(TransactionsApi::new(state.clone())))
+        .add_service(EventsServer::new(EventsApi::new(state)))
+        .serve_with_incoming_shutdown(TcpIncoming::from(listener), async move {
+            shutdown.wait().await;
+            info!("🛑 gRPC server received shutdown signal");
+        })
+        .await
+}
@@ -0,0 +1,146 @@
+//! Transaction queries
+
+use std::collections::HashMap;
+
+use tonic::{Request, Response, Status};
+
+use super::auth::authenticate_request;
+use super::proto::transactions_server::Transactions;
+use super::proto::{
+    GetTransactionRequest, ListTransactionsRequest, ListTransactionsResponse, Transaction,
+};
+use super::{from_timestamp, timestamp, GrpcState};
+use crate::domain::{self, TransactionBilling, TransactionStatus};
+
+/// Page size when the request leaves it at 0.
+const DEFAULT_PAGE_SIZE: u32 = 100;
+/// Largest page returned.
+const MAX_PAGE_SIZE: u32 = 1000;
+
+pub struct TransactionsApi {
+    state: GrpcState,
+}
+
+impl TransactionsApi {
+    pub fn new(state: GrpcState) -> Self {
+        Self { state }
+    }
+}
+
+fn to_proto(tx: domain::Transaction, billing: Option<TransactionBilling>) -> Transaction {
+    Transaction {
+        id: tx.id,
+        energy_wh: tx.energy_consumed(),
+        status: format!("{:?}", tx.status),
+        charge_point_id: tx.charge_point_id,
+        connector_id: tx.connector_id,
+        id_tag: tx.id_tag,
+        meter_start: tx.meter_start,
+        meter_stop: tx.meter_stop,
+        started_at: Some(timestamp(tx.started_at)),
+        stopped_at: tx.stopped_at.map(timestamp),
+        stop_reason: tx.stop_reason,
+        total_cost: billing.as_ref().map(|b| b.total_cost),
+        currency: billing.map(|b| b.currency),
+    }
+}
+
+#[tonic::async_trait]
+impl Transactions for TransactionsApi {
+    async fn list_transactions(
+        &self,
+        request: Request<ListTransactionsRequest>,
+    ) -> Result<Response<ListTransactionsResponse>, Status> {
+        authenticate_request(&self.state.auth, &request).await?;
+        let req = request.into_inner();
+        let from = req.from.as_ref().map(from_timestamp);
+        let to = req.to.as_ref().map(from_timestamp);
+        if matches!(from, Some(None)) || matches!(to, Some(None)) {
+            return Err(Status::invalid_argument("from/to out of range"));
+        }
+        let (from, to) = (from.flatten(), to.flatten());
+        let page_size = match req.page_size {
+            0 => DEFAULT_PAGE_SIZE,
+            n => n.min(MAX_PAGE_SIZE),
+        } as usize;
+        let transactions = self.state.repos.transactions();
+
+        // One more than asked for, to tell whether another page follows
+        let mut page = match &req.charge_point_id {
+            Some(charge_point_id) => {
+                let mut all = transactions
+                    .find_by_charge_point(charge_point_id)
+                    .await
+                    .map_err(|e| Status::internal(e.to_string()))?;
+                all.retain(|t| {
+                    t.id > req.after_id
+                        && from.is_none_or(|from| t.started_at >= from)
+                        && to.is_none_or(|to| t.started_at < to)
+                });
+                all.sort_by_key(|t| t.id);
+                all.truncate(page_size + 1);
+                all
+            }
+            None => transactions
+                .find_page(req.after_id, from, to, page_size as u64 + 1)
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?,
+        };
+        let more = page.len() > page_size;
+        page.truncate(page_size);
+        let next_after_id = if more { page.last().map(|t| t.id) } else { None };
+        if req.active_only {
+            page.retain(|t| t.status == TransactionStatus::Active);
+        }
+
+        let ids: Vec<i32> = page.iter().map(|t| t.id).collect();
+        let mut billings: HashMap<i32, TransactionBilling> = self
+            .state
+            .repos
+            .billing()
+            .find_billings(&ids)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .into_iter()
+            .map(|b| (b.transaction_id, b))
+            .collect();
+
+        Ok(Response::new(ListTransactionsResponse {
+            transactions: page
+                .into_iter()
+                .map(|t| {
+                    let billing = billings.remove(&t.id);
+                    to_proto(t, billing)
+                })
+                .collect(),
+            next_after_id,
+        }))
+    }
+
+    async fn get_transaction(
+        &self,
+        request: Request<GetTransactionRequest>,
+    ) -> Result<Response<Transaction>, Status> {
+        authenticate_request(&self.state.auth, &request).await?;
+        let transaction_id = request.into_inner().transaction_id;
+
+        let tx = self
+            .state
+            .repos
+            .transactions()
+            .find_by_id(transaction_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ok_or_else(|| {
+                Status::not_found(format!("Transaction {} not found", transaction_id))
+            })?;
+        let billing = self
+            .state
+            .repos
+            .billing()
+            .get_billing(transaction_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        Ok(Response::new(to_proto(tx, billing)))
+    }
+}
@@ -15,6 +15,7 @@ use serde_json::json;
 use crate::infrastructure::crypto::api_key::hash_api_key;
 use crate::infrastructure::crypto::jwt::{verify_token, JwtConfig, TokenClaims};
 use crate::infrastructure::database::entities::api_key;
+use crate::interfaces::http::common::ApiResponse;
 
 /// API key prefix
 const API_KEY_PREFIX: &str = "txocpp_";
@@ -74,6 +75,20 @@ impl AuthenticatedUser {
     }
 }
 
+/// Reject anyone but an administrator with `403 Forbidden`.
+pub fn require_admin<T>(
+    user: &AuthenticatedUser,
+) -> Result<(), (StatusCode, Json<ApiResponse<T>>)> {
+    if user.is_admin() {
+        Ok(())
+    } else {
+        Err((
+            StatusCode::FORBIDDEN,
+            Json(ApiResponse::error("Administrator role required")),
+        ))
+    }
+}
+
 fn extract_token(auth_header: &str) -> Option<&str> {
     if auth_header.starts_with("Bearer ") {
         Some(&auth_header[7..])
@@ -97,27 +112,36 @@ pub async fn auth_middleware(
         return auth_error_response(AuthError::MissingToken);
     };
 
+    match authenticate(&auth_header, &auth_state).await {
+        Ok(user) => {
+            request.extensions_mut().insert(user);
+            next.run(request).await
+        }
+        Err(e) => auth_error_response(e),
+    }
+}
+
+/// Resolve an `Authorization` value — an API key or `Bearer <JWT>` — to
+/// the user it belongs to. Shared by the REST and gRPC interfaces.
+pub async fn authenticate(
+    auth_header: &str,
+    auth_state: &AuthState,
+) -> Result<AuthenticatedUser, AuthError> {
     // Try API key first
-    if is_api_key_format(&auth_header) {
-        return handle_api_key_auth(&auth_header, &auth_state, request, next).await;
+    if is_api_key_format(auth_header) {
+        return try_api_key_auth(auth_header, auth_state)
+            .await
+            .ok_or(AuthError::InvalidApiKey);
     }
 
     // Try Bearer token
-    let Some(token) = extract_token(&auth_header) else {
-        return auth_error_response(AuthError::InvalidToken);
-    };
-
-    match verify_token(token, &auth_state.jwt_config) {
-        Ok(claims) => {
-            if claims.is_expired() {
-                return auth_error_response(AuthError::ExpiredToken);
-            }
-            let user = AuthenticatedUser::from_claims(claims);
-            request.extensions_mut().insert(user);
-            next.run(request).await
-        }
-        Err(_) => auth_error_response(AuthError::InvalidToken),
+    let token = extract_token(auth_header).ok_or(AuthError::InvalidToken)?;
+    let claims =
+        verify_token(token, &auth_state.jwt_config).map_err(|_| AuthError::InvalidToken)?;
+    if claims.is_expired() {
+        return Err(AuthError::ExpiredToken);
     }
+    Ok(AuthenticatedUser::from_claims(claims))
 }
 
 /// Optional authentication middleware
@@ -150,21 +174,6 @@ pub async fn optional_auth_middleware(
     next.run(request).await
 }
 
-async fn handle_api_key_auth(
-    api_key: &str,
-    auth_state: &AuthState,
-    mut request: Request<Body>,
-    next: Next,
-) -> Response {
-    match try_api_key_auth(api_key, auth_state).await {
-        Some(user) => {
-            request.extensions_mut().insert(user);
-            next.run(request).await
-        }
-        None => auth_error_response(AuthError::InvalidApiKey),
-    }
-}
-
 async fn try_api_key_auth(api_key_str: &str, auth_state: &AuthState) -> Option<AuthenticatedUser> {
     let key_hash = hash_api_key(api_key_str);
 
@@ -207,20 +216,30 @@ async fn try_api_key_auth(api_key_str: &str, auth_state: &AuthState) -> Option<A
     })
 }
 
+impl AuthError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::MissingToken => "Missing authentication token",
+            Self::InvalidToken => "Invalid authentication token",
+            Self::ExpiredToken => "Token has expired",
+            Self::InsufficientPermissions => "Insufficient permissions",
+            Self::InvalidCredentials => "Invalid credentials",
+            Self::UserNotFound => "User not found",
+            Self::InvalidApiKey => "Invalid API key",
+        }
+    }
+}
+
 fn auth_error_response(error: AuthError) -> Response {
-    let (status, message) = match error {
-        AuthError::MissingToken => (StatusCode::UNAUTHORIZED, "Missing authentication token"),
-        AuthError::InvalidToken => (StatusCode::UNAUTHORIZED, "Invalid authentication token"),
-        AuthError::ExpiredToken => (StatusCode::UNAUTHORIZED, "Token has expired"),
-        AuthError::InsufficientPermissions => (StatusCode::FORBIDDEN, "Insufficient permissions"),
-        AuthError::InvalidCredentials => (StatusCode::UNAUTHORIZED, "Invalid credentials"),
-        AuthError::UserNotFound => (StatusCode::NOT_FOUND, "User not found"),
-        AuthError::InvalidApiKey => (StatusCode::UNAUTHORIZED, "Invalid API key"),
+    let status = match error {
+        AuthError::InsufficientPermissions => StatusCode::FORBIDDEN,
+        AuthError::UserNotFound => StatusCode::NOT_FOUND,
+        _ => StatusCode::UNAUTHORIZED,
     };
 
     let body = Json(json!({
         "success": false,
-        "error": message
+        "error": error.message()
     }));
 
     (status, body).into_response()
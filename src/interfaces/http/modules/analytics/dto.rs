@@ -1,8 +1,12 @@
 //! Analytics API data transfer objects
 
+use std::collections::BTreeMap;
+
 use serde::Serialize;
 use utoipa::ToSchema;
 
+use crate::domain::{AvailabilityStats, ConnectorStatusInterval};
+
 // ── Summary ────────────────────────────────────────────────────
 
 /// Overall dashboard summary.
@@ -165,3 +169,149 @@ mod tests {
         assert!(json.contains("\"busiest_hour\":14"));
     }
 }
+
+// ── Availability (SLA) ─────────────────────────────────────────
+
+/// Uptime figures of a station or connector over the report window.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UptimeStats {
+    /// Seconds in the window (from the later of `from` and registration).
+    pub period_seconds: i64,
+    /// Seconds available.
+    pub available_seconds: i64,
+    /// Availability in percent (two decimals).
+    pub uptime_percent: f64,
+    /// Transitions from available to unavailable within the window.
+    pub failures: u32,
+    /// Mean time between failures in seconds (absent without failures).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtbf_seconds: Option<i64>,
+    /// Longest unavailable stretch in seconds.
+    pub longest_outage_seconds: i64,
+}
+
+impl From<AvailabilityStats> for UptimeStats {
+    fn from(s: AvailabilityStats) -> Self {
+        Self {
+            period_seconds: s.period_seconds,
+            available_seconds: s.available_seconds,
+            uptime_percent: s.uptime_percent,
+            failures: s.failures,
+            mtbf_seconds: s.mtbf_seconds,
+            longest_outage_seconds: s.longest_outage_seconds,
+        }
+    }
+}
+
+/// Connector availability: station connected and connector not
+/// Faulted / Unavailable.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConnectorUptime {
+    pub connector_id: u32,
+    #[serde(flatten)]
+    pub stats: UptimeStats,
+}
+
+/// Station availability: time connected to the Central System.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StationAvailability {
+    pub charge_point_id: String,
+    #[serde(flatten)]
+    pub stats: UptimeStats,
+    /// Connectors with recorded status history.
+    pub connectors: Vec<ConnectorUptime>,
+}
+
+/// Availability report for a time range.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AvailabilityReport {
+    /// Report window start (ISO 8601).
+    pub from: String,
+    /// Report window end (ISO 8601), never later than now.
+    pub to: String,
+    pub stations: Vec<StationAvailability>,
+}
+
+// ── Faults ─────────────────────────────────────────────────────
+
+/// Faults sharing one grouping key.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FaultGroup {
+    /// Error code, vendor, model, ... ("Unknown" when not reported).
+    pub key: String,
+    pub fault_count: u64,
+    /// Time spent Faulted within the window; open faults count until `to`.
+    pub fault_seconds: i64,
+    /// Distinct stations affected.
+    pub stations: u64,
+    /// Distinct connectors affected.
+    pub connectors: u64,
+}
+
+/// Faults that started in a time range, most frequent first.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FaultReport {
+    /// Report window start (ISO 8601).
+    pub from: String,
+    /// Report window end (ISO 8601), never later than now.
+    pub to: String,
+    pub group_by: String,
+    pub total_faults: u64,
+    pub groups: Vec<FaultGroup>,
+}
+
+// ── Connector states ───────────────────────────────────────────
+
+/// Time a connector spent in each status.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConnectorStateTime {
+    pub charge_point_id: String,
+    pub connector_id: u32,
+    /// Seconds per status; "Offline" while the station was disconnected.
+    pub seconds_by_status: BTreeMap<String, i64>,
+}
+
+/// Time-in-state breakdown for a time range.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConnectorStatesReport {
+    /// Report window start (ISO 8601).
+    pub from: String,
+    /// Report window end (ISO 8601), never later than now.
+    pub to: String,
+    pub connectors: Vec<ConnectorStateTime>,
+}
+
+/// One entry of a connector's status history.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConnectorStatusEntry {
+    pub charge_point_id: String,
+    pub connector_id: u32,
+    pub status: String,
+    pub error_code: Option<String>,
+    pub info: Option<String>,
+    pub vendor_id: Option<String>,
+    pub vendor_error_code: Option<String>,
+    /// Firmware the station ran at the time.
+    pub firmware_version: Option<String>,
+    /// ISO 8601.
+    pub started_at: String,
+    /// ISO 8601; absent for the current status.
+    pub ended_at: Option<String>,
+}
+
+impl From<ConnectorStatusInterval> for ConnectorStatusEntry {
+    fn from(i: ConnectorStatusInterval) -> Self {
+        Self {
+            charge_point_id: i.charge_point_id,
+            connector_id: i.connector_id,
+            status: i.status,
+            error_code: i.error_code,
+            info: i.info,
+            vendor_id: i.vendor_id,
+            vendor_error_code: i.vendor_error_code,
+            firmware_version: i.firmware_version,
+            started_at: i.started_at.to_rfc3339(),
+            ended_at: i.ended_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
@@ -1,12 +1,17 @@
 //! Analytics API handlers
 //!
 //! All endpoints use SeaORM entity queries directly for efficient SQL aggregation.
-//! They avoid loading entire result sets into memory when possible.
+//! They avoid loading entire result sets into memory when possible. The
+//! availability, fault and connector state reports read the recorded
+//! connection and status history through the repositories instead.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use axum::extract::{Query, State};
 use axum::Json;
 use axum::http::StatusCode;
-use chrono::{Datelike, Duration, NaiveTime, Timelike, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Timelike, Utc};
 use sea_orm::prelude::*;
 use sea_orm::{
     ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
@@ -14,6 +19,11 @@ use sea_orm::{
 
 use super::dto::*;
 use crate::application::charging::session::SharedSessionRegistry;
+use crate::domain::availability::{intersect_periods, merge_periods, time_in_state, Period};
+use crate::domain::{
+    AvailabilityStats, ChargePoint, ConnectionSession, ConnectorStatusInterval, DomainError,
+    DomainResult, RepositoryProvider,
+};
 use crate::infrastructure::database::entities::{
     charge_point as cp_entity, transaction as tx_entity,
 };
@@ -24,6 +34,7 @@ use crate::interfaces::http::common::ApiResponse;
 pub struct AnalyticsState {
     pub db: DatabaseConnection,
     pub session_registry: SharedSessionRegistry,
+    pub repos: Arc<dyn RepositoryProvider>,
 }
 
 // ── Query params ───────────────────────────────────────────────
@@ -42,6 +53,45 @@ pub struct PeakHoursParams {
     pub days: Option<u32>,
 }
 
+/// Time range and optional station for the availability and connector
+/// state reports.
+#[derive(Debug, serde::Deserialize)]
+pub struct AvailabilityParams {
+    /// Range start, RFC 3339 (default 30 days before `to`).
+    pub from: Option<String>,
+    /// Range end, RFC 3339 (default now).
+    pub to: Option<String>,
+    /// Report a single station.
+    pub charge_point_id: Option<String>,
+}
+
+/// Filters and grouping for the fault report.
+#[derive(Debug, serde::Deserialize)]
+pub struct FaultParams {
+    /// Range start, RFC 3339 (default 30 days before `to`).
+    pub from: Option<String>,
+    /// Range end, RFC 3339 (default now).
+    pub to: Option<String>,
+    /// Only faults of this station.
+    pub charge_point_id: Option<String>,
+    /// Only faults of this connector.
+    pub connector_id: Option<u32>,
+    /// error_code (default), vendor_error_code, vendor, model, firmware or connector.
+    pub group_by: Option<String>,
+}
+
+/// Station, optional connector and time range for the status history.
+#[derive(Debug, serde::Deserialize)]
+pub struct StatusHistoryParams {
+    pub charge_point_id: String,
+    /// Only this connector.
+    pub connector_id: Option<u32>,
+    /// Range start, RFC 3339 (default 30 days before `to`).
+    pub from: Option<String>,
+    /// Range end, RFC 3339 (default now).
+    pub to: Option<String>,
+}
+
 // ── 1. Summary ─────────────────────────────────────────────────
 
 /// Overall dashboard summary.
@@ -440,8 +490,391 @@ pub async fn analytics_station_uptime(
     Ok(Json(ApiResponse::success(StationUptimeResponse { stations })))
 }
 
+// ── 6. Availability ────────────────────────────────────────────
+
+/// Station and connector availability over a time range, computed from the
+/// recorded connection and connector status history.
+///
+/// A station is available while connected; a connector while its station
+/// is connected and it is not Faulted or Unavailable. Time before a station
+/// was registered is not counted.
+#[utoipa::path(
+    get,
+    path = "/api/v1/analytics/availability",
+    tag = "Analytics",
+    params(
+        ("from" = Option<String>, Query, description = "Range start, RFC 3339 (default 30 days before `to`)"),
+        ("to" = Option<String>, Query, description = "Range end, RFC 3339 (default now)"),
+        ("charge_point_id" = Option<String>, Query, description = "Report a single station")
+    ),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "Availability report", body = ApiResponse<AvailabilityReport>),
+        (status = 400, description = "Invalid range"),
+        (status = 404, description = "Charge point not found")
+    )
+)]
+pub async fn analytics_availability(
+    State(state): State<AnalyticsState>,
+    Query(params): Query<AvailabilityParams>,
+) -> Result<
+    Json<ApiResponse<AvailabilityReport>>,
+    (StatusCode, Json<ApiResponse<AvailabilityReport>>),
+> {
+    let error = |status: StatusCode, msg: String| {
+        (status, Json(ApiResponse::<AvailabilityReport>::error(msg)))
+    };
+    let internal = |e: DomainError| error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string());
+
+    let (from, to) = report_window(params.from.as_deref(), params.to.as_deref())
+        .map_err(|e| error(StatusCode::BAD_REQUEST, e))?;
+    let charge_point_id = params.charge_point_id.as_deref();
+    let Some(charge_points) = report_charge_points(&state.repos, charge_point_id)
+        .await
+        .map_err(internal)?
+    else {
+        return Err(error(StatusCode::NOT_FOUND, not_found(charge_point_id)));
+    };
+
+    let history = state.repos.availability();
+    let sessions = by_charge_point(
+        history
+            .find_sessions(charge_point_id, from, to)
+            .await
+            .map_err(internal)?,
+        |s| &s.charge_point_id,
+    );
+    let intervals = by_charge_point(
+        history
+            .find_status_intervals(charge_point_id, from, to)
+            .await
+            .map_err(internal)?,
+        |i| &i.charge_point_id,
+    );
+
+    let stations = charge_points
+        .into_iter()
+        .map(|cp| {
+            station_availability(
+                cp.id.clone(),
+                sessions.get(&cp.id).map(Vec::as_slice).unwrap_or_default(),
+                intervals.get(&cp.id).map(Vec::as_slice).unwrap_or_default(),
+                cp.registered_at.clamp(from, to),
+                to,
+            )
+        })
+        .collect();
+
+    Ok(Json(ApiResponse::success(AvailabilityReport {
+        from: from.to_rfc3339(),
+        to: to.to_rfc3339(),
+        stations,
+    })))
+}
+
+/// Availability of one station and each connector it reported a status for.
+fn station_availability(
+    charge_point_id: String,
+    sessions: &[ConnectionSession],
+    intervals: &[ConnectorStatusInterval],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> StationAvailability {
+    let online = online_periods(sessions, from, to);
+
+    let connectors = connector_ids(intervals)
+        .into_iter()
+        .map(|connector_id| {
+            let operative: Vec<Period> = intervals
+                .iter()
+                .filter(|i| i.connector_id == connector_id && i.is_operative())
+                .map(|i| i.period(to))
+                .collect();
+            let available = intersect_periods(&online, &merge_periods(&operative, from, to));
+            ConnectorUptime {
+                connector_id,
+                stats: AvailabilityStats::compute(&available, from, to).into(),
+            }
+        })
+        .collect();
+
+    StationAvailability {
+        charge_point_id,
+        stats: AvailabilityStats::compute(&online, from, to).into(),
+        connectors,
+    }
+}
+
+// ── 7. Faults ──────────────────────────────────────────────────
+
+/// Faults that started in a time range, grouped by error code, vendor
+/// error code, vendor, model, firmware or connector.
+#[utoipa::path(
+    get,
+    path = "/api/v1/analytics/faults",
+    tag = "Analytics",
+    params(
+        ("from" = Option<String>, Query, description = "Range start, RFC 3339 (default 30 days before `to`)"),
+        ("to" = Option<String>, Query, description = "Range end, RFC 3339 (default now)"),
+        ("charge_point_id" = Option<String>, Query, description = "Only faults of this station"),
+        ("connector_id" = Option<u32>, Query, description = "Only faults of this connector"),
+        ("group_by" = Option<String>, Query, description = "error_code (default), vendor_error_code, vendor, model, firmware or connector")
+    ),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "Fault report", body = ApiResponse<FaultReport>),
+        (status = 400, description = "Invalid range or grouping"),
+        (status = 404, description = "Charge point not found")
+    )
+)]
+pub async fn analytics_faults(
+    State(state): State<AnalyticsState>,
+    Query(params): Query<FaultParams>,
+) -> Result<Json<ApiResponse<FaultReport>>, (StatusCode, Json<ApiResponse<FaultReport>>)> {
+    let error =
+        |status: StatusCode, msg: String| (status, Json(ApiResponse::<FaultReport>::error(msg)));
+    let internal = |e: DomainError| error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string());
+
+    let (from, to) = report_window(params.from.as_deref(), params.to.as_deref())
+        .map_err(|e| error(StatusCode::BAD_REQUEST, e))?;
+    let group_by = params.group_by.as_deref().unwrap_or("error_code");
+    if !FAULT_GROUPINGS.contains(&group_by) {
+        return Err(error(
+            StatusCode::BAD_REQUEST,
+            format!("group_by must be one of: {}", FAULT_GROUPINGS.join(", ")),
+        ));
+    }
+    let charge_point_id = params.charge_point_id.as_deref();
+    let Some(charge_points) = report_charge_points(&state.repos, charge_point_id)
+        .await
+        .map_err(internal)?
+    else {
+        return Err(error(StatusCode::NOT_FOUND, not_found(charge_point_id)));
+    };
+    let charge_points: HashMap<String, ChargePoint> = charge_points
+        .into_iter()
+        .map(|cp| (cp.id.clone(), cp))
+        .collect();
+
+    let faults = state
+        .repos
+        .availability()
+        .find_faults(charge_point_id, params.connector_id, from, to)
+        .await
+        .map_err(internal)?;
+
+    #[derive(Default)]
+    struct Tally<'a> {
+        count: u64,
+        seconds: i64,
+        stations: HashSet<&'a str>,
+        connectors: HashSet<(&'a str, u32)>,
+    }
+
+    let mut tallies: HashMap<String, Tally<'_>> = HashMap::new();
+    for fault in &faults {
+        let cp = charge_points.get(&fault.charge_point_id);
+        let key = match group_by {
+            "error_code" => fault.error_code.clone(),
+            "vendor_error_code" => fault.vendor_error_code.clone(),
+            "vendor" => cp.and_then(|cp| cp.vendor.clone()),
+            "model" => cp.and_then(|cp| cp.model.clone()),
+            "firmware" => fault.firmware_version.clone(),
+            _ => Some(format!("{}/{}", fault.charge_point_id, fault.connector_id)),
+        }
+        .unwrap_or_else(|| "Unknown".to_string());
+
+        let tally = tallies.entry(key).or_default();
+        tally.count += 1;
+        tally.seconds += (fault.ended_at.unwrap_or(to).min(to) - fault.started_at).num_seconds();
+        tally.stations.insert(&fault.charge_point_id);
+        tally
+            .connectors
+            .insert((fault.charge_point_id.as_str(), fault.connector_id));
+    }
+
+    let mut groups: Vec<FaultGroup> = tallies
+        .into_iter()
+        .map(|(key, t)| FaultGroup {
+            key,
+            fault_count: t.count,
+            fault_seconds: t.seconds,
+            stations: t.stations.len() as u64,
+            connectors: t.connectors.len() as u64,
+        })
+        .collect();
+    groups.sort_by(|a, b| b.fault_count.cmp(&a.fault_count).then(a.key.cmp(&b.key)));
+
+    Ok(Json(ApiResponse::success(FaultReport {
+        from: from.to_rfc3339(),
+        to: to.to_rfc3339(),
+        group_by: group_by.to_string(),
+        total_faults: faults.len() as u64,
+        groups,
+    })))
+}
+
+// ── 8. Connector states ────────────────────────────────────────
+
+/// Time each connector spent in each status over a time range.
+///
+/// Time while the station was disconnected is reported as "Offline".
+#[utoipa::path(
+    get,
+    path = "/api/v1/analytics/connector-states",
+    tag = "Analytics",
+    params(
+        ("from" = Option<String>, Query, description = "Range start, RFC 3339 (default 30 days before `to`)"),
+        ("to" = Option<String>, Query, description = "Range end, RFC 3339 (default now)"),
+        ("charge_point_id" = Option<String>, Query, description = "Report a single station")
+    ),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "Time in state per connector", body = ApiResponse<ConnectorStatesReport>),
+        (status = 400, description = "Invalid range"),
+        (status = 404, description = "Charge point not found")
+    )
+)]
+pub async fn analytics_connector_states(
+    State(state): State<AnalyticsState>,
+    Query(params): Query<AvailabilityParams>,
+) -> Result<
+    Json<ApiResponse<ConnectorStatesReport>>,
+    (StatusCode, Json<ApiResponse<ConnectorStatesReport>>),
+> {
+    let error = |status: StatusCode, msg: String| {
+        (
+            status,
+            Json(ApiResponse::<ConnectorStatesReport>::error(msg)),
+        )
+    };
+    let internal = |e: DomainError| error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string());
+
+    let (from, to) = report_window(params.from.as_deref(), params.to.as_deref())
+        .map_err(|e| error(StatusCode::BAD_REQUEST, e))?;
+    let charge_point_id = params.charge_point_id.as_deref();
+    let Some(charge_points) = report_charge_points(&state.repos, charge_point_id)
+        .await
+        .map_err(internal)?
+    else {
+        return Err(error(StatusCode::NOT_FOUND, not_found(charge_point_id)));
+    };
+
+    let history = state.repos.availability();
+    let sessions = by_charge_point(
+        history
+            .find_sessions(charge_point_id, from, to)
+            .await
+            .map_err(internal)?,
+        |s| &s.charge_point_id,
+    );
+    let intervals = by_charge_point(
+        history
+            .find_status_intervals(charge_point_id, from, to)
+            .await
+            .map_err(internal)?,
+        |i| &i.charge_point_id,
+    );
+
+    let mut connectors = Vec::new();
+    for cp in charge_points {
+        let Some(cp_intervals) = intervals.get(&cp.id) else {
+            continue;
+        };
+        let online = online_periods(
+            sessions.get(&cp.id).map(Vec::as_slice).unwrap_or_default(),
+            from,
+            to,
+        );
+        for connector_id in connector_ids(cp_intervals) {
+            let held: Vec<ConnectorStatusInterval> = cp_intervals
+                .iter()
+                .filter(|i| i.connector_id == connector_id)
+                .cloned()
+                .collect();
+            connectors.push(ConnectorStateTime {
+                charge_point_id: cp.id.clone(),
+                connector_id,
+                seconds_by_status: time_in_state(&held, &online, from, to),
+            });
+        }
+    }
+
+    Ok(Json(ApiResponse::success(ConnectorStatesReport {
+        from: from.to_rfc3339(),
+        to: to.to_rfc3339(),
+        connectors,
+    })))
+}
+
+// ── 9. Connector status history ────────────────────────────────
+
+/// Status history of a station's connectors, oldest first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/analytics/connector-status-history",
+    tag = "Analytics",
+    params(
+        ("charge_point_id" = String, Query, description = "Station"),
+        ("connector_id" = Option<u32>, Query, description = "Only this connector"),
+        ("from" = Option<String>, Query, description = "Range start, RFC 3339 (default 30 days before `to`)"),
+        ("to" = Option<String>, Query, description = "Range end, RFC 3339 (default now)")
+    ),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "Status changes", body = ApiResponse<Vec<ConnectorStatusEntry>>),
+        (status = 400, description = "Invalid range")
+    )
+)]
+pub async fn analytics_connector_status_history(
+    State(state): State<AnalyticsState>,
+    Query(params): Query<StatusHistoryParams>,
+) -> Result<
+    Json<ApiResponse<Vec<ConnectorStatusEntry>>>,
+    (StatusCode, Json<ApiResponse<Vec<ConnectorStatusEntry>>>),
+> {
+    let error = |status: StatusCode, msg: String| {
+        (
+            status,
+            Json(ApiResponse::<Vec<ConnectorStatusEntry>>::error(msg)),
+        )
+    };
+
+    let (from, to) = report_window(params.from.as_deref(), params.to.as_deref())
+        .map_err(|e| error(StatusCode::BAD_REQUEST, e))?;
+    let intervals = state
+        .repos
+        .availability()
+        .find_status_intervals(Some(params.charge_point_id.as_str()), from, to)
+        .await
+        .map_err(|e| error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let entries: Vec<ConnectorStatusEntry> = intervals
+        .into_iter()
+        .filter(|i| params.connector_id.is_none_or(|id| i.connector_id == id))
+        .map(Into::into)
+        .collect();
+    Ok(Json(ApiResponse::success(entries)))
+}
+
 // ── Helpers ────────────────────────────────────────────────────
 
+/// Groupings accepted by the fault report.
+const FAULT_GROUPINGS: [&str; 6] = [
+    "error_code",
+    "vendor_error_code",
+    "vendor",
+    "model",
+    "firmware",
+    "connector",
+];
+
+fn parse_date(field: &str, value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| format!("Invalid {}: {}", field, e))
+}
+
 /// Produce a human-readable bucket key based on granularity.
 fn bucket_key(granularity: &str, dt: DateTimeUtc) -> String {
     match granularity {
@@ -453,3 +886,79 @@ fn bucket_key(granularity: &str, dt: DateTimeUtc) -> String {
         _ => format!("{}", dt.format("%Y-%m-%d")),
     }
 }
+
+/// Report window from optional RFC 3339 bounds.
+///
+/// `to` defaults to now and is capped at now (nothing is known about the
+/// future); `from` defaults to 30 days before `to`.
+fn report_window(
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), String> {
+    let now = Utc::now();
+    let to = match to {
+        Some(v) => parse_date("to", v)?,
+        None => now,
+    }
+    .min(now);
+    let from = match from {
+        Some(v) => parse_date("from", v)?,
+        None => to - Duration::days(30),
+    };
+    if from >= to {
+        return Err("from must be before to and in the past".to_string());
+    }
+    Ok((from, to))
+}
+
+/// The requested station, or every station. `None` if it does not exist.
+async fn report_charge_points(
+    repos: &Arc<dyn RepositoryProvider>,
+    charge_point_id: Option<&str>,
+) -> DomainResult<Option<Vec<ChargePoint>>> {
+    match charge_point_id {
+        Some(id) => Ok(repos
+            .charge_points()
+            .find_by_id(id)
+            .await?
+            .map(|cp| vec![cp])),
+        None => repos.charge_points().find_all().await.map(Some),
+    }
+}
+
+fn not_found(charge_point_id: Option<&str>) -> String {
+    format!(
+        "Charge point '{}' not found",
+        charge_point_id.unwrap_or_default()
+    )
+}
+
+fn by_charge_point<T>(items: Vec<T>, key: impl Fn(&T) -> &String) -> HashMap<String, Vec<T>> {
+    let mut grouped: HashMap<String, Vec<T>> = HashMap::new();
+    for item in items {
+        grouped.entry(key(&item).clone()).or_default().push(item);
+    }
+    grouped
+}
+
+/// Merged periods the station was connected within `[from, to)`.
+fn online_periods(
+    sessions: &[ConnectionSession],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<Period> {
+    let connected: Vec<Period> = sessions.iter().map(|s| s.period(to)).collect();
+    merge_periods(&connected, from, to)
+}
+
+/// Connectors with status history, without connector 0 (the station itself).
+fn connector_ids(intervals: &[ConnectorStatusInterval]) -> Vec<u32> {
+    let mut ids: Vec<u32> = intervals
+        .iter()
+        .map(|i| i.connector_id)
+        .filter(|&id| id > 0)
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
@@ -0,0 +1,77 @@
+//! Bulk import / export DTOs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::application::bulk::{ImportReport, RowError};
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ImportParams {
+    /// "csv" or "xlsx"; taken from the Content-Type header when omitted.
+    pub format: Option<String>,
+    /// Validate the file and report what would change, without writing.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ExportParams {
+    /// "csv" (default) or "xlsx".
+    pub format: Option<String>,
+    /// Transactions only: started at or after (ISO 8601).
+    pub from: Option<DateTime<Utc>>,
+    /// Transactions only: started before (ISO 8601).
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// A rejected row.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RowErrorDto {
+    /// Line (CSV) or row (XLSX) number in the file, header included.
+    pub line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    pub message: String,
+}
+
+impl From<RowError> for RowErrorDto {
+    fn from(e: RowError) -> Self {
+        Self {
+            line: e.line,
+            column: e.column,
+            message: e.message,
+        }
+    }
+}
+
+/// Outcome of an import.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportReportDto {
+    pub entity: String,
+    pub dry_run: bool,
+    /// Whether the rows were written; never on a dry run or when any row
+    /// is invalid.
+    pub applied: bool,
+    /// Data rows in the file.
+    pub rows: usize,
+    /// Rows that created a new entity, or would have.
+    pub created: usize,
+    /// Rows that updated an existing entity, or would have.
+    pub updated: usize,
+    pub errors: Vec<RowErrorDto>,
+}
+
+impl From<ImportReport> for ImportReportDto {
+    fn from(r: ImportReport) -> Self {
+        Self {
+            entity: r.entity.to_string(),
+            dry_run: r.dry_run,
+            applied: r.applied,
+            rows: r.rows,
+            created: r.created,
+            updated: r.updated,
+            errors: r.errors.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! Bulk handlers — import files and download exports
+//!
+//! Imports take the file as the raw request body. CSV exports are streamed
+//! page by page; XLSX exports are built in memory. Whole tables with
+//! passwords and billing pass through here, so every endpoint is limited
+//! to administrators.
+
+use std::sync::Arc;
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::Response,
+    Extension, Json,
+};
+use chrono::Utc;
+use futures_util::stream;
+use tracing::warn;
+
+use super::dto::{ExportParams, ImportParams, ImportReportDto};
+use crate::application::bulk::{BulkEntity, BulkService, ExportFilter};
+use crate::domain::DomainError;
+use crate::infrastructure::tabular::{csv_header, csv_rows, write_xlsx, TableFormat};
+use crate::interfaces::http::common::ApiResponse;
+use crate::interfaces::http::middleware::{require_admin, AuthenticatedUser};
+
+/// Largest import file accepted.
+pub const MAX_IMPORT_BYTES: usize = 20 * 1024 * 1024;
+
+/// Bulk handler state
+#[derive(Clone)]
+pub struct BulkAppState {
+    pub bulk: Arc<BulkService>,
+}
+
+type ErrorResponse = (StatusCode, Json<ApiResponse<()>>);
+
+fn error(status: StatusCode, message: impl Into<String>) -> ErrorResponse {
+    (status, Json(ApiResponse::error(message)))
+}
+
+fn parse_entity(entity: &str) -> Result<BulkEntity, ErrorResponse> {
+    BulkEntity::parse(entity).ok_or_else(|| {
+        error(
+            StatusCode::NOT_FOUND,
+            format!("Unknown entity '{}'", entity),
+        )
+    })
+}
+
+/// Validation errors are the caller's; database failures are ours.
+fn domain_error(e: DomainError) -> ErrorResponse {
+    match e {
+        DomainError::Validation(msg) if !msg.starts_with("Database error:") => {
+            error(StatusCode::BAD_REQUEST, msg)
+        }
+        e => error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+/// Import id tags, charge points or tariffs from a CSV or XLSX file.
+///
+/// Rows are matched to stored entities by key (`id_tag`, charge point `id`,
+/// tariff `id` or else `name`) and created or updated. Nothing is written
+/// on a dry run or when any row is invalid; the report lists each row
+/// error. A column left out keeps stored values; a blank cell clears an
+/// optional value, except `password`, where it keeps the current one.
+#[utoipa::path(
+    post,
+    path = "/api/v1/bulk/{entity}/import",
+    tag = "Bulk",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    params(
+        ("entity" = String, Path, description = "id-tags, charge-points or tariffs"),
+        ImportParams
+    ),
+    request_body(
+        content = String,
+        description = "CSV file, or XLSX with format=xlsx",
+        content_type = "text/csv"
+    ),
+    responses(
+        (status = 200, description = "Import report", body = ApiResponse<ImportReportDto>),
+        (status = 400, description = "Unreadable file or missing columns"),
+        (status = 403, description = "Not an administrator"),
+        (status = 404, description = "Unknown entity"),
+        (status = 415, description = "Unknown file format")
+    )
+)]
+pub async fn import_entities(
+    State(state): State<BulkAppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(entity): Path<String>,
+    Query(params): Query<ImportParams>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<ApiResponse<ImportReportDto>>, ErrorResponse> {
+    require_admin(&user)?;
+    let entity = parse_entity(&entity)?;
+    if !entity.is_importable() {
+        return Err(error(
+            StatusCode::BAD_REQUEST,
+            format!("{} cannot be imported", entity),
+        ));
+    }
+
+    let format = match &params.format {
+        Some(format) => TableFormat::parse(format),
+        None => headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(TableFormat::from_content_type),
+    }
+    .ok_or_else(|| {
+        error(
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            "Send text/csv or XLSX, or pass format=csv|xlsx",
+        )
+    })?;
+
+    let table = tokio::task::spawn_blocking(move || format.read(&body))
+        .await
+        .map_err(|e| error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .map_err(|e| error(StatusCode::BAD_REQUEST, e))?;
+
+    let report = state
+        .bulk
+        .import(entity, &table, params.dry_run)
+        .await
+        .map_err(domain_error)?;
+    Ok(Json(ApiResponse::success(report.into())))
+}
+
+/// Download id tags, charge points, tariffs or transactions as CSV or XLSX.
+///
+/// Transactions include their billing. Charge point passwords are never
+/// exported, only whether one is set.
+#[utoipa::path(
+    get,
+    path = "/api/v1/bulk/{entity}/export",
+    tag = "Bulk",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    params(
+        ("entity" = String, Path, description = "id-tags, charge-points, tariffs or transactions"),
+        ExportParams
+    ),
+    responses(
+        (status = 200, description = "File download", content_type = "text/csv", body = String),
+        (status = 400, description = "Unknown format"),
+        (status = 403, description = "Not an administrator"),
+        (status = 404, description = "Unknown entity")
+    )
+)]
+pub async fn export_entities(
+    State(state): State<BulkAppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(entity): Path<String>,
+    Query(params): Query<ExportParams>,
+) -> Result<Response, ErrorResponse> {
+    require_admin(&user)?;
+    let entity = parse_entity(&entity)?;
+    let format = match &params.format {
+        Some(format) => TableFormat::parse(format)
+            .ok_or_else(|| error(StatusCode::BAD_REQUEST, "format must be csv or xlsx"))?,
+        None => TableFormat::Csv,
+    };
+    let filter = ExportFilter {
+        from: params.from,
+        to: params.to,
+    };
+
+    let body = match format {
+        TableFormat::Csv => Body::from_stream(csv_stream(state.bulk, entity, filter)),
+        TableFormat::Xlsx => {
+            let mut rows = Vec::new();
+            let mut after = None;
+            loop {
+                let page = state
+                    .bulk
+                    .export_page(entity, &filter, after.as_deref())
+                    .await
+                    .map_err(domain_error)?;
+                rows.extend(page.rows);
+                match page.next {
+                    Some(next) => after = Some(next),
+                    None => break,
+                }
+            }
+            let bytes = tokio::task::spawn_blocking(move || {
+                write_xlsx(entity.as_str(), entity.columns(), &rows)
+            })
+            .await
+            .map_err(|e| error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .map_err(|e| error(StatusCode::INTERNAL_SERVER_ERROR, e))?;
+            Body::from(bytes)
+        }
+    };
+
+    let filename = format!(
+        "{}-{}.{}",
+        entity,
+        Utc::now().format("%Y%m%d"),
+        format.extension()
+    );
+    Response::builder()
+        .header(header::CONTENT_TYPE, format.content_type())
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", filename),
+        )
+        .body(body)
+        .map_err(|e| error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Where a CSV export stream stands.
+enum CsvStep {
+    /// Header line, then the first page.
+    Start,
+    /// The page after this cursor.
+    After(String),
+}
+
+/// The header line followed by one chunk per page. A failure mid-way ends
+/// the stream with an error, which aborts the download.
+fn csv_stream(
+    bulk: Arc<BulkService>,
+    entity: BulkEntity,
+    filter: ExportFilter,
+) -> impl futures_util::Stream<Item = Result<Vec<u8>, std::io::Error>> + Send + 'static {
+    stream::unfold(Some(CsvStep::Start), move |step| {
+        let bulk = bulk.clone();
+        let filter = filter.clone();
+        async move {
+            let step = step?;
+            let (mut chunk, after) = match step {
+                CsvStep::Start => match csv_header(entity.columns()) {
+                    Ok(header) => (header, None),
+                    Err(e) => return Some((Err(std::io::Error::other(e)), None)),
+                },
+                CsvStep::After(after) => (Vec::new(), Some(after)),
+            };
+            let page = match bulk.export_page(entity, &filter, after.as_deref()).await {
+                Ok(page) => page,
+                Err(e) => {
+                    warn!(entity = %entity, error = %e, "Bulk export failed");
+                    return Some((Err(std::io::Error::other(e.to_string())), None));
+                }
+            };
+            match csv_rows(&page.rows) {
+                Ok(rows) => chunk.extend(rows),
+                Err(e) => return Some((Err(std::io::Error::other(e)), None)),
+            }
+            Some((Ok(chunk), page.next.map(CsvStep::After)))
+        }
+    })
+}
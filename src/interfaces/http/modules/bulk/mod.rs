@@ -0,0 +1,8 @@
+//! Bulk module — CSV / XLSX import and export of id tags, charge points,
+//! tariffs and transactions
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
 
+use crate::domain::ChargingProfile;
+
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct RemoteStartRequest {
     #[validate(length(min = 1, max = 20, message = "id_tag must be 1–20 characters"))]
@@ -105,6 +107,12 @@ pub struct DataTransferResponse {
     pub data: Option<String>,
 }
 
+/// DataTransfer history of a charge point.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DataTransferListResponse {
+    pub data_transfers: Vec<crate::domain::DataTransferRecord>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct LocalListVersionResponse {
     pub list_version: i32,
@@ -279,6 +287,10 @@ pub struct GetCompositeScheduleResponse {
     /// Schedule start time as ISO 8601 string (v1.6 only).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schedule_start: Option<String>,
+    /// Active external limits (EMS, SO) on the connector or the whole
+    /// station; already applied by the station to `schedule`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub external_limits: Vec<ChargingProfileDto>,
 }
 
 // ─── Firmware Management ───────────────────────────────────────────────────
@@ -356,6 +368,40 @@ pub struct GetBaseReportResponse {
     pub request_id: i32,
 }
 
+/// Component (and optionally variable) to include in a report.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ComponentVariableDto {
+    /// Component name (e.g. "EVSE", "Connector", "ChargingStation").
+    #[validate(length(min = 1, max = 50, message = "component must be 1–50 characters"))]
+    pub component: String,
+    #[validate(length(max = 50))]
+    pub component_instance: Option<String>,
+    /// EVSE the component belongs to.
+    pub evse_id: Option<i32>,
+    /// Connector within the EVSE (requires `evse_id`).
+    pub connector_id: Option<i32>,
+    /// Variable name; omit to include every variable of the component.
+    #[validate(length(min = 1, max = 50, message = "variable must be 1–50 characters"))]
+    pub variable: Option<String>,
+    #[validate(length(max = 50))]
+    pub variable_instance: Option<String>,
+}
+
+/// GetReport request body (v2.0.1 only).
+///
+/// At least one of `component_criteria` or `component_variables` is required;
+/// use GetBaseReport for a full inventory.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct GetReportHttpRequest {
+    /// Component criteria: "Active", "Available", "Enabled", "Problem".
+    #[serde(default)]
+    #[validate(length(max = 4, message = "at most 4 component criteria"))]
+    pub component_criteria: Vec<String>,
+    #[serde(default)]
+    #[validate(nested)]
+    pub component_variables: Vec<ComponentVariableDto>,
+}
+
 // ─── Variable Monitoring (v2.0.1 only) ────────────────────────────────
 
 /// A single monitor descriptor for SetVariableMonitoring.
@@ -421,6 +467,49 @@ pub struct SetMonitoringBaseResponse {
     pub status: String,
 }
 
+/// SetMonitoringLevel request body (v2.0.1 only).
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetMonitoringLevelRequest {
+    /// Report only events of monitors with severity ≤ this level
+    /// (0 = Danger … 9 = Debug).
+    #[validate(range(max = 9, message = "severity must be 0–9"))]
+    pub severity: u8,
+}
+
+/// SetMonitoringLevel response.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SetMonitoringLevelResponse {
+    pub status: String,
+}
+
+/// GetMonitoringReport request body (v2.0.1 only).
+///
+/// Without criteria and component filters the station reports all of its
+/// monitors, and stored monitors it no longer has are removed.
+#[derive(Debug, Default, Deserialize, Validate, ToSchema)]
+pub struct GetMonitoringReportHttpRequest {
+    /// Monitoring criteria: "ThresholdMonitoring", "DeltaMonitoring", "PeriodicMonitoring".
+    #[serde(default)]
+    pub monitoring_criteria: Vec<String>,
+    #[serde(default)]
+    #[validate(nested)]
+    pub component_variables: Vec<ComponentVariableDto>,
+}
+
+/// GetMonitoringReport response.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GetMonitoringReportHttpResponse {
+    pub status: String,
+    /// The request_id of the NotifyMonitoringReport messages to expect.
+    pub request_id: i32,
+}
+
+/// Monitors configured on a station, as last reported.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VariableMonitorListResponse {
+    pub monitors: Vec<crate::domain::VariableMonitor>,
+}
+
 /// ClearVariableMonitoring request body (v2.0.1 only).
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct ClearVariableMonitoringRequest {
@@ -490,10 +579,35 @@ pub struct ChargingProfileDto {
     pub valid_to: Option<String>,
     pub schedule_json: String,
     pub is_active: bool,
+    /// Source of an external limit reported by the station (EMS, SO, Other).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_source: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
 
+impl ChargingProfileDto {
+    pub fn from_domain(p: ChargingProfile) -> Self {
+        Self {
+            id: p.id,
+            charge_point_id: p.charge_point_id,
+            evse_id: p.evse_id,
+            profile_id: p.profile_id,
+            stack_level: p.stack_level,
+            purpose: p.purpose,
+            kind: p.kind,
+            recurrency_kind: p.recurrency_kind,
+            valid_from: p.valid_from.map(|dt| dt.to_rfc3339()),
+            valid_to: p.valid_to.map(|dt| dt.to_rfc3339()),
+            schedule_json: p.schedule_json,
+            is_active: p.is_active,
+            limit_source: p.limit_source,
+            created_at: p.created_at.to_rfc3339(),
+            updated_at: p.updated_at.to_rfc3339(),
+        }
+    }
+}
+
 /// Response for listing stored charging profiles.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ChargingProfileListResponse {
@@ -519,3 +633,141 @@ pub struct GetTransactionStatusResponse {
     /// Whether the station has queued messages to deliver.
     pub messages_in_queue: bool,
 }
+
+// ─── Display Messages (v2.0.1) ───────────────────────────────────────
+
+fn default_message_priority() -> String {
+    "NormalCycle".to_string()
+}
+
+fn default_message_format() -> String {
+    "UTF8".to_string()
+}
+
+/// SetDisplayMessage request body (v2.0.1 only).
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetDisplayMessageRequest {
+    /// Message ID on the station. Defaults to the next free ID; reusing an
+    /// existing ID replaces that message.
+    #[serde(default)]
+    #[validate(range(min = 1, message = "message_id must be ≥ 1"))]
+    pub message_id: Option<i32>,
+    /// AlwaysFront, InFront or NormalCycle (default).
+    #[serde(default = "default_message_priority")]
+    pub priority: String,
+    /// Show only in this station state: Charging, Faulted, Idle, Unavailable.
+    #[serde(default)]
+    pub state: Option<String>,
+    /// Start of the display window.
+    #[serde(default)]
+    pub start_date_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// End of the display window.
+    #[serde(default)]
+    pub end_date_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Show only during this transaction.
+    #[serde(default)]
+    pub transaction_id: Option<String>,
+    /// ASCII, HTML, URI or UTF8 (default).
+    #[serde(default = "default_message_format")]
+    pub format: String,
+    /// RFC 5646 language code, e.g. "uz", "ru", "en".
+    #[serde(default)]
+    #[validate(length(max = 8, message = "language must be at most 8 characters"))]
+    pub language: Option<String>,
+    /// Message content.
+    #[validate(length(min = 1, max = 512, message = "content must be 1–512 characters"))]
+    pub content: String,
+}
+
+/// SetDisplayMessage response.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SetDisplayMessageResponse {
+    /// Station response: Accepted, Rejected, NotSupportedMessageFormat,
+    /// NotSupportedPriority, NotSupportedState, UnknownTransaction.
+    pub status: String,
+    /// Message ID used on the station.
+    pub message_id: i32,
+}
+
+/// Response of a display message sync (GetDisplayMessages).
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SyncDisplayMessagesResponse {
+    /// Accepted (NotifyDisplayMessages follows) or Unknown (station has no messages).
+    pub status: String,
+    /// Request ID echoed in the station's NotifyDisplayMessages.
+    pub request_id: i32,
+}
+
+/// Stored display message catalogue of a charge point.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DisplayMessageListResponse {
+    pub messages: Vec<crate::domain::DisplayMessage>,
+}
+
+// ─── Customer Information (v2.0.1) ───────────────────────────────────
+
+fn default_true() -> bool {
+    true
+}
+
+/// Contract certificate identifying a customer.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CustomerCertificateDto {
+    /// SHA256, SHA384 or SHA512.
+    pub hash_algorithm: String,
+    #[validate(length(max = 128))]
+    pub issuer_name_hash: String,
+    #[validate(length(max = 128))]
+    pub issuer_key_hash: String,
+    #[validate(length(max = 40))]
+    pub serial_number: String,
+}
+
+/// CustomerInformation request body (v2.0.1 only).
+///
+/// At least one of `customer_identifier`, `id_token` or `certificate` is required.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CustomerInformationCommandRequest {
+    /// Report the data the station holds (via NotifyCustomerInformation).
+    #[serde(default = "default_true")]
+    pub report: bool,
+    /// Clear the data the station holds.
+    #[serde(default)]
+    pub clear: bool,
+    /// Vendor-specific customer identifier.
+    #[serde(default)]
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "customer_identifier must be 1–64 characters"
+    ))]
+    pub customer_identifier: Option<String>,
+    /// Id token of the customer.
+    #[serde(default)]
+    #[validate(length(min = 1, max = 36, message = "id_token must be 1–36 characters"))]
+    pub id_token: Option<String>,
+    /// Contract certificate of the customer.
+    #[serde(default)]
+    #[validate(nested)]
+    pub certificate: Option<CustomerCertificateDto>,
+}
+
+/// CustomerInformation response.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CustomerInformationCommandResponse {
+    /// Accepted, Rejected or Invalid.
+    pub status: String,
+    /// Request ID echoed in the station's NotifyCustomerInformation.
+    /// Use GET /charge-points/{id}/customer-information?request_id={request_id}.
+    pub request_id: i32,
+}
+
+/// Customer data assembled from NotifyCustomerInformation parts.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CustomerInformationReportResponse {
+    #[serde(flatten)]
+    pub report:
+        crate::application::charging::services::customer_information::CustomerInformationReport,
+    /// All parts concatenated in sequence order.
+    pub data: String,
+}
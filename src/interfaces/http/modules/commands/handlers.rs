@@ -14,37 +14,56 @@ use super::dto::{
     ChangeAvailabilityRequest, ChangeConfigurationRequest, ClearChargingProfileRequest,
     ClearMonitoringResultDto, ClearVariableMonitoringRequest, ClearVariableMonitoringResponse,
     ChargingProfileDto, ChargingProfileListResponse,
-    CommandResponse, DataTransferRequest, DataTransferResponse, GetBaseReportRequest,
+    CommandResponse, ComponentVariableDto, CustomerInformationCommandRequest, CustomerInformationCommandResponse,
+    CustomerInformationReportResponse, DisplayMessageListResponse, DataTransferListResponse, DataTransferRequest, DataTransferResponse, GetBaseReportRequest,
     GetBaseReportResponse, GetChargingProfilesHttpRequest, GetChargingProfilesHttpResponse,
-    GetCompositeScheduleRequest,
+    GetCompositeScheduleRequest, GetMonitoringReportHttpRequest, GetMonitoringReportHttpResponse,
+    GetReportHttpRequest,
     GetCompositeScheduleResponse, GetDiagnosticsRequest, GetDiagnosticsResponse,
     GetTransactionStatusRequest, GetTransactionStatusResponse,
     GetVariablesRequest, GetVariablesResponse,
     LocalListVersionResponse, MonitoringResultDto,
     RemoteStartRequest, RemoteStopRequest, ResetRequest,
     SendLocalListRequest, SendLocalListResponse, SetChargingProfileRequest,
-    SetMonitoringBaseRequest, SetMonitoringBaseResponse,
+    SetDisplayMessageRequest, SetDisplayMessageResponse, SyncDisplayMessagesResponse,
+    SetMonitoringBaseRequest, SetMonitoringBaseResponse, SetMonitoringLevelRequest,
+    SetMonitoringLevelResponse,
     SetVariableMonitoringRequest, SetVariableMonitoringResponse,
     SetVariablesRequest, SetVariablesResponse,
     TriggerMessageRequest, UnlockConnectorRequest,
-    UpdateFirmwareRequest, UpdateFirmwareResponse, VariableResultDto,
+    UpdateFirmwareRequest, UpdateFirmwareResponse, VariableMonitorListResponse, VariableResultDto,
     SetVariableStatusDto,
 };
 use crate::application::events::{
     Event, SharedEventBus, TransactionBilledEvent, TransactionStoppedEvent,
 };
 use crate::application::charging::commands::dispatcher::ClearChargingProfileCriteria;
+use crate::application::charging::commands::dispatcher::ComponentVariableSelector;
 use crate::application::charging::commands::dispatcher::GetChargingProfilesCriteria;
 use crate::application::charging::commands::dispatcher::MonitorDescriptor;
+use crate::application::charging::commands::dispatcher::{CustomerCertificate, CustomerIdentifier};
+use crate::application::charging::commands::dispatcher::{
+    DisplayMessageInfo, GetDisplayMessagesCriteria,
+};
+use crate::application::charging::commands::v201::customer_information::parse_hash_algorithm;
+use crate::application::charging::commands::v201::get_monitoring_report::parse_monitoring_criterion;
+use crate::application::charging::commands::v201::get_report::parse_component_criterion;
+use crate::application::charging::commands::v201::set_display_message::{
+    parse_format, parse_priority, parse_state,
+};
 use crate::application::ChargePointService;
 use crate::application::SharedSessionRegistry;
 use crate::application::{
     Availability, ResetKind, SharedCommandDispatcher, TriggerType,
 };
 use crate::application::BillingService;
-use crate::domain::{ChargingLimitType, RepositoryProvider};
-use crate::interfaces::http::common::ApiResponse;
+use crate::domain::{
+    ChargingLimitType, DataTransferDirection, DataTransferRecord, DisplayMessage,
+    DisplayMessageStatus, DomainResult, RepositoryProvider,
+};
+use crate::interfaces::http::common::{ApiResponse, ValidatedJson};
 
+use crate::application::charging::services::customer_information::SharedCustomerInformationStore;
 use crate::application::charging::services::device_report::{
     DeviceReport, SharedDeviceReportStore,
 };
@@ -59,6 +78,7 @@ pub struct CommandAppState {
     pub charge_point_service: Arc<ChargePointService>,
     pub billing_service: Arc<BillingService>,
     pub report_store: SharedDeviceReportStore,
+    pub customer_info_store: SharedCustomerInformationStore,
 }
 
 #[utoipa::path(
@@ -837,11 +857,19 @@ pub async fn get_composite_schedule(
         .await
     {
         Ok(result) => {
+            let external_limits =
+                active_external_limits(&state, &charge_point_id, Some(request.connector_id))
+                    .await
+                    .unwrap_or_else(|e| {
+                        warn!("Failed to load external charging limits: {}", e);
+                        Vec::new()
+                    });
             Ok(Json(ApiResponse::success(GetCompositeScheduleResponse {
                 status: result.status,
                 schedule: result.schedule,
                 connector_id: result.connector_id,
                 schedule_start: result.schedule_start,
+                external_limits,
             })))
         }
         Err(e) => Err((
@@ -926,16 +954,36 @@ pub async fn data_transfer_handler(
         .command_dispatcher
         .data_transfer(
             &charge_point_id,
-            request.vendor_id,
-            request.message_id,
-            request.data,
+            request.vendor_id.clone(),
+            request.message_id.clone(),
+            request.data.clone(),
         )
         .await
     {
-        Ok(result) => Ok(Json(ApiResponse::success(DataTransferResponse {
-            status: result.status,
-            data: result.data,
-        }))),
+        Ok(result) => {
+            let record = DataTransferRecord {
+                id: 0,
+                charge_point_id: charge_point_id.clone(),
+                direction: DataTransferDirection::Outbound,
+                vendor_id: request.vendor_id,
+                message_id: request.message_id,
+                request_data: request.data,
+                status: result.status.clone(),
+                response_data: result.data.clone(),
+                created_at: Utc::now(),
+            };
+            if let Err(e) = state.repos.data_transfers().record(record).await {
+                warn!(
+                    charge_point_id = charge_point_id.as_str(),
+                    error = %e,
+                    "Failed to persist DataTransfer"
+                );
+            }
+            Ok(Json(ApiResponse::success(DataTransferResponse {
+                status: result.status,
+                data: result.data,
+            })))
+        }
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ApiResponse::error(e.to_string())),
@@ -943,6 +991,58 @@ pub async fn data_transfer_handler(
     }
 }
 
+/// Query params for the DataTransfer history.
+#[derive(Debug, serde::Deserialize)]
+pub struct DataTransferQueryParams {
+    pub vendor_id: Option<String>,
+    pub limit: Option<u64>,
+}
+
+/// DataTransfer exchanges with a charge point, both directions, newest first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/charge-points/{charge_point_id}/data-transfers",
+    tag = "Commands",
+    params(
+        ("charge_point_id" = String, Path, description = "Charge point ID"),
+        ("vendor_id" = Option<String>, Query, description = "Only this vendor"),
+        ("limit" = Option<u64>, Query, description = "Maximum entries (default: 100, max: 1000)")
+    ),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "Stored DataTransfer exchanges", body = ApiResponse<DataTransferListResponse>),
+        (status = 500, description = "Database error")
+    )
+)]
+pub async fn list_data_transfers(
+    State(state): State<CommandAppState>,
+    Path(charge_point_id): Path<String>,
+    Query(params): Query<DataTransferQueryParams>,
+) -> Result<
+    Json<ApiResponse<DataTransferListResponse>>,
+    (StatusCode, Json<ApiResponse<DataTransferListResponse>>),
+> {
+    let limit = params.limit.unwrap_or(100).clamp(1, 1000);
+
+    match state
+        .repos
+        .data_transfers()
+        .find_for_charge_point(&charge_point_id, params.vendor_id.as_deref(), limit)
+        .await
+    {
+        Ok(data_transfers) => Ok(Json(ApiResponse::success(DataTransferListResponse {
+            data_transfers,
+        }))),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!(
+                "Failed to load DataTransfer history: {}",
+                e
+            ))),
+        )),
+    }
+}
+
 // ── v2.0.1-specific command handlers ───────────────────────────────
 
 #[utoipa::path(
@@ -1256,6 +1356,7 @@ pub async fn set_charging_profile(
                     valid_to,
                     schedule_json,
                     is_active: true,
+                    limit_source: None,
                     created_at: now,
                     updated_at: now,
                 };
@@ -1537,6 +1638,119 @@ pub struct ReportQueryParams {
     pub request_id: Option<i32>,
 }
 
+/// Convert component-variable filters, rejecting a connector without an EVSE.
+fn to_selectors(dtos: Vec<ComponentVariableDto>) -> Result<Vec<ComponentVariableSelector>, String> {
+    dtos.into_iter()
+        .map(|cv| {
+            if cv.connector_id.is_some() && cv.evse_id.is_none() {
+                return Err(format!(
+                    "connector_id on component '{}' requires evse_id",
+                    cv.component
+                ));
+            }
+            Ok(ComponentVariableSelector {
+                component: cv.component,
+                component_instance: cv.component_instance,
+                evse_id: cv.evse_id,
+                connector_id: cv.connector_id,
+                variable: cv.variable,
+                variable_instance: cv.variable_instance,
+            })
+        })
+        .collect()
+}
+
+/// Request a filtered report from a v2.0.1 charge point (GetReport).
+///
+/// Only components matching the criteria and/or the listed component-variable
+/// pairs are reported. The result is assembled from NotifyReport messages like
+/// a base report; use GET /charge-points/{id}/report?request_id={request_id}.
+#[utoipa::path(
+    post,
+    path = "/api/v1/charge-points/{charge_point_id}/report/filtered",
+    tag = "Commands",
+    params(("charge_point_id" = String, Path, description = "Charge point ID")),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    request_body = GetReportHttpRequest,
+    responses(
+        (status = 200, description = "GetReport result", body = ApiResponse<GetBaseReportResponse>),
+        (status = 400, description = "Invalid or missing filter"),
+        (status = 404, description = "Not connected")
+    )
+)]
+pub async fn request_report(
+    State(state): State<CommandAppState>,
+    Path(charge_point_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<GetReportHttpRequest>,
+) -> Result<
+    Json<ApiResponse<GetBaseReportResponse>>,
+    (StatusCode, Json<ApiResponse<GetBaseReportResponse>>),
+> {
+    if !state.session_registry.is_connected(&charge_point_id) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "Charge point '{}' is not connected",
+                charge_point_id
+            ))),
+        ));
+    }
+
+    if request.component_criteria.is_empty() && request.component_variables.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(
+                "component_criteria or component_variables is required; use GetBaseReport for a full inventory"
+                    .to_string(),
+            )),
+        ));
+    }
+    if let Some(c) = request
+        .component_criteria
+        .iter()
+        .find(|c| parse_component_criterion(c).is_none())
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!(
+                "Invalid component criterion '{}': expected Active, Available, Enabled or Problem",
+                c
+            ))),
+        ));
+    }
+    let component_variables = to_selectors(request.component_variables)
+        .map_err(|e| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(e))))?;
+
+    let request_id = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i32;
+
+    state
+        .report_store
+        .init_report(&charge_point_id, request_id);
+
+    match state
+        .command_dispatcher
+        .get_report(
+            &charge_point_id,
+            request_id,
+            &request.component_criteria,
+            &component_variables,
+        )
+        .await
+    {
+        Ok(result) => Ok(Json(ApiResponse::success(GetBaseReportResponse {
+            status: result.status,
+            request_id,
+        }))),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(e.to_string())),
+        )),
+    }
+}
+
 // ─── Variable Monitoring (v2.0.1 only) ────────────────────────────────
 
 /// Configure variable monitors on a charge point (v2.0.1 only).
@@ -1701,6 +1915,28 @@ pub async fn clear_variable_monitoring_handler(
         .await
     {
         Ok(result) => {
+            // Accepted and NotFound both mean the monitor is gone from the station
+            let cleared: Vec<i32> = result
+                .results
+                .iter()
+                .filter(|r| r.status == "Accepted" || r.status == "NotFound")
+                .map(|r| r.id)
+                .collect();
+            if !cleared.is_empty() {
+                if let Err(e) = state
+                    .repos
+                    .variable_monitors()
+                    .remove(&charge_point_id, &cleared)
+                    .await
+                {
+                    warn!(
+                        charge_point_id = charge_point_id.as_str(),
+                        error = %e,
+                        "Failed to remove cleared variable monitors"
+                    );
+                }
+            }
+
             let results = result
                 .results
                 .into_iter()
@@ -1720,6 +1956,204 @@ pub async fn clear_variable_monitoring_handler(
     }
 }
 
+/// Set the severity level up to which a charge point reports monitoring
+/// events (v2.0.1 only).
+#[utoipa::path(
+    post,
+    path = "/api/v1/charge-points/{charge_point_id}/monitoring/level",
+    tag = "Commands",
+    params(("charge_point_id" = String, Path, description = "Charge point ID")),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    request_body = SetMonitoringLevelRequest,
+    responses(
+        (status = 200, description = "Monitoring level result", body = ApiResponse<SetMonitoringLevelResponse>),
+        (status = 400, description = "Severity out of range"),
+        (status = 404, description = "Not connected")
+    )
+)]
+pub async fn set_monitoring_level_handler(
+    State(state): State<CommandAppState>,
+    Path(charge_point_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<SetMonitoringLevelRequest>,
+) -> Result<
+    Json<ApiResponse<SetMonitoringLevelResponse>>,
+    (StatusCode, Json<ApiResponse<SetMonitoringLevelResponse>>),
+> {
+    if !state.session_registry.is_connected(&charge_point_id) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "Charge point '{}' is not connected",
+                charge_point_id
+            ))),
+        ));
+    }
+
+    match state
+        .command_dispatcher
+        .set_monitoring_level(&charge_point_id, request.severity)
+        .await
+    {
+        Ok(result) => Ok(Json(ApiResponse::success(SetMonitoringLevelResponse {
+            status: result.status,
+        }))),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(e.to_string())),
+        )),
+    }
+}
+
+/// Ask a charge point to report its configured monitors (v2.0.1 only).
+///
+/// The station answers with NotifyMonitoringReport messages; reported
+/// monitors are stored and listed by GET /charge-points/{id}/monitoring.
+/// An unfiltered request also removes stored monitors the station no
+/// longer has.
+#[utoipa::path(
+    post,
+    path = "/api/v1/charge-points/{charge_point_id}/monitoring/report",
+    tag = "Commands",
+    params(("charge_point_id" = String, Path, description = "Charge point ID")),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    request_body = GetMonitoringReportHttpRequest,
+    responses(
+        (status = 200, description = "GetMonitoringReport result", body = ApiResponse<GetMonitoringReportHttpResponse>),
+        (status = 400, description = "Invalid filter"),
+        (status = 404, description = "Not connected")
+    )
+)]
+pub async fn request_monitoring_report(
+    State(state): State<CommandAppState>,
+    Path(charge_point_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<GetMonitoringReportHttpRequest>,
+) -> Result<
+    Json<ApiResponse<GetMonitoringReportHttpResponse>>,
+    (StatusCode, Json<ApiResponse<GetMonitoringReportHttpResponse>>),
+> {
+    if !state.session_registry.is_connected(&charge_point_id) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "Charge point '{}' is not connected",
+                charge_point_id
+            ))),
+        ));
+    }
+
+    if let Some(c) = request
+        .monitoring_criteria
+        .iter()
+        .find(|c| parse_monitoring_criterion(c).is_none())
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!(
+                "Invalid monitoring criterion '{}': expected ThresholdMonitoring, DeltaMonitoring or PeriodicMonitoring",
+                c
+            ))),
+        ));
+    }
+    let full = request.monitoring_criteria.is_empty() && request.component_variables.is_empty();
+    let component_variables = to_selectors(request.component_variables)
+        .map_err(|e| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(e))))?;
+
+    let request_id = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i32;
+
+    state
+        .charge_point_service
+        .begin_monitoring_report(&charge_point_id, request_id, full);
+
+    let result = state
+        .command_dispatcher
+        .get_monitoring_report(
+            &charge_point_id,
+            request_id,
+            &request.monitoring_criteria,
+            &component_variables,
+        )
+        .await;
+
+    match result {
+        Ok(result) => {
+            if result.status != "Accepted" {
+                state
+                    .charge_point_service
+                    .cancel_monitoring_report(&charge_point_id, request_id);
+            }
+            // No monitors at all: nothing will be reported, reconcile now.
+            if full && result.status == "EmptyResultSet" {
+                if let Err(e) = state
+                    .charge_point_service
+                    .reconcile_variable_monitors(&charge_point_id, &[])
+                    .await
+                {
+                    warn!(
+                        charge_point_id = charge_point_id.as_str(),
+                        error = %e,
+                        "Failed to clear stored variable monitors"
+                    );
+                }
+            }
+            Ok(Json(ApiResponse::success(GetMonitoringReportHttpResponse {
+                status: result.status,
+                request_id,
+            })))
+        }
+        Err(e) => {
+            state
+                .charge_point_service
+                .cancel_monitoring_report(&charge_point_id, request_id);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(e.to_string())),
+            ))
+        }
+    }
+}
+
+/// List the monitors configured on a charge point, as last reported via
+/// NotifyMonitoringReport.
+#[utoipa::path(
+    get,
+    path = "/api/v1/charge-points/{charge_point_id}/monitoring",
+    tag = "Commands",
+    params(("charge_point_id" = String, Path, description = "Charge point ID")),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "Stored variable monitors", body = ApiResponse<VariableMonitorListResponse>),
+        (status = 500, description = "Database error")
+    )
+)]
+pub async fn list_variable_monitors(
+    State(state): State<CommandAppState>,
+    Path(charge_point_id): Path<String>,
+) -> Result<
+    Json<ApiResponse<VariableMonitorListResponse>>,
+    (StatusCode, Json<ApiResponse<VariableMonitorListResponse>>),
+> {
+    match state
+        .repos
+        .variable_monitors()
+        .find_for_charge_point(&charge_point_id)
+        .await
+    {
+        Ok(monitors) => Ok(Json(ApiResponse::success(VariableMonitorListResponse {
+            monitors,
+        }))),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!(
+                "Failed to load variable monitors: {}",
+                e
+            ))),
+        )),
+    }
+}
+
 // ─── Charging Profile Management ───────────────────────────────────────────
 
 /// Request the charge point to report its installed charging profiles (v2.0.1 only).
@@ -1818,22 +2252,7 @@ pub async fn list_charging_profiles(
         Ok(profiles) => {
             let dtos: Vec<ChargingProfileDto> = profiles
                 .into_iter()
-                .map(|p| ChargingProfileDto {
-                    id: p.id,
-                    charge_point_id: p.charge_point_id,
-                    evse_id: p.evse_id,
-                    profile_id: p.profile_id,
-                    stack_level: p.stack_level,
-                    purpose: p.purpose,
-                    kind: p.kind,
-                    recurrency_kind: p.recurrency_kind,
-                    valid_from: p.valid_from.map(|dt| dt.to_rfc3339()),
-                    valid_to: p.valid_to.map(|dt| dt.to_rfc3339()),
-                    schedule_json: p.schedule_json,
-                    is_active: p.is_active,
-                    created_at: p.created_at.to_rfc3339(),
-                    updated_at: p.updated_at.to_rfc3339(),
-                })
+                .map(ChargingProfileDto::from_domain)
                 .collect();
             Ok(Json(ApiResponse::success(ChargingProfileListResponse {
                 profiles: dtos,
@@ -1855,29 +2274,83 @@ pub struct ChargingProfileQueryParams {
     pub active_only: Option<bool>,
 }
 
-// ─── Transaction Status (v2.0.1) ───────────────────────────────────────────
-
-/// Ask the charge point whether a transaction is ongoing and if messages are queued.
+/// List limits imposed on a charge point by an EMS or system operator
+/// (NotifyChargingLimit, v2.0.1) that are still in force.
 #[utoipa::path(
-    post,
-    path = "/api/v1/charge-points/{charge_point_id}/transaction-status",
+    get,
+    path = "/api/v1/charge-points/{charge_point_id}/charging-limits",
     tag = "Commands",
     params(("charge_point_id" = String, Path, description = "Charge point ID")),
     security(("bearer_auth" = []), ("api_key" = [])),
-    request_body = GetTransactionStatusRequest,
     responses(
-        (status = 200, description = "Transaction status", body = ApiResponse<GetTransactionStatusResponse>),
-        (status = 404, description = "Not connected")
+        (status = 200, description = "Active external limits", body = ApiResponse<ChargingProfileListResponse>),
+        (status = 500, description = "Database error")
     )
 )]
-pub async fn get_transaction_status(
+pub async fn list_external_charging_limits(
     State(state): State<CommandAppState>,
     Path(charge_point_id): Path<String>,
-    Json(request): Json<GetTransactionStatusRequest>,
 ) -> Result<
-    Json<ApiResponse<GetTransactionStatusResponse>>,
-    (StatusCode, Json<ApiResponse<GetTransactionStatusResponse>>),
-> {
+    Json<ApiResponse<ChargingProfileListResponse>>,
+    (StatusCode, Json<ApiResponse<ChargingProfileListResponse>>),
+> {
+    match active_external_limits(&state, &charge_point_id, None).await {
+        Ok(profiles) => Ok(Json(ApiResponse::success(ChargingProfileListResponse {
+            profiles,
+        }))),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!(
+                "Failed to load charging limits: {}",
+                e
+            ))),
+        )),
+    }
+}
+
+/// Active external limits, optionally only those affecting one EVSE
+/// (its own plus station-wide ones).
+async fn active_external_limits(
+    state: &CommandAppState,
+    charge_point_id: &str,
+    evse_id: Option<i32>,
+) -> DomainResult<Vec<ChargingProfileDto>> {
+    let profiles = state
+        .repos
+        .charging_profiles()
+        .find_active_for_charge_point(charge_point_id)
+        .await?;
+    Ok(profiles
+        .into_iter()
+        .filter(|p| p.is_external_limit())
+        .filter(|p| evse_id.is_none_or(|id| p.evse_id == id || p.evse_id == 0))
+        .map(ChargingProfileDto::from_domain)
+        .collect())
+}
+
+// ─── Transaction Status (v2.0.1) ───────────────────────────────────────────
+
+/// Ask the charge point whether a transaction is ongoing and if messages are queued.
+#[utoipa::path(
+    post,
+    path = "/api/v1/charge-points/{charge_point_id}/transaction-status",
+    tag = "Commands",
+    params(("charge_point_id" = String, Path, description = "Charge point ID")),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    request_body = GetTransactionStatusRequest,
+    responses(
+        (status = 200, description = "Transaction status", body = ApiResponse<GetTransactionStatusResponse>),
+        (status = 404, description = "Not connected")
+    )
+)]
+pub async fn get_transaction_status(
+    State(state): State<CommandAppState>,
+    Path(charge_point_id): Path<String>,
+    Json(request): Json<GetTransactionStatusRequest>,
+) -> Result<
+    Json<ApiResponse<GetTransactionStatusResponse>>,
+    (StatusCode, Json<ApiResponse<GetTransactionStatusResponse>>),
+> {
     if !state.session_registry.is_connected(&charge_point_id) {
         return Err((
             StatusCode::NOT_FOUND,
@@ -1902,4 +2375,512 @@ pub async fn get_transaction_status(
             Json(ApiResponse::error(e.to_string())),
         )),
     }
-}
\ No newline at end of file
+}
+// ─── Display Messages (v2.0.1) ─────────────────────────────────────────────
+
+/// Query params for listing display messages.
+#[derive(Debug, serde::Deserialize)]
+pub struct DisplayMessageQueryParams {
+    pub include_cleared: Option<bool>,
+}
+
+/// List the stored display message catalogue of a charge point (from DB).
+#[utoipa::path(
+    get,
+    path = "/api/v1/charge-points/{charge_point_id}/display-messages",
+    tag = "Commands",
+    params(
+        ("charge_point_id" = String, Path, description = "Charge point ID"),
+        ("include_cleared" = Option<bool>, Query, description = "Include cleared messages (default: false)")
+    ),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "Stored display messages", body = ApiResponse<DisplayMessageListResponse>),
+        (status = 500, description = "Database error")
+    )
+)]
+pub async fn list_display_messages(
+    State(state): State<CommandAppState>,
+    Path(charge_point_id): Path<String>,
+    Query(params): Query<DisplayMessageQueryParams>,
+) -> Result<
+    Json<ApiResponse<DisplayMessageListResponse>>,
+    (StatusCode, Json<ApiResponse<DisplayMessageListResponse>>),
+> {
+    let include_cleared = params.include_cleared.unwrap_or(false);
+
+    match state
+        .repos
+        .display_messages()
+        .find_for_charge_point(&charge_point_id)
+        .await
+    {
+        Ok(messages) => Ok(Json(ApiResponse::success(DisplayMessageListResponse {
+            messages: messages
+                .into_iter()
+                .filter(|m| include_cleared || m.status != DisplayMessageStatus::Cleared)
+                .collect(),
+        }))),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!(
+                "Failed to load display messages: {}",
+                e
+            ))),
+        )),
+    }
+}
+
+/// Install a message on the station display and store it in the catalogue.
+#[utoipa::path(
+    post,
+    path = "/api/v1/charge-points/{charge_point_id}/display-messages",
+    tag = "Commands",
+    params(("charge_point_id" = String, Path, description = "Charge point ID")),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    request_body = SetDisplayMessageRequest,
+    responses(
+        (status = 200, description = "SetDisplayMessage result", body = ApiResponse<SetDisplayMessageResponse>),
+        (status = 400, description = "Invalid priority, state or format"),
+        (status = 404, description = "Not connected")
+    )
+)]
+pub async fn set_display_message(
+    State(state): State<CommandAppState>,
+    Path(charge_point_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<SetDisplayMessageRequest>,
+) -> Result<
+    Json<ApiResponse<SetDisplayMessageResponse>>,
+    (StatusCode, Json<ApiResponse<SetDisplayMessageResponse>>),
+> {
+    if !state.session_registry.is_connected(&charge_point_id) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "Charge point '{}' is not connected",
+                charge_point_id
+            ))),
+        ));
+    }
+
+    let invalid = if parse_priority(&request.priority).is_none() {
+        Some(format!("Invalid priority '{}'", request.priority))
+    } else if parse_format(&request.format).is_none() {
+        Some(format!("Invalid format '{}'", request.format))
+    } else {
+        request
+            .state
+            .as_deref()
+            .filter(|s| parse_state(s).is_none())
+            .map(|s| format!("Invalid state '{}'", s))
+    };
+    if let Some(msg) = invalid {
+        return Err((StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))));
+    }
+
+    let catalogue = match state
+        .repos
+        .display_messages()
+        .find_for_charge_point(&charge_point_id)
+        .await
+    {
+        Ok(messages) => messages,
+        Err(e) => {
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!(
+                    "Failed to load display messages: {}",
+                    e
+                ))),
+            ))
+        }
+    };
+    let message_id = request.message_id.unwrap_or_else(|| {
+        catalogue.iter().map(|m| m.message_id).max().unwrap_or(0) + 1
+    });
+
+    let info = DisplayMessageInfo {
+        id: message_id,
+        priority: request.priority.clone(),
+        state: request.state.clone(),
+        start_date_time: request.start_date_time,
+        end_date_time: request.end_date_time,
+        transaction_id: request.transaction_id.clone(),
+        format: request.format.clone(),
+        language: request.language.clone(),
+        content: request.content.clone(),
+    };
+
+    match state
+        .command_dispatcher
+        .set_display_message(&charge_point_id, info)
+        .await
+    {
+        Ok(result) => {
+            let accepted = result.status == "Accepted";
+            let replaces_installed = catalogue
+                .iter()
+                .any(|m| m.message_id == message_id && m.is_installed());
+
+            // A rejected replacement leaves the installed message untouched
+            if accepted || !replaces_installed {
+                let mut message = DisplayMessage::new(&charge_point_id, message_id, request.content);
+                message.priority = request.priority;
+                message.state = request.state;
+                message.start_date_time = request.start_date_time;
+                message.end_date_time = request.end_date_time;
+                message.transaction_id = request.transaction_id;
+                message.format = request.format;
+                message.language = request.language;
+                if !accepted {
+                    message.status = DisplayMessageStatus::Rejected;
+                    message.status_reason = Some(result.status.clone());
+                }
+                if let Err(e) = state.repos.display_messages().upsert(message).await {
+                    warn!("Failed to save display message {} to DB: {}", message_id, e);
+                }
+            }
+
+            Ok(Json(ApiResponse::success(SetDisplayMessageResponse {
+                status: result.status,
+                message_id,
+            })))
+        }
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(e.to_string())),
+        )),
+    }
+}
+
+/// Remove a message from the station display.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/charge-points/{charge_point_id}/display-messages/{message_id}",
+    tag = "Commands",
+    params(
+        ("charge_point_id" = String, Path, description = "Charge point ID"),
+        ("message_id" = i32, Path, description = "Display message ID")
+    ),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "ClearDisplayMessage result", body = ApiResponse<CommandResponse>),
+        (status = 404, description = "Not connected")
+    )
+)]
+pub async fn clear_display_message(
+    State(state): State<CommandAppState>,
+    Path((charge_point_id, message_id)): Path<(String, i32)>,
+) -> Result<Json<ApiResponse<CommandResponse>>, (StatusCode, Json<ApiResponse<CommandResponse>>)> {
+    if !state.session_registry.is_connected(&charge_point_id) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "Charge point '{}' is not connected",
+                charge_point_id
+            ))),
+        ));
+    }
+
+    match state
+        .command_dispatcher
+        .clear_display_message(&charge_point_id, message_id)
+        .await
+    {
+        Ok(status_str) => {
+            let accepted = status_str == "Accepted";
+            // Unknown: the station no longer has the message
+            let new_status = if accepted {
+                DisplayMessageStatus::Cleared
+            } else {
+                DisplayMessageStatus::Missing
+            };
+            if let Err(e) = state
+                .repos
+                .display_messages()
+                .update_status(&charge_point_id, message_id, new_status)
+                .await
+            {
+                warn!("Failed to update display message {} in DB: {}", message_id, e);
+            }
+
+            Ok(Json(ApiResponse::success(CommandResponse {
+                status: status_str,
+                message: if accepted {
+                    Some("Display message cleared".to_string())
+                } else {
+                    Some("Station has no message with this ID".to_string())
+                },
+            })))
+        }
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(e.to_string())),
+        )),
+    }
+}
+
+/// Request the station's full message list to reconcile the catalogue.
+///
+/// The station answers with NotifyDisplayMessages; reported messages are
+/// stored and catalogue entries it did not report are marked `Missing`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/charge-points/{charge_point_id}/display-messages/sync",
+    tag = "Commands",
+    params(("charge_point_id" = String, Path, description = "Charge point ID")),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "GetDisplayMessages result", body = ApiResponse<SyncDisplayMessagesResponse>),
+        (status = 404, description = "Not connected")
+    )
+)]
+pub async fn sync_display_messages(
+    State(state): State<CommandAppState>,
+    Path(charge_point_id): Path<String>,
+) -> Result<
+    Json<ApiResponse<SyncDisplayMessagesResponse>>,
+    (StatusCode, Json<ApiResponse<SyncDisplayMessagesResponse>>),
+> {
+    if !state.session_registry.is_connected(&charge_point_id) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "Charge point '{}' is not connected",
+                charge_point_id
+            ))),
+        ));
+    }
+
+    // Generate a unique request_id
+    let request_id = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i32;
+
+    match state
+        .command_dispatcher
+        .get_display_messages(
+            &charge_point_id,
+            request_id,
+            GetDisplayMessagesCriteria::default(),
+        )
+        .await
+    {
+        Ok(result) => {
+            // Unknown: the station has no messages at all
+            if result.status == "Unknown" {
+                if let Err(e) = state
+                    .charge_point_service
+                    .reconcile_display_messages(&charge_point_id, &[])
+                    .await
+                {
+                    warn!("Failed to reconcile display messages: {}", e);
+                }
+            }
+            Ok(Json(ApiResponse::success(SyncDisplayMessagesResponse {
+                status: result.status,
+                request_id,
+            })))
+        }
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(e.to_string())),
+        )),
+    }
+}
+
+// ─── Customer Information (v2.0.1 only) ───────────────────────────────
+
+/// Ask a charge point to report and/or clear the data it holds about a
+/// customer (v2.0.1 only).
+///
+/// With `report = true` the station sends NotifyCustomerInformation messages.
+/// Use GET /charge-points/{id}/customer-information to retrieve the assembled data.
+#[utoipa::path(
+    post,
+    path = "/api/v1/charge-points/{charge_point_id}/customer-information",
+    tag = "Commands",
+    params(("charge_point_id" = String, Path, description = "Charge point ID")),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    request_body = CustomerInformationCommandRequest,
+    responses(
+        (status = 200, description = "Station response", body = ApiResponse<CustomerInformationCommandResponse>),
+        (status = 400, description = "No customer identifier or nothing to do"),
+        (status = 404, description = "Not connected")
+    )
+)]
+pub async fn request_customer_information(
+    State(state): State<CommandAppState>,
+    Path(charge_point_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<CustomerInformationCommandRequest>,
+) -> Result<
+    Json<ApiResponse<CustomerInformationCommandResponse>>,
+    (
+        StatusCode,
+        Json<ApiResponse<CustomerInformationCommandResponse>>,
+    ),
+> {
+    if !state.session_registry.is_connected(&charge_point_id) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "Charge point '{}' is not connected",
+                charge_point_id
+            ))),
+        ));
+    }
+
+    if let Some(cert) = &request.certificate {
+        if parse_hash_algorithm(&cert.hash_algorithm).is_none() {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::error(format!(
+                    "Invalid hash_algorithm '{}'",
+                    cert.hash_algorithm
+                ))),
+            ));
+        }
+    }
+
+    let customer = CustomerIdentifier {
+        customer_identifier: request.customer_identifier,
+        id_token: request.id_token,
+        certificate: request.certificate.map(|c| CustomerCertificate {
+            hash_algorithm: c.hash_algorithm,
+            issuer_name_hash: c.issuer_name_hash,
+            issuer_key_hash: c.issuer_key_hash,
+            serial_number: c.serial_number,
+        }),
+    };
+    if customer.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(
+                "One of customer_identifier, id_token or certificate is required",
+            )),
+        ));
+    }
+    if !request.report && !request.clear {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(
+                "At least one of report or clear must be true",
+            )),
+        ));
+    }
+
+    let request_id = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i32;
+
+    if request.report {
+        state
+            .customer_info_store
+            .init_report(&charge_point_id, request_id);
+    }
+
+    match state
+        .command_dispatcher
+        .customer_information(
+            &charge_point_id,
+            request_id,
+            request.report,
+            request.clear,
+            &customer,
+        )
+        .await
+    {
+        Ok(result) => {
+            if request.report && result.status != "Accepted" {
+                state
+                    .customer_info_store
+                    .remove_report(&charge_point_id, request_id);
+            }
+            Ok(Json(ApiResponse::success(
+                CustomerInformationCommandResponse {
+                    status: result.status,
+                    request_id,
+                },
+            )))
+        }
+        Err(e) => {
+            state
+                .customer_info_store
+                .remove_report(&charge_point_id, request_id);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(e.to_string())),
+            ))
+        }
+    }
+}
+
+/// Retrieve customer data reported by a charge point.
+///
+/// Assembled from NotifyCustomerInformation messages received after a
+/// CustomerInformation command. `consume=true` drops the data from memory
+/// once returned.
+#[utoipa::path(
+    get,
+    path = "/api/v1/charge-points/{charge_point_id}/customer-information",
+    tag = "Commands",
+    params(
+        ("charge_point_id" = String, Path, description = "Charge point ID"),
+        ("request_id" = Option<i32>, Query, description = "Specific request_id (optional, defaults to latest)"),
+        ("consume" = Option<bool>, Query, description = "Forget the report after returning it"),
+    ),
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "Customer data", body = ApiResponse<CustomerInformationReportResponse>),
+        (status = 404, description = "No report")
+    )
+)]
+pub async fn get_customer_information(
+    State(state): State<CommandAppState>,
+    Path(charge_point_id): Path<String>,
+    Query(params): Query<CustomerInformationQueryParams>,
+) -> Result<
+    Json<ApiResponse<CustomerInformationReportResponse>>,
+    (
+        StatusCode,
+        Json<ApiResponse<CustomerInformationReportResponse>>,
+    ),
+> {
+    let report = match params.request_id {
+        Some(rid) => state.customer_info_store.get_report(&charge_point_id, rid),
+        None => state
+            .customer_info_store
+            .get_latest_report(&charge_point_id),
+    };
+
+    match report {
+        Some(report) => {
+            if params.consume && !report.in_progress {
+                state
+                    .customer_info_store
+                    .remove_report(&charge_point_id, report.request_id);
+            }
+            let data = report.data();
+            Ok(Json(ApiResponse::success(
+                CustomerInformationReportResponse { report, data },
+            )))
+        }
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "No customer information found for charge point '{}'",
+                charge_point_id
+            ))),
+        )),
+    }
+}
+
+/// Query parameters for customer information retrieval.
+#[derive(Debug, serde::Deserialize)]
+pub struct CustomerInformationQueryParams {
+    pub request_id: Option<i32>,
+    #[serde(default)]
+    pub consume: bool,
+}
@@ -0,0 +1,24 @@
+//! Configuration API data transfer objects
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::config::reload::ReloadReport;
+
+/// Outcome of a configuration reload.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConfigReloadDto {
+    /// Settings now in effect, as dotted paths, e.g. `cors.allowed_origins`
+    pub applied: Vec<String>,
+    /// Changed settings that take effect after a restart, e.g. `server.api_port`
+    pub restart_required: Vec<String>,
+}
+
+impl From<ReloadReport> for ConfigReloadDto {
+    fn from(report: ReloadReport) -> Self {
+        Self {
+            applied: report.applied,
+            restart_required: report.restart_required,
+        }
+    }
+}
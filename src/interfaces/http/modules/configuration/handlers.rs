@@ -0,0 +1,55 @@
+//! Configuration handlers — apply configuration file changes without a restart
+
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, Extension, Json};
+use tracing::info;
+
+use super::dto::ConfigReloadDto;
+use crate::config::reload::ConfigReloader;
+use crate::interfaces::http::common::ApiResponse;
+use crate::interfaces::http::middleware::{require_admin, AuthenticatedUser};
+
+/// Configuration handler state
+#[derive(Clone)]
+pub struct ConfigurationAppState {
+    pub reloader: Arc<ConfigReloader>,
+}
+
+type ErrorResponse = (StatusCode, Json<ApiResponse<()>>);
+
+/// Reload the configuration file.
+///
+/// Log level, CORS origins, rate limits, WebSocket auth and heartbeat
+/// thresholds apply right away; other changed settings are listed as
+/// needing a restart. An invalid file is refused and changes nothing.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/config/reload",
+    tag = "Configuration",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "Configuration reloaded", body = ApiResponse<ConfigReloadDto>),
+        (status = 403, description = "Not an administrator"),
+        (status = 422, description = "The configuration file is missing or invalid")
+    )
+)]
+pub async fn reload_configuration(
+    State(state): State<ConfigurationAppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+) -> Result<Json<ApiResponse<ConfigReloadDto>>, ErrorResponse> {
+    require_admin(&user)?;
+    let report = state.reloader.reload().map_err(|e| {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ApiResponse::error(e)),
+        )
+    })?;
+    info!(
+        user = user.username.as_str(),
+        applied = ?report.applied,
+        restart_required = ?report.restart_required,
+        "🔄 Configuration reloaded"
+    );
+    Ok(Json(ApiResponse::success(report.into())))
+}
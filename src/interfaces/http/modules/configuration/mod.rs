@@ -0,0 +1,7 @@
+//! Configuration module — reloading the configuration file (admin)
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
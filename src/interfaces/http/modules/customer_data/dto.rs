@@ -0,0 +1,177 @@
+//! Customer data DTOs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::application::privacy::{ErasureReport, PersonalData, TransactionRecord};
+use crate::domain::{AuthEvent, OcpiToken, TransactionBilling};
+use crate::interfaces::http::modules::id_tags::IdTagDto;
+use crate::interfaces::http::modules::reservations::ReservationDto;
+use crate::interfaces::http::modules::transactions::TransactionDto;
+use crate::interfaces::http::modules::users::UserDto;
+
+/// Billing record of a transaction
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BillingDataDto {
+    pub tariff_id: Option<i32>,
+    pub energy_wh: i32,
+    pub duration_seconds: i64,
+    pub energy_cost: i32,
+    pub time_cost: i32,
+    pub session_fee: i32,
+    pub total_cost: i32,
+    pub currency: String,
+    pub status: String,
+}
+
+impl From<TransactionBilling> for BillingDataDto {
+    fn from(b: TransactionBilling) -> Self {
+        Self {
+            tariff_id: b.tariff_id,
+            energy_wh: b.energy_wh,
+            duration_seconds: b.duration_seconds,
+            energy_cost: b.energy_cost,
+            time_cost: b.time_cost,
+            session_fee: b.session_fee,
+            total_cost: b.total_cost,
+            currency: b.currency,
+            status: b.status.to_string(),
+        }
+    }
+}
+
+/// Transaction with meter readings and billing
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TransactionDataDto {
+    #[serde(flatten)]
+    pub transaction: TransactionDto,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing: Option<BillingDataDto>,
+}
+
+impl From<TransactionRecord> for TransactionDataDto {
+    fn from(r: TransactionRecord) -> Self {
+        Self {
+            transaction: TransactionDto::from_domain(r.transaction),
+            billing: r.billing.map(BillingDataDto::from),
+        }
+    }
+}
+
+/// Authorization request handled for the customer
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthEventDto {
+    pub charge_point_id: String,
+    pub id_tag: String,
+    pub status: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl From<AuthEvent> for AuthEventDto {
+    fn from(e: AuthEvent) -> Self {
+        Self {
+            charge_point_id: e.charge_point_id,
+            id_tag: e.id_tag,
+            status: e.status,
+            occurred_at: e.occurred_at,
+        }
+    }
+}
+
+/// All personal data stored for a customer
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PersonalDataExport {
+    pub exported_at: DateTime<Utc>,
+    /// Id tag values covered by this export
+    pub id_tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserDto>,
+    pub id_tag_records: Vec<IdTagDto>,
+    pub transactions: Vec<TransactionDataDto>,
+    pub reservations: Vec<ReservationDto>,
+    pub auth_events: Vec<AuthEventDto>,
+    /// Tokens pushed by OCPI roaming partners
+    pub ocpi_tokens: Vec<OcpiToken>,
+}
+
+impl From<PersonalData> for PersonalDataExport {
+    fn from(d: PersonalData) -> Self {
+        Self {
+            exported_at: Utc::now(),
+            id_tags: d.id_tags,
+            user: d.user.map(UserDto::from),
+            id_tag_records: d.id_tag_records.into_iter().map(IdTagDto::from).collect(),
+            transactions: d
+                .transactions
+                .into_iter()
+                .map(TransactionDataDto::from)
+                .collect(),
+            reservations: d
+                .reservations
+                .into_iter()
+                .map(ReservationDto::from)
+                .collect(),
+            auth_events: d.auth_events.into_iter().map(AuthEventDto::from).collect(),
+            ocpi_tokens: d.ocpi_tokens,
+        }
+    }
+}
+
+/// Erasure options
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct EraseCustomerDataParams {
+    /// Also send CustomerInformation (clear) to every connected
+    /// OCPP 2.0.1 station so local auth caches and lists are wiped.
+    #[serde(default)]
+    pub clear_on_stations: bool,
+}
+
+/// Result of erasing one id tag
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErasedIdTagDto {
+    pub id_tag: String,
+    /// Pseudonym now stored in place of the id tag
+    pub pseudonym: String,
+    pub transactions: u64,
+    pub reservations: u64,
+    pub auth_events: u64,
+    pub ocpi_tokens: u64,
+    /// Id tags no longer grouped under the erased tag
+    pub child_id_tags: u64,
+    pub id_tag_removed: bool,
+}
+
+impl From<ErasureReport> for ErasedIdTagDto {
+    fn from(r: ErasureReport) -> Self {
+        Self {
+            id_tag: r.id_tag,
+            pseudonym: r.pseudonym,
+            transactions: r.transactions,
+            reservations: r.reservations,
+            auth_events: r.auth_events,
+            ocpi_tokens: r.ocpi_tokens,
+            child_id_tags: r.child_id_tags,
+            id_tag_removed: r.id_tag_removed,
+        }
+    }
+}
+
+/// CustomerInformation (clear) outcome on a station
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StationClearResult {
+    pub charge_point_id: String,
+    pub id_tag: String,
+    /// Accepted, Rejected, Invalid, or the error when the command failed
+    pub status: String,
+}
+
+/// Erasure response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErasureResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    pub id_tags: Vec<ErasedIdTagDto>,
+    /// Empty unless `clear_on_stations` was requested
+    pub stations: Vec<StationClearResult>,
+}
@@ -0,0 +1,237 @@
+//! Customer data (GDPR) handlers
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use futures_util::future::join_all;
+
+use super::dto::{
+    EraseCustomerDataParams, ErasedIdTagDto, ErasureResponse, PersonalDataExport,
+    StationClearResult,
+};
+use crate::application::charging::commands::dispatcher::CustomerIdentifier;
+use crate::application::charging::commands::SharedCommandDispatcher;
+use crate::application::charging::session::SharedSessionRegistry;
+use crate::application::privacy::CustomerDataService;
+use crate::domain::{DomainError, OcppVersion};
+use crate::interfaces::http::common::ApiResponse;
+use crate::interfaces::http::middleware::{require_admin, AuthenticatedUser};
+
+/// Customer data handler state
+#[derive(Clone)]
+pub struct CustomerDataAppState {
+    pub service: Arc<CustomerDataService>,
+    pub session_registry: SharedSessionRegistry,
+    pub command_dispatcher: SharedCommandDispatcher,
+}
+
+fn error_status(e: &DomainError) -> StatusCode {
+    match e {
+        DomainError::NotFound { .. } => StatusCode::NOT_FOUND,
+        DomainError::Conflict(_) => StatusCode::CONFLICT,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// Send CustomerInformation (clear) for each id tag to every connected
+/// OCPP 2.0.1 station.
+async fn clear_on_stations(
+    state: &CustomerDataAppState,
+    id_tags: &[String],
+) -> Vec<StationClearResult> {
+    let stations: Vec<String> = state
+        .session_registry
+        .connected_ids()
+        .into_iter()
+        .filter(|cp| {
+            matches!(
+                state.session_registry.get_version(cp),
+                Some(OcppVersion::V201) | Some(OcppVersion::V21)
+            )
+        })
+        .collect();
+
+    let request_id = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i32;
+
+    let requests = stations.iter().flat_map(|cp| {
+        id_tags.iter().map(move |id_tag| async move {
+            let customer = CustomerIdentifier {
+                id_token: Some(id_tag.clone()),
+                ..Default::default()
+            };
+            let status = match state
+                .command_dispatcher
+                .customer_information(cp, request_id, false, true, &customer)
+                .await
+            {
+                Ok(result) => result.status,
+                Err(e) => e.to_string(),
+            };
+            StationClearResult {
+                charge_point_id: cp.clone(),
+                id_tag: id_tag.clone(),
+                status,
+            }
+        })
+    });
+
+    join_all(requests).await
+}
+
+/// Export all personal data stored for an id tag.
+#[utoipa::path(
+    get,
+    path = "/api/v1/customer-data/id-tags/{id_tag}",
+    tag = "CustomerData",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    params(("id_tag" = String, Path, description = "IdTag value")),
+    responses(
+        (status = 200, description = "Personal data export", body = ApiResponse<PersonalDataExport>),
+        (status = 403, description = "Not an administrator"),
+        (status = 404, description = "Nothing stored for this id tag")
+    )
+)]
+pub async fn export_id_tag_data(
+    State(state): State<CustomerDataAppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(id_tag): Path<String>,
+) -> Result<
+    Json<ApiResponse<PersonalDataExport>>,
+    (StatusCode, Json<ApiResponse<PersonalDataExport>>),
+> {
+    require_admin(&user)?;
+    match state.service.export_id_tag(&id_tag).await {
+        Ok(data) => Ok(Json(ApiResponse::success(PersonalDataExport::from(data)))),
+        Err(e) => Err((error_status(&e), Json(ApiResponse::error(e.to_string())))),
+    }
+}
+
+/// Pseudonymise all records of an id tag and delete the tag.
+///
+/// Refused with 409 while a transaction of the tag is in progress.
+#[utoipa::path(
+    post,
+    path = "/api/v1/customer-data/id-tags/{id_tag}/erase",
+    tag = "CustomerData",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    params(
+        ("id_tag" = String, Path, description = "IdTag value"),
+        EraseCustomerDataParams,
+    ),
+    responses(
+        (status = 200, description = "Personal data erased", body = ApiResponse<ErasureResponse>),
+        (status = 403, description = "Not an administrator"),
+        (status = 404, description = "Nothing stored for this id tag"),
+        (status = 409, description = "Transaction in progress")
+    )
+)]
+pub async fn erase_id_tag_data(
+    State(state): State<CustomerDataAppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(id_tag): Path<String>,
+    Query(params): Query<EraseCustomerDataParams>,
+) -> Result<Json<ApiResponse<ErasureResponse>>, (StatusCode, Json<ApiResponse<ErasureResponse>>)> {
+    require_admin(&user)?;
+    let report = state
+        .service
+        .erase_id_tag(&id_tag)
+        .await
+        .map_err(|e| (error_status(&e), Json(ApiResponse::error(e.to_string()))))?;
+
+    let stations = if params.clear_on_stations {
+        clear_on_stations(&state, &[id_tag]).await
+    } else {
+        Vec::new()
+    };
+
+    Ok(Json(ApiResponse::success(ErasureResponse {
+        user_id: None,
+        id_tags: vec![ErasedIdTagDto::from(report)],
+        stations,
+    })))
+}
+
+/// Export a user account and all personal data of its id tags.
+#[utoipa::path(
+    get,
+    path = "/api/v1/customer-data/users/{user_id}",
+    tag = "CustomerData",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    params(("user_id" = String, Path, description = "User ID")),
+    responses(
+        (status = 200, description = "Personal data export", body = ApiResponse<PersonalDataExport>),
+        (status = 403, description = "Not an administrator"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn export_user_data(
+    State(state): State<CustomerDataAppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(user_id): Path<String>,
+) -> Result<
+    Json<ApiResponse<PersonalDataExport>>,
+    (StatusCode, Json<ApiResponse<PersonalDataExport>>),
+> {
+    require_admin(&user)?;
+    match state.service.export_user(&user_id).await {
+        Ok(data) => Ok(Json(ApiResponse::success(PersonalDataExport::from(data)))),
+        Err(e) => Err((error_status(&e), Json(ApiResponse::error(e.to_string())))),
+    }
+}
+
+/// Erase all id tags of a user and delete the account.
+///
+/// Refused with 409 while a transaction of any of the user's tags is in progress.
+#[utoipa::path(
+    post,
+    path = "/api/v1/customer-data/users/{user_id}/erase",
+    tag = "CustomerData",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    params(
+        ("user_id" = String, Path, description = "User ID"),
+        EraseCustomerDataParams,
+    ),
+    responses(
+        (status = 200, description = "Personal data erased", body = ApiResponse<ErasureResponse>),
+        (status = 403, description = "Not an administrator"),
+        (status = 404, description = "User not found"),
+        (status = 409, description = "Transaction in progress")
+    )
+)]
+pub async fn erase_user_data(
+    State(state): State<CustomerDataAppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(user_id): Path<String>,
+    Query(params): Query<EraseCustomerDataParams>,
+) -> Result<Json<ApiResponse<ErasureResponse>>, (StatusCode, Json<ApiResponse<ErasureResponse>>)> {
+    require_admin(&user)?;
+    let report = state
+        .service
+        .erase_user(&user_id)
+        .await
+        .map_err(|e| (error_status(&e), Json(ApiResponse::error(e.to_string()))))?;
+
+    let stations = if params.clear_on_stations {
+        let id_tags: Vec<String> = report.id_tags.iter().map(|r| r.id_tag.clone()).collect();
+        clear_on_stations(&state, &id_tags).await
+    } else {
+        Vec::new()
+    };
+
+    Ok(Json(ApiResponse::success(ErasureResponse {
+        user_id: Some(report.user_id),
+        id_tags: report
+            .id_tags
+            .into_iter()
+            .map(ErasedIdTagDto::from)
+            .collect(),
+        stations,
+    })))
+}
@@ -0,0 +1,7 @@
+//! Customer data module — GDPR export and erasure of driver personal data
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
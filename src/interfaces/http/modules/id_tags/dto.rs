@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
+use crate::domain::IdTag;
 use crate::infrastructure::database::entities::id_tag;
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -39,6 +40,24 @@ impl From<id_tag::Model> for IdTagDto {
     }
 }
 
+impl From<IdTag> for IdTagDto {
+    fn from(t: IdTag) -> Self {
+        Self {
+            id_tag: t.id_tag,
+            parent_id_tag: t.parent_id_tag,
+            status: t.status.to_string(),
+            user_id: t.user_id,
+            name: t.name,
+            expiry_date: t.expiry_date.map(|d| d.to_rfc3339()),
+            max_active_transactions: t.max_active_transactions,
+            is_active: t.is_active,
+            created_at: t.created_at.to_rfc3339(),
+            updated_at: t.updated_at.to_rfc3339(),
+            last_used_at: t.last_used_at.map(|d| d.to_rfc3339()),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateIdTagRequest {
     #[validate(length(min = 1, max = 20, message = "id_tag must be 1–20 characters"))]
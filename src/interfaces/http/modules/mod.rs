@@ -1,15 +1,20 @@
-
 pub mod analytics;
 pub mod api_keys;
 pub mod auth;
+pub mod bulk;
 pub mod charge_points;
 pub mod commands;
+pub mod configuration;
+pub mod customer_data;
 pub mod health;
 pub mod id_tags;
 pub mod metrics;
 pub mod monitoring;
+pub mod ocpi_parties;
+pub mod rate_limit;
 pub mod request_id;
 pub mod reservations;
+pub mod retention;
 pub mod tariffs;
 pub mod transactions;
-pub mod users;
\ No newline at end of file
+pub mod users;
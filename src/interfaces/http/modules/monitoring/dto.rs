@@ -11,6 +11,12 @@ pub struct HeartbeatStatusDto {
     pub is_connected: bool,
     pub status: String,
     pub seconds_since_heartbeat: Option<i64>,
+    /// Heartbeat interval the station last accepted (seconds)
+    pub heartbeat_interval: Option<i32>,
+    /// Silence after which the station counts as stale (seconds)
+    pub offline_threshold_secs: i64,
+    /// Silence after which the station is marked Unavailable (seconds)
+    pub unavailable_threshold_secs: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -38,6 +38,9 @@ pub async fn get_heartbeat_statuses(
                     is_connected: s.is_connected,
                     status: s.status.to_string(),
                     seconds_since_heartbeat: s.seconds_since_heartbeat,
+                    heartbeat_interval: s.heartbeat_interval,
+                    offline_threshold_secs: s.thresholds.offline_secs,
+                    unavailable_threshold_secs: s.thresholds.unavailable_secs,
                 })
                 .collect();
             Json(ApiResponse::success(dtos))
@@ -0,0 +1,65 @@
+//! OCPI party DTOs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::domain::OcpiParty;
+
+/// Roaming partner as seen by administrators
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OcpiPartyResponse {
+    pub id: i32,
+    pub country_code: String,
+    pub party_id: String,
+    pub role: String,
+    pub name: String,
+    /// Pending, Registered, Unregistered
+    pub status: String,
+    /// One-time registration token to hand to the partner (only while pending)
+    pub token_a: Option<String>,
+    /// Our versions endpoint the partner should call with token A
+    pub versions_url: String,
+    /// Partner's versions endpoint (after registration)
+    pub partner_versions_url: Option<String>,
+    pub version: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OcpiPartyResponse {
+    pub fn from_party(p: OcpiParty, versions_url: &str) -> Self {
+        Self {
+            id: p.id,
+            country_code: p.country_code,
+            party_id: p.party_id,
+            role: p.role,
+            name: p.name,
+            status: p.status.to_string(),
+            token_a: p.token_a,
+            versions_url: versions_url.to_string(),
+            partner_versions_url: p.versions_url,
+            version: p.version,
+            created_at: p.created_at,
+            updated_at: p.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateOcpiPartyRequest {
+    #[validate(length(equal = 2, message = "country_code must be 2 characters"))]
+    pub country_code: String,
+    #[validate(length(equal = 3, message = "party_id must be 3 characters"))]
+    pub party_id: String,
+    #[validate(length(min = 1, max = 100, message = "name is required"))]
+    pub name: String,
+    /// OCPI role of the partner (default: EMSP)
+    #[serde(default = "default_role")]
+    pub role: String,
+}
+
+fn default_role() -> String {
+    "EMSP".to_string()
+}
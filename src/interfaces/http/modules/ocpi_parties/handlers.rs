@@ -0,0 +1,192 @@
+//! OCPI party REST API handlers
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+use super::dto::{CreateOcpiPartyRequest, OcpiPartyResponse};
+use crate::domain::{OcpiParty, RepositoryProvider};
+use crate::interfaces::http::common::{ApiResponse, ValidatedJson};
+
+/// Application state for OCPI party handlers.
+#[derive(Clone)]
+pub struct OcpiPartyAppState {
+    pub repos: Arc<dyn RepositoryProvider>,
+    /// Public URL of our OCPI versions endpoint
+    pub versions_url: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/ocpi/parties",
+    tag = "OCPI",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "Roaming partners", body = ApiResponse<Vec<OcpiPartyResponse>>)
+    )
+)]
+pub async fn list_ocpi_parties(
+    State(state): State<OcpiPartyAppState>,
+) -> Result<Json<ApiResponse<Vec<OcpiPartyResponse>>>, (StatusCode, Json<ApiResponse<()>>)> {
+    match state.repos.ocpi_parties().find_all().await {
+        Ok(parties) => Ok(Json(ApiResponse::success(
+            parties
+                .into_iter()
+                .map(|p| OcpiPartyResponse::from_party(p, &state.versions_url))
+                .collect(),
+        ))),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!(
+                "Failed to list OCPI parties: {}",
+                e
+            ))),
+        )),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/ocpi/parties/{id}",
+    tag = "OCPI",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    params(("id" = i32, Path, description = "Party ID")),
+    responses(
+        (status = 200, description = "Roaming partner", body = ApiResponse<OcpiPartyResponse>),
+        (status = 404, description = "Not found")
+    )
+)]
+pub async fn get_ocpi_party(
+    State(state): State<OcpiPartyAppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<ApiResponse<OcpiPartyResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    match state.repos.ocpi_parties().find_by_id(id).await {
+        Ok(Some(party)) => Ok(Json(ApiResponse::success(OcpiPartyResponse::from_party(
+            party,
+            &state.versions_url,
+        )))),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("OCPI party {} not found", id))),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!(
+                "Failed to get OCPI party: {}",
+                e
+            ))),
+        )),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/ocpi/parties",
+    tag = "OCPI",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    request_body = CreateOcpiPartyRequest,
+    responses(
+        (status = 201, description = "Partner created, token A issued", body = ApiResponse<OcpiPartyResponse>),
+        (status = 409, description = "Partner already exists")
+    )
+)]
+pub async fn create_ocpi_party(
+    State(state): State<OcpiPartyAppState>,
+    ValidatedJson(request): ValidatedJson<CreateOcpiPartyRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<OcpiPartyResponse>>), (StatusCode, Json<ApiResponse<()>>)>
+{
+    let existing = state.repos.ocpi_parties().find_all().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!(
+                "Failed to list OCPI parties: {}",
+                e
+            ))),
+        )
+    })?;
+    if existing
+        .iter()
+        .any(|p| p.owns(&request.country_code, &request.party_id))
+    {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::error(format!(
+                "OCPI party {}/{} already exists",
+                request.country_code, request.party_id
+            ))),
+        ));
+    }
+
+    let party = OcpiParty::new(
+        request.country_code,
+        request.party_id,
+        request.role,
+        request.name,
+    );
+    match state.repos.ocpi_parties().save(party).await {
+        Ok(party) => Ok((
+            StatusCode::CREATED,
+            Json(ApiResponse::success(OcpiPartyResponse::from_party(
+                party,
+                &state.versions_url,
+            ))),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!(
+                "Failed to create OCPI party: {}",
+                e
+            ))),
+        )),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/ocpi/parties/{id}",
+    tag = "OCPI",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    params(("id" = i32, Path, description = "Party ID")),
+    responses(
+        (status = 200, description = "Deleted"),
+        (status = 404, description = "Not found")
+    )
+)]
+pub async fn delete_ocpi_party(
+    State(state): State<OcpiPartyAppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<ApiResponse<()>>)> {
+    match state.repos.ocpi_parties().find_by_id(id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(format!("OCPI party {} not found", id))),
+            ))
+        }
+        Err(e) => {
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!(
+                    "Failed to get OCPI party: {}",
+                    e
+                ))),
+            ))
+        }
+    }
+
+    state.repos.ocpi_parties().delete(id).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!(
+                "Failed to delete OCPI party: {}",
+                e
+            ))),
+        )
+    })?;
+    Ok(Json(ApiResponse::success(())))
+}
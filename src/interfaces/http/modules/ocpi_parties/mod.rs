@@ -0,0 +1,7 @@
+//! OCPI partner administration — issue token A, list and remove roaming partners
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
@@ -0,0 +1,107 @@
+//! Rate limiting middleware
+//!
+//! Limits requests per client IP over a sliding minute. The limit is read
+//! from the running configuration on every request, so reloading
+//! `[rate_limit]` applies immediately. Responses carry
+//! `x-ratelimit-limit` / `x-ratelimit-remaining`; rejected ones get 429
+//! with `retry-after`.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, State},
+    http::{HeaderName, HeaderValue, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::config::reload::LiveConfig;
+use crate::config::RateLimitConfig;
+use crate::interfaces::http::common::ApiResponse;
+use crate::shared::rate_limit::RateLimiter;
+
+const LIMIT_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+const REMAINING_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+
+/// One limit, e.g. API requests or login attempts, with its own windows.
+#[derive(Clone)]
+pub struct RateLimitState {
+    limiter: Arc<RateLimiter>,
+    live_config: LiveConfig,
+    limit: fn(&RateLimitConfig) -> u32,
+}
+
+impl RateLimitState {
+    /// `[rate_limit].api_requests_per_minute`
+    pub fn api(live_config: LiveConfig) -> Self {
+        Self::new(live_config, |cfg| cfg.api_requests_per_minute)
+    }
+
+    /// `[rate_limit].login_attempts_per_minute`
+    pub fn login(live_config: LiveConfig) -> Self {
+        Self::new(live_config, |cfg| cfg.login_attempts_per_minute)
+    }
+
+    fn new(live_config: LiveConfig, limit: fn(&RateLimitConfig) -> u32) -> Self {
+        Self {
+            limiter: Arc::new(RateLimiter::new()),
+            live_config,
+            limit,
+        }
+    }
+
+    fn current_limit(&self) -> u32 {
+        (self.limit)(&self.live_config.borrow().rate_limit).max(1)
+    }
+}
+
+/// Reject requests over the limit with 429 Too Many Requests.
+///
+/// Requests without a peer address (the server was not started with
+/// connect info) are not limited.
+pub async fn rate_limit_middleware(
+    State(state): State<RateLimitState>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let Some(ConnectInfo(addr)) = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .copied()
+    else {
+        return next.run(request).await;
+    };
+
+    let limit = state.current_limit();
+    match state.limiter.check(addr.ip(), limit) {
+        Ok(remaining) => {
+            let mut response = next.run(request).await;
+            let headers = response.headers_mut();
+            headers.insert(LIMIT_HEADER, HeaderValue::from(limit));
+            headers.insert(REMAINING_HEADER, HeaderValue::from(remaining));
+            response
+        }
+        Err(retry_after) => {
+            let retry_after = retry_after.as_secs().max(1);
+            let mut response = (
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(ApiResponse::<()>::error(format!(
+                    "Too many requests, retry in {}s",
+                    retry_after
+                ))),
+            )
+                .into_response();
+            let headers = response.headers_mut();
+            headers.insert(LIMIT_HEADER, HeaderValue::from(limit));
+            headers.insert(REMAINING_HEADER, HeaderValue::from(0u32));
+            headers.insert(
+                axum::http::header::RETRY_AFTER,
+                HeaderValue::from(retry_after),
+            );
+            response
+        }
+    }
+}
@@ -1,7 +1,9 @@
 //! Reservation DTOs
 
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::reservation::{ConnectorAvailability, Reservation};
 
 /// Request to create a new reservation (ReserveNow)
 #[derive(Debug, Deserialize, ToSchema)]
@@ -15,7 +17,10 @@ pub struct CreateReservationRequest {
     pub id_tag: String,
     /// Optional parent ID tag (group)
     pub parent_id_tag: Option<String>,
-    /// Reservation expiry date (ISO 8601)
+    /// Start of a booked slot (ISO 8601), in the future; omit to reserve
+    /// right away
+    pub start_date: Option<String>,
+    /// Reservation expiry date (ISO 8601), the end of a booked slot
     pub expiry_date: String,
 }
 
@@ -27,16 +32,67 @@ pub struct ReservationDto {
     pub connector_id: i32,
     pub id_tag: String,
     pub parent_id_tag: Option<String>,
+    pub start_date: Option<String>,
     pub expiry_date: String,
     pub status: String,
     pub created_at: String,
 }
 
+impl From<Reservation> for ReservationDto {
+    fn from(r: Reservation) -> Self {
+        Self {
+            id: r.id,
+            charge_point_id: r.charge_point_id,
+            connector_id: r.connector_id,
+            id_tag: r.id_tag,
+            parent_id_tag: r.parent_id_tag,
+            start_date: r.start_date.map(|d| d.to_rfc3339()),
+            expiry_date: r.expiry_date.to_rfc3339(),
+            status: r.status.as_str().to_string(),
+            created_at: r.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Time range for connector availability
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AvailabilityQuery {
+    pub charge_point_id: String,
+    /// Range start (ISO 8601)
+    pub from: String,
+    /// Range end (ISO 8601)
+    pub to: String,
+}
+
+/// Whether a connector can be reserved in the requested range
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConnectorAvailabilityDto {
+    pub connector_id: i32,
+    pub available: bool,
+    /// Reservations holding this connector during the range
+    pub reservations: Vec<ReservationDto>,
+}
+
+impl From<ConnectorAvailability> for ConnectorAvailabilityDto {
+    fn from(a: ConnectorAvailability) -> Self {
+        Self {
+            connector_id: a.connector_id,
+            available: a.available,
+            reservations: a
+                .reservations
+                .into_iter()
+                .map(ReservationDto::from)
+                .collect(),
+        }
+    }
+}
+
 /// Response from creating a reservation
 #[derive(Debug, Serialize, ToSchema)]
 pub struct CreateReservationResponse {
     pub reservation_id: i32,
-    /// Status returned by the charge point (e.g. "Accepted", "Rejected", "Occupied")
+    /// Status returned by the charge point (e.g. "Accepted", "Rejected", "Occupied"),
+    /// or "Scheduled" for a booked slot
     pub status: String,
     pub message: Option<String>,
 }
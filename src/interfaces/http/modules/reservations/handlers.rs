@@ -2,15 +2,18 @@
 
 use std::sync::Arc;
 
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::Json;
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
 
 use crate::application::charging::commands::SharedCommandDispatcher;
 use crate::application::charging::session::SharedSessionRegistry;
-use crate::domain::reservation::{Reservation, ReservationStatus};
-use crate::domain::RepositoryProvider;
+use crate::application::events::{Event, ReservationStatusChangedEvent, SharedEventBus};
+use crate::domain::reservation::{
+    connector_availability, ConnectorAvailability, Reservation, ReservationStatus,
+};
+use crate::domain::{DomainResult, RepositoryProvider};
 use crate::interfaces::http::common::ApiResponse;
 
 use super::dto::*;
@@ -21,6 +24,53 @@ pub struct ReservationAppState {
     pub repos: Arc<dyn RepositoryProvider>,
     pub session_registry: SharedSessionRegistry,
     pub command_dispatcher: SharedCommandDispatcher,
+    pub event_bus: SharedEventBus,
+}
+
+fn parse_date(field: &str, value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| format!("Invalid {}: {}", field, e))
+}
+
+/// Availability of every known connector of `charge_point_id` (plus
+/// `connector_id`, if given) between `from` and `to`.
+async fn availability(
+    state: &ReservationAppState,
+    charge_point_id: &str,
+    connector_id: Option<i32>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> DomainResult<Vec<ConnectorAvailability>> {
+    let mut connector_ids: Vec<i32> = state
+        .repos
+        .charge_points()
+        .find_by_id(charge_point_id)
+        .await?
+        .map(|cp| {
+            cp.connectors
+                .iter()
+                .map(|c| c.id as i32)
+                .filter(|&id| id > 0)
+                .collect()
+        })
+        .unwrap_or_default();
+    if let Some(id) = connector_id.filter(|&id| id > 0 && !connector_ids.contains(&id)) {
+        connector_ids.push(id);
+    }
+    connector_ids.sort_unstable();
+
+    let reservations = state
+        .repos
+        .reservations()
+        .find_pending_for_charge_point(charge_point_id)
+        .await?;
+    Ok(connector_availability(
+        &connector_ids,
+        &reservations,
+        from,
+        to,
+    ))
 }
 
 #[utoipa::path(
@@ -32,6 +82,7 @@ pub struct ReservationAppState {
     responses(
         (status = 200, description = "Reservation result", body = ApiResponse<CreateReservationResponse>),
         (status = 404, description = "Charge point not connected"),
+        (status = 409, description = "Connector already reserved for that time"),
         (status = 400, description = "Invalid request")
     )
 )]
@@ -42,8 +93,41 @@ pub async fn create_reservation(
     Json<ApiResponse<CreateReservationResponse>>,
     (StatusCode, Json<ApiResponse<CreateReservationResponse>>),
 > {
-    // Validate charge point is connected
-    if !state.session_registry.is_connected(&request.charge_point_id) {
+    let bad_request = |msg: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<CreateReservationResponse>::error(msg)),
+        )
+    };
+    let now = Utc::now();
+
+    let expiry_date = parse_date("expiry_date", &request.expiry_date).map_err(bad_request)?;
+    let start_date = request
+        .start_date
+        .as_deref()
+        .map(|d| parse_date("start_date", d))
+        .transpose()
+        .map_err(bad_request)?;
+
+    // Validate expiry is in the future
+    if expiry_date <= now {
+        return Err(bad_request("expiry_date must be in the future".to_string()));
+    }
+    if start_date.is_some_and(|start| start <= now) {
+        return Err(bad_request("start_date must be in the future".to_string()));
+    }
+    if start_date.is_some_and(|start| start >= expiry_date) {
+        return Err(bad_request(
+            "start_date must be before expiry_date".to_string(),
+        ));
+    }
+
+    // Immediate reservations go to the charge point right away
+    if start_date.is_none()
+        && !state
+            .session_registry
+            .is_connected(&request.charge_point_id)
+    {
         return Err((
             StatusCode::NOT_FOUND,
             Json(ApiResponse::error(format!(
@@ -53,27 +137,73 @@ pub async fn create_reservation(
         ));
     }
 
-    // Parse expiry date
-    let expiry_date = DateTime::parse_from_rfc3339(&request.expiry_date)
-        .map(|dt| dt.with_timezone(&chrono::Utc))
-        .map_err(|e| {
-            (
-                StatusCode::BAD_REQUEST,
-                Json(ApiResponse::error(format!("Invalid expiry_date: {}", e))),
-            )
-        })?;
-
-    // Validate expiry is in the future
-    if expiry_date <= chrono::Utc::now() {
+    // The connector must be free for the whole window
+    let slots = availability(
+        &state,
+        &request.charge_point_id,
+        Some(request.connector_id),
+        start_date.unwrap_or(now),
+        expiry_date,
+    )
+    .await
+    .map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(e.to_string())),
+        )
+    })?;
+    let free = if request.connector_id == 0 {
+        slots.is_empty() || slots.iter().any(|a| a.available)
+    } else {
+        slots
+            .iter()
+            .any(|a| a.connector_id == request.connector_id && a.available)
+    };
+    if !free {
         return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ApiResponse::error("expiry_date must be in the future")),
+            StatusCode::CONFLICT,
+            Json(ApiResponse::error(format!(
+                "Connector {} of '{}' is already reserved for that time",
+                request.connector_id, request.charge_point_id
+            ))),
         ));
     }
 
     // Generate reservation ID
     let reservation_id = state.repos.reservations().next_id().await;
 
+    // Booked slots are sent by the reservation scheduler before they begin
+    if let Some(start_date) = start_date {
+        let reservation = Reservation::scheduled(
+            reservation_id,
+            &request.charge_point_id,
+            request.connector_id,
+            &request.id_tag,
+            request.parent_id_tag.clone(),
+            start_date,
+            expiry_date,
+        );
+        state
+            .repos
+            .reservations()
+            .save(reservation.clone())
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiResponse::error(e.to_string())),
+                )
+            })?;
+        state.event_bus.publish(Event::ReservationStatusChanged(
+            ReservationStatusChangedEvent::from_reservation(&reservation),
+        ));
+        return Ok(Json(ApiResponse::success(CreateReservationResponse {
+            reservation_id,
+            status: reservation.status.as_str().to_string(),
+            message: Some("Reservation scheduled for the requested slot".to_string()),
+        })));
+    }
+
     // Send ReserveNow to the charge point
     let status = match state
         .command_dispatcher
@@ -107,8 +237,11 @@ pub async fn create_reservation(
             request.parent_id_tag.clone(),
             expiry_date,
         );
-        if let Err(e) = state.repos.reservations().save(reservation).await {
-            tracing::error!("Failed to save reservation: {}", e);
+        match state.repos.reservations().save(reservation.clone()).await {
+            Ok(()) => state.event_bus.publish(Event::ReservationStatusChanged(
+                ReservationStatusChangedEvent::from_reservation(&reservation),
+            )),
+            Err(e) => tracing::error!("Failed to save reservation: {}", e),
         }
     }
 
@@ -164,8 +297,8 @@ pub async fn cancel_reservation(
         ));
     };
 
-    // Can only cancel active reservations
-    if reservation.status != ReservationStatus::Accepted {
+    // Can only cancel active or booked reservations
+    if !reservation.is_pending() {
         return Err((
             StatusCode::BAD_REQUEST,
             Json(ApiResponse::error(format!(
@@ -175,10 +308,11 @@ pub async fn cancel_reservation(
         ));
     }
 
-    // Send CancelReservation to charge point (if connected)
-    let status = if state
-        .session_registry
-        .is_connected(&reservation.charge_point_id)
+    // Send CancelReservation to charge point (if connected and already sent)
+    let status = if reservation.status == ReservationStatus::Accepted
+        && state
+            .session_registry
+            .is_connected(&reservation.charge_point_id)
     {
         match state
             .command_dispatcher
@@ -194,15 +328,20 @@ pub async fn cancel_reservation(
             }
         }
     } else {
-        // Charge point is offline — cancel locally only
+        // Charge point is offline or the slot is not sent yet — cancel locally only
         "Accepted".to_string()
     };
 
     // Update reservation status in DB
     let accepted = status.contains("Accepted");
     if accepted {
-        if let Err(e) = state.repos.reservations().cancel(reservation_id).await {
-            tracing::error!("Failed to cancel reservation in DB: {}", e);
+        let mut cancelled = reservation.clone();
+        cancelled.cancel();
+        match state.repos.reservations().cancel(reservation_id).await {
+            Ok(()) => state.event_bus.publish(Event::ReservationStatusChanged(
+                ReservationStatusChangedEvent::from_reservation(&cancelled),
+            )),
+            Err(e) => tracing::error!("Failed to cancel reservation in DB: {}", e),
         }
     }
 
@@ -238,19 +377,7 @@ pub async fn list_reservations(
         )
     })?;
 
-    let dtos: Vec<ReservationDto> = reservations
-        .into_iter()
-        .map(|r| ReservationDto {
-            id: r.id,
-            charge_point_id: r.charge_point_id,
-            connector_id: r.connector_id,
-            id_tag: r.id_tag,
-            parent_id_tag: r.parent_id_tag,
-            expiry_date: r.expiry_date.to_rfc3339(),
-            status: r.status.as_str().to_string(),
-            created_at: r.created_at.to_rfc3339(),
-        })
-        .collect();
+    let dtos: Vec<ReservationDto> = reservations.into_iter().map(ReservationDto::from).collect();
 
     Ok(Json(ApiResponse::success(dtos)))
 }
@@ -295,14 +422,48 @@ pub async fn get_reservation(
         ));
     };
 
-    Ok(Json(ApiResponse::success(ReservationDto {
-        id: r.id,
-        charge_point_id: r.charge_point_id,
-        connector_id: r.connector_id,
-        id_tag: r.id_tag,
-        parent_id_tag: r.parent_id_tag,
-        expiry_date: r.expiry_date.to_rfc3339(),
-        status: r.status.as_str().to_string(),
-        created_at: r.created_at.to_rfc3339(),
-    })))
+    Ok(Json(ApiResponse::success(ReservationDto::from(r))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/reservations/availability",
+    tag = "Reservations",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    params(AvailabilityQuery),
+    responses(
+        (status = 200, description = "Connector availability for the range", body = ApiResponse<Vec<ConnectorAvailabilityDto>>),
+        (status = 400, description = "Invalid range")
+    )
+)]
+pub async fn get_availability(
+    State(state): State<ReservationAppState>,
+    Query(query): Query<AvailabilityQuery>,
+) -> Result<
+    Json<ApiResponse<Vec<ConnectorAvailabilityDto>>>,
+    (StatusCode, Json<ApiResponse<Vec<ConnectorAvailabilityDto>>>),
+> {
+    let bad_request = |msg: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<Vec<ConnectorAvailabilityDto>>::error(msg)),
+        )
+    };
+    let from = parse_date("from", &query.from).map_err(bad_request)?;
+    let to = parse_date("to", &query.to).map_err(bad_request)?;
+    if from >= to {
+        return Err(bad_request("from must be before to".to_string()));
+    }
+
+    let slots = availability(&state, &query.charge_point_id, None, from, to)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(e.to_string())),
+            )
+        })?;
+
+    let dtos: Vec<ConnectorAvailabilityDto> = slots.into_iter().map(Into::into).collect();
+    Ok(Json(ApiResponse::success(dtos)))
 }
@@ -0,0 +1,93 @@
+//! Retention API data transfer objects
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::retention::{ArchivedClass, RetentionPolicy};
+use crate::domain::ArchiveRun;
+
+/// Retention of one data class.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RetentionPolicyDto {
+    pub data_class: String,
+    /// Days rows are kept; 0 keeps them forever.
+    pub retain_days: u32,
+    /// Rows that ended before this instant are due now (ISO 8601).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cutoff: Option<String>,
+}
+
+impl From<&RetentionPolicy> for RetentionPolicyDto {
+    fn from(p: &RetentionPolicy) -> Self {
+        Self {
+            data_class: p.data_class.as_str().to_string(),
+            retain_days: p.retain_days,
+            cutoff: p.cutoff(Utc::now()).map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+/// Outcome of one data class within a run.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ArchivedClassDto {
+    pub data_class: String,
+    /// ISO 8601.
+    pub cutoff: String,
+    pub rows: u64,
+    /// Archive file, absent when nothing was due.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+}
+
+impl From<ArchivedClass> for ArchivedClassDto {
+    fn from(c: ArchivedClass) -> Self {
+        Self {
+            data_class: c.data_class,
+            cutoff: c.cutoff.to_rfc3339(),
+            rows: c.rows,
+            file: c.file,
+        }
+    }
+}
+
+/// An archival run.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ArchiveRunDto {
+    pub id: i32,
+    /// "Scheduled" or "Manual".
+    pub trigger: String,
+    /// "Running", "Completed" or "Failed".
+    pub status: String,
+    /// ISO 8601.
+    pub started_at: String,
+    /// ISO 8601; absent while running.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<String>,
+    pub rows_archived: u64,
+    pub classes: Vec<ArchivedClassDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl From<ArchiveRun> for ArchiveRunDto {
+    fn from(r: ArchiveRun) -> Self {
+        Self {
+            id: r.id,
+            trigger: r.trigger.as_str().to_string(),
+            status: r.status.as_str().to_string(),
+            started_at: r.started_at.to_rfc3339(),
+            finished_at: r.finished_at.map(|t| t.to_rfc3339()),
+            rows_archived: r.rows_archived,
+            classes: r.classes.into_iter().map(Into::into).collect(),
+            error: r.error,
+        }
+    }
+}
+
+/// Paging for the run list.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ArchiveRunListParams {
+    /// Most recent runs to return (default 20, at most 100).
+    pub limit: Option<u64>,
+}
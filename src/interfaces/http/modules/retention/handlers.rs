@@ -0,0 +1,150 @@
+//! Retention handlers — inspect policies, trigger and inspect archival runs
+//!
+//! Archiving deletes data, so every endpoint is limited to administrators.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Extension, Json,
+};
+
+use super::dto::{ArchiveRunDto, ArchiveRunListParams, RetentionPolicyDto};
+use crate::application::retention::ArchiveService;
+use crate::domain::{ArchiveTrigger, DomainError, RepositoryProvider};
+use crate::interfaces::http::common::ApiResponse;
+use crate::interfaces::http::middleware::{require_admin, AuthenticatedUser};
+
+/// Retention handler state
+#[derive(Clone)]
+pub struct RetentionAppState {
+    pub repos: Arc<dyn RepositoryProvider>,
+    pub archive: Arc<ArchiveService>,
+}
+
+type ErrorResponse = (StatusCode, Json<ApiResponse<()>>);
+
+fn internal(e: DomainError) -> ErrorResponse {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiResponse::error(e.to_string())),
+    )
+}
+
+/// Retention configured per data class.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retention/policies",
+    tag = "Retention",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 200, description = "Retention policies", body = ApiResponse<Vec<RetentionPolicyDto>>),
+        (status = 403, description = "Not an administrator")
+    )
+)]
+pub async fn list_retention_policies(
+    State(state): State<RetentionAppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+) -> Result<Json<ApiResponse<Vec<RetentionPolicyDto>>>, ErrorResponse> {
+    require_admin(&user)?;
+    Ok(Json(ApiResponse::success(
+        state.archive.policies().iter().map(Into::into).collect(),
+    )))
+}
+
+/// Start an archival run now.
+///
+/// The run continues in the background; poll it by id. Refused with 409
+/// while another run is in progress.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retention/runs",
+    tag = "Retention",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses(
+        (status = 202, description = "Run started", body = ApiResponse<ArchiveRunDto>),
+        (status = 403, description = "Not an administrator"),
+        (status = 409, description = "A run is already in progress")
+    )
+)]
+pub async fn start_archive_run(
+    State(state): State<RetentionAppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+) -> Result<(StatusCode, Json<ApiResponse<ArchiveRunDto>>), ErrorResponse> {
+    require_admin(&user)?;
+    match state.archive.start(ArchiveTrigger::Manual).await {
+        Ok(run) => Ok((
+            StatusCode::ACCEPTED,
+            Json(ApiResponse::success(ArchiveRunDto::from(run))),
+        )),
+        Err(DomainError::Conflict(msg)) => {
+            Err((StatusCode::CONFLICT, Json(ApiResponse::error(msg))))
+        }
+        Err(e) => Err(internal(e)),
+    }
+}
+
+/// Recent archival runs, newest first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retention/runs",
+    tag = "Retention",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    params(ArchiveRunListParams),
+    responses(
+        (status = 200, description = "Archival runs", body = ApiResponse<Vec<ArchiveRunDto>>),
+        (status = 403, description = "Not an administrator")
+    )
+)]
+pub async fn list_archive_runs(
+    State(state): State<RetentionAppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Query(params): Query<ArchiveRunListParams>,
+) -> Result<Json<ApiResponse<Vec<ArchiveRunDto>>>, ErrorResponse> {
+    require_admin(&user)?;
+    let limit = params.limit.unwrap_or(20).clamp(1, 100);
+    let runs = state
+        .repos
+        .retention()
+        .find_runs(limit)
+        .await
+        .map_err(internal)?;
+    Ok(Json(ApiResponse::success(
+        runs.into_iter().map(Into::into).collect(),
+    )))
+}
+
+/// One archival run.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retention/runs/{id}",
+    tag = "Retention",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    params(("id" = i32, Path, description = "Run ID")),
+    responses(
+        (status = 200, description = "Archival run", body = ApiResponse<ArchiveRunDto>),
+        (status = 403, description = "Not an administrator"),
+        (status = 404, description = "Not found")
+    )
+)]
+pub async fn get_archive_run(
+    State(state): State<RetentionAppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(id): Path<i32>,
+) -> Result<Json<ApiResponse<ArchiveRunDto>>, ErrorResponse> {
+    require_admin(&user)?;
+    let run = state
+        .repos
+        .retention()
+        .find_run(id)
+        .await
+        .map_err(internal)?;
+    match run {
+        Some(run) => Ok(Json(ApiResponse::success(run.into()))),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("Archival run {} not found", id))),
+        )),
+    }
+}
@@ -0,0 +1,7 @@
+//! Retention module — retention policies and archival runs (admin)
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
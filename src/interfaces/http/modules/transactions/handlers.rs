@@ -15,7 +15,7 @@ use crate::application::events::{
     Event, SharedEventBus, TransactionBilledEvent, TransactionStoppedEvent,
 };
 use crate::application::BillingService;
-use crate::domain::RepositoryProvider;
+use crate::domain::{EvChargingNeeds, RepositoryProvider};
 use crate::interfaces::http::common::{ApiResponse, PaginatedResponse, PaginationParams};
 
 /// Transaction handler state
@@ -163,6 +163,51 @@ pub async fn get_transaction(
     }
 }
 
+/// Charging needs the EV reported during a transaction (ISO 15118, OCPP 2.0.1),
+/// oldest first, with the schedule computed for each.
+#[utoipa::path(
+    get,
+    path = "/api/v1/transactions/{id}/charging-needs",
+    tag = "Transactions",
+    params(("id" = i32, Path, description = "Transaction ID")),
+    responses(
+        (status = 200, description = "Reported charging needs", body = ApiResponse<Vec<EvChargingNeeds>>),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []), ("api_key" = []))
+)]
+pub async fn get_transaction_charging_needs(
+    State(state): State<TransactionAppState>,
+    Path(id): Path<i32>,
+) -> Result<
+    Json<ApiResponse<Vec<EvChargingNeeds>>>,
+    (StatusCode, Json<ApiResponse<Vec<EvChargingNeeds>>>),
+> {
+    match state.repos.transactions().find_by_id(id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(format!("Transaction {} not found", id))),
+            ))
+        }
+        Err(e) => {
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(e.to_string())),
+            ))
+        }
+    }
+
+    match state.repos.ev_charging_needs().find_for_transaction(id).await {
+        Ok(needs) => Ok(Json(ApiResponse::success(needs))),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(e.to_string())),
+        )),
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/charge-points/{charge_point_id}/transactions/active",
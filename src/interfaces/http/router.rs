@@ -11,10 +11,9 @@ use axum::{
 use sea_orm::DatabaseConnection;
 use tower_http::cors::{AllowOrigin, Any, CorsLayer};
 
-use crate::config::AppConfig;
+use crate::config::reload::{ConfigReloader, LiveConfig};
+use crate::config::CorsConfig;
 use tower_http::trace::TraceLayer;
-use tower_governor::governor::GovernorConfigBuilder;
-use tower_governor::GovernorLayer;
 use tracing::info;
 use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
 use utoipa::{Modify, OpenApi};
@@ -25,9 +24,13 @@ use crate::application::identity::UserService;
 use crate::application::events::SharedEventBus;
 use crate::application::SharedCommandDispatcher;
 use crate::application::SharedSessionRegistry;
+use crate::application::charging::services::customer_information::SharedCustomerInformationStore;
 use crate::application::charging::services::device_report::SharedDeviceReportStore;
 use crate::application::{ChargePointService, HeartbeatMonitor};
 use crate::application::BillingService;
+use crate::application::bulk::BulkService;
+use crate::application::privacy::CustomerDataService;
+use crate::application::retention::ArchiveService;
 use crate::domain::RepositoryProvider;
 use crate::infrastructure::crypto::jwt::JwtConfig;
 use crate::infrastructure::database::repositories::user_repository::UserRepository;
@@ -36,9 +39,11 @@ use crate::interfaces::ws::{create_notification_state, ws_notifications_handler}
 use metrics_exporter_prometheus::PrometheusHandle;
 
 use super::modules::{
-    analytics, api_keys, auth, charge_points, commands, health, id_tags, metrics, monitoring,
-    reservations, tariffs, transactions, users,
+    analytics, api_keys, auth, bulk, charge_points, commands, configuration, customer_data,
+    health, id_tags, metrics, monitoring, ocpi_parties, rate_limit, reservations, retention,
+    tariffs, transactions, users,
 };
+use crate::interfaces::ocpi::{create_ocpi_router, OcpiClient, OcpiState};
 
 /// Unified state for all charge-point related routes (CP CRUD + commands + transactions).
 /// Axum extracts the specific handler state via `FromRef`.
@@ -52,6 +57,7 @@ pub struct ChargePointUnifiedState {
     pub charge_point_service: Arc<ChargePointService>,
     pub billing_service: Arc<BillingService>,
     pub report_store: SharedDeviceReportStore,
+    pub customer_info_store: SharedCustomerInformationStore,
 }
 
 // -- FromRef implementations so each handler keeps its own State<T> extractor --
@@ -75,6 +81,7 @@ impl FromRef<ChargePointUnifiedState> for commands::CommandAppState {
             charge_point_service: Arc::clone(&s.charge_point_service),
             billing_service: Arc::clone(&s.billing_service),
             report_store: s.report_store.clone(),
+            customer_info_store: s.customer_info_store.clone(),
         }
     }
 }
@@ -95,6 +102,7 @@ impl FromRef<ChargePointUnifiedState> for reservations::ReservationAppState {
             repos: Arc::clone(&s.repos),
             session_registry: s.session_registry.clone(),
             command_dispatcher: Arc::clone(&s.command_dispatcher),
+            event_bus: s.event_bus.clone(),
         }
     }
 }
@@ -197,9 +205,11 @@ impl Modify for SecurityAddon {
         commands::send_local_list,
         commands::clear_auth_cache,
         commands::data_transfer_handler,
+        commands::list_data_transfers,
         commands::clear_charging_profile,
         commands::set_charging_profile,
         commands::list_charging_profiles,
+        commands::list_external_charging_limits,
         commands::get_charging_profiles_handler,
         commands::get_composite_schedule,
         // Firmware Management
@@ -208,15 +218,27 @@ impl Modify for SecurityAddon {
         // Device Reports
         commands::request_base_report,
         commands::get_device_report,
+        commands::request_report,
         // Variable Monitoring (v2.0.1)
         commands::set_variable_monitoring_handler,
         commands::set_monitoring_base_handler,
+        commands::set_monitoring_level_handler,
         commands::clear_variable_monitoring_handler,
+        commands::request_monitoring_report,
+        commands::list_variable_monitors,
         commands::get_transaction_status,
+        commands::list_display_messages,
+        commands::set_display_message,
+        commands::clear_display_message,
+        commands::sync_display_messages,
+        // Customer Information (v2.0.1)
+        commands::request_customer_information,
+        commands::get_customer_information,
         // Transactions
         transactions::list_all_transactions,
         transactions::list_transactions_for_charge_point,
         transactions::get_transaction,
+        transactions::get_transaction_charging_needs,
         transactions::get_active_transactions,
         transactions::get_transaction_stats,
         transactions::force_stop_transaction,
@@ -225,12 +247,37 @@ impl Modify for SecurityAddon {
         reservations::cancel_reservation,
         reservations::list_reservations,
         reservations::get_reservation,
+        reservations::get_availability,
+        // Customer data (GDPR)
+        customer_data::export_id_tag_data,
+        customer_data::erase_id_tag_data,
+        customer_data::export_user_data,
+        customer_data::erase_user_data,
+        // Retention (admin)
+        retention::list_retention_policies,
+        retention::start_archive_run,
+        retention::list_archive_runs,
+        retention::get_archive_run,
+        // Configuration (admin)
+        configuration::reload_configuration,
+        // Bulk import / export (admin)
+        bulk::import_entities,
+        bulk::export_entities,
+        // OCPI partners
+        ocpi_parties::list_ocpi_parties,
+        ocpi_parties::get_ocpi_party,
+        ocpi_parties::create_ocpi_party,
+        ocpi_parties::delete_ocpi_party,
         // Analytics
         analytics::analytics_summary,
         analytics::analytics_revenue,
         analytics::analytics_energy,
         analytics::analytics_peak_hours,
         analytics::analytics_station_uptime,
+        analytics::analytics_availability,
+        analytics::analytics_faults,
+        analytics::analytics_connector_states,
+        analytics::analytics_connector_status_history,
     ),
     components(
         schemas(
@@ -270,6 +317,7 @@ impl Modify for SecurityAddon {
             // Transactions
             transactions::TransactionDto,
             transactions::TransactionStats,
+            crate::domain::EvChargingNeeds,
             // Monitoring
             monitoring::HeartbeatStatusDto,
             monitoring::ConnectionStatsDto,
@@ -311,6 +359,8 @@ impl Modify for SecurityAddon {
             commands::GetDiagnosticsResponse,
             commands::GetBaseReportRequest,
             commands::GetBaseReportResponse,
+            commands::ComponentVariableDto,
+            commands::GetReportHttpRequest,
             // Variable Monitoring
             commands::SetVariableMonitoringRequest,
             commands::MonitorDescriptorDto,
@@ -321,9 +371,31 @@ impl Modify for SecurityAddon {
             commands::ClearVariableMonitoringRequest,
             commands::ClearMonitoringResultDto,
             commands::ClearVariableMonitoringResponse,
+            commands::SetMonitoringLevelRequest,
+            commands::SetMonitoringLevelResponse,
+            commands::GetMonitoringReportHttpRequest,
+            commands::GetMonitoringReportHttpResponse,
+            commands::VariableMonitorListResponse,
+            crate::domain::VariableMonitor,
             // Transaction Status
             commands::GetTransactionStatusRequest,
             commands::GetTransactionStatusResponse,
+            commands::SetDisplayMessageRequest,
+            commands::SetDisplayMessageResponse,
+            commands::SyncDisplayMessagesResponse,
+            commands::DataTransferListResponse,
+            crate::domain::DataTransferRecord,
+            crate::domain::DataTransferDirection,
+            commands::DisplayMessageListResponse,
+            crate::domain::DisplayMessage,
+            crate::domain::DisplayMessageStatus,
+            // Customer Information (v2.0.1)
+            commands::CustomerInformationCommandRequest,
+            commands::CustomerCertificateDto,
+            commands::CustomerInformationCommandResponse,
+            commands::CustomerInformationReportResponse,
+            crate::application::charging::services::customer_information::CustomerInformationReport,
+            crate::application::charging::services::customer_information::CustomerDataPart,
             // Device Report types
             crate::application::charging::services::device_report::DeviceReport,
             crate::application::charging::services::device_report::ReportVariable,
@@ -338,11 +410,41 @@ impl Modify for SecurityAddon {
             analytics::PeakHourEntry,
             analytics::StationUptimeResponse,
             analytics::StationUptimeEntry,
+            analytics::AvailabilityReport,
+            analytics::StationAvailability,
+            analytics::ConnectorUptime,
+            analytics::UptimeStats,
+            analytics::FaultReport,
+            analytics::FaultGroup,
+            analytics::ConnectorStatesReport,
+            analytics::ConnectorStateTime,
+            analytics::ConnectorStatusEntry,
+            // OCPI partners
+            ocpi_parties::OcpiPartyResponse,
+            ocpi_parties::CreateOcpiPartyRequest,
             // Reservations
             reservations::CreateReservationRequest,
             reservations::CreateReservationResponse,
             reservations::CancelReservationResponse,
             reservations::ReservationDto,
+            reservations::ConnectorAvailabilityDto,
+            // Customer data (GDPR)
+            customer_data::PersonalDataExport,
+            customer_data::TransactionDataDto,
+            customer_data::BillingDataDto,
+            customer_data::AuthEventDto,
+            customer_data::ErasureResponse,
+            customer_data::ErasedIdTagDto,
+            customer_data::StationClearResult,
+            // Retention
+            retention::RetentionPolicyDto,
+            retention::ArchiveRunDto,
+            retention::ArchivedClassDto,
+            // Configuration
+            configuration::ConfigReloadDto,
+            // Bulk import / export
+            bulk::ImportReportDto,
+            bulk::RowErrorDto,
         )
     ),
     modifiers(&SecurityAddon),
@@ -359,7 +461,12 @@ impl Modify for SecurityAddon {
         (name = "Commands", description = "OCPP 1.6 remote commands to charge points via WebSocket"),
         (name = "Transactions", description = "Charging session (transaction) management"),
         (name = "Reservations", description = "Connector/EVSE reservation management (ReserveNow / CancelReservation)"),
-        (name = "Analytics", description = "Dashboard analytics: summary, revenue, energy, peak hours, station uptime"),
+        (name = "CustomerData", description = "GDPR: export and erase driver personal data by id tag or user"),
+        (name = "Retention", description = "Data retention policies and archival runs (admin)"),
+        (name = "Configuration", description = "Configuration reload without a restart (admin)"),
+        (name = "Bulk", description = "CSV / XLSX import and export of id tags, charge points, tariffs and transactions (admin)"),
+        (name = "OCPI", description = "OCPI roaming partner registration (token A issuance)"),
+        (name = "Analytics", description = "Dashboard analytics: summary, revenue, energy, peak hours, station uptime, availability, faults, connector states"),
         (name = "WebSocket Notifications", description = "Real-time event notifications via WebSocket"),
     ),
     info(
@@ -383,10 +490,15 @@ pub fn create_api_router(
     event_bus: SharedEventBus,
     charge_point_service: Arc<ChargePointService>,
     billing_service: Arc<BillingService>,
-    app_cfg: &AppConfig,
+    config_reloader: Arc<ConfigReloader>,
     prometheus_handle: PrometheusHandle,
     report_store: SharedDeviceReportStore,
+    customer_info_store: SharedCustomerInformationStore,
+    archive_service: Arc<ArchiveService>,
 ) -> Router {
+    let app_cfg = config_reloader.current();
+    let live_config = config_reloader.subscribe();
+
     let middleware_state = AuthState {
         jwt_config: jwt_config.clone(),
         db: db.clone(),
@@ -402,6 +514,7 @@ pub fn create_api_router(
         charge_point_service,
         billing_service: billing_service.clone(),
         report_store,
+        customer_info_store,
     };
 
     // A SINGLE router for every /api/v1/charge-points/* route.
@@ -473,6 +586,10 @@ pub fn create_api_router(
             "/{charge_point_id}/data-transfer",
             post(commands::data_transfer_handler),
         )
+        .route(
+            "/{charge_point_id}/data-transfers",
+            get(commands::list_data_transfers),
+        )
         // --- Smart Charging (v1.6 + v2.0.1) ---
         .route(
             "/{charge_point_id}/charging-profile/clear",
@@ -490,6 +607,10 @@ pub fn create_api_router(
             "/{charge_point_id}/charging-profiles/request",
             post(commands::get_charging_profiles_handler),
         )
+        .route(
+            "/{charge_point_id}/charging-limits",
+            get(commands::list_external_charging_limits),
+        )
         .route(
             "/{charge_point_id}/composite-schedule",
             post(commands::get_composite_schedule),
@@ -530,7 +651,23 @@ pub fn create_api_router(
             "/{charge_point_id}/report",
             post(commands::request_base_report).get(commands::get_device_report),
         )
+        .route(
+            "/{charge_point_id}/report/filtered",
+            post(commands::request_report),
+        )
         // --- Variable Monitoring (v2.0.1) ---
+        .route(
+            "/{charge_point_id}/monitoring",
+            get(commands::list_variable_monitors),
+        )
+        .route(
+            "/{charge_point_id}/monitoring/report",
+            post(commands::request_monitoring_report),
+        )
+        .route(
+            "/{charge_point_id}/monitoring/level",
+            post(commands::set_monitoring_level_handler),
+        )
         .route(
             "/{charge_point_id}/monitoring/set",
             post(commands::set_variable_monitoring_handler),
@@ -548,6 +685,24 @@ pub fn create_api_router(
             "/{charge_point_id}/transaction-status",
             post(commands::get_transaction_status),
         )
+        // --- Display Messages (v2.0.1) ---
+        .route(
+            "/{charge_point_id}/display-messages",
+            get(commands::list_display_messages).post(commands::set_display_message),
+        )
+        .route(
+            "/{charge_point_id}/display-messages/sync",
+            post(commands::sync_display_messages),
+        )
+        .route(
+            "/{charge_point_id}/display-messages/{message_id}",
+            delete(commands::clear_display_message),
+        )
+        // --- Customer Information (v2.0.1) ---
+        .route(
+            "/{charge_point_id}/customer-information",
+            post(commands::request_customer_information).get(commands::get_customer_information),
+        )
         // auth middleware + unified state
         .layer(middleware::from_fn_with_state(
             middleware_state.clone(),
@@ -565,40 +720,26 @@ pub fn create_api_router(
     let api_key_state = api_keys::ApiKeyHandlerState { db: db.clone() };
 
     // CORS configuration
-    let cors = build_cors_layer(&app_cfg.cors);
-
-    // Rate limiting configuration
-    let api_rpm = app_cfg.rate_limit.api_requests_per_minute.max(1);
-    let api_replenish = (60.0 / api_rpm as f64) as u64;
-    let api_governor_conf = GovernorConfigBuilder::default()
-        .per_second(api_replenish.max(1))
-        .burst_size(api_rpm)
-        .use_headers()
-        .finish()
-        .expect("Failed to build API rate limiter config");
+    let cors = build_cors_layer(live_config.clone());
+
+    // Rate limiting configuration, read from the running configuration per request
     info!(
         "🛡️  Rate limit (API): {} req/min per IP",
-        api_rpm
+        app_cfg.rate_limit.api_requests_per_minute
     );
-
-    let login_rpm = app_cfg.rate_limit.login_attempts_per_minute.max(1);
-    let login_replenish = (60.0 / login_rpm as f64) as u64;
-    let login_governor_conf = GovernorConfigBuilder::default()
-        .per_second(login_replenish.max(1))
-        .burst_size(login_rpm)
-        .use_headers()
-        .finish()
-        .expect("Failed to build login rate limiter config");
     info!(
         "🛡️  Rate limit (login): {} req/min per IP",
-        login_rpm
+        app_cfg.rate_limit.login_attempts_per_minute
     );
 
     // Auth routes (public) — stricter rate limit on login
     let auth_routes = Router::new()
         .route("/login", post(auth::login))
         .route("/register", post(auth::register))
-        .layer(GovernorLayer::new(login_governor_conf))
+        .layer(middleware::from_fn_with_state(
+            rate_limit::RateLimitState::login(live_config.clone()),
+            rate_limit::rate_limit_middleware,
+        ))
         .with_state(auth_state.clone());
 
     // Auth routes (protected)
@@ -663,6 +804,79 @@ pub fn create_api_router(
         ))
         .with_state(id_tag_state);
 
+    // Customer data (GDPR) routes (protected)
+    let customer_data_state = customer_data::CustomerDataAppState {
+        service: Arc::new(CustomerDataService::new(
+            repos.clone(),
+            Arc::new(UserRepository::new(db.clone())),
+        )),
+        session_registry: session_registry.clone(),
+        command_dispatcher: command_dispatcher.clone(),
+    };
+    let customer_data_routes = Router::new()
+        .route("/id-tags/{id_tag}", get(customer_data::export_id_tag_data))
+        .route(
+            "/id-tags/{id_tag}/erase",
+            post(customer_data::erase_id_tag_data),
+        )
+        .route("/users/{user_id}", get(customer_data::export_user_data))
+        .route(
+            "/users/{user_id}/erase",
+            post(customer_data::erase_user_data),
+        )
+        .layer(middleware::from_fn_with_state(
+            middleware_state.clone(),
+            auth_middleware,
+        ))
+        .with_state(customer_data_state);
+
+    // Retention routes (protected, admin only)
+    let retention_routes = Router::new()
+        .route("/policies", get(retention::list_retention_policies))
+        .route(
+            "/runs",
+            get(retention::list_archive_runs).post(retention::start_archive_run),
+        )
+        .route("/runs/{id}", get(retention::get_archive_run))
+        .layer(middleware::from_fn_with_state(
+            middleware_state.clone(),
+            auth_middleware,
+        ))
+        .with_state(retention::RetentionAppState {
+            repos: repos.clone(),
+            archive: archive_service,
+        });
+
+    // Configuration routes (protected, admin only)
+    let configuration_routes = Router::new()
+        .route("/reload", post(configuration::reload_configuration))
+        .layer(middleware::from_fn_with_state(
+            middleware_state.clone(),
+            auth_middleware,
+        ))
+        .with_state(configuration::ConfigurationAppState {
+            reloader: config_reloader,
+        });
+
+    // Bulk import / export routes (protected, admin only)
+    let bulk_routes = Router::new()
+        .route(
+            "/{entity}/import",
+            post(bulk::import_entities)
+                .layer(axum::extract::DefaultBodyLimit::max(bulk::MAX_IMPORT_BYTES)),
+        )
+        .route("/{entity}/export", get(bulk::export_entities))
+        .layer(middleware::from_fn_with_state(
+            middleware_state.clone(),
+            auth_middleware,
+        ))
+        .with_state(bulk::BulkAppState {
+            bulk: Arc::new(BulkService::new(
+                repos.clone(),
+                Arc::new(UserRepository::new(db.clone())),
+            )),
+        });
+
     // Tariff routes (protected)
     let tariff_state = charge_points::AppState {
         repos: repos.clone(),
@@ -688,6 +902,10 @@ pub fn create_api_router(
     let tx_routes = Router::new()
         .route("/", get(transactions::list_all_transactions))
         .route("/{id}", get(transactions::get_transaction))
+        .route(
+            "/{id}/charging-needs",
+            get(transactions::get_transaction_charging_needs),
+        )
         .route(
             "/{transaction_id}/force-stop",
             post(transactions::force_stop_transaction),
@@ -705,6 +923,7 @@ pub fn create_api_router(
     // Reservation routes (protected)
     let reservation_routes = Router::new()
         .route("/", get(reservations::list_reservations).post(reservations::create_reservation))
+        .route("/availability", get(reservations::get_availability))
         .route(
             "/{reservation_id}",
             get(reservations::get_reservation).delete(reservations::cancel_reservation),
@@ -717,6 +936,34 @@ pub fn create_api_router(
             repos: repos.clone(),
             session_registry: session_registry.clone(),
             command_dispatcher: command_dispatcher.clone(),
+            event_bus: event_bus.clone(),
+        });
+
+    // OCPI partner administration (protected)
+    let ocpi_config = Arc::new(app_cfg.ocpi.clone());
+    let ocpi_state = OcpiState {
+        repos: repos.clone(),
+        session_registry: session_registry.clone(),
+        command_dispatcher: command_dispatcher.clone(),
+        config: ocpi_config,
+        client: OcpiClient::new(),
+    };
+    let ocpi_party_routes = Router::new()
+        .route(
+            "/",
+            get(ocpi_parties::list_ocpi_parties).post(ocpi_parties::create_ocpi_party),
+        )
+        .route(
+            "/{id}",
+            get(ocpi_parties::get_ocpi_party).delete(ocpi_parties::delete_ocpi_party),
+        )
+        .layer(middleware::from_fn_with_state(
+            middleware_state.clone(),
+            auth_middleware,
+        ))
+        .with_state(ocpi_parties::OcpiPartyAppState {
+            repos: repos.clone(),
+            versions_url: ocpi_state.versions_url(),
         });
 
     // Monitoring routes (protected)
@@ -735,6 +982,7 @@ pub fn create_api_router(
     let analytics_state = analytics::AnalyticsState {
         db: db.clone(),
         session_registry: session_registry.clone(),
+        repos: repos.clone(),
     };
     let analytics_routes = Router::new()
         .route("/summary", get(analytics::analytics_summary))
@@ -742,6 +990,16 @@ pub fn create_api_router(
         .route("/energy", get(analytics::analytics_energy))
         .route("/peak-hours", get(analytics::analytics_peak_hours))
         .route("/station-uptime", get(analytics::analytics_station_uptime))
+        .route("/availability", get(analytics::analytics_availability))
+        .route("/faults", get(analytics::analytics_faults))
+        .route(
+            "/connector-states",
+            get(analytics::analytics_connector_states),
+        )
+        .route(
+            "/connector-status-history",
+            get(analytics::analytics_connector_status_history),
+        )
         .layer(middleware::from_fn_with_state(
             middleware_state.clone(),
             auth_middleware,
@@ -775,7 +1033,7 @@ pub fn create_api_router(
     let swagger_routes = SwaggerUi::new("/docs").url("/api-doc/openapi.json", ApiDoc::openapi());
 
     // Build router
-    Router::new()
+    let mut router = Router::new()
         // Swagger UI
         .merge(swagger_routes)
         // Health
@@ -791,6 +1049,14 @@ pub fn create_api_router(
         .nest("/api/v1/users", user_routes)
         // IdTags
         .nest("/api/v1/id-tags", id_tag_routes)
+        // Customer data (GDPR)
+        .nest("/api/v1/customer-data", customer_data_routes)
+        // Retention / archival
+        .nest("/api/v1/retention", retention_routes)
+        // Configuration reload
+        .nest("/api/v1/admin/config", configuration_routes)
+        // Bulk import / export
+        .nest("/api/v1/bulk", bulk_routes)
         // Tariffs
         .nest("/api/v1/tariffs", tariff_routes)
         // Charge Points
@@ -803,10 +1069,23 @@ pub fn create_api_router(
         .nest("/api/v1/monitoring", monitoring_routes)
         // Analytics
         .nest("/api/v1/analytics", analytics_routes)
+        // OCPI partners (admin)
+        .nest("/api/v1/ocpi/parties", ocpi_party_routes)
         // Notifications WebSocket
-        .nest("/api/v1/notifications", notification_routes)
-        // Middleware (layers execute bottom-to-top: request_id → metrics → trace → cors → body_limit → governor)
-        .layer(GovernorLayer::new(api_governor_conf))
+        .nest("/api/v1/notifications", notification_routes);
+
+    // OCPI 2.2.1 CPO interface (partner token auth)
+    if app_cfg.ocpi.enabled {
+        info!("🌍 OCPI 2.2.1 enabled at {}", ocpi_state.versions_url());
+        router = router.nest("/ocpi", create_ocpi_router(ocpi_state));
+    }
+
+    router
+        // Middleware (layers execute bottom-to-top: request_id → metrics → trace → cors → body_limit → rate limit)
+        .layer(middleware::from_fn_with_state(
+            rate_limit::RateLimitState::api(live_config),
+            rate_limit::rate_limit_middleware,
+        ))
         .layer(axum::extract::DefaultBodyLimit::max(1_048_576)) // 1 MB — prevent DDoS via large payloads
         .layer(cors)
         .layer(TraceLayer::new_for_http())
@@ -814,35 +1093,38 @@ pub fn create_api_router(
         .layer(axum::middleware::from_fn(super::modules::request_id::request_id_middleware))
 }
 
-/// Build the CORS layer from application configuration.
+/// Build the CORS layer from the running configuration.
 ///
 /// - If `allowed_origins` is empty or contains `"*"` → allow any origin (dev mode).
 /// - Otherwise → restrict to the explicit list of origins.
-fn build_cors_layer(cors_cfg: &crate::config::CorsConfig) -> CorsLayer {
-    use tracing::info;
-
-    let is_any = cors_cfg.allowed_origins.is_empty()
-        || cors_cfg
-            .allowed_origins
-            .iter()
-            .any(|o| o.trim() == "*");
-
-    if is_any {
+///
+/// Origins are checked against the current list on every request, so
+/// reloading `[cors]` applies immediately.
+fn build_cors_layer(live_config: LiveConfig) -> CorsLayer {
+    let initial = live_config.borrow().cors.clone();
+    if allows_any_origin(&initial) {
         info!("⚠️  CORS: allowing ANY origin (dev mode). Set [cors].allowed_origins for production.");
-        CorsLayer::new()
-            .allow_origin(Any)
-            .allow_methods(Any)
-            .allow_headers(Any)
     } else {
-        let origins: Vec<axum::http::HeaderValue> = cors_cfg
+        info!("🔒 CORS: allowed origins: {:?}", initial.allowed_origins);
+    }
+
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::predicate(move |origin, _| {
+            origin_allowed(&live_config.borrow().cors, origin)
+        }))
+        .allow_methods(Any)
+        .allow_headers(Any)
+}
+
+fn allows_any_origin(cors_cfg: &CorsConfig) -> bool {
+    cors_cfg.allowed_origins.is_empty()
+        || cors_cfg.allowed_origins.iter().any(|o| o.trim() == "*")
+}
+
+fn origin_allowed(cors_cfg: &CorsConfig, origin: &axum::http::HeaderValue) -> bool {
+    allows_any_origin(cors_cfg)
+        || cors_cfg
             .allowed_origins
             .iter()
-            .filter_map(|o| o.parse().ok())
-            .collect();
-        info!("🔒 CORS: allowed origins: {:?}", cors_cfg.allowed_origins);
-        CorsLayer::new()
-            .allow_origin(AllowOrigin::list(origins))
-            .allow_methods(Any)
-            .allow_headers(Any)
-    }
+            .any(|o| o.trim().as_bytes() == origin.as_bytes())
 }
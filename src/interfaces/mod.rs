@@ -1,3 +1,5 @@
 pub mod grpc;
 pub mod http;
+pub mod mqtt;
+pub mod ocpi;
 pub mod ws;
@@ -0,0 +1,259 @@
+//! Connection to the broker
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use rumqttc::{AsyncClient, Event as MqttEvent, EventLoop, MqttOptions, Packet, QoS};
+use tracing::{debug, info, warn};
+
+use super::command::{CommandReply, CommandRequest};
+use crate::application::events::{EventMessage, SharedEventBus};
+use crate::application::ports::OcppOutboundPort;
+use crate::application::session::SharedSessionRegistry;
+use crate::config::MqttConfig;
+use crate::shared::shutdown::ShutdownSignal;
+
+/// Requests queued for the broker; events beyond this are dropped while
+/// the broker is unreachable rather than holding up the event bus.
+const REQUEST_CAPACITY: usize = 1024;
+
+/// Pause before reconnecting after the connection fails
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Topic segment for events not tied to a charge point
+const SYSTEM_SEGMENT: &str = "system";
+
+/// Bridges the event bus and station commands to an MQTT broker.
+pub struct MqttBridge {
+    config: MqttConfig,
+    commands: Arc<dyn OcppOutboundPort>,
+    session_registry: SharedSessionRegistry,
+}
+
+impl MqttBridge {
+    pub fn new(
+        config: MqttConfig,
+        commands: Arc<dyn OcppOutboundPort>,
+        session_registry: SharedSessionRegistry,
+    ) -> Self {
+        Self {
+            config,
+            commands,
+            session_registry,
+        }
+    }
+
+    /// Connect and run in the background until `shutdown`; the connection
+    /// is re-established whenever it drops.
+    pub fn start(self, event_bus: SharedEventBus, shutdown: ShutdownSignal) {
+        let (client, eventloop) = AsyncClient::new(self.options(), REQUEST_CAPACITY);
+        let qos = qos(self.config.qos);
+        let bridge = Arc::new(self);
+
+        // ── Events → broker ────────────────────────────────────
+        // Local events only: in a cluster every node publishes its own
+        let mut subscriber = event_bus.subscribe();
+        let prefix = bridge.config.topic_prefix.clone();
+        let events_client = client.clone();
+        let events_shutdown = shutdown.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    message = subscriber.recv() => {
+                        let Some(message) = message else { break };
+                        publish_event(&events_client, &prefix, qos, &message);
+                    }
+                    _ = events_shutdown.notified().wait() => {
+                        let _ = events_client.disconnect().await;
+                        break;
+                    }
+                }
+            }
+        });
+
+        // ── Broker → commands ──────────────────────────────────
+        tokio::spawn(async move {
+            bridge.run(client, eventloop, shutdown).await;
+        });
+    }
+
+    fn options(&self) -> MqttOptions {
+        let client_id = if self.config.client_id.is_empty() {
+            format!(
+                "texnouz-ocpp-{}",
+                &uuid::Uuid::new_v4().simple().to_string()[..8]
+            )
+        } else {
+            self.config.client_id.clone()
+        };
+        let mut options = MqttOptions::new(client_id, &self.config.host, self.config.port);
+        options.set_keep_alive(Duration::from_secs(self.config.keep_alive_secs));
+        if !self.config.username.is_empty() {
+            options.set_credentials(&self.config.username, &self.config.password);
+        }
+        options
+    }
+
+    /// Drive the connection: subscribe on every (re)connect and execute
+    /// the commands that arrive.
+    async fn run(
+        self: Arc<Self>,
+        client: AsyncClient,
+        mut eventloop: EventLoop,
+        shutdown: ShutdownSignal,
+    ) {
+        let qos = qos(self.config.qos);
+        let command_filter = format!("{}/+/command", self.config.topic_prefix);
+        loop {
+            tokio::select! {
+                event = eventloop.poll() => match event {
+                    Ok(MqttEvent::Incoming(Packet::ConnAck(_))) => {
+                        info!(
+                            broker = %format!("{}:{}", self.config.host, self.config.port),
+                            "📡 Connected to MQTT broker"
+                        );
+                        if self.config.commands_enabled {
+                            // Not awaited: a full request queue would stall this loop
+                            if let Err(e) = client.try_subscribe(&command_filter, qos) {
+                                warn!(error = %e, "Failed to subscribe to MQTT commands");
+                            }
+                        }
+                    }
+                    Ok(MqttEvent::Incoming(Packet::Publish(publish))) => {
+                        self.handle_publish(&client, &publish.topic, &publish.payload);
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        warn!(error = %e, "MQTT connection failed, retrying in {:?}", RECONNECT_DELAY);
+                        tokio::select! {
+                            _ = tokio::time::sleep(RECONNECT_DELAY) => {}
+                            _ = shutdown.notified().wait() => break,
+                        }
+                    }
+                },
+                _ = shutdown.notified().wait() => break,
+            }
+        }
+        info!("📡 MQTT bridge shutting down");
+    }
+
+    fn handle_publish(self: &Arc<Self>, client: &AsyncClient, topic: &str, payload: &[u8]) {
+        let Some(charge_point_id) = command_target(&self.config.topic_prefix, topic) else {
+            return;
+        };
+        // The node holding the station's connection answers
+        if self
+            .session_registry
+            .remote_owner(charge_point_id)
+            .is_some()
+        {
+            return;
+        }
+
+        let charge_point_id = charge_point_id.to_string();
+        let response_topic = format!("{}/response", topic);
+        let payload = payload.to_vec();
+        let bridge = self.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let reply = match CommandRequest::parse(&payload) {
+                Ok(request) => {
+                    let action = request.command.action();
+                    info!(
+                        charge_point_id = charge_point_id.as_str(),
+                        action, "MQTT command"
+                    );
+                    let result = request
+                        .command
+                        .execute(bridge.commands.as_ref(), &charge_point_id)
+                        .await;
+                    CommandReply::new(request.id, action, result)
+                }
+                Err((id, message)) => {
+                    debug!(charge_point_id = charge_point_id.as_str(), "{}", message);
+                    CommandReply::invalid(id, message)
+                }
+            };
+            let payload = serde_json::to_vec(&reply).unwrap_or_default();
+            if let Err(e) = client
+                .publish(response_topic, qos(bridge.config.qos), false, payload)
+                .await
+            {
+                warn!(error = %e, "Failed to publish MQTT command reply");
+            }
+        });
+    }
+}
+
+fn publish_event(client: &AsyncClient, prefix: &str, qos: QoS, message: &EventMessage) {
+    let payload = match serde_json::to_vec(message) {
+        Ok(payload) => payload,
+        Err(e) => {
+            warn!(error = %e, "Failed to serialize event for MQTT");
+            return;
+        }
+    };
+    if let Err(e) = client.try_publish(event_topic(prefix, message), qos, false, payload) {
+        debug!(error = %e, "MQTT event dropped");
+    }
+}
+
+/// `{prefix}/{charge_point_id}/{event_type}`
+fn event_topic(prefix: &str, message: &EventMessage) -> String {
+    format!(
+        "{}/{}/{}",
+        prefix,
+        message.event.charge_point_id().unwrap_or(SYSTEM_SEGMENT),
+        message.event.event_type()
+    )
+}
+
+/// The charge point a `{prefix}/{charge_point_id}/command` topic addresses.
+fn command_target<'a>(prefix: &str, topic: &'a str) -> Option<&'a str> {
+    topic
+        .strip_prefix(prefix)?
+        .strip_prefix('/')?
+        .strip_suffix("/command")
+        .filter(|id| !id.is_empty() && !id.contains('/'))
+}
+
+fn qos(level: u8) -> QoS {
+    match level {
+        0 => QoS::AtMostOnce,
+        2 => QoS::ExactlyOnce,
+        _ => QoS::AtLeastOnce,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::events::{ChargePointConnectedEvent, Event};
+    use chrono::Utc;
+
+    #[test]
+    fn events_are_published_per_charge_point_and_type() {
+        let message = EventMessage::new(Event::ChargePointConnected(ChargePointConnectedEvent {
+            charge_point_id: "CP-1".into(),
+            ocpp_version: "1.6".into(),
+            remote_addr: None,
+            timestamp: Utc::now(),
+        }));
+        assert_eq!(
+            event_topic("ocpp", &message),
+            "ocpp/CP-1/charge_point_connected"
+        );
+    }
+
+    #[test]
+    fn command_topics_name_the_charge_point() {
+        assert_eq!(command_target("ocpp", "ocpp/CP-1/command"), Some("CP-1"));
+        assert_eq!(
+            command_target("site/ocpp", "site/ocpp/CP-1/command"),
+            Some("CP-1")
+        );
+        assert_eq!(command_target("ocpp", "ocpp/CP-1/command/response"), None);
+        assert_eq!(command_target("ocpp", "other/CP-1/command"), None);
+        assert_eq!(command_target("ocpp", "ocpp//command"), None);
+    }
+}
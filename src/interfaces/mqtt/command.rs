@@ -0,0 +1,394 @@
+//! Station commands received over MQTT
+//!
+//! A command names its OCPP action and carries the action's fields, in
+//! OCPP's camelCase, under `params`:
+//!
+//! ```json
+//! {"id": "42", "action": "RemoteStartTransaction", "params": {"idTag": "04A1B2C3", "connectorId": 1}}
+//! ```
+//!
+//! `id` is optional and echoed in the reply for correlation.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::application::charging::commands::dispatcher::ClearChargingProfileCriteria;
+use crate::application::ports::OcppOutboundPort;
+use crate::application::{Availability, CommandError, ResetKind, TriggerType};
+
+#[derive(Debug, Deserialize)]
+pub struct CommandRequest {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(flatten)]
+    pub command: StationCommand,
+}
+
+impl CommandRequest {
+    /// Parse a command payload. On failure, the error carries the `id`
+    /// if the payload had a readable one, so the reply can still echo it.
+    pub fn parse(payload: &[u8]) -> Result<Self, (Option<String>, String)> {
+        let value: Value =
+            serde_json::from_slice(payload).map_err(|e| (None, format!("Invalid JSON: {}", e)))?;
+        let id = value.get("id").and_then(Value::as_str).map(String::from);
+        serde_json::from_value(value).map_err(|e| (id, format!("Invalid command: {}", e)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum ResetType {
+    Soft,
+    Hard,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum AvailabilityType {
+    Operative,
+    Inoperative,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum MessageTrigger {
+    BootNotification,
+    DiagnosticsStatusNotification,
+    FirmwareStatusNotification,
+    Heartbeat,
+    MeterValues,
+    StatusNotification,
+}
+
+impl From<MessageTrigger> for TriggerType {
+    fn from(trigger: MessageTrigger) -> Self {
+        match trigger {
+            MessageTrigger::BootNotification => Self::BootNotification,
+            MessageTrigger::DiagnosticsStatusNotification => Self::DiagnosticsStatusNotification,
+            MessageTrigger::FirmwareStatusNotification => Self::FirmwareStatusNotification,
+            MessageTrigger::Heartbeat => Self::Heartbeat,
+            MessageTrigger::MeterValues => Self::MeterValues,
+            MessageTrigger::StatusNotification => Self::StatusNotification,
+        }
+    }
+}
+
+/// The commands the bridge accepts, each mapped to an [`OcppOutboundPort`] method.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "action", content = "params", rename_all_fields = "camelCase")]
+pub enum StationCommand {
+    RemoteStartTransaction {
+        id_tag: String,
+        connector_id: Option<u32>,
+    },
+    RemoteStopTransaction {
+        transaction_id: i32,
+    },
+    Reset {
+        #[serde(rename = "type")]
+        kind: ResetType,
+    },
+    UnlockConnector {
+        connector_id: u32,
+    },
+    ChangeAvailability {
+        connector_id: u32,
+        #[serde(rename = "type")]
+        availability: AvailabilityType,
+    },
+    ClearCache,
+    TriggerMessage {
+        requested_message: MessageTrigger,
+        connector_id: Option<u32>,
+    },
+    /// v1.6 only
+    GetConfiguration {
+        key: Option<Vec<String>>,
+    },
+    /// v1.6 only
+    ChangeConfiguration {
+        key: String,
+        value: String,
+    },
+    SetChargingProfile {
+        /// EVSE (v2.0.1) or connector (v1.6); 0 is the whole station
+        #[serde(default, alias = "connectorId")]
+        evse_id: i32,
+        #[serde(alias = "csChargingProfiles")]
+        charging_profile: Value,
+    },
+    ClearChargingProfile {
+        id: Option<i32>,
+        #[serde(alias = "connectorId")]
+        evse_id: Option<i32>,
+        charging_profile_purpose: Option<String>,
+        stack_level: Option<i32>,
+    },
+    DataTransfer {
+        vendor_id: String,
+        message_id: Option<String>,
+        data: Option<String>,
+    },
+}
+
+impl StationCommand {
+    pub fn action(&self) -> &'static str {
+        match self {
+            Self::RemoteStartTransaction { .. } => "RemoteStartTransaction",
+            Self::RemoteStopTransaction { .. } => "RemoteStopTransaction",
+            Self::Reset { .. } => "Reset",
+            Self::UnlockConnector { .. } => "UnlockConnector",
+            Self::ChangeAvailability { .. } => "ChangeAvailability",
+            Self::ClearCache => "ClearCache",
+            Self::TriggerMessage { .. } => "TriggerMessage",
+            Self::GetConfiguration { .. } => "GetConfiguration",
+            Self::ChangeConfiguration { .. } => "ChangeConfiguration",
+            Self::SetChargingProfile { .. } => "SetChargingProfile",
+            Self::ClearChargingProfile { .. } => "ClearChargingProfile",
+            Self::DataTransfer { .. } => "DataTransfer",
+        }
+    }
+
+    /// Send the command and wait for the station's answer, as JSON.
+    ///
+    /// Most commands answer `{"status": ...}`; GetConfiguration and
+    /// DataTransfer add their response fields.
+    pub async fn execute(
+        self,
+        commands: &dyn OcppOutboundPort,
+        charge_point_id: &str,
+    ) -> Result<Value, CommandError> {
+        let status = match self {
+            Self::RemoteStartTransaction {
+                id_tag,
+                connector_id,
+            } => {
+                commands
+                    .remote_start_transaction(charge_point_id, &id_tag, connector_id)
+                    .await?
+            }
+            Self::RemoteStopTransaction { transaction_id } => {
+                commands
+                    .remote_stop_transaction(charge_point_id, transaction_id)
+                    .await?
+            }
+            Self::Reset { kind } => {
+                let kind = match kind {
+                    ResetType::Soft => ResetKind::Soft,
+                    ResetType::Hard => ResetKind::Hard,
+                };
+                commands.reset(charge_point_id, kind).await?
+            }
+            Self::UnlockConnector { connector_id } => {
+                commands
+                    .unlock_connector(charge_point_id, connector_id)
+                    .await?
+            }
+            Self::ChangeAvailability {
+                connector_id,
+                availability,
+            } => {
+                let availability = match availability {
+                    AvailabilityType::Operative => Availability::Operative,
+                    AvailabilityType::Inoperative => Availability::Inoperative,
+                };
+                commands
+                    .change_availability(charge_point_id, connector_id, availability)
+                    .await?
+            }
+            Self::ClearCache => commands.clear_cache(charge_point_id).await?,
+            Self::TriggerMessage {
+                requested_message,
+                connector_id,
+            } => {
+                commands
+                    .trigger_message(charge_point_id, requested_message.into(), connector_id)
+                    .await?
+            }
+            Self::GetConfiguration { key } => {
+                let result = commands.get_configuration(charge_point_id, key).await?;
+                let keys: Vec<Value> = result
+                    .configuration_key
+                    .into_iter()
+                    .map(|kv| json!({ "key": kv.key, "readonly": kv.readonly, "value": kv.value }))
+                    .collect();
+                return Ok(json!({
+                    "configurationKey": keys,
+                    "unknownKey": result.unknown_key,
+                }));
+            }
+            Self::ChangeConfiguration { key, value } => {
+                commands
+                    .change_configuration(charge_point_id, key, value)
+                    .await?
+            }
+            Self::SetChargingProfile {
+                evse_id,
+                charging_profile,
+            } => {
+                commands
+                    .set_charging_profile(charge_point_id, evse_id, charging_profile)
+                    .await?
+            }
+            Self::ClearChargingProfile {
+                id,
+                evse_id,
+                charging_profile_purpose,
+                stack_level,
+            } => {
+                let criteria = ClearChargingProfileCriteria {
+                    charging_profile_id: id,
+                    evse_id,
+                    charging_profile_purpose,
+                    stack_level,
+                };
+                commands
+                    .clear_charging_profile(charge_point_id, criteria)
+                    .await?
+            }
+            Self::DataTransfer {
+                vendor_id,
+                message_id,
+                data,
+            } => {
+                let result = commands
+                    .data_transfer(charge_point_id, vendor_id, message_id, data)
+                    .await?;
+                return Ok(json!({ "status": result.status, "data": result.data }));
+            }
+        };
+        Ok(json!({ "status": status }))
+    }
+}
+
+/// Published to the response topic for every command received.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CommandReply {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// The station accepted the command
+    pub accepted: bool,
+    /// The station's answer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ReplyError>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReplyError {
+    /// `InvalidCommand`, or the [`CommandError`] variant
+    pub code: String,
+    pub message: String,
+}
+
+impl CommandReply {
+    pub fn new(id: Option<String>, action: &str, result: Result<Value, CommandError>) -> Self {
+        match result {
+            Ok(result) => {
+                let status = result["status"].as_str().unwrap_or_default();
+                Self {
+                    id,
+                    action: Some(action.to_string()),
+                    accepted: status == "Accepted" || status == "Unlocked",
+                    result: Some(result),
+                    error: None,
+                }
+            }
+            Err(e) => Self {
+                id,
+                action: Some(action.to_string()),
+                accepted: false,
+                result: None,
+                error: Some(ReplyError {
+                    code: error_code(&e).to_string(),
+                    message: e.to_string(),
+                }),
+            },
+        }
+    }
+
+    /// Reply to a payload that is not a valid command.
+    pub fn invalid(id: Option<String>, message: String) -> Self {
+        Self {
+            id,
+            action: None,
+            accepted: false,
+            result: None,
+            error: Some(ReplyError {
+                code: "InvalidCommand".to_string(),
+                message,
+            }),
+        }
+    }
+}
+
+fn error_code(error: &CommandError) -> &'static str {
+    match error {
+        CommandError::NotConnected(_) => "NotConnected",
+        CommandError::SendFailed(_) => "SendFailed",
+        CommandError::Timeout => "Timeout",
+        CommandError::InvalidResponse(_) => "InvalidResponse",
+        CommandError::CallError { .. } => "CallError",
+        CommandError::UnsupportedVersion(_) => "UnsupportedVersion",
+        CommandError::InvalidPayload(_) => "InvalidPayload",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_commands_with_ocpp_field_names() {
+        let request = CommandRequest::parse(
+            br#"{"id":"42","action":"RemoteStartTransaction","params":{"idTag":"TAG-1","connectorId":2}}"#,
+        )
+        .unwrap();
+        assert_eq!(request.id.as_deref(), Some("42"));
+        assert!(matches!(
+            request.command,
+            StationCommand::RemoteStartTransaction { ref id_tag, connector_id: Some(2) } if id_tag == "TAG-1"
+        ));
+
+        let request = CommandRequest::parse(br#"{"action":"ClearCache"}"#).unwrap();
+        assert!(request.id.is_none());
+        assert_eq!(request.command.action(), "ClearCache");
+
+        let request = CommandRequest::parse(
+            br#"{"action":"SetChargingProfile","params":{"connectorId":1,"csChargingProfiles":{"chargingProfileId":7}}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            request.command,
+            StationCommand::SetChargingProfile { evse_id: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn invalid_commands_keep_their_id() {
+        let (id, message) =
+            CommandRequest::parse(br#"{"id":"7","action":"Reset","params":{"type":"Warm"}}"#)
+                .unwrap_err();
+        assert_eq!(id.as_deref(), Some("7"));
+        assert!(message.starts_with("Invalid command"));
+
+        let (id, _) = CommandRequest::parse(b"not json").unwrap_err();
+        assert!(id.is_none());
+    }
+
+    #[test]
+    fn reply_reports_acceptance_and_errors() {
+        let reply = CommandReply::new(
+            Some("1".into()),
+            "UnlockConnector",
+            Ok(json!({ "status": "Unlocked" })),
+        );
+        assert!(reply.accepted);
+
+        let reply = CommandReply::new(None, "Reset", Err(CommandError::Timeout));
+        let json = serde_json::to_value(&reply).unwrap();
+        assert_eq!(json["accepted"], false);
+        assert_eq!(json["error"]["code"], "Timeout");
+        assert!(json.get("id").is_none());
+    }
+}
@@ -0,0 +1,18 @@
+//! MQTT bridge for energy-management and SCADA systems
+//!
+//! | Topic | Direction | Payload |
+//! |-------|-----------|---------|
+//! | `{prefix}/{charge_point_id}/{event_type}` | published | the event, as on the notification WebSocket |
+//! | `{prefix}/system/{event_type}` | published | events not tied to a charge point |
+//! | `{prefix}/{charge_point_id}/command` | subscribed | a [`CommandRequest`] |
+//! | `{prefix}/{charge_point_id}/command/response` | published | a [`CommandReply`] |
+//!
+//! Like the gRPC commands, MQTT commands go straight to
+//! [`OcppOutboundPort`](crate::application::ports::OcppOutboundPort) and
+//! do not update stored state themselves.
+
+pub mod bridge;
+pub mod command;
+
+pub use bridge::MqttBridge;
+pub use command::{CommandReply, CommandRequest, StationCommand};
@@ -0,0 +1,163 @@
+//! Outbound OCPI client — calls to a partner's OCPI API using token B
+
+use std::time::Duration;
+
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::debug;
+
+use super::common::{authorization_header, status_code, OcpiResponse};
+use super::modules::versions::{Version, VersionDetails};
+
+/// Errors from calls to a partner's OCPI API.
+#[derive(Debug, thiserror::Error)]
+pub enum OcpiClientError {
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("Partner responded with HTTP {0}")]
+    Status(u16),
+    #[error("Partner responded with OCPI status {code}: {message}")]
+    Ocpi { code: u32, message: String },
+    #[error("Partner response has no data")]
+    MissingData,
+}
+
+impl OcpiClientError {
+    /// Transport failures and server-side errors are worth retrying;
+    /// rejections of the request itself are not.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Http(_) => true,
+            Self::Status(code) => *code >= 500 || *code == 429,
+            Self::Ocpi { code, .. } => (3000..4000).contains(code),
+            Self::MissingData => false,
+        }
+    }
+}
+
+/// Thin reqwest wrapper that speaks the OCPI envelope.
+#[derive(Clone)]
+pub struct OcpiClient {
+    http: reqwest::Client,
+}
+
+impl Default for OcpiClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OcpiClient {
+    pub fn new() -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .unwrap_or_default();
+        Self { http }
+    }
+
+    /// GET an OCPI resource and unwrap its `data`.
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        token: &str,
+    ) -> Result<T, OcpiClientError> {
+        debug!(url, "OCPI GET");
+        let response = self
+            .http
+            .get(url)
+            .header("Authorization", authorization_header(token))
+            .header("X-Request-ID", uuid::Uuid::new_v4().to_string())
+            .header("X-Correlation-ID", uuid::Uuid::new_v4().to_string())
+            .send()
+            .await?;
+        Self::unwrap_envelope::<T>(response)
+            .await?
+            .ok_or(OcpiClientError::MissingData)
+    }
+
+    /// POST a JSON body, ignoring any `data` in the response.
+    pub async fn post<B: Serialize + ?Sized>(
+        &self,
+        url: &str,
+        token: &str,
+        body: &B,
+    ) -> Result<(), OcpiClientError> {
+        self.send(Method::POST, url, token, body).await
+    }
+
+    /// PUT a full object into a partner's receiver interface.
+    pub async fn put<B: Serialize + ?Sized>(
+        &self,
+        url: &str,
+        token: &str,
+        body: &B,
+    ) -> Result<(), OcpiClientError> {
+        self.send(Method::PUT, url, token, body).await
+    }
+
+    /// PATCH selected fields of an object in a partner's receiver interface.
+    pub async fn patch<B: Serialize + ?Sized>(
+        &self,
+        url: &str,
+        token: &str,
+        body: &B,
+    ) -> Result<(), OcpiClientError> {
+        self.send(Method::PATCH, url, token, body).await
+    }
+
+    async fn send<B: Serialize + ?Sized>(
+        &self,
+        method: Method,
+        url: &str,
+        token: &str,
+        body: &B,
+    ) -> Result<(), OcpiClientError> {
+        debug!(url, %method, "OCPI request");
+        let response = self
+            .http
+            .request(method, url)
+            .header("Authorization", authorization_header(token))
+            .header("X-Request-ID", uuid::Uuid::new_v4().to_string())
+            .header("X-Correlation-ID", uuid::Uuid::new_v4().to_string())
+            .json(body)
+            .send()
+            .await?;
+        Self::unwrap_envelope::<serde_json::Value>(response).await?;
+        Ok(())
+    }
+
+    pub async fn get_versions(
+        &self,
+        url: &str,
+        token: &str,
+    ) -> Result<Vec<Version>, OcpiClientError> {
+        self.get(url, token).await
+    }
+
+    pub async fn get_version_details(
+        &self,
+        url: &str,
+        token: &str,
+    ) -> Result<VersionDetails, OcpiClientError> {
+        self.get(url, token).await
+    }
+
+    async fn unwrap_envelope<T: DeserializeOwned>(
+        response: reqwest::Response,
+    ) -> Result<Option<T>, OcpiClientError> {
+        let http_status = response.status();
+        if !http_status.is_success() {
+            return Err(OcpiClientError::Status(http_status.as_u16()));
+        }
+        let envelope: OcpiResponse<T> = response.json().await?;
+        if envelope.status_code != status_code::SUCCESS {
+            return Err(OcpiClientError::Ocpi {
+                code: envelope.status_code,
+                message: envelope.status_message.unwrap_or_default(),
+            });
+        }
+        Ok(envelope.data)
+    }
+}
@@ -0,0 +1,269 @@
+//! OCPI response envelope, error codes, pagination and token helpers
+
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::DomainError;
+
+/// The only OCPI version implemented.
+pub const OCPI_VERSION: &str = "2.2.1";
+
+/// Upper bound for `limit` on paginated list endpoints.
+pub const MAX_PAGE_LIMIT: usize = 100;
+
+/// OCPI status codes (section 5 of the spec).
+pub mod status_code {
+    pub const SUCCESS: u32 = 1000;
+    pub const CLIENT_ERROR: u32 = 2000;
+    pub const INVALID_PARAMETERS: u32 = 2001;
+    pub const UNKNOWN_LOCATION: u32 = 2003;
+    pub const UNKNOWN_TOKEN: u32 = 2004;
+    pub const SERVER_ERROR: u32 = 3000;
+    pub const UNABLE_TO_USE_CLIENT_API: u32 = 3001;
+    pub const UNSUPPORTED_VERSION: u32 = 3002;
+    pub const NO_MATCHING_ENDPOINTS: u32 = 3003;
+}
+
+/// Standard OCPI response envelope.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OcpiResponse<T> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<T>,
+    pub status_code: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_message: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl<T> OcpiResponse<T> {
+    pub fn success(data: T) -> Self {
+        Self {
+            data: Some(data),
+            status_code: status_code::SUCCESS,
+            status_message: None,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+impl OcpiResponse<()> {
+    /// Success without payload (e.g. PUT / DELETE acknowledgements).
+    pub fn empty() -> Self {
+        Self {
+            data: None,
+            status_code: status_code::SUCCESS,
+            status_message: None,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+impl<T: Serialize> IntoResponse for OcpiResponse<T> {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
+/// Errors returned by OCPI handlers, rendered as OCPI envelopes.
+#[derive(Debug, thiserror::Error)]
+pub enum OcpiError {
+    #[error("Invalid or missing credentials token")]
+    Unauthorized,
+    #[error("{0}")]
+    InvalidParameters(String),
+    #[error("Unknown location: {0}")]
+    UnknownLocation(String),
+    #[error("Unknown token: {0}")]
+    UnknownToken(String),
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    MethodNotAllowed(String),
+    #[error("Unable to use client API: {0}")]
+    ClientApi(String),
+    #[error("No mutual OCPI version, {0} required")]
+    UnsupportedVersion(String),
+    #[error("No matching endpoints: {0}")]
+    NoMatchingEndpoints(String),
+    #[error("{0}")]
+    Server(String),
+}
+
+impl OcpiError {
+    fn codes(&self) -> (StatusCode, u32) {
+        match self {
+            Self::Unauthorized => (StatusCode::UNAUTHORIZED, status_code::CLIENT_ERROR),
+            Self::InvalidParameters(_) => (StatusCode::BAD_REQUEST, status_code::INVALID_PARAMETERS),
+            Self::UnknownLocation(_) => (StatusCode::NOT_FOUND, status_code::UNKNOWN_LOCATION),
+            Self::UnknownToken(_) => (StatusCode::NOT_FOUND, status_code::UNKNOWN_TOKEN),
+            Self::NotFound(_) => (StatusCode::NOT_FOUND, status_code::CLIENT_ERROR),
+            Self::MethodNotAllowed(_) => (StatusCode::METHOD_NOT_ALLOWED, status_code::CLIENT_ERROR),
+            Self::ClientApi(_) => (StatusCode::OK, status_code::UNABLE_TO_USE_CLIENT_API),
+            Self::UnsupportedVersion(_) => (StatusCode::OK, status_code::UNSUPPORTED_VERSION),
+            Self::NoMatchingEndpoints(_) => (StatusCode::OK, status_code::NO_MATCHING_ENDPOINTS),
+            Self::Server(_) => (StatusCode::INTERNAL_SERVER_ERROR, status_code::SERVER_ERROR),
+        }
+    }
+}
+
+impl IntoResponse for OcpiError {
+    fn into_response(self) -> Response {
+        let (http_status, code) = self.codes();
+        let body = OcpiResponse::<()> {
+            data: None,
+            status_code: code,
+            status_message: Some(self.to_string()),
+            timestamp: Utc::now(),
+        };
+        (http_status, Json(body)).into_response()
+    }
+}
+
+impl From<DomainError> for OcpiError {
+    fn from(e: DomainError) -> Self {
+        Self::Server(e.to_string())
+    }
+}
+
+pub type OcpiResult<T> = Result<T, OcpiError>;
+
+// ── Pagination ─────────────────────────────────────────────────
+
+/// Query parameters shared by all paginated GET list endpoints.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OcpiListParams {
+    pub date_from: Option<DateTime<Utc>>,
+    pub date_to: Option<DateTime<Utc>>,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
+}
+
+impl OcpiListParams {
+    /// `date_from` is inclusive, `date_to` exclusive (per spec).
+    pub fn in_range(&self, last_updated: DateTime<Utc>) -> bool {
+        self.date_from.is_none_or(|from| last_updated >= from)
+            && self.date_to.is_none_or(|to| last_updated < to)
+    }
+}
+
+/// Slice `items` according to offset/limit and attach the OCPI pagination
+/// headers (`X-Total-Count`, `X-Limit` and a `Link` to the next page).
+pub fn paginate<T: Serialize>(items: Vec<T>, params: &OcpiListParams, url: &str) -> Response {
+    let total = items.len();
+    let limit = params.limit.unwrap_or(MAX_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT);
+    let offset = params.offset.unwrap_or(0);
+
+    let page: Vec<T> = items.into_iter().skip(offset).take(limit).collect();
+
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Total-Count", HeaderValue::from(total));
+    headers.insert("X-Limit", HeaderValue::from(limit));
+
+    if offset + limit < total {
+        let mut next = format!("{}?offset={}&limit={}", url, offset + limit, limit);
+        if let Some(from) = params.date_from {
+            next.push_str(&format!(
+                "&date_from={}",
+                from.to_rfc3339_opts(SecondsFormat::Secs, true)
+            ));
+        }
+        if let Some(to) = params.date_to {
+            next.push_str(&format!(
+                "&date_to={}",
+                to.to_rfc3339_opts(SecondsFormat::Secs, true)
+            ));
+        }
+        if let Ok(link) = HeaderValue::from_str(&format!("<{}>; rel=\"next\"", next)) {
+            headers.insert("Link", link);
+        }
+    }
+
+    (headers, OcpiResponse::success(page)).into_response()
+}
+
+// ── Credentials tokens ─────────────────────────────────────────
+
+/// Build an `Authorization` header value for calls to a partner.
+///
+/// OCPI 2.2.1 requires the token to be Base64 encoded.
+pub fn authorization_header(token: &str) -> String {
+    format!("Token {}", BASE64.encode(token))
+}
+
+/// Extract candidate tokens from an `Authorization: Token ...` header.
+///
+/// Returns the Base64-decoded token first, followed by the raw value, because
+/// many 2.1.1-era implementations still send tokens unencoded.
+pub fn token_candidates(header: &str) -> Vec<String> {
+    let Some(raw) = header.strip_prefix("Token ").map(str::trim) else {
+        return Vec::new();
+    };
+    let mut candidates = Vec::with_capacity(2);
+    if let Some(decoded) = BASE64
+        .decode(raw)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+    {
+        candidates.push(decoded);
+    }
+    candidates.push(raw.to_string());
+    candidates
+}
+
+/// Cents (smallest currency unit) → decimal price.
+pub fn cents_to_price(cents: i32) -> f64 {
+    cents as f64 / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_candidates_decodes_base64_and_keeps_raw() {
+        let header = authorization_header("abc-123");
+        let candidates = token_candidates(&header);
+        assert_eq!(candidates[0], "abc-123");
+        assert_eq!(candidates.len(), 2);
+
+        let raw = token_candidates("Token 0b3c3a4e-6a4e-4bd8-a4b2-1c5a4a3f0b2e");
+        assert_eq!(raw, vec!["0b3c3a4e-6a4e-4bd8-a4b2-1c5a4a3f0b2e".to_string()]);
+
+        assert!(token_candidates("Bearer xyz").is_empty());
+    }
+
+    #[test]
+    fn list_params_range_is_half_open() {
+        let from = Utc::now();
+        let to = from + chrono::Duration::hours(1);
+        let params = OcpiListParams {
+            date_from: Some(from),
+            date_to: Some(to),
+            ..Default::default()
+        };
+        assert!(params.in_range(from));
+        assert!(!params.in_range(to));
+        assert!(!params.in_range(from - chrono::Duration::seconds(1)));
+    }
+
+    #[test]
+    fn paginate_sets_headers_and_next_link() {
+        let params = OcpiListParams {
+            limit: Some(2),
+            ..Default::default()
+        };
+        let resp = paginate(vec![1, 2, 3], &params, "http://cpo/ocpi/2.2.1/locations");
+        let headers = resp.headers();
+        assert_eq!(headers["X-Total-Count"], "3");
+        assert_eq!(headers["X-Limit"], "2");
+        assert_eq!(
+            headers["Link"],
+            "<http://cpo/ocpi/2.2.1/locations?offset=2&limit=2>; rel=\"next\""
+        );
+    }
+}
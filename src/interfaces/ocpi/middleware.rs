@@ -0,0 +1,88 @@
+//! OCPI credentials-token authentication
+//!
+//! Partners authenticate with `Authorization: Token <base64(token)>`.
+//! Token A is only accepted by the versions and credentials modules
+//! (to complete the handshake); everything else requires token C.
+
+use axum::extract::{Request, State};
+use axum::http::HeaderValue;
+use axum::middleware::Next;
+use axum::response::Response;
+
+use super::common::{token_candidates, OcpiError};
+use super::router::OcpiState;
+use crate::domain::OcpiParty;
+
+/// The authenticated partner, inserted as a request extension.
+#[derive(Debug, Clone)]
+pub struct OcpiCaller {
+    pub party: OcpiParty,
+    /// `true` when the request was authenticated with the one-time token A.
+    pub via_token_a: bool,
+}
+
+/// Accepts token C only.
+pub async fn ocpi_auth_middleware(
+    State(state): State<OcpiState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, OcpiError> {
+    authenticate(state, request, next, false).await
+}
+
+/// Accepts token A or token C (versions + credentials).
+pub async fn ocpi_registration_auth_middleware(
+    State(state): State<OcpiState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, OcpiError> {
+    authenticate(state, request, next, true).await
+}
+
+async fn authenticate(
+    state: OcpiState,
+    mut request: Request,
+    next: Next,
+    allow_token_a: bool,
+) -> Result<Response, OcpiError> {
+    let header = request
+        .headers()
+        .get("Authorization")
+        .and_then(|h| h.to_str().ok())
+        .ok_or(OcpiError::Unauthorized)?;
+
+    let mut caller = None;
+    for token in token_candidates(header) {
+        if let Some(party) = state.repos.ocpi_parties().find_by_token(&token).await? {
+            let via_token_a = party.token_a.as_deref() == Some(token.as_str());
+            caller = Some(OcpiCaller { party, via_token_a });
+            break;
+        }
+    }
+
+    let caller = caller.ok_or(OcpiError::Unauthorized)?;
+    if caller.via_token_a && !allow_token_a {
+        return Err(OcpiError::Unauthorized);
+    }
+
+    // Echo the OCPI tracing headers back to the caller.
+    let request_id = request.headers().get("X-Request-ID").cloned();
+    let correlation_id = request.headers().get("X-Correlation-ID").cloned();
+
+    request.extensions_mut().insert(caller);
+    let mut response = next.run(request).await;
+
+    let headers = response.headers_mut();
+    headers.insert(
+        "X-Request-ID",
+        request_id.unwrap_or_else(|| {
+            HeaderValue::from_str(&uuid::Uuid::new_v4().to_string())
+                .expect("uuid is a valid header value")
+        }),
+    );
+    if let Some(correlation_id) = correlation_id {
+        headers.insert("X-Correlation-ID", correlation_id);
+    }
+
+    Ok(response)
+}
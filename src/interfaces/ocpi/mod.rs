@@ -0,0 +1,22 @@
+//! OCPI 2.2.1 interface (CPO role)
+//!
+//! Exposes charge points, transactions and tariffs to roaming partners:
+//!
+//! - **versions / credentials**: version discovery and the token A → C handshake
+//! - **locations**: charge points as Locations, connectors as EVSEs
+//! - **sessions / cdrs**: transactions and billed transactions
+//! - **tariffs**: active tariffs as OCPI price components
+//! - **tokens** (receiver): tokens pushed by eMSPs, used for OCPP authorization
+//! - **commands** (receiver): START_SESSION, STOP_SESSION, RESERVE_NOW, UNLOCK_CONNECTOR
+//! - **push**: EVSE status, sessions and CDRs sent to the partners' receiver interfaces
+
+pub mod client;
+pub mod common;
+pub mod middleware;
+pub mod modules;
+pub mod push;
+pub mod router;
+
+pub use client::{OcpiClient, OcpiClientError};
+pub use push::OcpiPushService;
+pub use router::{create_ocpi_router, OcpiState};
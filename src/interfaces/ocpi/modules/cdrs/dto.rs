@@ -0,0 +1,188 @@
+//! CDR DTOs and billed Transaction → CDR mapping
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::config::OcpiConfig;
+use crate::domain::{OcpiToken, Transaction, TransactionBilling};
+use crate::interfaces::ocpi::modules::locations::{evse_id, evse_uid, GeoLocation, CONNECTOR_ID};
+use crate::interfaces::ocpi::modules::sessions::{session_kwh, CdrToken, Price};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CdrLocation {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub address: String,
+    pub city: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    pub country: String,
+    pub coordinates: GeoLocation,
+    pub evse_uid: String,
+    pub evse_id: String,
+    pub connector_id: String,
+    pub connector_standard: String,
+    pub connector_format: String,
+    pub connector_power_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CdrDimension {
+    /// ENERGY (kWh), TIME (hours), ...
+    #[serde(rename = "type")]
+    pub dimension_type: String,
+    pub volume: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChargingPeriod {
+    pub start_date_time: DateTime<Utc>,
+    pub dimensions: Vec<CdrDimension>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tariff_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cdr {
+    pub country_code: String,
+    pub party_id: String,
+    pub id: String,
+    pub start_date_time: DateTime<Utc>,
+    pub end_date_time: DateTime<Utc>,
+    pub session_id: String,
+    pub cdr_token: CdrToken,
+    pub auth_method: String,
+    pub cdr_location: CdrLocation,
+    pub currency: String,
+    pub charging_periods: Vec<ChargingPeriod>,
+    pub total_cost: Price,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_fixed_cost: Option<Price>,
+    pub total_energy: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_energy_cost: Option<Price>,
+    /// Total duration in hours.
+    pub total_time: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_time_cost: Option<Price>,
+    pub last_updated: DateTime<Utc>,
+}
+
+fn non_zero(cents: i32) -> Option<Price> {
+    (cents != 0).then(|| Price::from_cents(cents))
+}
+
+/// Build a CDR for a completed, billed transaction.
+///
+/// Returns `None` while the transaction is still running.
+pub fn cdr_from_transaction(
+    tx: &Transaction,
+    token: &OcpiToken,
+    billing: &TransactionBilling,
+    cfg: &OcpiConfig,
+) -> Option<Cdr> {
+    let end = tx.stopped_at?;
+    let total_energy = session_kwh(tx);
+    let total_time = billing.duration_seconds.max(0) as f64 / 3600.0;
+
+    Some(Cdr {
+        country_code: cfg.country_code.clone(),
+        party_id: cfg.party_id.clone(),
+        id: tx.id.to_string(),
+        start_date_time: tx.started_at,
+        end_date_time: end,
+        session_id: tx.id.to_string(),
+        cdr_token: CdrToken::from(token),
+        auth_method: "WHITELIST".to_string(),
+        cdr_location: CdrLocation {
+            id: tx.charge_point_id.clone(),
+            name: Some(tx.charge_point_id.clone()),
+            address: cfg.address.clone(),
+            city: cfg.city.clone(),
+            postal_code: (!cfg.postal_code.is_empty()).then(|| cfg.postal_code.clone()),
+            country: cfg.country.clone(),
+            coordinates: GeoLocation {
+                latitude: format!("{:.6}", cfg.latitude),
+                longitude: format!("{:.6}", cfg.longitude),
+            },
+            evse_uid: evse_uid(&tx.charge_point_id, tx.connector_id),
+            evse_id: evse_id(cfg, &tx.charge_point_id, tx.connector_id),
+            connector_id: CONNECTOR_ID.to_string(),
+            connector_standard: cfg.connector_standard.clone(),
+            connector_format: cfg.connector_format.clone(),
+            connector_power_type: cfg.power_type.clone(),
+        },
+        currency: billing.currency.clone(),
+        charging_periods: vec![ChargingPeriod {
+            start_date_time: tx.started_at,
+            dimensions: vec![
+                CdrDimension {
+                    dimension_type: "ENERGY".to_string(),
+                    volume: total_energy,
+                },
+                CdrDimension {
+                    dimension_type: "TIME".to_string(),
+                    volume: total_time,
+                },
+            ],
+            tariff_id: billing.tariff_id.map(|id| id.to_string()),
+        }],
+        total_cost: Price::from_cents(billing.total_cost),
+        total_fixed_cost: non_zero(billing.session_fee),
+        total_energy,
+        total_energy_cost: non_zero(billing.energy_cost),
+        total_time,
+        total_time_cost: non_zero(billing.time_cost),
+        last_updated: end,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::BillingStatus;
+
+    #[test]
+    fn cdr_maps_billing_in_major_units() {
+        let mut tx = Transaction::new(7, "CP001", 1, "RFID1", 1000);
+        tx.stop(13_500, None);
+
+        let token = OcpiToken {
+            country_code: "NL".into(),
+            party_id: "ABC".into(),
+            uid: "RFID1".into(),
+            token_type: "RFID".into(),
+            contract_id: "NL-ABC-C12345678-X".into(),
+            visual_number: None,
+            issuer: "ABC".into(),
+            group_id: None,
+            valid: true,
+            whitelist: "ALLOWED".into(),
+            language: None,
+            last_updated: Utc::now(),
+        };
+        let billing = TransactionBilling {
+            transaction_id: 7,
+            tariff_id: Some(1),
+            energy_wh: 12_500,
+            duration_seconds: 1800,
+            energy_cost: 6250,
+            time_cost: 0,
+            session_fee: 100,
+            total_cost: 6350,
+            currency: "EUR".into(),
+            status: BillingStatus::Calculated,
+        };
+
+        let cdr = cdr_from_transaction(&tx, &token, &billing, &OcpiConfig::default()).unwrap();
+        assert_eq!(cdr.id, "7");
+        assert_eq!(cdr.total_energy, 12.5);
+        assert_eq!(cdr.total_time, 0.5);
+        assert_eq!(cdr.total_cost.excl_vat, 63.5);
+        assert_eq!(cdr.total_fixed_cost.unwrap().excl_vat, 1.0);
+        assert!(cdr.total_time_cost.is_none());
+        assert_eq!(cdr.cdr_location.evse_uid, "CP001-1");
+        assert_eq!(cdr.cdr_token.uid, "RFID1");
+    }
+}
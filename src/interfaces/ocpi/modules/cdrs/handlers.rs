@@ -0,0 +1,51 @@
+//! CDRs handlers (SENDER interface)
+
+use axum::extract::{Query, State};
+use axum::response::Response;
+use axum::Extension;
+
+use super::dto::*;
+use crate::domain::{BillingStatus, TransactionStatus};
+use crate::interfaces::ocpi::common::{paginate, OcpiListParams, OcpiResult};
+use crate::interfaces::ocpi::middleware::OcpiCaller;
+use crate::interfaces::ocpi::modules::sessions::caller_tokens;
+use crate::interfaces::ocpi::router::OcpiState;
+
+/// `GET /ocpi/2.2.1/cdrs`
+///
+/// CDRs exist for completed transactions whose billing has been calculated.
+pub async fn list_cdrs(
+    State(state): State<OcpiState>,
+    Extension(caller): Extension<OcpiCaller>,
+    Query(params): Query<OcpiListParams>,
+) -> OcpiResult<Response> {
+    let tokens = caller_tokens(&state, &caller).await?;
+
+    let mut transactions: Vec<_> = state
+        .repos
+        .transactions()
+        .find_all()
+        .await?
+        .into_iter()
+        .filter(|tx| tx.status == TransactionStatus::Completed)
+        .filter(|tx| tokens.contains_key(&tx.id_tag))
+        .collect();
+    transactions.sort_by_key(|tx| tx.id);
+
+    let mut cdrs = Vec::new();
+    for tx in &transactions {
+        let Some(billing) = state.repos.billing().get_billing(tx.id).await? else {
+            continue;
+        };
+        if matches!(billing.status, BillingStatus::Pending | BillingStatus::Failed) {
+            continue;
+        }
+        if let Some(cdr) = cdr_from_transaction(tx, &tokens[&tx.id_tag], &billing, &state.config) {
+            if params.in_range(cdr.last_updated) {
+                cdrs.push(cdr);
+            }
+        }
+    }
+
+    Ok(paginate(cdrs, &params, &state.module_url("cdrs")))
+}
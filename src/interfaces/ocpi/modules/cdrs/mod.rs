@@ -0,0 +1,7 @@
+//! OCPI CDRs module — charge detail records of billed transactions
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
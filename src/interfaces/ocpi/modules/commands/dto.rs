@@ -0,0 +1,123 @@
+//! Commands DTOs and OCPP status → OCPI CommandResult mapping
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::application::charging::commands::CommandError;
+use crate::interfaces::ocpi::modules::tokens::Token;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisplayText {
+    pub language: String,
+    pub text: String,
+}
+
+impl DisplayText {
+    pub fn en(text: impl Into<String>) -> Vec<Self> {
+        vec![Self {
+            language: "en".to_string(),
+            text: text.into(),
+        }]
+    }
+}
+
+/// Synchronous answer to a command request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandResponse {
+    /// ACCEPTED, NOT_SUPPORTED, REJECTED, UNKNOWN_SESSION
+    pub result: String,
+    /// Seconds the eMSP should wait for the asynchronous CommandResult.
+    pub timeout: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<Vec<DisplayText>>,
+}
+
+/// Asynchronous result POSTed to the command's `response_url`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandResult {
+    /// ACCEPTED, CANCELED_RESERVATION, EVSE_OCCUPIED, EVSE_INOPERATIVE,
+    /// FAILED, NOT_SUPPORTED, REJECTED, TIMEOUT, UNKNOWN_RESERVATION
+    pub result: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<Vec<DisplayText>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StartSession {
+    pub response_url: String,
+    pub token: Token,
+    pub location_id: String,
+    #[serde(default)]
+    pub evse_uid: Option<String>,
+    #[serde(default)]
+    pub connector_id: Option<String>,
+    #[serde(default)]
+    pub authorization_reference: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StopSession {
+    pub response_url: String,
+    pub session_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReserveNow {
+    pub response_url: String,
+    pub token: Token,
+    pub expiry_date: DateTime<Utc>,
+    pub reservation_id: String,
+    pub location_id: String,
+    #[serde(default)]
+    pub evse_uid: Option<String>,
+    #[serde(default)]
+    pub authorization_reference: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UnlockConnector {
+    pub response_url: String,
+    pub location_id: String,
+    pub evse_uid: String,
+    pub connector_id: String,
+}
+
+/// OCPP response status (RemoteStart/Stop, ReserveNow, UnlockConnector)
+/// → OCPI CommandResultType.
+pub fn command_result_for_status(status: &str) -> &'static str {
+    match status {
+        "Accepted" | "Unlocked" => "ACCEPTED",
+        "Occupied" | "OngoingAuthorizedTransaction" => "EVSE_OCCUPIED",
+        "Faulted" | "Unavailable" => "EVSE_INOPERATIVE",
+        "NotSupported" => "NOT_SUPPORTED",
+        "UnlockFailed" => "FAILED",
+        _ => "REJECTED",
+    }
+}
+
+pub fn command_result_for_error(error: &CommandError) -> &'static str {
+    match error {
+        CommandError::Timeout => "TIMEOUT",
+        CommandError::UnsupportedVersion(_) => "NOT_SUPPORTED",
+        _ => "FAILED",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ocpp_statuses_map_to_command_results() {
+        assert_eq!(command_result_for_status("Accepted"), "ACCEPTED");
+        assert_eq!(command_result_for_status("Unlocked"), "ACCEPTED");
+        assert_eq!(command_result_for_status("Occupied"), "EVSE_OCCUPIED");
+        assert_eq!(command_result_for_status("Faulted"), "EVSE_INOPERATIVE");
+        assert_eq!(command_result_for_status("Rejected"), "REJECTED");
+        assert_eq!(command_result_for_error(&CommandError::Timeout), "TIMEOUT");
+        assert_eq!(
+            command_result_for_error(&CommandError::NotConnected("CP".into())),
+            "FAILED"
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Commands handlers (RECEIVER interface)
+//!
+//! Each command is validated synchronously and answered with a
+//! `CommandResponse`; the OCPP command is then dispatched in the background
+//! and its outcome POSTed to `response_url` as a `CommandResult`.
+
+use std::future::Future;
+
+use axum::extract::{Path, State};
+use axum::{Extension, Json};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tracing::{info, warn};
+
+use super::dto::*;
+use crate::application::charging::commands::CommandError;
+use crate::domain::{ChargePoint, OcpiParty, OcpiToken, Reservation};
+use crate::interfaces::ocpi::common::{OcpiError, OcpiResponse, OcpiResult};
+use crate::interfaces::ocpi::middleware::OcpiCaller;
+use crate::interfaces::ocpi::modules::locations::{parse_evse_uid, CONNECTOR_ID};
+use crate::interfaces::ocpi::router::OcpiState;
+
+/// Matches the CommandSender response timeout.
+const COMMAND_TIMEOUT_SECS: u32 = 30;
+
+fn response(result: &str, message: Option<&str>) -> CommandResponse {
+    CommandResponse {
+        result: result.to_string(),
+        timeout: COMMAND_TIMEOUT_SECS,
+        message: message.map(DisplayText::en),
+    }
+}
+
+fn to_command_result(outcome: Result<String, CommandError>) -> CommandResult {
+    match outcome {
+        Ok(status) => CommandResult {
+            result: command_result_for_status(&status).to_string(),
+            message: None,
+        },
+        Err(e) => CommandResult {
+            result: command_result_for_error(&e).to_string(),
+            message: Some(DisplayText::en(e.to_string())),
+        },
+    }
+}
+
+fn parse<T: DeserializeOwned>(body: Value) -> OcpiResult<T> {
+    serde_json::from_value(body).map_err(|e| OcpiError::InvalidParameters(e.to_string()))
+}
+
+/// Run `task` in the background and deliver its result to the eMSP.
+fn spawn_result<F>(state: &OcpiState, party: OcpiParty, response_url: String, task: F)
+where
+    F: Future<Output = CommandResult> + Send + 'static,
+{
+    let client = state.client.clone();
+    tokio::spawn(async move {
+        let result = task.await;
+        let Some(token) = party.token_b.as_deref() else {
+            warn!(party_id = party.party_id.as_str(), "No token B, cannot deliver CommandResult");
+            return;
+        };
+        if let Err(e) = client.post(&response_url, token, &result).await {
+            warn!(url = response_url.as_str(), error = %e, "Failed to deliver OCPI CommandResult");
+        }
+    });
+}
+
+async fn find_location(state: &OcpiState, location_id: &str) -> OcpiResult<ChargePoint> {
+    state
+        .repos
+        .charge_points()
+        .find_by_id(location_id)
+        .await?
+        .ok_or_else(|| OcpiError::UnknownLocation(location_id.to_string()))
+}
+
+/// Resolve an EVSE uid of `cp` to its OCPP connector id.
+fn connector_for_evse(cp: &ChargePoint, evse_uid: &str) -> OcpiResult<u32> {
+    parse_evse_uid(evse_uid)
+        .filter(|(cp_id, id)| *cp_id == cp.id && cp.get_connector(*id).is_some())
+        .map(|(_, id)| id)
+        .ok_or_else(|| OcpiError::InvalidParameters(format!("Unknown EVSE: {}", evse_uid)))
+}
+
+/// Store the token carried by a command so the charge point's subsequent
+/// Authorize / StartTransaction for it is accepted.
+async fn store_command_token(
+    state: &OcpiState,
+    caller: &OcpiCaller,
+    token: OcpiToken,
+) -> OcpiResult<()> {
+    if !caller.party.owns(&token.country_code, &token.party_id) {
+        return Err(OcpiError::InvalidParameters(
+            "Token is not owned by the calling party".to_string(),
+        ));
+    }
+    state.repos.ocpi_tokens().upsert(token).await?;
+    Ok(())
+}
+
+/// `POST /ocpi/2.2.1/commands/{command}`
+pub async fn receive_command(
+    State(state): State<OcpiState>,
+    Extension(caller): Extension<OcpiCaller>,
+    Path(command): Path<String>,
+    Json(body): Json<Value>,
+) -> OcpiResult<OcpiResponse<CommandResponse>> {
+    info!(
+        party_id = caller.party.party_id.as_str(),
+        command = command.as_str(),
+        "OCPI command received"
+    );
+
+    let response = match command.as_str() {
+        "START_SESSION" => start_session(&state, &caller, parse(body)?).await?,
+        "STOP_SESSION" => stop_session(&state, &caller, parse(body)?).await?,
+        "RESERVE_NOW" => reserve_now(&state, &caller, parse(body)?).await?,
+        "UNLOCK_CONNECTOR" => unlock_connector(&state, &caller, parse(body)?).await?,
+        _ => response("NOT_SUPPORTED", Some("Command not supported")),
+    };
+
+    Ok(OcpiResponse::success(response))
+}
+
+// ─── START_SESSION ─────────────────────────────────────────────────
+
+async fn start_session(
+    state: &OcpiState,
+    caller: &OcpiCaller,
+    cmd: StartSession,
+) -> OcpiResult<CommandResponse> {
+    let cp = find_location(state, &cmd.location_id).await?;
+    let connector_id = cmd
+        .evse_uid
+        .as_deref()
+        .map(|uid| connector_for_evse(&cp, uid))
+        .transpose()?;
+
+    if !state.session_registry.is_connected(&cp.id) {
+        return Ok(response("REJECTED", Some("Charge point is offline")));
+    }
+
+    let uid = cmd.token.uid.clone();
+    store_command_token(state, caller, cmd.token.into()).await?;
+
+    let dispatcher = state.command_dispatcher.clone();
+    spawn_result(state, caller.party.clone(), cmd.response_url, async move {
+        to_command_result(dispatcher.remote_start(&cp.id, &uid, connector_id).await)
+    });
+
+    Ok(response("ACCEPTED", None))
+}
+
+// ─── STOP_SESSION ──────────────────────────────────────────────────
+
+async fn stop_session(
+    state: &OcpiState,
+    caller: &OcpiCaller,
+    cmd: StopSession,
+) -> OcpiResult<CommandResponse> {
+    let Ok(transaction_id) = cmd.session_id.parse::<i32>() else {
+        return Ok(response("UNKNOWN_SESSION", None));
+    };
+    let Some(tx) = state.repos.transactions().find_by_id(transaction_id).await? else {
+        return Ok(response("UNKNOWN_SESSION", None));
+    };
+
+    let owned = state
+        .repos
+        .ocpi_tokens()
+        .find(&caller.party.country_code, &caller.party.party_id, &tx.id_tag)
+        .await?
+        .is_some();
+    if !owned || !tx.is_active() {
+        return Ok(response("UNKNOWN_SESSION", None));
+    }
+
+    let dispatcher = state.command_dispatcher.clone();
+    spawn_result(state, caller.party.clone(), cmd.response_url, async move {
+        to_command_result(dispatcher.remote_stop(&tx.charge_point_id, tx.id).await)
+    });
+
+    Ok(response("ACCEPTED", None))
+}
+
+// ─── RESERVE_NOW ───────────────────────────────────────────────────
+
+async fn reserve_now(
+    state: &OcpiState,
+    caller: &OcpiCaller,
+    cmd: ReserveNow,
+) -> OcpiResult<CommandResponse> {
+    let cp = find_location(state, &cmd.location_id).await?;
+    let connector_id = cmd
+        .evse_uid
+        .as_deref()
+        .map(|uid| connector_for_evse(&cp, uid))
+        .transpose()?
+        .unwrap_or(0) as i32;
+
+    if cmd.expiry_date <= chrono::Utc::now() {
+        return Err(OcpiError::InvalidParameters(
+            "expiry_date must be in the future".to_string(),
+        ));
+    }
+    if !state.session_registry.is_connected(&cp.id) {
+        return Ok(response("REJECTED", Some("Charge point is offline")));
+    }
+
+    let uid = cmd.token.uid.clone();
+    let group_id = cmd.token.group_id.clone();
+    store_command_token(state, caller, cmd.token.into()).await?;
+
+    let reservation_id = state.repos.reservations().next_id().await;
+    info!(
+        reservation_id,
+        ocpi_reservation_id = cmd.reservation_id.as_str(),
+        "OCPI RESERVE_NOW"
+    );
+
+    let dispatcher = state.command_dispatcher.clone();
+    let repos = state.repos.clone();
+    let expiry_date = cmd.expiry_date;
+    spawn_result(state, caller.party.clone(), cmd.response_url, async move {
+        let outcome = dispatcher
+            .reserve_now(
+                &cp.id,
+                reservation_id,
+                connector_id,
+                &uid,
+                group_id.as_deref(),
+                expiry_date,
+            )
+            .await;
+
+        if matches!(outcome.as_deref(), Ok("Accepted")) {
+            let reservation = Reservation::new(
+                reservation_id,
+                &cp.id,
+                connector_id,
+                &uid,
+                group_id,
+                expiry_date,
+            );
+            if let Err(e) = repos.reservations().save(reservation).await {
+                tracing::error!("Failed to save reservation: {}", e);
+            }
+        }
+        to_command_result(outcome)
+    });
+
+    Ok(response("ACCEPTED", None))
+}
+
+// ─── UNLOCK_CONNECTOR ──────────────────────────────────────────────
+
+async fn unlock_connector(
+    state: &OcpiState,
+    caller: &OcpiCaller,
+    cmd: UnlockConnector,
+) -> OcpiResult<CommandResponse> {
+    let cp = find_location(state, &cmd.location_id).await?;
+    let connector_id = connector_for_evse(&cp, &cmd.evse_uid)?;
+    if cmd.connector_id != CONNECTOR_ID {
+        return Err(OcpiError::InvalidParameters(format!(
+            "Unknown connector: {}",
+            cmd.connector_id
+        )));
+    }
+
+    if !state.session_registry.is_connected(&cp.id) {
+        return Ok(response("REJECTED", Some("Charge point is offline")));
+    }
+
+    let dispatcher = state.command_dispatcher.clone();
+    spawn_result(state, caller.party.clone(), cmd.response_url, async move {
+        to_command_result(dispatcher.unlock_connector(&cp.id, connector_id).await)
+    });
+
+    Ok(response("ACCEPTED", None))
+}
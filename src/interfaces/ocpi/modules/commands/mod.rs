@@ -0,0 +1,7 @@
+//! OCPI commands module (RECEIVER) — remote control by eMSPs via CommandDispatcher
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
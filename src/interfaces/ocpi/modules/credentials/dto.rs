@@ -0,0 +1,31 @@
+//! Credentials DTOs
+
+use serde::{Deserialize, Serialize};
+
+/// Business details of a party.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusinessDetails {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+}
+
+/// One role a platform plays, with its party identifiers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialsRole {
+    /// CPO, EMSP, HUB, NAP, NSP, OTHER, SCSP
+    pub role: String,
+    pub business_details: BusinessDetails,
+    pub party_id: String,
+    pub country_code: String,
+}
+
+/// Credentials object exchanged during registration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Credentials {
+    /// Token the receiver must use to call the sender's API.
+    pub token: String,
+    /// Sender's versions endpoint.
+    pub url: String,
+    pub roles: Vec<CredentialsRole>,
+}
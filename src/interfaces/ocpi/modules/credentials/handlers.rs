@@ -0,0 +1,158 @@
+//! Credentials handlers
+//!
+//! Registration: the partner calls `POST /credentials` with token A and its
+//! own token B + versions URL. We fetch their version details with token B,
+//! store the endpoints, and answer with a newly issued token C.
+
+use axum::extract::State;
+use axum::{Extension, Json};
+use tracing::{info, warn};
+
+use super::dto::*;
+use crate::domain::{OcpiParty, OcpiPartyStatus};
+use crate::interfaces::ocpi::common::{OcpiError, OcpiResponse, OcpiResult, OCPI_VERSION};
+use crate::interfaces::ocpi::middleware::OcpiCaller;
+use crate::interfaces::ocpi::router::OcpiState;
+
+/// Our credentials as presented to a partner holding `token`.
+fn own_credentials(state: &OcpiState, token: String) -> Credentials {
+    Credentials {
+        token,
+        url: state.versions_url(),
+        roles: vec![CredentialsRole {
+            role: "CPO".to_string(),
+            business_details: BusinessDetails {
+                name: state.config.business_name.clone(),
+                website: None,
+            },
+            party_id: state.config.party_id.clone(),
+            country_code: state.config.country_code.clone(),
+        }],
+    }
+}
+
+/// `GET /ocpi/2.2.1/credentials`
+pub async fn get_credentials(
+    State(state): State<OcpiState>,
+    Extension(caller): Extension<OcpiCaller>,
+) -> OcpiResponse<Credentials> {
+    let token = caller.party.token_c.clone().unwrap_or_default();
+    OcpiResponse::success(own_credentials(&state, token))
+}
+
+/// `POST /ocpi/2.2.1/credentials` — initial registration with token A.
+pub async fn post_credentials(
+    State(state): State<OcpiState>,
+    Extension(caller): Extension<OcpiCaller>,
+    Json(body): Json<Credentials>,
+) -> OcpiResult<OcpiResponse<Credentials>> {
+    if !caller.via_token_a {
+        return Err(OcpiError::MethodNotAllowed(
+            "Already registered, use PUT to update credentials".to_string(),
+        ));
+    }
+    register(&state, caller.party, body).await
+}
+
+/// `PUT /ocpi/2.2.1/credentials` — update credentials / rotate token C.
+pub async fn put_credentials(
+    State(state): State<OcpiState>,
+    Extension(caller): Extension<OcpiCaller>,
+    Json(body): Json<Credentials>,
+) -> OcpiResult<OcpiResponse<Credentials>> {
+    if caller.via_token_a || caller.party.status != OcpiPartyStatus::Registered {
+        return Err(OcpiError::MethodNotAllowed(
+            "Not registered, use POST to register".to_string(),
+        ));
+    }
+    register(&state, caller.party, body).await
+}
+
+/// `DELETE /ocpi/2.2.1/credentials` — unregister.
+pub async fn delete_credentials(
+    State(state): State<OcpiState>,
+    Extension(caller): Extension<OcpiCaller>,
+) -> OcpiResult<OcpiResponse<()>> {
+    if caller.via_token_a || caller.party.status != OcpiPartyStatus::Registered {
+        return Err(OcpiError::MethodNotAllowed("Not registered".to_string()));
+    }
+
+    let mut party = caller.party;
+    party.token_b = None;
+    party.token_c = None;
+    party.status = OcpiPartyStatus::Unregistered;
+    state.repos.ocpi_parties().update(party.clone()).await?;
+
+    info!(
+        country_code = party.country_code.as_str(),
+        party_id = party.party_id.as_str(),
+        "OCPI party unregistered"
+    );
+    Ok(OcpiResponse::empty())
+}
+
+/// Shared POST/PUT flow: discover the partner's endpoints with token B,
+/// persist them and issue a new token C.
+async fn register(
+    state: &OcpiState,
+    mut party: OcpiParty,
+    body: Credentials,
+) -> OcpiResult<OcpiResponse<Credentials>> {
+    if body.token.is_empty() || body.url.is_empty() {
+        return Err(OcpiError::InvalidParameters(
+            "token and url are required".to_string(),
+        ));
+    }
+    let role = body.roles.first().ok_or_else(|| {
+        OcpiError::InvalidParameters("at least one role is required".to_string())
+    })?;
+
+    let versions = state
+        .client
+        .get_versions(&body.url, &body.token)
+        .await
+        .map_err(|e| {
+            warn!(url = body.url.as_str(), error = %e, "OCPI versions fetch failed");
+            OcpiError::ClientApi(e.to_string())
+        })?;
+
+    let version = versions
+        .into_iter()
+        .find(|v| v.version == OCPI_VERSION)
+        .ok_or_else(|| OcpiError::UnsupportedVersion(OCPI_VERSION.to_string()))?;
+
+    let details = state
+        .client
+        .get_version_details(&version.url, &body.token)
+        .await
+        .map_err(|e| OcpiError::ClientApi(e.to_string()))?;
+
+    if details.endpoints.is_empty() {
+        return Err(OcpiError::NoMatchingEndpoints(version.url));
+    }
+
+    let token_c = uuid::Uuid::new_v4().to_string();
+
+    party.country_code = role.country_code.to_uppercase();
+    party.party_id = role.party_id.to_uppercase();
+    party.role = role.role.clone();
+    party.name = role.business_details.name.clone();
+    party.token_a = None;
+    party.token_b = Some(body.token);
+    party.token_c = Some(token_c.clone());
+    party.versions_url = Some(body.url);
+    party.version = Some(OCPI_VERSION.to_string());
+    party.endpoints_json = serde_json::to_string(&details.endpoints).ok();
+    party.status = OcpiPartyStatus::Registered;
+
+    state.repos.ocpi_parties().update(party.clone()).await?;
+
+    info!(
+        country_code = party.country_code.as_str(),
+        party_id = party.party_id.as_str(),
+        endpoints = details.endpoints.len(),
+        "OCPI party registered"
+    );
+
+    Ok(OcpiResponse::success(own_credentials(state, token_c)))
+}
@@ -0,0 +1,7 @@
+//! OCPI credentials module — registration handshake (token A → B/C exchange)
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
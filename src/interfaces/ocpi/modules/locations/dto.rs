@@ -0,0 +1,236 @@
+//! Locations DTOs and ChargePoint → Location mapping
+//!
+//! Every charge point is published as one Location (id = charge point id).
+//! Each OCPP connector (id > 0) becomes one EVSE with a single OCPI
+//! connector `"1"`; the EVSE uid is `{charge_point_id}-{connector_id}`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::config::OcpiConfig;
+use crate::domain::{ChargePoint, ChargePointStatus, Connector, ConnectorStatus};
+
+/// OCPI connector id used for the single connector of every EVSE.
+pub const CONNECTOR_ID: &str = "1";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeoLocation {
+    pub latitude: String,
+    pub longitude: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperatorDetails {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocationConnector {
+    pub id: String,
+    pub standard: String,
+    pub format: String,
+    pub power_type: String,
+    pub max_voltage: i32,
+    pub max_amperage: i32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tariff_ids: Vec<String>,
+    pub last_updated: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Evse {
+    pub uid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evse_id: Option<String>,
+    pub status: String,
+    pub capabilities: Vec<String>,
+    pub connectors: Vec<LocationConnector>,
+    pub last_updated: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Location {
+    pub country_code: String,
+    pub party_id: String,
+    pub id: String,
+    pub publish: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub address: String,
+    pub city: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    pub country: String,
+    pub coordinates: GeoLocation,
+    pub evses: Vec<Evse>,
+    pub operator: OperatorDetails,
+    pub time_zone: String,
+    pub last_updated: DateTime<Utc>,
+}
+
+// ── Mapping ────────────────────────────────────────────────────
+
+/// EVSE uid for a charge point connector.
+pub fn evse_uid(charge_point_id: &str, connector_id: u32) -> String {
+    format!("{}-{}", charge_point_id, connector_id)
+}
+
+/// Split an EVSE uid back into (charge_point_id, connector_id).
+pub fn parse_evse_uid(uid: &str) -> Option<(&str, u32)> {
+    let (cp, connector) = uid.rsplit_once('-')?;
+    let connector_id = connector.parse().ok()?;
+    (!cp.is_empty() && connector_id > 0).then_some((cp, connector_id))
+}
+
+/// eMI3 EVSE ID, e.g. `UZ*TXN*ECP001*1`.
+pub fn evse_id(cfg: &OcpiConfig, charge_point_id: &str, connector_id: u32) -> String {
+    let station: String = charge_point_id
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    format!(
+        "{}*{}*E{}*{}",
+        cfg.country_code, cfg.party_id, station, connector_id
+    )
+}
+
+/// OCPP connector status → OCPI EVSE status.
+pub fn evse_status(cp_status: &ChargePointStatus, status: &ConnectorStatus) -> &'static str {
+    if *cp_status != ChargePointStatus::Online {
+        return "UNKNOWN";
+    }
+    match status {
+        ConnectorStatus::Available => "AVAILABLE",
+        ConnectorStatus::Preparing
+        | ConnectorStatus::Charging
+        | ConnectorStatus::SuspendedEV
+        | ConnectorStatus::SuspendedEVSE
+        | ConnectorStatus::Finishing => "CHARGING",
+        ConnectorStatus::Reserved => "RESERVED",
+        ConnectorStatus::Unavailable => "INOPERATIVE",
+        ConnectorStatus::Faulted => "OUTOFORDER",
+    }
+}
+
+/// Last time the charge point reported anything.
+pub fn last_updated(cp: &ChargePoint) -> DateTime<Utc> {
+    cp.last_heartbeat.unwrap_or(cp.registered_at)
+}
+
+pub fn connector_from_config(
+    cfg: &OcpiConfig,
+    tariff_ids: &[String],
+    last_updated: DateTime<Utc>,
+) -> LocationConnector {
+    LocationConnector {
+        id: CONNECTOR_ID.to_string(),
+        standard: cfg.connector_standard.clone(),
+        format: cfg.connector_format.clone(),
+        power_type: cfg.power_type.clone(),
+        max_voltage: cfg.max_voltage,
+        max_amperage: cfg.max_amperage,
+        tariff_ids: tariff_ids.to_vec(),
+        last_updated,
+    }
+}
+
+pub fn evse_from_connector(
+    cp: &ChargePoint,
+    connector: &Connector,
+    cfg: &OcpiConfig,
+    tariff_ids: &[String],
+) -> Evse {
+    let updated = last_updated(cp);
+    Evse {
+        uid: evse_uid(&cp.id, connector.id),
+        evse_id: Some(evse_id(cfg, &cp.id, connector.id)),
+        status: evse_status(&cp.status, &connector.status).to_string(),
+        capabilities: vec![
+            "REMOTE_START_STOP_CAPABLE".to_string(),
+            "RESERVABLE".to_string(),
+            "RFID_READER".to_string(),
+            "UNLOCK_CAPABLE".to_string(),
+        ],
+        connectors: vec![connector_from_config(cfg, tariff_ids, updated)],
+        last_updated: updated,
+    }
+}
+
+pub fn location_from_charge_point(
+    cp: &ChargePoint,
+    cfg: &OcpiConfig,
+    tariff_ids: &[String],
+) -> Location {
+    let mut connectors: Vec<&Connector> = cp.connectors.iter().filter(|c| c.id > 0).collect();
+    connectors.sort_by_key(|c| c.id);
+
+    let name = match (&cp.vendor, &cp.model) {
+        (Some(vendor), Some(model)) => format!("{} {} ({})", vendor, model, cp.id),
+        _ => cp.id.clone(),
+    };
+
+    Location {
+        country_code: cfg.country_code.clone(),
+        party_id: cfg.party_id.clone(),
+        id: cp.id.clone(),
+        publish: true,
+        name: Some(name),
+        address: cfg.address.clone(),
+        city: cfg.city.clone(),
+        postal_code: (!cfg.postal_code.is_empty()).then(|| cfg.postal_code.clone()),
+        country: cfg.country.clone(),
+        coordinates: GeoLocation {
+            latitude: format!("{:.6}", cfg.latitude),
+            longitude: format!("{:.6}", cfg.longitude),
+        },
+        evses: connectors
+            .into_iter()
+            .map(|c| evse_from_connector(cp, c, cfg, tariff_ids))
+            .collect(),
+        operator: OperatorDetails {
+            name: cfg.business_name.clone(),
+        },
+        time_zone: cfg.time_zone.clone(),
+        last_updated: last_updated(cp),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evse_uid_roundtrip() {
+        let uid = evse_uid("CP-001", 2);
+        assert_eq!(uid, "CP-001-2");
+        assert_eq!(parse_evse_uid(&uid), Some(("CP-001", 2)));
+        assert_eq!(parse_evse_uid("CP001"), None);
+        assert_eq!(parse_evse_uid("CP001-0"), None);
+    }
+
+    #[test]
+    fn location_skips_connector_zero_and_maps_status() {
+        let cfg = OcpiConfig::default();
+        let mut cp = ChargePoint::new("CP001");
+        cp.set_online();
+        cp.ensure_connectors(2);
+        cp.update_connector_status(1, ConnectorStatus::Charging);
+
+        let location = location_from_charge_point(&cp, &cfg, &["1".to_string()]);
+        assert_eq!(location.id, "CP001");
+        assert_eq!(location.evses.len(), 2);
+        assert_eq!(location.evses[0].uid, "CP001-1");
+        assert_eq!(location.evses[0].status, "CHARGING");
+        assert_eq!(location.evses[1].status, "AVAILABLE");
+        assert_eq!(location.evses[0].evse_id.as_deref(), Some("UZ*TXN*ECP001*1"));
+        assert_eq!(location.evses[0].connectors[0].tariff_ids, vec!["1"]);
+    }
+
+    #[test]
+    fn offline_charge_point_reports_unknown() {
+        assert_eq!(
+            evse_status(&ChargePointStatus::Offline, &ConnectorStatus::Available),
+            "UNKNOWN"
+        );
+    }
+}
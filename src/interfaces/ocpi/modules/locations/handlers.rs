@@ -0,0 +1,108 @@
+//! Locations handlers (SENDER interface)
+
+use axum::extract::{Path, Query, State};
+use axum::response::Response;
+
+use super::dto::*;
+use crate::domain::ChargePoint;
+use crate::interfaces::ocpi::common::{paginate, OcpiError, OcpiListParams, OcpiResponse, OcpiResult};
+use crate::interfaces::ocpi::router::OcpiState;
+
+/// Tariff IDs attached to every connector (the default tariff, if any).
+async fn connector_tariff_ids(state: &OcpiState) -> OcpiResult<Vec<String>> {
+    Ok(state
+        .repos
+        .tariffs()
+        .find_default()
+        .await?
+        .map(|t| vec![t.id.to_string()])
+        .unwrap_or_default())
+}
+
+async fn find_location(state: &OcpiState, location_id: &str) -> OcpiResult<ChargePoint> {
+    state
+        .repos
+        .charge_points()
+        .find_by_id(location_id)
+        .await?
+        .ok_or_else(|| OcpiError::UnknownLocation(location_id.to_string()))
+}
+
+/// `GET /ocpi/2.2.1/locations`
+pub async fn list_locations(
+    State(state): State<OcpiState>,
+    Query(params): Query<OcpiListParams>,
+) -> OcpiResult<Response> {
+    let tariff_ids = connector_tariff_ids(&state).await?;
+    let mut charge_points = state.repos.charge_points().find_all().await?;
+    charge_points.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let locations: Vec<Location> = charge_points
+        .iter()
+        .filter(|cp| params.in_range(last_updated(cp)))
+        .map(|cp| location_from_charge_point(cp, &state.config, &tariff_ids))
+        .collect();
+
+    Ok(paginate(locations, &params, &state.module_url("locations")))
+}
+
+/// `GET /ocpi/2.2.1/locations/{location_id}`
+pub async fn get_location(
+    State(state): State<OcpiState>,
+    Path(location_id): Path<String>,
+) -> OcpiResult<OcpiResponse<Location>> {
+    let cp = find_location(&state, &location_id).await?;
+    let tariff_ids = connector_tariff_ids(&state).await?;
+    Ok(OcpiResponse::success(location_from_charge_point(
+        &cp,
+        &state.config,
+        &tariff_ids,
+    )))
+}
+
+/// `GET /ocpi/2.2.1/locations/{location_id}/{evse_uid}`
+pub async fn get_evse(
+    State(state): State<OcpiState>,
+    Path((location_id, uid)): Path<(String, String)>,
+) -> OcpiResult<OcpiResponse<Evse>> {
+    let cp = find_location(&state, &location_id).await?;
+    let tariff_ids = connector_tariff_ids(&state).await?;
+
+    let connector = parse_evse_uid(&uid)
+        .filter(|(cp_id, _)| *cp_id == cp.id)
+        .and_then(|(_, connector_id)| cp.get_connector(connector_id))
+        .ok_or_else(|| OcpiError::NotFound(format!("Unknown EVSE: {}", uid)))?;
+
+    Ok(OcpiResponse::success(evse_from_connector(
+        &cp,
+        connector,
+        &state.config,
+        &tariff_ids,
+    )))
+}
+
+/// `GET /ocpi/2.2.1/locations/{location_id}/{evse_uid}/{connector_id}`
+pub async fn get_connector(
+    State(state): State<OcpiState>,
+    Path((location_id, uid, connector_id)): Path<(String, String, String)>,
+) -> OcpiResult<OcpiResponse<LocationConnector>> {
+    let cp = find_location(&state, &location_id).await?;
+    let tariff_ids = connector_tariff_ids(&state).await?;
+
+    let exists = parse_evse_uid(&uid)
+        .filter(|(cp_id, _)| *cp_id == cp.id)
+        .and_then(|(_, id)| cp.get_connector(id))
+        .is_some();
+    if !exists || connector_id != CONNECTOR_ID {
+        return Err(OcpiError::NotFound(format!(
+            "Unknown connector: {}/{}",
+            uid, connector_id
+        )));
+    }
+
+    Ok(OcpiResponse::success(connector_from_config(
+        &state.config,
+        &tariff_ids,
+        last_updated(&cp),
+    )))
+}
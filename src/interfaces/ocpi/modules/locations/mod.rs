@@ -0,0 +1,7 @@
+//! OCPI locations module — charge points as Locations, connectors as EVSEs
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
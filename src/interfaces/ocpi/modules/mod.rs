@@ -0,0 +1,10 @@
+//! OCPI modules (one directory per OCPI module)
+
+pub mod cdrs;
+pub mod commands;
+pub mod credentials;
+pub mod locations;
+pub mod sessions;
+pub mod tariffs;
+pub mod tokens;
+pub mod versions;
@@ -0,0 +1,121 @@
+//! Sessions DTOs and Transaction → Session mapping
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::config::OcpiConfig;
+use crate::domain::{OcpiToken, Transaction, TransactionBilling, TransactionStatus};
+use crate::interfaces::ocpi::common::cents_to_price;
+use crate::interfaces::ocpi::modules::locations::{evse_uid, CONNECTOR_ID};
+
+/// Price with and without VAT.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Price {
+    pub excl_vat: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub incl_vat: Option<f64>,
+}
+
+impl Price {
+    pub fn from_cents(cents: i32) -> Self {
+        Self {
+            excl_vat: cents_to_price(cents),
+            incl_vat: None,
+        }
+    }
+}
+
+/// Token reference used in Sessions and CDRs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CdrToken {
+    pub country_code: String,
+    pub party_id: String,
+    pub uid: String,
+    #[serde(rename = "type")]
+    pub token_type: String,
+    pub contract_id: String,
+}
+
+impl From<&OcpiToken> for CdrToken {
+    fn from(t: &OcpiToken) -> Self {
+        Self {
+            country_code: t.country_code.clone(),
+            party_id: t.party_id.clone(),
+            uid: t.uid.clone(),
+            token_type: t.token_type.clone(),
+            contract_id: t.contract_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub country_code: String,
+    pub party_id: String,
+    pub id: String,
+    pub start_date_time: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date_time: Option<DateTime<Utc>>,
+    pub kwh: f64,
+    pub cdr_token: CdrToken,
+    pub auth_method: String,
+    pub location_id: String,
+    pub evse_uid: String,
+    pub connector_id: String,
+    pub currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_cost: Option<Price>,
+    pub status: String,
+    pub last_updated: DateTime<Utc>,
+}
+
+/// Energy delivered so far in kWh.
+pub fn session_kwh(tx: &Transaction) -> f64 {
+    tx.energy_consumed()
+        .or_else(|| tx.live_energy_consumed())
+        .unwrap_or(0)
+        .max(0) as f64
+        / 1000.0
+}
+
+pub fn session_status(status: &TransactionStatus) -> &'static str {
+    match status {
+        TransactionStatus::Active => "ACTIVE",
+        TransactionStatus::Completed => "COMPLETED",
+        TransactionStatus::Failed => "INVALID",
+    }
+}
+
+pub fn session_last_updated(tx: &Transaction) -> DateTime<Utc> {
+    tx.stopped_at
+        .or(tx.last_meter_update)
+        .unwrap_or(tx.started_at)
+}
+
+pub fn session_from_transaction(
+    tx: &Transaction,
+    token: &OcpiToken,
+    cfg: &OcpiConfig,
+    currency: &str,
+    billing: Option<&TransactionBilling>,
+) -> Session {
+    Session {
+        country_code: cfg.country_code.clone(),
+        party_id: cfg.party_id.clone(),
+        id: tx.id.to_string(),
+        start_date_time: tx.started_at,
+        end_date_time: tx.stopped_at,
+        kwh: session_kwh(tx),
+        cdr_token: CdrToken::from(token),
+        auth_method: "WHITELIST".to_string(),
+        location_id: tx.charge_point_id.clone(),
+        evse_uid: evse_uid(&tx.charge_point_id, tx.connector_id),
+        connector_id: CONNECTOR_ID.to_string(),
+        currency: billing
+            .map(|b| b.currency.clone())
+            .unwrap_or_else(|| currency.to_string()),
+        total_cost: billing.map(|b| Price::from_cents(b.total_cost)),
+        status: session_status(&tx.status).to_string(),
+        last_updated: session_last_updated(tx),
+    }
+}
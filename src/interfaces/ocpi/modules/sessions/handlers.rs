@@ -0,0 +1,75 @@
+//! Sessions handlers (SENDER interface)
+
+use std::collections::HashMap;
+
+use axum::extract::{Query, State};
+use axum::response::Response;
+use axum::Extension;
+
+use super::dto::*;
+use crate::domain::{DomainResult, OcpiToken, RepositoryProvider};
+use crate::interfaces::ocpi::common::{paginate, OcpiListParams, OcpiResult};
+use crate::interfaces::ocpi::middleware::OcpiCaller;
+use crate::interfaces::ocpi::router::OcpiState;
+
+/// Tokens of the calling party keyed by UID.
+pub async fn caller_tokens(
+    state: &OcpiState,
+    caller: &OcpiCaller,
+) -> OcpiResult<HashMap<String, OcpiToken>> {
+    Ok(state
+        .repos
+        .ocpi_tokens()
+        .find_for_party(&caller.party.country_code, &caller.party.party_id)
+        .await?
+        .into_iter()
+        .map(|t| (t.uid.clone(), t))
+        .collect())
+}
+
+/// Currency used when a transaction has no billing yet.
+pub async fn default_currency(repos: &dyn RepositoryProvider) -> DomainResult<String> {
+    Ok(repos
+        .tariffs()
+        .find_default()
+        .await?
+        .map(|t| t.currency)
+        .unwrap_or_else(|| "UZS".to_string()))
+}
+
+/// `GET /ocpi/2.2.1/sessions`
+///
+/// Only sessions started with tokens owned by the caller are returned.
+pub async fn list_sessions(
+    State(state): State<OcpiState>,
+    Extension(caller): Extension<OcpiCaller>,
+    Query(params): Query<OcpiListParams>,
+) -> OcpiResult<Response> {
+    let tokens = caller_tokens(&state, &caller).await?;
+    let currency = default_currency(state.repos.as_ref()).await?;
+
+    let mut transactions: Vec<_> = state
+        .repos
+        .transactions()
+        .find_all()
+        .await?
+        .into_iter()
+        .filter(|tx| tokens.contains_key(&tx.id_tag))
+        .filter(|tx| params.in_range(session_last_updated(tx)))
+        .collect();
+    transactions.sort_by_key(|tx| tx.id);
+
+    let mut sessions = Vec::with_capacity(transactions.len());
+    for tx in &transactions {
+        let billing = state.repos.billing().get_billing(tx.id).await?;
+        sessions.push(session_from_transaction(
+            tx,
+            &tokens[&tx.id_tag],
+            &state.config,
+            &currency,
+            billing.as_ref(),
+        ));
+    }
+
+    Ok(paginate(sessions, &params, &state.module_url("sessions")))
+}
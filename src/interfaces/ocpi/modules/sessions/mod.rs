@@ -0,0 +1,7 @@
+//! OCPI sessions module — transactions of the caller's tokens
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
@@ -0,0 +1,137 @@
+//! Tariff DTOs and Tariff → OCPI Tariff mapping
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::config::OcpiConfig;
+use crate::domain::{Tariff, TariffType};
+use crate::interfaces::ocpi::common::cents_to_price;
+use crate::interfaces::ocpi::modules::sessions::Price;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceComponent {
+    /// ENERGY (per kWh), TIME (per hour), FLAT (per session), PARKING_TIME
+    #[serde(rename = "type")]
+    pub component_type: String,
+    pub price: f64,
+    /// Billing step: Wh for ENERGY, seconds for TIME, 1 for FLAT.
+    pub step_size: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TariffElement {
+    pub price_components: Vec<PriceComponent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcpiTariff {
+    pub country_code: String,
+    pub party_id: String,
+    pub id: String,
+    pub currency: String,
+    pub elements: Vec<TariffElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_price: Option<Price>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_price: Option<Price>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date_time: Option<DateTime<Utc>>,
+    pub last_updated: DateTime<Utc>,
+}
+
+fn component(component_type: &str, price: f64, step_size: i32) -> PriceComponent {
+    PriceComponent {
+        component_type: component_type.to_string(),
+        price,
+        step_size,
+    }
+}
+
+/// Price components matching `Tariff::calculate_cost`.
+///
+/// Per-minute prices are converted to OCPI's per-hour TIME price
+/// with a 60 second step.
+pub fn price_components(tariff: &Tariff) -> Vec<PriceComponent> {
+    let energy = component("ENERGY", cents_to_price(tariff.price_per_kwh), 1);
+    let time = component("TIME", cents_to_price(tariff.price_per_minute * 60), 60);
+    let flat = component("FLAT", cents_to_price(tariff.session_fee), 1);
+
+    match tariff.tariff_type {
+        TariffType::PerKwh => vec![energy],
+        TariffType::PerMinute => vec![time],
+        TariffType::PerSession => vec![flat],
+        TariffType::Combined => [
+            (tariff.price_per_kwh, energy),
+            (tariff.price_per_minute, time),
+            (tariff.session_fee, flat),
+        ]
+        .into_iter()
+        .filter(|(cents, _)| *cents > 0)
+        .map(|(_, c)| c)
+        .collect(),
+    }
+}
+
+pub fn tariff_to_ocpi(tariff: &Tariff, cfg: &OcpiConfig) -> OcpiTariff {
+    OcpiTariff {
+        country_code: cfg.country_code.clone(),
+        party_id: cfg.party_id.clone(),
+        id: tariff.id.to_string(),
+        currency: tariff.currency.clone(),
+        elements: vec![TariffElement {
+            price_components: price_components(tariff),
+        }],
+        min_price: (tariff.min_fee > 0).then(|| Price::from_cents(tariff.min_fee)),
+        max_price: (tariff.max_fee > 0).then(|| Price::from_cents(tariff.max_fee)),
+        start_date_time: tariff.valid_from,
+        end_date_time: tariff.valid_until,
+        last_updated: tariff.updated_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tariff(tariff_type: TariffType) -> Tariff {
+        Tariff {
+            id: 3,
+            name: "Test".into(),
+            description: None,
+            tariff_type,
+            price_per_kwh: 500,
+            price_per_minute: 10,
+            session_fee: 0,
+            currency: "UZS".into(),
+            min_fee: 0,
+            max_fee: 2000,
+            is_active: true,
+            is_default: true,
+            valid_from: None,
+            valid_until: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn per_kwh_maps_to_single_energy_component() {
+        let components = price_components(&tariff(TariffType::PerKwh));
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].component_type, "ENERGY");
+        assert_eq!(components[0].price, 5.0);
+    }
+
+    #[test]
+    fn combined_skips_zero_components_and_converts_minutes_to_hours() {
+        let ocpi = tariff_to_ocpi(&tariff(TariffType::Combined), &OcpiConfig::default());
+        let components = &ocpi.elements[0].price_components;
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[1].component_type, "TIME");
+        assert_eq!(components[1].price, 6.0);
+        assert_eq!(ocpi.max_price.unwrap().excl_vat, 20.0);
+        assert!(ocpi.min_price.is_none());
+    }
+}
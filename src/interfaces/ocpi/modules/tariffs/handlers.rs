@@ -0,0 +1,31 @@
+//! Tariffs handlers (SENDER interface)
+
+use axum::extract::{Query, State};
+use axum::response::Response;
+
+use super::dto::*;
+use crate::interfaces::ocpi::common::{paginate, OcpiListParams, OcpiResult};
+use crate::interfaces::ocpi::router::OcpiState;
+
+/// `GET /ocpi/2.2.1/tariffs`
+pub async fn list_tariffs(
+    State(state): State<OcpiState>,
+    Query(params): Query<OcpiListParams>,
+) -> OcpiResult<Response> {
+    let mut tariffs: Vec<_> = state
+        .repos
+        .tariffs()
+        .find_all()
+        .await?
+        .into_iter()
+        .filter(|t| t.is_active && params.in_range(t.updated_at))
+        .collect();
+    tariffs.sort_by_key(|t| t.id);
+
+    let items: Vec<OcpiTariff> = tariffs
+        .iter()
+        .map(|t| tariff_to_ocpi(t, &state.config))
+        .collect();
+
+    Ok(paginate(items, &params, &state.module_url("tariffs")))
+}
@@ -0,0 +1,7 @@
+//! OCPI tariffs module — active tariffs as OCPI price components
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
@@ -0,0 +1,65 @@
+//! Token DTO
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::OcpiToken;
+
+/// OCPI Token object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Token {
+    pub country_code: String,
+    pub party_id: String,
+    pub uid: String,
+    #[serde(rename = "type")]
+    pub token_type: String,
+    pub contract_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visual_number: Option<String>,
+    pub issuer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<String>,
+    pub valid: bool,
+    pub whitelist: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    pub last_updated: DateTime<Utc>,
+}
+
+impl From<OcpiToken> for Token {
+    fn from(t: OcpiToken) -> Self {
+        Self {
+            country_code: t.country_code,
+            party_id: t.party_id,
+            uid: t.uid,
+            token_type: t.token_type,
+            contract_id: t.contract_id,
+            visual_number: t.visual_number,
+            issuer: t.issuer,
+            group_id: t.group_id,
+            valid: t.valid,
+            whitelist: t.whitelist,
+            language: t.language,
+            last_updated: t.last_updated,
+        }
+    }
+}
+
+impl From<Token> for OcpiToken {
+    fn from(t: Token) -> Self {
+        Self {
+            country_code: t.country_code.to_uppercase(),
+            party_id: t.party_id.to_uppercase(),
+            uid: t.uid,
+            token_type: t.token_type,
+            contract_id: t.contract_id,
+            visual_number: t.visual_number,
+            issuer: t.issuer,
+            group_id: t.group_id,
+            valid: t.valid,
+            whitelist: t.whitelist,
+            language: t.language,
+            last_updated: t.last_updated,
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Tokens handlers (RECEIVER interface)
+//!
+//! eMSPs push their tokens here; `ChargePointService::get_auth_status`
+//! falls back to these when an OCPP idTag is not known locally.
+
+use axum::extract::{Path, State};
+use axum::{Extension, Json};
+use serde_json::Value;
+use tracing::info;
+
+use super::dto::*;
+use crate::domain::OcpiToken;
+use crate::interfaces::ocpi::common::{OcpiError, OcpiResponse, OcpiResult};
+use crate::interfaces::ocpi::middleware::OcpiCaller;
+use crate::interfaces::ocpi::router::OcpiState;
+
+fn ensure_owner(caller: &OcpiCaller, country_code: &str, party_id: &str) -> OcpiResult<()> {
+    if caller.party.owns(country_code, party_id) {
+        Ok(())
+    } else {
+        Err(OcpiError::InvalidParameters(format!(
+            "Tokens of {}/{} cannot be managed by {}/{}",
+            country_code, party_id, caller.party.country_code, caller.party.party_id
+        )))
+    }
+}
+
+async fn find_token(
+    state: &OcpiState,
+    country_code: &str,
+    party_id: &str,
+    uid: &str,
+) -> OcpiResult<OcpiToken> {
+    state
+        .repos
+        .ocpi_tokens()
+        .find(
+            &country_code.to_uppercase(),
+            &party_id.to_uppercase(),
+            uid,
+        )
+        .await?
+        .ok_or_else(|| OcpiError::UnknownToken(uid.to_string()))
+}
+
+/// `GET /ocpi/2.2.1/tokens/{country_code}/{party_id}/{token_uid}`
+pub async fn get_token(
+    State(state): State<OcpiState>,
+    Extension(caller): Extension<OcpiCaller>,
+    Path((country_code, party_id, uid)): Path<(String, String, String)>,
+) -> OcpiResult<OcpiResponse<Token>> {
+    ensure_owner(&caller, &country_code, &party_id)?;
+    let token = find_token(&state, &country_code, &party_id, &uid).await?;
+    Ok(OcpiResponse::success(Token::from(token)))
+}
+
+/// `PUT /ocpi/2.2.1/tokens/{country_code}/{party_id}/{token_uid}`
+pub async fn put_token(
+    State(state): State<OcpiState>,
+    Extension(caller): Extension<OcpiCaller>,
+    Path((country_code, party_id, uid)): Path<(String, String, String)>,
+    Json(token): Json<Token>,
+) -> OcpiResult<OcpiResponse<()>> {
+    ensure_owner(&caller, &country_code, &party_id)?;
+    if token.uid != uid
+        || !token.country_code.eq_ignore_ascii_case(&country_code)
+        || !token.party_id.eq_ignore_ascii_case(&party_id)
+    {
+        return Err(OcpiError::InvalidParameters(
+            "Token identity does not match the URL".to_string(),
+        ));
+    }
+
+    info!(
+        country_code = country_code.as_str(),
+        party_id = party_id.as_str(),
+        uid = uid.as_str(),
+        valid = token.valid,
+        "OCPI token stored"
+    );
+    state.repos.ocpi_tokens().upsert(token.into()).await?;
+    Ok(OcpiResponse::empty())
+}
+
+/// `PATCH /ocpi/2.2.1/tokens/{country_code}/{party_id}/{token_uid}`
+///
+/// Partial update; `last_updated` is mandatory, identity fields are ignored.
+pub async fn patch_token(
+    State(state): State<OcpiState>,
+    Extension(caller): Extension<OcpiCaller>,
+    Path((country_code, party_id, uid)): Path<(String, String, String)>,
+    Json(patch): Json<Value>,
+) -> OcpiResult<OcpiResponse<()>> {
+    ensure_owner(&caller, &country_code, &party_id)?;
+
+    let Value::Object(fields) = patch else {
+        return Err(OcpiError::InvalidParameters(
+            "PATCH body must be an object".to_string(),
+        ));
+    };
+    if !fields.contains_key("last_updated") {
+        return Err(OcpiError::InvalidParameters(
+            "last_updated is required".to_string(),
+        ));
+    }
+
+    let existing = Token::from(find_token(&state, &country_code, &party_id, &uid).await?);
+    let mut merged = serde_json::to_value(&existing)
+        .map_err(|e| OcpiError::Server(e.to_string()))?;
+    if let Value::Object(target) = &mut merged {
+        for (key, value) in fields {
+            if !matches!(key.as_str(), "country_code" | "party_id" | "uid") {
+                target.insert(key, value);
+            }
+        }
+    }
+
+    let token: Token = serde_json::from_value(merged)
+        .map_err(|e| OcpiError::InvalidParameters(e.to_string()))?;
+    state.repos.ocpi_tokens().upsert(token.into()).await?;
+    Ok(OcpiResponse::empty())
+}
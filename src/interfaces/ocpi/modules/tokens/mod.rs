@@ -0,0 +1,7 @@
+//! OCPI tokens module (RECEIVER) — tokens pushed by eMSPs
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
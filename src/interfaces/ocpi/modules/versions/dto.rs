@@ -0,0 +1,26 @@
+//! Versions DTOs
+
+use serde::{Deserialize, Serialize};
+
+/// Entry of the versions list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Version {
+    pub version: String,
+    pub url: String,
+}
+
+/// Module endpoint of a version.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Endpoint {
+    pub identifier: String,
+    /// SENDER or RECEIVER
+    pub role: String,
+    pub url: String,
+}
+
+/// Details of one version: the module endpoints it offers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionDetails {
+    pub version: String,
+    pub endpoints: Vec<Endpoint>,
+}
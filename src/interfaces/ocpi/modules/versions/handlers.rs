@@ -0,0 +1,44 @@
+//! Versions handlers
+
+use axum::extract::State;
+
+use super::dto::*;
+use crate::interfaces::ocpi::common::{OcpiResponse, OCPI_VERSION};
+use crate::interfaces::ocpi::router::OcpiState;
+
+/// Modules implemented by this CPO and the role we play in each.
+const ENDPOINTS: &[(&str, &str)] = &[
+    ("credentials", "SENDER"),
+    ("credentials", "RECEIVER"),
+    ("locations", "SENDER"),
+    ("sessions", "SENDER"),
+    ("cdrs", "SENDER"),
+    ("tariffs", "SENDER"),
+    ("tokens", "RECEIVER"),
+    ("commands", "RECEIVER"),
+];
+
+/// `GET /ocpi/versions`
+pub async fn list_versions(State(state): State<OcpiState>) -> OcpiResponse<Vec<Version>> {
+    OcpiResponse::success(vec![Version {
+        version: OCPI_VERSION.to_string(),
+        url: state.module_url(""),
+    }])
+}
+
+/// `GET /ocpi/2.2.1`
+pub async fn version_details(State(state): State<OcpiState>) -> OcpiResponse<VersionDetails> {
+    let endpoints = ENDPOINTS
+        .iter()
+        .map(|(identifier, role)| Endpoint {
+            identifier: identifier.to_string(),
+            role: role.to_string(),
+            url: state.module_url(identifier),
+        })
+        .collect();
+
+    OcpiResponse::success(VersionDetails {
+        version: OCPI_VERSION.to_string(),
+        endpoints,
+    })
+}
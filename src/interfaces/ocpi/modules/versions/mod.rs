@@ -0,0 +1,7 @@
+//! OCPI versions module — version discovery
+
+pub mod dto;
+pub mod handlers;
+
+pub use dto::*;
+pub use handlers::*;
@@ -0,0 +1,494 @@
+//! OCPI push — forwards local changes to registered partners
+//!
+//! Subscribes to the event bus and calls the RECEIVER interfaces of every
+//! registered party that published one during the credentials handshake:
+//!
+//! - `ConnectorStatusChanged` → `PATCH locations/{cc}/{pid}/{location}/{evse}` (all parties)
+//! - `TransactionStarted` / `TransactionStopped` → `PUT sessions/{cc}/{pid}/{id}` (token owner)
+//! - `MeterValuesReceived` → `PATCH sessions/{cc}/{pid}/{id}` with `kwh` (token owner)
+//! - `TransactionBilled` → `POST cdrs` (token owner)
+//!
+//! Deliveries are queued per party so a partner sees updates in order, and
+//! failed calls are retried with exponential backoff.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use dashmap::DashMap;
+use reqwest::Method;
+use serde_json::{json, Value};
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
+
+use super::client::{OcpiClient, OcpiClientError};
+use super::modules::cdrs::cdr_from_transaction;
+use super::modules::locations::{evse_status, evse_uid};
+use super::modules::sessions::{
+    default_currency, session_from_transaction, session_kwh, session_last_updated,
+};
+use crate::application::events::{Event, SharedEventBus};
+use crate::config::OcpiConfig;
+use crate::domain::{
+    BillingStatus, DomainResult, OcpiParty, OcpiPartyStatus, RepositoryProvider, Transaction,
+};
+use crate::shared::shutdown::ShutdownSignal;
+
+/// A single call to a partner's API.
+#[derive(Debug, Clone)]
+struct Delivery {
+    method: Method,
+    url: String,
+    token: String,
+    body: Value,
+}
+
+/// Pushes EVSE status, sessions and CDRs to registered partners.
+pub struct OcpiPushService {
+    repos: Arc<dyn RepositoryProvider>,
+    config: Arc<OcpiConfig>,
+    client: OcpiClient,
+    /// Per-party delivery queues keyed by `CC*PID`.
+    queues: DashMap<String, mpsc::UnboundedSender<Delivery>>,
+}
+
+impl OcpiPushService {
+    pub fn new(
+        repos: Arc<dyn RepositoryProvider>,
+        config: Arc<OcpiConfig>,
+        client: OcpiClient,
+    ) -> Self {
+        Self {
+            repos,
+            config,
+            client,
+            queues: DashMap::new(),
+        }
+    }
+
+    /// Subscribe to the event bus and push changes until shutdown.
+    pub fn start(self, event_bus: SharedEventBus, shutdown: ShutdownSignal) {
+        let mut subscriber = event_bus.subscribe();
+        tokio::spawn(async move {
+            info!("🌍 OCPI push service started");
+            loop {
+                tokio::select! {
+                    msg = subscriber.recv() => {
+                        let Some(msg) = msg else { break };
+                        if let Err(e) = self.handle_event(&msg.event).await {
+                            warn!(error = %e, "OCPI push failed to prepare update");
+                        }
+                    }
+                    _ = shutdown.notified().wait() => {
+                        info!("🌍 OCPI push service shutting down");
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    async fn handle_event(&self, event: &Event) -> DomainResult<()> {
+        match event {
+            Event::ConnectorStatusChanged(e) => {
+                self.push_evse_status(&e.charge_point_id, e.connector_id)
+                    .await
+            }
+            Event::TransactionStarted(e) => self.push_session(e.transaction_id).await,
+            Event::TransactionStopped(e) => self.push_session(e.transaction_id).await,
+            Event::MeterValuesReceived(e) => match e.transaction_id {
+                Some(transaction_id) => self.push_session_kwh(transaction_id).await,
+                None => Ok(()),
+            },
+            Event::TransactionBilled(e) => self.push_cdr(e.transaction_id).await,
+            _ => Ok(()),
+        }
+    }
+
+    /// `PATCH` the EVSE status to every party with a Locations receiver.
+    async fn push_evse_status(&self, charge_point_id: &str, connector_id: u32) -> DomainResult<()> {
+        // Connector 0 is the charge point itself, not an EVSE
+        if connector_id == 0 {
+            return Ok(());
+        }
+        let Some(cp) = self
+            .repos
+            .charge_points()
+            .find_by_id(charge_point_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        let Some(connector) = cp.get_connector(connector_id) else {
+            return Ok(());
+        };
+
+        let body = json!({
+            "status": evse_status(&cp.status, &connector.status),
+            "last_updated": Utc::now(),
+        });
+        for party in self.registered_parties().await? {
+            if let Some(endpoint) = party.endpoint_url("locations", "RECEIVER") {
+                let url = format!(
+                    "{}/{}",
+                    self.object_url(&endpoint, &cp.id),
+                    evse_uid(&cp.id, connector_id)
+                );
+                self.enqueue(&party, Method::PATCH, url, body.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// `PUT` the full session to the party owning the transaction's token.
+    async fn push_session(&self, transaction_id: i32) -> DomainResult<()> {
+        let Some(tx) = self.repos.transactions().find_by_id(transaction_id).await? else {
+            return Ok(());
+        };
+        let Some(token) = self.repos.ocpi_tokens().find_by_uid(&tx.id_tag).await? else {
+            return Ok(());
+        };
+        let Some((party, endpoint)) = self.owner_endpoint(&tx, "sessions").await? else {
+            return Ok(());
+        };
+
+        let currency = default_currency(self.repos.as_ref()).await?;
+        let billing = self.repos.billing().get_billing(tx.id).await?;
+        let session =
+            session_from_transaction(&tx, &token, &self.config, &currency, billing.as_ref());
+
+        let url = self.object_url(&endpoint, &session.id);
+        self.enqueue(&party, Method::PUT, url, json!(session));
+        Ok(())
+    }
+
+    /// `PATCH` the energy delivered so far into the running session.
+    async fn push_session_kwh(&self, transaction_id: i32) -> DomainResult<()> {
+        let Some(tx) = self.repos.transactions().find_by_id(transaction_id).await? else {
+            return Ok(());
+        };
+        let Some((party, endpoint)) = self.owner_endpoint(&tx, "sessions").await? else {
+            return Ok(());
+        };
+
+        let body = json!({
+            "kwh": session_kwh(&tx),
+            "last_updated": session_last_updated(&tx),
+        });
+        let url = self.object_url(&endpoint, &tx.id.to_string());
+        self.enqueue(&party, Method::PATCH, url, body);
+        Ok(())
+    }
+
+    /// `POST` the CDR of a billed transaction to the token owner.
+    async fn push_cdr(&self, transaction_id: i32) -> DomainResult<()> {
+        let Some(tx) = self.repos.transactions().find_by_id(transaction_id).await? else {
+            return Ok(());
+        };
+        let Some(billing) = self.repos.billing().get_billing(tx.id).await? else {
+            return Ok(());
+        };
+        if matches!(
+            billing.status,
+            BillingStatus::Pending | BillingStatus::Failed
+        ) {
+            return Ok(());
+        }
+        let Some(token) = self.repos.ocpi_tokens().find_by_uid(&tx.id_tag).await? else {
+            return Ok(());
+        };
+        let Some((party, endpoint)) = self.owner_endpoint(&tx, "cdrs").await? else {
+            return Ok(());
+        };
+
+        if let Some(cdr) = cdr_from_transaction(&tx, &token, &billing, &self.config) {
+            self.enqueue(&party, Method::POST, endpoint, json!(cdr));
+        }
+        Ok(())
+    }
+
+    async fn registered_parties(&self) -> DomainResult<Vec<OcpiParty>> {
+        Ok(self
+            .repos
+            .ocpi_parties()
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|p| p.status == OcpiPartyStatus::Registered && p.token_b.is_some())
+            .collect())
+    }
+
+    /// Registered party owning the transaction's token and its receiver URL
+    /// for `module`. `None` for local id_tags or partners without a receiver.
+    async fn owner_endpoint(
+        &self,
+        tx: &Transaction,
+        module: &str,
+    ) -> DomainResult<Option<(OcpiParty, String)>> {
+        let Some(token) = self.repos.ocpi_tokens().find_by_uid(&tx.id_tag).await? else {
+            return Ok(None);
+        };
+        Ok(self
+            .registered_parties()
+            .await?
+            .into_iter()
+            .find(|p| p.owns(&token.country_code, &token.party_id))
+            .and_then(|p| {
+                let url = p.endpoint_url(module, "RECEIVER")?;
+                Some((p, url))
+            }))
+    }
+
+    /// `{endpoint}/{our country_code}/{our party_id}/{object_id}`
+    fn object_url(&self, endpoint: &str, object_id: &str) -> String {
+        format!(
+            "{}/{}/{}/{}",
+            endpoint.trim_end_matches('/'),
+            self.config.country_code,
+            self.config.party_id,
+            object_id
+        )
+    }
+
+    /// Queue a call to `party`, starting its delivery worker if needed.
+    fn enqueue(&self, party: &OcpiParty, method: Method, url: String, body: Value) {
+        let Some(token) = party.token_b.clone() else {
+            return;
+        };
+        let key = format!("{}*{}", party.country_code, party.party_id);
+        let delivery = Delivery {
+            method,
+            url,
+            token,
+            body,
+        };
+
+        let sender = self
+            .queues
+            .entry(key.clone())
+            .or_insert_with(|| {
+                let (tx, rx) = mpsc::unbounded_channel();
+                tokio::spawn(delivery_worker(
+                    key,
+                    rx,
+                    self.client.clone(),
+                    self.config.push_max_retries,
+                    Duration::from_millis(self.config.push_retry_delay_ms),
+                ));
+                tx
+            })
+            .clone();
+
+        let _ = sender.send(delivery);
+    }
+}
+
+/// Deliver queued calls to one party, in order.
+async fn delivery_worker(
+    party: String,
+    mut rx: mpsc::UnboundedReceiver<Delivery>,
+    client: OcpiClient,
+    max_retries: u32,
+    retry_delay: Duration,
+) {
+    while let Some(delivery) = rx.recv().await {
+        if let Err(e) = deliver(&client, &delivery, max_retries, retry_delay).await {
+            warn!(
+                party = party.as_str(),
+                method = %delivery.method,
+                url = delivery.url.as_str(),
+                error = %e,
+                "OCPI push dropped"
+            );
+        }
+    }
+}
+
+/// Longest wait between two attempts at one call.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(300);
+
+/// Wait before retry number `attempt + 1`: `retry_delay` doubled per
+/// earlier retry, capped at [`MAX_RETRY_DELAY`].
+fn backoff(retry_delay: Duration, attempt: u32) -> Duration {
+    retry_delay
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_RETRY_DELAY)
+}
+
+/// Send one call, retrying transient failures with exponential backoff.
+async fn deliver(
+    client: &OcpiClient,
+    delivery: &Delivery,
+    max_retries: u32,
+    retry_delay: Duration,
+) -> Result<(), OcpiClientError> {
+    let mut attempt = 0;
+    loop {
+        let result = match delivery.method {
+            Method::PUT => {
+                client
+                    .put(&delivery.url, &delivery.token, &delivery.body)
+                    .await
+            }
+            Method::PATCH => {
+                client
+                    .patch(&delivery.url, &delivery.token, &delivery.body)
+                    .await
+            }
+            _ => {
+                client
+                    .post(&delivery.url, &delivery.token, &delivery.body)
+                    .await
+            }
+        };
+        match result {
+            Ok(()) => {
+                debug!(method = %delivery.method, url = delivery.url.as_str(), "OCPI push delivered");
+                return Ok(());
+            }
+            Err(e) if e.is_retryable() && attempt < max_retries => {
+                let delay = backoff(retry_delay, attempt);
+                attempt += 1;
+                debug!(
+                    url = delivery.url.as_str(),
+                    attempt,
+                    error = %e,
+                    "OCPI push failed, retrying in {:?}",
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use axum::extract::State;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::put;
+    use axum::{Json, Router};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct MockEmsp {
+        calls: Arc<AtomicUsize>,
+        fail_first: usize,
+        auth: Arc<std::sync::Mutex<Option<String>>>,
+    }
+
+    async fn receive(
+        State(mock): State<MockEmsp>,
+        headers: HeaderMap,
+    ) -> (StatusCode, Json<Value>) {
+        let n = mock.calls.fetch_add(1, Ordering::SeqCst);
+        *mock.auth.lock().unwrap() = headers
+            .get("Authorization")
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+        if n < mock.fail_first {
+            return (StatusCode::SERVICE_UNAVAILABLE, Json(json!({})));
+        }
+        (
+            StatusCode::OK,
+            Json(json!({ "status_code": 1000, "timestamp": Utc::now() })),
+        )
+    }
+
+    /// Spawn a mock eMSP on an ephemeral port and return its sessions URL.
+    async fn spawn_mock(mock: MockEmsp) -> String {
+        let app = Router::new()
+            .route("/sessions/{cc}/{pid}/{id}", put(receive))
+            .with_state(mock);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        format!("http://{}/sessions/UZ/TXN/1", addr)
+    }
+
+    fn delivery(url: String) -> Delivery {
+        Delivery {
+            method: Method::PUT,
+            url,
+            token: "token-b".to_string(),
+            body: json!({ "id": "1" }),
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_until_partner_accepts() {
+        let mock = MockEmsp {
+            fail_first: 2,
+            ..Default::default()
+        };
+        let url = spawn_mock(mock.clone()).await;
+
+        let result = deliver(
+            &OcpiClient::new(),
+            &delivery(url),
+            3,
+            Duration::from_millis(1),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(mock.calls.load(Ordering::SeqCst), 3);
+        assert_eq!(
+            mock.auth.lock().unwrap().as_deref(),
+            Some(crate::interfaces::ocpi::common::authorization_header("token-b").as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_retries() {
+        let mock = MockEmsp {
+            fail_first: usize::MAX,
+            ..Default::default()
+        };
+        let url = spawn_mock(mock.clone()).await;
+
+        let result = deliver(
+            &OcpiClient::new(),
+            &delivery(url),
+            2,
+            Duration::from_millis(1),
+        )
+        .await;
+
+        assert!(matches!(result, Err(OcpiClientError::Status(503))));
+        assert_eq!(mock.calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let delay = Duration::from_secs(1);
+        assert_eq!(backoff(delay, 0), delay);
+        assert_eq!(backoff(delay, 3), Duration::from_secs(8));
+        assert_eq!(backoff(delay, 9), MAX_RETRY_DELAY);
+        // Past 2^31 the multiplier saturates instead of overflowing
+        assert_eq!(backoff(delay, 40), MAX_RETRY_DELAY);
+        assert_eq!(backoff(Duration::MAX, 1), MAX_RETRY_DELAY);
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let mock = MockEmsp::default();
+        let url = spawn_mock(mock.clone()).await;
+
+        // Unknown route on the mock → 404
+        let result = deliver(
+            &OcpiClient::new(),
+            &delivery(format!("{}/extra", url)),
+            3,
+            Duration::from_millis(1),
+        )
+        .await;
+
+        assert!(matches!(result, Err(OcpiClientError::Status(404))));
+        assert_eq!(mock.calls.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,96 @@
+//! OCPI router and shared state
+
+use std::sync::Arc;
+
+use axum::{
+    middleware,
+    routing::{get, post},
+    Router,
+};
+
+use super::client::OcpiClient;
+use super::common::OCPI_VERSION;
+use super::middleware::{ocpi_auth_middleware, ocpi_registration_auth_middleware};
+use super::modules::{cdrs, commands, credentials, locations, sessions, tariffs, tokens, versions};
+use crate::application::{SharedCommandDispatcher, SharedSessionRegistry};
+use crate::config::OcpiConfig;
+use crate::domain::RepositoryProvider;
+
+/// State shared by all OCPI handlers.
+#[derive(Clone)]
+pub struct OcpiState {
+    pub repos: Arc<dyn RepositoryProvider>,
+    pub session_registry: SharedSessionRegistry,
+    pub command_dispatcher: SharedCommandDispatcher,
+    pub config: Arc<OcpiConfig>,
+    pub client: OcpiClient,
+}
+
+impl OcpiState {
+    /// Public URL of the versions endpoint.
+    pub fn versions_url(&self) -> String {
+        format!("{}/versions", self.config.base_url.trim_end_matches('/'))
+    }
+
+    /// Public URL of a module under the implemented version, e.g. `locations`.
+    pub fn module_url(&self, module: &str) -> String {
+        let base = format!("{}/{}", self.config.base_url.trim_end_matches('/'), OCPI_VERSION);
+        if module.is_empty() {
+            base
+        } else {
+            format!("{}/{}", base, module)
+        }
+    }
+}
+
+/// Create the `/ocpi` router (nest it under `/ocpi`).
+pub fn create_ocpi_router(state: OcpiState) -> Router {
+    // Token A or C: version discovery + credentials handshake
+    let registration_routes = Router::new()
+        .route("/versions", get(versions::list_versions))
+        .route("/2.2.1", get(versions::version_details))
+        .route(
+            "/2.2.1/credentials",
+            get(credentials::get_credentials)
+                .post(credentials::post_credentials)
+                .put(credentials::put_credentials)
+                .delete(credentials::delete_credentials),
+        )
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            ocpi_registration_auth_middleware,
+        ));
+
+    // Token C only: functional modules
+    let module_routes = Router::new()
+        // --- Locations (SENDER) ---
+        .route("/2.2.1/locations", get(locations::list_locations))
+        .route("/2.2.1/locations/{location_id}", get(locations::get_location))
+        .route(
+            "/2.2.1/locations/{location_id}/{evse_uid}",
+            get(locations::get_evse),
+        )
+        .route(
+            "/2.2.1/locations/{location_id}/{evse_uid}/{connector_id}",
+            get(locations::get_connector),
+        )
+        // --- Sessions / CDRs / Tariffs (SENDER) ---
+        .route("/2.2.1/sessions", get(sessions::list_sessions))
+        .route("/2.2.1/cdrs", get(cdrs::list_cdrs))
+        .route("/2.2.1/tariffs", get(tariffs::list_tariffs))
+        // --- Tokens (RECEIVER) ---
+        .route(
+            "/2.2.1/tokens/{country_code}/{party_id}/{token_uid}",
+            get(tokens::get_token)
+                .put(tokens::put_token)
+                .patch(tokens::patch_token),
+        )
+        // --- Commands (RECEIVER) ---
+        .route("/2.2.1/commands/{command}", post(commands::receive_command))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            ocpi_auth_middleware,
+        ));
+
+    registration_routes.merge(module_routes).with_state(state)
+}
@@ -76,7 +76,7 @@ async fn handle_notification_socket(
     filter: EventFilter,
 ) {
     let (mut sender, mut receiver) = socket.split();
-    let mut subscriber = state.event_bus.subscribe();
+    let mut subscriber = state.event_bus.subscribe_cluster();
 
     // Send welcome message
     let welcome = serde_json::json!({
@@ -5,15 +5,13 @@
 //! via the `Sec-WebSocket-Protocol` header and creates the appropriate
 //! version-specific adapter for each connection.
 
-use std::net::{IpAddr, SocketAddr};
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use chrono::Utc;
-use dashmap::DashMap;
 use futures_util::{SinkExt, StreamExt};
 use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::mpsc;
-use tokio::time::Instant;
 use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
 use tokio_tungstenite::tungstenite::Message;
 use tracing::{error, info, warn};
@@ -24,10 +22,12 @@ use crate::application::events::{
 use crate::application::SharedCommandSender;
 use crate::application::SharedSessionRegistry;
 use crate::application::session::RegisterResult;
+use crate::config::reload::LiveConfig;
 use crate::config::{Config, WsAuthConfig};
 use crate::domain::RepositoryProvider;
 use crate::domain::OcppVersion;
 use crate::infrastructure::crypto::password::verify_password;
+use crate::shared::rate_limit::RateLimiter;
 use crate::shared::shutdown::ShutdownSignal;
 
 use super::negotiator::ProtocolAdapters;
@@ -45,10 +45,14 @@ pub struct OcppServer {
     shutdown_signal: Option<ShutdownSignal>,
     event_bus: SharedEventBus,
     repos: Arc<dyn RepositoryProvider>,
-    /// Per-IP WebSocket connection rate limiter: max connections per minute
-    ws_rate_limiter: Arc<WsRateLimiter>,
+    /// Per-IP WebSocket connection rate limiter
+    ws_rate_limiter: Arc<RateLimiter>,
+    /// Max new connections per minute per IP
+    ws_connections_per_minute: u32,
     /// WebSocket authentication configuration
     ws_auth: WsAuthConfig,
+    /// Reloaded configuration; overrides the connection limit and auth
+    live_config: Option<LiveConfig>,
 }
 
 impl OcppServer {
@@ -74,8 +78,10 @@ impl OcppServer {
             shutdown_signal: None,
             event_bus,
             repos,
-            ws_rate_limiter: Arc::new(WsRateLimiter::new(ws_connections_per_minute)),
+            ws_rate_limiter: Arc::new(RateLimiter::new()),
+            ws_connections_per_minute,
             ws_auth,
+            live_config: None,
         }
     }
 
@@ -85,10 +91,25 @@ impl OcppServer {
         self
     }
 
+    /// Take the connection limit and WebSocket auth from the running
+    /// configuration, so reloads apply to new connections
+    pub fn with_live_config(mut self, live_config: LiveConfig) -> Self {
+        self.live_config = Some(live_config);
+        self
+    }
+
     /// Start the WebSocket server
     pub async fn run(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let addr = self.config.address();
-        let listener = TcpListener::bind(&addr).await?;
+        let listener = TcpListener::bind(self.config.address()).await?;
+        self.serve(listener).await
+    }
+
+    /// Serve on an already bound listener (e.g. an ephemeral port in tests).
+    pub async fn serve(
+        &self,
+        listener: TcpListener,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let addr = listener.local_addr()?;
 
         let negotiator = self.protocol_adapters.build_negotiator();
         let supported: Vec<String> = negotiator
@@ -148,8 +169,23 @@ impl OcppServer {
     }
 
     fn spawn_connection(&self, stream: TcpStream, addr: SocketAddr) {
+        let (ws_connections_per_minute, ws_auth) = match &self.live_config {
+            Some(live) => {
+                let config = live.borrow();
+                (
+                    config.rate_limit.ws_connections_per_minute,
+                    config.ws_auth.clone(),
+                )
+            }
+            None => (self.ws_connections_per_minute, self.ws_auth.clone()),
+        };
+
         // WS rate limiting: check per-IP connection rate
-        if !self.ws_rate_limiter.check(addr.ip()) {
+        if self
+            .ws_rate_limiter
+            .check(addr.ip(), ws_connections_per_minute)
+            .is_err()
+        {
             warn!(
                 "🛡️ WS rate limit exceeded for IP {}, dropping connection",
                 addr.ip()
@@ -164,7 +200,6 @@ impl OcppServer {
         let shutdown = self.shutdown_signal.clone();
         let event_bus = self.event_bus.clone();
         let repos = self.repos.clone();
-        let ws_auth = self.ws_auth.clone();
 
         tokio::spawn(async move {
             if let Err(e) = handle_connection(
@@ -186,7 +221,7 @@ impl OcppServer {
     }
 
     async fn graceful_shutdown(&self) {
-        let connected = self.session_registry.connected_ids();
+        let connected = self.session_registry.local_ids();
         let count = connected.len();
 
         if count > 0 {
@@ -390,6 +425,8 @@ async fn handle_connection(
         }
     }
 
+    let connected_since = session_registry.connected_since(&charge_point_id);
+
     event_bus.publish(Event::ChargePointConnected(ChargePointConnectedEvent {
         charge_point_id: charge_point_id.clone(),
         ocpp_version: version.version_string().to_string(),
@@ -416,10 +453,11 @@ async fn handle_connection(
                             metrics::counter!("ws_messages_total", "direction" => "outbound", "type" => "text").increment(1);
                             if let Err(e) = ws_sender.send(Message::Text(msg)).await {
                                 error!("[{}] Send error: {}", cp_id_send, e);
-                                break;
+                                break "Send error";
                             }
                         }
-                        None => break,
+                        // Evicted by a newer connection, or unregistered
+                        None => break "Session closed",
                     }
                 }
                 _ = ping_interval.tick() => {
@@ -427,13 +465,13 @@ async fn handle_connection(
                     if !pong_received_send.load(std::sync::atomic::Ordering::Relaxed) {
                         warn!("[{}] No Pong received within ping interval — closing dead connection", cp_id_send);
                         metrics::counter!("ws_connections_dead", "reason" => "pong_timeout").increment(1);
-                        break;
+                        break "Ping timeout";
                     }
                     pong_received_send.store(false, std::sync::atomic::Ordering::Relaxed);
                     metrics::counter!("ws_messages_total", "direction" => "outbound", "type" => "ping").increment(1);
                     if let Err(e) = ws_sender.send(Message::Ping(vec![].into())).await {
                         error!("[{}] Ping send error: {}", cp_id_send, e);
-                        break;
+                        break "Send error";
                     }
                 }
             }
@@ -444,7 +482,10 @@ async fn handle_connection(
     let cp_id_recv = charge_point_id.clone();
     let session_reg = session_registry.clone();
     let recv_task = tokio::spawn(async move {
-        while let Some(msg) = ws_receiver.next().await {
+        let reason = loop {
+            let Some(msg) = ws_receiver.next().await else {
+                break "Connection closed";
+            };
             match msg {
                 Ok(Message::Text(text)) => {
                     // Extract OCPP unique_id for per-message correlation
@@ -465,7 +506,7 @@ async fn handle_connection(
                     if let Some(response) = adapter.handle_message(&text).await {
                         if let Err(e) = session_reg.send_to(&cp_id_recv, response) {
                             error!("[{}] Failed to send response: {}", cp_id_recv, e);
-                            break;
+                            break "Send error";
                         }
                     }
                 }
@@ -481,7 +522,7 @@ async fn handle_connection(
                 Ok(Message::Close(frame)) => {
                     info!("[{}] Close frame received: {:?}", cp_id_recv, frame);
                     metrics::counter!("ws_messages_total", "direction" => "inbound", "type" => "close").increment(1);
-                    break;
+                    break "Closed by charge point";
                 }
                 Ok(Message::Binary(data)) => {
                     warn!(
@@ -494,28 +535,43 @@ async fn handle_connection(
                 Ok(Message::Frame(_)) => {}
                 Err(e) => {
                     error!("[{}] WebSocket error: {}", cp_id_recv, e);
-                    break;
+                    break "WebSocket error";
                 }
             }
-        }
+        };
 
-        session_reg.unregister(&cp_id_recv);
+        // Leave a newer session that evicted this one in place
+        if session_reg.connected_since(&cp_id_recv) == connected_since {
+            session_reg.unregister(&cp_id_recv);
+        }
+        reason
     });
 
-    // Wait for tasks or shutdown
-    if let Some(shutdown) = shutdown {
+    // Wait for tasks or shutdown. The receive side is checked first: when
+    // it ends it unregisters the session, which also stops the send side.
+    let reason = if let Some(shutdown) = shutdown {
         tokio::select! {
-            _ = send_task => {},
-            _ = recv_task => {},
+            biased;
+            r = recv_task => r.unwrap_or("Receive task failed"),
+            r = send_task => r.unwrap_or("Send task failed"),
             _ = shutdown.notified().wait() => {
                 info!("[{}] Connection closing due to server shutdown", charge_point_id);
+                "Server shutdown"
             }
         }
     } else {
         tokio::select! {
-            _ = send_task => {},
-            _ = recv_task => {},
+            biased;
+            r = recv_task => r.unwrap_or("Receive task failed"),
+            r = send_task => r.unwrap_or("Send task failed"),
         }
+    };
+
+    // A newer connection evicted this one and already reported the disconnect
+    let current = session_registry.connected_since(&charge_point_id);
+    if current.is_some() && current != connected_since {
+        info!("[{}] Replaced by a newer connection", charge_point_id);
+        return Ok(());
     }
 
     // Cleanup
@@ -526,7 +582,7 @@ async fn handle_connection(
         ChargePointDisconnectedEvent {
             charge_point_id: charge_point_id.clone(),
             timestamp: Utc::now(),
-            reason: None,
+            reason: Some(reason.to_string()),
         },
     ));
 
@@ -636,43 +692,3 @@ fn extract_ocpp_unique_id(text: &str) -> Option<String> {
         .and_then(|arr| arr.get(1).cloned())
         .and_then(|v| v.as_str().map(String::from))
 }
-
-// ── WebSocket connection rate limiter ──────────────────────────
-
-/// Simple per-IP sliding-window rate limiter for WebSocket connections.
-///
-/// Tracks connection timestamps per IP and rejects new connections
-/// if the count within the last 60 seconds exceeds the configured limit.
-struct WsRateLimiter {
-    /// Map from IP address to list of connection timestamps within the window
-    connections: DashMap<IpAddr, Vec<Instant>>,
-    /// Maximum connections per minute per IP
-    max_per_minute: u32,
-}
-
-impl WsRateLimiter {
-    fn new(max_per_minute: u32) -> Self {
-        Self {
-            connections: DashMap::new(),
-            max_per_minute,
-        }
-    }
-
-    /// Check if a new connection from this IP is allowed.
-    /// Returns `true` if allowed, `false` if rate-limited.
-    fn check(&self, ip: IpAddr) -> bool {
-        let now = Instant::now();
-        let window = std::time::Duration::from_secs(60);
-
-        let mut entry = self.connections.entry(ip).or_default();
-        // Remove timestamps older than the window
-        entry.retain(|t| now.duration_since(*t) < window);
-
-        if entry.len() >= self.max_per_minute as usize {
-            false
-        } else {
-            entry.push(now);
-            true
-        }
-    }
-}
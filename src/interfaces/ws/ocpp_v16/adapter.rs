@@ -8,6 +8,9 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
+use crate::application::charging::quirks::SharedQuirkRegistry;
+use crate::application::charging::schema::SharedSchemaValidator;
+use crate::application::charging::services::data_transfer::SharedDataTransferService;
 use crate::application::events::SharedEventBus;
 use crate::application::ports::{OcppAdapterFactory, OcppInboundPort};
 use crate::application::OcppHandlerV16;
@@ -27,12 +30,16 @@ pub struct V16InboundAdapter {
 }
 
 impl V16InboundAdapter {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         charge_point_id: String,
         service: Arc<ChargePointService>,
         billing_service: Arc<BillingService>,
         command_sender: Arc<CommandSender>,
         event_bus: SharedEventBus,
+        schema_validator: SharedSchemaValidator,
+        quirks: SharedQuirkRegistry,
+        data_transfers: SharedDataTransferService,
     ) -> Self {
         let handler = Arc::new(OcppHandlerV16::new(
             charge_point_id.clone(),
@@ -40,6 +47,9 @@ impl V16InboundAdapter {
             billing_service,
             command_sender,
             event_bus,
+            schema_validator,
+            quirks,
+            data_transfers,
         ));
         Self {
             handler,
@@ -74,6 +84,9 @@ pub struct V16AdapterFactory {
     billing_service: Arc<BillingService>,
     command_sender: SharedCommandSender,
     event_bus: SharedEventBus,
+    schema_validator: SharedSchemaValidator,
+    quirks: SharedQuirkRegistry,
+    data_transfers: SharedDataTransferService,
 }
 
 impl V16AdapterFactory {
@@ -82,12 +95,18 @@ impl V16AdapterFactory {
         billing_service: Arc<BillingService>,
         command_sender: SharedCommandSender,
         event_bus: SharedEventBus,
+        schema_validator: SharedSchemaValidator,
+        quirks: SharedQuirkRegistry,
+        data_transfers: SharedDataTransferService,
     ) -> Self {
         Self {
             service,
             billing_service,
             command_sender,
             event_bus,
+            schema_validator,
+            quirks,
+            data_transfers,
         }
     }
 }
@@ -100,6 +119,9 @@ impl OcppAdapterFactory for V16AdapterFactory {
             self.billing_service.clone(),
             self.command_sender.clone(),
             self.event_bus.clone(),
+            self.schema_validator.clone(),
+            self.quirks.clone(),
+            self.data_transfers.clone(),
         ))
     }
 
@@ -8,11 +8,16 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
+use crate::application::charging::schema::SharedSchemaValidator;
+use crate::application::charging::services::data_transfer::SharedDataTransferService;
+use crate::application::charging::services::plug_and_charge::SharedPlugAndChargeService;
+use crate::application::charging::services::smart_charging::SharedSmartChargingService;
 use crate::application::events::SharedEventBus;
 use crate::application::ports::{OcppAdapterFactory, OcppInboundPort};
 use crate::application::OcppHandlerV201;
 use crate::application::{BillingService, ChargePointService};
 use crate::application::{CommandSender, SharedCommandSender};
+use crate::application::charging::services::customer_information::SharedCustomerInformationStore;
 use crate::application::charging::services::device_report::SharedDeviceReportStore;
 use crate::domain::OcppVersion;
 
@@ -28,6 +33,7 @@ pub struct V201InboundAdapter {
 }
 
 impl V201InboundAdapter {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         charge_point_id: String,
         service: Arc<ChargePointService>,
@@ -35,6 +41,11 @@ impl V201InboundAdapter {
         command_sender: Arc<CommandSender>,
         event_bus: SharedEventBus,
         report_store: SharedDeviceReportStore,
+        customer_info_store: SharedCustomerInformationStore,
+        schema_validator: SharedSchemaValidator,
+        data_transfers: SharedDataTransferService,
+        plug_and_charge: SharedPlugAndChargeService,
+        smart_charging: SharedSmartChargingService,
     ) -> Self {
         let handler = Arc::new(OcppHandlerV201::new(
             charge_point_id.clone(),
@@ -43,6 +54,11 @@ impl V201InboundAdapter {
             command_sender,
             event_bus,
             report_store,
+            customer_info_store,
+            schema_validator,
+            data_transfers,
+            plug_and_charge,
+            smart_charging,
         ));
         Self {
             handler,
@@ -78,15 +94,26 @@ pub struct V201AdapterFactory {
     command_sender: SharedCommandSender,
     event_bus: SharedEventBus,
     report_store: SharedDeviceReportStore,
+    customer_info_store: SharedCustomerInformationStore,
+    schema_validator: SharedSchemaValidator,
+    data_transfers: SharedDataTransferService,
+    plug_and_charge: SharedPlugAndChargeService,
+    smart_charging: SharedSmartChargingService,
 }
 
 impl V201AdapterFactory {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         service: Arc<ChargePointService>,
         billing_service: Arc<BillingService>,
         command_sender: SharedCommandSender,
         event_bus: SharedEventBus,
         report_store: SharedDeviceReportStore,
+        customer_info_store: SharedCustomerInformationStore,
+        schema_validator: SharedSchemaValidator,
+        data_transfers: SharedDataTransferService,
+        plug_and_charge: SharedPlugAndChargeService,
+        smart_charging: SharedSmartChargingService,
     ) -> Self {
         Self {
             service,
@@ -94,6 +121,11 @@ impl V201AdapterFactory {
             command_sender,
             event_bus,
             report_store,
+            customer_info_store,
+            schema_validator,
+            data_transfers,
+            plug_and_charge,
+            smart_charging,
         }
     }
 }
@@ -107,6 +139,11 @@ impl OcppAdapterFactory for V201AdapterFactory {
             self.command_sender.clone(),
             self.event_bus.clone(),
             self.report_store.clone(),
+            self.customer_info_store.clone(),
+            self.schema_validator.clone(),
+            self.data_transfers.clone(),
+            self.plug_and_charge.clone(),
+            self.smart_charging.clone(),
         ))
     }
 
@@ -8,8 +8,9 @@
 //! - **domain**: Core business entities, traits, and value objects
 //! - **application**: Use-case orchestration, commands, events, DTOs
 //! - **infrastructure**: External concerns (database, crypto)
-//! - **interfaces**: Delivery mechanisms (HTTP REST, WebSocket, gRPC placeholder)
+//! - **interfaces**: Delivery mechanisms (HTTP REST, WebSocket, gRPC)
 //! - **config**: Application configuration (TOML-based)
+//! - **simulator**: Simulated charge points for testing and load generation
 
 pub mod application;
 pub mod config;
@@ -17,6 +18,7 @@ pub mod domain;
 pub mod infrastructure;
 pub mod interfaces;
 pub mod shared;
+pub mod simulator;
 
 // Re-export commonly used types at crate root
 pub use application::events::{create_event_bus, Event, EventBus, SharedEventBus};
@@ -10,18 +10,44 @@ use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 
 use metrics_exporter_prometheus;
-use texnouz_ocpp::application::commands::{create_command_dispatcher, create_command_sender};
-use texnouz_ocpp::application::services::{BillingService, ChargePointService, HeartbeatMonitor};
+use texnouz_ocpp::application::commands::{
+    create_command_dispatcher, create_command_sender, SharedCommandSender,
+};
+use texnouz_ocpp::application::services::{
+    BillingService, ChargePointService, HeartbeatConfig, HeartbeatIntervalSync, HeartbeatMonitor,
+    SharedHeartbeatConfig,
+};
+use texnouz_ocpp::application::charging::services::customer_information::CustomerInformationStore;
+use texnouz_ocpp::application::charging::services::data_transfer::{
+    DataTransferRegistry, DataTransferService,
+};
 use texnouz_ocpp::application::charging::services::device_report::DeviceReportStore;
-use texnouz_ocpp::application::session::SessionRegistry;
+use texnouz_ocpp::application::charging::services::plug_and_charge::{
+    ContractRoot, LocalCertificatePool, LocalOcspResponder, PlugAndChargeService,
+};
+use texnouz_ocpp::application::charging::services::smart_charging::{
+    SmartChargingService, SmartChargingSettings,
+};
+use texnouz_ocpp::application::charging::quirks::{QuirkProfile, QuirkRegistry};
+use texnouz_ocpp::application::charging::schema::{SchemaValidationMode, SchemaValidator};
+use texnouz_ocpp::application::cluster::SharedClusterNode;
+use texnouz_ocpp::application::retention::{start_archival_task, ArchiveService, ArchiveSettings};
+use texnouz_ocpp::application::session::{SessionRegistry, SharedSessionRegistry};
+use texnouz_ocpp::application::SharedEventBus;
+use texnouz_ocpp::config::reload::ConfigReloader;
 use texnouz_ocpp::config::AppConfig;
 use texnouz_ocpp::domain::OcppVersion;
+use texnouz_ocpp::infrastructure::archive::JsonlArchiveStore;
 use texnouz_ocpp::infrastructure::crypto::jwt::JwtConfig;
 use texnouz_ocpp::infrastructure::database::migrator::Migrator;
+use texnouz_ocpp::interfaces::grpc::{self, GrpcState};
+use texnouz_ocpp::interfaces::http::middleware::AuthState;
+use texnouz_ocpp::interfaces::mqtt::MqttBridge;
+use texnouz_ocpp::interfaces::ocpi::{OcpiClient, OcpiPushService};
 use texnouz_ocpp::interfaces::ws::{
     OcppServer, ProtocolAdapters, V16AdapterFactory, V201AdapterFactory,
 };
-use texnouz_ocpp::shared::shutdown::ShutdownCoordinator;
+use texnouz_ocpp::shared::shutdown::{ShutdownCoordinator, ShutdownSignal};
 use texnouz_ocpp::{
     create_api_router, create_event_bus, default_config_path, init_database, Config,
     DatabaseConfig, SeaOrmRepositoryProvider,
@@ -33,11 +59,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let config_path = std::env::var("OCPP_CONFIG")
         .map(std::path::PathBuf::from)
         .unwrap_or_else(|_| default_config_path());
-    let app_cfg = match AppConfig::load(&config_path) {
+    let (app_cfg, log_filter) = match AppConfig::load(&config_path) {
         Ok(cfg) => {
-            // Initialize logging with configured level and format
-            let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(&cfg.logging.level));
+            // Initialize logging with configured level and format. The level
+            // follows configuration reloads unless RUST_LOG sets it.
+            let from_env = tracing_subscriber::EnvFilter::try_from_default_env().ok();
+            let follows_config = from_env.is_none();
+            let (env_filter, log_filter) = tracing_subscriber::reload::Layer::new(
+                from_env.unwrap_or_else(|| tracing_subscriber::EnvFilter::new(&cfg.logging.level)),
+            );
 
             match cfg.logging.format.to_lowercase().as_str() {
                 "json" => {
@@ -55,17 +85,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
 
             info!("Configuration loaded from {}", config_path.display());
-            cfg
+            (cfg, follows_config.then_some(log_filter))
         }
         Err(e) => {
             tracing_subscriber::fmt()
                 .with_env_filter(tracing_subscriber::EnvFilter::new("info"))
                 .init();
             error!("Failed to load config: {}. Using defaults.", e);
-            AppConfig::default()
+            (AppConfig::default(), None)
         }
     };
 
+    // Settings that apply without a restart follow this reloader
+    let config_reloader = Arc::new(ConfigReloader::new(config_path.clone(), app_cfg.clone()));
+    if let Some(log_filter) = log_filter {
+        config_reloader.on_change(move |cfg| {
+            let level = tracing_subscriber::EnvFilter::new(&cfg.logging.level);
+            if let Err(e) = log_filter.modify(|filter| *filter = level) {
+                warn!("Failed to change the log level: {}", e);
+            }
+        });
+    }
+
     info!("Starting Texnouz OCPP Central System...");
 
     // ── Prometheus metrics recorder (must be installed before any metrics calls) ──
@@ -115,8 +156,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let repos: Arc<dyn texnouz_ocpp::domain::RepositoryProvider> =
         Arc::new(SeaOrmRepositoryProvider::new(db.clone()));
 
+    // Heartbeat intervals and thresholds, replaced by configuration reloads
+    let heartbeat_config = SharedHeartbeatConfig::new(HeartbeatConfig::from(&app_cfg));
+
     // Initialize services
-    let service = Arc::new(ChargePointService::new(repos.clone()));
+    let service = Arc::new(
+        ChargePointService::new(repos.clone()).with_heartbeat_config(heartbeat_config.clone()),
+    );
     let billing_service = Arc::new(BillingService::new(repos.clone()));
 
     // Initialize event bus for real-time notifications
@@ -125,15 +171,36 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // ── Session & Command infrastructure (shared across WS + API) ──
     let session_registry = SessionRegistry::shared();
-    let command_sender = create_command_sender(session_registry.clone());
+    let schema_validator = Arc::new(SchemaValidator::new(SchemaValidationMode::from_config(
+        &app_cfg.ocpp.schema_validation,
+    )));
+    info!("📐 OCPP schema validation: {:?}", schema_validator.mode());
+    let command_sender = create_command_sender(session_registry.clone(), schema_validator.clone());
     let command_dispatcher = create_command_dispatcher(command_sender.clone(), session_registry.clone());
 
+    let quirks = QuirkRegistry::shared(app_cfg.ocpp.quirks.iter().map(QuirkProfile::from).collect());
+    if !quirks.is_empty() {
+        info!("🩹 {} vendor quirk profile(s) loaded", quirks.len());
+    }
+
+    // Vendor DataTransfer extensions register here, e.g.
+    // `data_transfer_registry.register("com.vendor", Some("QrPayment"), Arc::new(handler));`
+    let data_transfer_registry = DataTransferRegistry::new();
+    let data_transfers = Arc::new(DataTransferService::new(
+        data_transfer_registry,
+        repos.clone(),
+        event_bus.clone(),
+    ));
+
     // ── Protocol adapters (one per supported OCPP version) ─────
     let v16_factory = Arc::new(V16AdapterFactory::new(
         service.clone(),
         billing_service.clone(),
         command_sender.clone(),
         event_bus.clone(),
+        schema_validator.clone(),
+        quirks,
+        data_transfers.clone(),
     ));
 
     let mut protocol_adapters = ProtocolAdapters::new();
@@ -141,6 +208,43 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // ── OCPP 2.0.1 adapter ────────────────────────────────────
     let device_report_store = Arc::new(DeviceReportStore::new());
+    let customer_info_store = Arc::new(CustomerInformationStore::new());
+
+    let iso15118 = &app_cfg.iso15118;
+    let contract_roots = iso15118
+        .contract_roots
+        .iter()
+        .map(ContractRoot::from)
+        .collect();
+    let mut plug_and_charge = PlugAndChargeService::new(contract_roots)
+        .accept_unknown_revocation(iso15118.accept_unknown_revocation);
+    if iso15118.local_pki {
+        warn!("⚠️ Plug & Charge uses the local development PKI stand-ins");
+        plug_and_charge = plug_and_charge
+            .with_certificate_pool(Arc::new(LocalCertificatePool::new()))
+            .with_ocsp_responder(Arc::new(LocalOcspResponder::new(
+                iso15118.revoked_serial_numbers.iter().cloned(),
+            )));
+    }
+    if !plug_and_charge.contract_roots().is_empty() {
+        if plug_and_charge.has_ocsp_responder() || iso15118.accept_unknown_revocation {
+            info!(
+                "🔏 Plug & Charge enabled with {} contract root(s)",
+                plug_and_charge.contract_roots().len()
+            );
+        } else {
+            warn!(
+                "🔏 Plug & Charge has no OCSP responder; contract authorizations will be rejected"
+            );
+        }
+    }
+    let plug_and_charge = Arc::new(plug_and_charge);
+
+    let smart_charging = Arc::new(SmartChargingService::new(
+        repos.clone(),
+        command_sender.clone(),
+        SmartChargingSettings::from(&app_cfg.smart_charging),
+    ));
 
     let v201_factory = Arc::new(V201AdapterFactory::new(
         service.clone(),
@@ -148,6 +252,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         command_sender.clone(),
         event_bus.clone(),
         device_report_store.clone(),
+        customer_info_store.clone(),
+        schema_validator.clone(),
+        data_transfers,
+        plug_and_charge,
+        smart_charging,
     ));
     protocol_adapters.register(OcppVersion::V201, v201_factory);
     // Future: protocol_adapters.register(OcppVersion::V21,  v21_factory);
@@ -160,6 +269,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Start listening for shutdown signals (SIGTERM, SIGINT)
     shutdown.start_signal_listener();
 
+    // ── Cluster mode (before stations can connect) ─────────────
+    let cluster_node = if app_cfg.cluster.enabled {
+        match join_cluster(
+            &app_cfg,
+            &db,
+            &session_registry,
+            &command_sender,
+            &event_bus,
+            &shutdown_signal,
+        )
+        .await
+        {
+            Ok(node) => Some(node),
+            Err(e) => {
+                error!("Failed to join cluster: {}", e);
+                return Err(e);
+            }
+        }
+    } else {
+        None
+    };
+
     // Create unified OCPP WebSocket server with shutdown support
     let server = OcppServer::new(
         config.clone(),
@@ -171,22 +302,143 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         app_cfg.rate_limit.ws_connections_per_minute,
         app_cfg.ws_auth.clone(),
     )
+    .with_live_config(config_reloader.subscribe())
     .with_shutdown(shutdown_signal.clone());
 
-    // Start Heartbeat Monitor
-    let heartbeat_monitor = Arc::new(HeartbeatMonitor::new(
+    // Heartbeat Monitor, started with the other background tasks below
+    let heartbeat_monitor = Arc::new(
+        HeartbeatMonitor::new(repos.clone(), session_registry.clone())
+            .with_shared_config(heartbeat_config.clone()),
+    );
+
+    // Push changed heartbeat intervals to the stations connected here
+    let heartbeat_interval_sync = Arc::new(HeartbeatIntervalSync::new(
         repos.clone(),
         session_registry.clone(),
+        command_dispatcher.clone(),
+        heartbeat_config.clone(),
     ));
-    heartbeat_monitor.start(shutdown_signal.clone());
+    heartbeat_interval_sync
+        .clone()
+        .start(event_bus.clone(), shutdown_signal.clone());
+    config_reloader.on_change(move |cfg| {
+        heartbeat_config.set(HeartbeatConfig::from(cfg));
+        let sync = heartbeat_interval_sync.clone();
+        tokio::spawn(async move { sync.sync_connected().await });
+    });
 
-    // Start Reservation Expiry Task
-    texnouz_ocpp::application::charging::services::start_reservation_expiry_task(
+    // Record connection history for availability reports. Cluster nodes
+    // cannot tell a crashed peer's open sessions from live ones.
+    texnouz_ocpp::application::charging::services::start_connection_history_task(
         repos.clone(),
+        event_bus.clone(),
         shutdown_signal.clone(),
-        60, // check every 60 seconds
+        !app_cfg.cluster.enabled,
     );
 
+    // Archive data past its retention; runs can also be started via the API
+    let archive_service = Arc::new(ArchiveService::new(
+        repos.clone(),
+        Arc::new(JsonlArchiveStore::new(&app_cfg.retention.archive_dir)),
+        ArchiveSettings::from(&app_cfg.retention),
+    ));
+
+    // Background tasks that must run on one node only: heartbeat monitor,
+    // reservation expiry, reservation scheduler (booked slots) and
+    // scheduled archival. A cluster runs them on its elected leader.
+    let start_background_tasks = {
+        let heartbeat_monitor = heartbeat_monitor.clone();
+        let repos = repos.clone();
+        let command_dispatcher = command_dispatcher.clone();
+        let event_bus = event_bus.clone();
+        let archive_service = archive_service.clone();
+        let reservations = app_cfg.reservations.clone();
+        let retention = app_cfg.retention.clone();
+        move |shutdown: ShutdownSignal| {
+            heartbeat_monitor.start(shutdown.clone());
+            texnouz_ocpp::application::charging::services::start_reservation_expiry_task(
+                repos.clone(),
+                event_bus.clone(),
+                shutdown.clone(),
+                60, // check every 60 seconds
+            );
+            texnouz_ocpp::application::charging::services::start_reservation_scheduler_task(
+                repos.clone(),
+                command_dispatcher.clone(),
+                event_bus.clone(),
+                shutdown.clone(),
+                reservations.activation_lead_secs,
+                reservations.check_interval_secs,
+            );
+            if retention.enabled {
+                start_archival_task(
+                    archive_service.clone(),
+                    shutdown,
+                    retention.check_interval_secs,
+                );
+            }
+        }
+    };
+    match &cluster_node {
+        None => start_background_tasks(shutdown_signal.clone()),
+        Some(node) if app_cfg.cluster.run_background_tasks => node.lead(
+            shutdown_signal.clone(),
+            std::time::Duration::from_secs(app_cfg.cluster.heartbeat_interval_secs),
+            start_background_tasks,
+        ),
+        Some(_) => info!("🕸️ Background tasks run on another cluster node"),
+    }
+
+    // Start OCPI push to registered eMSPs
+    if app_cfg.ocpi.enabled && app_cfg.ocpi.push_enabled {
+        OcpiPushService::new(
+            repos.clone(),
+            Arc::new(app_cfg.ocpi.clone()),
+            OcpiClient::new(),
+        )
+        .start(event_bus.clone(), shutdown_signal.clone());
+    }
+
+    // Start the gRPC API alongside REST; it authenticates the same way
+    if app_cfg.grpc.enabled {
+        let grpc_addr = format!("{}:{}", app_cfg.grpc.host, app_cfg.grpc.port);
+        let listener = tokio::net::TcpListener::bind(&grpc_addr).await?;
+        let grpc_state = GrpcState {
+            repos: repos.clone(),
+            session_registry: session_registry.clone(),
+            commands: command_dispatcher.clone(),
+            event_bus: event_bus.clone(),
+            auth: AuthState {
+                jwt_config: jwt_config.clone(),
+                db: db.clone(),
+            },
+        };
+        let grpc_shutdown = shutdown_signal.clone();
+        tokio::spawn(async move {
+            if let Err(e) = grpc::serve(listener, grpc_state, grpc_shutdown).await {
+                error!("gRPC server error: {}", e);
+            }
+        });
+    }
+
+    // Bridge events and station commands to an MQTT broker
+    if app_cfg.mqtt.enabled {
+        MqttBridge::new(
+            app_cfg.mqtt.clone(),
+            command_dispatcher.clone(),
+            session_registry.clone(),
+        )
+        .start(event_bus.clone(), shutdown_signal.clone());
+    }
+
+    // Apply edits to the configuration file without a restart
+    if app_cfg.server.config_reload_interval_secs > 0 {
+        config_reloader.clone().start_watcher(
+            shutdown_signal.clone(),
+            std::time::Duration::from_secs(app_cfg.server.config_reload_interval_secs),
+        );
+    }
+
     // Create REST API router
     let api_router = create_api_router(
         repos,
@@ -198,9 +450,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         event_bus,
         service,
         billing_service,
-        &app_cfg,
+        config_reloader,
         prometheus_handle,
         device_report_store,
+        customer_info_store,
+        archive_service,
     );
 
     // Start REST API server with graceful shutdown
@@ -257,6 +511,54 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Join the cluster over the shared PostgreSQL database.
+#[cfg(feature = "postgres")]
+async fn join_cluster(
+    app_cfg: &AppConfig,
+    db: &sea_orm::DatabaseConnection,
+    session_registry: &SharedSessionRegistry,
+    command_sender: &SharedCommandSender,
+    event_bus: &SharedEventBus,
+    shutdown: &ShutdownSignal,
+) -> Result<SharedClusterNode, Box<dyn std::error::Error>> {
+    use texnouz_ocpp::application::cluster::ClusterNode;
+    use texnouz_ocpp::infrastructure::cluster::PgClusterBackplane;
+
+    let node_id = if app_cfg.cluster.node_id.is_empty() {
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+        format!("node-{}", &suffix[..8])
+    } else {
+        app_cfg.cluster.node_id.clone()
+    };
+
+    let backplane = Arc::new(PgClusterBackplane::new(db.clone()));
+    let node = Arc::new(ClusterNode::new(node_id, backplane, session_registry.clone()));
+    command_sender.attach_cluster(node.clone());
+    node.start(
+        command_sender.clone(),
+        event_bus.clone(),
+        shutdown.clone(),
+        std::time::Duration::from_secs(app_cfg.cluster.heartbeat_interval_secs),
+        std::time::Duration::from_secs(app_cfg.cluster.node_timeout_secs),
+    )
+    .await?;
+
+    info!("🕸️ Cluster mode: this is node '{}'", node.node_id());
+    Ok(node)
+}
+
+#[cfg(not(feature = "postgres"))]
+async fn join_cluster(
+    _app_cfg: &AppConfig,
+    _db: &sea_orm::DatabaseConnection,
+    _session_registry: &SharedSessionRegistry,
+    _command_sender: &SharedCommandSender,
+    _event_bus: &SharedEventBus,
+    _shutdown: &ShutdownSignal,
+) -> Result<SharedClusterNode, Box<dyn std::error::Error>> {
+    Err("cluster mode requires a build with the 'postgres' feature".into())
+}
+
 /// Create default admin user if no users exist
 async fn create_default_admin(db: &sea_orm::DatabaseConnection, app_cfg: &AppConfig) {
     use sea_orm::{ActiveModelTrait, EntityTrait, PaginatorTrait, Set};
@@ -1,8 +1,10 @@
+pub mod rate_limit;
 pub mod shutdown;
 pub mod types;
 pub mod utills;
 pub mod validations;
 
+pub use rate_limit::*;
 pub use shutdown::*;
 pub use types::*;
 pub use utills::*;
@@ -0,0 +1,115 @@
+//! Per-IP rate limiting
+//!
+//! A sliding one-minute window per client IP. The limit is passed on every
+//! check, so it follows configuration reloads without losing the window.
+//! IPs without events in the last minute are dropped about once a minute,
+//! so memory stays bounded by the clients seen recently.
+
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use dashmap::DashMap;
+use tokio::time::Instant;
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Sliding-window limiter counting events per IP over the last minute.
+#[derive(Default)]
+pub struct RateLimiter {
+    /// Event timestamps per IP within the window
+    events: DashMap<IpAddr, Vec<Instant>>,
+    /// When idle IPs were last dropped
+    last_sweep: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an event from `ip` if fewer than `max_per_minute` happened in
+    /// the last minute. Returns how many remain, or how long until the
+    /// next one is allowed.
+    pub fn check(&self, ip: IpAddr, max_per_minute: u32) -> Result<u32, Duration> {
+        let now = Instant::now();
+        self.sweep_if_due(now);
+
+        let mut entry = self.events.entry(ip).or_default();
+        // Remove timestamps older than the window
+        entry.retain(|t| now.duration_since(*t) < WINDOW);
+
+        let max = max_per_minute as usize;
+        if entry.len() >= max {
+            let oldest = entry.len() - max;
+            let retry_after = entry
+                .get(oldest)
+                .map(|t| WINDOW.saturating_sub(now.duration_since(*t)))
+                .unwrap_or(WINDOW);
+            Err(retry_after)
+        } else {
+            entry.push(now);
+            Ok((max - entry.len()) as u32)
+        }
+    }
+
+    /// Drop IPs with no events in the window before `now`.
+    pub fn sweep(&self, now: Instant) {
+        self.events.retain(|_, events| {
+            events.retain(|t| now.duration_since(*t) < WINDOW);
+            !events.is_empty()
+        });
+    }
+
+    fn sweep_if_due(&self, now: Instant) {
+        {
+            let Ok(mut last) = self.last_sweep.lock() else {
+                return;
+            };
+            if last.is_some_and(|t| now.duration_since(t) < WINDOW) {
+                return;
+            }
+            *last = Some(now);
+        }
+        self.sweep(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_each_ip_within_the_window() {
+        let limiter = RateLimiter::new();
+        let ip: IpAddr = "10.0.0.1".parse().unwrap();
+        let other: IpAddr = "10.0.0.2".parse().unwrap();
+
+        assert_eq!(limiter.check(ip, 2), Ok(1));
+        assert_eq!(limiter.check(ip, 2), Ok(0));
+        let retry_after = limiter.check(ip, 2).unwrap_err();
+        assert!(retry_after > Duration::from_secs(59) && retry_after <= WINDOW);
+        assert_eq!(limiter.check(other, 2), Ok(1));
+
+        // A raised limit applies to the same window
+        assert_eq!(limiter.check(ip, 3), Ok(0));
+        assert!(limiter.check(ip, 3).is_err());
+    }
+
+    #[test]
+    fn idle_ips_are_dropped() {
+        let limiter = RateLimiter::new();
+        let idle: IpAddr = "10.0.0.1".parse().unwrap();
+        let active: IpAddr = "10.0.0.2".parse().unwrap();
+
+        assert!(limiter.check(idle, 5).is_ok());
+        limiter.sweep(Instant::now());
+        assert_eq!(limiter.events.len(), 1, "events within the window are kept");
+
+        let later = Instant::now() + WINDOW;
+        limiter.events.entry(active).or_default().push(later);
+        limiter.sweep(later);
+        assert_eq!(limiter.events.len(), 1);
+        assert!(!limiter.events.contains_key(&idle));
+    }
+}
@@ -0,0 +1,217 @@
+//! OCPP-J WebSocket client
+//!
+//! Connects to the Central System with the requested subprotocol (and
+//! optional Basic Auth), correlates outgoing Calls with their
+//! CallResult/CallError and hands incoming Calls to the station.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use base64::Engine;
+use dashmap::DashMap;
+use futures_util::{SinkExt, StreamExt};
+use serde_json::Value;
+use tokio::sync::{mpsc, oneshot};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, warn};
+
+use super::config::StationConfig;
+use super::error::{SimulatorError, SimulatorResult};
+use crate::shared::types::ocpp_frame::OcppFrame;
+
+/// A Call received from the Central System.
+#[derive(Debug, Clone)]
+pub struct IncomingCall {
+    pub unique_id: String,
+    pub action: String,
+    pub payload: Value,
+}
+
+type CallOutcome = Result<Value, (String, String)>;
+
+/// Cloneable handle to a station's WebSocket connection.
+#[derive(Clone)]
+pub struct OcppClient {
+    outbound: mpsc::UnboundedSender<Message>,
+    pending: Arc<DashMap<String, oneshot::Sender<CallOutcome>>>,
+    connected: Arc<AtomicBool>,
+    call_timeout: Duration,
+}
+
+impl OcppClient {
+    /// Open the WebSocket connection; incoming Calls are delivered on the
+    /// returned receiver.
+    pub async fn connect(
+        config: &StationConfig,
+    ) -> SimulatorResult<(Self, mpsc::UnboundedReceiver<IncomingCall>)> {
+        let mut request = config
+            .endpoint()
+            .into_client_request()
+            .map_err(|e| SimulatorError::Connect(e.to_string()))?;
+        let headers = request.headers_mut();
+        headers.insert(
+            "Sec-WebSocket-Protocol",
+            HeaderValue::from_static(config.version.subprotocol()),
+        );
+        if let Some(password) = &config.password {
+            let credentials = base64::engine::general_purpose::STANDARD
+                .encode(format!("{}:{}", config.charge_point_id, password));
+            let value = HeaderValue::from_str(&format!("Basic {}", credentials))
+                .map_err(|e| SimulatorError::Connect(e.to_string()))?;
+            headers.insert("Authorization", value);
+        }
+
+        let (ws, _) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(|e| SimulatorError::Connect(e.to_string()))?;
+        let (mut sink, mut stream) = ws.split();
+
+        let (out_tx, mut out_rx) = mpsc::unbounded_channel::<Message>();
+        let (in_tx, in_rx) = mpsc::unbounded_channel();
+        let pending: Arc<DashMap<String, oneshot::Sender<CallOutcome>>> = Arc::new(DashMap::new());
+        let connected = Arc::new(AtomicBool::new(true));
+
+        tokio::spawn(async move {
+            while let Some(msg) = out_rx.recv().await {
+                let closing = matches!(msg, Message::Close(_));
+                if sink.send(msg).await.is_err() || closing {
+                    break;
+                }
+            }
+        });
+
+        let reader_pending = pending.clone();
+        let reader_connected = connected.clone();
+        let charge_point_id = config.charge_point_id.clone();
+        tokio::spawn(async move {
+            while let Some(msg) = stream.next().await {
+                let text = match msg {
+                    Ok(Message::Text(text)) => text,
+                    Ok(Message::Close(_)) | Err(_) => break,
+                    Ok(_) => continue,
+                };
+                match OcppFrame::parse(&text) {
+                    Ok(OcppFrame::Call {
+                        unique_id,
+                        action,
+                        payload,
+                    }) => {
+                        let _ = in_tx.send(IncomingCall {
+                            unique_id,
+                            action,
+                            payload,
+                        });
+                    }
+                    Ok(OcppFrame::CallResult { unique_id, payload }) => {
+                        if let Some((_, tx)) = reader_pending.remove(&unique_id) {
+                            let _ = tx.send(Ok(payload));
+                        }
+                    }
+                    Ok(OcppFrame::CallError {
+                        unique_id,
+                        error_code,
+                        error_description,
+                        ..
+                    }) => {
+                        if let Some((_, tx)) = reader_pending.remove(&unique_id) {
+                            let _ = tx.send(Err((error_code, error_description)));
+                        }
+                    }
+                    Err(e) => warn!(charge_point_id, error = %e, "Simulator: invalid frame"),
+                }
+            }
+            reader_connected.store(false, Ordering::SeqCst);
+            // Dropping the senders fails every in-flight call with `Closed`
+            reader_pending.clear();
+            debug!(charge_point_id, "Simulator: connection closed");
+        });
+
+        Ok((
+            Self {
+                outbound: out_tx,
+                pending,
+                connected,
+                call_timeout: config.call_timeout,
+            },
+            in_rx,
+        ))
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    /// Send a Call and wait for its CallResult payload.
+    pub async fn call(&self, action: &str, payload: Value) -> SimulatorResult<Value> {
+        let unique_id = uuid::Uuid::new_v4().to_string();
+        let (tx, rx) = oneshot::channel();
+        self.pending.insert(unique_id.clone(), tx);
+
+        let frame = OcppFrame::Call {
+            unique_id: unique_id.clone(),
+            action: action.to_string(),
+            payload,
+        };
+        if self
+            .outbound
+            .send(Message::Text(frame.serialize()))
+            .is_err()
+        {
+            self.pending.remove(&unique_id);
+            return Err(SimulatorError::Closed);
+        }
+
+        match tokio::time::timeout(self.call_timeout, rx).await {
+            Ok(Ok(Ok(payload))) => Ok(payload),
+            Ok(Ok(Err((code, description)))) => Err(SimulatorError::CallError {
+                action: action.to_string(),
+                code,
+                description,
+            }),
+            Ok(Err(_)) => Err(SimulatorError::Closed),
+            Err(_) => {
+                self.pending.remove(&unique_id);
+                Err(SimulatorError::Timeout {
+                    action: action.to_string(),
+                })
+            }
+        }
+    }
+
+    /// Answer an incoming Call with a CallResult.
+    pub fn respond(&self, unique_id: &str, payload: Value) -> SimulatorResult<()> {
+        let frame = OcppFrame::CallResult {
+            unique_id: unique_id.to_string(),
+            payload,
+        };
+        self.send_frame(frame)
+    }
+
+    /// Answer an incoming Call with a CallError.
+    pub fn respond_error(
+        &self,
+        unique_id: &str,
+        error_code: &str,
+        description: &str,
+    ) -> SimulatorResult<()> {
+        self.send_frame(OcppFrame::error_response(
+            unique_id,
+            error_code,
+            description,
+        ))
+    }
+
+    /// Close the WebSocket connection.
+    pub fn close(&self) {
+        let _ = self.outbound.send(Message::Close(None));
+    }
+
+    fn send_frame(&self, frame: OcppFrame) -> SimulatorResult<()> {
+        self.outbound
+            .send(Message::Text(frame.serialize()))
+            .map_err(|_| SimulatorError::Closed)
+    }
+}
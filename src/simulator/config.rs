@@ -0,0 +1,95 @@
+//! Simulator configuration
+
+use std::time::Duration;
+
+use crate::domain::OcppVersion;
+
+/// Identity and connection settings of one simulated station.
+#[derive(Debug, Clone)]
+pub struct StationConfig {
+    /// Base WebSocket URL, e.g. `ws://127.0.0.1:9000/ocpp`; the charge
+    /// point ID is appended as the last path segment.
+    pub server_url: String,
+    pub charge_point_id: String,
+    pub version: OcppVersion,
+    /// Basic Auth password (Security Profile 1); `None` sends no header.
+    pub password: Option<String>,
+    pub vendor: String,
+    pub model: String,
+    pub serial_number: Option<String>,
+    pub firmware_version: String,
+    /// Number of connectors (v1.6) / EVSEs with one connector each (v2.0.1).
+    pub connectors: u32,
+    /// Maximum power a connector can deliver, in W.
+    pub max_power_w: f64,
+    /// How long to wait for a CallResult before giving up.
+    pub call_timeout: Duration,
+}
+
+impl StationConfig {
+    pub fn new(
+        server_url: impl Into<String>,
+        charge_point_id: impl Into<String>,
+        version: OcppVersion,
+    ) -> Self {
+        Self {
+            server_url: server_url.into(),
+            charge_point_id: charge_point_id.into(),
+            version,
+            password: None,
+            vendor: "Texnouz".to_string(),
+            model: "Simulator".to_string(),
+            serial_number: None,
+            firmware_version: env!("CARGO_PKG_VERSION").to_string(),
+            connectors: 2,
+            max_power_w: 22_000.0,
+            call_timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Full URL of this station's WebSocket endpoint.
+    pub fn endpoint(&self) -> String {
+        format!(
+            "{}/{}",
+            self.server_url.trim_end_matches('/'),
+            self.charge_point_id
+        )
+    }
+}
+
+/// Parameters of a scripted charging session
+/// (Authorize → start → periodic MeterValues → stop).
+#[derive(Debug, Clone)]
+pub struct SessionScript {
+    pub id_tag: String,
+    pub connector_id: u32,
+    /// Time between start and stop.
+    pub duration: Duration,
+    /// Interval between MeterValues / TransactionEvent(Updated) messages.
+    pub meter_interval: Duration,
+    /// Simulated charging power, in W.
+    pub power_w: f64,
+}
+
+impl Default for SessionScript {
+    fn default() -> Self {
+        Self {
+            id_tag: "SIMTAG01".to_string(),
+            connector_id: 1,
+            duration: Duration::from_secs(60),
+            meter_interval: Duration::from_secs(10),
+            power_w: 11_000.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_appends_charge_point_id() {
+        let config = StationConfig::new("ws://localhost:9000/ocpp/", "SIM-0001", OcppVersion::V16);
+        assert_eq!(config.endpoint(), "ws://localhost:9000/ocpp/SIM-0001");
+    }
+}
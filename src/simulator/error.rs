@@ -0,0 +1,31 @@
+//! Simulator errors
+
+use thiserror::Error;
+
+/// Errors raised by a simulated charge point.
+#[derive(Debug, Error)]
+pub enum SimulatorError {
+    #[error("Connection failed: {0}")]
+    Connect(String),
+
+    #[error("Connection closed")]
+    Closed,
+
+    #[error("{action} timed out")]
+    Timeout { action: String },
+
+    #[error("{action} failed with CallError {code}: {description}")]
+    CallError {
+        action: String,
+        code: String,
+        description: String,
+    },
+
+    #[error("Unexpected {action} response: {reason}")]
+    InvalidResponse { action: String, reason: String },
+
+    #[error("{0}")]
+    Rejected(String),
+}
+
+pub type SimulatorResult<T> = Result<T, SimulatorError>;
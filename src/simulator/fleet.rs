@@ -0,0 +1,230 @@
+//! Fleet runner for load tests
+//!
+//! Starts many simulated stations concurrently (optionally ramped up),
+//! boots each one, runs its scripted sessions and keeps it connected —
+//! answering commands — until the hold time elapses or shutdown is
+//! triggered.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tracing::warn;
+
+use super::config::{SessionScript, StationConfig};
+use super::station::SimulatedChargePoint;
+use crate::shared::shutdown::ShutdownSignal;
+
+#[derive(Debug, Clone)]
+pub struct FleetConfig {
+    /// Template for every station; its `charge_point_id` is used as-is for
+    /// a single station and as a prefix (`SIM-0001`, …) otherwise.
+    pub station: StationConfig,
+    pub stations: u32,
+    /// Delay between starting consecutive stations.
+    pub ramp_up: Duration,
+    /// Scripted sessions per station, cycling over its connectors.
+    pub sessions_per_station: u32,
+    pub script: SessionScript,
+    pub pause_between_sessions: Duration,
+    /// How long to stay connected after the sessions; `None` = until shutdown.
+    pub hold: Option<Duration>,
+}
+
+impl FleetConfig {
+    pub fn station_id(&self, index: u32) -> String {
+        if self.stations == 1 {
+            self.station.charge_point_id.clone()
+        } else {
+            format!("{}{:04}", self.station.charge_point_id, index + 1)
+        }
+    }
+}
+
+/// Live counters shared by all stations of a fleet.
+#[derive(Debug, Default)]
+pub struct FleetStats {
+    pub connected: AtomicU64,
+    pub connect_failures: AtomicU64,
+    pub boots_accepted: AtomicU64,
+    pub boots_rejected: AtomicU64,
+    pub sessions_completed: AtomicU64,
+    pub sessions_failed: AtomicU64,
+    /// Energy delivered by completed sessions, in Wh.
+    pub energy_wh: AtomicU64,
+    /// Sum of BootNotification round trips, in ms.
+    pub boot_latency_ms: AtomicU64,
+}
+
+impl FleetStats {
+    pub fn report(&self, elapsed: Duration) -> FleetReport {
+        let boots = self.boots_accepted.load(Ordering::Relaxed)
+            + self.boots_rejected.load(Ordering::Relaxed);
+        FleetReport {
+            elapsed,
+            connected: self.connected.load(Ordering::Relaxed),
+            connect_failures: self.connect_failures.load(Ordering::Relaxed),
+            boots_accepted: self.boots_accepted.load(Ordering::Relaxed),
+            boots_rejected: self.boots_rejected.load(Ordering::Relaxed),
+            sessions_completed: self.sessions_completed.load(Ordering::Relaxed),
+            sessions_failed: self.sessions_failed.load(Ordering::Relaxed),
+            energy_kwh: self.energy_wh.load(Ordering::Relaxed) as f64 / 1000.0,
+            avg_boot_latency_ms: self
+                .boot_latency_ms
+                .load(Ordering::Relaxed)
+                .checked_div(boots)
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// Snapshot of [`FleetStats`].
+#[derive(Debug, Clone)]
+pub struct FleetReport {
+    pub elapsed: Duration,
+    pub connected: u64,
+    pub connect_failures: u64,
+    pub boots_accepted: u64,
+    pub boots_rejected: u64,
+    pub sessions_completed: u64,
+    pub sessions_failed: u64,
+    pub energy_kwh: f64,
+    pub avg_boot_latency_ms: u64,
+}
+
+impl fmt::Display for FleetReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Elapsed:            {:.1}s", self.elapsed.as_secs_f64())?;
+        writeln!(
+            f,
+            "Connections:        {} ok, {} failed",
+            self.connected, self.connect_failures
+        )?;
+        writeln!(
+            f,
+            "Boots:              {} accepted, {} not accepted (avg {} ms)",
+            self.boots_accepted, self.boots_rejected, self.avg_boot_latency_ms
+        )?;
+        writeln!(
+            f,
+            "Sessions:           {} completed, {} failed",
+            self.sessions_completed, self.sessions_failed
+        )?;
+        write!(f, "Energy delivered:   {:.2} kWh", self.energy_kwh)
+    }
+}
+
+/// Run the fleet to completion (or until `shutdown`) and return the final
+/// counters. `stats` can be read concurrently for progress output.
+pub async fn run_fleet(
+    config: FleetConfig,
+    stats: Arc<FleetStats>,
+    shutdown: ShutdownSignal,
+) -> FleetReport {
+    let started = Instant::now();
+    let mut handles = Vec::with_capacity(config.stations as usize);
+
+    for index in 0..config.stations {
+        if index > 0 && !config.ramp_up.is_zero() {
+            tokio::select! {
+                _ = tokio::time::sleep(config.ramp_up) => {}
+                _ = shutdown.notified().wait() => break,
+            }
+        }
+        let mut station = config.station.clone();
+        station.charge_point_id = config.station_id(index);
+        handles.push(tokio::spawn(run_station(
+            station,
+            config.clone(),
+            stats.clone(),
+            shutdown.clone(),
+        )));
+    }
+
+    for handle in handles {
+        let _ = handle.await;
+    }
+    stats.report(started.elapsed())
+}
+
+async fn run_station(
+    station_config: StationConfig,
+    config: FleetConfig,
+    stats: Arc<FleetStats>,
+    shutdown: ShutdownSignal,
+) {
+    let charge_point_id = station_config.charge_point_id.clone();
+    let connectors = station_config.connectors.max(1);
+
+    let station = match SimulatedChargePoint::connect(station_config).await {
+        Ok(station) => station,
+        Err(e) => {
+            warn!(charge_point_id, error = %e, "Simulator: connect failed");
+            stats.connect_failures.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+    };
+    stats.connected.fetch_add(1, Ordering::Relaxed);
+
+    let work = async {
+        let boot_started = Instant::now();
+        let booted = station.boot().await;
+        stats
+            .boot_latency_ms
+            .fetch_add(boot_started.elapsed().as_millis() as u64, Ordering::Relaxed);
+        match booted {
+            Ok(status) if status == "Accepted" => {
+                stats.boots_accepted.fetch_add(1, Ordering::Relaxed);
+            }
+            Ok(status) => {
+                warn!(charge_point_id, status, "Simulator: boot not accepted");
+                stats.boots_rejected.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+            Err(e) => {
+                warn!(charge_point_id, error = %e, "Simulator: boot failed");
+                stats.boots_rejected.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+        }
+        station.spawn_heartbeats();
+
+        for n in 0..config.sessions_per_station {
+            if n > 0 {
+                tokio::time::sleep(config.pause_between_sessions).await;
+            }
+            let script = SessionScript {
+                connector_id: n % connectors + 1,
+                ..config.script.clone()
+            };
+            match station.run_session(&script).await {
+                Ok(summary) => {
+                    stats.sessions_completed.fetch_add(1, Ordering::Relaxed);
+                    stats
+                        .energy_wh
+                        .fetch_add(summary.energy_wh.round() as u64, Ordering::Relaxed);
+                }
+                Err(e) => {
+                    warn!(charge_point_id, error = %e, "Simulator: session failed");
+                    stats.sessions_failed.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+
+        match config.hold {
+            Some(hold) => tokio::time::sleep(hold).await,
+            None => {
+                while station.is_connected() {
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+            }
+        }
+    };
+
+    tokio::select! {
+        _ = work => {}
+        _ = shutdown.notified().wait() => {}
+    }
+    station.close();
+}
@@ -0,0 +1,34 @@
+//! # Charge point simulator
+//!
+//! Simulated OCPP 1.6 / 2.0.1 charging stations for exercising the Central
+//! System without real hardware. Used by the `simulator` binary and
+//! reusable from integration tests.
+//!
+//! - **client**: OCPP-J WebSocket client (subprotocol, Basic Auth, RPC)
+//! - **station**: a single simulated station — boot, heartbeats, status,
+//!   scripted sessions and answers to every CSMS → CP command
+//! - **v16 / v201**: version-specific payload builders and command responders
+//! - **fleet**: runs many stations concurrently for load tests
+//!
+//! ```ignore
+//! let config = StationConfig::new("ws://127.0.0.1:9000/ocpp", "SIM-0001", OcppVersion::V201);
+//! let station = SimulatedChargePoint::connect(config).await?;
+//! station.boot().await?;
+//! station.run_session(&SessionScript::default()).await?;
+//! ```
+
+pub mod client;
+pub mod config;
+pub mod error;
+pub mod fleet;
+pub mod state;
+pub mod station;
+mod v16;
+mod v201;
+
+pub use client::{IncomingCall, OcppClient};
+pub use config::{SessionScript, StationConfig};
+pub use error::{SimulatorError, SimulatorResult};
+pub use fleet::{run_fleet, FleetConfig, FleetReport, FleetStats};
+pub use state::{ConnectorState, StationState};
+pub use station::SimulatedChargePoint;
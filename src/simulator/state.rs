@@ -0,0 +1,276 @@
+//! Simulated station state
+//!
+//! Version-agnostic model of what a real station keeps in memory:
+//! connectors and their transactions, configuration / device model,
+//! local authorization list, charging profiles, display messages and
+//! variable monitors. The v1.6 and v2.0.1 responders read and mutate it.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use super::config::StationConfig;
+
+/// Physical state of a connector, mapped to the version-specific status
+/// enum when reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectorState {
+    Available,
+    Preparing,
+    Charging,
+    Finishing,
+    Reserved,
+    Unavailable,
+    Faulted,
+}
+
+/// Why a transaction was stopped, mapped to the version-specific reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    Local,
+    Remote,
+    Reset { hard: bool },
+    UnlockCommand,
+}
+
+#[derive(Debug, Clone)]
+pub struct ActiveTransaction {
+    /// Server-assigned ID (v1.6) or station-generated UUID (v2.0.1).
+    pub transaction_id: String,
+    pub id_tag: String,
+    pub started_at: DateTime<Utc>,
+    pub meter_start_wh: f64,
+    /// Next TransactionEvent sequence number (v2.0.1).
+    pub seq_no: i32,
+    pub remote_start_id: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Connector {
+    /// Connector ID (v1.6) / EVSE ID (v2.0.1); 1-based.
+    pub id: u32,
+    pub state: ConnectorState,
+    pub operative: bool,
+    /// Energy register, in Wh.
+    pub meter_wh: f64,
+    /// Current power draw, in W.
+    pub power_w: f64,
+    pub transaction: Option<ActiveTransaction>,
+    pub reservation_id: Option<i32>,
+}
+
+/// A charging profile installed by SetChargingProfile.
+#[derive(Debug, Clone)]
+pub struct StoredProfile {
+    /// Connector (v1.6) / EVSE (v2.0.1); 0 = whole station.
+    pub evse_id: u32,
+    pub id: i32,
+    pub stack_level: i32,
+    pub purpose: String,
+    pub profile: Value,
+}
+
+/// A variable monitor installed by SetVariableMonitoring.
+#[derive(Debug, Clone)]
+pub struct StoredMonitor {
+    pub component: Value,
+    pub variable: Value,
+    pub monitor_type: String,
+    pub value: f64,
+    pub severity: i32,
+    pub transaction: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct StationState {
+    pub connectors: Vec<Connector>,
+    pub registration_status: Option<String>,
+    pub heartbeat_interval: u64,
+    /// v1.6 configuration keys: key → (value, readonly).
+    pub configuration: BTreeMap<String, (String, bool)>,
+    /// v2.0.1 device model: (component, variable) → Actual value.
+    pub variables: BTreeMap<(String, String), String>,
+    pub local_list_version: i32,
+    /// Local authorization list: id tag → status.
+    pub local_list: BTreeMap<String, String>,
+    pub charging_profiles: Vec<StoredProfile>,
+    pub display_messages: BTreeMap<i32, Value>,
+    pub monitors: BTreeMap<i32, StoredMonitor>,
+    pub next_monitor_id: i32,
+    pub monitoring_level: u8,
+    /// Actions of every command received from the Central System, in order.
+    pub received_commands: Vec<String>,
+}
+
+impl StationState {
+    pub fn new(config: &StationConfig) -> Self {
+        let connectors = (1..=config.connectors)
+            .map(|id| Connector {
+                id,
+                state: ConnectorState::Available,
+                operative: true,
+                meter_wh: 0.0,
+                power_w: 0.0,
+                transaction: None,
+                reservation_id: None,
+            })
+            .collect();
+
+        let mut configuration = BTreeMap::new();
+        for (key, value, readonly) in [
+            ("HeartbeatInterval", "300".to_string(), false),
+            ("MeterValueSampleInterval", "60".to_string(), false),
+            ("AuthorizeRemoteTxRequests", "false".to_string(), false),
+            ("LocalAuthListEnabled", "true".to_string(), false),
+            ("ConnectionTimeOut", "60".to_string(), false),
+            ("NumberOfConnectors", config.connectors.to_string(), true),
+            (
+                "SupportedFeatureProfiles",
+                "Core,FirmwareManagement,LocalAuthListManagement,Reservation,SmartCharging,RemoteTrigger"
+                    .to_string(),
+                true,
+            ),
+        ] {
+            configuration.insert(key.to_string(), (value, readonly));
+        }
+
+        let mut variables = BTreeMap::new();
+        for (component, variable, value) in [
+            ("OCPPCommCtrlr", "HeartbeatInterval", "300".to_string()),
+            ("SampledDataCtrlr", "TxUpdatedInterval", "60".to_string()),
+            ("AuthCtrlr", "Enabled", "true".to_string()),
+            ("AuthCtrlr", "AuthorizeRemoteStart", "false".to_string()),
+            ("LocalAuthListCtrlr", "Enabled", "true".to_string()),
+            ("DeviceDataCtrlr", "ItemsPerMessage", "10".to_string()),
+            ("ChargingStation", "Model", config.model.clone()),
+            ("ChargingStation", "VendorName", config.vendor.clone()),
+            (
+                "ChargingStation",
+                "AvailabilityState",
+                "Available".to_string(),
+            ),
+            ("SecurityCtrlr", "SecurityProfile", "1".to_string()),
+            ("TxCtrlr", "EVConnectionTimeOut", "60".to_string()),
+        ] {
+            variables.insert((component.to_string(), variable.to_string()), value);
+        }
+
+        Self {
+            connectors,
+            registration_status: None,
+            heartbeat_interval: 300,
+            configuration,
+            variables,
+            local_list_version: 0,
+            local_list: BTreeMap::new(),
+            charging_profiles: Vec::new(),
+            display_messages: BTreeMap::new(),
+            monitors: BTreeMap::new(),
+            next_monitor_id: 1,
+            monitoring_level: 9,
+            received_commands: Vec::new(),
+        }
+    }
+
+    pub fn connector(&self, id: u32) -> Option<&Connector> {
+        self.connectors.iter().find(|c| c.id == id)
+    }
+
+    pub fn connector_mut(&mut self, id: u32) -> Option<&mut Connector> {
+        self.connectors.iter_mut().find(|c| c.id == id)
+    }
+
+    /// First operative connector without a transaction or reservation.
+    pub fn free_connector(&self) -> Option<u32> {
+        self.connectors
+            .iter()
+            .find(|c| c.operative && c.state == ConnectorState::Available)
+            .map(|c| c.id)
+    }
+
+    /// Connector running the given transaction.
+    pub fn connector_for_transaction(&self, transaction_id: &str) -> Option<u32> {
+        self.connectors
+            .iter()
+            .find(|c| {
+                c.transaction
+                    .as_ref()
+                    .is_some_and(|tx| tx.transaction_id == transaction_id)
+            })
+            .map(|c| c.id)
+    }
+
+    pub fn has_active_transaction(&self) -> bool {
+        self.connectors.iter().any(|c| c.transaction.is_some())
+    }
+
+    /// Resting state of a connector once it has no transaction.
+    pub fn idle_state(&self, id: u32) -> ConnectorState {
+        match self.connector(id) {
+            Some(c) if !c.operative => ConnectorState::Unavailable,
+            Some(c) if c.reservation_id.is_some() => ConnectorState::Reserved,
+            _ => ConnectorState::Available,
+        }
+    }
+
+    /// Power limit of a connector in W, from the highest-stack-level
+    /// installed profile (first period) or the hardware maximum.
+    pub fn power_limit_w(&self, evse_id: u32, max_power_w: f64) -> f64 {
+        self.charging_profiles
+            .iter()
+            .filter(|p| p.evse_id == evse_id || p.evse_id == 0)
+            .max_by_key(|p| p.stack_level)
+            .and_then(|p| first_period_limit_w(&p.profile))
+            .map(|limit| limit.min(max_power_w))
+            .unwrap_or(max_power_w)
+    }
+}
+
+/// Limit of the first schedule period of a profile, converted to W.
+fn first_period_limit_w(profile: &Value) -> Option<f64> {
+    // v1.6 has one `chargingSchedule` object, v2.0.1 an array
+    let schedule = match &profile["chargingSchedule"] {
+        Value::Array(list) => list.first()?,
+        other => other,
+    };
+    let limit = schedule["chargingSchedulePeriod"]
+        .as_array()?
+        .first()?
+        .get("limit")?
+        .as_f64()?;
+    match schedule["chargingRateUnit"].as_str() {
+        // Three phases at 230 V
+        Some("A") => Some(limit * 230.0 * 3.0),
+        _ => Some(limit),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::OcppVersion;
+
+    #[test]
+    fn profile_limit_caps_connector_power() {
+        let config = StationConfig::new("ws://localhost", "SIM", OcppVersion::V16);
+        let mut state = StationState::new(&config);
+        state.charging_profiles.push(StoredProfile {
+            evse_id: 1,
+            id: 1,
+            stack_level: 0,
+            purpose: "TxDefaultProfile".to_string(),
+            profile: json!({
+                "chargingSchedule": {
+                    "chargingRateUnit": "A",
+                    "chargingSchedulePeriod": [{ "startPeriod": 0, "limit": 16.0 }]
+                }
+            }),
+        });
+
+        assert_eq!(state.power_limit_w(1, 22_000.0), 16.0 * 690.0);
+        assert_eq!(state.power_limit_w(2, 22_000.0), 22_000.0);
+    }
+}
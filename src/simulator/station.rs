@@ -0,0 +1,637 @@
+//! A single simulated charge point
+//!
+//! Drives the station side of the protocol (boot, heartbeats, status,
+//! sessions) and answers every command the Central System sends. The
+//! version-specific payloads live in `v16` / `v201`; this module only
+//! sequences them and applies their follow-up actions.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::Utc;
+use serde_json::Value;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use super::client::{IncomingCall, OcppClient};
+use super::config::{SessionScript, StationConfig};
+use super::error::{SimulatorError, SimulatorResult};
+use super::state::{ActiveTransaction, ConnectorState, StationState, StopReason};
+use super::{v16, v201};
+use crate::domain::OcppVersion;
+
+/// Action the station performs after answering a command.
+#[derive(Debug, Clone)]
+pub(super) enum FollowUp {
+    /// Send these Calls in order (status notifications, reports, …).
+    Send(Vec<(&'static str, Value)>),
+    StartTransaction {
+        connector_id: u32,
+        id_tag: String,
+        remote_start_id: Option<i32>,
+    },
+    StopTransaction {
+        connector_id: u32,
+        reason: StopReason,
+    },
+    Reboot {
+        hard: bool,
+    },
+}
+
+/// Response to an incoming command plus what to do once it is sent.
+#[derive(Debug, Clone)]
+pub(super) struct CommandOutcome {
+    pub response: Result<Value, (&'static str, String)>,
+    pub follow_ups: Vec<FollowUp>,
+}
+
+impl CommandOutcome {
+    pub fn reply(payload: Value) -> Self {
+        Self {
+            response: Ok(payload),
+            follow_ups: Vec::new(),
+        }
+    }
+
+    pub fn error(code: &'static str, description: impl Into<String>) -> Self {
+        Self {
+            response: Err((code, description.into())),
+            follow_ups: Vec::new(),
+        }
+    }
+
+    pub fn not_implemented(action: &str) -> Self {
+        Self::error(
+            "NotImplemented",
+            format!("{} is not supported by the simulator", action),
+        )
+    }
+
+    pub fn then(mut self, follow_up: FollowUp) -> Self {
+        self.follow_ups.push(follow_up);
+        self
+    }
+}
+
+/// Outcome of a scripted session.
+#[derive(Debug, Clone)]
+pub struct SessionSummary {
+    pub transaction_id: String,
+    pub energy_wh: f64,
+}
+
+struct Inner {
+    config: StationConfig,
+    client: OcppClient,
+    state: Mutex<StationState>,
+}
+
+/// Cloneable handle to a connected simulated station.
+#[derive(Clone)]
+pub struct SimulatedChargePoint {
+    inner: Arc<Inner>,
+}
+
+impl SimulatedChargePoint {
+    /// Connect to the Central System and start answering its commands.
+    /// Call [`boot`](Self::boot) next.
+    pub async fn connect(config: StationConfig) -> SimulatorResult<Self> {
+        let (client, incoming) = OcppClient::connect(&config).await?;
+        let station = Self {
+            inner: Arc::new(Inner {
+                state: Mutex::new(StationState::new(&config)),
+                config,
+                client,
+            }),
+        };
+        tokio::spawn(station.clone().answer_commands(incoming));
+        Ok(station)
+    }
+
+    pub fn config(&self) -> &StationConfig {
+        &self.inner.config
+    }
+
+    pub fn client(&self) -> &OcppClient {
+        &self.inner.client
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.inner.client.is_connected()
+    }
+
+    /// Snapshot of the current station state.
+    pub fn state(&self) -> StationState {
+        self.with_state(|s| s.clone())
+    }
+
+    /// Actions of every command received so far, in order.
+    pub fn received_commands(&self) -> Vec<String> {
+        self.with_state(|s| s.received_commands.clone())
+    }
+
+    pub fn close(&self) {
+        self.inner.client.close();
+    }
+
+    fn is_v16(&self) -> bool {
+        self.inner.config.version == OcppVersion::V16
+    }
+
+    fn with_state<R>(&self, f: impl FnOnce(&mut StationState) -> R) -> R {
+        let mut state = self.inner.state.lock().unwrap_or_else(|e| e.into_inner());
+        f(&mut state)
+    }
+
+    async fn call(&self, action: &str, payload: Value) -> SimulatorResult<Value> {
+        self.inner.client.call(action, payload).await
+    }
+
+    // ── Station-initiated messages ─────────────────────────
+
+    /// Send BootNotification; on `Accepted` adopt the heartbeat interval
+    /// and report every connector's status. Returns the registration status.
+    pub async fn boot(&self) -> SimulatorResult<String> {
+        self.boot_with_reason(false).await
+    }
+
+    async fn boot_with_reason(&self, after_reset: bool) -> SimulatorResult<String> {
+        let config = &self.inner.config;
+        let payload = if self.is_v16() {
+            v16::boot_notification(config)
+        } else {
+            v201::boot_notification(config, after_reset)
+        };
+        let response = self.call("BootNotification", payload).await?;
+
+        let status = response["status"].as_str().unwrap_or_default().to_string();
+        let interval = response["interval"].as_u64().unwrap_or(0);
+        self.with_state(|s| {
+            s.registration_status = Some(status.clone());
+            if interval > 0 {
+                s.heartbeat_interval = interval;
+            }
+        });
+        info!(
+            charge_point_id = config.charge_point_id.as_str(),
+            status = status.as_str(),
+            interval,
+            "Simulator: BootNotification answered"
+        );
+
+        if status == "Accepted" {
+            let mut ids: Vec<u32> =
+                self.with_state(|s| s.connectors.iter().map(|c| c.id).collect());
+            if self.is_v16() {
+                // Connector 0 reports the station as a whole
+                ids.insert(0, 0);
+            }
+            for id in ids {
+                let state = if id == 0 {
+                    ConnectorState::Available
+                } else {
+                    self.with_state(|s| s.connector(id).map(|c| c.state))
+                        .unwrap_or(ConnectorState::Available)
+                };
+                self.send_status(id, state).await?;
+            }
+        }
+        Ok(status)
+    }
+
+    pub async fn heartbeat(&self) -> SimulatorResult<()> {
+        self.call("Heartbeat", serde_json::json!({}))
+            .await
+            .map(|_| ())
+    }
+
+    /// Send Heartbeat at the interval returned by BootNotification until
+    /// the connection closes.
+    pub fn spawn_heartbeats(&self) -> JoinHandle<()> {
+        let station = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let interval = station.with_state(|s| s.heartbeat_interval).max(1);
+                tokio::time::sleep(Duration::from_secs(interval)).await;
+                if !station.is_connected() {
+                    break;
+                }
+                if let Err(SimulatorError::Closed) = station.heartbeat().await {
+                    break;
+                }
+            }
+        })
+    }
+
+    /// Set a connector's state and report it with StatusNotification.
+    pub async fn send_status(
+        &self,
+        connector_id: u32,
+        state: ConnectorState,
+    ) -> SimulatorResult<()> {
+        self.with_state(|s| {
+            if let Some(c) = s.connector_mut(connector_id) {
+                c.state = state;
+            }
+        });
+        let payload = if self.is_v16() {
+            v16::status_notification(connector_id, state)
+        } else {
+            v201::status_notification(connector_id, state)
+        };
+        self.call("StatusNotification", payload).await.map(|_| ())
+    }
+
+    /// Send Authorize and return the `idTagInfo` / `idTokenInfo` status.
+    pub async fn authorize(&self, id_tag: &str) -> SimulatorResult<String> {
+        let (payload, info_key) = if self.is_v16() {
+            (v16::authorize(id_tag), "idTagInfo")
+        } else {
+            (v201::authorize(id_tag), "idTokenInfo")
+        };
+        let response = self.call("Authorize", payload).await?;
+        Ok(response[info_key]["status"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string())
+    }
+
+    /// Authorize locally-presented `id_tag` and start a transaction on
+    /// `connector_id`. Returns the transaction ID.
+    pub async fn start_transaction(
+        &self,
+        connector_id: u32,
+        id_tag: &str,
+    ) -> SimulatorResult<String> {
+        self.begin_transaction(connector_id, id_tag, None, true)
+            .await
+    }
+
+    async fn begin_transaction(
+        &self,
+        connector_id: u32,
+        id_tag: &str,
+        remote_start_id: Option<i32>,
+        authorize: bool,
+    ) -> SimulatorResult<String> {
+        let usable = self.with_state(|s| {
+            s.connector(connector_id).is_some_and(|c| {
+                c.operative
+                    && c.transaction.is_none()
+                    && matches!(
+                        c.state,
+                        ConnectorState::Available | ConnectorState::Reserved
+                    )
+            })
+        });
+        if !usable {
+            return Err(SimulatorError::Rejected(format!(
+                "Connector {} is not available",
+                connector_id
+            )));
+        }
+
+        if authorize {
+            let status = self.authorize(id_tag).await?;
+            if status != "Accepted" {
+                return Err(SimulatorError::Rejected(format!(
+                    "Authorization of {} returned {}",
+                    id_tag, status
+                )));
+            }
+        }
+
+        self.send_status(connector_id, ConnectorState::Preparing)
+            .await?;
+
+        let now = Utc::now();
+        let (meter_start_wh, reservation_id) = self.with_state(|s| {
+            let c = s
+                .connector_mut(connector_id)
+                .expect("connector checked above");
+            (c.meter_wh, c.reservation_id.take())
+        });
+
+        let (transaction_id, status) = if self.is_v16() {
+            let payload =
+                v16::start_transaction(connector_id, id_tag, meter_start_wh, reservation_id, now);
+            let response = self.call("StartTransaction", payload).await?;
+            let transaction_id = response["transactionId"].as_i64().ok_or_else(|| {
+                SimulatorError::InvalidResponse {
+                    action: "StartTransaction".to_string(),
+                    reason: "missing transactionId".to_string(),
+                }
+            })?;
+            let status = response["idTagInfo"]["status"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            (transaction_id.to_string(), status)
+        } else {
+            let transaction = ActiveTransaction {
+                transaction_id: uuid::Uuid::new_v4().to_string(),
+                id_tag: id_tag.to_string(),
+                started_at: now,
+                meter_start_wh,
+                seq_no: 0,
+                remote_start_id,
+            };
+            let payload = v201::transaction_started(connector_id, &transaction, reservation_id);
+            let response = self.call("TransactionEvent", payload).await?;
+            // idTokenInfo is optional in the response; absent means accepted
+            let status = response["idTokenInfo"]["status"]
+                .as_str()
+                .unwrap_or("Accepted")
+                .to_string();
+            (transaction.transaction_id, status)
+        };
+
+        let power_w = self.with_state(|s| {
+            let limit = s.power_limit_w(connector_id, self.inner.config.max_power_w);
+            let c = s
+                .connector_mut(connector_id)
+                .expect("connector checked above");
+            c.transaction = Some(ActiveTransaction {
+                transaction_id: transaction_id.clone(),
+                id_tag: id_tag.to_string(),
+                started_at: now,
+                meter_start_wh,
+                seq_no: 1,
+                remote_start_id,
+            });
+            c.power_w = limit;
+            limit
+        });
+        debug!(
+            charge_point_id = self.inner.config.charge_point_id.as_str(),
+            connector_id,
+            transaction_id = transaction_id.as_str(),
+            power_w,
+            "Simulator: transaction started"
+        );
+
+        if status != "Accepted" {
+            self.stop_transaction(connector_id, StopReason::Local)
+                .await?;
+            return Err(SimulatorError::Rejected(format!(
+                "Transaction for {} was not accepted: {}",
+                id_tag, status
+            )));
+        }
+
+        self.send_status(connector_id, ConnectorState::Charging)
+            .await?;
+        Ok(transaction_id)
+    }
+
+    /// Advance the energy register of `connector_id` by `elapsed` at its
+    /// current power.
+    pub fn advance_meter(&self, connector_id: u32, elapsed: Duration) {
+        self.with_state(|s| {
+            if let Some(c) = s.connector_mut(connector_id) {
+                c.meter_wh += c.power_w * elapsed.as_secs_f64() / 3600.0;
+            }
+        });
+    }
+
+    /// Report the connector's meter: TransactionEvent(Updated) during a
+    /// v2.0.1 transaction, MeterValues otherwise.
+    pub async fn send_meter_values(&self, connector_id: u32) -> SimulatorResult<()> {
+        let Some(connector) = self.with_state(|s| {
+            let c = s.connector_mut(connector_id)?;
+            let snapshot = c.clone();
+            if let Some(tx) = c.transaction.as_mut() {
+                tx.seq_no += 1;
+            }
+            Some(snapshot)
+        }) else {
+            return Err(SimulatorError::Rejected(format!(
+                "Unknown connector {}",
+                connector_id
+            )));
+        };
+
+        let (action, payload) = match (self.is_v16(), &connector.transaction) {
+            (true, _) => ("MeterValues", v16::meter_values(&connector)),
+            (false, Some(tx)) => (
+                "TransactionEvent",
+                v201::transaction_updated(&connector, tx),
+            ),
+            (false, None) => ("MeterValues", v201::meter_values(&connector)),
+        };
+        self.call(action, payload).await.map(|_| ())
+    }
+
+    /// Stop the transaction on `connector_id` and return the connector to
+    /// its idle state.
+    pub async fn stop_transaction(
+        &self,
+        connector_id: u32,
+        reason: StopReason,
+    ) -> SimulatorResult<()> {
+        let Some((connector, transaction)) = self.with_state(|s| {
+            let c = s.connector_mut(connector_id)?;
+            let tx = c.transaction.take()?;
+            c.power_w = 0.0;
+            Some((c.clone(), tx))
+        }) else {
+            return Err(SimulatorError::Rejected(format!(
+                "No transaction on connector {}",
+                connector_id
+            )));
+        };
+
+        if self.is_v16() {
+            self.call(
+                "StopTransaction",
+                v16::stop_transaction(&connector, &transaction, reason),
+            )
+            .await?;
+            self.send_status(connector_id, ConnectorState::Finishing)
+                .await?;
+        } else {
+            self.call(
+                "TransactionEvent",
+                v201::transaction_ended(&connector, &transaction, reason),
+            )
+            .await?;
+        }
+
+        let idle = self.with_state(|s| s.idle_state(connector_id));
+        self.send_status(connector_id, idle).await
+    }
+
+    /// Run one scripted session: Authorize → start → MeterValues every
+    /// `meter_interval` → stop after `duration`. Ends early (without
+    /// error) if the Central System stops the transaction remotely.
+    pub async fn run_session(&self, script: &SessionScript) -> SimulatorResult<SessionSummary> {
+        let connector_id = script.connector_id;
+        let transaction_id = self.start_transaction(connector_id, &script.id_tag).await?;
+        self.with_state(|s| {
+            let limit = s.power_limit_w(connector_id, self.inner.config.max_power_w);
+            if let Some(c) = s.connector_mut(connector_id) {
+                c.power_w = script.power_w.min(limit);
+            }
+        });
+        let meter_start = self
+            .with_state(|s| s.connector(connector_id).map(|c| c.meter_wh))
+            .unwrap_or_default();
+
+        let mut elapsed = Duration::ZERO;
+        while elapsed < script.duration {
+            let step = script
+                .meter_interval
+                .min(script.duration - elapsed)
+                .max(Duration::from_millis(1));
+            tokio::time::sleep(step).await;
+            elapsed += step;
+            if !self.is_transaction_active(connector_id, &transaction_id) {
+                break;
+            }
+            self.advance_meter(connector_id, step);
+            self.send_meter_values(connector_id).await?;
+        }
+
+        let meter_stop = self
+            .with_state(|s| s.connector(connector_id).map(|c| c.meter_wh))
+            .unwrap_or_default();
+        if self.is_transaction_active(connector_id, &transaction_id) {
+            self.stop_transaction(connector_id, StopReason::Local)
+                .await?;
+        }
+
+        Ok(SessionSummary {
+            transaction_id,
+            energy_wh: meter_stop - meter_start,
+        })
+    }
+
+    fn is_transaction_active(&self, connector_id: u32, transaction_id: &str) -> bool {
+        self.with_state(|s| s.connector_for_transaction(transaction_id) == Some(connector_id))
+    }
+
+    /// Meter a remotely started transaction until it is stopped.
+    fn spawn_metering(&self, connector_id: u32, transaction_id: String) {
+        let station = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let interval = station.with_state(|s| {
+                    let value = if station.is_v16() {
+                        s.configuration
+                            .get("MeterValueSampleInterval")
+                            .map(|(v, _)| v.clone())
+                    } else {
+                        s.variables
+                            .get(&(
+                                "SampledDataCtrlr".to_string(),
+                                "TxUpdatedInterval".to_string(),
+                            ))
+                            .cloned()
+                    };
+                    value
+                        .and_then(|v| v.parse::<u64>().ok())
+                        .unwrap_or(60)
+                        .max(1)
+                });
+                let step = Duration::from_secs(interval);
+                tokio::time::sleep(step).await;
+                if !station.is_transaction_active(connector_id, &transaction_id) {
+                    break;
+                }
+                station.advance_meter(connector_id, step);
+                if station.send_meter_values(connector_id).await.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    // ── Central System → station commands ──────────────────
+
+    async fn answer_commands(self, mut incoming: mpsc::UnboundedReceiver<IncomingCall>) {
+        while let Some(call) = incoming.recv().await {
+            let outcome = self.with_state(|s| {
+                s.received_commands.push(call.action.clone());
+                if self.is_v16() {
+                    v16::handle_command(s, &self.inner.config, &call.action, &call.payload)
+                } else {
+                    v201::handle_command(s, &self.inner.config, &call.action, &call.payload)
+                }
+            });
+            debug!(
+                charge_point_id = self.inner.config.charge_point_id.as_str(),
+                action = call.action.as_str(),
+                "Simulator: command received"
+            );
+
+            let sent = match &outcome.response {
+                Ok(payload) => self.inner.client.respond(&call.unique_id, payload.clone()),
+                Err((code, description)) => {
+                    self.inner
+                        .client
+                        .respond_error(&call.unique_id, code, description)
+                }
+            };
+            if sent.is_err() {
+                break;
+            }
+
+            if !outcome.follow_ups.is_empty() {
+                let station = self.clone();
+                tokio::spawn(async move {
+                    for follow_up in outcome.follow_ups {
+                        if let Err(e) = station.apply(follow_up).await {
+                            warn!(
+                                charge_point_id = station.inner.config.charge_point_id.as_str(),
+                                error = %e,
+                                "Simulator: follow-up failed"
+                            );
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    async fn apply(&self, follow_up: FollowUp) -> SimulatorResult<()> {
+        match follow_up {
+            FollowUp::Send(calls) => {
+                for (action, payload) in calls {
+                    self.call(action, payload).await?;
+                }
+                Ok(())
+            }
+            FollowUp::StartTransaction {
+                connector_id,
+                id_tag,
+                remote_start_id,
+            } => {
+                let transaction_id = self
+                    .begin_transaction(connector_id, &id_tag, remote_start_id, false)
+                    .await?;
+                self.spawn_metering(connector_id, transaction_id);
+                Ok(())
+            }
+            FollowUp::StopTransaction {
+                connector_id,
+                reason,
+            } => self.stop_transaction(connector_id, reason).await,
+            FollowUp::Reboot { hard } => {
+                let active: Vec<u32> = self.with_state(|s| {
+                    s.connectors
+                        .iter()
+                        .filter(|c| c.transaction.is_some())
+                        .map(|c| c.id)
+                        .collect()
+                });
+                for connector_id in active {
+                    self.stop_transaction(connector_id, StopReason::Reset { hard })
+                        .await?;
+                }
+                self.boot_with_reason(true).await.map(|_| ())
+            }
+        }
+    }
+}
@@ -0,0 +1,644 @@
+//! OCPP 1.6 payloads and command responders
+
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+
+use super::config::StationConfig;
+use super::state::{
+    ActiveTransaction, Connector, ConnectorState, StationState, StopReason, StoredProfile,
+};
+use super::station::{CommandOutcome, FollowUp};
+
+// ── Station-initiated messages ─────────────────────────────────
+
+pub(super) fn boot_notification(config: &StationConfig) -> Value {
+    let mut payload = json!({
+        "chargePointVendor": config.vendor,
+        "chargePointModel": config.model,
+        "firmwareVersion": config.firmware_version,
+    });
+    if let Some(serial) = &config.serial_number {
+        payload["chargePointSerialNumber"] = json!(serial);
+    }
+    payload
+}
+
+fn status_name(state: ConnectorState) -> &'static str {
+    match state {
+        ConnectorState::Available => "Available",
+        ConnectorState::Preparing => "Preparing",
+        ConnectorState::Charging => "Charging",
+        ConnectorState::Finishing => "Finishing",
+        ConnectorState::Reserved => "Reserved",
+        ConnectorState::Unavailable => "Unavailable",
+        ConnectorState::Faulted => "Faulted",
+    }
+}
+
+pub(super) fn status_notification(connector_id: u32, state: ConnectorState) -> Value {
+    json!({
+        "connectorId": connector_id,
+        "errorCode": if state == ConnectorState::Faulted { "OtherError" } else { "NoError" },
+        "status": status_name(state),
+        "timestamp": Utc::now(),
+    })
+}
+
+pub(super) fn authorize(id_tag: &str) -> Value {
+    json!({ "idTag": id_tag })
+}
+
+pub(super) fn start_transaction(
+    connector_id: u32,
+    id_tag: &str,
+    meter_start_wh: f64,
+    reservation_id: Option<i32>,
+    timestamp: DateTime<Utc>,
+) -> Value {
+    let mut payload = json!({
+        "connectorId": connector_id,
+        "idTag": id_tag,
+        "meterStart": meter_start_wh.round() as i64,
+        "timestamp": timestamp,
+    });
+    if let Some(id) = reservation_id {
+        payload["reservationId"] = json!(id);
+    }
+    payload
+}
+
+fn meter_value(connector: &Connector) -> Value {
+    json!({
+        "timestamp": Utc::now(),
+        "sampledValue": [
+            {
+                "value": format!("{:.0}", connector.meter_wh),
+                "context": "Sample.Periodic",
+                "measurand": "Energy.Active.Import.Register",
+                "unit": "Wh",
+            },
+            {
+                "value": format!("{:.0}", connector.power_w),
+                "context": "Sample.Periodic",
+                "measurand": "Power.Active.Import",
+                "unit": "W",
+            },
+        ],
+    })
+}
+
+pub(super) fn meter_values(connector: &Connector) -> Value {
+    let mut payload = json!({
+        "connectorId": connector.id,
+        "meterValue": [meter_value(connector)],
+    });
+    if let Some(id) = connector
+        .transaction
+        .as_ref()
+        .and_then(|tx| tx.transaction_id.parse::<i32>().ok())
+    {
+        payload["transactionId"] = json!(id);
+    }
+    payload
+}
+
+pub(super) fn stop_transaction(
+    connector: &Connector,
+    transaction: &ActiveTransaction,
+    reason: StopReason,
+) -> Value {
+    let reason = match reason {
+        StopReason::Local => "Local",
+        StopReason::Remote => "Remote",
+        StopReason::Reset { hard: true } => "HardReset",
+        StopReason::Reset { hard: false } => "SoftReset",
+        StopReason::UnlockCommand => "UnlockCommand",
+    };
+    json!({
+        "transactionId": transaction.transaction_id.parse::<i32>().unwrap_or_default(),
+        "idTag": transaction.id_tag,
+        "meterStop": connector.meter_wh.round() as i64,
+        "timestamp": Utc::now(),
+        "reason": reason,
+    })
+}
+
+// ── Central System → station commands ──────────────────────────
+
+pub(super) fn handle_command(
+    state: &mut StationState,
+    config: &StationConfig,
+    action: &str,
+    payload: &Value,
+) -> CommandOutcome {
+    match action {
+        "RemoteStartTransaction" => remote_start_transaction(state, payload),
+        "RemoteStopTransaction" => remote_stop_transaction(state, payload),
+        "Reset" => CommandOutcome::reply(json!({ "status": "Accepted" })).then(FollowUp::Reboot {
+            hard: payload["type"] == "Hard",
+        }),
+        "UnlockConnector" => unlock_connector(state, payload),
+        "ChangeAvailability" => change_availability(state, payload),
+        "ClearCache" => CommandOutcome::reply(json!({ "status": "Accepted" })),
+        "TriggerMessage" => trigger_message(state, config, payload),
+        "GetConfiguration" => get_configuration(state, payload),
+        "ChangeConfiguration" => change_configuration(state, payload),
+        "SetChargingProfile" => set_charging_profile(state, payload),
+        "ClearChargingProfile" => clear_charging_profile(state, payload),
+        "GetCompositeSchedule" => get_composite_schedule(state, config, payload),
+        "DataTransfer" => {
+            let mut response = json!({ "status": "Accepted" });
+            if let Some(data) = payload.get("data") {
+                response["data"] = data.clone();
+            }
+            CommandOutcome::reply(response)
+        }
+        "GetLocalListVersion" => {
+            CommandOutcome::reply(json!({ "listVersion": state.local_list_version }))
+        }
+        "SendLocalList" => send_local_list(state, payload),
+        "ReserveNow" => reserve_now(state, payload),
+        "CancelReservation" => cancel_reservation(state, payload),
+        "UpdateFirmware" => {
+            let notifications = ["Downloading", "Downloaded", "Installing", "Installed"]
+                .into_iter()
+                .map(|status| ("FirmwareStatusNotification", json!({ "status": status })))
+                .collect();
+            CommandOutcome::reply(json!({})).then(FollowUp::Send(notifications))
+        }
+        "GetDiagnostics" => {
+            let notifications = ["Uploading", "Uploaded"]
+                .into_iter()
+                .map(|status| ("DiagnosticsStatusNotification", json!({ "status": status })))
+                .collect();
+            CommandOutcome::reply(json!({
+                "fileName": format!("diagnostics-{}.log", config.charge_point_id),
+            }))
+            .then(FollowUp::Send(notifications))
+        }
+        _ => CommandOutcome::not_implemented(action),
+    }
+}
+
+fn status(status: &str) -> Value {
+    json!({ "status": status })
+}
+
+fn connector_id(payload: &Value) -> Option<u32> {
+    payload["connectorId"].as_u64().map(|id| id as u32)
+}
+
+fn remote_start_transaction(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let Some(id_tag) = payload["idTag"].as_str() else {
+        return CommandOutcome::error("FormationViolation", "idTag is required");
+    };
+
+    let connector = match connector_id(payload) {
+        Some(id) => state
+            .connector(id)
+            .filter(|c| c.operative && c.transaction.is_none())
+            .filter(|c| {
+                matches!(
+                    c.state,
+                    ConnectorState::Available | ConnectorState::Reserved
+                )
+            })
+            .map(|c| c.id),
+        None => state.free_connector(),
+    };
+    let Some(connector_id) = connector else {
+        return CommandOutcome::reply(status("Rejected"));
+    };
+
+    if let Some(profile) = payload.get("chargingProfile") {
+        store_profile(state, connector_id, profile);
+    }
+
+    CommandOutcome::reply(status("Accepted")).then(FollowUp::StartTransaction {
+        connector_id,
+        id_tag: id_tag.to_string(),
+        remote_start_id: None,
+    })
+}
+
+fn remote_stop_transaction(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let transaction_id = payload["transactionId"].to_string();
+    match state.connector_for_transaction(&transaction_id) {
+        Some(connector_id) => {
+            CommandOutcome::reply(status("Accepted")).then(FollowUp::StopTransaction {
+                connector_id,
+                reason: StopReason::Remote,
+            })
+        }
+        None => CommandOutcome::reply(status("Rejected")),
+    }
+}
+
+fn unlock_connector(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let Some(connector) = connector_id(payload).and_then(|id| state.connector(id)) else {
+        return CommandOutcome::reply(status("UnlockFailed"));
+    };
+    let outcome = CommandOutcome::reply(status("Unlocked"));
+    if connector.transaction.is_some() {
+        outcome.then(FollowUp::StopTransaction {
+            connector_id: connector.id,
+            reason: StopReason::UnlockCommand,
+        })
+    } else {
+        outcome
+    }
+}
+
+fn change_availability(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let operative = payload["type"] == "Operative";
+    let targets: Vec<u32> = match connector_id(payload) {
+        Some(0) => state.connectors.iter().map(|c| c.id).collect(),
+        Some(id) if state.connector(id).is_some() => vec![id],
+        _ => return CommandOutcome::reply(status("Rejected")),
+    };
+
+    let mut scheduled = false;
+    let mut notifications = Vec::new();
+    for id in targets {
+        let Some(c) = state.connector_mut(id) else {
+            continue;
+        };
+        c.operative = operative;
+        if c.transaction.is_some() {
+            // Applied once the transaction ends
+            scheduled = true;
+            continue;
+        }
+        let new_state = state.idle_state(id);
+        notifications.push(("StatusNotification", status_notification(id, new_state)));
+        if let Some(c) = state.connector_mut(id) {
+            c.state = new_state;
+        }
+    }
+
+    CommandOutcome::reply(status(if scheduled { "Scheduled" } else { "Accepted" }))
+        .then(FollowUp::Send(notifications))
+}
+
+fn trigger_message(
+    state: &mut StationState,
+    config: &StationConfig,
+    payload: &Value,
+) -> CommandOutcome {
+    let connectors: Vec<&Connector> = match connector_id(payload) {
+        Some(id) => state.connector(id).into_iter().collect(),
+        None => state.connectors.iter().collect(),
+    };
+
+    let calls = match payload["requestedMessage"].as_str().unwrap_or_default() {
+        "BootNotification" => vec![("BootNotification", boot_notification(config))],
+        "Heartbeat" => vec![("Heartbeat", json!({}))],
+        "StatusNotification" => connectors
+            .iter()
+            .map(|c| ("StatusNotification", status_notification(c.id, c.state)))
+            .collect(),
+        "MeterValues" => connectors
+            .iter()
+            .map(|c| ("MeterValues", meter_values(c)))
+            .collect(),
+        "FirmwareStatusNotification" => {
+            vec![("FirmwareStatusNotification", json!({ "status": "Idle" }))]
+        }
+        "DiagnosticsStatusNotification" => {
+            vec![("DiagnosticsStatusNotification", json!({ "status": "Idle" }))]
+        }
+        _ => return CommandOutcome::reply(status("NotImplemented")),
+    };
+    if calls.is_empty() {
+        return CommandOutcome::reply(status("Rejected"));
+    }
+
+    CommandOutcome::reply(status("Accepted")).then(FollowUp::Send(calls))
+}
+
+fn get_configuration(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let requested: Vec<String> = payload["key"]
+        .as_array()
+        .map(|keys| {
+            keys.iter()
+                .filter_map(|k| k.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let entry = |key: &str, (value, readonly): &(String, bool)| json!({ "key": key, "readonly": readonly, "value": value });
+
+    let (known, unknown): (Vec<Value>, Vec<String>) = if requested.is_empty() {
+        (
+            state
+                .configuration
+                .iter()
+                .map(|(k, v)| entry(k, v))
+                .collect(),
+            Vec::new(),
+        )
+    } else {
+        let mut known = Vec::new();
+        let mut unknown = Vec::new();
+        for key in requested {
+            match state.configuration.get(&key) {
+                Some(v) => known.push(entry(&key, v)),
+                None => unknown.push(key),
+            }
+        }
+        (known, unknown)
+    };
+
+    let mut response = json!({ "configurationKey": known });
+    if !unknown.is_empty() {
+        response["unknownKey"] = json!(unknown);
+    }
+    CommandOutcome::reply(response)
+}
+
+fn change_configuration(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let (Some(key), Some(value)) = (payload["key"].as_str(), payload["value"].as_str()) else {
+        return CommandOutcome::error("FormationViolation", "key and value are required");
+    };
+
+    let result = match state.configuration.get_mut(key) {
+        None => "NotSupported",
+        Some((_, true)) => "Rejected",
+        Some((current, false)) => {
+            *current = value.to_string();
+            if key == "HeartbeatInterval" {
+                if let Ok(interval) = value.parse::<u64>() {
+                    state.heartbeat_interval = interval;
+                }
+            }
+            "Accepted"
+        }
+    };
+    CommandOutcome::reply(status(result))
+}
+
+fn store_profile(state: &mut StationState, evse_id: u32, profile: &Value) {
+    let id = profile["chargingProfileId"]
+        .as_i64()
+        .or_else(|| profile["id"].as_i64())
+        .unwrap_or_default() as i32;
+    state.charging_profiles.retain(|p| p.id != id);
+    state.charging_profiles.push(StoredProfile {
+        evse_id,
+        id,
+        stack_level: profile["stackLevel"].as_i64().unwrap_or_default() as i32,
+        purpose: profile["chargingProfilePurpose"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        profile: profile.clone(),
+    });
+}
+
+fn set_charging_profile(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let Some(id) = connector_id(payload) else {
+        return CommandOutcome::error("FormationViolation", "connectorId is required");
+    };
+    let profile = &payload["csChargingProfiles"];
+    let accepted = match state.connector(id) {
+        // TxProfile requires a running transaction on the connector
+        Some(c) => profile["chargingProfilePurpose"] != "TxProfile" || c.transaction.is_some(),
+        None => id == 0 && profile["chargingProfilePurpose"] != "TxProfile",
+    };
+    if !accepted {
+        return CommandOutcome::reply(status("Rejected"));
+    }
+
+    store_profile(state, id, profile);
+    CommandOutcome::reply(status("Accepted"))
+}
+
+fn clear_charging_profile(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let before = state.charging_profiles.len();
+    state.charging_profiles.retain(|p| {
+        let matches = payload["id"].as_i64().is_none_or(|id| p.id as i64 == id)
+            && connector_id(payload).is_none_or(|id| p.evse_id == id)
+            && payload["chargingProfilePurpose"]
+                .as_str()
+                .is_none_or(|purpose| p.purpose == purpose)
+            && payload["stackLevel"]
+                .as_i64()
+                .is_none_or(|level| p.stack_level as i64 == level);
+        !matches
+    });
+
+    let removed = state.charging_profiles.len() < before;
+    CommandOutcome::reply(status(if removed { "Accepted" } else { "Unknown" }))
+}
+
+fn get_composite_schedule(
+    state: &mut StationState,
+    config: &StationConfig,
+    payload: &Value,
+) -> CommandOutcome {
+    let Some(id) = connector_id(payload) else {
+        return CommandOutcome::error("FormationViolation", "connectorId is required");
+    };
+    if id != 0 && state.connector(id).is_none() {
+        return CommandOutcome::reply(status("Rejected"));
+    }
+
+    let limit_w = state.power_limit_w(id, config.max_power_w);
+    let (unit, limit) = match payload["chargingRateUnit"].as_str() {
+        Some("A") => ("A", limit_w / (230.0 * 3.0)),
+        _ => ("W", limit_w),
+    };
+    CommandOutcome::reply(json!({
+        "status": "Accepted",
+        "connectorId": id,
+        "scheduleStart": Utc::now(),
+        "chargingSchedule": {
+            "duration": payload["duration"].as_i64().unwrap_or(86_400),
+            "chargingRateUnit": unit,
+            "chargingSchedulePeriod": [{ "startPeriod": 0, "limit": (limit * 10.0).round() / 10.0 }],
+        },
+    }))
+}
+
+fn send_local_list(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let version = payload["listVersion"].as_i64().unwrap_or_default() as i32;
+    let differential = payload["updateType"] == "Differential";
+    if differential && version <= state.local_list_version {
+        return CommandOutcome::reply(status("VersionMismatch"));
+    }
+
+    if !differential {
+        state.local_list.clear();
+    }
+    for entry in payload["localAuthorizationList"]
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        let Some(id_tag) = entry["idTag"].as_str() else {
+            continue;
+        };
+        match entry["idTagInfo"]["status"].as_str() {
+            Some(tag_status) => {
+                state
+                    .local_list
+                    .insert(id_tag.to_string(), tag_status.to_string());
+            }
+            // Differential entries without idTagInfo are removals
+            None => {
+                state.local_list.remove(id_tag);
+            }
+        }
+    }
+    state.local_list_version = version;
+    CommandOutcome::reply(status("Accepted"))
+}
+
+fn reserve_now(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let reservation_id = payload["reservationId"].as_i64().unwrap_or_default() as i32;
+    let Some(c) = connector_id(payload).and_then(|id| state.connector_mut(id)) else {
+        return CommandOutcome::reply(status("Rejected"));
+    };
+
+    let result = if c.state == ConnectorState::Faulted {
+        "Faulted"
+    } else if !c.operative {
+        "Unavailable"
+    } else if c.transaction.is_some() || c.reservation_id.is_some_and(|id| id != reservation_id) {
+        "Occupied"
+    } else {
+        "Accepted"
+    };
+    if result != "Accepted" {
+        return CommandOutcome::reply(status(result));
+    }
+
+    c.reservation_id = Some(reservation_id);
+    c.state = ConnectorState::Reserved;
+    let notification = status_notification(c.id, ConnectorState::Reserved);
+    CommandOutcome::reply(status("Accepted"))
+        .then(FollowUp::Send(vec![("StatusNotification", notification)]))
+}
+
+fn cancel_reservation(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let reservation_id = payload["reservationId"].as_i64().map(|id| id as i32);
+    let Some(c) = state
+        .connectors
+        .iter_mut()
+        .find(|c| c.reservation_id.is_some() && c.reservation_id == reservation_id)
+    else {
+        return CommandOutcome::reply(status("Rejected"));
+    };
+
+    c.reservation_id = None;
+    let id = c.id;
+    let new_state = state.idle_state(id);
+    if let Some(c) = state.connector_mut(id) {
+        c.state = new_state;
+    }
+    CommandOutcome::reply(status("Accepted")).then(FollowUp::Send(vec![(
+        "StatusNotification",
+        status_notification(id, new_state),
+    )]))
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_ocpp::v1_6::messages::get_configuration::GetConfigurationResponse;
+    use rust_ocpp::v1_6::messages::meter_values::MeterValuesRequest;
+    use rust_ocpp::v1_6::messages::start_transaction::StartTransactionRequest;
+    use rust_ocpp::v1_6::messages::status_notification::StatusNotificationRequest;
+    use rust_ocpp::v1_6::messages::stop_transaction::StopTransactionRequest;
+
+    use super::*;
+    use crate::domain::OcppVersion;
+
+    fn station() -> (StationState, StationConfig) {
+        let config = StationConfig::new("ws://localhost", "SIM", OcppVersion::V16);
+        (StationState::new(&config), config)
+    }
+
+    #[test]
+    fn session_messages_match_ocpp_schema() {
+        let mut connector = station().0.connectors[0].clone();
+        connector.meter_wh = 1234.4;
+        connector.power_w = 7400.0;
+        let tx = ActiveTransaction {
+            transaction_id: "42".to_string(),
+            id_tag: "TAG".to_string(),
+            started_at: Utc::now(),
+            meter_start_wh: 0.0,
+            seq_no: 0,
+            remote_start_id: None,
+        };
+        connector.transaction = Some(tx.clone());
+
+        let start: StartTransactionRequest =
+            serde_json::from_value(start_transaction(1, "TAG", 0.0, Some(7), Utc::now())).unwrap();
+        assert_eq!(start.reservation_id, Some(7));
+        let status: StatusNotificationRequest =
+            serde_json::from_value(status_notification(1, ConnectorState::Charging)).unwrap();
+        assert_eq!(status.connector_id, 1);
+        let meter: MeterValuesRequest = serde_json::from_value(meter_values(&connector)).unwrap();
+        assert_eq!(meter.transaction_id, Some(42));
+        let stop: StopTransactionRequest =
+            serde_json::from_value(stop_transaction(&connector, &tx, StopReason::Remote)).unwrap();
+        assert_eq!(stop.meter_stop, 1234);
+    }
+
+    #[test]
+    fn remote_start_picks_free_connector_and_starts_transaction() {
+        let (mut state, config) = station();
+        state.connectors[0].state = ConnectorState::Charging;
+
+        let outcome = handle_command(
+            &mut state,
+            &config,
+            "RemoteStartTransaction",
+            &json!({ "idTag": "TAG" }),
+        );
+
+        assert_eq!(outcome.response.unwrap(), json!({ "status": "Accepted" }));
+        assert!(matches!(
+            outcome.follow_ups.as_slice(),
+            [FollowUp::StartTransaction {
+                connector_id: 2,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn readonly_configuration_cannot_be_changed() {
+        let (mut state, config) = station();
+
+        let outcome = handle_command(
+            &mut state,
+            &config,
+            "ChangeConfiguration",
+            &json!({ "key": "NumberOfConnectors", "value": "4" }),
+        );
+        assert_eq!(outcome.response.unwrap(), json!({ "status": "Rejected" }));
+
+        let outcome = handle_command(
+            &mut state,
+            &config,
+            "GetConfiguration",
+            &json!({ "key": ["NumberOfConnectors", "Nope"] }),
+        );
+        let response: GetConfigurationResponse =
+            serde_json::from_value(outcome.response.unwrap()).unwrap();
+        assert_eq!(
+            response.configuration_key.unwrap()[0].value.as_deref(),
+            Some("2")
+        );
+        assert_eq!(response.unknown_key, Some(vec!["Nope".to_string()]));
+    }
+
+    #[test]
+    fn unknown_action_is_not_implemented() {
+        let (mut state, config) = station();
+        let outcome = handle_command(&mut state, &config, "Frobnicate", &json!({}));
+        assert_eq!(outcome.response.unwrap_err().0, "NotImplemented");
+    }
+}
@@ -0,0 +1,1277 @@
+//! OCPP 2.0.1 payloads and command responders
+//!
+//! Each connector of the simulated station is modelled as an EVSE with a
+//! single connector (`evseId = connector id`, `connectorId = 1`).
+
+use chrono::Utc;
+use serde_json::{json, Value};
+
+use super::config::StationConfig;
+use super::state::{
+    ActiveTransaction, Connector, ConnectorState, StationState, StopReason, StoredMonitor,
+    StoredProfile,
+};
+use super::station::{CommandOutcome, FollowUp};
+
+/// Device-model variables the CSMS may not change.
+const READ_ONLY_VARIABLES: &[(&str, &str)] = &[
+    ("ChargingStation", "Model"),
+    ("ChargingStation", "VendorName"),
+];
+
+// ── Station-initiated messages ─────────────────────────────────
+
+pub(super) fn boot_notification(config: &StationConfig, after_reset: bool) -> Value {
+    let mut station = json!({
+        "model": config.model,
+        "vendorName": config.vendor,
+        "firmwareVersion": config.firmware_version,
+    });
+    if let Some(serial) = &config.serial_number {
+        station["serialNumber"] = json!(serial);
+    }
+    json!({
+        "chargingStation": station,
+        "reason": if after_reset { "RemoteReset" } else { "PowerUp" },
+    })
+}
+
+fn connector_status(state: ConnectorState) -> &'static str {
+    match state {
+        ConnectorState::Available => "Available",
+        ConnectorState::Preparing | ConnectorState::Charging | ConnectorState::Finishing => {
+            "Occupied"
+        }
+        ConnectorState::Reserved => "Reserved",
+        ConnectorState::Unavailable => "Unavailable",
+        ConnectorState::Faulted => "Faulted",
+    }
+}
+
+pub(super) fn status_notification(evse_id: u32, state: ConnectorState) -> Value {
+    json!({
+        "timestamp": Utc::now(),
+        "connectorStatus": connector_status(state),
+        "evseId": evse_id,
+        "connectorId": 1,
+    })
+}
+
+fn id_token(id_tag: &str) -> Value {
+    json!({ "idToken": id_tag, "type": "ISO14443" })
+}
+
+pub(super) fn authorize(id_tag: &str) -> Value {
+    json!({ "idToken": id_token(id_tag) })
+}
+
+fn meter_value(connector: &Connector, context: &str) -> Value {
+    json!({
+        "timestamp": Utc::now(),
+        "sampledValue": [
+            {
+                "value": connector.meter_wh.round(),
+                "context": context,
+                "measurand": "Energy.Active.Import.Register",
+                "unitOfMeasure": { "unit": "Wh" },
+            },
+            {
+                "value": connector.power_w.round(),
+                "context": context,
+                "measurand": "Power.Active.Import",
+                "unitOfMeasure": { "unit": "W" },
+            },
+        ],
+    })
+}
+
+pub(super) fn meter_values(connector: &Connector) -> Value {
+    json!({
+        "evseId": connector.id,
+        "meterValue": [meter_value(connector, "Sample.Periodic")],
+    })
+}
+
+fn transaction_event(
+    event_type: &str,
+    trigger_reason: &str,
+    connector: &Connector,
+    transaction: &ActiveTransaction,
+    transaction_info: Value,
+    context: &str,
+) -> Value {
+    json!({
+        "eventType": event_type,
+        "timestamp": Utc::now(),
+        "triggerReason": trigger_reason,
+        "seqNo": transaction.seq_no,
+        "transactionInfo": transaction_info,
+        "evse": { "id": connector.id, "connectorId": 1 },
+        "meterValue": [meter_value(connector, context)],
+    })
+}
+
+pub(super) fn transaction_started(
+    evse_id: u32,
+    transaction: &ActiveTransaction,
+    reservation_id: Option<i32>,
+) -> Value {
+    let connector = Connector {
+        id: evse_id,
+        state: ConnectorState::Charging,
+        operative: true,
+        meter_wh: transaction.meter_start_wh,
+        power_w: 0.0,
+        transaction: None,
+        reservation_id: None,
+    };
+    let mut info = json!({
+        "transactionId": transaction.transaction_id,
+        "chargingState": "Charging",
+    });
+    let trigger_reason = match transaction.remote_start_id {
+        Some(id) => {
+            info["remoteStartId"] = json!(id);
+            "RemoteStart"
+        }
+        None => "Authorized",
+    };
+
+    let mut payload = transaction_event(
+        "Started",
+        trigger_reason,
+        &connector,
+        transaction,
+        info,
+        "Transaction.Begin",
+    );
+    payload["idToken"] = id_token(&transaction.id_tag);
+    if let Some(id) = reservation_id {
+        payload["reservationId"] = json!(id);
+    }
+    payload
+}
+
+fn transaction_update(
+    connector: &Connector,
+    transaction: &ActiveTransaction,
+    trigger_reason: &str,
+) -> Value {
+    transaction_event(
+        "Updated",
+        trigger_reason,
+        connector,
+        transaction,
+        json!({ "transactionId": transaction.transaction_id, "chargingState": "Charging" }),
+        if trigger_reason == "Trigger" {
+            "Trigger"
+        } else {
+            "Sample.Periodic"
+        },
+    )
+}
+
+pub(super) fn transaction_updated(connector: &Connector, transaction: &ActiveTransaction) -> Value {
+    transaction_update(connector, transaction, "MeterValuePeriodic")
+}
+
+pub(super) fn transaction_ended(
+    connector: &Connector,
+    transaction: &ActiveTransaction,
+    reason: StopReason,
+) -> Value {
+    let (trigger_reason, stopped_reason) = match reason {
+        StopReason::Local => ("StopAuthorized", "Local"),
+        StopReason::Remote => ("RemoteStop", "Remote"),
+        StopReason::Reset { hard: true } => ("ResetCommand", "ImmediateReset"),
+        StopReason::Reset { hard: false } => ("ResetCommand", "Reboot"),
+        StopReason::UnlockCommand => ("UnlockCommand", "Other"),
+    };
+    transaction_event(
+        "Ended",
+        trigger_reason,
+        connector,
+        transaction,
+        json!({
+            "transactionId": transaction.transaction_id,
+            "chargingState": "Idle",
+            "stoppedReason": stopped_reason,
+        }),
+        "Transaction.End",
+    )
+}
+
+// ── Central System → station commands ──────────────────────────
+
+pub(super) fn handle_command(
+    state: &mut StationState,
+    config: &StationConfig,
+    action: &str,
+    payload: &Value,
+) -> CommandOutcome {
+    match action {
+        "RequestStartTransaction" => request_start_transaction(state, payload),
+        "RequestStopTransaction" => request_stop_transaction(state, payload),
+        "Reset" => reset(state, payload),
+        "UnlockConnector" => unlock_connector(state, payload),
+        "ChangeAvailability" => change_availability(state, payload),
+        "ClearCache" => CommandOutcome::reply(status("Accepted")),
+        "TriggerMessage" => trigger_message(state, config, payload),
+        "GetVariables" => get_variables(state, payload),
+        "SetVariables" => set_variables(state, payload),
+        "GetBaseReport" => {
+            let request_id = request_id(payload);
+            let data = report_data(state, |_, _| true);
+            CommandOutcome::reply(status("Accepted"))
+                .then(FollowUp::Send(notify_report(state, request_id, data)))
+        }
+        "GetReport" => get_report(state, payload),
+        "SetChargingProfile" => set_charging_profile(state, payload),
+        "ClearChargingProfile" => clear_charging_profile(state, payload),
+        "GetChargingProfiles" => get_charging_profiles(state, payload),
+        "GetCompositeSchedule" => get_composite_schedule(state, config, payload),
+        "DataTransfer" => {
+            let mut response = status("Accepted");
+            if let Some(data) = payload.get("data") {
+                response["data"] = data.clone();
+            }
+            CommandOutcome::reply(response)
+        }
+        "GetLocalListVersion" => {
+            CommandOutcome::reply(json!({ "versionNumber": state.local_list_version }))
+        }
+        "SendLocalList" => send_local_list(state, payload),
+        "ReserveNow" => reserve_now(state, payload),
+        "CancelReservation" => cancel_reservation(state, payload),
+        "UpdateFirmware" => {
+            let request_id = request_id(payload);
+            let notifications = ["Downloading", "Downloaded", "Installing", "Installed"]
+                .into_iter()
+                .map(|s| {
+                    (
+                        "FirmwareStatusNotification",
+                        json!({ "status": s, "requestId": request_id }),
+                    )
+                })
+                .collect();
+            CommandOutcome::reply(status("Accepted")).then(FollowUp::Send(notifications))
+        }
+        "GetLog" => {
+            let request_id = request_id(payload);
+            let notifications = ["Uploading", "Uploaded"]
+                .into_iter()
+                .map(|s| {
+                    (
+                        "LogStatusNotification",
+                        json!({ "status": s, "requestId": request_id }),
+                    )
+                })
+                .collect();
+            CommandOutcome::reply(json!({
+                "status": "Accepted",
+                "filename": format!("{}-{}.log", config.charge_point_id, request_id),
+            }))
+            .then(FollowUp::Send(notifications))
+        }
+        "SetVariableMonitoring" => set_variable_monitoring(state, payload),
+        "SetMonitoringBase" => {
+            if payload["monitoringBase"] != "All" {
+                // Every simulated monitor is a custom one
+                state.monitors.clear();
+            }
+            CommandOutcome::reply(status("Accepted"))
+        }
+        "SetMonitoringLevel" => match payload["severity"].as_u64() {
+            Some(severity) if severity <= 9 => {
+                state.monitoring_level = severity as u8;
+                CommandOutcome::reply(status("Accepted"))
+            }
+            _ => CommandOutcome::reply(status("Rejected")),
+        },
+        "ClearVariableMonitoring" => {
+            let results: Vec<Value> = payload["id"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_i64)
+                .map(|id| {
+                    let found = state.monitors.remove(&(id as i32)).is_some();
+                    json!({ "id": id, "status": if found { "Accepted" } else { "NotFound" } })
+                })
+                .collect();
+            CommandOutcome::reply(json!({ "clearMonitoringResult": results }))
+        }
+        "GetMonitoringReport" => get_monitoring_report(state, payload),
+        "GetTransactionStatus" => {
+            let mut response = json!({ "messagesInQueue": false });
+            if let Some(id) = payload["transactionId"].as_str() {
+                response["ongoingIndicator"] = json!(state.connector_for_transaction(id).is_some());
+            }
+            CommandOutcome::reply(response)
+        }
+        "SetDisplayMessage" => set_display_message(state, payload),
+        "GetDisplayMessages" => get_display_messages(state, payload),
+        "ClearDisplayMessage" => {
+            let id = payload["id"].as_i64().unwrap_or_default() as i32;
+            let found = state.display_messages.remove(&id).is_some();
+            CommandOutcome::reply(status(if found { "Accepted" } else { "Unknown" }))
+        }
+        "CustomerInformation" => customer_information(state, payload),
+        _ => CommandOutcome::not_implemented(action),
+    }
+}
+
+fn status(status: &str) -> Value {
+    json!({ "status": status })
+}
+
+fn request_id(payload: &Value) -> i64 {
+    payload["requestId"].as_i64().unwrap_or_default()
+}
+
+fn evse_id(payload: &Value) -> Option<u32> {
+    payload["evseId"].as_u64().map(|id| id as u32)
+}
+
+fn request_start_transaction(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let (Some(id_tag), Some(remote_start_id)) = (
+        payload["idToken"]["idToken"].as_str(),
+        payload["remoteStartId"].as_i64(),
+    ) else {
+        return CommandOutcome::error(
+            "FormationViolation",
+            "idToken and remoteStartId are required",
+        );
+    };
+
+    let evse = match evse_id(payload) {
+        Some(id) => state
+            .connector(id)
+            .filter(|c| c.operative && c.transaction.is_none())
+            .filter(|c| {
+                matches!(
+                    c.state,
+                    ConnectorState::Available | ConnectorState::Reserved
+                )
+            })
+            .map(|c| c.id),
+        None => state.free_connector(),
+    };
+    let Some(connector_id) = evse else {
+        return CommandOutcome::reply(status("Rejected"));
+    };
+
+    if let Some(profile) = payload.get("chargingProfile") {
+        store_profile(state, connector_id, profile);
+    }
+
+    CommandOutcome::reply(status("Accepted")).then(FollowUp::StartTransaction {
+        connector_id,
+        id_tag: id_tag.to_string(),
+        remote_start_id: Some(remote_start_id as i32),
+    })
+}
+
+fn request_stop_transaction(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let transaction_id = payload["transactionId"].as_str().unwrap_or_default();
+    match state.connector_for_transaction(transaction_id) {
+        Some(connector_id) => {
+            CommandOutcome::reply(status("Accepted")).then(FollowUp::StopTransaction {
+                connector_id,
+                reason: StopReason::Remote,
+            })
+        }
+        None => CommandOutcome::reply(status("Rejected")),
+    }
+}
+
+fn reset(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    if payload.get("evseId").is_some() {
+        // Resetting a single EVSE is not modelled
+        return CommandOutcome::reply(status("Rejected"));
+    }
+    match payload["type"].as_str() {
+        Some("OnIdle") if state.has_active_transaction() => {
+            CommandOutcome::reply(status("Scheduled"))
+        }
+        Some(kind) => CommandOutcome::reply(status("Accepted")).then(FollowUp::Reboot {
+            hard: kind == "Immediate",
+        }),
+        None => CommandOutcome::error("FormationViolation", "type is required"),
+    }
+}
+
+fn unlock_connector(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let connector = evse_id(payload)
+        .and_then(|id| state.connector(id))
+        .filter(|_| payload["connectorId"] == 1);
+    let result = match connector {
+        None => "UnknownConnector",
+        Some(c) if c.transaction.is_some() => "OngoingAuthorizedTransaction",
+        Some(_) => "Unlocked",
+    };
+    CommandOutcome::reply(status(result))
+}
+
+fn change_availability(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let operative = payload["operationalStatus"] == "Operative";
+    let targets: Vec<u32> = match payload["evse"]["id"].as_u64().map(|id| id as u32) {
+        None | Some(0) => state.connectors.iter().map(|c| c.id).collect(),
+        Some(id) if state.connector(id).is_some() => vec![id],
+        _ => return CommandOutcome::reply(status("Rejected")),
+    };
+
+    let mut scheduled = false;
+    let mut notifications = Vec::new();
+    for id in targets {
+        let Some(c) = state.connector_mut(id) else {
+            continue;
+        };
+        c.operative = operative;
+        if c.transaction.is_some() {
+            // Applied once the transaction ends
+            scheduled = true;
+            continue;
+        }
+        let new_state = state.idle_state(id);
+        notifications.push(("StatusNotification", status_notification(id, new_state)));
+        if let Some(c) = state.connector_mut(id) {
+            c.state = new_state;
+        }
+    }
+
+    CommandOutcome::reply(status(if scheduled { "Scheduled" } else { "Accepted" }))
+        .then(FollowUp::Send(notifications))
+}
+
+fn trigger_message(
+    state: &mut StationState,
+    config: &StationConfig,
+    payload: &Value,
+) -> CommandOutcome {
+    let requested_evse = payload["evse"]["id"].as_u64().map(|id| id as u32);
+    let selected = |c: &Connector| requested_evse.is_none_or(|id| c.id == id);
+
+    let calls: Vec<(&'static str, Value)> =
+        match payload["requestedMessage"].as_str().unwrap_or_default() {
+            "BootNotification" => vec![("BootNotification", boot_notification(config, false))],
+            "Heartbeat" => vec![("Heartbeat", json!({}))],
+            "StatusNotification" => state
+                .connectors
+                .iter()
+                .filter(|c| selected(c))
+                .map(|c| ("StatusNotification", status_notification(c.id, c.state)))
+                .collect(),
+            "MeterValues" => state
+                .connectors
+                .iter()
+                .filter(|c| selected(c))
+                .map(|c| ("MeterValues", meter_values(c)))
+                .collect(),
+            "TransactionEvent" => state
+                .connectors
+                .iter_mut()
+                .filter(|c| requested_evse.is_none_or(|id| c.id == id))
+                .filter_map(|c| {
+                    let snapshot = c.clone();
+                    let tx = c.transaction.as_mut()?;
+                    let payload = transaction_update(&snapshot, tx, "Trigger");
+                    tx.seq_no += 1;
+                    Some(("TransactionEvent", payload))
+                })
+                .collect(),
+            "FirmwareStatusNotification" => {
+                vec![("FirmwareStatusNotification", status("Idle"))]
+            }
+            "LogStatusNotification" => vec![("LogStatusNotification", status("Idle"))],
+            _ => return CommandOutcome::reply(status("NotImplemented")),
+        };
+    if calls.is_empty() {
+        return CommandOutcome::reply(status("Rejected"));
+    }
+
+    CommandOutcome::reply(status("Accepted")).then(FollowUp::Send(calls))
+}
+
+// ── Device model ───────────────────────────────────────────────
+
+/// Look up a component/variable pair; `Err` carries the attribute status.
+fn lookup<'a>(
+    state: &'a StationState,
+    component: &str,
+    variable: &str,
+) -> Result<&'a String, &'static str> {
+    match state
+        .variables
+        .get(&(component.to_string(), variable.to_string()))
+    {
+        Some(value) => Ok(value),
+        None if state.variables.keys().any(|(c, _)| c == component) => Err("UnknownVariable"),
+        None => Err("UnknownComponent"),
+    }
+}
+
+fn get_variables(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let results: Vec<Value> = payload["getVariableData"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|data| {
+            let component = data["component"]["name"].as_str().unwrap_or_default();
+            let variable = data["variable"]["name"].as_str().unwrap_or_default();
+            let mut result = json!({
+                "component": data["component"],
+                "variable": data["variable"],
+            });
+            match lookup(state, component, variable) {
+                Ok(value) => {
+                    result["attributeStatus"] = json!("Accepted");
+                    result["attributeValue"] = json!(value);
+                }
+                Err(attribute_status) => result["attributeStatus"] = json!(attribute_status),
+            }
+            result
+        })
+        .collect();
+    CommandOutcome::reply(json!({ "getVariableResult": results }))
+}
+
+fn set_variables(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let results: Vec<Value> = payload["setVariableData"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|data| {
+            let component = data["component"]["name"].as_str().unwrap_or_default();
+            let variable = data["variable"]["name"].as_str().unwrap_or_default();
+            let value = data["attributeValue"].as_str().unwrap_or_default();
+
+            let attribute_status = match lookup(state, component, variable) {
+                Err(s) => s,
+                Ok(_) if READ_ONLY_VARIABLES.contains(&(component, variable)) => "Rejected",
+                Ok(_) => {
+                    if (component, variable) == ("OCPPCommCtrlr", "HeartbeatInterval") {
+                        match value.parse::<u64>() {
+                            Ok(interval) => state.heartbeat_interval = interval,
+                            Err(_) => {
+                                return json!({
+                                    "attributeStatus": "Rejected",
+                                    "component": data["component"],
+                                    "variable": data["variable"],
+                                })
+                            }
+                        }
+                    }
+                    state.variables.insert(
+                        (component.to_string(), variable.to_string()),
+                        value.to_string(),
+                    );
+                    "Accepted"
+                }
+            };
+            json!({
+                "attributeStatus": attribute_status,
+                "component": data["component"],
+                "variable": data["variable"],
+            })
+        })
+        .collect();
+    CommandOutcome::reply(json!({ "setVariableResult": results }))
+}
+
+/// Build NotifyReport `reportData` entries for the selected variables.
+fn report_data(state: &StationState, select: impl Fn(&str, &str) -> bool) -> Vec<Value> {
+    state
+        .variables
+        .iter()
+        .filter(|((component, variable), _)| select(component, variable))
+        .map(|((component, variable), value)| {
+            let read_only = READ_ONLY_VARIABLES.contains(&(component.as_str(), variable.as_str()));
+            let data_type = if value.parse::<i64>().is_ok() {
+                "integer"
+            } else if value == "true" || value == "false" {
+                "boolean"
+            } else {
+                "string"
+            };
+            json!({
+                "component": { "name": component },
+                "variable": { "name": variable },
+                "variableAttribute": [{
+                    "type": "Actual",
+                    "value": value,
+                    "mutability": if read_only { "ReadOnly" } else { "ReadWrite" },
+                }],
+                "variableCharacteristics": { "dataType": data_type, "supportsMonitoring": true },
+            })
+        })
+        .collect()
+}
+
+/// Split report data into NotifyReport parts of `ItemsPerMessage` entries.
+fn notify_report(
+    state: &StationState,
+    request_id: i64,
+    data: Vec<Value>,
+) -> Vec<(&'static str, Value)> {
+    let per_message = state
+        .variables
+        .get(&("DeviceDataCtrlr".to_string(), "ItemsPerMessage".to_string()))
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(10)
+        .max(1);
+    let generated_at = Utc::now();
+    let parts: Vec<&[Value]> = data.chunks(per_message).collect();
+    let count = parts.len();
+    parts
+        .into_iter()
+        .enumerate()
+        .map(|(seq_no, chunk)| {
+            (
+                "NotifyReport",
+                json!({
+                    "requestId": request_id,
+                    "generatedAt": generated_at,
+                    "seqNo": seq_no,
+                    "tbc": seq_no + 1 < count,
+                    "reportData": chunk,
+                }),
+            )
+        })
+        .collect()
+}
+
+/// Does `componentVariable` (absent = everything) select this pair?
+fn selected_by(selectors: &Value, component: &str, variable: &str) -> bool {
+    match selectors.as_array() {
+        None => true,
+        Some(list) => list.iter().any(|s| {
+            s["component"]["name"] == component
+                && s["variable"]["name"]
+                    .as_str()
+                    .is_none_or(|name| name == variable)
+        }),
+    }
+}
+
+fn get_report(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    // Every simulated component is Active, Available and Enabled; none
+    // has a Problem
+    let criteria_match = payload["componentCriteria"]
+        .as_array()
+        .is_none_or(|criteria| criteria.iter().any(|c| c != "Problem"));
+    let selectors = &payload["componentVariable"];
+    let data = report_data(state, |component, variable| {
+        criteria_match && selected_by(selectors, component, variable)
+    });
+    if data.is_empty() {
+        return CommandOutcome::reply(status("EmptyResultSet"));
+    }
+
+    let calls = notify_report(state, request_id(payload), data);
+    CommandOutcome::reply(status("Accepted")).then(FollowUp::Send(calls))
+}
+
+// ── Monitoring ─────────────────────────────────────────────────
+
+fn set_variable_monitoring(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let results: Vec<Value> = payload["setMonitoringData"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|data| {
+            let component = data["component"]["name"].as_str().unwrap_or_default();
+            let variable = data["variable"]["name"].as_str().unwrap_or_default();
+            let mut result = json!({
+                "type": data["type"],
+                "severity": data["severity"],
+                "component": data["component"],
+                "variable": data["variable"],
+            });
+
+            if let Err(s) = lookup(state, component, variable) {
+                result["status"] = json!(s);
+                return result;
+            }
+            let id = match data["id"].as_i64() {
+                Some(id) if !state.monitors.contains_key(&(id as i32)) => {
+                    result["status"] = json!("Rejected");
+                    return result;
+                }
+                Some(id) => id as i32,
+                None => {
+                    let id = state.next_monitor_id;
+                    state.next_monitor_id += 1;
+                    id
+                }
+            };
+            state.monitors.insert(
+                id,
+                StoredMonitor {
+                    component: data["component"].clone(),
+                    variable: data["variable"].clone(),
+                    monitor_type: data["type"].as_str().unwrap_or_default().to_string(),
+                    value: data["value"].as_f64().unwrap_or_default(),
+                    severity: data["severity"].as_i64().unwrap_or_default() as i32,
+                    transaction: data["transaction"].as_bool().unwrap_or(false),
+                },
+            );
+            result["id"] = json!(id);
+            result["status"] = json!("Accepted");
+            result
+        })
+        .collect();
+    CommandOutcome::reply(json!({ "setMonitoringResult": results }))
+}
+
+fn get_monitoring_report(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let criteria: Vec<&str> = payload["monitoringCriteria"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    let matches_criteria = |monitor_type: &str| {
+        criteria.is_empty()
+            || criteria.iter().any(|c| match *c {
+                "ThresholdMonitoring" => monitor_type.ends_with("Threshold"),
+                "DeltaMonitoring" => monitor_type == "Delta",
+                "PeriodicMonitoring" => monitor_type.starts_with("Periodic"),
+                _ => false,
+            })
+    };
+
+    let selectors = &payload["componentVariable"];
+    let monitors: Vec<Value> = state
+        .monitors
+        .iter()
+        .filter(|(_, m)| matches_criteria(&m.monitor_type))
+        .filter(|(_, m)| {
+            selected_by(
+                selectors,
+                m.component["name"].as_str().unwrap_or_default(),
+                m.variable["name"].as_str().unwrap_or_default(),
+            )
+        })
+        .map(|(id, m)| {
+            json!({
+                "component": m.component,
+                "variable": m.variable,
+                "variableMonitoring": [{
+                    "id": id,
+                    "transaction": m.transaction,
+                    "value": m.value,
+                    "type": m.monitor_type,
+                    "severity": m.severity,
+                }],
+            })
+        })
+        .collect();
+    if monitors.is_empty() {
+        return CommandOutcome::reply(status("EmptyResultSet"));
+    }
+
+    let report = json!({
+        "requestId": request_id(payload),
+        "seqNo": 0,
+        "generatedAt": Utc::now(),
+        "tbc": false,
+        "monitor": monitors,
+    });
+    CommandOutcome::reply(status("Accepted"))
+        .then(FollowUp::Send(vec![("NotifyMonitoringReport", report)]))
+}
+
+// ── Smart charging ─────────────────────────────────────────────
+
+fn store_profile(state: &mut StationState, evse_id: u32, profile: &Value) {
+    let id = profile["id"].as_i64().unwrap_or_default() as i32;
+    state.charging_profiles.retain(|p| p.id != id);
+    state.charging_profiles.push(StoredProfile {
+        evse_id,
+        id,
+        stack_level: profile["stackLevel"].as_i64().unwrap_or_default() as i32,
+        purpose: profile["chargingProfilePurpose"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        profile: profile.clone(),
+    });
+}
+
+fn set_charging_profile(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let Some(id) = evse_id(payload) else {
+        return CommandOutcome::error("FormationViolation", "evseId is required");
+    };
+    let profile = &payload["chargingProfile"];
+    let is_tx_profile = profile["chargingProfilePurpose"] == "TxProfile";
+    let accepted = match state.connector(id) {
+        // TxProfile requires a running transaction on the EVSE
+        Some(c) => !is_tx_profile || c.transaction.is_some(),
+        None => id == 0 && !is_tx_profile,
+    };
+    if !accepted {
+        return CommandOutcome::reply(status("Rejected"));
+    }
+
+    store_profile(state, id, profile);
+    CommandOutcome::reply(status("Accepted"))
+}
+
+fn clear_charging_profile(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let criteria = &payload["chargingProfileCriteria"];
+    let before = state.charging_profiles.len();
+    state.charging_profiles.retain(|p| {
+        let matches = payload["chargingProfileId"]
+            .as_i64()
+            .is_none_or(|id| p.id as i64 == id)
+            && criteria["evseId"]
+                .as_u64()
+                .is_none_or(|id| p.evse_id as u64 == id)
+            && criteria["chargingProfilePurpose"]
+                .as_str()
+                .is_none_or(|purpose| p.purpose == purpose)
+            && criteria["stackLevel"]
+                .as_i64()
+                .is_none_or(|level| p.stack_level as i64 == level);
+        !matches
+    });
+
+    let removed = state.charging_profiles.len() < before;
+    CommandOutcome::reply(status(if removed { "Accepted" } else { "Unknown" }))
+}
+
+fn get_charging_profiles(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let criteria = &payload["chargingProfile"];
+    let requested_ids: Option<Vec<i64>> = criteria["chargingProfileId"]
+        .as_array()
+        .map(|ids| ids.iter().filter_map(Value::as_i64).collect());
+
+    let matching: Vec<&StoredProfile> = state
+        .charging_profiles
+        .iter()
+        .filter(|p| evse_id(payload).is_none_or(|id| p.evse_id == id))
+        .filter(|p| {
+            criteria["chargingProfilePurpose"]
+                .as_str()
+                .is_none_or(|purpose| p.purpose == purpose)
+        })
+        .filter(|p| {
+            criteria["stackLevel"]
+                .as_i64()
+                .is_none_or(|level| p.stack_level as i64 == level)
+        })
+        .filter(|p| {
+            requested_ids
+                .as_ref()
+                .is_none_or(|ids| ids.contains(&(p.id as i64)))
+        })
+        .collect();
+    if matching.is_empty() {
+        return CommandOutcome::reply(status("NoProfiles"));
+    }
+
+    // One ReportChargingProfiles per EVSE, the last one closing the report
+    let mut evses: Vec<u32> = matching.iter().map(|p| p.evse_id).collect();
+    evses.sort_unstable();
+    evses.dedup();
+    let count = evses.len();
+    let reports = evses
+        .into_iter()
+        .enumerate()
+        .map(|(i, evse)| {
+            let profiles: Vec<&Value> = matching
+                .iter()
+                .filter(|p| p.evse_id == evse)
+                .map(|p| &p.profile)
+                .collect();
+            (
+                "ReportChargingProfiles",
+                json!({
+                    "requestId": request_id(payload),
+                    "chargingLimitSource": "CSO",
+                    "evseId": evse,
+                    "chargingProfile": profiles,
+                    "tbc": i + 1 < count,
+                }),
+            )
+        })
+        .collect();
+    CommandOutcome::reply(status("Accepted")).then(FollowUp::Send(reports))
+}
+
+fn get_composite_schedule(
+    state: &mut StationState,
+    config: &StationConfig,
+    payload: &Value,
+) -> CommandOutcome {
+    let Some(id) = evse_id(payload) else {
+        return CommandOutcome::error("FormationViolation", "evseId is required");
+    };
+    if id != 0 && state.connector(id).is_none() {
+        return CommandOutcome::reply(status("Rejected"));
+    }
+
+    let limit_w = state.power_limit_w(id, config.max_power_w);
+    let (unit, limit) = match payload["chargingRateUnit"].as_str() {
+        Some("A") => ("A", limit_w / (230.0 * 3.0)),
+        _ => ("W", limit_w),
+    };
+    CommandOutcome::reply(json!({
+        "status": "Accepted",
+        "schedule": {
+            "evseId": id,
+            "duration": payload["duration"].as_i64().unwrap_or(86_400),
+            "scheduleStart": Utc::now(),
+            "chargingRateUnit": unit,
+            "chargingSchedulePeriod": [{ "startPeriod": 0, "limit": (limit * 10.0).round() / 10.0 }],
+        },
+    }))
+}
+
+// ── Authorization & reservations ───────────────────────────────
+
+fn send_local_list(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let version = payload["versionNumber"].as_i64().unwrap_or_default() as i32;
+    let differential = payload["updateType"] == "Differential";
+    if differential && version <= state.local_list_version {
+        return CommandOutcome::reply(status("VersionMismatch"));
+    }
+
+    if !differential {
+        state.local_list.clear();
+    }
+    for entry in payload["localAuthorizationList"]
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        let Some(id_tag) = entry["idToken"]["idToken"].as_str() else {
+            continue;
+        };
+        match entry["idTokenInfo"]["status"].as_str() {
+            Some(token_status) => {
+                state
+                    .local_list
+                    .insert(id_tag.to_string(), token_status.to_string());
+            }
+            // Differential entries without idTokenInfo are removals
+            None => {
+                state.local_list.remove(id_tag);
+            }
+        }
+    }
+    state.local_list_version = version;
+    CommandOutcome::reply(status("Accepted"))
+}
+
+fn reserve_now(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let reservation_id = payload["id"].as_i64().unwrap_or_default() as i32;
+    let target = match evse_id(payload) {
+        Some(id) => state.connector(id).map(|c| c.id),
+        None => state.free_connector(),
+    };
+    let Some(c) = target.and_then(|id| state.connector_mut(id)) else {
+        return CommandOutcome::reply(status(if evse_id(payload).is_some() {
+            "Rejected"
+        } else {
+            "Occupied"
+        }));
+    };
+
+    let result = if c.state == ConnectorState::Faulted {
+        "Faulted"
+    } else if !c.operative {
+        "Unavailable"
+    } else if c.transaction.is_some() || c.reservation_id.is_some_and(|id| id != reservation_id) {
+        "Occupied"
+    } else {
+        "Accepted"
+    };
+    if result != "Accepted" {
+        return CommandOutcome::reply(status(result));
+    }
+
+    c.reservation_id = Some(reservation_id);
+    c.state = ConnectorState::Reserved;
+    let notification = status_notification(c.id, ConnectorState::Reserved);
+    CommandOutcome::reply(status("Accepted"))
+        .then(FollowUp::Send(vec![("StatusNotification", notification)]))
+}
+
+fn cancel_reservation(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let reservation_id = payload["reservationId"].as_i64().map(|id| id as i32);
+    let Some(c) = state
+        .connectors
+        .iter_mut()
+        .find(|c| c.reservation_id.is_some() && c.reservation_id == reservation_id)
+    else {
+        return CommandOutcome::reply(status("Rejected"));
+    };
+
+    c.reservation_id = None;
+    let id = c.id;
+    let new_state = state.idle_state(id);
+    if let Some(c) = state.connector_mut(id) {
+        c.state = new_state;
+    }
+    CommandOutcome::reply(status("Accepted")).then(FollowUp::Send(vec![(
+        "StatusNotification",
+        status_notification(id, new_state),
+    )]))
+}
+
+// ── Display messages & customer information ────────────────────
+
+fn set_display_message(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let message = &payload["message"];
+    let Some(id) = message["id"].as_i64() else {
+        return CommandOutcome::error("FormationViolation", "message.id is required");
+    };
+    if let Some(transaction_id) = message["transactionId"].as_str() {
+        if state.connector_for_transaction(transaction_id).is_none() {
+            return CommandOutcome::reply(status("UnknownTransaction"));
+        }
+    }
+
+    state.display_messages.insert(id as i32, message.clone());
+    CommandOutcome::reply(status("Accepted"))
+}
+
+fn get_display_messages(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let ids: Option<Vec<i64>> = payload["id"]
+        .as_array()
+        .map(|ids| ids.iter().filter_map(Value::as_i64).collect());
+    let messages: Vec<&Value> = state
+        .display_messages
+        .iter()
+        .filter(|(id, _)| ids.as_ref().is_none_or(|ids| ids.contains(&(**id as i64))))
+        .map(|(_, m)| m)
+        .filter(|m| {
+            payload["priority"]
+                .as_str()
+                .is_none_or(|p| m["priority"] == p)
+        })
+        .filter(|m| payload["state"].as_str().is_none_or(|s| m["state"] == s))
+        .collect();
+    if messages.is_empty() {
+        return CommandOutcome::reply(status("Unknown"));
+    }
+
+    let notification = json!({
+        "requestId": request_id(payload),
+        "tbc": false,
+        "messageInfo": messages,
+    });
+    CommandOutcome::reply(status("Accepted")).then(FollowUp::Send(vec![(
+        "NotifyDisplayMessages",
+        notification,
+    )]))
+}
+
+fn customer_information(state: &mut StationState, payload: &Value) -> CommandOutcome {
+    let id_tag = payload["idToken"]["idToken"].as_str();
+    let identifier = payload["customerIdentifier"].as_str();
+    if id_tag.is_none() && identifier.is_none() && payload.get("customerCertificate").is_none() {
+        return CommandOutcome::reply(status("Invalid"));
+    }
+
+    let mut outcome = CommandOutcome::reply(status("Accepted"));
+    if payload["report"] == true {
+        let data = match id_tag.and_then(|t| state.local_list.get(t).map(|s| (t, s))) {
+            Some((tag, tag_status)) => format!("Local list entry {}: {}", tag, tag_status),
+            None => "No customer data stored".to_string(),
+        };
+        outcome = outcome.then(FollowUp::Send(vec![(
+            "NotifyCustomerInformation",
+            json!({
+                "data": data,
+                "tbc": false,
+                "seqNo": 0,
+                "generatedAt": Utc::now(),
+                "requestId": request_id(payload),
+            }),
+        )]));
+    }
+    if payload["clear"] == true {
+        if let Some(tag) = id_tag {
+            state.local_list.remove(tag);
+        }
+    }
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_ocpp::v2_0_1::messages::boot_notification::BootNotificationRequest;
+    use rust_ocpp::v2_0_1::messages::get_variables::GetVariablesResponse;
+    use rust_ocpp::v2_0_1::messages::notify_monitoring_report::NotifyMonitoringReportRequest;
+    use rust_ocpp::v2_0_1::messages::notify_report::NotifyReportRequest;
+    use rust_ocpp::v2_0_1::messages::status_notification::StatusNotificationRequest;
+    use rust_ocpp::v2_0_1::messages::transaction_event::TransactionEventRequest;
+
+    use super::*;
+    use crate::domain::OcppVersion;
+
+    fn station() -> (StationState, StationConfig) {
+        let config = StationConfig::new("ws://localhost", "SIM", OcppVersion::V201);
+        (StationState::new(&config), config)
+    }
+
+    fn sent(outcome: &CommandOutcome) -> Vec<(&'static str, Value)> {
+        outcome
+            .follow_ups
+            .iter()
+            .flat_map(|f| match f {
+                FollowUp::Send(calls) => calls.clone(),
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn station_messages_match_ocpp_schema() {
+        let (state, config) = station();
+        let mut connector = state.connectors[0].clone();
+        connector.meter_wh = 500.0;
+        let tx = ActiveTransaction {
+            transaction_id: "tx-1".to_string(),
+            id_tag: "TAG".to_string(),
+            started_at: Utc::now(),
+            meter_start_wh: 0.0,
+            seq_no: 0,
+            remote_start_id: Some(9),
+        };
+
+        let boot: BootNotificationRequest =
+            serde_json::from_value(boot_notification(&config, true)).unwrap();
+        assert_eq!(boot.charging_station.model, "Simulator");
+        let _: StatusNotificationRequest =
+            serde_json::from_value(status_notification(1, ConnectorState::Charging)).unwrap();
+        let started: TransactionEventRequest =
+            serde_json::from_value(transaction_started(1, &tx, Some(3))).unwrap();
+        assert_eq!(started.transaction_info.remote_start_id, Some(9));
+        assert_eq!(started.reservation_id, Some(3));
+        let _: TransactionEventRequest =
+            serde_json::from_value(transaction_updated(&connector, &tx)).unwrap();
+        let _: TransactionEventRequest =
+            serde_json::from_value(transaction_ended(&connector, &tx, StopReason::Remote)).unwrap();
+    }
+
+    #[test]
+    fn base_report_is_split_by_items_per_message() {
+        let (mut state, config) = station();
+        state.variables.insert(
+            ("DeviceDataCtrlr".to_string(), "ItemsPerMessage".to_string()),
+            "4".to_string(),
+        );
+
+        let outcome = handle_command(
+            &mut state,
+            &config,
+            "GetBaseReport",
+            &json!({ "requestId": 7, "reportBase": "FullInventory" }),
+        );
+
+        let parts = sent(&outcome);
+        assert_eq!(parts.len(), 3);
+        let last: NotifyReportRequest = serde_json::from_value(parts[2].1.clone()).unwrap();
+        assert_eq!(last.seq_no, 2);
+        assert_eq!(last.tbc, Some(false));
+        assert_eq!(last.report_data.unwrap().len(), 3);
+    }
+
+    #[test]
+    fn get_variables_reports_unknown_component_and_variable() {
+        let (mut state, config) = station();
+
+        let outcome = handle_command(
+            &mut state,
+            &config,
+            "GetVariables",
+            &json!({ "getVariableData": [
+                { "component": { "name": "OCPPCommCtrlr" }, "variable": { "name": "HeartbeatInterval" } },
+                { "component": { "name": "OCPPCommCtrlr" }, "variable": { "name": "Nope" } },
+                { "component": { "name": "Nope" }, "variable": { "name": "Nope" } },
+            ] }),
+        );
+
+        let response: GetVariablesResponse =
+            serde_json::from_value(outcome.response.unwrap()).unwrap();
+        let statuses: Vec<String> = response
+            .get_variable_result
+            .iter()
+            .map(|r| format!("{:?}", r.attribute_status))
+            .collect();
+        assert_eq!(
+            statuses,
+            ["Accepted", "UnknownVariable", "UnknownComponent"]
+        );
+        assert_eq!(
+            response.get_variable_result[0].attribute_value.as_deref(),
+            Some("300")
+        );
+    }
+
+    #[test]
+    fn monitors_set_by_csms_are_reported_back() {
+        let (mut state, config) = station();
+        handle_command(
+            &mut state,
+            &config,
+            "SetVariableMonitoring",
+            &json!({ "setMonitoringData": [{
+                "value": 60.5,
+                "type": "UpperThreshold",
+                "severity": 2,
+                "component": { "name": "OCPPCommCtrlr" },
+                "variable": { "name": "HeartbeatInterval" },
+            }] }),
+        );
+
+        let outcome = handle_command(
+            &mut state,
+            &config,
+            "GetMonitoringReport",
+            &json!({ "requestId": 1, "monitoringCriteria": ["ThresholdMonitoring"] }),
+        );
+        let report: NotifyMonitoringReportRequest =
+            serde_json::from_value(sent(&outcome)[0].1.clone()).unwrap();
+        assert_eq!(report.monitor.unwrap()[0].variable_monitoring[0].id, 1);
+
+        let outcome = handle_command(
+            &mut state,
+            &config,
+            "GetMonitoringReport",
+            &json!({ "requestId": 2, "monitoringCriteria": ["DeltaMonitoring"] }),
+        );
+        assert_eq!(
+            outcome.response.unwrap(),
+            json!({ "status": "EmptyResultSet" })
+        );
+    }
+
+    #[test]
+    fn unlock_is_refused_during_transaction() {
+        let (mut state, config) = station();
+        state.connectors[0].transaction = Some(ActiveTransaction {
+            transaction_id: "tx-1".to_string(),
+            id_tag: "TAG".to_string(),
+            started_at: Utc::now(),
+            meter_start_wh: 0.0,
+            seq_no: 1,
+            remote_start_id: None,
+        });
+
+        let outcome = handle_command(
+            &mut state,
+            &config,
+            "UnlockConnector",
+            &json!({ "evseId": 1, "connectorId": 1 }),
+        );
+        assert_eq!(
+            outcome.response.unwrap(),
+            json!({ "status": "OngoingAuthorizedTransaction" })
+        );
+    }
+}
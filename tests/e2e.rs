@@ -0,0 +1,1105 @@
+//! End-to-end tests: full server in-process, simulated stations, REST and gRPC APIs
+
+mod support;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use tonic::Code;
+
+use support::{eventually, TestServer};
+use texnouz_ocpp::application::charging::services::data_transfer::{
+    DataTransferHandler, DataTransferRegistry, DataTransferReply, IncomingDataTransfer,
+};
+use texnouz_ocpp::config::{AppConfig, HeartbeatOverrideConfig};
+use texnouz_ocpp::domain::{AuthEvent, ChargePoint, IdTag, OcpiToken, OcppVersion};
+use texnouz_ocpp::interfaces::grpc::proto::{
+    self, charge_points_client::ChargePointsClient, commands_client::CommandsClient,
+    events_client::EventsClient, transactions_client::TransactionsClient,
+};
+use texnouz_ocpp::simulator::{ConnectorState, SimulatedChargePoint};
+
+const ID_TAG: &str = "E2ETAG01";
+
+async fn transactions(server: &TestServer, charge_point_id: &str) -> Vec<Value> {
+    let (status, body) = server.get("/transactions").await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    body["items"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|tx| tx["charge_point_id"] == charge_point_id)
+        .collect()
+}
+
+#[tokio::test]
+async fn v16_remote_start_and_stop_via_rest() {
+    let server = TestServer::start().await;
+    server.add_id_tag(ID_TAG).await;
+    let station = server.boot_station("E2E-V16", OcppVersion::V16).await;
+
+    let (status, body) = server
+        .post(
+            "/charge-points/E2E-V16/remote-start",
+            json!({ "id_tag": ID_TAG, "connector_id": 1 }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["status"], "Accepted");
+    assert!(station
+        .received_commands()
+        .contains(&"RemoteStartTransaction".to_string()));
+
+    let tx = eventually("active transaction", || async {
+        transactions(&server, "E2E-V16")
+            .await
+            .into_iter()
+            .find(|tx| tx["status"] == "Active")
+    })
+    .await;
+    assert_eq!(tx["id_tag"], ID_TAG);
+    assert_eq!(tx["connector_id"], 1);
+
+    let (status, body) = server
+        .post(
+            "/charge-points/E2E-V16/remote-stop",
+            json!({ "transaction_id": tx["id"] }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["status"], "Accepted");
+
+    eventually("transaction stopped", || async {
+        transactions(&server, "E2E-V16")
+            .await
+            .into_iter()
+            .find(|t| t["id"] == tx["id"] && t["status"] != "Active")
+    })
+    .await;
+    eventually("station idle", || async {
+        (!station.state().has_active_transaction()).then_some(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn v201_get_variables_and_remote_start_via_rest() {
+    let server = TestServer::start().await;
+    server.add_id_tag(ID_TAG).await;
+    let station = server.boot_station("E2E-V201", OcppVersion::V201).await;
+
+    let (status, body) = server
+        .post(
+            "/charge-points/E2E-V201/variables/get",
+            json!({ "variables": [{ "component": "ChargingStation", "variable": "Model" }] }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let result = &body["data"]["results"][0];
+    assert_eq!(result["status"], "Accepted");
+    assert_eq!(result["value"], station.config().model.as_str());
+
+    let (status, body) = server
+        .post(
+            "/charge-points/E2E-V201/remote-start",
+            json!({ "id_tag": ID_TAG, "connector_id": 1 }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["status"], "Accepted");
+    assert!(station
+        .received_commands()
+        .contains(&"RequestStartTransaction".to_string()));
+
+    let tx = eventually("active transaction", || async {
+        transactions(&server, "E2E-V201")
+            .await
+            .into_iter()
+            .find(|tx| tx["status"] == "Active")
+    })
+    .await;
+    assert_eq!(tx["id_tag"], ID_TAG);
+}
+
+struct Echo;
+
+#[async_trait]
+impl DataTransferHandler for Echo {
+    async fn handle(&self, request: &IncomingDataTransfer) -> DataTransferReply {
+        DataTransferReply::accepted(request.data.clone())
+    }
+}
+
+#[tokio::test]
+async fn data_transfer_is_routed_to_vendor_extension_and_stored() {
+    let mut registry = DataTransferRegistry::new();
+    registry.register("com.e2e", Some("Echo"), Arc::new(Echo));
+    let server = TestServer::start_with(registry).await;
+    let station = server.boot_station("E2E-DT", OcppVersion::V16).await;
+
+    let reply = station
+        .client()
+        .call(
+            "DataTransfer",
+            json!({ "vendorId": "com.e2e", "messageId": "Echo", "data": "ping" }),
+        )
+        .await
+        .expect("DataTransfer answered");
+    assert_eq!(reply, json!({ "status": "Accepted", "data": "ping" }));
+
+    let reply = station
+        .client()
+        .call("DataTransfer", json!({ "vendorId": "com.other" }))
+        .await
+        .expect("DataTransfer answered");
+    assert_eq!(reply["status"], "UnknownVendorId");
+
+    let (status, body) = server.get("/charge-points/E2E-DT/data-transfers").await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let history = body["data"]["data_transfers"].as_array().unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[1]["vendor_id"], "com.e2e");
+    assert_eq!(history[1]["direction"], "Inbound");
+    assert_eq!(history[1]["response_data"], "ping");
+}
+
+#[tokio::test]
+async fn v201_plug_and_charge_without_contract_roots_is_rejected() {
+    let server = TestServer::start().await;
+    server.add_id_tag("DEABCC12345678X").await;
+    let station = server.boot_station("E2E-PNC", OcppVersion::V201).await;
+
+    let reply = station
+        .client()
+        .call(
+            "Authorize",
+            json!({
+                "idToken": { "idToken": "DE-ABC-C12345678-X", "type": "eMAID" },
+                "iso15118CertificateHashData": [{
+                    "hashAlgorithm": "SHA256",
+                    "issuerNameHash": "aa11",
+                    "issuerKeyHash": "bb22",
+                    "serialNumber": "0C01",
+                    "responderURL": "http://ocsp.local"
+                }]
+            }),
+        )
+        .await
+        .expect("Authorize answered");
+    assert_eq!(reply["certificateStatus"], "CertChainError");
+    assert_eq!(reply["idTokenInfo"]["status"], "Invalid");
+
+    let reply = station
+        .client()
+        .call(
+            "Authorize",
+            json!({ "idToken": { "idToken": "DE-ABC-C12345678-X", "type": "eMAID" } }),
+        )
+        .await
+        .expect("Authorize answered");
+    assert_eq!(reply["idTokenInfo"]["status"], "Accepted");
+
+    let reply = station
+        .client()
+        .call(
+            "Get15118EVCertificate",
+            json!({
+                "iso15118SchemaVersion": "urn:iso:15118:2:2013:MsgDef",
+                "action": "Install",
+                "exiRequest": "cmVx"
+            }),
+        )
+        .await
+        .expect("Get15118EVCertificate answered");
+    assert_eq!(reply["status"], "Failed");
+}
+
+#[tokio::test]
+async fn v201_ev_charging_needs_are_answered_with_tx_profile() {
+    let server = TestServer::start().await;
+    server.add_id_tag(ID_TAG).await;
+    let station = server.boot_station("E2E-SC", OcppVersion::V201).await;
+
+    let (status, body) = server
+        .post(
+            "/charge-points/E2E-SC/remote-start",
+            json!({ "id_tag": ID_TAG, "connector_id": 1 }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let tx = eventually("active transaction", || async {
+        transactions(&server, "E2E-SC")
+            .await
+            .into_iter()
+            .find(|tx| tx["status"] == "Active")
+    })
+    .await;
+
+    let departure = chrono::Utc::now() + chrono::Duration::hours(8);
+    let reply = station
+        .client()
+        .call(
+            "NotifyEVChargingNeeds",
+            json!({
+                "evseId": 1,
+                "chargingNeeds": {
+                    "requestedEnergyTransfer": "AC_three_phase",
+                    "departureTime": departure.to_rfc3339(),
+                    "acChargingParameters": {
+                        "energyAmount": 20000,
+                        "evMinCurrent": 6,
+                        "evMaxCurrent": 16,
+                        "evMaxVoltage": 230
+                    }
+                }
+            }),
+        )
+        .await
+        .expect("NotifyEVChargingNeeds answered");
+    assert_eq!(reply["status"], "Accepted");
+
+    let path = format!("/transactions/{}/charging-needs", tx["id"]);
+    let needs = eventually("TxProfile sent", || async {
+        let (_, body) = server.get(&path).await;
+        let needs = body["data"][0].clone();
+        (needs["profile_status"] == "Accepted").then_some(needs)
+    })
+    .await;
+    assert_eq!(needs["energy_amount_wh"], 20000);
+    let state = station.state();
+    let profile = state
+        .charging_profiles
+        .iter()
+        .find(|p| p.purpose == "TxProfile" && p.id == needs["profile_id"])
+        .expect("TxProfile installed");
+
+    // The profile names the station's transactionId, which is also stored
+    let station_tx = state.connectors[0].transaction.as_ref().unwrap();
+    assert_eq!(profile.profile["transactionId"], station_tx.transaction_id);
+    let stored = server
+        .repos
+        .transactions()
+        .find_by_id(tx["id"].as_i64().unwrap() as i32)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        stored.station_transaction_id.as_deref(),
+        Some(station_tx.transaction_id.as_str())
+    );
+
+    let reply = station
+        .client()
+        .call(
+            "NotifyEVChargingSchedule",
+            json!({
+                "timeBase": chrono::Utc::now().to_rfc3339(),
+                "evseId": 1,
+                "chargingSchedule": {
+                    "id": 1,
+                    "chargingRateUnit": "W",
+                    "chargingSchedulePeriod": [{ "startPeriod": 0, "limit": 7000 }]
+                }
+            }),
+        )
+        .await
+        .expect("NotifyEVChargingSchedule answered");
+    assert_eq!(reply["status"], "Accepted");
+}
+
+#[tokio::test]
+async fn v201_external_charging_limits_are_stored_and_cleared() {
+    let server = TestServer::start().await;
+    let station = server.boot_station("E2E-LIM", OcppVersion::V201).await;
+
+    let reply = station
+        .client()
+        .call(
+            "NotifyChargingLimit",
+            json!({
+                "evseId": 1,
+                "chargingLimit": { "chargingLimitSource": "SO", "isGridCritical": true },
+                "chargingSchedule": [{
+                    "id": 7,
+                    "chargingRateUnit": "W",
+                    "chargingSchedulePeriod": [{ "startPeriod": 0, "limit": 4000 }]
+                }]
+            }),
+        )
+        .await
+        .expect("NotifyChargingLimit answered");
+    assert_eq!(reply, json!({}));
+
+    let (status, body) = server.get("/charge-points/E2E-LIM/charging-limits").await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let limits = body["data"]["profiles"].as_array().unwrap();
+    assert_eq!(limits.len(), 1);
+    assert_eq!(limits[0]["limit_source"], "SO");
+    assert_eq!(limits[0]["evse_id"], 1);
+    assert_eq!(limits[0]["purpose"], "ChargingStationExternalConstraints");
+
+    station
+        .client()
+        .call(
+            "ClearedChargingLimit",
+            json!({ "chargingLimitSource": "SO" }),
+        )
+        .await
+        .expect("ClearedChargingLimit answered");
+
+    let (_, body) = server.get("/charge-points/E2E-LIM/charging-limits").await;
+    assert!(body["data"]["profiles"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn v16_reservation_blocks_other_tags_and_is_used_by_holder() {
+    let server = TestServer::start().await;
+    server.add_id_tag(ID_TAG).await;
+    server.add_id_tag("E2ETAG02").await;
+    let station = server.boot_station("E2E-RES", OcppVersion::V16).await;
+
+    let expiry = chrono::Utc::now() + chrono::Duration::hours(1);
+    let (status, body) = server
+        .post(
+            "/reservations",
+            json!({
+                "charge_point_id": "E2E-RES",
+                "connector_id": 1,
+                "id_tag": ID_TAG,
+                "expiry_date": expiry.to_rfc3339()
+            }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["status"], "Accepted");
+    let reservation_id = body["data"]["reservation_id"].clone();
+
+    let start = |id_tag: &str| {
+        json!({
+            "connectorId": 1,
+            "idTag": id_tag,
+            "meterStart": 0,
+            "reservationId": reservation_id,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        })
+    };
+    let reply = station
+        .client()
+        .call("StartTransaction", start("E2ETAG02"))
+        .await
+        .expect("StartTransaction answered");
+    assert_eq!(reply["idTagInfo"]["status"], "Invalid");
+    assert_eq!(reply["transactionId"], 0);
+
+    let reply = station
+        .client()
+        .call("StartTransaction", start(ID_TAG))
+        .await
+        .expect("StartTransaction answered");
+    assert_eq!(reply["idTagInfo"]["status"], "Accepted");
+
+    let (status, body) = server
+        .get(&format!("/reservations/{}", reservation_id))
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["status"], "Used");
+}
+
+#[tokio::test]
+async fn reservations_can_book_future_slots_without_overlap() {
+    let server = TestServer::start().await;
+    let station = server.boot_station("E2E-SLOT", OcppVersion::V16).await;
+
+    let rfc3339 =
+        |t: chrono::DateTime<chrono::Utc>| t.to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    let start = chrono::Utc::now() + chrono::Duration::hours(2);
+    let book = |offset_min: i64| {
+        let from = start + chrono::Duration::minutes(offset_min);
+        json!({
+            "charge_point_id": "E2E-SLOT",
+            "connector_id": 1,
+            "id_tag": ID_TAG,
+            "start_date": rfc3339(from),
+            "expiry_date": rfc3339(from + chrono::Duration::hours(1))
+        })
+    };
+
+    let (status, body) = server.post("/reservations", book(0)).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["status"], "Scheduled");
+    assert!(!station
+        .received_commands()
+        .contains(&"ReserveNow".to_string()));
+
+    let (status, body) = server.post("/reservations", book(30)).await;
+    assert_eq!(status, StatusCode::CONFLICT, "{}", body);
+    let (status, body) = server.post("/reservations", book(60)).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    // A slot that has already begun is refused, not booked right away
+    let (status, body) = server.post("/reservations", book(-150)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+
+    let path = format!(
+        "/reservations/availability?charge_point_id=E2E-SLOT&from={}&to={}",
+        rfc3339(start),
+        rfc3339(start + chrono::Duration::minutes(30)),
+    );
+    let (status, body) = server.get(&path).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let connector = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["connector_id"] == 1)
+        .cloned()
+        .expect("connector 1 listed");
+    assert_eq!(connector["available"], false);
+    assert_eq!(connector["reservations"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn connection_history_feeds_availability_report() {
+    let server = TestServer::start().await;
+    let station = server.boot_station("E2E-AVAIL", OcppVersion::V16).await;
+    station
+        .send_status(1, ConnectorState::Faulted)
+        .await
+        .unwrap();
+    station.close();
+
+    let window = (
+        chrono::Utc::now() - chrono::Duration::hours(1),
+        chrono::Utc::now() + chrono::Duration::hours(1),
+    );
+    let session = eventually("connection session closed", || async {
+        server
+            .repos
+            .availability()
+            .find_sessions(Some("E2E-AVAIL"), window.0, window.1)
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|s| s.disconnected_at.is_some())
+    })
+    .await;
+    assert_eq!(session.ocpp_version, "1.6");
+    assert!(session.remote_addr.is_some());
+    assert_eq!(
+        session.disconnect_reason.as_deref(),
+        Some("Closed by charge point")
+    );
+
+    let (status, body) = server
+        .get("/analytics/availability?charge_point_id=E2E-AVAIL")
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let report = &body["data"]["stations"][0];
+    assert_eq!(report["charge_point_id"], "E2E-AVAIL");
+    assert_eq!(report["failures"], 1);
+    let connectors: Vec<u64> = report["connectors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["connector_id"].as_u64().unwrap())
+        .collect();
+    assert_eq!(connectors, vec![1, 2]);
+
+    let (status, _) = server
+        .get("/analytics/availability?from=2030-01-02T00:00:00Z&to=2030-01-01T00:00:00Z")
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn status_history_feeds_fault_and_time_in_state_reports() {
+    let server = TestServer::start().await;
+    let station = server.boot_station("E2E-FAULTS", OcppVersion::V16).await;
+    station
+        .send_status(1, ConnectorState::Faulted)
+        .await
+        .unwrap();
+
+    let window = (
+        chrono::Utc::now() - chrono::Duration::hours(1),
+        chrono::Utc::now() + chrono::Duration::hours(1),
+    );
+    eventually("fault recorded", || async {
+        server
+            .repos
+            .availability()
+            .find_faults(Some("E2E-FAULTS"), Some(1), window.0, window.1)
+            .await
+            .unwrap()
+            .pop()
+    })
+    .await;
+
+    let (status, body) = server
+        .get("/analytics/faults?charge_point_id=E2E-FAULTS&group_by=error_code")
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["total_faults"], 1);
+    let group = &body["data"]["groups"][0];
+    assert_eq!(group["key"], "OtherError");
+    assert_eq!(group["fault_count"], 1);
+    assert_eq!(group["connectors"], 1);
+
+    let (status, _) = server.get("/analytics/faults?group_by=colour").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, body) = server
+        .get("/analytics/connector-states?charge_point_id=E2E-FAULTS")
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let connectors = body["data"]["connectors"].as_array().unwrap();
+    let connector = connectors
+        .iter()
+        .find(|c| c["connector_id"] == 1)
+        .expect("connector 1");
+    // Seconds are whole, so a fresh fault may not show up yet
+    assert!(connector["seconds_by_status"].is_object());
+
+    let (status, body) = server
+        .get("/analytics/connector-status-history?charge_point_id=E2E-FAULTS&connector_id=1")
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let history = body["data"].as_array().unwrap();
+    let current = history.last().unwrap();
+    assert_eq!(current["status"], "Faulted");
+    assert!(current["ended_at"].is_null());
+}
+
+#[tokio::test]
+async fn manual_archival_run_moves_expired_rows_to_archive() {
+    let server = TestServer::start().await;
+    let station = server.boot_station("E2E-ARCHIVE", OcppVersion::V16).await;
+    station.close();
+
+    // Authorization events are kept 90 days by default
+    let old = AuthEvent {
+        occurred_at: chrono::Utc::now() - chrono::Duration::days(120),
+        ..AuthEvent::new("E2E-ARCHIVE", "OLD-TAG", "Accepted")
+    };
+    let recent = AuthEvent::new("E2E-ARCHIVE", "NEW-TAG", "Accepted");
+    for event in [old, recent] {
+        server.repos.auth_events().record(event).await.unwrap();
+    }
+
+    let (status, body) = server.post("/retention/runs", json!({})).await;
+    assert_eq!(status, StatusCode::ACCEPTED, "{}", body);
+    let run_id = body["data"]["id"].as_i64().unwrap();
+
+    let run = eventually("archival run finished", || async {
+        let (_, body) = server.get(&format!("/retention/runs/{}", run_id)).await;
+        (body["data"]["status"] != "Running").then(|| body["data"].clone())
+    })
+    .await;
+    assert_eq!(run["status"], "Completed", "{}", run);
+    assert_eq!(run["trigger"], "Manual");
+
+    let auth_events = run["classes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["data_class"] == "auth_events")
+        .expect("auth_events archived");
+    assert_eq!(auth_events["rows"], 1);
+    let file = auth_events["file"].as_str().expect("archive file");
+    assert!(std::path::Path::new(file).starts_with(&server.archive_dir));
+    assert!(std::path::Path::new(file).exists());
+
+    // Archived id tags are pseudonymised
+    let mut archived = String::new();
+    std::io::Read::read_to_string(
+        &mut flate2::read::GzDecoder::new(std::fs::File::open(file).unwrap()),
+        &mut archived,
+    )
+    .unwrap();
+    let row: Value = serde_json::from_str(archived.lines().next().unwrap()).unwrap();
+    assert_eq!(row["charge_point_id"], "E2E-ARCHIVE");
+    assert!(row["id_tag"].as_str().unwrap().starts_with("ANON-"));
+    assert!(!archived.contains("OLD-TAG"));
+
+    let old_left = server.repos.auth_events().find_by_id_tag("OLD-TAG").await;
+    assert!(old_left.unwrap().is_empty());
+    let recent_left = server.repos.auth_events().find_by_id_tag("NEW-TAG").await;
+    assert_eq!(recent_left.unwrap().len(), 1);
+
+    let (status, body) = server.get("/retention/runs").await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"][0]["id"], run_id);
+}
+
+#[tokio::test]
+async fn bulk_import_validates_every_row_before_writing() {
+    let server = TestServer::start().await;
+
+    let file = "id_tag,name,status,max_active_transactions\n\
+                BULK-1,Alice,Accepted,1\n\
+                BULK-2,Bob,Lost,\n\
+                BULK-1,Carol,,\n";
+    let (status, body) = server
+        .upload("/bulk/id-tags/import", "text/csv", file.into())
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let report = &body["data"];
+    assert_eq!(report["applied"], false);
+    assert_eq!(report["rows"], 3);
+    let errors: Vec<_> = report["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| (e["line"].as_u64().unwrap(), e["column"].as_str().unwrap()))
+        .collect();
+    assert_eq!(errors, [(3, "status"), (4, "id_tag")]);
+    assert!(server
+        .repos
+        .id_tags()
+        .find("BULK-1")
+        .await
+        .unwrap()
+        .is_none());
+
+    let file = "id_tag,name,status,max_active_transactions\n\
+                BULK-1,Alice,Accepted,1\n\
+                BULK-2,Bob,Blocked,\n";
+    let (_, body) = server
+        .upload("/bulk/id-tags/import?dry_run=true", "text/csv", file.into())
+        .await;
+    assert_eq!(body["data"]["created"], 2);
+    assert_eq!(body["data"]["applied"], false);
+    assert!(server
+        .repos
+        .id_tags()
+        .find("BULK-1")
+        .await
+        .unwrap()
+        .is_none());
+
+    let (_, body) = server
+        .upload("/bulk/id-tags/import", "text/csv", file.into())
+        .await;
+    assert_eq!(body["data"]["applied"], true, "{}", body);
+    assert_eq!(body["data"]["created"], 2);
+    let bob = server
+        .repos
+        .id_tags()
+        .find("BULK-2")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(bob.name.as_deref(), Some("Bob"));
+    assert_eq!(bob.status.to_string(), "Blocked");
+
+    // Only the listed columns change
+    let (_, body) = server
+        .upload(
+            "/bulk/id-tags/import",
+            "text/csv",
+            "id_tag,status\nBULK-2,Accepted\n".into(),
+        )
+        .await;
+    assert_eq!(body["data"]["updated"], 1, "{}", body);
+    let bob = server
+        .repos
+        .id_tags()
+        .find("BULK-2")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(bob.name.as_deref(), Some("Bob"));
+    assert_eq!(bob.status.to_string(), "Accepted");
+
+    // Unknown users are row errors; a child may precede its parent
+    let file = "id_tag,parent_id_tag,user_id\n\
+                BULK-CHILD,BULK-PARENT,\n\
+                BULK-PARENT,,no-such-user\n";
+    let (_, body) = server
+        .upload("/bulk/id-tags/import", "text/csv", file.into())
+        .await;
+    assert_eq!(body["data"]["applied"], false, "{}", body);
+    assert_eq!(body["data"]["errors"][0]["line"], 3);
+    assert_eq!(body["data"]["errors"][0]["column"], "user_id");
+    assert!(server
+        .repos
+        .id_tags()
+        .find("BULK-CHILD")
+        .await
+        .unwrap()
+        .is_none());
+
+    let file = "id_tag,parent_id_tag\nBULK-CHILD,BULK-PARENT\nBULK-PARENT,\n";
+    let (_, body) = server
+        .upload("/bulk/id-tags/import", "text/csv", file.into())
+        .await;
+    assert_eq!(body["data"]["created"], 2, "{}", body);
+    let child = server.repos.id_tags().find("BULK-CHILD").await.unwrap();
+    assert_eq!(child.unwrap().parent_id_tag.as_deref(), Some("BULK-PARENT"));
+
+    let file = "id,vendor,model,connectors,password\nBULK-CP-1,Acme,Fast 50,2,s3cret-pass\n";
+    let (_, body) = server
+        .upload("/bulk/charge-points/import", "text/csv", file.into())
+        .await;
+    assert_eq!(body["data"]["created"], 1, "{}", body);
+    let cp = server
+        .repos
+        .charge_points()
+        .find_by_id("BULK-CP-1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(cp.password_hash.is_some());
+    assert!(cp.get_connector(2).is_some());
+
+    let (status, _) = server
+        .upload("/bulk/transactions/import", "text/csv", "id\n1\n".into())
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn bulk_exports_download_as_csv_and_xlsx() {
+    let server = TestServer::start().await;
+    server.add_id_tag("EXPORT-1").await;
+    server.add_id_tag("EXPORT-2").await;
+
+    let (status, content_type, body) = server.download("/bulk/id-tags/export").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type, "text/csv");
+    let csv = String::from_utf8(body).unwrap();
+    let mut lines = csv.lines();
+    assert!(lines
+        .next()
+        .unwrap()
+        .starts_with("id_tag,parent_id_tag,status,"));
+    let tags: Vec<_> = lines.map(|l| l.split(',').next().unwrap()).collect();
+    assert_eq!(tags, ["EXPORT-1", "EXPORT-2"]);
+
+    // An export imports back unchanged
+    let (_, body) = server
+        .upload("/bulk/id-tags/import?dry_run=true", "text/csv", csv.into())
+        .await;
+    assert_eq!(body["data"]["updated"], 2, "{}", body);
+    assert_eq!(body["data"]["errors"], json!([]));
+
+    let (status, content_type, body) = server
+        .download("/bulk/transactions/export?format=xlsx")
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(content_type.contains("spreadsheetml"));
+    assert!(body.starts_with(b"PK"), "XLSX is a zip archive");
+
+    let (status, _, _) = server.download("/bulk/meters/export").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn grpc_api_queries_commands_and_streams_events() {
+    let server = TestServer::start().await;
+    server.add_id_tag(ID_TAG).await;
+    let channel = server.grpc_channel().await;
+
+    let mut anonymous = ChargePointsClient::new(channel.clone());
+    let err = anonymous
+        .list_charge_points(proto::ListChargePointsRequest::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::Unauthenticated);
+
+    let mut events = EventsClient::with_interceptor(channel.clone(), server.grpc_auth())
+        .subscribe_events(proto::SubscribeEventsRequest {
+            charge_point_id: Some("GRPC-V16".to_string()),
+            event_types: vec!["transaction_started".to_string()],
+        })
+        .await
+        .expect("subscribed")
+        .into_inner();
+
+    let station = server.boot_station("GRPC-V16", OcppVersion::V16).await;
+    let mut charge_points = ChargePointsClient::with_interceptor(channel.clone(), server.grpc_auth());
+    let online = charge_points
+        .list_charge_points(proto::ListChargePointsRequest { online_only: true })
+        .await
+        .unwrap()
+        .into_inner();
+    let ids: Vec<_> = online.charge_points.iter().map(|cp| cp.id.as_str()).collect();
+    assert_eq!(ids, ["GRPC-V16"]);
+
+    let mut commands = CommandsClient::with_interceptor(channel.clone(), server.grpc_auth());
+    let reply = commands
+        .remote_start_transaction(proto::RemoteStartTransactionRequest {
+            charge_point_id: "GRPC-V16".to_string(),
+            id_tag: ID_TAG.to_string(),
+            connector_id: Some(1),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(reply.accepted, "{:?}", reply);
+    assert!(station
+        .received_commands()
+        .contains(&"RemoteStartTransaction".to_string()));
+
+    let event = tokio::time::timeout(Duration::from_secs(5), events.message())
+        .await
+        .expect("event in time")
+        .unwrap()
+        .expect("stream open");
+    assert_eq!(event.event_type, "transaction_started");
+    assert_eq!(event.charge_point_id.as_deref(), Some("GRPC-V16"));
+    let data: Value = serde_json::from_str(&event.data_json).unwrap();
+    assert_eq!(data["id_tag"], ID_TAG);
+
+    let mut transactions = TransactionsClient::with_interceptor(channel, server.grpc_auth());
+    let page = transactions
+        .list_transactions(proto::ListTransactionsRequest {
+            charge_point_id: Some("GRPC-V16".to_string()),
+            active_only: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(page.transactions.len(), 1);
+    assert_eq!(page.next_after_id, None);
+    let tx = transactions
+        .get_transaction(proto::GetTransactionRequest {
+            transaction_id: page.transactions[0].id,
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(tx.status, "Active");
+    assert_eq!(tx.id_tag, ID_TAG);
+
+    let err = commands
+        .reset(proto::ResetRequest {
+            charge_point_id: "NOPE".to_string(),
+            reset_type: proto::ResetType::Hard.into(),
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn commands_to_unknown_station_return_not_found() {
+    let server = TestServer::start().await;
+
+    let (status, _) = server
+        .post(
+            "/charge-points/NOPE/remote-start",
+            json!({ "id_tag": ID_TAG }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn config_reload_applies_live_settings_without_restart() {
+    let server = TestServer::start().await;
+    server
+        .repos
+        .charge_points()
+        .save(ChargePoint::new("E2E-RELOAD"))
+        .await
+        .unwrap();
+
+    let mut cfg = AppConfig::load(&server.config_path).unwrap();
+    cfg.logging.level = "loud".to_string();
+    cfg.save(&server.config_path).unwrap();
+    let (status, body) = server.post("/admin/config/reload", json!({})).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+
+    cfg.logging.level = "info".to_string();
+    cfg.cors.allowed_origins = vec!["https://ops.example.com".to_string()];
+    cfg.rate_limit.api_requests_per_minute = 5;
+    cfg.ws_auth.reject_unknown_charge_points = true;
+    cfg.server.api_port = 9090;
+    cfg.save(&server.config_path).unwrap();
+    let (status, body) = server.post("/admin/config/reload", json!({})).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(
+        body["data"]["applied"],
+        json!([
+            "cors.allowed_origins",
+            "rate_limit.api_requests_per_minute",
+            "ws_auth.reject_unknown_charge_points",
+        ])
+    );
+    assert_eq!(body["data"]["restart_required"], json!(["server.api_port"]));
+
+    // CORS answers only the configured origin
+    let http = reqwest::Client::new();
+    let allowed_origin = |origin: &'static str| {
+        let request = http
+            .get(format!("{}/charge-points", server.api_url))
+            .bearer_auth(&server.token)
+            .header("Origin", origin);
+        async move {
+            let response = request.send().await.unwrap();
+            response
+                .headers()
+                .get("access-control-allow-origin")
+                .map(|v| v.to_str().unwrap().to_string())
+        }
+    };
+    assert_eq!(
+        allowed_origin("https://ops.example.com").await.as_deref(),
+        Some("https://ops.example.com")
+    );
+    assert_eq!(allowed_origin("https://other.example.com").await, None);
+
+    // Unknown stations are closed after the handshake, registered ones boot
+    if let Ok(unknown) = SimulatedChargePoint::connect(
+        server.station_config("E2E-UNKNOWN", OcppVersion::V16),
+    )
+    .await
+    {
+        assert!(unknown.boot().await.is_err(), "unknown station refused");
+    }
+    server.boot_station("E2E-RELOAD", OcppVersion::V16).await;
+
+    // The lowered API limit applies to the current window
+    let mut limited = None;
+    for _ in 0..5 {
+        let response = http
+            .get(format!("{}/charge-points", server.api_url))
+            .bearer_auth(&server.token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.headers()["x-ratelimit-limit"], "5");
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            limited = Some(response);
+            break;
+        }
+    }
+    let limited = limited.expect("rate limited");
+    assert!(limited.headers().contains_key("retry-after"));
+}
+
+#[tokio::test]
+async fn heartbeat_interval_changes_reach_connected_stations() {
+    let server = TestServer::start().await;
+    let v16 = server.boot_station("E2E-HB-V16", OcppVersion::V16).await;
+    let v201 = server.boot_station("E2E-HB-V201", OcppVersion::V201).await;
+    assert_eq!(v16.state().heartbeat_interval, 300);
+    assert_eq!(v201.state().heartbeat_interval, 300);
+
+    let heartbeat_status = |charge_point_id: &'static str| {
+        let server = &server;
+        async move {
+            let (status, body) = server.get("/monitoring/heartbeats").await;
+            assert_eq!(status, StatusCode::OK, "{}", body);
+            body["data"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .find(|s| s["charge_point_id"] == charge_point_id)
+                .cloned()
+                .unwrap_or(Value::Null)
+        }
+    };
+    let status = heartbeat_status("E2E-HB-V16").await;
+    assert_eq!(status["heartbeat_interval"], 300);
+    assert_eq!(status["offline_threshold_secs"], 600);
+    assert_eq!(status["unavailable_threshold_secs"], 900);
+
+    // A shorter default, and a long interval for a cellular station
+    let mut cfg = AppConfig::load(&server.config_path).unwrap();
+    cfg.server.heartbeat_interval = 60;
+    cfg.heartbeat.overrides = vec![HeartbeatOverrideConfig {
+        charge_point_ids: vec!["E2E-HB-V201".to_string()],
+        interval_secs: Some(1800),
+        ..HeartbeatOverrideConfig::default()
+    }];
+    cfg.save(&server.config_path).unwrap();
+    let (status, body) = server.post("/admin/config/reload", json!({})).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(
+        body["data"]["applied"],
+        json!(["heartbeat.overrides", "server.heartbeat_interval"])
+    );
+
+    eventually("new intervals accepted", || async {
+        (v16.state().heartbeat_interval == 60 && v201.state().heartbeat_interval == 1800)
+            .then_some(())
+    })
+    .await;
+    assert!(v16
+        .received_commands()
+        .contains(&"ChangeConfiguration".to_string()));
+    assert!(v201.received_commands().contains(&"SetVariables".to_string()));
+
+    let status = eventually("accepted intervals recorded", || async {
+        let status = heartbeat_status("E2E-HB-V201").await;
+        (status["heartbeat_interval"] == 1800).then_some(status)
+    })
+    .await;
+    assert_eq!(status["offline_threshold_secs"], 3600);
+    assert_eq!(status["unavailable_threshold_secs"], 5400);
+    let status = eventually("accepted intervals recorded", || async {
+        let status = heartbeat_status("E2E-HB-V16").await;
+        (status["heartbeat_interval"] == 60).then_some(status)
+    })
+    .await;
+    assert_eq!(status["offline_threshold_secs"], 180);
+
+    // Stations booting later are given the configured interval straight away
+    let late = server.boot_station("E2E-HB-LATE", OcppVersion::V16).await;
+    assert_eq!(late.state().heartbeat_interval, 60);
+}
+
+#[tokio::test]
+async fn erasing_an_id_tag_removes_its_tokens_and_detaches_children() {
+    let server = TestServer::start().await;
+    let id_tags = server.repos.id_tags();
+    id_tags.save(IdTag::new("E2E-GDPR-GROUP")).await.unwrap();
+    let mut child = IdTag::new("E2E-GDPR-CHILD");
+    child.parent_id_tag = Some("E2E-GDPR-GROUP".to_string());
+    id_tags.save(child).await.unwrap();
+    server
+        .repos
+        .ocpi_tokens()
+        .upsert(OcpiToken {
+            country_code: "DE".to_string(),
+            party_id: "EMS".to_string(),
+            uid: "E2E-GDPR-GROUP".to_string(),
+            token_type: "RFID".to_string(),
+            contract_id: "DE-EMS-C00001".to_string(),
+            visual_number: None,
+            issuer: "eMSP".to_string(),
+            group_id: None,
+            valid: true,
+            whitelist: "ALLOWED".to_string(),
+            language: None,
+            last_updated: chrono::Utc::now(),
+        })
+        .await
+        .unwrap();
+
+    let (status, body) = server
+        .post("/customer-data/id-tags/E2E-GDPR-GROUP/erase", json!({}))
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let erased = &body["data"]["id_tags"][0];
+    assert_eq!(erased["ocpi_tokens"], 1);
+    assert_eq!(erased["child_id_tags"], 1);
+    assert_eq!(erased["id_tag_removed"], true);
+
+    assert!(server
+        .repos
+        .ocpi_tokens()
+        .find_by_uid("E2E-GDPR-GROUP")
+        .await
+        .unwrap()
+        .is_none());
+    let child = id_tags.find("E2E-GDPR-CHILD").await.unwrap().unwrap();
+    assert_eq!(child.parent_id_tag, None);
+    assert!(id_tags.find("E2E-GDPR-GROUP").await.unwrap().is_none());
+}
@@ -0,0 +1,219 @@
+//! MQTT bridge tests against a real broker
+//!
+//! The tests need an MQTT broker and pass without running unless
+//! `TEST_MQTT_BROKER` names one, e.g. a local Mosquitto:
+//!
+//! ```text
+//! docker run -d -p 1883:1883 eclipse-mosquitto:2 mosquitto -c /mosquitto-no-auth.conf
+//! TEST_MQTT_BROKER=localhost:1883 cargo test --test mqtt
+//! ```
+
+mod support;
+
+use std::time::Duration;
+
+use rumqttc::{AsyncClient, Event, MqttOptions, Packet, QoS};
+use serde_json::{json, Value};
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+use texnouz_ocpp::config::MqttConfig;
+use texnouz_ocpp::domain::OcppVersion;
+
+use support::TestServer;
+
+const ID_TAG: &str = "MQTTTAG1";
+
+/// Broker `(host, port)`, or `None` when `TEST_MQTT_BROKER` is unset and
+/// the test is skipped.
+fn broker() -> Option<(String, u16)> {
+    let Ok(address) = std::env::var("TEST_MQTT_BROKER") else {
+        eprintln!("skipped: TEST_MQTT_BROKER does not name an MQTT broker");
+        return None;
+    };
+    let (host, port) = address
+        .rsplit_once(':')
+        .expect("TEST_MQTT_BROKER is host:port");
+    Some((host.to_string(), port.parse().expect("broker port")))
+}
+
+/// Messages received by the observer, as `(topic, payload)`.
+struct Inbox {
+    rx: UnboundedReceiver<(String, Value)>,
+    /// Received but not yet asked for
+    pending: Vec<(String, Value)>,
+}
+
+impl Inbox {
+    /// The next message on `topic` that `matches`, waiting up to `wait`.
+    async fn next(
+        &mut self,
+        topic: &str,
+        matches: impl Fn(&Value) -> bool,
+        wait: Duration,
+    ) -> Option<Value> {
+        if let Some(i) = self
+            .pending
+            .iter()
+            .position(|(t, v)| t == topic && matches(v))
+        {
+            return Some(self.pending.remove(i).1);
+        }
+        tokio::time::timeout(wait, async {
+            loop {
+                let (received, payload) = self.rx.recv().await.expect("observer running");
+                if received == topic && matches(&payload) {
+                    return payload;
+                }
+                self.pending.push((received, payload));
+            }
+        })
+        .await
+        .ok()
+    }
+
+    async fn event(&mut self, topic: &str) -> Value {
+        self.next(topic, |_| true, Duration::from_secs(10))
+            .await
+            .unwrap_or_else(|| panic!("no message on {}", topic))
+    }
+
+    async fn reply(&mut self, topic: &str, id: &str) -> Value {
+        self.next(topic, |v| v["id"] == id, Duration::from_secs(10))
+            .await
+            .unwrap_or_else(|| panic!("no reply {} on {}", id, topic))
+    }
+}
+
+/// A client subscribed to everything under `prefix`.
+async fn observer(host: &str, port: u16, prefix: &str) -> (AsyncClient, Inbox) {
+    let id = format!("observer-{}", uuid::Uuid::new_v4().simple());
+    let (client, mut eventloop) = AsyncClient::new(MqttOptions::new(id, host, port), 64);
+    client
+        .subscribe(format!("{}/#", prefix), QoS::AtLeastOnce)
+        .await
+        .unwrap();
+
+    let (subscribed_tx, subscribed_rx) = tokio::sync::oneshot::channel();
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let mut subscribed_tx = Some(subscribed_tx);
+        while let Ok(event) = eventloop.poll().await {
+            match event {
+                Event::Incoming(Packet::SubAck(_)) => {
+                    if let Some(subscribed) = subscribed_tx.take() {
+                        let _ = subscribed.send(());
+                    }
+                }
+                Event::Incoming(Packet::Publish(publish)) => {
+                    let payload = serde_json::from_slice(&publish.payload).unwrap_or(Value::Null);
+                    if tx.send((publish.topic, payload)).is_err() {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+    });
+    tokio::time::timeout(Duration::from_secs(5), subscribed_rx)
+        .await
+        .expect("observer subscribed in time")
+        .unwrap();
+    let inbox = Inbox {
+        rx,
+        pending: Vec::new(),
+    };
+    (client, inbox)
+}
+
+#[tokio::test]
+async fn bridge_publishes_events_and_executes_commands() {
+    let Some((host, port)) = broker() else { return };
+    let prefix = format!("test-{}", uuid::Uuid::new_v4().simple());
+    let (client, mut messages) = observer(&host, port, &prefix).await;
+
+    let server = TestServer::start().await;
+    server.add_id_tag(ID_TAG).await;
+    server.start_mqtt_bridge(MqttConfig {
+        enabled: true,
+        host: host.clone(),
+        port,
+        topic_prefix: prefix.clone(),
+        ..MqttConfig::default()
+    });
+
+    let station = server.boot_station("MQTT-V16", OcppVersion::V16).await;
+    let connected = messages
+        .event(&format!("{}/MQTT-V16/charge_point_connected", prefix))
+        .await;
+    assert_eq!(connected["type"], "ChargePointConnected");
+    assert_eq!(connected["data"]["charge_point_id"], "MQTT-V16");
+
+    // The command subscription follows the connection; retry until it is in place
+    let command_topic = format!("{}/MQTT-V16/command", prefix);
+    let response_topic = format!("{}/response", command_topic);
+    let invalid = tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            client
+                .publish(
+                    &command_topic,
+                    QoS::AtLeastOnce,
+                    false,
+                    r#"{"id":"probe","action":"Fly"}"#,
+                )
+                .await
+                .unwrap();
+            let probe = messages.next(
+                &response_topic,
+                |v| v["id"] == "probe",
+                Duration::from_millis(500),
+            );
+            if let Some(reply) = probe.await {
+                return reply;
+            }
+        }
+    })
+    .await
+    .expect("bridge subscribed to commands");
+    assert_eq!(invalid["id"], "probe");
+    assert_eq!(invalid["accepted"], false);
+    assert_eq!(invalid["error"]["code"], "InvalidCommand");
+
+    let command = json!({
+        "id": "start-1",
+        "action": "RemoteStartTransaction",
+        "params": { "idTag": ID_TAG, "connectorId": 1 },
+    });
+    client
+        .publish(&command_topic, QoS::AtLeastOnce, false, command.to_string())
+        .await
+        .unwrap();
+    let reply = messages.reply(&response_topic, "start-1").await;
+    assert_eq!(reply["id"], "start-1");
+    assert_eq!(reply["action"], "RemoteStartTransaction");
+    assert_eq!(reply["accepted"], true, "{}", reply);
+    assert_eq!(reply["result"]["status"], "Accepted");
+    assert!(station
+        .received_commands()
+        .contains(&"RemoteStartTransaction".to_string()));
+
+    let started = messages
+        .event(&format!("{}/MQTT-V16/transaction_started", prefix))
+        .await;
+    assert_eq!(started["data"]["id_tag"], ID_TAG);
+
+    // Stations that are not connected get an error reply
+    client
+        .publish(
+            format!("{}/NOWHERE/command", prefix),
+            QoS::AtLeastOnce,
+            false,
+            json!({ "id": "reset-1", "action": "Reset", "params": { "type": "Soft" } }).to_string(),
+        )
+        .await
+        .unwrap();
+    let reply = messages
+        .reply(&format!("{}/NOWHERE/command/response", prefix), "reset-1")
+        .await;
+    assert_eq!(reply["id"], "reset-1");
+    assert_eq!(reply["error"]["code"], "NotConnected");
+}
@@ -0,0 +1,375 @@
+//! OCPI CPO interface over HTTP: a mock eMSP registers, manages its tokens
+//! and sends commands the way a roaming partner would
+
+mod support;
+
+use std::sync::{Arc, Mutex};
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, Method, Uri};
+use axum::{Json, Router};
+use chrono::Utc;
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use tokio::net::TcpListener;
+
+use support::{eventually, TestServer};
+use texnouz_ocpp::domain::{OcpiParty, OcpiPartyStatus, OcppVersion};
+use texnouz_ocpp::interfaces::ocpi::common::authorization_header;
+
+/// A request the mock eMSP received.
+#[derive(Debug, Clone)]
+struct Received {
+    method: Method,
+    path: String,
+    authorization: Option<String>,
+    body: Value,
+}
+
+/// An eMSP's OCPI API: versions, version details and a catch-all for
+/// asynchronous results. Records every request.
+#[derive(Clone)]
+struct MockEmsp {
+    url: String,
+    received: Arc<Mutex<Vec<Received>>>,
+}
+
+impl MockEmsp {
+    async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind eMSP");
+        let emsp = Self {
+            url: format!("http://{}", listener.local_addr().expect("eMSP address")),
+            received: Arc::default(),
+        };
+        let app = Router::new().fallback(answer).with_state(emsp.clone());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        emsp
+    }
+
+    fn versions_url(&self) -> String {
+        format!("{}/versions", self.url)
+    }
+
+    fn requests_to(&self, path: &str) -> Vec<Received> {
+        self.received
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.path == path)
+            .cloned()
+            .collect()
+    }
+}
+
+async fn answer(
+    State(emsp): State<MockEmsp>,
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Json<Value> {
+    let path = uri.path().to_string();
+    emsp.received.lock().unwrap().push(Received {
+        method,
+        path: path.clone(),
+        authorization: headers
+            .get("Authorization")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string),
+        body: serde_json::from_slice(&body).unwrap_or(Value::Null),
+    });
+
+    let data = match path.as_str() {
+        "/versions" => json!([{ "version": "2.2.1", "url": format!("{}/2.2.1", emsp.url) }]),
+        "/2.2.1" => json!({
+            "version": "2.2.1",
+            "endpoints": [
+                { "identifier": "tokens", "role": "SENDER", "url": format!("{}/tokens", emsp.url) },
+                { "identifier": "commands", "role": "SENDER", "url": format!("{}/commands", emsp.url) },
+            ],
+        }),
+        _ => Value::Null,
+    };
+    Json(json!({ "data": data, "status_code": 1000, "timestamp": Utc::now() }))
+}
+
+/// A partner that completed the handshake; returns its token C.
+async fn registered_party(
+    server: &TestServer,
+    country_code: &str,
+    party_id: &str,
+    token_b: &str,
+) -> String {
+    let token_c = uuid::Uuid::new_v4().to_string();
+    let mut party = OcpiParty::new(country_code, party_id, "EMSP", "Mock eMSP");
+    party.token_a = None;
+    party.token_b = Some(token_b.to_string());
+    party.token_c = Some(token_c.clone());
+    party.status = OcpiPartyStatus::Registered;
+    server.repos.ocpi_parties().save(party).await.unwrap();
+    token_c
+}
+
+fn token(country_code: &str, party_id: &str, uid: &str) -> Value {
+    json!({
+        "country_code": country_code,
+        "party_id": party_id,
+        "uid": uid,
+        "type": "RFID",
+        "contract_id": format!("{}-{}-C{}", country_code, party_id, uid),
+        "issuer": "Mock eMSP",
+        "valid": true,
+        "whitelist": "ALLOWED",
+        "last_updated": Utc::now(),
+    })
+}
+
+fn credentials(emsp: &MockEmsp, token_b: &str) -> Value {
+    json!({
+        "token": token_b,
+        "url": emsp.versions_url(),
+        "roles": [{
+            "role": "EMSP",
+            "business_details": { "name": "Mock eMSP" },
+            "party_id": "EMS",
+            "country_code": "NL",
+        }],
+    })
+}
+
+#[tokio::test]
+async fn credentials_handshake_registers_updates_and_unregisters() {
+    let server = TestServer::start_with_ocpi().await;
+    let emsp = MockEmsp::start().await;
+
+    let (status, body) = server
+        .post(
+            "/ocpi/parties",
+            json!({ "country_code": "NL", "party_id": "EMS", "name": "Mock eMSP" }),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let party_id = body["data"]["id"].as_i64().unwrap();
+    let token_a = body["data"]["token_a"].as_str().unwrap().to_string();
+
+    // Unknown tokens are turned away
+    let (status, body) = server
+        .ocpi(Method::GET, "not-a-token", "/versions", None)
+        .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["status_code"], 2000);
+
+    // Token A only opens versions and credentials
+    let (status, _) = server.ocpi(Method::GET, &token_a, "/versions", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = server
+        .ocpi(Method::GET, &token_a, "/2.2.1/locations", None)
+        .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // Register: we fetch the eMSP's endpoints with its token B
+    let (status, body) = server
+        .ocpi(
+            Method::POST,
+            &token_a,
+            "/2.2.1/credentials",
+            Some(credentials(&emsp, "token-b")),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["status_code"], 1000);
+    assert_eq!(body["data"]["roles"][0]["role"], "CPO");
+    assert_eq!(body["data"]["url"], format!("{}/versions", server.ocpi_url));
+    let token_c = body["data"]["token"].as_str().unwrap().to_string();
+    assert_ne!(token_c, token_a);
+    for path in ["/versions", "/2.2.1"] {
+        let calls = emsp.requests_to(path);
+        assert_eq!(calls.len(), 1, "{}", path);
+        assert_eq!(
+            calls[0].authorization.as_deref(),
+            Some(authorization_header("token-b").as_str())
+        );
+    }
+
+    // Token A was single-use; registering twice is refused
+    let (status, _) = server.ocpi(Method::GET, &token_a, "/versions", None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = server
+        .ocpi(
+            Method::POST,
+            &token_c,
+            "/2.2.1/credentials",
+            Some(credentials(&emsp, "token-b")),
+        )
+        .await;
+    assert_eq!(status, StatusCode::METHOD_NOT_ALLOWED);
+
+    // Update: a new token B, and a new token C replacing the old one
+    let (status, body) = server
+        .ocpi(
+            Method::PUT,
+            &token_c,
+            "/2.2.1/credentials",
+            Some(credentials(&emsp, "token-b2")),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let rotated = body["data"]["token"].as_str().unwrap().to_string();
+    assert_ne!(rotated, token_c);
+    let (status, _) = server
+        .ocpi(Method::GET, &token_c, "/2.2.1/credentials", None)
+        .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, body) = server
+        .ocpi(Method::GET, &rotated, "/2.2.1/credentials", None)
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["token"], rotated.as_str());
+    let party = server
+        .repos
+        .ocpi_parties()
+        .find_by_id(party_id as i32)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(party.token_b.as_deref(), Some("token-b2"));
+
+    // Unregister: token C stops working
+    let (status, body) = server
+        .ocpi(Method::DELETE, &rotated, "/2.2.1/credentials", None)
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let (status, _) = server
+        .ocpi(Method::GET, &rotated, "/2.2.1/credentials", None)
+        .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, body) = server.get(&format!("/ocpi/parties/{}", party_id)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["status"], "Unregistered");
+}
+
+#[tokio::test]
+async fn tokens_can_only_be_managed_by_their_owner() {
+    let server = TestServer::start_with_ocpi().await;
+    let owner = registered_party(&server, "NL", "EMS", "token-b").await;
+    let other = registered_party(&server, "DE", "ABC", "token-b-abc").await;
+    let path = "/2.2.1/tokens/NL/EMS/TAG-1";
+
+    let (status, body) = server
+        .ocpi(Method::PUT, &owner, path, Some(token("NL", "EMS", "TAG-1")))
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let (status, body) = server.ocpi(Method::GET, &owner, path, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["uid"], "TAG-1");
+    assert_eq!(body["data"]["valid"], true);
+
+    // Another party can neither read nor change them
+    let (status, body) = server.ocpi(Method::GET, &other, path, None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["status_code"], 2001);
+    let (status, _) = server
+        .ocpi(Method::PUT, &other, path, Some(token("NL", "EMS", "TAG-1")))
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let patch = json!({ "valid": false, "last_updated": Utc::now() });
+    let (status, _) = server
+        .ocpi(Method::PATCH, &other, path, Some(patch.clone()))
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // nor store one of theirs under its own URL
+    let (status, _) = server
+        .ocpi(
+            Method::PUT,
+            &other,
+            "/2.2.1/tokens/DE/ABC/TAG-1",
+            Some(token("NL", "EMS", "TAG-1")),
+        )
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let stored = server
+        .repos
+        .ocpi_tokens()
+        .find("NL", "EMS", "TAG-1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(stored.valid);
+
+    // The owner can
+    let (status, body) = server.ocpi(Method::PATCH, &owner, path, Some(patch)).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let (_, body) = server.ocpi(Method::GET, &owner, path, None).await;
+    assert_eq!(body["data"]["valid"], false);
+
+    let (status, body) = server
+        .ocpi(Method::GET, &owner, "/2.2.1/tokens/NL/EMS/TAG-2", None)
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["status_code"], 2004);
+}
+
+#[tokio::test]
+async fn command_results_are_posted_to_the_response_url() {
+    let server = TestServer::start_with_ocpi().await;
+    let emsp = MockEmsp::start().await;
+    let token_c = registered_party(&server, "NL", "EMS", "token-b").await;
+    let station = server.boot_station("OCPI-CP", OcppVersion::V16).await;
+
+    // Tokens in commands must belong to the caller too
+    let (status, _) = server
+        .ocpi(
+            Method::POST,
+            &token_c,
+            "/2.2.1/commands/START_SESSION",
+            Some(json!({
+                "response_url": format!("{}/commands/START_SESSION/0", emsp.url),
+                "token": token("DE", "ABC", "TAG-1"),
+                "location_id": "OCPI-CP",
+            })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, body) = server
+        .ocpi(
+            Method::POST,
+            &token_c,
+            "/2.2.1/commands/START_SESSION",
+            Some(json!({
+                "response_url": format!("{}/commands/START_SESSION/1", emsp.url),
+                "token": token("NL", "EMS", "TAG-1"),
+                "location_id": "OCPI-CP",
+            })),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["result"], "ACCEPTED");
+    assert_eq!(body["data"]["timeout"], 30);
+
+    // The station's answer arrives later, at the eMSP, with token B
+    let result = eventually("CommandResult delivered", || async {
+        emsp.requests_to("/commands/START_SESSION/1").pop()
+    })
+    .await;
+    assert_eq!(result.method, Method::POST);
+    assert_eq!(
+        result.authorization.as_deref(),
+        Some(authorization_header("token-b").as_str())
+    );
+    assert_eq!(result.body["result"], "ACCEPTED");
+    assert!(station
+        .received_commands()
+        .contains(&"RemoteStartTransaction".to_string()));
+    assert!(emsp.requests_to("/commands/START_SESSION/0").is_empty());
+    assert!(server
+        .repos
+        .ocpi_tokens()
+        .find("NL", "EMS", "TAG-1")
+        .await
+        .unwrap()
+        .is_some());
+}